@@ -1,8 +1,10 @@
 // src/analysis/ast.rs
 use super::checks::{self, CheckContext};
+use super::exempt::Exemptions;
+use super::{docs, metrics, paranoia, safety, sfc};
 use crate::config::RuleConfig;
 use crate::lang::Lang;
-use crate::types::Violation;
+use crate::types::{FileMetrics, Violation};
 use tree_sitter::{Language, Parser, Query};
 
 pub struct Analyzer;
@@ -27,33 +29,96 @@ impl Analyzer {
         content: &str,
         config: &RuleConfig,
     ) -> Vec<Violation> {
-        let Some(lang) = Lang::from_ext(ext) else {
-            return vec![];
-        };
-        Self::run_analysis(lang, filename, content, config)
+        Self::analyze_with(ext, filename, content, config, &Exemptions::new(config))
     }
 
-    fn run_analysis(
-        lang: Lang,
+    /// Like [`Self::analyze`], with the `ignore_*_on` lists compiled once
+    /// by the caller.
+    #[must_use]
+    pub fn analyze_with(
+        ext: &str,
         filename: &str,
         content: &str,
         config: &RuleConfig,
+        exempt: &Exemptions,
     ) -> Vec<Violation> {
+        let file = File {
+            name: filename,
+            content,
+            exempt,
+        };
+        if sfc::is_sfc(ext) {
+            return Self::analyze_sfc(&file, config);
+        }
+        let Some(lang) = Lang::from_ext(ext) else {
+            return vec![];
+        };
+        Self::run_analysis(lang, &file, config)
+    }
+
+    /// Runs the JS/TS rules against each `<script>` block, mapping rows back
+    /// to the component file.
+    fn analyze_sfc(file: &File, config: &RuleConfig) -> Vec<Violation> {
+        sfc::extract_scripts(file.content)
+            .into_iter()
+            .flat_map(|block| {
+                let script = File {
+                    content: &block.source,
+                    ..*file
+                };
+                let mut found = Self::run_analysis(Lang::TypeScript, &script, config);
+                for v in &mut found {
+                    v.row += block.line_offset;
+                }
+                found
+            })
+            .collect()
+    }
+
+    /// Computes complexity, Halstead volume and maintainability index for a file.
+    #[must_use]
+    pub fn measure(&self, ext: &str, content: &str) -> Option<FileMetrics> {
+        let lang = Lang::from_ext(ext)?;
         let grammar = lang.grammar();
         let mut parser = Parser::new();
-        if parser.set_language(grammar).is_err() {
+        parser.set_language(&grammar).ok()?;
+        let tree = parser.parse(content, None)?;
+        let root = tree.root_node();
+
+        let q_complexity = compile_query(&grammar, lang.q_complexity());
+        let complexity = metrics::calculate_complexity(root, content, &q_complexity);
+        let volume = metrics::calculate_halstead(root, content).volume();
+        let loc = content.lines().filter(|l| !l.trim().is_empty()).count();
+
+        Some(FileMetrics {
+            complexity,
+            halstead_volume: volume,
+            maintainability: metrics::maintainability_index(volume, complexity, loc),
+        })
+    }
+
+    fn run_analysis(lang: Lang, file: &File, config: &RuleConfig) -> Vec<Violation> {
+        let File {
+            name: filename,
+            content,
+            exempt,
+        } = *file;
+        let grammar = lang.grammar();
+        let mut parser = Parser::new();
+        if parser.set_language(&grammar).is_err() {
             return vec![];
         }
 
         let Some(tree) = parser.parse(content, None) else {
             return vec![];
         };
+        let config = &*config.for_lang(lang);
 
         // Compile queries on demand (memoization could be added here later if perf matters,
         // but for CLI usage creating queries per file is acceptable/fast enough).
-        let q_naming = compile_query(grammar, lang.q_naming());
-        let q_complexity = compile_query(grammar, lang.q_complexity());
-        let q_banned = lang.q_banned().map(|q| compile_query(grammar, q));
+        let q_naming = compile_query(&grammar, lang.q_naming());
+        let q_complexity = compile_query(&grammar, lang.q_complexity());
+        let q_banned = lang.q_banned().map(|q| compile_query(&grammar, q));
 
         let mut violations = Vec::new();
         let ctx = CheckContext {
@@ -61,22 +126,47 @@ impl Analyzer {
             source: content,
             filename,
             config,
+            exempt,
         };
 
         checks::check_naming(&ctx, &q_naming, &mut violations);
+        checks::check_function_count(&ctx, &q_naming, &mut violations);
         checks::check_metrics(&ctx, &q_complexity, &mut violations);
 
         if let Some(banned) = q_banned {
-            checks::check_banned(&ctx, &banned, &mut violations);
+            paranoia::check_banned(&ctx, &banned, &mut violations);
+        }
+
+        if lang == Lang::Rust {
+            safety::check_unsafe(&ctx, &mut violations);
+        }
+
+        if let Some(severity) = config.require_docs.get(lang.config_key()) {
+            docs::check_docs(&ctx, lang, *severity, &mut violations);
+        }
+
+        if let Some(banned) = config.banned_calls.get(lang.config_key()) {
+            let q_calls = compile_query(&grammar, lang.q_calls());
+            paranoia::check_banned_calls(&ctx, &q_calls, banned, &mut violations);
         }
 
         violations
     }
 }
 
-fn compile_query(lang: Language, pattern: &str) -> Query {
+/// One file to analyze, or one script block of it.
+#[derive(Clone, Copy)]
+struct File<'a> {
+    name: &'a str,
+    content: &'a str,
+    exempt: &'a Exemptions,
+}
+
+fn compile_query(lang: &Language, pattern: &str) -> Query {
     match Query::new(lang, pattern) {
         Ok(q) => q,
+        // Built-in queries are constants; a bad one is a programming error.
+        // slopchop:ignore-next-line paranoia
         Err(e) => panic!("Invalid tree-sitter query pattern: {e}"),
     }
-}
\ No newline at end of file
+}
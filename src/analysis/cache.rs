@@ -0,0 +1,151 @@
+// src/analysis/cache.rs
+//! Incremental scan cache.
+//!
+//! Per-file results are stored by path with a hash of the file's content, so
+//! unchanged files skip analysis. Any change to `[rules]` (or to the slopchop
+//! version or token model) invalidates the whole cache. Cross-file findings
+//! (dead code, cycles) are never cached.
+
+use super::scopes::FileScopes;
+use crate::config::RuleConfig;
+use crate::tokens::Tokenizer;
+use crate::types::FileReport;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+pub const CACHE_DIR: &str = ".slopchop_cache";
+const CACHE_FILE: &str = "scan.json";
+/// Rule keys that never affect the cached per-file results: cross-file
+/// passes and the failure policy.
+const UNCACHED_KEYS: &[&str] = &["detect_dead_code", "detect_cycles", "fail"];
+
+/// Analysis results for one file, before cross-file passes.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedFile {
+    pub report: FileReport,
+    pub scopes: FileScopes,
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+struct Entry {
+    hash: u64,
+    #[serde(flatten)]
+    file: CachedFile,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct Stored {
+    config: u64,
+    files: HashMap<PathBuf, Entry>,
+}
+
+/// Per-file results shared by every scan of an engine (or a daemon).
+pub struct ScanCache {
+    dir: PathBuf,
+    config: u64,
+    files: Mutex<HashMap<PathBuf, Entry>>,
+}
+
+impl ScanCache {
+    /// Loads the cache in `dir`, discarding it if `rules` changed since it
+    /// was written. A missing or unreadable cache starts empty.
+    #[must_use]
+    pub fn load(dir: &Path, rules: &RuleConfig) -> Self {
+        let config = config_hash(rules);
+        let stored: Stored = fs::read_to_string(dir.join(CACHE_FILE))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default();
+        let files = if stored.config == config {
+            stored.files
+        } else {
+            HashMap::new()
+        };
+        Self {
+            dir: dir.to_path_buf(),
+            config,
+            files: Mutex::new(files),
+        }
+    }
+
+    /// True if this cache was built for `rules`.
+    #[must_use]
+    pub fn is_for(&self, rules: &RuleConfig) -> bool {
+        self.config == config_hash(rules)
+    }
+
+    /// Returns the cached result for `path` if its content is unchanged.
+    #[must_use]
+    pub fn get(&self, path: &Path, content: &str) -> Option<CachedFile> {
+        let files = self.files.lock().ok()?;
+        let entry = files.get(path)?;
+        (entry.hash == content_hash(content)).then(|| entry.file.clone())
+    }
+
+    /// Records a freshly analyzed file.
+    pub fn put(&self, path: &Path, content: &str, file: CachedFile) {
+        let entry = Entry {
+            hash: content_hash(content),
+            file,
+        };
+        if let Ok(mut files) = self.files.lock() {
+            files.insert(path.to_path_buf(), entry);
+        }
+    }
+
+    /// Writes the cache, dropping entries for files that no longer exist.
+    ///
+    /// # Errors
+    /// Returns error if the cache directory or file cannot be written.
+    pub fn save(&self) -> Result<()> {
+        // Held while writing, so scans sharing this cache (daemon clients)
+        // never interleave their writes.
+        let guard = self.files.lock();
+        let mut files = guard.as_deref().cloned().unwrap_or_default();
+        files.retain(|path, _| path.is_file());
+        let stored = Stored {
+            config: self.config,
+            files,
+        };
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.dir.join(".gitignore"), "*\n")?;
+        fs::write(self.dir.join(CACHE_FILE), serde_json::to_string(&stored)?)?;
+        drop(guard);
+        Ok(())
+    }
+}
+
+fn content_hash(content: &str) -> u64 {
+    fnv1a(&[content.as_bytes()])
+}
+
+/// `serde_json::Value` objects are sorted maps, so this is stable across runs.
+fn config_hash(rules: &RuleConfig) -> u64 {
+    let mut value = serde_json::to_value(rules).unwrap_or_default();
+    if let Some(map) = value.as_object_mut() {
+        map.retain(|k, _| !UNCACHED_KEYS.contains(&k.as_str()));
+    }
+    let json = value.to_string();
+    fnv1a(&[
+        env!("CARGO_PKG_VERSION").as_bytes(),
+        Tokenizer::model().name().as_bytes(),
+        json.as_bytes(),
+    ])
+}
+
+/// 64-bit FNV-1a over `parts`, with a separator between each part.
+pub(crate) fn fnv1a(parts: &[&[u8]]) -> u64 {
+    const PRIME: u64 = 0x0100_0000_01b3;
+    let mut hash: u64 = 0xcbf2_9ce4_8422_2325;
+    for part in parts {
+        for byte in part.iter().chain(&[0xff]) {
+            hash ^= u64::from(*byte);
+            hash = hash.wrapping_mul(PRIME);
+        }
+    }
+    hash
+}
@@ -1,19 +1,21 @@
 // src/analysis/checks.rs
-use super::metrics;
+use super::exempt::Exemptions;
+use super::{cognitive, fields, metrics};
 use crate::config::RuleConfig;
-use crate::types::Violation;
-use tree_sitter::{Node, Query, QueryCursor, QueryMatch, TreeCursor};
+use crate::types::{Severity, Violation};
+use tree_sitter::{Node, Query, QueryCursor, TreeCursor};
 
 pub struct CheckContext<'a> {
     pub root: Node<'a>,
     pub source: &'a str,
     pub filename: &'a str,
     pub config: &'a RuleConfig,
+    pub exempt: &'a Exemptions,
 }
 
 /// Checks for naming violations (function name word count).
 pub fn check_naming(ctx: &CheckContext, query: &Query, out: &mut Vec<Violation>) {
-    if is_ignored(ctx.filename, &ctx.config.ignore_naming_on) {
+    if ctx.exempt.naming(ctx.filename) {
         return;
     }
 
@@ -23,19 +25,41 @@ pub fn check_naming(ctx: &CheckContext, query: &Query, out: &mut Vec<Violation>)
         let name = node.utf8_text(ctx.source.as_bytes()).unwrap_or("?");
         let word_count = count_words(name);
 
-        if word_count > ctx.config.max_function_words {
+        if word_count > ctx.config.functions.max_function_words {
             out.push(Violation {
                 row: node.start_position().row,
                 message: format!(
                     "Function '{name}' has {word_count} words (Max: {}). Is it doing too much?",
-                    ctx.config.max_function_words
+                    ctx.config.functions.max_function_words
                 ),
                 law: "LAW OF BLUNTNESS",
+                severity: Severity::Error,
             });
         }
     }
 }
 
+/// Checks how many functions the file defines, counting the matches of
+/// the naming query.
+pub fn check_function_count(ctx: &CheckContext, query: &Query, out: &mut Vec<Violation>) {
+    if ctx.exempt.tokens(ctx.filename) {
+        return;
+    }
+    let mut cursor = QueryCursor::new();
+    let count = cursor
+        .matches(query, ctx.root, ctx.source.as_bytes())
+        .count();
+    let max = ctx.config.size.max_file_functions;
+    if count > max {
+        out.push(Violation {
+            row: 0,
+            message: format!("File defines {count} functions (Max: {max}). Split it up."),
+            law: "LAW OF ATOMICITY",
+            severity: Severity::Error,
+        });
+    }
+}
+
 fn count_words(name: &str) -> usize {
     if name.contains('_') {
         name.split('_').count()
@@ -49,28 +73,41 @@ fn count_words(name: &str) -> usize {
     }
 }
 
-fn is_ignored(filename: &str, patterns: &[String]) -> bool {
-    patterns.iter().any(|p| filename.contains(p))
-}
-
-/// Checks for complexity metrics (arity, depth, cyclomatic complexity).
+/// Checks for complexity metrics (arity, depth, cyclomatic complexity, field count).
 pub fn check_metrics(ctx: &CheckContext, complexity_query: &Query, out: &mut Vec<Violation>) {
     traverse_nodes(ctx, |node| {
         let kind = node.kind();
         if kind.contains("function") || kind.contains("method") {
-            validate_arity(node, ctx.config.max_function_args, out);
-            validate_depth(node, ctx.config.max_nesting_depth, out);
+            validate_arity(node, ctx.config.functions.max_function_args, out);
+            validate_depth(node, ctx.config.functions.max_nesting_depth, out);
             validate_complexity(
                 node,
                 ctx.source,
                 complexity_query,
-                ctx.config.max_cyclomatic_complexity,
+                ctx.config.functions.max_cyclomatic_complexity,
                 out,
             );
+            validate_cognitive(node, ctx.config.functions.max_cognitive_complexity, out);
+            validate_length(node, ctx, out);
+        } else if let Some((name, count)) = fields::count(node, ctx.source) {
+            validate_fields(node, name, count, ctx.config.max_struct_fields, out);
         }
     });
 }
 
+fn validate_fields(node: Node, name: &str, count: usize, max: usize, out: &mut Vec<Violation>) {
+    if count > max {
+        out.push(Violation {
+            row: node.start_position().row,
+            message: format!(
+                "Too Many Fields: '{name}' has {count} fields (Max: {max}). Split the type."
+            ),
+            law: "LAW OF COMPLEXITY",
+            severity: Severity::Error,
+        });
+    }
+}
+
 fn validate_arity(node: Node, max: usize, out: &mut Vec<Violation>) {
     let args = metrics::count_arguments(node);
     if args > max {
@@ -80,6 +117,7 @@ fn validate_arity(node: Node, max: usize, out: &mut Vec<Violation>) {
                 "High Arity: Function takes {args} arguments (Max: {max}). Use a Struct."
             ),
             law: "LAW OF COMPLEXITY",
+            severity: Severity::Error,
         });
     }
 }
@@ -91,6 +129,7 @@ fn validate_depth(node: Node, max: usize, out: &mut Vec<Violation>) {
             row: node.start_position().row,
             message: format!("Deep Nesting: Max depth is {depth} (Max: {max}). Extract logic."),
             law: "LAW OF COMPLEXITY",
+            severity: Severity::Error,
         });
     }
 }
@@ -108,48 +147,45 @@ fn validate_complexity(
             row: node.start_position().row,
             message: format!("High Complexity: Score is {score} (Max: {max}). Hard to test."),
             law: "LAW OF COMPLEXITY",
+            severity: Severity::Error,
         });
     }
 }
 
-/// Checks for banned constructs (`.unwrap()` and `.expect()` calls).
-pub fn check_banned(ctx: &CheckContext, banned_query: &Query, out: &mut Vec<Violation>) {
-    let mut cursor = QueryCursor::new();
-    let names = banned_query.capture_names();
-
-    for m in cursor.matches(banned_query, ctx.root, ctx.source.as_bytes()) {
-        process_banned_match(&m, names, ctx, out);
+fn validate_cognitive(node: Node, max: usize, out: &mut Vec<Violation>) {
+    let score = cognitive::calculate(node);
+    if score > max {
+        out.push(Violation {
+            row: node.start_position().row,
+            message: format!(
+                "High Cognitive Complexity: Score is {score} (Max: {max}). Flatten the logic."
+            ),
+            law: "LAW OF COMPLEXITY",
+            severity: Severity::Error,
+        });
     }
 }
 
-fn process_banned_match(
-    m: &QueryMatch,
-    names: &[String],
-    ctx: &CheckContext,
-    out: &mut Vec<Violation>,
-) {
-    let mut method_name: Option<&str> = None;
-    let mut row = 0;
-
-    for cap in m.captures {
-        let capture_name = &names[cap.index as usize];
-
-        if capture_name == "method" {
-            method_name = cap.node.utf8_text(ctx.source.as_bytes()).ok();
-        }
-        if capture_name == "call" {
-            row = cap.node.start_position().row;
-        }
-    }
-
-    if let Some(name) = method_name {
-        if name == "unwrap" || name == "expect" {
-            out.push(Violation {
-                row,
-                message: format!("Banned: '.{name}()'. Use '?' or 'unwrap_or'."),
-                law: "LAW OF PARANOIA",
-            });
-        }
+fn validate_length(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let max = ctx.config.functions.max_function_lines;
+    let start = node.start_position().row;
+    let end = node.end_position().row;
+    let lines = end - start + 1;
+    if lines > max {
+        let name = node
+            .child_by_field_name("name")
+            .and_then(|n| n.utf8_text(ctx.source.as_bytes()).ok())
+            .unwrap_or("<anonymous>");
+        out.push(Violation {
+            row: start,
+            message: format!(
+                "Long Function: '{name}' spans {lines} lines ({}-{}) (Max: {max}). Split it up.",
+                start + 1,
+                end + 1
+            ),
+            law: "LAW OF COMPLEXITY",
+            severity: Severity::Error,
+        });
     }
 }
 
@@ -176,4 +212,4 @@ fn advance_cursor(cursor: &mut TreeCursor) -> bool {
         }
     }
     true
-}
\ No newline at end of file
+}
@@ -0,0 +1,109 @@
+// src/analysis/cognitive.rs
+//! Cognitive complexity (Sonar-style).
+//!
+//! Unlike cyclomatic complexity, every control structure is penalized by how
+//! deeply it is nested, so a pyramid of `if`s scores far worse than a flat
+//! sequence of the same branches.
+
+use tree_sitter::Node;
+
+/// Structures that break linear flow and increase nesting.
+const STRUCTURES: &[&str] = &[
+    "if_expression",
+    "if_statement",
+    "match_expression",
+    "match_statement",
+    "switch_statement",
+    "case_statement",
+    "for_expression",
+    "for_statement",
+    "for_in_statement",
+    "c_style_for_statement",
+    "while_expression",
+    "while_statement",
+    "loop_expression",
+    "do_statement",
+    "catch_clause",
+    "except_clause",
+    "ternary_expression",
+    "conditional_expression",
+];
+
+/// Constructs that only increase nesting (nested functions and lambdas).
+const NESTERS: &[&str] = &[
+    "closure_expression",
+    "arrow_function",
+    "function_expression",
+    "lambda",
+];
+
+const ELSE_CLAUSES: &[&str] = &["else_clause", "elif_clause"];
+const LOGICAL_NODES: &[&str] = &["binary_expression", "boolean_operator", "list"];
+const LOGICAL_OPS: &[&str] = &["&&", "||", "??", "and", "or"];
+
+/// Calculates the cognitive complexity of a function node.
+#[must_use]
+pub fn calculate(node: Node) -> usize {
+    score_children(node, 0)
+}
+
+fn score_children(node: Node, nesting: usize) -> usize {
+    let mut cursor = node.walk();
+    let total = node
+        .children(&mut cursor)
+        .map(|c| score_node(c, nesting))
+        .sum();
+    total
+}
+
+fn score_node(node: Node, nesting: usize) -> usize {
+    let kind = node.kind();
+    if STRUCTURES.contains(&kind) {
+        return 1 + nesting + score_children(node, nesting + 1);
+    }
+    if ELSE_CLAUSES.contains(&kind) {
+        return 1 + score_else(node, nesting);
+    }
+    if NESTERS.contains(&kind) {
+        return score_children(node, nesting + 1);
+    }
+    logical_increment(node) + score_children(node, nesting)
+}
+
+/// `else` and `else if` cost a flat increment; the chained `if` is not
+/// penalized for nesting.
+fn score_else(node: Node, nesting: usize) -> usize {
+    let mut cursor = node.walk();
+    let total = node
+        .children(&mut cursor)
+        .map(|c| {
+            if c.kind().starts_with("if_") {
+                score_children(c, nesting)
+            } else {
+                score_node(c, nesting)
+            }
+        })
+        .sum();
+    total
+}
+
+/// A sequence of like boolean operators costs one increment.
+fn logical_increment(node: Node) -> usize {
+    let Some(op) = logical_operator(node) else {
+        return 0;
+    };
+    let continues = node.parent().and_then(logical_operator) == Some(op);
+    usize::from(!continues)
+}
+
+fn logical_operator(node: Node) -> Option<&'static str> {
+    if !LOGICAL_NODES.contains(&node.kind()) {
+        return None;
+    }
+    let mut cursor = node.walk();
+    let op = node
+        .children(&mut cursor)
+        .map(|c| c.kind())
+        .find(|k| LOGICAL_OPS.contains(k));
+    op
+}
@@ -0,0 +1,50 @@
+// src/analysis/cross.rs
+//! Passes that need every scanned file at once (dead code, import cycles).
+
+use super::source::Source;
+use super::{cycles, oversize, xref};
+use crate::config::RuleConfig;
+use crate::types::{FileReport, Violation};
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+/// What the cross-file passes found, by file.
+pub type Findings = HashMap<PathBuf, Vec<Violation>>;
+
+/// Runs the enabled cross-file passes and attaches their findings.
+pub fn run(rules: &RuleConfig, files: &[PathBuf], source: &dyn Source, results: &mut [FileReport]) {
+    attach(&find(rules, files, source), results);
+}
+
+/// Runs the enabled cross-file passes over `files`. Files over
+/// `max_file_bytes` are left out, since these passes read every file.
+#[must_use]
+pub fn find(rules: &RuleConfig, files: &[PathBuf], source: &dyn Source) -> Findings {
+    let mut found = Findings::new();
+    if !rules.cross_file.detect_dead_code && !rules.cross_file.detect_cycles {
+        return found;
+    }
+    let files: Vec<PathBuf> = files
+        .iter()
+        .filter(|p| oversize::check(source, p, rules.size.max_file_bytes).is_none())
+        .cloned()
+        .collect();
+    if rules.cross_file.detect_dead_code {
+        found = xref::find_dead_code(&files, source);
+    }
+    if rules.cross_file.detect_cycles {
+        for (path, mut extra) in cycles::find_cycles(&files, source) {
+            found.entry(path).or_default().append(&mut extra);
+        }
+    }
+    found
+}
+
+/// Adds the findings for each of `results` to its report.
+pub fn attach(found: &Findings, results: &mut [FileReport]) {
+    for report in results {
+        if let Some(extra) = found.get(&report.path) {
+            report.violations.extend(extra.iter().cloned());
+        }
+    }
+}
@@ -0,0 +1,84 @@
+// src/analysis/custom.rs
+//! User-defined tree-sitter query rules (`[[rules.custom]]`, `slopchop_rules/`).
+
+use crate::config::CustomRule;
+use crate::lang::Lang;
+use crate::types::{intern_law, Severity, Violation};
+use tree_sitter::{Parser, Query, QueryCursor};
+
+/// A custom rule whose query has been compiled against its grammar.
+pub struct CompiledRule {
+    lang: Lang,
+    query: Query,
+    /// Interned at compile time so violations can carry it as their law.
+    name: &'static str,
+    message: String,
+    severity: Severity,
+}
+
+/// Compiles every custom rule, warning about and skipping invalid ones.
+#[must_use]
+pub fn compile(rules: &[CustomRule]) -> Vec<CompiledRule> {
+    rules
+        .iter()
+        .filter_map(|rule| match compile_rule(rule) {
+            Ok(compiled) => Some(compiled),
+            Err(e) => {
+                eprintln!("WARN: Skipping custom rule '{}': {e}", rule.name);
+                None
+            }
+        })
+        .collect()
+}
+
+fn compile_rule(rule: &CustomRule) -> Result<CompiledRule, String> {
+    let lang = Lang::from_ext(&rule.lang)
+        .ok_or_else(|| format!("unsupported language '{}'", rule.lang))?;
+    let query = Query::new(&lang.grammar(), &rule.query).map_err(|e| e.to_string())?;
+    Ok(CompiledRule {
+        lang,
+        query,
+        name: intern_law(&rule.name),
+        message: rule.message.clone(),
+        severity: rule.severity,
+    })
+}
+
+/// Runs the compiled rules that target `lang` against `source`.
+pub fn check(rules: &[CompiledRule], lang: Lang, source: &str, out: &mut Vec<Violation>) {
+    let active: Vec<&CompiledRule> = rules.iter().filter(|r| r.lang == lang).collect();
+    if active.is_empty() {
+        return;
+    }
+    let mut parser = Parser::new();
+    if parser.set_language(&lang.grammar()).is_err() {
+        return;
+    }
+    let Some(tree) = parser.parse(source, None) else {
+        return;
+    };
+    for rule in active {
+        run_rule(rule, tree.root_node(), source, out);
+    }
+}
+
+/// Each match is reported at its `@match` capture, or its first capture.
+fn run_rule(rule: &CompiledRule, root: tree_sitter::Node, source: &str, out: &mut Vec<Violation>) {
+    let match_idx = rule.query.capture_index_for_name("match");
+    let mut cursor = QueryCursor::new();
+    for m in cursor.matches(&rule.query, root, source.as_bytes()) {
+        let target = m
+            .captures
+            .iter()
+            .find(|c| Some(c.index) == match_idx)
+            .or_else(|| m.captures.first());
+        if let Some(cap) = target {
+            out.push(Violation {
+                row: cap.node.start_position().row,
+                message: rule.message.clone(),
+                law: rule.name,
+                severity: rule.severity,
+            });
+        }
+    }
+}
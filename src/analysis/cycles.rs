@@ -0,0 +1,172 @@
+// src/analysis/cycles.rs
+//! Cross-file pass that reports circular imports.
+//!
+//! Builds a file-level import graph (Rust `use`, TS/JS imports, Python
+//! imports) over the scan set and flags every strongly connected component
+//! with more than one file. Rust `mod` declarations are not edges: a parent
+//! declaring a child is structure, not a dependency.
+
+use super::modpath::RustModules;
+use super::source::Source;
+use crate::graph::{imports, resolver};
+use crate::types::{Severity, Violation};
+use std::collections::{HashMap, HashSet};
+use std::path::{Component, Path, PathBuf};
+
+/// Finds import cycles in `files`, keyed by each file in a cycle.
+#[must_use]
+pub fn find_cycles(files: &[PathBuf], source: &dyn Source) -> HashMap<PathBuf, Vec<Violation>> {
+    let graph = build_graph(files, source);
+    let mut found = HashMap::new();
+    for component in strongly_connected(&graph) {
+        if component.len() < 2 {
+            continue;
+        }
+        for &idx in &component {
+            let others: Vec<String> = component
+                .iter()
+                .filter(|&&o| o != idx)
+                .map(|&o| graph.files[o].display().to_string())
+                .collect();
+            let violation = Violation {
+                row: 0,
+                message: format!(
+                    "Import Cycle: depends on and is used by {}",
+                    others.join(", ")
+                ),
+                law: "LAW OF CYCLES",
+                severity: Severity::Error,
+            };
+            found.insert(graph.files[idx].clone(), vec![violation]);
+        }
+    }
+    found
+}
+
+/// Import edges between files, by index into `files`.
+struct Graph {
+    files: Vec<PathBuf>,
+    edges: Vec<Vec<usize>>,
+}
+
+fn build_graph(files: &[PathBuf], source: &dyn Source) -> Graph {
+    let normalized: Vec<PathBuf> = files.iter().map(|f| normalize(f)).collect();
+    let index: HashMap<&Path, usize> = normalized
+        .iter()
+        .enumerate()
+        .map(|(i, f)| (f.as_path(), i))
+        .collect();
+    let modules = RustModules::new(&normalized);
+
+    let edges = normalized
+        .iter()
+        .zip(files)
+        .enumerate()
+        .map(|(i, (file, original))| {
+            let Some(content) = source.read(original) else {
+                return Vec::new();
+            };
+            let targets: HashSet<usize> = resolve_imports(file, &content, &modules)
+                .iter()
+                .filter_map(|target| index.get(normalize(target).as_path()).copied())
+                .filter(|&t| t != i)
+                .collect();
+            targets.into_iter().collect()
+        })
+        .collect();
+
+    Graph {
+        files: files.to_vec(),
+        edges,
+    }
+}
+
+fn resolve_imports(file: &Path, content: &str, modules: &RustModules) -> Vec<PathBuf> {
+    if file.extension().is_some_and(|e| e == "rs") {
+        return modules.imports(file, content);
+    }
+    imports::extract(file, content)
+        .iter()
+        .filter_map(|imp| resolver::resolve(Path::new("."), file, imp))
+        .collect()
+}
+
+/// Tarjan's algorithm.
+fn strongly_connected(graph: &Graph) -> Vec<Vec<usize>> {
+    let mut state = Tarjan {
+        index: vec![None; graph.files.len()],
+        low: vec![0; graph.files.len()],
+        on_stack: vec![false; graph.files.len()],
+        stack: Vec::new(),
+        counter: 0,
+        out: Vec::new(),
+    };
+    for v in 0..graph.files.len() {
+        if state.index[v].is_none() {
+            state.visit(v, &graph.edges);
+        }
+    }
+    state.out
+}
+
+struct Tarjan {
+    index: Vec<Option<usize>>,
+    low: Vec<usize>,
+    on_stack: Vec<bool>,
+    stack: Vec<usize>,
+    counter: usize,
+    out: Vec<Vec<usize>>,
+}
+
+impl Tarjan {
+    fn visit(&mut self, v: usize, edges: &[Vec<usize>]) {
+        self.index[v] = Some(self.counter);
+        self.low[v] = self.counter;
+        self.counter += 1;
+        self.stack.push(v);
+        self.on_stack[v] = true;
+
+        for &w in &edges[v] {
+            match self.index[w] {
+                None => {
+                    self.visit(w, edges);
+                    self.low[v] = self.low[v].min(self.low[w]);
+                }
+                Some(iw) if self.on_stack[w] => self.low[v] = self.low[v].min(iw),
+                Some(_) => {}
+            }
+        }
+
+        if Some(self.low[v]) == self.index[v] {
+            self.pop_component(v);
+        }
+    }
+
+    fn pop_component(&mut self, root: usize) {
+        let mut component = Vec::new();
+        while let Some(w) = self.stack.pop() {
+            self.on_stack[w] = false;
+            component.push(w);
+            if w == root {
+                break;
+            }
+        }
+        component.sort_unstable();
+        self.out.push(component);
+    }
+}
+
+/// Lexically resolves `.` and `..` so paths from different sources compare equal.
+fn normalize(path: &Path) -> PathBuf {
+    let mut out = PathBuf::new();
+    for c in path.components() {
+        match c {
+            Component::CurDir => {}
+            Component::ParentDir => {
+                out.pop();
+            }
+            other => out.push(other),
+        }
+    }
+    out
+}
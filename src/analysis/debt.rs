@@ -0,0 +1,60 @@
+// src/analysis/debt.rs
+//! Debt marker (TODO/FIXME/HACK) collection.
+use crate::config::DebtConfig;
+use crate::types::{DebtMarker, Severity, Violation};
+use regex::Regex;
+
+const COMMENT_OPENERS: &[&str] = &["//", "#", "/*", "<!--", "--"];
+
+/// Builds the marker matcher, or `None` if tracking is off.
+#[must_use]
+pub fn compile(config: &DebtConfig) -> Option<Regex> {
+    if !config.enabled || config.markers.is_empty() {
+        return None;
+    }
+    let alts: Vec<String> = config.markers.iter().map(|m| regex::escape(m)).collect();
+    Regex::new(&format!(r"\b({})\b[:(]?\s*(.*)", alts.join("|"))).ok()
+}
+
+/// Finds every marker that sits inside a comment.
+#[must_use]
+pub fn collect(re: &Regex, content: &str) -> Vec<DebtMarker> {
+    content
+        .lines()
+        .enumerate()
+        .filter_map(|(row, line)| {
+            let caps = re.captures(line)?;
+            let start = caps.get(0)?.start();
+            if !in_comment(&line[..start]) {
+                return None;
+            }
+            Some(DebtMarker {
+                row,
+                marker: caps[1].to_string(),
+                text: caps[2].trim().trim_end_matches("*/").trim().to_string(),
+            })
+        })
+        .collect()
+}
+
+/// Flags the first marker past `max_per_file`.
+pub fn check_limit(found: &[DebtMarker], config: &DebtConfig, out: &mut Vec<Violation>) {
+    let Some(max) = config.max_per_file else {
+        return;
+    };
+    if let Some(over) = found.get(max) {
+        out.push(Violation {
+            row: over.row,
+            message: format!(
+                "Debt Markers: {} found (Max: {max}). Pay some of it down.",
+                found.len()
+            ),
+            law: "LAW OF DEBT",
+            severity: Severity::Error,
+        });
+    }
+}
+
+fn in_comment(prefix: &str) -> bool {
+    prefix.trim_start().starts_with('*') || COMMENT_OPENERS.iter().any(|c| prefix.contains(c))
+}
@@ -0,0 +1,138 @@
+// src/analysis/docs.rs
+//! Doc-comment coverage for public items (`[rules.require_docs]`).
+use super::checks::CheckContext;
+use crate::lang::Lang;
+use crate::types::{Severity, Violation};
+use tree_sitter::Node;
+
+const RUST_ITEMS: &[&str] = &[
+    "function_item",
+    "struct_item",
+    "enum_item",
+    "trait_item",
+    "type_item",
+    "const_item",
+    "static_item",
+];
+
+/// Reports public functions and types without documentation.
+pub fn check_docs(ctx: &CheckContext, lang: Lang, severity: Severity, out: &mut Vec<Violation>) {
+    let mut missing = Vec::new();
+    match lang {
+        Lang::Rust => rust_missing(ctx.root, ctx.source, &mut missing),
+        Lang::Python => python_missing(ctx.root, ctx.source, &mut missing),
+        Lang::TypeScript => ts_missing(ctx.root, ctx.source, &mut missing),
+        Lang::Bash => {}
+        #[cfg(feature = "zig")]
+        Lang::Zig => {}
+    }
+    out.extend(missing.into_iter().map(|(row, name)| Violation {
+        row,
+        message: format!("Missing Docs: public item '{name}' has no doc comment."),
+        law: "LAW OF DOCS",
+        severity,
+    }));
+}
+
+fn rust_missing(node: Node, source: &str, out: &mut Vec<(usize, String)>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let is_pub_item = RUST_ITEMS.contains(&child.kind()) && is_pub(child, source);
+        if is_pub_item && !has_rust_doc(child, source) {
+            out.push((child.start_position().row, name_of(child, source)));
+        }
+        if !matches!(child.kind(), "function_item" | "block") {
+            rust_missing(child, source, out);
+        }
+    }
+}
+
+fn is_pub(item: Node, source: &str) -> bool {
+    let mut cursor = item.walk();
+    let vis = item
+        .named_children(&mut cursor)
+        .find(|c| c.kind() == "visibility_modifier");
+    vis.is_some_and(|v| text(v, source) == "pub")
+}
+
+fn has_rust_doc(item: Node, source: &str) -> bool {
+    let mut prev = item.prev_named_sibling();
+    while let Some(p) = prev {
+        let t = text(p, source);
+        match p.kind() {
+            "line_comment" if t.starts_with("///") => return true,
+            "block_comment" if t.starts_with("/**") => return true,
+            "attribute_item" if t.contains("doc") => return true,
+            "attribute_item" => {}
+            _ => return false,
+        }
+        prev = p.prev_named_sibling();
+    }
+    false
+}
+
+/// Module-level and class-level definitions not starting with `_`.
+fn python_missing(node: Node, source: &str, out: &mut Vec<(usize, String)>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let def = if child.kind() == "decorated_definition" {
+            child.child_by_field_name("definition")
+        } else {
+            Some(child)
+        };
+        let Some(def) = def.filter(|d| d.kind().ends_with("_definition")) else {
+            continue;
+        };
+        let name = name_of(def, source);
+        if !name.starts_with('_') && !has_docstring(def) {
+            out.push((def.start_position().row, name));
+        }
+        if def.kind() == "class_definition" {
+            if let Some(body) = def.child_by_field_name("body") {
+                python_missing(body, source, out);
+            }
+        }
+    }
+}
+
+fn has_docstring(def: Node) -> bool {
+    def.child_by_field_name("body")
+        .and_then(|b| b.named_child(0))
+        .filter(|s| s.kind() == "expression_statement")
+        .and_then(|s| s.named_child(0))
+        .is_some_and(|s| s.kind() == "string")
+}
+
+/// Exported declarations need a `JSDoc` (`/** ... */`) comment right above.
+fn ts_missing(node: Node, source: &str, out: &mut Vec<(usize, String)>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let Some(decl) = exported_declaration(child) else {
+            continue;
+        };
+        let documented = child.prev_named_sibling().is_some_and(|p| {
+            p.kind() == "comment"
+                && text(p, source).starts_with("/**")
+                && p.end_position().row + 1 >= child.start_position().row
+        });
+        if !documented {
+            out.push((child.start_position().row, name_of(decl, source)));
+        }
+    }
+}
+
+fn exported_declaration(node: Node) -> Option<Node> {
+    if node.kind() != "export_statement" {
+        return None;
+    }
+    node.child_by_field_name("declaration")
+}
+
+fn name_of(node: Node, source: &str) -> String {
+    node.child_by_field_name("name")
+        .map_or_else(|| node.kind().to_string(), |n| text(n, source).to_string())
+}
+
+fn text<'a>(node: Node, source: &'a str) -> &'a str {
+    node.utf8_text(source.as_bytes()).unwrap_or("")
+}
@@ -0,0 +1,43 @@
+// src/analysis/exempt.rs
+//! The `ignore_*_on` path lists, compiled once per engine rather than for
+//! every file or node they are checked against.
+
+use crate::config::RuleConfig;
+use crate::ignore::IgnoreSet;
+
+/// Paths exempt from the naming, token, and panic rules.
+#[derive(Debug, Clone, Default)]
+pub struct Exemptions {
+    naming: IgnoreSet,
+    tokens: IgnoreSet,
+    panics: IgnoreSet,
+}
+
+impl Exemptions {
+    #[must_use]
+    pub fn new(rules: &RuleConfig) -> Self {
+        Self {
+            naming: IgnoreSet::from_patterns(&rules.exempt.ignore_naming_on),
+            tokens: IgnoreSet::from_patterns(&rules.exempt.ignore_tokens_on),
+            panics: IgnoreSet::from_patterns(&rules.exempt.ignore_panics_on),
+        }
+    }
+
+    /// `ignore_naming_on`.
+    #[must_use]
+    pub fn naming(&self, filename: &str) -> bool {
+        self.naming.is_ignored(filename)
+    }
+
+    /// `ignore_tokens_on`.
+    #[must_use]
+    pub fn tokens(&self, filename: &str) -> bool {
+        self.tokens.is_ignored(filename)
+    }
+
+    /// `ignore_panics_on`.
+    #[must_use]
+    pub fn panics(&self, filename: &str) -> bool {
+        self.panics.is_ignored(filename)
+    }
+}
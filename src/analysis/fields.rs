@@ -0,0 +1,90 @@
+// src/analysis/fields.rs
+//! Field counting for structs, classes, and interfaces.
+use std::collections::HashSet;
+use tree_sitter::Node;
+
+/// Returns `(name, field_count)` if `node` declares a struct-like type.
+#[must_use]
+pub fn count<'a>(node: Node, source: &'a str) -> Option<(&'a str, usize)> {
+    let fields = match node.kind() {
+        "struct_item" => count_children(node, &["field_declaration"]),
+        "interface_declaration" => count_children(node, &["property_signature"]),
+        "class_declaration" | "abstract_class_declaration" => {
+            count_children(node, &["public_field_definition"])
+        }
+        "class_definition" => count_python(node, source),
+        _ => return None,
+    };
+    let name = node
+        .child_by_field_name("name")
+        .and_then(|n| n.utf8_text(source.as_bytes()).ok())
+        .unwrap_or("<anonymous>");
+    Some((name, fields))
+}
+
+fn count_children(node: Node, kinds: &[&str]) -> usize {
+    let Some(body) = node.child_by_field_name("body") else {
+        return 0;
+    };
+    let mut cursor = body.walk();
+    body.named_children(&mut cursor)
+        .filter(|c| kinds.contains(&c.kind()))
+        .count()
+}
+
+/// Python has no declarations: count class-level assignments plus the
+/// distinct `self.x = ...` targets in `__init__`.
+fn count_python(node: Node, source: &str) -> usize {
+    let Some(body) = node.child_by_field_name("body") else {
+        return 0;
+    };
+    let mut names = HashSet::new();
+    let mut cursor = body.walk();
+    for stmt in body.named_children(&mut cursor) {
+        if let Some(target) = assignment_target(stmt) {
+            names.insert(text(target, source));
+        } else if is_init(stmt, source) {
+            collect_self_attrs(stmt, source, &mut names);
+        }
+    }
+    names.len()
+}
+
+fn assignment_target(stmt: Node) -> Option<Node> {
+    let expr = (stmt.kind() == "expression_statement").then(|| stmt.named_child(0))??;
+    (expr.kind() == "assignment").then(|| expr.child_by_field_name("left"))?
+}
+
+fn is_init(stmt: Node, source: &str) -> bool {
+    let func = if stmt.kind() == "decorated_definition" {
+        stmt.child_by_field_name("definition")
+    } else {
+        Some(stmt)
+    };
+    func.filter(|f| f.kind() == "function_definition")
+        .and_then(|f| f.child_by_field_name("name"))
+        .is_some_and(|n| text(n, source) == "__init__")
+}
+
+fn collect_self_attrs<'a>(node: Node, source: &'a str, names: &mut HashSet<&'a str>) {
+    if node.kind() == "assignment" {
+        let attr = node
+            .child_by_field_name("left")
+            .filter(|l| l.kind() == "attribute")
+            .filter(|l| {
+                l.child_by_field_name("object")
+                    .is_some_and(|o| text(o, source) == "self")
+            });
+        if let Some(name) = attr.and_then(|l| l.child_by_field_name("attribute")) {
+            names.insert(text(name, source));
+        }
+    }
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        collect_self_attrs(child, source, names);
+    }
+}
+
+fn text<'a>(node: Node, source: &'a str) -> &'a str {
+    node.utf8_text(source.as_bytes()).unwrap_or("")
+}
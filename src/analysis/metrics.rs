@@ -1,4 +1,5 @@
 // src/analysis/metrics.rs
+use std::collections::HashSet;
 use tree_sitter::{Node, Query, QueryCursor};
 
 /// Calculates the nesting depth of a node.
@@ -8,7 +9,8 @@ pub fn calculate_max_depth(node: Node) -> usize {
     let mut cursor = node.walk();
 
     for child in node.children(&mut cursor) {
-        if child.kind().contains("block") || child.kind().contains("body") {
+        let kind = child.kind();
+        if kind.contains("block") || kind.contains("body") || kind == "compound_statement" {
             max_depth = std::cmp::max(max_depth, walk_depth(child, 0));
         }
     }
@@ -34,6 +36,8 @@ fn walk_depth(node: Node, current: usize) -> usize {
                 | "while_statement"
                 | "do_statement"
                 | "switch_case"
+                | "case_statement" // Shell
+                | "c_style_for_statement" // Shell
                 | "catch_clause"
                 | "try_statement"
                 | "closure_expression" // Rust closures
@@ -70,4 +74,73 @@ pub fn count_arguments(node: Node) -> usize {
         }
     }
     0
-}
\ No newline at end of file
+}
+
+/// Halstead operator/operand counts.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Halstead {
+    pub distinct_operators: usize,
+    pub distinct_operands: usize,
+    pub total_operators: usize,
+    pub total_operands: usize,
+}
+
+impl Halstead {
+    /// Halstead volume: `N * log2(n)`.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn volume(&self) -> f64 {
+        let length = (self.total_operators + self.total_operands) as f64;
+        let vocabulary = (self.distinct_operators + self.distinct_operands) as f64;
+        if vocabulary < 2.0 {
+            return 0.0;
+        }
+        length * vocabulary.log2()
+    }
+}
+
+/// Counts Halstead tokens over the leaves of `node`. Named leaves are
+/// operands; anonymous ones (keywords, punctuation) are operators. Closing
+/// delimiters are skipped so each pair counts once.
+#[must_use]
+pub fn calculate_halstead(node: Node, source: &str) -> Halstead {
+    let mut operators = HashSet::new();
+    let mut operands = HashSet::new();
+    let mut h = Halstead::default();
+    let mut stack = vec![node];
+
+    while let Some(n) = stack.pop() {
+        if n.child_count() > 0 {
+            let mut cursor = n.walk();
+            stack.extend(n.children(&mut cursor));
+            continue;
+        }
+        let kind = n.kind();
+        if kind.contains("comment") || matches!(kind, ")" | "]" | "}") {
+            continue;
+        }
+        if n.is_named() {
+            h.total_operands += 1;
+            operands.insert(n.utf8_text(source.as_bytes()).unwrap_or(kind));
+        } else {
+            h.total_operators += 1;
+            operators.insert(kind);
+        }
+    }
+
+    h.distinct_operators = operators.len();
+    h.distinct_operands = operands.len();
+    h
+}
+
+/// Maintainability index scaled to 0-100 (higher is better), from Halstead
+/// volume, cyclomatic complexity, and non-blank lines of code.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn maintainability_index(volume: f64, complexity: usize, loc: usize) -> f64 {
+    let raw = 171.0
+        - 5.2 * volume.max(1.0).ln()
+        - 0.23 * complexity as f64
+        - 16.2 * (loc.max(1) as f64).ln();
+    (raw * 100.0 / 171.0).clamp(0.0, 100.0)
+}
@@ -1,39 +1,151 @@
 // src/analysis/mod.rs
 pub mod ast;
+pub mod cache;
 pub mod checks;
+pub mod cognitive;
+pub mod cross;
+pub mod custom;
+pub mod cycles;
+pub mod debt;
+pub mod docs;
+pub mod exempt;
+pub mod fields;
 pub mod metrics;
+pub mod modpath;
+pub mod oversize;
+pub mod paranoia;
+pub mod progress;
+pub mod safety;
+pub mod scopes;
+pub mod sfc;
+pub mod source;
+pub mod suppress;
+pub mod test_code;
+pub mod xref;
 
 use crate::config::Config;
+use crate::lang::Lang;
 use crate::tokens::Tokenizer;
-use crate::types::{FileReport, ScanReport, Violation};
+use crate::types::{FileReport, ScanReport, Severity, Violation};
 use ast::Analyzer;
+use cache::{CachedFile, ScanCache};
+use exempt::Exemptions;
+use progress::{ScanProgress, Tracker};
 use rayon::prelude::*;
-use std::fs;
+use regex::Regex;
+use scopes::FileScopes;
+use source::{Disk, Memory, Source};
+use std::collections::BTreeMap;
 use std::path::{Path, PathBuf};
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 use std::time::Instant;
 
 static ANALYZER: LazyLock<Analyzer> = LazyLock::new(Analyzer::new);
 
 pub struct RuleEngine {
     config: Config,
+    custom: Vec<custom::CompiledRule>,
+    debt: Option<Regex>,
+    exempt: Exemptions,
+    cache: Option<Arc<ScanCache>>,
+    cross: Option<Arc<cross::Findings>>,
 }
 
 impl RuleEngine {
     #[must_use]
     pub fn new(config: Config) -> Self {
-        Self { config }
+        let custom = custom::compile(&config.rules.custom);
+        let debt = debt::compile(&config.rules.debt);
+        let exempt = Exemptions::new(&config.rules);
+        Self {
+            config,
+            custom,
+            debt,
+            exempt,
+            cache: None,
+            cross: None,
+        }
+    }
+
+    /// Reuses per-file results from the scan cache in `dir` for files whose
+    /// content has not changed, and updates it after each scan.
+    #[must_use]
+    pub fn with_cache(self, dir: impl AsRef<Path>) -> Self {
+        let cache = ScanCache::load(dir.as_ref(), &self.config.rules);
+        self.with_shared_cache(Arc::new(cache))
+    }
+
+    /// Like [`Self::with_cache`], for a cache kept alive across engines.
+    #[must_use]
+    pub fn with_shared_cache(mut self, cache: Arc<ScanCache>) -> Self {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Attaches `found`, from cross-file passes run over a wider set of
+    /// files (a whole workspace), instead of running them on each scan.
+    #[must_use]
+    pub fn with_cross_findings(mut self, found: Arc<cross::Findings>) -> Self {
+        self.cross = Some(found);
+        self
     }
 
     /// Scans a list of files and returns a structured report.
     #[must_use]
     pub fn scan(&self, files: Vec<PathBuf>) -> ScanReport {
+        self.scan_with_progress(files, |_| {})
+    }
+
+    /// Like [`Self::scan`], calling `progress` from the worker threads as
+    /// each file finishes. Takes `files` by value, as [`Self::scan`] does.
+    #[must_use]
+    #[allow(clippy::needless_pass_by_value)]
+    pub fn scan_with_progress(
+        &self,
+        files: Vec<PathBuf>,
+        progress: impl Fn(ScanProgress) + Sync,
+    ) -> ScanReport {
+        self.run(&files, &Disk, &progress)
+    }
+
+    /// Scans in-memory contents (e.g. unsaved editor buffers) without
+    /// reading or writing anything on disk, the scan cache included.
+    #[must_use]
+    pub fn scan_sources(&self, sources: Vec<(PathBuf, String)>) -> ScanReport {
+        let files: Vec<PathBuf> = sources.iter().map(|(p, _)| p.clone()).collect();
+        let memory = Memory(sources.into_iter().collect());
+        self.run(&files, &memory, &|_| {})
+    }
+
+    fn run(
+        &self,
+        files: &[PathBuf],
+        source: &dyn Source,
+        progress: &(dyn Fn(ScanProgress) + Sync),
+    ) -> ScanReport {
         let start = Instant::now();
+        let tracker = Tracker::new(files.len());
 
-        let results: Vec<FileReport> = files
-            .into_par_iter()
-            .filter_map(|path| self.analyze_file(&path))
-            .collect();
+        let (mut results, scopes): (Vec<FileReport>, Vec<FileScopes>) = files
+            .par_iter()
+            .filter_map(|path| {
+                let analyzed = self.analyze_file(path, source);
+                progress(tracker.tick(path));
+                analyzed
+            })
+            .map(|f| (f.report, f.scopes))
+            .unzip();
+        if let Some(Err(e)) = self.cache_for(source).map(ScanCache::save) {
+            eprintln!("WARN: Could not write scan cache: {e}");
+        }
+
+        match &self.cross {
+            Some(found) => cross::attach(found, &mut results),
+            None => cross::run(&self.config.rules, files, source, &mut results),
+        }
+        scopes::apply_suppressions(&mut results, &scopes);
+        scopes::apply_severities(&mut results, &self.config.rules.severity);
+        scopes::apply_test_profile(&mut results, &scopes, &self.config.rules.tests);
 
         let total_tokens = results.iter().map(|f| f.token_count).sum();
         let total_violations = results.iter().map(|f| f.violations.len()).sum();
@@ -46,53 +158,87 @@ impl RuleEngine {
         }
     }
 
-    fn analyze_file(&self, path: &Path) -> Option<FileReport> {
-        let content = fs::read_to_string(path).ok()?;
+    fn cache_for(&self, source: &dyn Source) -> Option<&ScanCache> {
+        self.cache.as_deref().filter(|_| source.cacheable())
+    }
+
+    fn analyze_file(&self, path: &Path, source: &dyn Source) -> Option<CachedFile> {
+        let limit = self.config.rules.size.max_file_bytes;
+        if let Some(size) = oversize::check(source, path, limit) {
+            return Some(oversize::skipped(path, size, limit));
+        }
+        let content = source.read(path)?;
+        let Some(cache) = self.cache_for(source) else {
+            return self.analyze_content(path, &content);
+        };
+        if let Some(hit) = cache.get(path, &content) {
+            return Some(hit);
+        }
+        let fresh = self.analyze_content(path, &content)?;
+        cache.put(path, &content, fresh.clone());
+        Some(fresh)
+    }
 
+    fn analyze_content(&self, path: &Path, content: &str) -> Option<CachedFile> {
         // Support C-style, Hash-style, and HTML-style (Markdown) ignores
-        if content.contains("// slopchop:ignore")
-            || content.contains("# slopchop:ignore")
-            || content.contains("<!-- slopchop:ignore -->")
-        {
+        if suppress::is_file_ignored(content) {
             return None;
         }
 
         let filename = path.to_string_lossy();
-        let token_count = Tokenizer::count(&content);
+        let token_count = Tokenizer::count(content);
         let mut violations = Vec::new();
 
         // 1. Law of Atomicity (checked unless exempted)
-        if !self.is_exempt_from_tokens(&filename) && token_count > self.config.rules.max_file_tokens
-        {
+        if !self.exempt.tokens(&filename) && token_count > self.config.rules.size.max_file_tokens {
             violations.push(Violation {
                 row: 0,
                 message: format!(
                     "File size is {token_count} tokens (Limit: {})",
-                    self.config.rules.max_file_tokens
+                    self.config.rules.size.max_file_tokens
                 ),
                 law: "LAW OF ATOMICITY",
+                severity: Severity::Error,
             });
         }
 
-        // 2. AST Analysis (complexity, nesting, arity, banned calls)
-        if let Some(ext) = path.extension().and_then(|s| s.to_str()) {
-            let mut ast_violations = ANALYZER.analyze(ext, &filename, &content, &self.config.rules);
-            violations.append(&mut ast_violations);
+        // 2. AST Analysis (complexity, nesting, arity, banned calls, custom rules)
+        let ext = path.extension().and_then(|s| s.to_str());
+        if let Some(ext) = ext {
+            self.analyze_ast(ext, &filename, content, &mut violations);
         }
+        let metrics = ext.and_then(|e| ANALYZER.measure(e, content));
 
-        Some(FileReport {
+        let debt = self.debt.as_ref().map(|re| debt::collect(re, content));
+        if let Some(found) = &debt {
+            debt::check_limit(found, &self.config.rules.debt, &mut violations);
+        }
+
+        let report = FileReport {
             path: path.to_path_buf(),
             token_count,
-            complexity_score: 0,
+            complexity_score: metrics.map_or(0, |m| m.complexity),
             violations,
-        })
+            suppressed: Vec::new(),
+            baselined: Vec::new(),
+            debt: debt.unwrap_or_default(),
+            metrics,
+            blame: BTreeMap::new(),
+        };
+        let lang = ext.and_then(Lang::from_ext);
+        let scopes = FileScopes {
+            suppressions: suppress::collect(content, lang),
+            tests: test_code::scope(&filename, content, lang),
+        };
+        Some(CachedFile { report, scopes })
     }
 
-    fn is_exempt_from_tokens(&self, filename: &str) -> bool {
-        self.config
-            .rules
-            .ignore_tokens_on
-            .iter()
-            .any(|pattern| filename.contains(pattern))
+    fn analyze_ast(&self, ext: &str, filename: &str, content: &str, out: &mut Vec<Violation>) {
+        let rules = &self.config.rules;
+        let mut found = Analyzer::analyze_with(ext, filename, content, rules, &self.exempt);
+        out.append(&mut found);
+        if let Some(lang) = Lang::from_ext(ext) {
+            custom::check(&self.custom, lang, content, out);
+        }
     }
 }
@@ -0,0 +1,181 @@
+// src/analysis/modpath.rs
+//! Maps Rust `use` paths to the files that define the module.
+use crate::lang::Lang;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use tree_sitter::{Node, Parser};
+
+/// Index of module paths (`["analysis", "checks"]`) to files for every
+/// scanned Rust file under a `src/` directory.
+pub struct RustModules {
+    by_path: HashMap<Vec<String>, PathBuf>,
+    by_file: HashMap<PathBuf, Vec<String>>,
+}
+
+impl RustModules {
+    #[must_use]
+    pub fn new(files: &[PathBuf]) -> Self {
+        let mut by_path = HashMap::new();
+        let mut by_file = HashMap::new();
+        for file in files {
+            if let Some(module) = module_path(file) {
+                by_path.insert(module.clone(), file.clone());
+                by_file.insert(file.clone(), module);
+            }
+        }
+        Self { by_path, by_file }
+    }
+
+    /// Resolves every `use` in `content` to the files of the deepest known
+    /// modules it names. `mod x;` declarations and external crates are not
+    /// dependencies and resolve to nothing.
+    #[must_use]
+    pub fn imports(&self, file: &Path, content: &str) -> Vec<PathBuf> {
+        let Some(current) = self.by_file.get(file) else {
+            return Vec::new();
+        };
+        let mut parser = Parser::new();
+        if parser.set_language(&Lang::Rust.grammar()).is_err() {
+            return Vec::new();
+        }
+        let Some(tree) = parser.parse(content, None) else {
+            return Vec::new();
+        };
+        let mut uses = Vec::new();
+        collect_uses(tree.root_node(), content, current, &mut uses);
+        uses.iter()
+            .flat_map(|(module, arg)| expand(arg).into_iter().map(move |p| (module, p)))
+            .filter_map(|(module, path)| self.absolute(module, &path))
+            .filter_map(|segs| self.deepest_module(&segs))
+            .collect()
+    }
+
+    fn absolute(&self, current: &[String], path: &str) -> Option<Vec<String>> {
+        let segs: Vec<&str> = path.split("::").filter(|s| !s.is_empty()).collect();
+        let (first, rest) = segs.split_first()?;
+        if rest.is_empty() && !matches!(*first, "super" | "self") {
+            return None;
+        }
+        let mut base = self.base_module(current, first)?;
+        let mut rest = rest.iter().peekable();
+        while rest.next_if(|s| **s == "super").is_some() {
+            base.pop();
+        }
+        base.extend(rest.map(|s| (*s).to_string()));
+        Some(base)
+    }
+
+    /// The module a path's first segment refers to. A bare first segment is
+    /// a child module (2018 paths), or an external crate if none exists.
+    fn base_module(&self, current: &[String], first: &str) -> Option<Vec<String>> {
+        match first {
+            "crate" => Some(Vec::new()),
+            "self" => Some(current.to_vec()),
+            "super" => current
+                .get(..current.len().checked_sub(1)?)
+                .map(<[String]>::to_vec),
+            child => {
+                let mut local = current.to_vec();
+                local.push(child.to_string());
+                self.by_path.contains_key(&local).then_some(local)
+            }
+        }
+    }
+
+    fn deepest_module(&self, segs: &[String]) -> Option<PathBuf> {
+        (0..=segs.len())
+            .rev()
+            .find_map(|n| self.by_path.get(&segs[..n]))
+            .cloned()
+    }
+}
+
+/// Collects `(enclosing module, use argument)` pairs, descending into inline
+/// `mod x { ... }` blocks so `super` inside them resolves correctly.
+fn collect_uses(node: Node, source: &str, module: &[String], out: &mut Vec<(Vec<String>, String)>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        match child.kind() {
+            "use_declaration" => {
+                let arg = child.child_by_field_name("argument");
+                let text = arg.and_then(|a| a.utf8_text(source.as_bytes()).ok());
+                out.extend(text.map(|t| (module.to_vec(), t.to_string())));
+            }
+            "mod_item" => collect_inline_mod(child, source, module, out),
+            _ => collect_uses(child, source, module, out),
+        }
+    }
+}
+
+fn collect_inline_mod(
+    item: Node,
+    source: &str,
+    module: &[String],
+    out: &mut Vec<(Vec<String>, String)>,
+) {
+    let name = item.child_by_field_name("name");
+    let body = item.child_by_field_name("body");
+    if let (Some(name), Some(body)) = (name, body) {
+        let mut inner = module.to_vec();
+        inner.push(name.utf8_text(source.as_bytes()).unwrap_or("").to_string());
+        collect_uses(body, source, &inner, out);
+    }
+}
+
+/// `src/a/b.rs` → `["a", "b"]`; `mod.rs`, `lib.rs`, and `main.rs` name
+/// their directory. Files under `src/bin` are separate crates and skipped.
+fn module_path(file: &Path) -> Option<Vec<String>> {
+    let parts: Vec<String> = file
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy().into_owned())
+        .collect();
+    let src = parts.iter().rposition(|p| p == "src")?;
+    let mut module: Vec<String> = parts[src + 1..].to_vec();
+    if module.first().is_some_and(|m| m == "bin") {
+        return None;
+    }
+    let last = module.pop()?;
+    let stem = last.strip_suffix(".rs")?;
+    let names_dir = stem == "mod" || (module.is_empty() && matches!(stem, "lib" | "main"));
+    if !names_dir {
+        module.push(stem.to_string());
+    }
+    Some(module)
+}
+
+/// Expands `a::{b, c::{d, e}}` into `a::b`, `a::c::d`, `a::c::e` and drops
+/// `as` renames and globs.
+fn expand(import: &str) -> Vec<String> {
+    let import: String = import.split_whitespace().collect::<Vec<_>>().join(" ");
+    let Some(open) = import.find('{') else {
+        let path = import.split(" as ").next().unwrap_or(&import);
+        return vec![path.trim_end_matches("::*").to_string()];
+    };
+    let prefix = &import[..open];
+    let inner = import[open + 1..]
+        .strip_suffix('}')
+        .unwrap_or(&import[open + 1..]);
+    split_top_level(inner)
+        .into_iter()
+        .flat_map(|part| expand(&format!("{prefix}{}", part.trim())))
+        .collect()
+}
+
+fn split_top_level(s: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut start = 0;
+    for (i, c) in s.char_indices() {
+        match c {
+            '{' => depth += 1,
+            '}' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&s[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&s[start..]);
+    parts.into_iter().filter(|p| !p.trim().is_empty()).collect()
+}
@@ -0,0 +1,45 @@
+// src/analysis/oversize.rs
+//! Files over `max_file_bytes` are never read; they get a single
+//! [`NOTE`](crate::types::NOTE), which fails no run.
+
+use super::cache::CachedFile;
+use super::scopes::FileScopes;
+use super::source::Source;
+use super::test_code::TestScope;
+use crate::types::{FileReport, Severity, Violation, NOTE};
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Size of `path` in bytes, if it is over `limit`.
+#[must_use]
+pub fn check(source: &dyn Source, path: &Path, limit: u64) -> Option<u64> {
+    let size = source.size(path)?;
+    (size > limit).then_some(size)
+}
+
+/// The result reported in place of analysis.
+#[must_use]
+pub fn skipped(path: &Path, size: u64, limit: u64) -> CachedFile {
+    let note = Violation {
+        row: 0,
+        message: format!("Skipped: too large to analyze ({size} bytes, Limit: {limit})"),
+        law: NOTE,
+        severity: Severity::Info,
+    };
+    let report = FileReport {
+        path: path.to_path_buf(),
+        token_count: 0,
+        complexity_score: 0,
+        violations: vec![note],
+        suppressed: Vec::new(),
+        baselined: Vec::new(),
+        debt: Vec::new(),
+        metrics: None,
+        blame: BTreeMap::new(),
+    };
+    let scopes = FileScopes {
+        suppressions: Vec::new(),
+        tests: TestScope::None,
+    };
+    CachedFile { report, scopes }
+}
@@ -0,0 +1,136 @@
+// src/analysis/paranoia.rs
+//! Law of Paranoia: banned calls and dangerous constructs.
+
+use super::checks::CheckContext;
+use super::test_code;
+use crate::types::{Severity, Violation};
+use tree_sitter::{Node, Query, QueryCursor, QueryMatch};
+
+/// Checks call sites against the user-configured `banned_calls` list.
+pub fn check_banned_calls(
+    ctx: &CheckContext,
+    calls_query: &Query,
+    banned: &[String],
+    out: &mut Vec<Violation>,
+) {
+    let mut cursor = QueryCursor::new();
+    let names = calls_query.capture_names();
+
+    for m in cursor.matches(calls_query, ctx.root, ctx.source.as_bytes()) {
+        for cap in m.captures {
+            let is_macro = names[cap.index as usize] == "macro";
+            check_callee(ctx, cap.node, is_macro, banned, out);
+        }
+    }
+}
+
+fn check_callee(
+    ctx: &CheckContext,
+    node: Node,
+    is_macro: bool,
+    banned: &[String],
+    out: &mut Vec<Violation>,
+) {
+    let Ok(text) = node.utf8_text(ctx.source.as_bytes()) else {
+        return;
+    };
+    let callee = if is_macro {
+        format!("{text}!")
+    } else {
+        text.to_string()
+    };
+    if banned.iter().any(|p| matches_call(&callee, p)) {
+        out.push(Violation {
+            row: node.start_position().row,
+            message: format!("Banned: '{callee}' is disallowed by config."),
+            law: "LAW OF PARANOIA",
+            severity: Severity::Error,
+        });
+    }
+}
+
+/// Exact match, or a suffix match for method patterns such as `.clone`.
+fn matches_call(callee: &str, pattern: &str) -> bool {
+    callee == pattern || (pattern.starts_with('.') && callee.ends_with(pattern))
+}
+
+/// Checks for banned constructs (`.unwrap()`/`.expect()` calls, shell hazards).
+pub fn check_banned(ctx: &CheckContext, banned_query: &Query, out: &mut Vec<Violation>) {
+    let mut cursor = QueryCursor::new();
+    let names = banned_query.capture_names();
+
+    for m in cursor.matches(banned_query, ctx.root, ctx.source.as_bytes()) {
+        process_banned_match(&m, names, ctx, out);
+    }
+}
+
+fn process_banned_match(
+    m: &QueryMatch,
+    names: &[&str],
+    ctx: &CheckContext,
+    out: &mut Vec<Violation>,
+) {
+    let mut method_name: Option<&str> = None;
+    let mut row = 0;
+
+    for cap in m.captures {
+        let capture_name = names[cap.index as usize];
+        check_hazard(capture_name, cap.node, ctx, out);
+        if capture_name == "method" {
+            method_name = cap.node.utf8_text(ctx.source.as_bytes()).ok();
+        }
+        if capture_name == "call" {
+            row = cap.node.start_position().row;
+        }
+    }
+
+    if let Some(name) = method_name {
+        if name == "unwrap" || name == "expect" {
+            out.push(Violation {
+                row,
+                message: format!("Banned: '.{name}()'. Use '?' or 'unwrap_or'."),
+                law: "LAW OF PARANOIA",
+                severity: Severity::Error,
+            });
+        }
+    }
+}
+
+/// Captures that are violations on their own, without a method name.
+fn check_hazard(capture_name: &str, node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    if let Some(message) = hazard_message(capture_name) {
+        out.push(Violation {
+            row: node.start_position().row,
+            message: message.to_string(),
+            law: "LAW OF PARANOIA",
+            severity: Severity::Error,
+        });
+    } else if capture_name == "panic_macro" {
+        check_panic_macro(node, ctx, out);
+    }
+}
+
+/// `todo!`, `unimplemented!` and `panic!` are fine in tests and exempted paths.
+fn check_panic_macro(node: Node, ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let exempt = ctx.exempt.panics(ctx.filename);
+    if exempt || test_code::is_test_path(ctx.filename) || test_code::in_test_item(node, ctx.source)
+    {
+        return;
+    }
+    let name = node.utf8_text(ctx.source.as_bytes()).unwrap_or("panic");
+    out.push(Violation {
+        row: node.start_position().row,
+        message: format!("Banned: '{name}!()'. Finish the code or return an error."),
+        law: "LAW OF PARANOIA",
+        severity: Severity::Error,
+    });
+}
+
+fn hazard_message(capture_name: &str) -> Option<&'static str> {
+    match capture_name {
+        "eval" => Some("Banned: 'eval'. Executes arbitrary strings as code."),
+        "unquoted_args" => Some("Unquoted '$@'/'$*'. Use \"$@\" to preserve arguments."),
+        "rm_var" => Some("Dangerous: 'rm -r' on a variable. Guard with '${VAR:?}'."),
+        _ => None,
+    }
+}
@@ -0,0 +1,48 @@
+// src/analysis/progress.rs
+//! Progress updates for [`super::RuleEngine::scan_with_progress`].
+
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::time::{Duration, Instant};
+
+/// Reported once per file as it finishes, in completion order.
+#[derive(Debug, Clone)]
+pub struct ScanProgress {
+    pub completed: usize,
+    pub total: usize,
+    /// The file that just finished.
+    pub current: PathBuf,
+    /// Remaining time extrapolated from the average so far.
+    pub eta: Duration,
+}
+
+/// Counts completed files across the worker threads.
+pub struct Tracker {
+    start: Instant,
+    total: usize,
+    completed: AtomicUsize,
+}
+
+impl Tracker {
+    #[must_use]
+    pub fn new(total: usize) -> Self {
+        Self {
+            start: Instant::now(),
+            total,
+            completed: AtomicUsize::new(0),
+        }
+    }
+
+    /// Marks `path` as done.
+    pub fn tick(&self, path: &Path) -> ScanProgress {
+        let completed = self.completed.fetch_add(1, Ordering::Relaxed) + 1;
+        let remaining = u32::try_from(self.total.saturating_sub(completed)).unwrap_or(u32::MAX);
+        let per_file = self.start.elapsed() / u32::try_from(completed).unwrap_or(u32::MAX);
+        ScanProgress {
+            completed,
+            total: self.total,
+            current: path.to_path_buf(),
+            eta: per_file.saturating_mul(remaining),
+        }
+    }
+}
@@ -0,0 +1,33 @@
+// src/analysis/safety.rs
+//! Law of Safety: a per-file budget for Rust `unsafe` blocks.
+use super::checks::CheckContext;
+use crate::types::{Severity, Violation};
+use tree_sitter::Node;
+
+/// Flags the first `unsafe` block past `max_unsafe_blocks`.
+pub fn check_unsafe(ctx: &CheckContext, out: &mut Vec<Violation>) {
+    let mut blocks = Vec::new();
+    collect_blocks(ctx.root, &mut blocks);
+    let max = ctx.config.max_unsafe_blocks;
+    if let Some(over) = blocks.get(max) {
+        out.push(Violation {
+            row: over.start_position().row,
+            message: format!(
+                "Unsafe Code: {} unsafe blocks (Max: {max}). Justify or remove.",
+                blocks.len()
+            ),
+            law: "LAW OF SAFETY",
+            severity: Severity::Error,
+        });
+    }
+}
+
+fn collect_blocks<'a>(node: Node<'a>, out: &mut Vec<Node<'a>>) {
+    if node.kind() == "unsafe_block" {
+        out.push(node);
+    }
+    let mut cursor = node.walk();
+    for child in node.children(&mut cursor) {
+        collect_blocks(child, out);
+    }
+}
@@ -0,0 +1,64 @@
+// src/analysis/scopes.rs
+//! Per-file row scopes (inline suppressions, test code) and severity
+//! overrides, applied after the cross-file passes.
+
+use super::suppress::{self, Suppression};
+use super::test_code::TestScope;
+use crate::config::TestRules;
+use crate::types::{FileReport, Severity};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Per-file context kept until the cross-file passes are done.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FileScopes {
+    pub suppressions: Vec<Suppression>,
+    pub tests: TestScope,
+}
+
+/// Moves inline-suppressed violations out of the failing set.
+pub fn apply_suppressions(results: &mut [FileReport], scopes: &[FileScopes]) {
+    for (report, scope) in results.iter_mut().zip(scopes) {
+        if scope.suppressions.is_empty() {
+            continue;
+        }
+        let all = std::mem::take(&mut report.violations);
+        (report.violations, report.suppressed) = suppress::partition(all, &scope.suppressions);
+    }
+}
+
+/// Applies the `[rules.severity]` overrides to every violation.
+#[allow(clippy::implicit_hasher)]
+pub fn apply_severities(results: &mut [FileReport], overrides: &HashMap<String, Severity>) {
+    let violations = results.iter_mut().flat_map(|f| &mut f.violations);
+    for v in violations.filter(|v| !v.is_note()) {
+        if let Some(severity) = overrides.get(&v.rule()) {
+            v.severity = *severity;
+        }
+    }
+}
+
+/// Applies the `[rules.tests]` profile to test code.
+pub fn apply_test_profile(results: &mut [FileReport], scopes: &[FileScopes], profile: &TestRules) {
+    for (report, scope) in results.iter_mut().zip(scopes) {
+        if !matches!(scope.tests, TestScope::None) {
+            relax_tests(report, &scope.tests, profile);
+        }
+    }
+}
+
+/// Drops skipped rules and applies test severities inside `tests`.
+fn relax_tests(report: &mut FileReport, tests: &TestScope, profile: &TestRules) {
+    report
+        .violations
+        .retain(|v| !tests.contains(v.row) || !profile.skip.contains(&v.rule()));
+    for v in report
+        .violations
+        .iter_mut()
+        .filter(|v| tests.contains(v.row))
+    {
+        if let Some(severity) = profile.severity.get(&v.rule()) {
+            v.severity = *severity;
+        }
+    }
+}
@@ -0,0 +1,36 @@
+// src/analysis/sfc.rs
+//! Script extraction for single-file components (`.vue`, `.svelte`).
+
+use regex::Regex;
+use std::sync::LazyLock;
+
+static SCRIPT_RE: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"(?s)<script\b[^>]*>(.*?)</script>").ok());
+
+/// A `<script>` block lifted out of a component file.
+pub struct ScriptBlock {
+    pub source: String,
+    /// Zero-based line in the original file where `source` begins.
+    pub line_offset: usize,
+}
+
+/// Returns true if the extension belongs to a single-file component format.
+#[must_use]
+pub fn is_sfc(ext: &str) -> bool {
+    matches!(ext, "vue" | "svelte")
+}
+
+/// Extracts every `<script>` block from a component file.
+#[must_use]
+pub fn extract_scripts(content: &str) -> Vec<ScriptBlock> {
+    let Some(re) = SCRIPT_RE.as_ref() else {
+        return Vec::new();
+    };
+    re.captures_iter(content)
+        .filter_map(|caps| caps.get(1))
+        .map(|m| ScriptBlock {
+            source: m.as_str().to_string(),
+            line_offset: content[..m.start()].matches('\n').count(),
+        })
+        .collect()
+}
@@ -0,0 +1,49 @@
+// src/analysis/source.rs
+//! Where the engine reads file contents from: the filesystem, or buffers
+//! handed in by an embedder (see [`super::RuleEngine::scan_sources`]).
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+/// A provider of file contents.
+pub trait Source: Sync {
+    /// Size in bytes, checked before reading; `None` if unknown.
+    fn size(&self, path: &Path) -> Option<u64>;
+    fn read(&self, path: &Path) -> Option<Cow<'_, str>>;
+    /// Whether results may be written to the scan cache.
+    fn cacheable(&self) -> bool {
+        false
+    }
+}
+
+/// Reads files from disk.
+pub struct Disk;
+
+impl Source for Disk {
+    fn size(&self, path: &Path) -> Option<u64> {
+        fs::metadata(path).ok().map(|m| m.len())
+    }
+
+    fn read(&self, path: &Path) -> Option<Cow<'_, str>> {
+        fs::read_to_string(path).ok().map(Cow::Owned)
+    }
+
+    fn cacheable(&self) -> bool {
+        true
+    }
+}
+
+/// In-memory contents, e.g. unsaved editor buffers. Never touches disk.
+pub struct Memory(pub HashMap<PathBuf, String>);
+
+impl Source for Memory {
+    fn size(&self, path: &Path) -> Option<u64> {
+        self.0.get(path).map(|s| s.len() as u64)
+    }
+
+    fn read(&self, path: &Path) -> Option<Cow<'_, str>> {
+        self.0.get(path).map(|s| Cow::Borrowed(s.as_str()))
+    }
+}
@@ -0,0 +1,149 @@
+// src/analysis/suppress.rs
+//! Inline suppressions.
+//!
+//! * `// slopchop:ignore` anywhere skips the whole file.
+//! * `// slopchop:ignore-next-line [rules]` silences the following line.
+//! * `// slopchop:ignore(rules)` silences the function or type that follows
+//!   it (or the next line if nothing is attached).
+//!
+//! Rules are the keys used in `[rules.severity]` (`complexity`, `paranoia`,
+//! ...), separated by commas or spaces. Omitting them silences every rule.
+
+use crate::lang::Lang;
+use crate::types::Violation;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::LazyLock;
+use tree_sitter::{Node, Parser};
+
+const MARKER: &str = "slopchop:ignore";
+const FILE_PREFIXES: &[&str] = &["// ", "# ", "<!-- "];
+
+static NEXT_LINE_RE: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"slopchop:ignore-next-line\b([\w\s,-]*)").ok());
+static SCOPED_RE: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"slopchop:ignore\(([^)]*)\)").ok());
+
+/// A range of rows (inclusive) where the listed rules are silenced.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Suppression {
+    pub start: usize,
+    pub end: usize,
+    /// Empty means every rule.
+    pub rules: Vec<String>,
+}
+
+impl Suppression {
+    fn covers(&self, v: &Violation) -> bool {
+        (self.start..=self.end).contains(&v.row)
+            && (self.rules.is_empty() || self.rules.contains(&v.rule()))
+    }
+}
+
+/// Returns true if the file carries a bare file-level ignore marker.
+#[must_use]
+pub fn is_file_ignored(content: &str) -> bool {
+    FILE_PREFIXES.iter().any(|prefix| {
+        let marker = format!("{prefix}{MARKER}");
+        content
+            .match_indices(&marker)
+            .any(|(i, _)| !content[i + marker.len()..].starts_with(['-', '(']))
+    })
+}
+
+/// Collects every scoped suppression in `content`.
+#[must_use]
+pub fn collect(content: &str, lang: Option<Lang>) -> Vec<Suppression> {
+    let lines: Vec<&str> = content.lines().collect();
+    let mut found = Vec::new();
+    for (row, line) in lines.iter().enumerate() {
+        if let Some(rules) = capture_rules(NEXT_LINE_RE.as_ref(), line) {
+            found.push(Suppression {
+                start: row + 1,
+                end: row + 1,
+                rules,
+            });
+        } else if let Some(rules) = capture_rules(SCOPED_RE.as_ref(), line) {
+            let end = lang
+                .and_then(|l| attached_item_end(l, content, &lines, row))
+                .unwrap_or(row + 1);
+            found.push(Suppression {
+                start: row + 1,
+                end,
+                rules,
+            });
+        }
+    }
+    found
+}
+
+/// Splits violations into `(kept, suppressed)`.
+#[must_use]
+pub fn partition(
+    violations: Vec<Violation>,
+    suppressions: &[Suppression],
+) -> (Vec<Violation>, Vec<Violation>) {
+    violations
+        .into_iter()
+        .partition(|v| !suppressions.iter().any(|s| s.covers(v)))
+}
+
+fn capture_rules(re: Option<&Regex>, line: &str) -> Option<Vec<String>> {
+    let caps = re?.captures(line)?;
+    let list = caps.get(1).map_or("", |m| m.as_str());
+    Some(
+        list.split(|c: char| c == ',' || c.is_whitespace())
+            .filter(|r| !r.is_empty())
+            .map(str::to_lowercase)
+            .collect(),
+    )
+}
+
+/// End row of the function or type directly below `row`, if only attributes,
+/// decorators, comments, or blank lines sit between them.
+fn attached_item_end(lang: Lang, content: &str, lines: &[&str], row: usize) -> Option<usize> {
+    let mut parser = Parser::new();
+    parser.set_language(&lang.grammar()).ok()?;
+    let tree = parser.parse(content, None)?;
+    let item = first_item_after(tree.root_node(), row)?;
+    let start = item.start_position().row;
+    let gap = lines.get(row + 1..start)?;
+    gap.iter()
+        .all(|l| is_preamble(l))
+        .then(|| item.end_position().row)
+}
+
+fn first_item_after(node: Node, row: usize) -> Option<Node> {
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+    children
+        .into_iter()
+        .filter(|c| c.end_position().row > row)
+        .find_map(|c| {
+            let kind = c.kind();
+            if is_item(kind) && c.start_position().row > row {
+                Some(c)
+            } else {
+                first_item_after(c, row)
+            }
+        })
+}
+
+fn is_item(kind: &str) -> bool {
+    [
+        "function",
+        "method",
+        "struct",
+        "class",
+        "interface",
+        "enum",
+        "impl",
+    ]
+    .iter()
+    .any(|k| kind.contains(k))
+}
+
+fn is_preamble(line: &str) -> bool {
+    let t = line.trim_start();
+    t.is_empty() || ["#", "//", "/*", "*", "@"].iter().any(|p| t.starts_with(p))
+}
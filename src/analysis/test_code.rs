@@ -0,0 +1,125 @@
+// src/analysis/test_code.rs
+//! Recognizes test code, where the `[rules.tests]` profile applies.
+use crate::lang::Lang;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tree_sitter::{Node, Parser};
+
+const TEST_DIRS: &[&str] = &["tests/", "test/", "__tests__/", "spec/"];
+const TEST_SUFFIXES: &[&str] = &[
+    "_test.go",
+    "_test.py",
+    ".spec.ts",
+    ".spec.js",
+    ".spec.tsx",
+    ".test.ts",
+    ".test.js",
+    ".test.tsx",
+];
+
+/// Which rows of a file are test code.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub enum TestScope {
+    #[default]
+    None,
+    File,
+    /// Inclusive row ranges.
+    Rows(Vec<(usize, usize)>),
+}
+
+impl TestScope {
+    #[must_use]
+    pub fn contains(&self, row: usize) -> bool {
+        match self {
+            Self::None => false,
+            Self::File => true,
+            Self::Rows(ranges) => ranges.iter().any(|(a, b)| (*a..=*b).contains(&row)),
+        }
+    }
+}
+
+/// Finds the test code in a file: whole test files by path, or Rust
+/// `#[cfg(test)]` modules and `#[test]` functions.
+#[must_use]
+pub fn scope(filename: &str, content: &str, lang: Option<Lang>) -> TestScope {
+    if is_test_path(filename) {
+        return TestScope::File;
+    }
+    if lang != Some(Lang::Rust) || !content.contains("test") {
+        return TestScope::None;
+    }
+    let mut parser = Parser::new();
+    if parser.set_language(&Lang::Rust.grammar()).is_err() {
+        return TestScope::None;
+    }
+    let Some(tree) = parser.parse(content, None) else {
+        return TestScope::None;
+    };
+    let mut ranges = Vec::new();
+    collect_test_items(tree.root_node(), content, &mut ranges);
+    if ranges.is_empty() {
+        TestScope::None
+    } else {
+        TestScope::Rows(ranges)
+    }
+}
+
+/// Returns true for files in test directories or with test-file suffixes
+/// (`*_test.go`, `*.spec.ts`, `test_*.py`, ...).
+#[must_use]
+pub fn is_test_path(filename: &str) -> bool {
+    let path = filename.replace('\\', "/");
+    let name = path.rsplit('/').next().unwrap_or(&path);
+    TEST_DIRS
+        .iter()
+        .any(|d| path.starts_with(d) || path.contains(&format!("/{d}")))
+        || TEST_SUFFIXES.iter().any(|s| name.ends_with(s))
+        || (name.starts_with("test_")
+            && Path::new(name)
+                .extension()
+                .is_some_and(|e| e.eq_ignore_ascii_case("py")))
+}
+
+fn collect_test_items(node: Node, source: &str, out: &mut Vec<(usize, usize)>) {
+    let mut cursor = node.walk();
+    for child in node.named_children(&mut cursor) {
+        let is_item = matches!(child.kind(), "mod_item" | "function_item");
+        if is_item && has_test_attr(child, source) {
+            out.push((child.start_position().row, child.end_position().row));
+        } else {
+            collect_test_items(child, source, out);
+        }
+    }
+}
+
+/// Returns true if `node` sits inside a Rust `#[cfg(test)]` module or a
+/// `#[test]` function.
+#[must_use]
+pub fn in_test_item(node: Node, source: &str) -> bool {
+    let mut current = node.parent();
+    while let Some(n) = current {
+        if matches!(n.kind(), "mod_item" | "function_item") && has_test_attr(n, source) {
+            return true;
+        }
+        current = n.parent();
+    }
+    false
+}
+
+fn has_test_attr(item: Node, source: &str) -> bool {
+    let mut prev = item.prev_sibling();
+    while let Some(p) = prev {
+        match p.kind() {
+            "attribute_item" => {
+                let text = p.utf8_text(source.as_bytes()).unwrap_or("");
+                if text.contains("test") {
+                    return true;
+                }
+            }
+            "line_comment" | "block_comment" => {}
+            _ => return false,
+        }
+        prev = p.prev_sibling();
+    }
+    false
+}
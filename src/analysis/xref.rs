@@ -0,0 +1,167 @@
+// src/analysis/xref.rs
+//! Cross-file symbol index for detecting unused private Rust functions.
+//!
+//! Per-file queries cannot tell whether a function is dead, so this pass
+//! indexes every private definition and every identifier reference across
+//! the whole scan set before reporting.
+
+use super::source::Source;
+use crate::lang::Lang;
+use crate::types::{Severity, Violation};
+use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use tree_sitter::{Node, Parser, Query, QueryCursor};
+
+const Q_FUNCTIONS: &str = "(function_item name: (identifier) @name) @fn";
+// String contents catch names passed by reference, e.g. `#[serde(default = "f")]`.
+const Q_REFERENCES: &str = "[(identifier) (field_identifier) (string_content)] @ref";
+
+/// A private function definition.
+struct PrivateFn {
+    name: String,
+    row: usize,
+}
+
+/// Symbols collected from a single file.
+struct FileSymbols {
+    path: PathBuf,
+    private_fns: Vec<PrivateFn>,
+    references: HashSet<String>,
+}
+
+/// Finds private Rust functions that are never referenced in `files`.
+///
+/// Returns violations keyed by the file that defines the dead function.
+#[must_use]
+pub fn find_dead_code(files: &[PathBuf], source: &dyn Source) -> HashMap<PathBuf, Vec<Violation>> {
+    let symbols: Vec<FileSymbols> = files
+        .par_iter()
+        .filter(|p| p.extension().is_some_and(|e| e == "rs"))
+        .filter_map(|p| index_file(p, &source.read(p)?))
+        .collect();
+
+    let referenced: HashSet<&str> = symbols
+        .iter()
+        .flat_map(|s| s.references.iter().map(String::as_str))
+        .collect();
+
+    let mut dead = HashMap::new();
+    for file in &symbols {
+        let violations: Vec<Violation> = file
+            .private_fns
+            .iter()
+            .filter(|f| !referenced.contains(f.name.as_str()))
+            .map(dead_code_violation)
+            .collect();
+        if !violations.is_empty() {
+            dead.insert(file.path.clone(), violations);
+        }
+    }
+    dead
+}
+
+fn dead_code_violation(f: &PrivateFn) -> Violation {
+    Violation {
+        row: f.row,
+        message: format!(
+            "Dead Code: private function '{}' is never referenced. Delete it.",
+            f.name
+        ),
+        law: "LAW OF ECONOMY",
+        severity: Severity::Error,
+    }
+}
+
+fn index_file(path: &Path, source: &str) -> Option<FileSymbols> {
+    let grammar = Lang::Rust.grammar();
+    let mut parser = Parser::new();
+    parser.set_language(&grammar).ok()?;
+    let tree = parser.parse(source, None)?;
+    let root = tree.root_node();
+
+    let fn_query = Query::new(&grammar, Q_FUNCTIONS).ok()?;
+    let ref_query = Query::new(&grammar, Q_REFERENCES).ok()?;
+
+    Some(FileSymbols {
+        path: path.to_path_buf(),
+        private_fns: collect_private_fns(root, source, &fn_query),
+        references: collect_references(root, source, &ref_query),
+    })
+}
+
+fn collect_private_fns(root: Node, source: &str, query: &Query) -> Vec<PrivateFn> {
+    let mut cursor = QueryCursor::new();
+    cursor
+        .matches(query, root, source.as_bytes())
+        .filter_map(|m| {
+            let func = m
+                .captures
+                .iter()
+                .find(|c| c.node.kind() == "function_item")?;
+            let name = m.captures.iter().find(|c| c.node.kind() == "identifier")?;
+            if !is_candidate(func.node) {
+                return None;
+            }
+            Some(PrivateFn {
+                name: name.node.utf8_text(source.as_bytes()).ok()?.to_string(),
+                row: func.node.start_position().row,
+            })
+        })
+        .filter(|f| f.name != "main")
+        .collect()
+}
+
+/// Only bare private functions with no attributes are candidates. Trait
+/// items, `pub` items, and attributed functions (`#[test]`, `#[no_mangle]`,
+/// ...) are reachable in ways this index cannot see.
+fn is_candidate(func: Node) -> bool {
+    let mut cursor = func.walk();
+    let has_visibility = func
+        .children(&mut cursor)
+        .any(|c| c.kind() == "visibility_modifier");
+    let has_attribute = preceding_item(func).is_some_and(|s| s.kind() == "attribute_item");
+    !has_visibility && !has_attribute && !in_trait_context(func)
+}
+
+/// The previous sibling, skipping doc and line comments.
+fn preceding_item(node: Node) -> Option<Node> {
+    let mut prev = node.prev_named_sibling();
+    while let Some(p) = prev {
+        if !p.kind().ends_with("comment") {
+            return Some(p);
+        }
+        prev = p.prev_named_sibling();
+    }
+    None
+}
+
+fn in_trait_context(func: Node) -> bool {
+    let mut current = func.parent();
+    while let Some(node) = current {
+        if node.kind() == "trait_item" {
+            return true;
+        }
+        if node.kind() == "impl_item" {
+            return node.child_by_field_name("trait").is_some();
+        }
+        current = node.parent();
+    }
+    false
+}
+
+fn collect_references(root: Node, source: &str, query: &Query) -> HashSet<String> {
+    let mut cursor = QueryCursor::new();
+    cursor
+        .matches(query, root, source.as_bytes())
+        .flat_map(|m| m.captures.iter().map(|c| c.node).collect::<Vec<_>>())
+        .filter(|node| !is_definition_name(*node))
+        .filter_map(|node| node.utf8_text(source.as_bytes()).ok())
+        .map(str::to_string)
+        .collect()
+}
+
+fn is_definition_name(node: Node) -> bool {
+    node.parent()
+        .is_some_and(|p| p.kind() == "function_item" && p.child_by_field_name("name") == Some(node))
+}
@@ -0,0 +1,43 @@
+// src/apply/allowlist.rs
+//! `[apply] allowed_paths`: globs that every file a payload touches must
+//! match. `*` stays within a directory, `**` spans any number of them.
+
+use super::manifest;
+use super::types::Operation;
+use crate::ignore;
+use regex::Regex;
+
+/// Describes each file in the response's manifest (rename targets
+/// included) outside `allowed`. An empty allowlist allows everything.
+#[must_use]
+pub fn violations(content: &str, allowed: &[String]) -> Vec<String> {
+    if allowed.is_empty() {
+        return Vec::new();
+    }
+    let Ok(Some(entries)) = manifest::parse_manifest(content) else {
+        return Vec::new();
+    };
+    let patterns: Vec<Regex> = allowed.iter().filter_map(|g| glob_regex(g)).collect();
+    let mut paths: Vec<&str> = Vec::new();
+    for entry in &entries {
+        paths.push(&entry.path);
+        if let Operation::Rename(to) = &entry.operation {
+            paths.push(to);
+        }
+    }
+    paths
+        .into_iter()
+        .filter(|p| !patterns.iter().any(|re| re.is_match(p)))
+        .map(|p| format!("{p} is outside the allowed paths ({})", allowed.join(", ")))
+        .collect()
+}
+
+/// True if `path` matches the glob `pattern`.
+#[must_use]
+pub fn matches(pattern: &str, path: &str) -> bool {
+    glob_regex(pattern).is_some_and(|re| re.is_match(path))
+}
+
+fn glob_regex(pattern: &str) -> Option<Regex> {
+    ignore::glob_regex(pattern.trim_start_matches("./"))
+}
@@ -0,0 +1,178 @@
+// src/apply/commit.rs
+//! After a successful write: verification, commit, and the saved intent
+//! that carries a failed plan's goal into the follow-up commit.
+
+use super::types::{ApplyContext, ApplyOutcome};
+use super::{commit_message, fix_prompt, format, git, ledger, messages, undo, verification};
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+use crate::config::files::{self, INTENT_FILE};
+/// Runs verification on a successful write, then commits on a pass or
+/// saves the plan as intent on a failure, and logs the apply. `tasks` are
+/// the roadmap tasks the apply checked off, for the commit template.
+///
+/// # Errors
+/// Returns error if the verification commands cannot be run.
+pub fn verify_and_commit(
+    outcome: &ApplyOutcome,
+    ctx: &ApplyContext,
+    plan: Option<&str>,
+    tasks: &[String],
+) -> Result<()> {
+    if !matches!(outcome, ApplyOutcome::Success { .. }) {
+        return Ok(());
+    }
+
+    if !has_changes(outcome) {
+        println!("{}", "No changes detected.".yellow());
+        return Ok(());
+    }
+
+    let (success, log) = verify(outcome, ctx)?;
+    let commit = if success {
+        handle_success(plan, tasks, &log, ctx)
+    } else {
+        handle_failure(plan, &log, outcome);
+        None
+    };
+    if let Err(e) = ledger::record(plan, outcome, success, commit) {
+        eprintln!("{} Could not log apply: {e}", "⚠️".yellow());
+    }
+    Ok(())
+}
+
+fn has_changes(outcome: &ApplyOutcome) -> bool {
+    if let ApplyOutcome::Success {
+        written,
+        deleted,
+        roadmap_results,
+        ..
+    } = outcome
+    {
+        !written.is_empty() || !deleted.is_empty() || !roadmap_results.is_empty()
+    } else {
+        false
+    }
+}
+
+/// Formats the written files, runs the post-apply hooks, then the checks.
+fn verify(outcome: &ApplyOutcome, ctx: &ApplyContext) -> Result<(bool, String)> {
+    let mut touched = Vec::new();
+    if let ApplyOutcome::Success {
+        written, deleted, ..
+    } = outcome
+    {
+        let (failures, diff) = format::format_with_diff(&ctx.config.preferences, written);
+        for failure in failures {
+            eprintln!("{} Formatter failed (continuing): {failure}", "⚠️".yellow());
+        }
+        if !diff.is_empty() {
+            println!("{}{diff}", "\n> Formatter changes:".blue().bold());
+        }
+        touched = [written.as_slice(), deleted].concat();
+    }
+    let (hooks_ok, hook_log) = verification::run_post_apply(ctx)?;
+    if !hooks_ok {
+        return Ok((false, hook_log));
+    }
+    let (success, log) = verification::verify_application(ctx, &touched)?;
+    Ok((success, hook_log + &log))
+}
+
+/// Commits and pushes, opening a draft PR if `[git] create_pr` is set, and
+/// returns the new commit.
+fn handle_success(
+    plan: Option<&str>,
+    tasks: &[String],
+    log: &str,
+    ctx: &ApplyContext,
+) -> Option<String> {
+    println!(
+        "{}",
+        "\n✨ Verification Passed. Committing & Pushing..."
+            .green()
+            .bold()
+    );
+    let message = construct_commit_message(plan);
+    let message = match &ctx.config.git.commit_template {
+        Some(template) => {
+            let prefix = &ctx.config.preferences.commit_prefix;
+            commit_message::render(template, &message, prefix, tasks)
+        }
+        None => message,
+    };
+    if let Err(e) = git::commit_and_push(&message, &ctx.config.git) {
+        eprintln!("{} Git operation failed: {e}", "⚠️".yellow());
+        return None;
+    }
+    clear_intent();
+    if ctx.config.git.create_pr {
+        open_pr(&message, log);
+    }
+    let head = git::head().ok()?;
+    let _ = undo::record_commit(Path::new("."), &head);
+    Some(head)
+}
+
+fn open_pr(message: &str, log: &str) {
+    let title = message.lines().next().unwrap_or_default();
+    match git::create_draft_pr(title, &git::pr_body(message, log)) {
+        Ok(url) => println!("{} {url}", "Draft PR:".green()),
+        Err(e) => eprintln!("{} Could not open PR: {e}", "⚠️".yellow()),
+    }
+}
+
+fn handle_failure(plan: Option<&str>, failure_log: &str, outcome: &ApplyOutcome) {
+    println!(
+        "{}",
+        "\n❌ Verification Failed. Changes applied but NOT committed."
+            .red()
+            .bold()
+    );
+    println!("Fix the issues manually and then commit.");
+
+    let goal = std::fs::read_to_string(files::local(INTENT_FILE))
+        .ok()
+        .or_else(|| plan.map(str::to_string));
+    let written = match outcome {
+        ApplyOutcome::Success { written, .. } => written.as_slice(),
+        _ => &[],
+    };
+    let prompt = fix_prompt::build(goal.as_deref(), failure_log, written, Path::new("."));
+    messages::print_fix_prompt(failure_log, &prompt);
+
+    if let Some(p) = plan {
+        save_intent(p);
+    }
+}
+
+fn save_intent(plan: &str) {
+    // Only save if no intent exists (preserve the original goal)
+    if !files::local(INTENT_FILE).exists() {
+        let clean = plan.replace("GOAL:", "").trim().to_string();
+        // Ignore errors silently (best effort)
+        let _ = std::fs::write(INTENT_FILE, clean);
+    }
+}
+
+fn clear_intent() {
+    let _ = std::fs::remove_file(files::local(INTENT_FILE));
+}
+
+fn construct_commit_message(current_plan: Option<&str>) -> String {
+    let current = current_plan
+        .unwrap_or("Automated update")
+        .replace("GOAL:", "")
+        .trim()
+        .to_string();
+
+    if let Ok(stored) = std::fs::read_to_string(files::local(INTENT_FILE)) {
+        let stored = stored.trim();
+        if !stored.is_empty() && stored != current {
+            return format!("{stored}\n\nFollow-up: {current}");
+        }
+    }
+    current
+}
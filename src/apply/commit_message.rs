@@ -0,0 +1,51 @@
+// src/apply/commit_message.rs
+//! `[git] commit_template`: the commit message built from the plan.
+//!
+//! Placeholders are `{prefix}` (`commit_prefix`), `{summary}` (the plan's
+//! first line), `{body}` (the rest), `{plan}` (all of it) and `{task}` (the
+//! roadmap tasks the apply checked off). A line whose placeholders all come
+//! out empty is dropped, so `Refs: {task}` vanishes when no task was checked.
+
+use crate::roadmap_v2::{parse_commands, RoadmapCommand};
+
+/// Fills `template` for a commit of `plan`.
+#[must_use]
+pub fn render(template: &str, plan: &str, prefix: &str, tasks: &[String]) -> String {
+    let plan = plan.trim();
+    let (summary, body) = plan.split_once('\n').unwrap_or((plan, ""));
+    let values = [
+        ("{prefix}", prefix.to_string()),
+        ("{summary}", summary.trim().to_string()),
+        ("{body}", body.trim().to_string()),
+        ("{plan}", plan.to_string()),
+        ("{task}", tasks.join(", ")),
+    ];
+
+    let mut lines: Vec<String> = Vec::new();
+    for line in template.lines() {
+        let used: Vec<_> = values.iter().filter(|(k, _)| line.contains(k)).collect();
+        if !used.is_empty() && used.iter().all(|(_, v)| v.is_empty()) {
+            continue;
+        }
+        let filled = used
+            .iter()
+            .fold(line.to_string(), |acc, (k, v)| acc.replace(k, v));
+        if !(filled.trim().is_empty() && lines.last().is_some_and(|l| l.trim().is_empty())) {
+            lines.push(filled);
+        }
+    }
+    lines.join("\n").trim().to_string()
+}
+
+/// Ids of the roadmap tasks checked off in `content`.
+#[must_use]
+pub fn task_ids(content: &str) -> Vec<String> {
+    parse_commands(content)
+        .unwrap_or_default()
+        .into_iter()
+        .filter_map(|cmd| match cmd {
+            RoadmapCommand::Check { id } => Some(id),
+            _ => None,
+        })
+        .collect()
+}
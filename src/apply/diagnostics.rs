@@ -0,0 +1,115 @@
+// src/apply/diagnostics.rs
+//! Structured `cargo` diagnostics for the verification log. Compile commands
+//! run with `--message-format=json`, and each error or warning is reported
+//! as `file:line: level: message` instead of the raw compiler text.
+
+use serde_json::Value;
+use std::fmt::Write;
+
+/// Cargo subcommands that accept `--message-format`.
+const JSON_SUBCOMMANDS: &[&str] = &["build", "check", "clippy", "test"];
+
+/// One compiler error or warning.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Diagnostic {
+    /// Primary span as `(file, line)`; `None` for crate-level messages.
+    pub location: Option<(String, usize)>,
+    pub level: String,
+    pub message: String,
+}
+
+impl std::fmt::Display for Diagnostic {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let Some((file, line)) = &self.location {
+            write!(f, "{file}:{line}: ")?;
+        }
+        write!(f, "{}: {}", self.level, self.message)
+    }
+}
+
+/// Cargo output split into its JSON diagnostics and everything else.
+#[derive(Debug, Default)]
+pub struct Parsed {
+    pub diagnostics: Vec<Diagnostic>,
+    /// The diagnostics as the compiler would have printed them.
+    pub rendered: String,
+    /// Lines that weren't JSON, such as test output.
+    pub other: String,
+}
+
+impl Parsed {
+    /// The log for the AI: one line per diagnostic, then the other output.
+    #[must_use]
+    pub fn summary(&self) -> String {
+        let mut out = String::new();
+        if !self.diagnostics.is_empty() {
+            out.push_str("DIAGNOSTICS:\n");
+        }
+        for d in &self.diagnostics {
+            let _ = writeln!(out, "{d}");
+        }
+        out + &self.other
+    }
+}
+
+/// Adds `--message-format=json` to `args` if they run a cargo compile
+/// command without a message format of its own, returning whether it did.
+pub fn with_json(args: &mut Vec<String>) -> bool {
+    if args.first().map(String::as_str) != Some("cargo")
+        || args.iter().any(|a| a.starts_with("--message-format"))
+    {
+        return false;
+    }
+    let Some(sub) = args.iter().skip(1).position(|t| !t.starts_with('+')) else {
+        return false;
+    };
+    if !JSON_SUBCOMMANDS.contains(&args[sub + 1].as_str()) {
+        return false;
+    }
+    args.insert(sub + 2, "--message-format=json".to_string());
+    true
+}
+
+/// Splits cargo's JSON `stdout` into diagnostics and the remaining lines.
+#[must_use]
+pub fn parse(stdout: &str) -> Parsed {
+    let mut parsed = Parsed::default();
+    for line in stdout.lines() {
+        let Ok(value) = serde_json::from_str::<Value>(line) else {
+            parsed.other.push_str(line);
+            parsed.other.push('\n');
+            continue;
+        };
+        if value["reason"] != "compiler-message" {
+            continue;
+        }
+        let message = &value["message"];
+        if let Some(rendered) = message["rendered"].as_str() {
+            parsed.rendered.push_str(rendered);
+        }
+        if let Some(d) = diagnostic(message) {
+            parsed.diagnostics.push(d);
+        }
+    }
+    parsed
+}
+
+/// Errors and warnings only; notes and the closing summaries are skipped.
+fn diagnostic(message: &Value) -> Option<Diagnostic> {
+    let level = message["level"].as_str()?;
+    let text = message["message"].as_str()?;
+    let summary = text.starts_with("aborting due to") || text.ends_with(" emitted");
+    if !matches!(level, "error" | "warning") || summary {
+        return None;
+    }
+    let location = message["spans"].as_array().and_then(|spans| {
+        let span = spans.iter().find(|s| s["is_primary"] == true)?;
+        let line = usize::try_from(span["line_start"].as_u64()?).ok()?;
+        Some((span["file_name"].as_str()?.to_string(), line))
+    });
+    Some(Diagnostic {
+        location,
+        level: level.to_string(),
+        message: text.to_string(),
+    })
+}
@@ -0,0 +1,82 @@
+// src/apply/diff.rs
+//! Line diffs for showing proposed changes.
+
+/// Larger inputs skip the LCS table and show a full replacement.
+const MAX_CELLS: usize = 4_000_000;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiffLine<'a> {
+    Same(&'a str),
+    Removed(&'a str),
+    Added(&'a str),
+}
+
+/// Line diff of `old` to `new` via longest common subsequence.
+#[must_use]
+pub fn diff_lines<'a>(old: &'a str, new: &'a str) -> Vec<DiffLine<'a>> {
+    let a: Vec<&str> = old.lines().collect();
+    let b: Vec<&str> = new.lines().collect();
+    if (a.len() + 1) * (b.len() + 1) > MAX_CELLS {
+        let removed = a.iter().map(|l| DiffLine::Removed(l));
+        return removed
+            .chain(b.iter().map(|l| DiffLine::Added(l)))
+            .collect();
+    }
+    let lcs = Lcs::build(a, b);
+    let (mut i, mut j, mut out) = (0, 0, Vec::new());
+    while i < lcs.a.len() || j < lcs.b.len() {
+        let line = lcs.next(i, j);
+        match line {
+            DiffLine::Same(_) => (i, j) = (i + 1, j + 1),
+            DiffLine::Removed(_) => i += 1,
+            DiffLine::Added(_) => j += 1,
+        }
+        out.push(line);
+    }
+    out
+}
+
+/// Suffix LCS lengths: `len(i, j)` is the LCS of `a[i..]` and `b[j..]`.
+struct Lcs<'a> {
+    a: Vec<&'a str>,
+    b: Vec<&'a str>,
+    table: Vec<u32>,
+}
+
+impl<'a> Lcs<'a> {
+    fn build(a: Vec<&'a str>, b: Vec<&'a str>) -> Self {
+        let width = b.len() + 1;
+        let mut table = vec![0u32; (a.len() + 1) * width];
+        for i in (0..a.len()).rev() {
+            for j in (0..b.len()).rev() {
+                table[i * width + j] = if a[i] == b[j] {
+                    table[(i + 1) * width + j + 1] + 1
+                } else {
+                    table[(i + 1) * width + j].max(table[i * width + j + 1])
+                };
+            }
+        }
+        Self { a, b, table }
+    }
+
+    fn len(&self, i: usize, j: usize) -> u32 {
+        self.table[i * (self.b.len() + 1) + j]
+    }
+
+    /// The diff line at position (`i`, `j`), preferring removals first.
+    fn next(&self, i: usize, j: usize) -> DiffLine<'a> {
+        if i == self.a.len() {
+            return DiffLine::Added(self.b[j]);
+        }
+        if j == self.b.len() {
+            return DiffLine::Removed(self.a[i]);
+        }
+        if self.a[i] == self.b[j] {
+            DiffLine::Same(self.a[i])
+        } else if self.len(i + 1, j) >= self.len(i, j + 1) {
+            DiffLine::Removed(self.a[i])
+        } else {
+            DiffLine::Added(self.b[j])
+        }
+    }
+}
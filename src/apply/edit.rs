@@ -0,0 +1,129 @@
+// src/apply/edit.rs
+//! Search/replace edit blocks: partial edits anchored on existing text.
+//!
+//! ```text
+//! #__SLOPCHOP_EDIT__# src/lib.rs
+//! <<<<<<< SEARCH
+//! old lines
+//! =======
+//! new lines
+//! >>>>>>> REPLACE
+//! #__SLOPCHOP_END__#
+//! ```
+
+use std::collections::BTreeMap;
+
+const SEARCH: &str = "<<<<<<< SEARCH";
+const DIVIDER: &str = "=======";
+const REPLACE: &str = ">>>>>>> REPLACE";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EditBlock {
+    pub path: String,
+    pub search: String,
+    pub replace: String,
+}
+
+/// Parses the SEARCH/REPLACE pairs in one edit block's body. Pairs missing
+/// a divider or closing marker are dropped.
+#[must_use]
+pub fn parse_body(path: &str, body: &str) -> Vec<EditBlock> {
+    let mut out = Vec::new();
+    let mut lines = body.lines();
+    while lines.any(|l| l.trim_end() == SEARCH) {
+        let search: Vec<&str> = lines
+            .by_ref()
+            .take_while(|l| l.trim_end() != DIVIDER)
+            .collect();
+        let mut closed = false;
+        let replace: Vec<&str> = lines
+            .by_ref()
+            .take_while(|l| {
+                closed = l.trim_end() == REPLACE;
+                !closed
+            })
+            .collect();
+        if closed {
+            out.push(EditBlock {
+                path: path.to_string(),
+                search: search.join("\n"),
+                replace: replace.join("\n"),
+            });
+        }
+    }
+    out
+}
+
+/// Edits grouped by file, each group in payload order.
+#[must_use]
+pub fn by_path(edits: &[EditBlock]) -> BTreeMap<&str, Vec<&EditBlock>> {
+    let mut groups: BTreeMap<&str, Vec<&EditBlock>> = BTreeMap::new();
+    for e in edits {
+        groups.entry(e.path.as_str()).or_default().push(e);
+    }
+    groups
+}
+
+/// Applies `edits` in order. Each SEARCH must match exactly once, first
+/// verbatim, then ignoring leading and trailing whitespace on each line.
+///
+/// # Errors
+/// Returns a message for the AI naming the block and what to fix when an
+/// anchor is empty, missing, or ambiguous.
+pub fn apply(content: &str, edits: &[&EditBlock]) -> Result<String, String> {
+    let mut current = content.to_string();
+    for (i, e) in edits.iter().enumerate() {
+        let which = format!("SEARCH block {} for {}", i + 1, e.path);
+        if e.search.trim().is_empty() {
+            return Err(format!("{which} is empty; quote the lines to replace."));
+        }
+        let range = match find(&current, &e.search) {
+            Ok(range) => range,
+            Err(0) => {
+                return Err(format!(
+                    "{which} was not found; copy the lines exactly from the current file."
+                ))
+            }
+            Err(n) => {
+                return Err(format!(
+                    "{which} matches {n} places; add surrounding lines so it matches once."
+                ))
+            }
+        };
+        current.replace_range(range.clone(), &replacement(&current[range], &e.replace));
+    }
+    Ok(current)
+}
+
+/// The byte range of the single match, or `Err(count)`.
+fn find(content: &str, search: &str) -> Result<std::ops::Range<usize>, usize> {
+    let exact: Vec<usize> = content.match_indices(search).map(|(i, _)| i).collect();
+    match exact.as_slice() {
+        [at] => return Ok(*at..*at + search.len()),
+        [] => {}
+        many => return Err(many.len()),
+    }
+    let lines: Vec<&str> = content.split_inclusive('\n').collect();
+    let wanted: Vec<&str> = search.lines().map(str::trim).collect();
+    let hits: Vec<usize> = (0..lines.len().saturating_sub(wanted.len() - 1))
+        .filter(|&at| {
+            let window = lines[at..].iter().zip(&wanted);
+            window.into_iter().all(|(a, b)| a.trim() == *b)
+        })
+        .collect();
+    let [at] = hits.as_slice() else {
+        return Err(hits.len());
+    };
+    let start: usize = lines[..*at].iter().map(|l| l.len()).sum();
+    let len: usize = lines[*at..*at + wanted.len()].iter().map(|l| l.len()).sum();
+    Ok(start..start + len)
+}
+
+/// Keeps the matched text's trailing newline when the replacement lacks one.
+fn replacement(matched: &str, replace: &str) -> String {
+    if matched.ends_with('\n') && !replace.is_empty() && !replace.ends_with('\n') {
+        format!("{replace}\n")
+    } else {
+        replace.to_string()
+    }
+}
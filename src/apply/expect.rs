@@ -0,0 +1,71 @@
+// src/apply/expect.rs
+//! Per-file assertions in the manifest: `src/lib.rs [lines:120]` and
+//! `src/lib.rs [sha256:9f86d081]`. They catch clipboard corruption and a
+//! payload cut off mid-file, which truncation markers can't.
+
+use super::manifest;
+use anyhow::Result;
+use regex::Regex;
+use sha2::{Digest, Sha256};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+
+/// What the manifest says a file's final content looks like.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Expectation {
+    pub lines: Option<usize>,
+    /// Leading hex digits of the content's SHA-256.
+    pub sha256: Option<String>,
+}
+
+impl Expectation {
+    /// Describes how `content` for `path` differs from the expectation.
+    #[must_use]
+    pub fn mismatch(&self, path: &str, content: &str) -> Option<String> {
+        let actual = content.lines().count();
+        if let Some(lines) = self.lines.filter(|&n| n != actual) {
+            return Some(format!(
+                "{path} has {actual} lines but the manifest expects {lines}; the payload may be cut off"
+            ));
+        }
+        let digest = sha256_hex(content);
+        let prefix = self.sha256.as_deref()?;
+        (!digest.starts_with(&prefix.to_lowercase())).then(|| {
+            format!(
+                "{path} has SHA-256 {} but the manifest expects {prefix}; the payload may be corrupted",
+                &digest[..prefix.len().min(digest.len())]
+            )
+        })
+    }
+}
+
+/// Maps manifest paths to the line count and hash declared for them.
+///
+/// # Errors
+/// Returns error if regex compilation fails.
+pub fn parse(response: &str) -> Result<HashMap<String, Expectation>> {
+    let lines_re = Regex::new(r"(?i)\[lines:\s*(\d+)\]")?;
+    let sha_re = Regex::new(r"(?i)\[sha256:\s*([0-9a-f]+)\]")?;
+    let mut expectations = HashMap::new();
+    for (path, line) in manifest::tagged_lines(response)? {
+        let expectation = Expectation {
+            lines: lines_re.captures(&line).and_then(|c| c[1].parse().ok()),
+            sha256: sha_re.captures(&line).map(|c| c[1].to_string()),
+        };
+        if expectation != Expectation::default() {
+            expectations.insert(path, expectation);
+        }
+    }
+    Ok(expectations)
+}
+
+/// Lowercase hex SHA-256 of `content`.
+#[must_use]
+pub fn sha256_hex(content: &str) -> String {
+    Sha256::digest(content.as_bytes())
+        .iter()
+        .fold(String::new(), |mut out, b| {
+            let _ = write!(out, "{b:02x}");
+            out
+        })
+}
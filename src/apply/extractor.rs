@@ -1,8 +1,12 @@
 // src/apply/extractor.rs
-use crate::apply::types::FileContent;
-use anyhow::Result;
+use crate::apply::edit::{self, EditBlock};
+use crate::apply::types::{ExtractedFiles, FileContent};
+use crate::apply::{manifest, patch};
+use anyhow::{Context, Result};
 use regex::Regex;
 use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
 
 /// Extracts the optional PLAN block.
 #[must_use]
@@ -47,7 +51,8 @@ fn process_block(
     footer_re: &Regex,
     files: &mut HashMap<String, FileContent>,
 ) -> usize {
-    let raw_path = path.unwrap_or_default().trim().to_string();
+    let raw = path.unwrap_or_default();
+    let raw_path = manifest::split_base(&raw).0.to_string();
 
     // Skip MANIFEST and PLAN blocks (don't write them to disk)
     if raw_path == "MANIFEST" || raw_path == "PLAN" || raw_path.is_empty() {
@@ -85,4 +90,62 @@ fn skip_block(response: &str, start_pos: usize, footer_re: &Regex) -> usize {
 
 fn clean_block_content(raw: &str) -> String {
     raw.trim_matches('\n').to_string()
-}
\ No newline at end of file
+}
+
+/// Extracts whole-file blocks plus ```` ```diff ```` blocks outside them,
+/// resolving each diff against the extracted or on-disk (under `root`)
+/// version of its file.
+///
+/// # Errors
+/// Returns error if a diff is malformed, targets a missing file, or has a
+/// hunk that does not match.
+pub fn extract_changes(response: &str, root: Option<&Path>) -> Result<ExtractedFiles> {
+    let mut files = extract_files(response)?;
+    let block_re = Regex::new(r"(?ms)^#__SLOPCHOP_FILE__#.*?^#__SLOPCHOP_END__#")?;
+    let outside = block_re.replace_all(response, "");
+    let fence_re = Regex::new(r"(?ms)^```diff[ \t]*\n(.*?)^```[ \t]*$")?;
+    for caps in fence_re.captures_iter(&outside) {
+        let diff = caps.get(1).map_or("", |m| m.as_str());
+        for p in patch::parse(diff)? {
+            let original = match files.get(&p.path) {
+                Some(f) => f.content.clone(),
+                None => read_original(&p, root)?,
+            };
+            let content = patch::apply(&original, &p)?;
+            let line_count = content.lines().count();
+            files.insert(
+                p.path,
+                FileContent {
+                    content,
+                    line_count,
+                },
+            );
+        }
+    }
+    Ok(files)
+}
+
+fn read_original(p: &patch::FilePatch, root: Option<&Path>) -> Result<String> {
+    if p.is_new {
+        return Ok(String::new());
+    }
+    let path = root.map_or_else(|| Path::new(&p.path).to_path_buf(), |r| r.join(&p.path));
+    fs::read_to_string(&path).with_context(|| format!("Cannot patch {}", p.path))
+}
+
+/// Extracts search/replace edits from `#__SLOPCHOP_EDIT__#` blocks.
+///
+/// # Errors
+/// Returns error if regex compilation fails.
+pub fn extract_edits(response: &str) -> Result<Vec<EditBlock>> {
+    let block_re =
+        Regex::new(r"(?ms)^#__SLOPCHOP_EDIT__#[ \t]*(\S+)[ \t]*\n(.*?)^#__SLOPCHOP_END__#")?;
+    Ok(block_re
+        .captures_iter(response)
+        .flat_map(|caps| {
+            let path = caps.get(1).map_or("", |m| m.as_str());
+            let body = caps.get(2).map_or("", |m| m.as_str());
+            edit::parse_body(path, body)
+        })
+        .collect())
+}
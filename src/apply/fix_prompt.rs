@@ -0,0 +1,76 @@
+// src/apply/fix_prompt.rs
+//! The follow-up prompt copied after a failed verification: the goal, the
+//! failure log, and the current contents of the files that failed, with
+//! secrets redacted as `pack` does.
+
+use super::manifest;
+use crate::pack::redact::redact;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+/// Builds a ready-to-paste prompt asking the AI to fix the failed apply.
+///
+/// `goal` is the original plan; `written` are the files the apply wrote,
+/// relative to `root`.
+#[must_use]
+pub fn build(goal: Option<&str>, failure_log: &str, written: &[String], root: &Path) -> String {
+    let mut out =
+        String::from("The changes were applied, but post-application verification failed.\n\n");
+    if let Some(goal) = goal.map(str::trim).filter(|g| !g.is_empty()) {
+        let _ = writeln!(
+            out,
+            "ORIGINAL GOAL:\n{}\n",
+            goal.replace("GOAL:", "").trim()
+        );
+    }
+    let (log, _) = redact(failure_log.trim());
+    let _ = writeln!(out, "FAILURE LOG:\n{log}\n");
+
+    let files = failing_files(failure_log, written);
+    if !files.is_empty() {
+        out.push_str("CURRENT FILES:\n\n");
+    }
+    for path in files {
+        if let Ok(content) = fs::read_to_string(root.join(&path)) {
+            let base = manifest::base_hash(&content);
+            let (content, _) = redact(&content);
+            let _ = writeln!(
+                out,
+                "#__SLOPCHOP_FILE__# {path} [base:{base}]\n{content}\n#__SLOPCHOP_END__#\n"
+            );
+        }
+    }
+    out.push_str(
+        "Please fix the implementation so that checks pass, replying with the corrected \
+         files using #__SLOPCHOP_FILE__# path ... #__SLOPCHOP_END__#",
+    );
+    out
+}
+
+/// The written files named in `failure_log`, or all of them if none is.
+/// A path counts only as a whole token, so `a.rs` is not named by `data.rs`.
+#[must_use]
+pub fn failing_files(failure_log: &str, written: &[String]) -> Vec<String> {
+    let tokens: Vec<String> = failure_log
+        .split(|c: char| c.is_whitespace() || "\"'`()[],:;<>".contains(c))
+        .map(|t| t.replace('\\', "/"))
+        .collect();
+    let named: Vec<String> = written
+        .iter()
+        .filter(|path| tokens.iter().any(|t| names(t, path)))
+        .cloned()
+        .collect();
+    if named.is_empty() {
+        written.to_vec()
+    } else {
+        named
+    }
+}
+
+/// True if the log token `token` is `path`, on its own or under a directory.
+fn names(token: &str, path: &str) -> bool {
+    token
+        .strip_suffix(path)
+        .is_some_and(|dir| dir.is_empty() || dir.ends_with('/'))
+}
@@ -0,0 +1,73 @@
+// src/apply/format.rs
+//! Formats applied files with the command configured for their extension
+//! (`auto_format` and `[preferences.formatters]`).
+
+use crate::apply::preview;
+use crate::config::Preferences;
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+
+/// Runs each formatter once over the `written` files with its extension.
+/// Returns a message per formatter that failed; formatting never fails an
+/// apply.
+#[must_use]
+pub fn format_files(prefs: &Preferences, written: &[String]) -> Vec<String> {
+    if !prefs.auto_format {
+        return Vec::new();
+    }
+    let mut batches: BTreeMap<&str, Vec<&str>> = BTreeMap::new();
+    for path in written {
+        let ext = Path::new(path).extension().and_then(|e| e.to_str());
+        if let Some(cmd) = ext.and_then(|e| prefs.formatters.get(e)) {
+            batches.entry(cmd).or_default().push(path);
+        }
+    }
+    batches
+        .into_iter()
+        .filter_map(|(cmd, files)| run(cmd, &files).err())
+        .collect()
+}
+
+/// [`format_files`], plus a diff of every file the formatters changed: the
+/// preview and review showed the files as sent, not as formatted.
+#[must_use]
+pub fn format_with_diff(prefs: &Preferences, written: &[String]) -> (Vec<String>, String) {
+    if !prefs.auto_format {
+        return (Vec::new(), String::new());
+    }
+    let before: Vec<String> = written
+        .iter()
+        .map(|p| fs::read_to_string(p).unwrap_or_default())
+        .collect();
+    let failures = format_files(prefs, written);
+    let mut diff = String::new();
+    for (path, old) in written.iter().zip(before) {
+        let new = fs::read_to_string(path).unwrap_or_default();
+        if new != old {
+            let heading = format!("{} {}", "FORMATTED".cyan().bold(), path.bold());
+            diff.push_str(&preview::render_change(&heading, &old, &new));
+        }
+    }
+    (failures, diff)
+}
+
+fn run(cmd: &str, files: &[&str]) -> Result<(), String> {
+    let parts: Vec<&str> = cmd.split_whitespace().collect();
+    let Some((prog, args)) = parts.split_first() else {
+        return Ok(());
+    };
+    let output = Command::new(prog)
+        .args(args)
+        .args(files)
+        .output()
+        .map_err(|e| format!("{cmd}: {e}"))?;
+    if output.status.success() {
+        return Ok(());
+    }
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let first = stderr.lines().next().unwrap_or("failed").trim();
+    Err(format!("{cmd}: {first}"))
+}
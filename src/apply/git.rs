@@ -1,4 +1,5 @@
 // src/apply/git.rs
+use crate::config::GitSettings;
 use anyhow::{anyhow, Result};
 use colored::Colorize;
 use std::process::Command;
@@ -6,8 +7,9 @@ use std::process::Command;
 /// Stages all files, commits with the provided message, and pushes.
 ///
 /// # Errors
-/// Returns error if git commands fail.
-pub fn commit_and_push(message: &str) -> Result<()> {
+/// Returns error if git commands fail, with a hint at the signing setup
+/// when a signed commit does.
+pub fn commit_and_push(message: &str, settings: &GitSettings) -> Result<()> {
     // 1. Git Add All
     run_git(&["add", "."])?;
 
@@ -23,7 +25,9 @@ pub fn commit_and_push(message: &str) -> Result<()> {
 
     // 3. Git Commit
     let final_message = clean_message(message);
-    run_git(&["commit", "-m", &final_message])?;
+    let args = commit_args(&final_message, settings);
+    run_git(&args.iter().map(String::as_str).collect::<Vec<_>>())
+        .map_err(|e| signing_hint(e, settings))?;
     println!(
         "{} {}",
         "Git Commit:".green(),
@@ -38,6 +42,93 @@ pub fn commit_and_push(message: &str) -> Result<()> {
     Ok(())
 }
 
+/// The `git commit` arguments for `message` under `settings`.
+#[must_use]
+pub fn commit_args(message: &str, settings: &GitSettings) -> Vec<String> {
+    let mut args = vec!["commit".to_string()];
+    if settings.sign {
+        args.push("-S".to_string());
+    }
+    if settings.signoff {
+        args.push("--signoff".to_string());
+    }
+    args.extend(["-m".to_string(), message.to_string()]);
+    args
+}
+
+/// Points a failed signed commit at the signing setup.
+fn signing_hint(err: anyhow::Error, settings: &GitSettings) -> anyhow::Error {
+    if settings.sign {
+        anyhow!(
+            "{err}\nSigning failed; check `git config user.signingkey` and `gpg.format`, \
+             that your key is unlocked, or turn [git] sign off"
+        )
+    } else {
+        err
+    }
+}
+
+/// Returns the full hash of `HEAD`.
+///
+/// # Errors
+/// Returns error if git fails or there is no commit yet.
+pub fn head() -> Result<String> {
+    let output = Command::new("git").args(["rev-parse", "HEAD"]).output()?;
+    if !output.status.success() {
+        return Err(anyhow!("No HEAD commit"));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// Opens a draft pull (or merge) request for the pushed branch with `gh`,
+/// or `glab` when `origin` is on GitLab, returning the tool's output.
+///
+/// # Errors
+/// Returns error if the tool is missing or refuses, e.g. on the default branch.
+pub fn create_draft_pr(title: &str, body: &str) -> Result<String> {
+    let remote = Command::new("git")
+        .args(["remote", "get-url", "origin"])
+        .output()?;
+    let tool = pr_tool(&String::from_utf8_lossy(&remote.stdout));
+    let body_flag = if tool == "glab" {
+        "--description"
+    } else {
+        "--body"
+    };
+    let sub = if tool == "glab" { "mr" } else { "pr" };
+    let output = Command::new(tool)
+        .args([sub, "create", "--draft", "--title", title, body_flag, body])
+        .output()
+        .map_err(|e| anyhow!("Could not run {tool}: {e}"))?;
+    if !output.status.success() {
+        let err = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("{tool} {sub} create failed: {}", err.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+/// The CLI that opens review requests on `remote_url`'s host.
+#[must_use]
+pub fn pr_tool(remote_url: &str) -> &'static str {
+    if remote_url.contains("gitlab") {
+        "glab"
+    } else {
+        "gh"
+    }
+}
+
+/// The draft request description: the plan, then the verification log
+/// folded away.
+#[must_use]
+pub fn pr_body(plan: &str, verification_log: &str) -> String {
+    let plan = clean_message(plan);
+    let log = verification_log.trim();
+    if log.is_empty() {
+        return plan;
+    }
+    format!("{plan}\n\n<details><summary>Verification log</summary>\n\n```\n{log}\n```\n</details>")
+}
+
 fn run_git(args: &[&str]) -> Result<()> {
     let output = Command::new("git").args(args).output()?;
 
@@ -0,0 +1,92 @@
+// src/apply/input.rs
+//! Where `apply` reads the AI response from.
+//!
+//! `--url` runs the `curl` program, which must be on `PATH`.
+
+use super::multi;
+use crate::clipboard;
+use anyhow::{anyhow, Context, Result};
+use std::fs;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Seconds `--url` waits for the whole response.
+const FETCH_TIMEOUT_SECS: &str = "30";
+
+/// Source of the payload handed to `apply`.
+#[derive(Debug, Clone, Default)]
+pub enum InputSource {
+    #[default]
+    Clipboard,
+    /// A response split over several messages, copied one at a time.
+    ClipboardParts,
+    File(PathBuf),
+    Stdin,
+    Url(String),
+}
+
+impl InputSource {
+    /// True if prompts can't be answered because stdin holds the payload.
+    #[must_use]
+    pub fn uses_stdin(&self) -> bool {
+        matches!(self, Self::Stdin)
+    }
+}
+
+/// Reads the payload from `source`.
+///
+/// # Errors
+/// Returns error if the clipboard, file, stdin, or URL cannot be read.
+pub fn read(source: &InputSource) -> Result<String> {
+    match source {
+        InputSource::Clipboard => clipboard::read_clipboard().context("Failed to read clipboard"),
+        InputSource::ClipboardParts => multi::read_parts(
+            || clipboard::read_clipboard().context("Failed to read clipboard"),
+            &mut io::stdin().lock(),
+        ),
+        InputSource::File(path) => {
+            fs::read_to_string(path).with_context(|| format!("Failed to read {}", path.display()))
+        }
+        InputSource::Stdin => {
+            let mut content = String::new();
+            io::stdin()
+                .read_to_string(&mut content)
+                .context("Failed to read stdin")?;
+            Ok(content)
+        }
+        InputSource::Url(url) => fetch(url),
+    }
+}
+
+/// Points gist page links at their raw content.
+#[must_use]
+pub fn raw_url(url: &str) -> String {
+    let trimmed = url.trim_end_matches('/');
+    if trimmed.contains("://gist.github.com/") && !trimmed.ends_with("/raw") {
+        format!("{trimmed}/raw")
+    } else {
+        url.to_string()
+    }
+}
+
+/// Fetches over HTTPS only, redirects included, so the payload can't be
+/// swapped in transit.
+fn fetch(url: &str) -> Result<String> {
+    let url = raw_url(url);
+    if !url.starts_with("https://") {
+        return Err(anyhow!(
+            "Refusing to fetch {url}: only https:// URLs are allowed"
+        ));
+    }
+    let output = Command::new("curl")
+        .args(["-fsSL", "--proto", "=https", "--proto-redir", "=https"])
+        .args(["--max-time", FETCH_TIMEOUT_SECS, &url])
+        .output()
+        .context("Failed to run curl, which --url needs on PATH")?;
+    if !output.status.success() {
+        let err = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("Failed to fetch {url}: {}", err.trim()));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).into_owned())
+}
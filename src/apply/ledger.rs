@@ -0,0 +1,177 @@
+// src/apply/ledger.rs
+//! Apply history: one JSON line per apply in `.slopchop_apply_history.jsonl`,
+//! an audit trail of what the AI sent and what came of it: written and
+//! verified, written but failing, rejected, previewed, or cancelled.
+
+use super::types::ApplyOutcome;
+use crate::time;
+use anyhow::Result;
+use colored::{ColoredString, Colorize};
+use serde::{Deserialize, Serialize};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+pub const LEDGER_FILE: &str = ".slopchop_apply_history.jsonl";
+
+/// What came of an apply.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    /// Written, and verification passed.
+    Passed,
+    /// Written, but verification or a post-apply hook failed.
+    Failed,
+    /// Refused before anything was written.
+    Rejected,
+    /// Writing the files failed.
+    WriteError,
+    /// Validated and previewed with `--dry-run`.
+    DryRun,
+    /// Declined at the plan prompt or during review.
+    Cancelled,
+}
+
+/// One apply.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ApplyRecord {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    pub plan: Option<String>,
+    pub status: Status,
+    pub written: Vec<String>,
+    pub deleted: Vec<String>,
+    /// Why it was rejected or could not be written.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub errors: Vec<String>,
+    pub commit: Option<String>,
+}
+
+impl ApplyRecord {
+    /// Describes `outcome`, stamped with the current time. `verified` only
+    /// matters for a successful write.
+    #[must_use]
+    pub fn new(plan: Option<&str>, outcome: &ApplyOutcome, verified: bool) -> Self {
+        let mut record = Self {
+            timestamp: time::now(),
+            plan: plan.map(|p| p.trim().to_string()),
+            status: Status::Rejected,
+            written: Vec::new(),
+            deleted: Vec::new(),
+            errors: Vec::new(),
+            commit: None,
+        };
+        match outcome {
+            ApplyOutcome::Success {
+                written, deleted, ..
+            } => {
+                record.status = if verified {
+                    Status::Passed
+                } else {
+                    Status::Failed
+                };
+                record.written.clone_from(written);
+                record.deleted.clone_from(deleted);
+            }
+            ApplyOutcome::ValidationFailure { errors, .. } => record.errors.clone_from(errors),
+            ApplyOutcome::ParseError(e) => record.errors = vec![e.clone()],
+            ApplyOutcome::WriteError(e) => {
+                record.status = Status::WriteError;
+                record.errors = vec![e.clone()];
+            }
+            ApplyOutcome::DryRun => record.status = Status::DryRun,
+            ApplyOutcome::Cancelled => record.status = Status::Cancelled,
+        }
+        record
+    }
+
+    /// Appends this record as one JSON line.
+    ///
+    /// # Errors
+    /// Returns error if the ledger cannot be written.
+    pub fn append(&self, path: &Path) -> Result<()> {
+        let mut file = OpenOptions::new().create(true).append(true).open(path)?;
+        writeln!(file, "{}", serde_json::to_string(self)?)?;
+        Ok(())
+    }
+}
+
+/// Logs an apply to the ledger in the current directory.
+///
+/// # Errors
+/// Returns error if the ledger cannot be written.
+pub fn record(
+    plan: Option<&str>,
+    outcome: &ApplyOutcome,
+    verified: bool,
+    commit: Option<String>,
+) -> Result<()> {
+    let mut entry = ApplyRecord::new(plan, outcome, verified);
+    entry.commit = commit;
+    entry.append(Path::new(LEDGER_FILE))
+}
+
+/// Reads every record in `path`, oldest first. A missing file is an empty
+/// ledger; unparseable lines are skipped.
+///
+/// # Errors
+/// Returns error if the file exists but cannot be read.
+pub fn load(path: &Path) -> Result<Vec<ApplyRecord>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(fs::read_to_string(path)?
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect())
+}
+
+/// Prints the ledger, newest first (`slopchop apply --history`).
+///
+/// # Errors
+/// Returns error if the ledger cannot be read.
+pub fn print() -> Result<()> {
+    let records = load(Path::new(LEDGER_FILE))?;
+    if records.is_empty() {
+        println!("No applies recorded yet.");
+    }
+    for r in records.iter().rev() {
+        print_record(r);
+    }
+    Ok(())
+}
+
+fn print_record(r: &ApplyRecord) {
+    let status = status_label(r.status);
+    let commit = r.commit.as_deref().map_or("-", |c| c.get(..8).unwrap_or(c));
+    let goal = r
+        .plan
+        .as_deref()
+        .and_then(|p| p.lines().map(str::trim).find(|l| !l.is_empty()))
+        .unwrap_or("(no plan)");
+    println!(
+        "{} {status} {} {goal}",
+        time::date(i64::try_from(r.timestamp).unwrap_or(0)).bold(),
+        commit.dimmed()
+    );
+    for path in &r.written {
+        println!("    {} {path}", "✓".green());
+    }
+    for path in &r.deleted {
+        println!("    {} {path}", "✗".red());
+    }
+    for error in &r.errors {
+        println!("    {}", error.dimmed());
+    }
+}
+
+fn status_label(status: Status) -> ColoredString {
+    match status {
+        Status::Passed => "passed".green(),
+        Status::Failed => "failed".red(),
+        Status::Rejected => "rejected".yellow(),
+        Status::WriteError => "write error".red(),
+        Status::DryRun => "dry run".dimmed(),
+        Status::Cancelled => "cancelled".dimmed(),
+    }
+}
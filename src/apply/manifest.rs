@@ -1,7 +1,13 @@
 // src/apply/manifest.rs
+use crate::analysis::cache::fnv1a;
 use crate::apply::types::{ManifestEntry, Operation};
 use anyhow::Result;
 use regex::Regex;
+use std::collections::HashMap;
+
+/// Marker `pack` puts after each file path, and the AI copies into the
+/// manifest: `src/lib.rs [base:1a2b3c4d]`.
+const BASE_PREFIX: &str = "[base:";
 
 /// Parses the delivery manifest block.
 /// Supports both Legacy XML and `SlopChop` Protocol.
@@ -24,6 +30,59 @@ pub fn parse_manifest(response: &str) -> Result<Option<Vec<ManifestEntry>>> {
     Ok(None)
 }
 
+/// Short hash of the file content the AI was shown.
+#[must_use]
+pub fn base_hash(content: &str) -> String {
+    format!("{:016x}", fnv1a(&[content.as_bytes()]))[..8].to_string()
+}
+
+/// Splits a trailing `[base:HASH]` marker off a path.
+#[must_use]
+pub fn split_base(raw: &str) -> (&str, Option<&str>) {
+    let raw = raw.trim();
+    let Some(at) = raw.rfind(BASE_PREFIX) else {
+        return (raw, None);
+    };
+    let hash = raw[at + BASE_PREFIX.len()..].trim_end_matches(']').trim();
+    (raw[..at].trim_end(), Some(hash).filter(|h| !h.is_empty()))
+}
+
+/// Maps manifest paths to the base hash declared for them, if any.
+///
+/// # Errors
+/// Returns error if regex compilation fails.
+pub fn parse_bases(response: &str) -> Result<HashMap<String, String>> {
+    let mut bases = HashMap::new();
+    for (path, line) in tagged_lines(response)? {
+        if let (_, Some(base)) = split_base(&line) {
+            bases.insert(path, base.to_string());
+        }
+    }
+    Ok(bases)
+}
+
+/// Each manifest line with its path, list markers stripped, for reading the
+/// tags after the path.
+///
+/// # Errors
+/// Returns error if regex compilation fails.
+pub(crate) fn tagged_lines(response: &str) -> Result<Vec<(String, String)>> {
+    let block = match find_slopchop_manifest(response)? {
+        Some(range) => Some(range),
+        None => find_legacy_manifest(response)?,
+    };
+    let Some((start, end)) = block else {
+        return Ok(Vec::new());
+    };
+    let marker_re = Regex::new(r"^\s*(?:[-*]|\d+\.)\s+")?;
+    Ok(response[start..end]
+        .lines()
+        .map(|line| marker_re.replace(line.trim(), "").into_owned())
+        .map(|clean| (extract_clean_path(&clean), clean))
+        .filter(|(path, _)| !path.is_empty())
+        .collect())
+}
+
 fn find_slopchop_manifest(response: &str) -> Result<Option<(usize, usize)>> {
     let open_re = Regex::new(r"#__SLOPCHOP_MANIFEST__#")?;
     let close_re = Regex::new(r"#__SLOPCHOP_END__#")?;
@@ -92,6 +151,9 @@ fn parse_manifest_line(line: &str, marker_re: &Regex) -> Option<ManifestEntry> {
 }
 
 fn parse_operation(line: &str) -> (String, Operation) {
+    if let Some(rename) = parse_rename(line) {
+        return rename;
+    }
     let upper = line.to_uppercase();
 
     if upper.contains("[NEW]") {
@@ -109,6 +171,19 @@ fn parse_operation(line: &str) -> (String, Operation) {
     }
 }
 
+/// Accepts `old -> new [RENAME]`, `[RENAME] old -> new`, and
+/// `[RENAME old -> new]`.
+fn parse_rename(line: &str) -> Option<(String, Operation)> {
+    let tag_re = Regex::new(r"(?i)\[rename\]?").ok()?;
+    if !tag_re.is_match(line) {
+        return None;
+    }
+    let body = tag_re.replace_all(line, "");
+    let (from, to) = body.trim().trim_end_matches(']').split_once("->")?;
+    let to = extract_clean_path(to.trim());
+    Some((from.trim().to_string(), Operation::Rename(to)))
+}
+
 fn extract_clean_path(raw: &str) -> String {
     raw.split_whitespace().next().unwrap_or(raw).to_string()
-}
\ No newline at end of file
+}
@@ -20,13 +20,17 @@ pub fn print_outcome(outcome: &ApplyOutcome) {
         }
         ApplyOutcome::ParseError(e) => println!("{}: {e}", "⚠️  Parse Error".red()),
         ApplyOutcome::WriteError(e) => println!("{}: {e}", "💥 Write Error".red()),
+        ApplyOutcome::DryRun => {
+            println!("\n{}", "Dry run: payload is valid, nothing written.".cyan());
+        }
+        ApplyOutcome::Cancelled => println!("{}", "Cancelled; nothing written.".yellow()),
     }
 }
 
 fn print_success(written: &[String], deleted: &[String], roadmap: &[String], backed_up: bool) {
     println!("{}", "✅ Apply successful!".green().bold());
     if backed_up {
-        println!("   (Backup created in .slopchop_apply_backup/)");
+        println!("   (Backup created in .slopchop_apply_backup/; `slopchop undo` rolls back)");
     }
     println!();
 
@@ -76,11 +80,26 @@ pub fn print_ai_feedback(ai_message: &str) {
     println!("{ai_message}");
     println!("{}", "─".repeat(60).black());
 
+    crate::clipboard::history::record("feedback", ai_message);
     if crate::clipboard::copy_to_clipboard(ai_message).is_ok() {
         println!("{}", "✓ Copied to clipboard".green());
     }
 }
 
+/// Shows the failure log and copies the fix prompt for the AI.
+pub fn print_fix_prompt(failure_log: &str, prompt: &str) {
+    println!();
+    println!("{}", "─".repeat(60).black());
+    println!("{}", failure_log.trim());
+    println!("{}", "─".repeat(60).black());
+
+    crate::clipboard::history::record("fix", prompt);
+    match crate::clipboard::smart_copy(prompt) {
+        Ok(note) => println!("{} {note}", "📋 Fix prompt ready:".cyan().bold()),
+        Err(e) => eprintln!("{} Could not copy fix prompt: {e}", "⚠️".yellow()),
+    }
+}
+
 #[must_use]
 pub fn format_ai_rejection(missing: &[String], errors: &[String]) -> String {
     use std::fmt::Write;
@@ -117,11 +136,3 @@ pub fn format_ai_rejection(missing: &[String], errors: &[String]) -> String {
     );
     msg
 }
-
-#[must_use]
-pub fn format_verification_failure(output: &str) -> String {
-    format!(
-        "The changes were applied, but post-application verification failed.\n\nFAILURE LOG:\n{}\n\nPlease fix the implementation so that checks pass.",
-        output.trim()
-    )
-}
@@ -1,28 +1,54 @@
+pub mod allowlist;
+pub mod commit;
+pub mod commit_message;
+pub mod diagnostics;
+pub mod diff;
+pub mod edit;
+pub mod expect;
 pub mod extractor;
+pub mod fix_prompt;
+pub mod format;
 pub mod git;
+pub mod input;
+pub mod ledger;
 pub mod manifest;
 pub mod messages;
+pub mod multi;
+pub mod patch;
+pub mod placeholders;
+pub mod preserve;
+pub mod preview;
+pub mod review;
+pub mod scope;
+pub mod stripped;
+pub mod syntax;
+pub mod truncation;
 pub mod types;
+pub mod undo;
 pub mod validator;
 pub mod verification;
+pub mod watch;
 pub mod writer;
 
-use crate::clipboard;
+use crate::config::CheckMode;
 use crate::roadmap_v2;
-use anyhow::{Context, Result};
+use anyhow::Result;
 use colored::Colorize;
+use preserve::WriteOptions;
 use std::io::{self, Write};
 use std::path::Path;
-use types::{ApplyContext, ApplyOutcome, ExtractedFiles, Manifest};
+use types::{ApplyContext, ApplyOutcome};
 
-const INTENT_FILE: &str = ".slopchop_intent";
-
-/// Runs the apply command logic.
+/// Runs the apply command logic on the payload from `ctx.input`.
 ///
 /// # Errors
-/// Returns error if clipboard access fails.
+/// Returns error if the input cannot be read, or comes from stdin, which
+/// leaves nothing to confirm the plan with, without `--force` or `--dry-run`.
 pub fn run_apply(ctx: &ApplyContext) -> Result<ApplyOutcome> {
-    let content = clipboard::read_clipboard().context("Failed to read clipboard")?;
+    if ctx.input.uses_stdin() && !ctx.skips_confirm() {
+        anyhow::bail!("--stdin can't confirm the plan; pass --force, or --dry-run to preview");
+    }
+    let content = input::read(&ctx.input)?;
     process_input(&content, ctx)
 }
 
@@ -30,38 +56,79 @@ pub fn print_result(outcome: &ApplyOutcome) {
     messages::print_outcome(outcome);
 }
 
-/// Processes input content directly.
+/// Processes input content directly. Applies that write nothing are
+/// logged here; written ones once verified.
 ///
 /// # Errors
 /// Returns error if extraction, write, or git operations fail.
 pub fn process_input(content: &str, ctx: &ApplyContext) -> Result<ApplyOutcome> {
     if content.trim().is_empty() {
-        return Ok(ApplyOutcome::ParseError(
-            "Clipboard/Input is empty".to_string(),
-        ));
+        return Ok(ApplyOutcome::ParseError("Input is empty".to_string()));
     }
 
     let plan_opt = extractor::extract_plan(content);
+    let outcome = process_plan(content, ctx, plan_opt.as_deref())?;
+    if !matches!(outcome, ApplyOutcome::Success { .. }) {
+        if let Err(e) = ledger::record(plan_opt.as_deref(), &outcome, false, None) {
+            eprintln!("{} Could not log apply: {e}", "⚠️".yellow());
+        }
+    }
+    Ok(outcome)
+}
+
+fn process_plan(content: &str, ctx: &ApplyContext, plan_opt: Option<&str>) -> Result<ApplyOutcome> {
+    if !ensure_consent(plan_opt, ctx)? {
+        return Ok(ApplyOutcome::Cancelled);
+    }
 
-    if !ensure_consent(plan_opt.as_deref(), ctx)? {
-        return Ok(ApplyOutcome::ParseError(
-            "Operation cancelled by user.".to_string(),
-        ));
+    if let Some(rejected) = pre_checks(content, ctx) {
+        return Ok(rejected);
     }
 
-    let validation = validate_payload(content);
+    let validation = validator::validate_payload(content, None, &ctx.config.apply.truncation);
     if !matches!(validation, ApplyOutcome::Success { .. }) {
         // Validation failed immediately (bad format/safety)
         // We do NOT persist intent here because the user likely needs to reprompt entirely.
         return Ok(validation);
     }
 
-    apply_and_verify(content, ctx, plan_opt.as_deref())
+    apply_and_verify(content, ctx, plan_opt)
+}
+
+/// Rejects files outside `[apply] allowed_paths`, and files edited since
+/// they were packed or that don't parse unless configured to only warn.
+fn pre_checks(content: &str, ctx: &ApplyContext) -> Option<ApplyOutcome> {
+    let prefs = &ctx.config.preferences;
+    let mut denied = allowlist::violations(content, &ctx.config.apply.allowed_paths);
+    let stale = validator::stale_files(content, None);
+    triage(stale, !prefs.allow_stale_apply, &mut denied);
+    if prefs.syntax_check != CheckMode::Off {
+        let broken = validator::syntax_errors(content, None);
+        triage(broken, prefs.syntax_check == CheckMode::Deny, &mut denied);
+    }
+    if denied.is_empty() {
+        return None;
+    }
+    Some(ApplyOutcome::ValidationFailure {
+        ai_message: messages::format_ai_rejection(&[], &denied),
+        errors: denied,
+        missing: vec![],
+    })
+}
+
+fn triage(problems: Vec<String>, deny: bool, denied: &mut Vec<String>) {
+    if deny {
+        denied.extend(problems);
+        return;
+    }
+    for msg in &problems {
+        eprintln!("{} {msg}", "⚠️".yellow());
+    }
 }
 
 fn ensure_consent(plan: Option<&str>, ctx: &ApplyContext) -> Result<bool> {
     let Some(p) = plan else {
-        if ctx.force || ctx.dry_run {
+        if ctx.skips_confirm() {
             return Ok(true);
         }
         println!(
@@ -76,7 +143,7 @@ fn ensure_consent(plan: Option<&str>, ctx: &ApplyContext) -> Result<bool> {
     println!("{}", p.trim());
     println!("{}", "─".repeat(50).dimmed());
 
-    if ctx.force || ctx.dry_run {
+    if ctx.skips_confirm() {
         return Ok(true);
     }
 
@@ -84,160 +151,62 @@ fn ensure_consent(plan: Option<&str>, ctx: &ApplyContext) -> Result<bool> {
     confirm("Apply these changes?")
 }
 
-fn validate_payload(content: &str) -> ApplyOutcome {
-    let manifest = match parse_manifest_step(content) {
-        Ok(m) => m,
-        Err(e) => return ApplyOutcome::ParseError(e),
-    };
-
-    let extracted = match extract_files_step(content) {
-        Ok(e) => e,
-        Err(e) => return ApplyOutcome::ParseError(e),
-    };
-
-    validator::validate(&manifest, &extracted)
-}
-
 fn apply_and_verify(content: &str, ctx: &ApplyContext, plan: Option<&str>) -> Result<ApplyOutcome> {
-    let extracted = extractor::extract_files(content)?;
-    let manifest = manifest::parse_manifest(content)?.unwrap_or_default();
+    let mut extracted = extractor::extract_changes(content, None)?;
+    let mut edits = extractor::extract_edits(content)?;
+    let mut manifest = manifest::parse_manifest(content)?.unwrap_or_default();
 
     if ctx.dry_run {
-        return Ok(ApplyOutcome::Success {
-            written: vec!["(Dry Run) Files verified".to_string()],
-            deleted: vec![],
-            roadmap_results: vec![],
-            backed_up: false,
-        });
+        print!("{}", preview::render(&manifest, &extracted, &edits)?);
+        return Ok(ApplyOutcome::DryRun);
     }
 
-    let mut outcome = writer::write_files(&manifest, &extracted, None)?;
-
-    // Handle roadmap updates using v2 system
-    // v2 uses slopchop.toml/tasks.toml, but we also support updating if commands are present
-    let roadmap_path = Path::new("slopchop.toml"); 
-    let mut roadmap_results = Vec::new();
-    
-    // We check for roadmap commands regardless of file existence, 
-    // handle_input will check for store existence.
-    match roadmap_v2::handle_input(roadmap_path, content) {
-        Ok(results) => roadmap_results = results,
-        Err(e) => {
-             // If it's just "no commands found" we ignore it, but handle_input returns empty vec
-             // If parsing fails or store load fails, we report it.
-             // We only log if it looks like they tried to do something.
-             if content.contains("===ROADMAP===") {
-                 eprintln!("{} Roadmap update failed: {e}", "⚠️".yellow());
-             }
+    if ctx.review {
+        let input = &mut io::stdin().lock();
+        if !review::review(&mut manifest, &mut extracted, &mut edits, input)? {
+            return Ok(ApplyOutcome::Cancelled);
         }
     }
-    
-    if let ApplyOutcome::Success {
-        roadmap_results: ref mut rr,
-        ..
-    } = outcome
-    {
-        rr.append(&mut roadmap_results);
-    }
-
-    verify_and_commit(&outcome, ctx, plan)?;
-    Ok(outcome)
-}
 
-fn verify_and_commit(outcome: &ApplyOutcome, ctx: &ApplyContext, plan: Option<&str>) -> Result<()> {
-    if !matches!(outcome, ApplyOutcome::Success { .. }) {
-        return Ok(());
-    }
-
-    if !has_changes(outcome) {
-        println!("{}", "No changes detected.".yellow());
-        return Ok(());
-    }
-
-    let (success, log) = verification::verify_application(ctx)?;
-
-    if success {
-        handle_success(plan);
-    } else {
-        let msg = messages::format_verification_failure(&log);
-        handle_failure(plan, &msg);
-    }
-    Ok(())
-}
-
-fn has_changes(outcome: &ApplyOutcome) -> bool {
-    if let ApplyOutcome::Success {
-        written,
-        deleted,
-        roadmap_results,
+    let check_syntax = ctx.config.preferences.syntax_check == CheckMode::Deny;
+    let opts = WriteOptions::new(&ctx.config.apply, check_syntax);
+    let mut outcome = writer::write_all(&manifest, &extracted, &edits, None, &opts)?;
+    let ApplyOutcome::Success {
+        ref mut roadmap_results,
         ..
     } = outcome
-    {
-        !written.is_empty() || !deleted.is_empty() || !roadmap_results.is_empty()
-    } else {
-        false
-    }
-}
-
-fn handle_success(plan: Option<&str>) {
-    println!(
-        "{}",
-        "\n✨ Verification Passed. Committing & Pushing..."
-            .green()
-            .bold()
-    );
-    let message = construct_commit_message(plan);
-    if let Err(e) = git::commit_and_push(&message) {
-        eprintln!("{} Git operation failed: {e}", "⚠️".yellow());
-    } else {
-        clear_intent();
-    }
-}
-
-fn handle_failure(plan: Option<&str>, failure_log: &str) {
-    println!(
-        "{}",
-        "\n❌ Verification Failed. Changes applied but NOT committed."
-            .red()
-            .bold()
-    );
-    println!("Fix the issues manually and then commit.");
-
-    // Auto-copy failure log
-    messages::print_ai_feedback(failure_log);
-
-    if let Some(p) = plan {
-        save_intent(p);
-    }
-}
-
-fn save_intent(plan: &str) {
-    // Only save if no intent exists (preserve the original goal)
-    if !Path::new(INTENT_FILE).exists() {
-        let clean = plan.replace("GOAL:", "").trim().to_string();
-        // Ignore errors silently (best effort)
-        let _ = std::fs::write(INTENT_FILE, clean);
-    }
-}
+    else {
+        return Ok(outcome);
+    };
+    roadmap_results.append(&mut roadmap_updates(content));
 
-fn clear_intent() {
-    let _ = std::fs::remove_file(INTENT_FILE);
+    let tasks = commit_message::task_ids(content);
+    commit::verify_and_commit(&outcome, ctx, plan, &tasks)?;
+    Ok(outcome)
 }
 
-fn construct_commit_message(current_plan: Option<&str>) -> String {
-    let current = current_plan
-        .unwrap_or("Automated update")
-        .replace("GOAL:", "")
-        .trim()
-        .to_string();
+/// Runs any roadmap commands in the response.
+fn roadmap_updates(content: &str) -> Vec<String> {
+    // Handle roadmap updates using v2 system; `slopchop migrate` moves
+    // tasks older versions kept in warden.toml over to tasks.toml.
+    let roadmap_path = Path::new(roadmap_v2::store::DEFAULT_PATH);
+    let mut roadmap_results = Vec::new();
 
-    if let Ok(stored) = std::fs::read_to_string(INTENT_FILE) {
-        let stored = stored.trim();
-        if !stored.is_empty() && stored != current {
-            return format!("{stored}\n\nFollow-up: {current}");
+    // We check for roadmap commands regardless of file existence,
+    // handle_input will check for store existence.
+    match roadmap_v2::handle_input(roadmap_path, content) {
+        Ok(results) => roadmap_results = results,
+        Err(e) => {
+            // If it's just "no commands found" we ignore it, but handle_input returns empty vec
+            // If parsing fails or store load fails, we report it.
+            // We only log if it looks like they tried to do something.
+            if content.contains("===ROADMAP===") {
+                eprintln!("{} Roadmap update failed: {e}", "⚠️".yellow());
+            }
         }
     }
-    current
+
+    roadmap_results
 }
 
 fn validate_plan_structure(plan: &str) {
@@ -256,15 +225,3 @@ fn confirm(prompt: &str) -> Result<bool> {
     io::stdin().read_line(&mut input)?;
     Ok(input.trim().eq_ignore_ascii_case("y"))
 }
-
-fn parse_manifest_step(content: &str) -> Result<Manifest, String> {
-    match manifest::parse_manifest(content) {
-        Ok(Some(m)) => Ok(m),
-        Ok(None) => Ok(Vec::new()),
-        Err(e) => Err(format!("Manifest Error: {e}")),
-    }
-}
-
-fn extract_files_step(content: &str) -> Result<ExtractedFiles, String> {
-    extractor::extract_files(content).map_err(|e| format!("Extraction Error: {e}"))
-}
\ No newline at end of file
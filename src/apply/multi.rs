@@ -0,0 +1,73 @@
+// src/apply/multi.rs
+//! `apply --multi`: a response split over several messages, copied one at a
+//! time and merged into one payload before validation.
+
+use anyhow::Result;
+use regex::Regex;
+use std::io::{self, BufRead, Write};
+
+/// Ends the last part of a split response.
+pub const DONE_MARKER: &str = "#__SLOPCHOP_DONE__#";
+
+/// Reads parts from `next` until one holds [`DONE_MARKER`] or the user
+/// answers `d` (or closes) `answers`, then merges them. A part identical to
+/// the one before means the clipboard wasn't updated and is skipped.
+///
+/// # Errors
+/// Returns error if a part or an answer cannot be read.
+pub fn read_parts(
+    mut next: impl FnMut() -> Result<String>,
+    answers: &mut impl BufRead,
+) -> Result<String> {
+    let mut parts: Vec<String> = Vec::new();
+    loop {
+        let part = next()?;
+        if parts.last() == Some(&part) {
+            println!("Clipboard unchanged; copy the next part first.");
+        } else if !part.trim().is_empty() {
+            parts.push(part);
+            println!("Part {} read.", parts.len());
+        }
+        if parts.last().is_some_and(|p| p.contains(DONE_MARKER)) {
+            break;
+        }
+        print!("Copy the next part and press Enter, or type 'd' when done: ");
+        io::stdout().flush()?;
+        let mut line = String::new();
+        if answers.read_line(&mut line)? == 0 || line.trim().eq_ignore_ascii_case("d") {
+            break;
+        }
+    }
+    Ok(merge(&parts))
+}
+
+/// Joins `parts` into one payload with a single manifest listing every
+/// part's entries once, followed by the rest of each part in order.
+#[must_use]
+pub fn merge(parts: &[String]) -> String {
+    let Ok(manifest_re) = Regex::new(r"(?s)#__SLOPCHOP_MANIFEST__#(.*?)#__SLOPCHOP_END__#") else {
+        return parts.concat();
+    };
+    let mut entries: Vec<&str> = Vec::new();
+    let mut body = String::new();
+    for part in parts {
+        let lines = manifest_re
+            .captures_iter(part)
+            .flat_map(|cap| cap.get(1).map_or("", |m| m.as_str()).lines())
+            .map(str::trim);
+        for line in lines.filter(|l| !l.is_empty()) {
+            if !entries.contains(&line) {
+                entries.push(line);
+            }
+        }
+        body.push_str(&manifest_re.replace_all(part, "").replace(DONE_MARKER, ""));
+        body.push('\n');
+    }
+    if entries.is_empty() {
+        return body;
+    }
+    format!(
+        "#__SLOPCHOP_MANIFEST__#\n{}\n#__SLOPCHOP_END__#\n{body}",
+        entries.join("\n")
+    )
+}
@@ -0,0 +1,189 @@
+// src/apply/patch.rs
+//! Unified diff support: AI responses may send small edits as ```` ```diff ````
+//! blocks instead of whole files. Hunks are located by their context, so
+//! line numbers that have drifted still apply.
+
+use anyhow::{anyhow, Result};
+use regex::Regex;
+use std::sync::LazyLock;
+
+static HUNK_RE: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(r"^@@ -(\d+)(?:,\d+)? \+\d+(?:,\d+)? @@").ok());
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HunkLine {
+    Context(String),
+    Remove(String),
+    Add(String),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Hunk {
+    /// 1-based line in the original file; a hint, not a requirement.
+    pub old_start: usize,
+    pub lines: Vec<HunkLine>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilePatch {
+    pub path: String,
+    /// Set by a `--- /dev/null` header.
+    pub is_new: bool,
+    pub hunks: Vec<Hunk>,
+}
+
+/// Parses one or more file diffs from unified diff text.
+///
+/// # Errors
+/// Returns error if a hunk appears before any file header, or a diff
+/// deletes a file (use a `[DELETE]` manifest entry instead).
+pub fn parse(diff: &str) -> Result<Vec<FilePatch>> {
+    let mut patches: Vec<FilePatch> = Vec::new();
+    let mut lines = diff.lines().peekable();
+    while let Some(line) = lines.next() {
+        let header = line.strip_prefix("--- ").and_then(|old| {
+            let new = lines.next_if(|l| l.starts_with("+++ "))?;
+            Some((old, &new[4..]))
+        });
+        match header {
+            Some((old, new)) => patches.push(file_header(old, new)?),
+            None => push_line(&mut patches, line)?,
+        }
+    }
+    Ok(patches)
+}
+
+/// Starts a hunk or adds a line to the current one.
+fn push_line(patches: &mut [FilePatch], line: &str) -> Result<()> {
+    let current = patches.last_mut();
+    if let Some(start) = hunk_start(line) {
+        let patch = current.ok_or_else(|| anyhow!("Diff hunk before any file header"))?;
+        patch.hunks.push(Hunk {
+            old_start: start,
+            lines: Vec::new(),
+        });
+    } else if let Some(hunk) = current.and_then(|p| p.hunks.last_mut()) {
+        hunk.lines.extend(hunk_line(line));
+    }
+    Ok(())
+}
+
+fn file_header(old: &str, new: &str) -> Result<FilePatch> {
+    let new = strip_path(new);
+    if new == "/dev/null" {
+        return Err(anyhow!(
+            "Diffs cannot delete files; use [DELETE] in the manifest"
+        ));
+    }
+    Ok(FilePatch {
+        path: new.to_string(),
+        is_new: strip_path(old) == "/dev/null",
+        hunks: Vec::new(),
+    })
+}
+
+/// Drops the `a/`/`b/` prefix and any trailing timestamp.
+fn strip_path(raw: &str) -> &str {
+    let path = raw.split('\t').next().unwrap_or(raw).trim();
+    path.strip_prefix("a/")
+        .or_else(|| path.strip_prefix("b/"))
+        .unwrap_or(path)
+}
+
+fn hunk_start(line: &str) -> Option<usize> {
+    let caps = HUNK_RE.as_ref()?.captures(line)?;
+    caps.get(1)?.as_str().parse().ok()
+}
+
+/// `None` for `\ No newline at end of file` and other markers.
+fn hunk_line(line: &str) -> Option<HunkLine> {
+    match line.chars().next() {
+        Some('+') => Some(HunkLine::Add(line[1..].to_string())),
+        Some('-') => Some(HunkLine::Remove(line[1..].to_string())),
+        Some(' ') => Some(HunkLine::Context(line[1..].to_string())),
+        // Editors and chat UIs often strip the space from blank context lines.
+        None => Some(HunkLine::Context(String::new())),
+        Some(_) => None,
+    }
+}
+
+impl Hunk {
+    /// The lines this hunk expects to find: context and removals.
+    fn old_lines(&self) -> Vec<&str> {
+        self.lines
+            .iter()
+            .filter_map(|l| match l {
+                HunkLine::Context(s) | HunkLine::Remove(s) => Some(s.as_str()),
+                HunkLine::Add(_) => None,
+            })
+            .collect()
+    }
+}
+
+/// Applies `patch` to `original`, hunk by hunk in order.
+///
+/// # Errors
+/// Returns error if a hunk's context cannot be found.
+pub fn apply(original: &str, patch: &FilePatch) -> Result<String> {
+    let lines: Vec<&str> = original.lines().collect();
+    let mut out: Vec<&str> = Vec::new();
+    let mut pos = 0;
+    for (i, hunk) in patch.hunks.iter().enumerate() {
+        let at = locate(
+            &lines,
+            &hunk.old_lines(),
+            pos,
+            hunk.old_start.saturating_sub(1),
+        )
+        .ok_or_else(|| anyhow!("Hunk {} does not match {}", i + 1, patch.path))?;
+        out.extend(&lines[pos..at]);
+        pos = splice(&lines, hunk, at, &mut out);
+    }
+    out.extend(&lines[pos..]);
+    let mut result = out.join("\n");
+    if original.is_empty() || original.ends_with('\n') {
+        result.push('\n');
+    }
+    Ok(result)
+}
+
+/// Writes `hunk` applied at `at` to `out`, returning the first original
+/// line after it. Context comes from the original, so whitespace-tolerant
+/// matches keep the file's own formatting.
+fn splice<'a>(lines: &[&'a str], hunk: &'a Hunk, at: usize, out: &mut Vec<&'a str>) -> usize {
+    let mut pos = at;
+    for line in &hunk.lines {
+        match line {
+            HunkLine::Context(_) => {
+                out.push(lines[pos]);
+                pos += 1;
+            }
+            HunkLine::Remove(_) => pos += 1,
+            HunkLine::Add(s) => out.push(s),
+        }
+    }
+    pos
+}
+
+/// Where `old` occurs at or after `from`, closest to `hint` first. Exact
+/// matches win over ones that differ only in trailing whitespace.
+fn locate(lines: &[&str], old: &[&str], from: usize, hint: usize) -> Option<usize> {
+    let last = lines.len().checked_sub(old.len())?;
+    if from > last {
+        return None;
+    }
+    let mut candidates: Vec<usize> = (from..=last).collect();
+    candidates.sort_by_key(|&at| at.abs_diff(hint));
+    let exact = |at: &usize| lines[*at..].iter().zip(old).all(|(a, b)| a == b);
+    let loose = |at: &usize| {
+        let window = lines[*at..].iter().zip(old);
+        window
+            .into_iter()
+            .all(|(a, b)| a.trim_end() == b.trim_end())
+    };
+    candidates
+        .iter()
+        .find(|at| exact(at))
+        .or_else(|| candidates.iter().find(|at| loose(at)))
+        .copied()
+}
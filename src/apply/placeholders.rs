@@ -0,0 +1,35 @@
+// src/apply/placeholders.rs
+//! Catches replies that copy the `[REDACTED:<type>]` placeholders `pack`
+//! put in place of secrets, which would write them over the real values.
+
+use crate::apply::types::ExtractedFiles;
+use crate::pack::redact::PLACEHOLDER;
+use std::fs;
+use std::path::Path;
+
+/// Describes `path` if `new` holds more placeholders than `old`, the file
+/// as it is now (empty for a new file).
+#[must_use]
+pub fn check(path: &str, new: &str, old: &str) -> Option<String> {
+    if new.matches(PLACEHOLDER).count() <= old.matches(PLACEHOLDER).count() {
+        return None;
+    }
+    Some(format!(
+        "{path} contains {PLACEHOLDER}...] placeholders from the pack, which would replace the real values. Send an edit block that leaves those lines alone."
+    ))
+}
+
+/// [`check`] for every extracted file against its copy under `root`.
+#[must_use]
+pub fn errors(extracted: &ExtractedFiles, root: Option<&Path>) -> Vec<String> {
+    let mut errors: Vec<String> = extracted
+        .iter()
+        .filter_map(|(path, file)| {
+            let full = root.map_or_else(|| Path::new(path).to_path_buf(), |r| r.join(path));
+            let old = fs::read_to_string(full).unwrap_or_default();
+            check(path, &file.content, &old)
+        })
+        .collect();
+    errors.sort();
+    errors
+}
@@ -0,0 +1,90 @@
+// src/apply/preserve.rs
+//! Keeps what a rewrite shouldn't change: a file's line endings and its
+//! permissions, unless `[apply]` overrides them.
+
+use super::allowlist;
+use crate::config::{ApplySettings, LineEndings};
+use anyhow::{Context, Result};
+use std::fs;
+use std::path::Path;
+
+/// How the writer writes files.
+#[derive(Debug, Clone, Default)]
+pub struct WriteOptions {
+    /// Roll the batch back if a written file no longer parses.
+    pub check_syntax: bool,
+    pub line_endings: LineEndings,
+    /// Globs of files to mark executable, e.g. `scripts/*.sh`.
+    pub executable: Vec<String>,
+}
+
+impl WriteOptions {
+    #[must_use]
+    pub fn new(settings: &ApplySettings, check_syntax: bool) -> Self {
+        Self {
+            check_syntax,
+            line_endings: settings.line_endings,
+            executable: settings.executable.clone(),
+        }
+    }
+}
+
+/// Writes `content` to `path` (`rel` from the project root), creating its
+/// directory and keeping the existing file's line endings and permissions.
+///
+/// # Errors
+/// Returns error if the file or its permissions cannot be written.
+pub fn write(path: &Path, rel: &str, content: &str, opts: &WriteOptions) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create directory {}", parent.display()))?;
+    }
+    let crlf = match opts.line_endings {
+        LineEndings::Lf => false,
+        LineEndings::Crlf => true,
+        LineEndings::Preserve => {
+            fs::read(path).map_or_else(|_| content.contains("\r\n"), |old| uses_crlf(&old))
+        }
+    };
+    let permissions = fs::metadata(path).ok().map(|m| m.permissions());
+    fs::write(path, convert(content, crlf))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    if let Some(permissions) = permissions {
+        fs::set_permissions(path, permissions)?;
+    }
+    if opts.executable.iter().any(|g| allowlist::matches(g, rel)) {
+        make_executable(path)?;
+    }
+    Ok(())
+}
+
+/// True if `bytes` has any CRLF line ending.
+#[must_use]
+pub fn uses_crlf(bytes: &[u8]) -> bool {
+    bytes.windows(2).any(|w| w == b"\r\n")
+}
+
+/// `content` with every line ending made CRLF or LF.
+#[must_use]
+pub fn convert(content: &str, crlf: bool) -> String {
+    let lf = content.replace("\r\n", "\n");
+    if crlf {
+        lf.replace('\n', "\r\n")
+    } else {
+        lf
+    }
+}
+
+#[cfg(unix)]
+fn make_executable(path: &Path) -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let mut permissions = fs::metadata(path)?.permissions();
+    permissions.set_mode(permissions.mode() | 0o111);
+    fs::set_permissions(path, permissions)?;
+    Ok(())
+}
+
+#[cfg(not(unix))]
+fn make_executable(_path: &Path) -> Result<()> {
+    Ok(())
+}
@@ -0,0 +1,107 @@
+// src/apply/preview.rs
+//! Colored diffs of a payload against the files on disk, shown by
+//! `apply --dry-run` and per file by `apply --review`.
+
+use crate::apply::diff::{diff_lines, DiffLine};
+use crate::apply::edit::{self, EditBlock};
+use crate::apply::types::{ExtractedFiles, FileContent, ManifestEntry, Operation};
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use std::fmt::Write;
+use std::fs;
+
+/// Unchanged lines shown around each change.
+const CONTEXT: usize = 3;
+
+/// Renders every manifest entry as a diff against its current content.
+///
+/// # Errors
+/// Returns error if an edit's target can't be read or no longer matches.
+pub fn render(
+    manifest: &[ManifestEntry],
+    files: &ExtractedFiles,
+    edits: &[EditBlock],
+) -> Result<String> {
+    let mut files = files.clone();
+    resolve_edits(&mut files, &mut edits.to_vec())?;
+    let mut out = String::new();
+    for entry in manifest {
+        let current = fs::read_to_string(&entry.path).ok();
+        let Some(proposed) = proposed(entry, &files) else {
+            let _ = writeln!(out, "\n{} (no content sent)", heading(entry, false));
+            continue;
+        };
+        let is_new = current.is_none() && !matches!(entry.operation, Operation::Delete);
+        out.push_str(&render_change(
+            &heading(entry, is_new),
+            current.as_deref().unwrap_or_default(),
+            &proposed,
+        ));
+    }
+    Ok(out)
+}
+
+/// The file's content after this entry; `None` if nothing was sent for it.
+pub(crate) fn proposed(entry: &ManifestEntry, files: &ExtractedFiles) -> Option<String> {
+    match &entry.operation {
+        Operation::Delete => Some(String::new()),
+        Operation::Rename(to) => files
+            .get(to)
+            .map(|f| f.content.clone())
+            .or_else(|| fs::read_to_string(&entry.path).ok()),
+        _ => files.get(&entry.path).map(|f| f.content.clone()),
+    }
+}
+
+pub(crate) fn heading(entry: &ManifestEntry, is_new: bool) -> String {
+    let action = match &entry.operation {
+        Operation::Delete => "DELETE",
+        Operation::Rename(_) => "RENAME",
+        _ if is_new => "NEW FILE",
+        _ => "CHANGE",
+    };
+    let target = match &entry.operation {
+        Operation::Rename(to) => format!("{} -> {}", entry.path.bold(), to.bold()),
+        _ => entry.path.bold().to_string(),
+    };
+    format!("{} {target}", action.cyan().bold())
+}
+
+/// Replaces `edits` with the whole files they produce.
+pub(crate) fn resolve_edits(files: &mut ExtractedFiles, edits: &mut Vec<EditBlock>) -> Result<()> {
+    for (path, blocks) in edit::by_path(edits) {
+        let current = fs::read_to_string(path)?;
+        let content = edit::apply(&current, &blocks).map_err(|e| anyhow!(e))?;
+        files.insert(path.to_string(), FileContent::new(content));
+    }
+    edits.clear();
+    Ok(())
+}
+
+pub(crate) fn render_change(heading: &str, current: &str, proposed: &str) -> String {
+    let mut out = format!("\n{heading}\n");
+    let lines = diff_lines(current, proposed);
+    let near_change = |i: usize| {
+        let lo = i.saturating_sub(CONTEXT);
+        let hi = (i + CONTEXT + 1).min(lines.len());
+        lines[lo..hi]
+            .iter()
+            .any(|l| !matches!(l, DiffLine::Same(_)))
+    };
+    let mut gap = false;
+    for (i, line) in lines.iter().enumerate() {
+        if !near_change(i) {
+            gap = true;
+            continue;
+        }
+        if std::mem::take(&mut gap) {
+            let _ = writeln!(out, "{}", "   ...".dimmed());
+        }
+        let _ = match line {
+            DiffLine::Same(l) => writeln!(out, "{}", format!("   {l}").dimmed()),
+            DiffLine::Removed(l) => writeln!(out, "{}", format!(" - {l}").red()),
+            DiffLine::Added(l) => writeln!(out, "{}", format!(" + {l}").green()),
+        };
+    }
+    out
+}
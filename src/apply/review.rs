@@ -0,0 +1,119 @@
+// src/apply/review.rs
+//! `apply --review`: steps through each change with a colored diff against
+//! the current file and asks whether to accept, skip, or edit it.
+
+use crate::apply::edit::EditBlock;
+use crate::apply::preview::{heading, proposed, render_change, resolve_edits};
+use crate::apply::types::{ExtractedFiles, FileContent, Manifest};
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use std::env;
+use std::fs;
+use std::io::{BufRead, Write};
+use std::path::Path;
+use std::process::Command;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Decision {
+    Accept,
+    Skip,
+    Edit,
+    Abort,
+}
+
+/// Asks about every manifest entry in turn. Skipped entries are removed from
+/// `manifest`; edited ones replace their content in `files`. Edits are
+/// resolved into whole files first so they can be shown and changed.
+///
+/// Returns `false` if the user aborted.
+///
+/// # Errors
+/// Returns error if input cannot be read or an edit no longer applies.
+pub fn review(
+    manifest: &mut Manifest,
+    files: &mut ExtractedFiles,
+    edits: &mut Vec<EditBlock>,
+    input: &mut impl BufRead,
+) -> Result<bool> {
+    resolve_edits(files, edits)?;
+    let mut kept = Vec::new();
+    for entry in manifest.drain(..) {
+        let current = fs::read_to_string(&entry.path).unwrap_or_default();
+        let Some(proposed) = proposed(&entry, files) else {
+            continue;
+        };
+        print!(
+            "{}",
+            render_change(
+                &heading(&entry, !Path::new(&entry.path).exists()),
+                &current,
+                &proposed
+            )
+        );
+        match decide(input, &entry.path, &proposed, files)? {
+            Decision::Accept | Decision::Edit => kept.push(entry),
+            Decision::Skip => println!("{}", format!("   skipped {}", entry.path).dimmed()),
+            Decision::Abort => return Ok(false),
+        }
+    }
+    *manifest = kept;
+    Ok(true)
+}
+
+/// Prompts until a valid answer; `e` opens the proposal in `$EDITOR`.
+fn decide(
+    input: &mut impl BufRead,
+    path: &str,
+    proposed: &str,
+    files: &mut ExtractedFiles,
+) -> Result<Decision> {
+    loop {
+        print!("[a]ccept, [s]kip, [e]dit, a[b]ort? ");
+        std::io::stdout().flush()?;
+        let mut answer = String::new();
+        if input.read_line(&mut answer)? == 0 {
+            return Ok(Decision::Abort);
+        }
+        let decision = match answer.trim() {
+            "a" | "y" => Decision::Accept,
+            "s" | "n" => Decision::Skip,
+            "b" | "q" => Decision::Abort,
+            "e" => Decision::Edit,
+            _ => continue,
+        };
+        if decision == Decision::Edit {
+            let content = edit_in_editor(path, proposed)?;
+            let line_count = content.lines().count();
+            files.insert(
+                path.to_string(),
+                FileContent {
+                    content,
+                    line_count,
+                },
+            );
+        }
+        return Ok(decision);
+    }
+}
+
+fn edit_in_editor(path: &str, proposed: &str) -> Result<String> {
+    let name = Path::new(path).file_name().map_or_else(
+        || "slopchop_review".into(),
+        |n| n.to_string_lossy().into_owned(),
+    );
+    let temp = env::temp_dir().join(format!("slopchop_review_{}_{name}", std::process::id()));
+    fs::write(&temp, proposed)?;
+    let editor = env::var("VISUAL")
+        .or_else(|_| env::var("EDITOR"))
+        .unwrap_or_else(|_| "vi".into());
+    // Allow editors with arguments, e.g. `code --wait`.
+    let mut parts = editor.split_whitespace();
+    let program = parts.next().unwrap_or("vi");
+    let status = Command::new(program).args(parts).arg(&temp).status()?;
+    let edited = fs::read_to_string(&temp);
+    let _ = fs::remove_file(&temp);
+    if !status.success() {
+        return Err(anyhow!("{editor} exited with {status}"));
+    }
+    Ok(edited?)
+}
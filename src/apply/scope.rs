@@ -0,0 +1,78 @@
+// src/apply/scope.rs
+//! `scoped = true` on a `[verify.*]` pipeline: narrows `cargo` and `npm`
+//! commands to the crates or packages that own the touched files.
+
+use std::fs;
+use std::path::Path;
+
+const NODE_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "mjs", "cjs"];
+
+/// Rewrites `cmd` to run only for the packages that own the `touched` files
+/// under `root`, e.g. `cargo test` to `cargo test -p core`. Other commands,
+/// and applies touching a file outside any member package, are unchanged.
+#[must_use]
+pub fn scope_command(cmd: &str, touched: &[String], root: &Path) -> String {
+    let mut tokens: Vec<&str> = cmd.split_whitespace().collect();
+    let (manifest, extensions, flag) = match tokens.first() {
+        Some(&"cargo") => ("Cargo.toml", &["rs"][..], "-p "),
+        Some(&"npm") => ("package.json", NODE_EXTENSIONS, "--workspace="),
+        _ => return cmd.to_string(),
+    };
+    let files: Vec<&String> = touched
+        .iter()
+        .filter(|f| {
+            let ext = Path::new(f).extension().and_then(|e| e.to_str());
+            ext.is_some_and(|e| extensions.contains(&e))
+        })
+        .collect();
+    let packages = match owners(root, &files, manifest) {
+        Some(p) if !p.is_empty() && tokens.len() > 1 => p,
+        _ => return cmd.to_string(),
+    };
+    tokens.retain(|t| !matches!(*t, "--workspace" | "--workspaces" | "--all"));
+    let mut out: Vec<String> = tokens.iter().map(ToString::to_string).collect();
+    out.splice(2..2, packages.iter().map(|p| format!("{flag}{p}")));
+    out.join(" ")
+}
+
+/// Names of the packages owning `files`, or `None` if a file belongs to no
+/// package below the workspace root.
+#[must_use]
+pub fn owners(root: &Path, files: &[&String], manifest: &str) -> Option<Vec<String>> {
+    let mut names: Vec<String> = Vec::new();
+    for file in files {
+        let name = owner(root, Path::new(file.as_str()), manifest)?;
+        if !names.contains(&name) {
+            names.push(name);
+        }
+    }
+    Some(names)
+}
+
+/// The nearest package above `file`, stopping short of `root` itself: a
+/// manifest at the root describes the whole workspace.
+fn owner(root: &Path, file: &Path, manifest: &str) -> Option<String> {
+    let mut dir = file.parent();
+    while let Some(d) = dir.filter(|d| !d.as_os_str().is_empty()) {
+        if let Some(name) = package_name(&root.join(d).join(manifest)) {
+            return Some(name);
+        }
+        dir = d.parent();
+    }
+    None
+}
+
+fn package_name(manifest: &Path) -> Option<String> {
+    let content = fs::read_to_string(manifest).ok()?;
+    if manifest.ends_with("Cargo.toml") {
+        let value: toml::Value = toml::from_str(&content).ok()?;
+        value
+            .get("package")?
+            .get("name")?
+            .as_str()
+            .map(String::from)
+    } else {
+        let value: serde_json::Value = serde_json::from_str(&content).ok()?;
+        value.get("name")?.as_str().map(String::from)
+    }
+}
@@ -0,0 +1,30 @@
+// src/apply/stripped.rs
+//! Catches whole-file replies for files `pack --strip-comments` sent
+//! without their comments, which would delete those comments.
+
+use crate::apply::extractor;
+use crate::pack::comments;
+use std::path::Path;
+
+/// Describes each `#__SLOPCHOP_FILE__#` block in `content` that replaces a
+/// file the last pack under `root` stripped. Edit blocks and diffs are
+/// fine: they only touch the lines they name.
+#[must_use]
+pub fn errors(content: &str, root: Option<&Path>) -> Vec<String> {
+    let stripped = comments::stripped(root.unwrap_or(Path::new(".")));
+    if stripped.is_empty() {
+        return Vec::new();
+    }
+    let files = extractor::extract_files(content).unwrap_or_default();
+    let mut errors: Vec<String> = stripped
+        .iter()
+        .filter(|path| files.contains_key(*path))
+        .map(|path| {
+            format!(
+                "{path} was packed with its comments stripped; a whole file would delete them. Send #__SLOPCHOP_EDIT__# blocks instead."
+            )
+        })
+        .collect();
+    errors.sort();
+    errors
+}
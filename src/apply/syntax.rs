@@ -0,0 +1,52 @@
+// src/apply/syntax.rs
+//! Post-write parse check for files in a supported language.
+
+use crate::lang::Lang;
+use std::path::Path;
+use tree_sitter::{Language, Node, Parser};
+
+/// The 1-based line of the first parse error in `content`, or `None` if it
+/// parses cleanly or its language isn't supported.
+#[must_use]
+pub fn first_error(path: &str, content: &str) -> Option<usize> {
+    let ext = Path::new(path).extension()?.to_str()?;
+    let mut parser = Parser::new();
+    parser.set_language(&grammar(ext)?).ok()?;
+    let tree = parser.parse(content, None)?;
+    let root = tree.root_node();
+    if !root.has_error() {
+        return None;
+    }
+    Some(error_node(root).unwrap_or(root).start_position().row + 1)
+}
+
+/// The line of a syntax error `new` introduces; an error already present
+/// in `old` isn't blamed on the change.
+#[must_use]
+pub fn introduced_error(path: &str, new: &str, old: Option<&str>) -> Option<usize> {
+    let line = first_error(path, new)?;
+    if old.is_some_and(|o| first_error(path, o).is_some()) {
+        return None;
+    }
+    Some(line)
+}
+
+/// JSX only parses with the TSX grammar.
+fn grammar(ext: &str) -> Option<Language> {
+    match ext {
+        "tsx" | "jsx" | "js" => Some(tree_sitter_typescript::LANGUAGE_TSX.into()),
+        _ => Lang::from_ext(ext).map(|l| l.grammar()),
+    }
+}
+
+fn error_node(node: Node) -> Option<Node> {
+    if node.is_error() || node.is_missing() {
+        return Some(node);
+    }
+    let mut cursor = node.walk();
+    let children: Vec<Node> = node.children(&mut cursor).collect();
+    children
+        .into_iter()
+        .filter(Node::has_error)
+        .find_map(error_node)
+}
@@ -0,0 +1,71 @@
+// src/apply/truncation.rs
+//! Truncation detection: lines where the AI elided code ("// ...",
+//! "// rest of"), tuned by `[apply.truncation]`, and in strict mode full
+//! replacements that shrink a long file to a fraction of its size.
+
+use crate::config::TruncationSettings;
+use std::path::Path;
+
+/// Markers flagged unless `[apply.truncation] remove` drops them.
+pub const DEFAULT_PATTERNS: &[&str] = &[
+    "// ...",
+    "/* ... */",
+    "# ...",
+    "// rest of",
+    "// remaining",
+    "# rest of",
+    "# remaining",
+    "<!-- ... -->",
+];
+
+/// Strict mode only judges files at least this long.
+const STRICT_MIN_LINES: usize = 50;
+
+/// The default patterns without `remove`, plus `add`.
+#[must_use]
+pub fn patterns(settings: &TruncationSettings) -> Vec<String> {
+    DEFAULT_PATTERNS
+        .iter()
+        .map(ToString::to_string)
+        .filter(|p| !settings.remove.contains(p))
+        .chain(settings.add.iter().cloned())
+        .collect()
+}
+
+/// The 1-based line of the first truncation marker in `content`, unless the
+/// language of `path` is skipped.
+#[must_use]
+pub fn detect(path: &str, content: &str, settings: &TruncationSettings) -> Option<usize> {
+    let ext = Path::new(path).extension().and_then(|e| e.to_str());
+    if ext.is_some_and(|e| settings.skip_langs.iter().any(|s| s == e)) {
+        return None;
+    }
+    let patterns = patterns(settings);
+    content
+        .lines()
+        .position(|line| {
+            let trimmed = line.trim();
+            !trimmed.contains("slopchop:ignore")
+                && patterns.iter().any(|p| trimmed.contains(p.as_str()))
+        })
+        .map(|i| i + 1)
+}
+
+/// In strict mode, describes a replacement of a long file with one under
+/// half its length.
+#[must_use]
+pub fn short_replacement(
+    path: &str,
+    new: &str,
+    old: &str,
+    settings: &TruncationSettings,
+) -> Option<String> {
+    let (new_lines, old_lines) = (new.lines().count(), old.lines().count());
+    if !settings.strict || old_lines < STRICT_MIN_LINES || new_lines * 2 >= old_lines {
+        return None;
+    }
+    Some(format!(
+        "Suspiciously short replacement of {path}: {new_lines} lines for a file of \
+         {old_lines}. Send the complete file, or an edit block for the changed part."
+    ))
+}
@@ -1,4 +1,5 @@
 // src/apply/types.rs
+use crate::apply::input::InputSource;
 use crate::config::Config;
 use std::collections::HashMap;
 
@@ -7,6 +8,9 @@ pub enum Operation {
     Update,
     New,
     Delete,
+    /// Move the entry's file to this path, keeping its content unless a
+    /// file block for the new path is sent too.
+    Rename(String),
 }
 
 #[derive(Debug, Clone)]
@@ -15,12 +19,31 @@ pub struct ManifestEntry {
     pub operation: Operation,
 }
 
+impl ManifestEntry {
+    /// True if this entry moves its file to `path`.
+    #[must_use]
+    pub fn renames_to(&self, path: &str) -> bool {
+        matches!(&self.operation, Operation::Rename(to) if to == path)
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct FileContent {
     pub content: String,
     pub line_count: usize,
 }
 
+impl FileContent {
+    #[must_use]
+    pub fn new(content: String) -> Self {
+        let line_count = content.lines().count();
+        Self {
+            content,
+            line_count,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub enum ApplyOutcome {
     Success {
@@ -36,6 +59,10 @@ pub enum ApplyOutcome {
     },
     ParseError(String),
     WriteError(String),
+    /// Validated and previewed; nothing written.
+    DryRun,
+    /// Declined at the plan prompt or during review; nothing written.
+    Cancelled,
 }
 
 /// Context for the apply operation.
@@ -44,6 +71,8 @@ pub struct ApplyContext<'a> {
     pub config: &'a Config,
     pub force: bool,   // Skips interactive confirmation (for tests/automation)
     pub dry_run: bool, // Skips disk writes (for tests)
+    pub review: bool,  // Asks about each file instead of once for the plan
+    pub input: InputSource,
 }
 
 impl<'a> ApplyContext<'a> {
@@ -53,12 +82,20 @@ impl<'a> ApplyContext<'a> {
             config,
             force: false,
             dry_run: false,
+            review: false,
+            input: InputSource::Clipboard,
         }
     }
+
+    /// True if the plan is applied without asking.
+    #[must_use]
+    pub fn skips_confirm(&self) -> bool {
+        self.force || self.dry_run || self.review
+    }
 }
 
 // The manifest is just a list of entries
 pub type Manifest = Vec<ManifestEntry>;
 
 // The extracted files are mapped by path
-pub type ExtractedFiles = HashMap<String, FileContent>;
\ No newline at end of file
+pub type ExtractedFiles = HashMap<String, FileContent>;
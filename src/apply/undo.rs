@@ -0,0 +1,230 @@
+// src/apply/undo.rs
+//! Backup sets written by `apply`, and `slopchop undo` to roll one back.
+//!
+//! Each set is `.slopchop_apply_backup/<nanos>-<pid>/` holding the files as
+//! they were before the apply, plus a record of the files the apply created
+//! and the commit it made, if any. Apply never writes dotfiles, so the
+//! record can't clash with a backed-up file.
+
+use super::writer::BACKUP_DIR;
+use anyhow::{anyhow, bail, Context, Result};
+use colored::Colorize;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::time::{SystemTime, UNIX_EPOCH};
+use walkdir::WalkDir;
+
+const RECORD_FILE: &str = ".slopchop_set.json";
+
+/// What the backup copies alone can't tell about an apply.
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct SetRecord {
+    #[serde(default)]
+    pub created: Vec<String>,
+    #[serde(default)]
+    pub commit: Option<String>,
+}
+
+impl SetRecord {
+    /// Writes the record into the set folder `set`.
+    ///
+    /// # Errors
+    /// Returns error if the record cannot be written.
+    pub fn save(&self, set: &Path) -> Result<()> {
+        fs::write(record_path(set), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    fn load(set: &Path) -> Self {
+        fs::read_to_string(record_path(set))
+            .ok()
+            .and_then(|s| serde_json::from_str(&s).ok())
+            .unwrap_or_default()
+    }
+}
+
+/// One apply's backup.
+#[derive(Debug)]
+pub struct BackupSet {
+    pub id: String,
+    pub path: PathBuf,
+    /// Files saved before the apply changed or deleted them.
+    pub files: Vec<String>,
+    pub record: SetRecord,
+}
+
+/// Lists the backup sets under `root`, newest first.
+///
+/// # Errors
+/// Returns error if the backup directory exists but cannot be read.
+pub fn list(root: &Path) -> Result<Vec<BackupSet>> {
+    let dir = root.join(BACKUP_DIR);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut sets = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            sets.push(open(path));
+        }
+    }
+    sets.sort_by_key(|s| std::cmp::Reverse(age(&s.id)));
+    Ok(sets)
+}
+
+/// Creates the folder for a new backup set under `root`. The id is the
+/// time in nanoseconds plus the pid, so applies in the same second or from
+/// racing processes never share a set.
+///
+/// # Errors
+/// Returns error if the folder cannot be created or already exists.
+pub fn new_set(root: &Path) -> Result<PathBuf> {
+    let dir = root.join(BACKUP_DIR);
+    fs::create_dir_all(&dir).context("Failed to create backup directory")?;
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH)?.as_nanos();
+    let set = dir.join(format!("{nanos}-{}", std::process::id()));
+    fs::create_dir(&set)
+        .with_context(|| format!("Failed to create backup set {}", set.display()))?;
+    Ok(set)
+}
+
+/// Puts the files of `set` back, deletes the files its apply created, and
+/// removes the set.
+///
+/// # Errors
+/// Returns error if a file cannot be restored or removed.
+pub fn restore(root: &Path, set: &BackupSet) -> Result<()> {
+    for rel in &set.files {
+        let dest = root.join(rel);
+        if let Some(parent) = dest.parent() {
+            fs::create_dir_all(parent)?;
+        }
+        fs::copy(set.path.join(rel), &dest).with_context(|| format!("Failed to restore {rel}"))?;
+    }
+    for rel in &set.record.created {
+        let path = root.join(rel);
+        if path.exists() {
+            fs::remove_file(&path).with_context(|| format!("Failed to remove {rel}"))?;
+        }
+    }
+    discard(set)
+}
+
+/// Notes `commit` as the commit made by the newest apply under `root`.
+///
+/// # Errors
+/// Returns error if the record cannot be written.
+pub fn record_commit(root: &Path, commit: &str) -> Result<()> {
+    let Some(mut set) = list(root)?.into_iter().next() else {
+        return Ok(());
+    };
+    set.record.commit = Some(commit.to_string());
+    set.record.save(&set.path)
+}
+
+/// Runs `slopchop undo`: lists sets, or rolls back `id` (default newest),
+/// reverting its commit instead of copying files when `revert` is set.
+///
+/// # Errors
+/// Returns error if there is nothing to undo or the rollback fails.
+pub fn run(id: Option<&str>, show: bool, revert: bool) -> Result<()> {
+    let root = Path::new(".");
+    let sets = list(root)?;
+    if show {
+        print_sets(&sets);
+        return Ok(());
+    }
+    let set = match id {
+        Some(id) => sets.iter().find(|s| s.id == id),
+        None => sets.first(),
+    }
+    .ok_or_else(|| anyhow!("No backup set {}", id.unwrap_or("to undo")))?;
+
+    if revert {
+        revert_commit(set)?;
+    } else {
+        restore(root, set)?;
+    }
+    println!(
+        "{} apply {}: {} restored, {} removed.",
+        "↩ Undid".green().bold(),
+        set.id,
+        set.files.len(),
+        set.record.created.len()
+    );
+    Ok(())
+}
+
+fn revert_commit(set: &BackupSet) -> Result<()> {
+    let Some(commit) = &set.record.commit else {
+        bail!(
+            "Apply {} made no commit; run `slopchop undo` without --revert",
+            set.id
+        );
+    };
+    let output = Command::new("git")
+        .args(["revert", "--no-edit", commit])
+        .output()?;
+    if !output.status.success() {
+        let err = String::from_utf8_lossy(&output.stderr);
+        return Err(anyhow!("git revert {commit} failed: {err}"));
+    }
+    discard(set)
+}
+
+fn print_sets(sets: &[BackupSet]) {
+    if sets.is_empty() {
+        println!("No backups.");
+    }
+    for set in sets {
+        let commit = set.record.commit.as_deref().unwrap_or("-");
+        println!(
+            "{}  {} backed up, {} created, commit {}",
+            set.id.bold(),
+            set.files.len(),
+            set.record.created.len(),
+            commit.get(..8).unwrap_or(commit)
+        );
+    }
+}
+
+/// Reads the backup set stored in the folder `path`.
+#[must_use]
+pub fn open(path: PathBuf) -> BackupSet {
+    let files = WalkDir::new(&path)
+        .into_iter()
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file() && e.file_name() != RECORD_FILE)
+        .filter_map(|e| {
+            let rel = e.path().strip_prefix(&path).ok()?;
+            Some(rel.to_string_lossy().replace('\\', "/"))
+        })
+        .collect();
+    BackupSet {
+        id: path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default(),
+        record: SetRecord::load(&path),
+        files,
+        path,
+    }
+}
+
+/// The time part of a set id; older sets named by seconds alone sort first.
+fn age(id: &str) -> u128 {
+    let time = id.split_once('-').map_or(id, |(t, _)| t);
+    time.parse().unwrap_or(0)
+}
+
+fn discard(set: &BackupSet) -> Result<()> {
+    fs::remove_dir_all(&set.path)?;
+    Ok(())
+}
+
+fn record_path(set: &Path) -> PathBuf {
+    set.join(RECORD_FILE)
+}
@@ -1,13 +1,20 @@
 // slopchop:ignore
 // src/apply/validator.rs
-use crate::apply::types::{ExtractedFiles, Manifest};
-use crate::apply::ApplyOutcome;
+use crate::apply::edit::{self, EditBlock};
+use crate::apply::types::{ExtractedFiles, FileContent, Manifest, Operation};
+use crate::apply::{
+    expect, extractor, manifest, placeholders, stripped, syntax, truncation, ApplyOutcome,
+};
+use crate::config::TruncationSettings;
+use std::fs;
 use std::path::{Component, Path};
 
 const PROTECTED_FILES: &[&str] = &[
     "ROADMAP.md",
     ".slopchopignore",
     "slopchop.toml",
+    ".wardenignore",
+    "warden.toml",
     "Cargo.lock",
     "package-lock.json",
     "yarn.lock",
@@ -24,24 +31,172 @@ const BLOCKED_DIRS: &[&str] = &[
     ".slopchop_apply_backup",
 ];
 
+/// Parses and validates a whole response: manifest, files, diffs, and
+/// search/replace edits against the files under `root`.
+#[must_use]
+pub fn validate_payload(
+    content: &str,
+    root: Option<&Path>,
+    truncation: &TruncationSettings,
+) -> ApplyOutcome {
+    let (manifest, extracted, edits) = match parse_payload(content, root) {
+        Ok(parsed) => parsed,
+        Err(e) => return ApplyOutcome::ParseError(e),
+    };
+    let outcome = check_files(&manifest, &extracted, truncation);
+    let mut edit_errors = validate_edits(&manifest, &edits, root, truncation);
+    edit_errors.extend(short_replacements(&extracted, root, truncation));
+    edit_errors.extend(placeholders::errors(&extracted, root));
+    edit_errors.extend(stripped::errors(content, root));
+    edit_errors.extend(expectation_errors(content, root, truncation.line_counts));
+    if edit_errors.is_empty() {
+        return outcome;
+    }
+    match outcome {
+        ApplyOutcome::ValidationFailure { mut errors, .. } => {
+            errors.extend(edit_errors);
+            failure(errors)
+        }
+        _ => failure(edit_errors),
+    }
+}
+
+/// Describes each manifest file whose `[base:HASH]` no longer matches the
+/// file on disk under `root`, i.e. it changed after it was packed.
+#[must_use]
+pub fn stale_files(content: &str, root: Option<&Path>) -> Vec<String> {
+    let Ok(bases) = manifest::parse_bases(content) else {
+        return Vec::new();
+    };
+    let mut stale: Vec<String> = bases
+        .iter()
+        .filter_map(|(path, base)| {
+            let full = root.map_or_else(|| Path::new(path).to_path_buf(), |r| r.join(path));
+            let now = fs::read_to_string(&full).ok().map(|c| manifest::base_hash(&c));
+            match now {
+                Some(now) if now == *base => None,
+                Some(now) => Some(format!(
+                    "{path} changed since it was packed (base {base}, now {now}); re-pack and resend it"
+                )),
+                None => Some(format!("{path} was removed since it was packed (base {base})")),
+            }
+        })
+        .collect();
+    stale.sort();
+    stale
+}
+
+/// Describes each file in the response that fails to parse, counting edits
+/// and diffs as the files they produce. A file that already failed to
+/// parse on disk under `root` isn't reported.
+#[must_use]
+pub fn syntax_errors(content: &str, root: Option<&Path>) -> Vec<String> {
+    let Some(files) = final_files(content, root) else {
+        return Vec::new();
+    };
+    let mut errors: Vec<String> = files
+        .iter()
+        .filter_map(|(path, file)| {
+            let old = read_under(root, path);
+            let line = syntax::introduced_error(path, &file.content, old.as_deref())?;
+            Some(format!(
+                "{path}:{line}: syntax error (unbalanced braces or a cut-off file?); resend the complete file"
+            ))
+        })
+        .collect();
+    errors.sort();
+    errors
+}
+
+/// Describes each file whose final content breaks the `[sha256:PREFIX]`,
+/// or with `line_counts` the `[lines:N]`, its manifest entry declares.
+#[must_use]
+pub fn expectation_errors(content: &str, root: Option<&Path>, line_counts: bool) -> Vec<String> {
+    let mut expectations = expect::parse(content).unwrap_or_default();
+    if !line_counts {
+        for e in expectations.values_mut() {
+            e.lines = None;
+        }
+    }
+    if expectations.is_empty() {
+        return Vec::new();
+    }
+    let files = final_files(content, root).unwrap_or_default();
+    let mut errors: Vec<String> = expectations
+        .iter()
+        .filter_map(|(path, expected)| expected.mismatch(path, &files.get(path)?.content))
+        .collect();
+    errors.sort();
+    errors
+}
+
+/// The files the response would write, with edits applied to the files
+/// under `root`.
+fn final_files(content: &str, root: Option<&Path>) -> Option<ExtractedFiles> {
+    let (_, mut files, edits) = parse_payload(content, root).ok()?;
+    for (path, blocks) in edit::by_path(&edits) {
+        if let Some(updated) = read_under(root, path).and_then(|c| edit::apply(&c, &blocks).ok()) {
+            files.insert(path.to_string(), FileContent::new(updated));
+        }
+    }
+    Some(files)
+}
+
+fn read_under(root: Option<&Path>, path: &str) -> Option<String> {
+    let full = root.map_or_else(|| Path::new(path).to_path_buf(), |r| r.join(path));
+    fs::read_to_string(full).ok()
+}
+
+fn parse_payload(
+    content: &str,
+    root: Option<&Path>,
+) -> Result<(Manifest, ExtractedFiles, Vec<EditBlock>), String> {
+    let manifest = manifest::parse_manifest(content)
+        .map_err(|e| format!("Manifest Error: {e}"))?
+        .unwrap_or_default();
+    let extract_err = |e: anyhow::Error| format!("Extraction Error: {e}");
+    let files = extractor::extract_changes(content, root).map_err(extract_err)?;
+    let edits = extractor::extract_edits(content).map_err(extract_err)?;
+    Ok((manifest, files, edits))
+}
+
+fn failure(errors: Vec<String>) -> ApplyOutcome {
+    ApplyOutcome::ValidationFailure {
+        errors,
+        missing: vec![],
+        ai_message: String::new(),
+    }
+}
+
+/// Validates the manifest and extracted files with the default truncation
+/// settings.
 #[must_use]
 pub fn validate(manifest: &Manifest, extracted: &ExtractedFiles) -> ApplyOutcome {
+    check_files(manifest, extracted, &TruncationSettings::default())
+}
+
+fn check_files(
+    manifest: &Manifest,
+    extracted: &ExtractedFiles,
+    truncation: &TruncationSettings,
+) -> ApplyOutcome {
     let mut errors = Vec::new();
 
     for entry in manifest {
-        if let Err(e) = validate_path(&entry.path) {
-            errors.push(e);
-        }
-        if is_protected(&entry.path) {
-            errors.push(format!("Cannot overwrite protected file: {}", entry.path));
+        errors.extend(validate_target(&entry.path));
+        if let Operation::Rename(to) = &entry.operation {
+            errors.extend(validate_target(to));
         }
     }
 
     for (path, content) in extracted {
-        if !manifest.iter().any(|e| e.path == *path) {
+        if !manifest
+            .iter()
+            .any(|e| e.path == *path || e.renames_to(path))
+        {
             errors.push(format!("File extracted but not in manifest: {path}"));
         }
-        if let Err(e) = validate_content(path, &content.content) {
+        if let Err(e) = validate_content(path, &content.content, truncation) {
             errors.push(e);
         }
     }
@@ -54,12 +209,74 @@ pub fn validate(manifest: &Manifest, extracted: &ExtractedFiles) -> ApplyOutcome
             backed_up: false,
         }
     } else {
-        ApplyOutcome::ValidationFailure {
-            errors,
-            missing: vec![],
-            ai_message: String::new(),
+        failure(errors)
+    }
+}
+
+/// Checks that every edit targets a manifest file and that its anchors
+/// each match exactly once, against the file under `root`.
+#[must_use]
+pub fn validate_edits(
+    manifest: &Manifest,
+    edits: &[EditBlock],
+    root: Option<&Path>,
+    truncation: &TruncationSettings,
+) -> Vec<String> {
+    let mut errors = Vec::new();
+    for (path, blocks) in edit::by_path(edits) {
+        if !manifest.iter().any(|e| e.path == path) {
+            errors.push(format!("File edited but not in manifest: {path}"));
+            continue;
+        }
+        let full = root.map_or_else(|| Path::new(path).to_path_buf(), |r| r.join(path));
+        let Ok(current) = fs::read_to_string(&full) else {
+            errors.push(format!(
+                "Cannot edit {path}: file does not exist. Send the whole file."
+            ));
+            continue;
+        };
+        match edit::apply(&current, &blocks) {
+            Ok(updated) => {
+                errors.extend(validate_content(path, &updated, truncation).err());
+                errors.extend(placeholders::check(path, &updated, &current));
+            }
+            Err(e) => errors.push(e),
         }
     }
+    errors
+}
+
+/// In strict truncation mode, describes each extracted file that replaces
+/// a long file under `root` with a much shorter one.
+fn short_replacements(
+    extracted: &ExtractedFiles,
+    root: Option<&Path>,
+    truncation: &TruncationSettings,
+) -> Vec<String> {
+    if !truncation.strict {
+        return Vec::new();
+    }
+    let mut errors: Vec<String> = extracted
+        .iter()
+        .filter_map(|(path, file)| {
+            let full = root.map_or_else(|| Path::new(path).to_path_buf(), |r| r.join(path));
+            let old = fs::read_to_string(full).ok()?;
+            truncation::short_replacement(path, &file.content, &old, truncation)
+        })
+        .collect();
+    errors.sort();
+    errors
+}
+
+fn validate_target(path: &str) -> Vec<String> {
+    let mut errors: Vec<String> = validate_path(path).err().into_iter().collect();
+    if path.is_empty() {
+        errors.push("Manifest entry has an empty path".to_string());
+    }
+    if is_protected(path) {
+        errors.push(format!("Cannot overwrite protected file: {path}"));
+    }
+    errors
 }
 
 fn validate_path(path_str: &str) -> Result<(), String> {
@@ -76,9 +293,10 @@ fn validate_path(path_str: &str) -> Result<(), String> {
             if BLOCKED_DIRS.contains(&s.as_ref()) {
                 return Err(format!("Access to sensitive directory blocked: {s}"));
             }
-            if s.starts_with('.') 
-                && !s.eq(".gitignore") 
+            if s.starts_with('.')
+                && !s.eq(".gitignore")
                 && !s.eq(".slopchopignore")
+                && !s.eq(".wardenignore")
                 && !s.eq(".github")
             {
                 return Err(format!("Hidden files blocked: {s}"));
@@ -89,40 +307,28 @@ fn validate_path(path_str: &str) -> Result<(), String> {
 }
 
 fn is_protected(path_str: &str) -> bool {
-    PROTECTED_FILES.iter().any(|&f| f.eq_ignore_ascii_case(path_str))
+    PROTECTED_FILES
+        .iter()
+        .any(|&f| f.eq_ignore_ascii_case(path_str))
 }
 
-fn validate_content(path: &str, content: &str) -> Result<(), String> {
+fn validate_content(
+    path: &str,
+    content: &str,
+    truncation: &TruncationSettings,
+) -> Result<(), String> {
     if content.trim().is_empty() {
         return Err(format!("File is empty: {path}"));
     }
     if content.contains("```") || content.contains("~~~") {
-        return Err(format!("Markdown fences detected in {path}. Content must be raw code."));
+        return Err(format!(
+            "Markdown fences detected in {path}. Content must be raw code."
+        ));
     }
-    if let Some(line) = detect_truncation(content) {
-        return Err(format!("Truncation detected in {path} at line {line}: AI gave up."));
+    if let Some(line) = truncation::detect(path, content, truncation) {
+        return Err(format!(
+            "Truncation detected in {path} at line {line}: AI gave up."
+        ));
     }
     Ok(())
 }
-
-fn detect_truncation(content: &str) -> Option<usize> {
-    let truncation_patterns = [
-        "// ...",
-        "/* ... */",
-        "# ...",
-        "// rest of",
-        "// remaining",
-        "# rest of",
-        "# remaining",
-        "<!-- ... -->",
-    ];
-    for (i, line) in content.lines().enumerate() {
-        let trimmed = line.trim();
-        for pattern in &truncation_patterns {
-            if trimmed.contains(pattern) && !trimmed.contains("slopchop:ignore") {
-                return Some(i + 1);
-            }
-        }
-    }
-    None
-}
\ No newline at end of file
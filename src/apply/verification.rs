@@ -1,73 +1,203 @@
-// src/apply/verification.rs
-use crate::apply::types::ApplyContext;
-use crate::spinner::Spinner;
-use anyhow::Result;
-use colored::Colorize;
-use std::fmt::Write as FmtWrite;
-use std::process::Command;
-
-/// Runs configured checks and `SlopChop` scan to verify application.
-/// Returns `(success, log_output)`.
-///
-/// # Errors
-/// Returns error if command execution fails.
-pub fn verify_application(ctx: &ApplyContext) -> Result<(bool, String)> {
-    println!("{}", "\n> Verifying changes...".blue().bold());
-    let mut log_buffer = String::new();
-
-    if let Some(commands) = ctx.config.commands.get("check") {
-        for cmd in commands {
-            let (success, output) = run_check_command(cmd)?;
-            let _ = writeln!(log_buffer, "> {cmd}\n{output}");
-
-            if !success {
-                return Ok((false, log_buffer));
-            }
-        }
-    }
-
-    println!("Running structural scan...");
-    let (success, output) = run_slopchop_check()?;
-    let _ = writeln!(log_buffer, "> slopchop scan\n{output}");
-
-    Ok((success, log_buffer))
-}
-
-fn run_check_command(cmd: &str) -> Result<(bool, String)> {
-    let sp = Spinner::start(cmd);
-    let parts: Vec<&str> = cmd.split_whitespace().collect();
-    let Some((prog, args)) = parts.split_first() else {
-        sp.stop(true);
-        return Ok((true, String::new()));
-    };
-
-    let output = Command::new(prog).args(args).output()?;
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let combined = format!("{stdout}\n{stderr}");
-
-    let success = output.status.success();
-    sp.stop(success);
-
-    if !success {
-        print!("{stdout}");
-        eprint!("{stderr}");
-    }
-
-    Ok((success, combined))
-}
-
-fn run_slopchop_check() -> Result<(bool, String)> {
-    // slopchop check is fast, but we can spin on it too for consistency if needed.
-    // However, it outputs its own colorized report.
-    // For now, let's keep it simple as it was.
-    let output = Command::new("slopchop").output()?;
-    let stdout = String::from_utf8_lossy(&output.stdout);
-    let stderr = String::from_utf8_lossy(&output.stderr);
-    let combined = format!("{stdout}\n{stderr}");
-
-    print!("{stdout}");
-    eprint!("{stderr}");
-
-    Ok((output.status.success(), combined))
-}
\ No newline at end of file
+// src/apply/verification.rs
+use crate::apply::types::ApplyContext;
+use crate::apply::{diagnostics, scope};
+use crate::config::commands::{ChangedFiles, CommandStep};
+use crate::config::Config;
+use crate::spinner::Spinner;
+use anyhow::Result;
+use colored::Colorize;
+use std::fmt::Write as FmtWrite;
+use std::io::Read;
+use std::path::Path;
+use std::process::{Command, Output, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+/// Runs the checks for the `touched` files and the `SlopChop` scan to verify
+/// application. Returns `(success, log_output)`.
+///
+/// # Errors
+/// Returns error if command execution fails.
+pub fn verify_application(ctx: &ApplyContext, touched: &[String]) -> Result<(bool, String)> {
+    println!("{}", "\n> Verifying changes...".blue().bold());
+    let mut log_buffer = String::new();
+    let mut changed = ChangedFiles::listed(touched);
+
+    for step in &check_commands(ctx.config, touched) {
+        if !step.should_run(&mut changed) {
+            continue;
+        }
+        let (success, output) = run_check_command(step, ctx.config.preferences.verify_timeout);
+        let _ = writeln!(log_buffer, "> {}\n{output}", step.run);
+
+        if !success && !step.continue_on_error {
+            return Ok((false, log_buffer));
+        }
+    }
+
+    println!("Running structural scan...");
+    let (success, output) = run_slopchop_check()?;
+    let _ = writeln!(log_buffer, "> slopchop scan\n{output}");
+
+    Ok((success, log_buffer))
+}
+
+/// The check commands for an apply that touched `touched`: those of every
+/// `[verify.*]` pipeline matching a touched file or manifest, in name order and scoped
+/// to the touched packages where the pipeline asks, or
+/// `commands.check` when no pipelines are configured.
+#[must_use]
+pub fn check_commands(config: &Config, touched: &[String]) -> Vec<CommandStep> {
+    if config.verify.is_empty() {
+        return config.commands.get("check").cloned().unwrap_or_default();
+    }
+    let matching = config.verify.iter().filter(|(name, pipeline)| {
+        touched
+            .iter()
+            .any(|p| pipeline.matches_path(name, Path::new(p)))
+    });
+    let mut commands: Vec<CommandStep> = Vec::new();
+    for (_, pipeline) in matching {
+        for cmd in &pipeline.commands {
+            let run = if pipeline.scoped {
+                scope::scope_command(cmd, touched, Path::new("."))
+            } else {
+                cmd.clone()
+            };
+            let cmd = CommandStep {
+                cwd: pipeline.cwd.clone(),
+                ..CommandStep::line(run)
+            };
+            if !commands.contains(&cmd) {
+                commands.push(cmd);
+            }
+        }
+    }
+    commands
+}
+
+/// Runs the `[hooks] post_apply` commands in order, stopping at the first
+/// failure. Returns `(success, log_output)`.
+///
+/// # Errors
+/// Returns error if a command cannot be started.
+pub fn run_post_apply(ctx: &ApplyContext) -> Result<(bool, String)> {
+    let hooks = &ctx.config.hooks.post_apply;
+    let mut log_buffer = String::new();
+    if hooks.is_empty() {
+        return Ok((true, log_buffer));
+    }
+    println!("{}", "\n> Running post-apply hooks...".blue().bold());
+    for cmd in hooks {
+        let step = CommandStep::line(cmd.clone());
+        let (success, output) = run_check_command(&step, ctx.config.preferences.verify_timeout);
+        let _ = writeln!(log_buffer, "> {cmd}\n{output}");
+        if !success {
+            return Ok((false, log_buffer));
+        }
+    }
+    Ok((true, log_buffer))
+}
+
+/// Runs `step` with a spinner, killing it after `timeout` seconds. A
+/// command that can't start fails.
+fn run_check_command(step: &CommandStep, timeout: u64) -> (bool, String) {
+    let sp = Spinner::start(&step.run);
+    let mut args = step.args();
+    let json = diagnostics::with_json(&mut args);
+    let Some(command) = step.command_with(&args) else {
+        sp.stop(true);
+        return (true, String::new());
+    };
+
+    let output = match run_limited(command, timeout) {
+        Ok(output) => output,
+        Err(e) => {
+            sp.stop(false);
+            let msg = format!("Cannot start `{}`: {e}", step.run);
+            eprintln!("{}", msg.red());
+            return (false, msg);
+        }
+    };
+    let Some(output) = output else {
+        sp.stop(false);
+        let msg = format!("TIMED OUT after {timeout}s; the command was killed.");
+        eprintln!("{}", msg.red());
+        return (false, msg);
+    };
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let (shown, logged) = if json {
+        let parsed = diagnostics::parse(&stdout);
+        (parsed.rendered.clone() + &parsed.other, parsed.summary())
+    } else {
+        (stdout.to_string(), stdout.to_string())
+    };
+
+    let success = output.status.success();
+    sp.stop(success);
+
+    if !success {
+        print!("{shown}");
+        eprint!("{stderr}");
+    }
+
+    (success, format!("{logged}\n{stderr}"))
+}
+
+/// Runs `command`, killing it and its children after `timeout` seconds (0
+/// waits forever). Returns `None` if it timed out.
+fn run_limited(mut command: Command, timeout: u64) -> Result<Option<Output>> {
+    let mut child = command
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()?;
+    if timeout == 0 {
+        return Ok(Some(child.wait_with_output()?));
+    }
+    let stdout = read_pipe(child.stdout.take());
+    let stderr = read_pipe(child.stderr.take());
+    let deadline = Instant::now() + Duration::from_secs(timeout);
+    while Instant::now() < deadline {
+        if let Some(status) = child.try_wait()? {
+            return Ok(Some(Output {
+                status,
+                stdout: stdout.join().unwrap_or_default(),
+                stderr: stderr.join().unwrap_or_default(),
+            }));
+        }
+        thread::sleep(Duration::from_millis(50));
+    }
+    // `cargo test` leaves the hung test binary as its child.
+    let _ = Command::new("pkill")
+        .args(["-9", "-P", &child.id().to_string()])
+        .output();
+    let _ = child.kill();
+    let _ = child.wait();
+    Ok(None)
+}
+
+fn read_pipe<R: Read + Send + 'static>(pipe: Option<R>) -> JoinHandle<Vec<u8>> {
+    thread::spawn(move || {
+        let mut buf = Vec::new();
+        if let Some(mut pipe) = pipe {
+            let _ = pipe.read_to_end(&mut buf);
+        }
+        buf
+    })
+}
+
+fn run_slopchop_check() -> Result<(bool, String)> {
+    // slopchop check is fast, but we can spin on it too for consistency if needed.
+    // However, it outputs its own colorized report.
+    // For now, let's keep it simple as it was.
+    let output = Command::new("slopchop").output()?;
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+    let combined = format!("{stdout}\n{stderr}");
+
+    print!("{stdout}");
+    eprint!("{stderr}");
+
+    Ok((output.status.success(), combined))
+}
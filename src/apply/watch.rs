@@ -0,0 +1,96 @@
+// src/apply/watch.rs
+//! `apply --watch`: polls the clipboard and runs the apply flow whenever a
+//! new slopchop payload is copied, so the AI loop needs no trip to the
+//! terminal.
+//!
+//! Packs, prompts, and feedback carry the markers too, so text slopchop
+//! copied itself is never taken for a payload.
+
+use std::path::Path;
+use std::thread;
+use std::time::Duration;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::types::ApplyContext;
+use crate::clipboard::{self, history};
+
+const POLL: Duration = Duration::from_millis(500);
+
+/// Heads the system prompt in every pack and prompt.
+const PROMPT_HEADING: &str = "SYSTEM MANDATE: THE SLOPCHOP PROTOCOL";
+
+/// True if `text` declares a plan or manifest, as a reply does and a
+/// stray file block does not, and is not a slopchop prompt.
+#[must_use]
+pub fn is_payload(text: &str) -> bool {
+    (text.contains("#__SLOPCHOP_PLAN__#") || text.contains("#__SLOPCHOP_MANIFEST__#"))
+        && !text.contains(PROMPT_HEADING)
+}
+
+/// Tracks the last clipboard content seen, so each payload is applied once.
+#[derive(Debug, Default)]
+pub struct Watcher {
+    last: String,
+}
+
+impl Watcher {
+    /// Starts from `current`, so a payload already on the clipboard is not
+    /// applied.
+    #[must_use]
+    pub fn new(current: String) -> Self {
+        Self { last: current }
+    }
+
+    /// `content` if it changed since the last poll and is a payload.
+    pub fn next(&mut self, content: String) -> Option<String> {
+        if content == self.last {
+            return None;
+        }
+        self.last = content;
+        is_payload(&self.last).then(|| self.last.clone())
+    }
+
+    /// Takes `content` as seen without applying it, e.g. what an apply
+    /// just copied back for the AI.
+    pub fn skip(&mut self, content: String) {
+        self.last = content;
+    }
+}
+
+/// Applies each new payload copied to the clipboard until interrupted. A
+/// failed apply is reported and watching goes on.
+///
+/// # Errors
+/// Currently infallible; returns Result like the other apply modes.
+pub fn run(ctx: &ApplyContext) -> Result<()> {
+    let mut watcher = Watcher::new(clipboard::read_clipboard().unwrap_or_default());
+    print_waiting();
+    loop {
+        thread::sleep(POLL);
+        let Ok(content) = clipboard::read_clipboard() else {
+            continue;
+        };
+        let Some(payload) = watcher.next(content) else {
+            continue;
+        };
+        if history::contains(Path::new("."), &payload) {
+            continue;
+        }
+        println!("{}", "📥 Payload detected on the clipboard".cyan().bold());
+        match super::process_input(&payload, ctx) {
+            Ok(outcome) => super::print_result(&outcome),
+            Err(e) => eprintln!("{} {e}", "❌ Apply failed:".red().bold()),
+        }
+        watcher.skip(clipboard::read_clipboard().unwrap_or_default());
+        print_waiting();
+    }
+}
+
+fn print_waiting() {
+    println!(
+        "{}",
+        "👀 Watching the clipboard for payloads (Ctrl+C to stop)...".dimmed()
+    );
+}
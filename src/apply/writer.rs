@@ -1,11 +1,14 @@
 // src/apply/writer.rs
-use crate::apply::types::{ApplyOutcome, ExtractedFiles, Manifest, Operation};
+use crate::apply::edit::{self, EditBlock};
+use crate::apply::preserve::{self, WriteOptions};
+use crate::apply::types::{ApplyOutcome, ExtractedFiles, Manifest, ManifestEntry, Operation};
+use crate::apply::undo::{self, SetRecord};
+use crate::apply::{messages, syntax};
 use anyhow::{anyhow, Context, Result};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::time::{SystemTime, UNIX_EPOCH};
 
-const BACKUP_DIR: &str = ".slopchop_apply_backup";
+pub const BACKUP_DIR: &str = ".slopchop_apply_backup";
 
 /// Writes changes (updates, new files, deletes) to disk.
 ///
@@ -16,31 +19,158 @@ pub fn write_files(
     files: &ExtractedFiles,
     root: Option<&Path>,
 ) -> Result<ApplyOutcome> {
-    let backup_path = create_backup(manifest, root)?;
-    let mut written = Vec::new();
-    let mut deleted = Vec::new();
+    let opts = WriteOptions {
+        check_syntax: true,
+        ..WriteOptions::default()
+    };
+    write_all(manifest, files, &[], root, &opts)
+}
 
-    for entry in manifest {
-        match entry.operation {
-            Operation::Delete => {
-                delete_file(&entry.path, root)?;
-                deleted.push(entry.path.clone());
-            }
-            Operation::Update | Operation::New => {
-                if let Some(file_data) = files.get(&entry.path) {
-                    write_single_file(&entry.path, &file_data.content, root)?;
-                    written.push(entry.path.clone());
+/// Writes files and search/replace edits as one batch. If any write fails,
+/// or (with `opts.check_syntax`) a written file no longer parses, every
+/// change is rolled back from the backup taken first.
+///
+/// # Errors
+/// Returns error if a write fails (after rolling back) or the rollback
+/// itself fails.
+pub fn write_all(
+    manifest: &Manifest,
+    files: &ExtractedFiles,
+    edits: &[EditBlock],
+    root: Option<&Path>,
+    opts: &WriteOptions,
+) -> Result<ApplyOutcome> {
+    let backup = create_backup(manifest, root)?;
+    let batch = Batch {
+        manifest,
+        files,
+        edits,
+        root,
+        backup: backup.as_deref(),
+        opts,
+    };
+    match batch.perform() {
+        Ok(outcome @ ApplyOutcome::Success { .. }) => Ok(outcome),
+        Ok(outcome) => {
+            rollback(backup.as_deref(), root)?;
+            Ok(outcome)
+        }
+        Err(e) => {
+            rollback(backup.as_deref(), root)?;
+            Err(e.context("Apply failed; all changes were rolled back"))
+        }
+    }
+}
+
+struct Batch<'a> {
+    manifest: &'a Manifest,
+    files: &'a ExtractedFiles,
+    edits: &'a [EditBlock],
+    root: Option<&'a Path>,
+    backup: Option<&'a Path>,
+    opts: &'a WriteOptions,
+}
+
+impl Batch<'_> {
+    fn perform(&self) -> Result<ApplyOutcome> {
+        let mut written = Vec::new();
+        let mut deleted = Vec::new();
+        for entry in self.manifest {
+            match &entry.operation {
+                Operation::Delete => {
+                    delete_file(&entry.path, self.root)?;
+                    deleted.push(entry.path.clone());
+                }
+                Operation::Rename(to) => {
+                    rename_file(&entry.path, to, self.root)?;
+                    self.write_sent(to)?;
+                    deleted.push(entry.path.clone());
+                    written.push(to.clone());
+                }
+                Operation::Update | Operation::New => {
+                    if self.write_sent(&entry.path)? {
+                        written.push(entry.path.clone());
+                    }
                 }
             }
         }
+        written.extend(write_edits(self.edits, self.root, self.opts)?);
+
+        let errors: Vec<String> = written
+            .iter()
+            .filter_map(|p| self.syntax_error(p))
+            .collect();
+        if !errors.is_empty() {
+            return Ok(ApplyOutcome::ValidationFailure {
+                ai_message: messages::format_ai_rejection(&[], &errors),
+                errors,
+                missing: vec![],
+            });
+        }
+        Ok(ApplyOutcome::Success {
+            written,
+            deleted,
+            roadmap_results: Vec::new(),
+            backed_up: self.backup.is_some(),
+        })
+    }
+
+    /// Writes the file block sent for `path`, if any.
+    fn write_sent(&self, path: &str) -> Result<bool> {
+        let Some(file_data) = self.files.get(path) else {
+            return Ok(false);
+        };
+        preserve::write(
+            &resolve_path(path, self.root),
+            path,
+            &file_data.content,
+            self.opts,
+        )?;
+        Ok(true)
+    }
+
+    fn syntax_error(&self, path: &str) -> Option<String> {
+        if !self.opts.check_syntax {
+            return None;
+        }
+        let new = fs::read_to_string(resolve_path(path, self.root)).ok()?;
+        let old = self
+            .backup
+            .and_then(|b| fs::read_to_string(b.join(path)).ok());
+        let line = syntax::introduced_error(path, &new, old.as_deref())?;
+        Some(format!("{path}:{line}: syntax error after apply"))
     }
+}
+
+fn rollback(backup: Option<&Path>, root: Option<&Path>) -> Result<()> {
+    let Some(backup) = backup else {
+        return Ok(());
+    };
+    let root = root.unwrap_or_else(|| Path::new("."));
+    undo::restore(root, &undo::open(backup.to_path_buf()))
+        .context("Rollback failed; restore manually from the backup")
+}
 
-    Ok(ApplyOutcome::Success {
-        written,
-        deleted,
-        roadmap_results: Vec::new(),
-        backed_up: backup_path.is_some(),
-    })
+/// Applies search/replace edits in place, returning the edited paths.
+///
+/// # Errors
+/// Returns error if a file cannot be read or written, or an anchor no
+/// longer matches.
+pub fn write_edits(
+    edits: &[EditBlock],
+    root: Option<&Path>,
+    opts: &WriteOptions,
+) -> Result<Vec<String>> {
+    let mut written = Vec::new();
+    for (path_str, blocks) in edit::by_path(edits) {
+        let path = resolve_path(path_str, root);
+        let current = fs::read_to_string(&path)
+            .with_context(|| format!("Failed to read {}", path.display()))?;
+        let updated = edit::apply(&current, &blocks).map_err(|e| anyhow!(e))?;
+        preserve::write(&resolve_path(path_str, root), path_str, &updated, opts)?;
+        written.push(path_str.to_string());
+    }
+    Ok(written)
 }
 
 fn delete_file(path_str: &str, root: Option<&Path>) -> Result<()> {
@@ -51,15 +181,15 @@ fn delete_file(path_str: &str, root: Option<&Path>) -> Result<()> {
     Ok(())
 }
 
-fn write_single_file(path_str: &str, content: &str, root: Option<&Path>) -> Result<()> {
-    let path = resolve_path(path_str, root);
-
-    if let Some(parent) = path.parent() {
-        fs::create_dir_all(parent)
-            .map_err(|e| anyhow!("Failed to create directory {}: {e}", parent.display()))?;
+fn rename_file(from: &str, to: &str, root: Option<&Path>) -> Result<()> {
+    let (src, dest) = (resolve_path(from, root), resolve_path(to, root));
+    if dest.exists() {
+        return Err(anyhow!("Cannot rename {from} to {to}: {to} already exists"));
     }
-    fs::write(&path, content).map_err(|e| anyhow!("Failed to write {}: {e}", path.display()))?;
-    Ok(())
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::rename(&src, &dest).with_context(|| format!("Failed to rename {from} to {to}"))
 }
 
 fn resolve_path(path_str: &str, root: Option<&Path>) -> PathBuf {
@@ -69,26 +199,37 @@ fn resolve_path(path_str: &str, root: Option<&Path>) -> PathBuf {
     }
 }
 
+/// Saves the files `manifest` touches and notes the ones it creates, so
+/// `slopchop undo` can roll the apply back.
 fn create_backup(manifest: &Manifest, root: Option<&Path>) -> Result<Option<PathBuf>> {
-    let targets: Vec<&String> = manifest
-        .iter()
-        .map(|e| &e.path)
-        .filter(|p| resolve_path(p, root).exists())
-        .collect();
-
-    if targets.is_empty() {
+    if manifest.is_empty() {
         return Ok(None);
     }
+    let (targets, created): (Vec<&ManifestEntry>, Vec<&ManifestEntry>) = manifest
+        .iter()
+        .partition(|e| resolve_path(&e.path, root).exists());
 
-    let timestamp = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
     let root_path = root.map_or_else(|| PathBuf::from("."), Path::to_path_buf);
-    let backup_folder = root_path.join(BACKUP_DIR).join(timestamp.to_string());
-
-    fs::create_dir_all(&backup_folder).context("Failed to create backup directory")?;
+    let backup_folder = undo::new_set(&root_path)?;
 
-    for path_str in targets {
-        backup_single_file(path_str, &backup_folder, root)?;
+    for entry in targets {
+        backup_single_file(&entry.path, &backup_folder, root)?;
     }
+    let renamed = manifest.iter().filter_map(|e| match &e.operation {
+        Operation::Rename(to) if !resolve_path(to, root).exists() => Some(to),
+        _ => None,
+    });
+    let record = SetRecord {
+        created: created
+            .iter()
+            .filter(|e| e.operation != Operation::Delete)
+            .map(|e| &e.path)
+            .chain(renamed)
+            .cloned()
+            .collect(),
+        commit: None,
+    };
+    record.save(&backup_folder)?;
 
     Ok(Some(backup_folder))
 }
@@ -0,0 +1,125 @@
+// src/baseline.rs
+//! Violation baselines for adopting slopchop on an existing codebase.
+//!
+//! `slopchop baseline` records every current violation. Later scans move
+//! matching violations into `FileReport::baselined`, so only new ones fail.
+
+use crate::error::{Result, SlopChopError};
+use crate::types::{ScanReport, Violation};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+pub const BASELINE_FILE: &str = ".slopchop_baseline.json";
+
+/// A recorded violation. Rows are not stored so edits elsewhere in the
+/// file don't invalidate the baseline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BaselineEntry {
+    pub path: String,
+    pub rule: String,
+    pub message: String,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub violations: Vec<BaselineEntry>,
+}
+
+impl Baseline {
+    /// Snapshots every violation in `report`.
+    #[must_use]
+    pub fn from_report(report: &ScanReport) -> Self {
+        let violations = report
+            .files
+            .iter()
+            .flat_map(|f| f.violations.iter().map(|v| entry(&f.path, v)))
+            .collect();
+        Self { violations }
+    }
+
+    /// Loads a baseline, returning `None` if the file does not exist.
+    ///
+    /// # Errors
+    /// Returns error if the file exists but cannot be read or parsed.
+    pub fn load(path: &Path) -> Result<Option<Self>> {
+        if !path.exists() {
+            return Ok(None);
+        }
+        let content = fs::read_to_string(path).map_err(|source| SlopChopError::Io {
+            source,
+            path: path.to_path_buf(),
+        })?;
+        let baseline = serde_json::from_str(&content).map_err(|e| {
+            SlopChopError::Other(format!("Invalid baseline {}: {e}", path.display()))
+        })?;
+        Ok(Some(baseline))
+    }
+
+    /// Writes the baseline as pretty-printed JSON.
+    ///
+    /// # Errors
+    /// Returns error if serialization or the write fails.
+    pub fn save(&self, path: &Path) -> Result<()> {
+        let json =
+            serde_json::to_string_pretty(self).map_err(|e| SlopChopError::Other(e.to_string()))?;
+        fs::write(path, json + "\n").map_err(|source| SlopChopError::Io {
+            source,
+            path: path.to_path_buf(),
+        })
+    }
+
+    /// Moves violations present in the baseline out of the failing set.
+    /// Each entry absorbs at most one violation.
+    pub fn apply(&self, report: &mut ScanReport) {
+        let mut budget: HashMap<(String, String, String), usize> = HashMap::new();
+        for e in &self.violations {
+            *budget.entry(key(e)).or_default() += 1;
+        }
+        for file in &mut report.files {
+            let all = std::mem::take(&mut file.violations);
+            for v in all {
+                let slot = budget.get_mut(&key(&entry(&file.path, &v)));
+                match slot {
+                    Some(n) if *n > 0 => {
+                        *n -= 1;
+                        file.baselined.push(v);
+                    }
+                    _ => file.violations.push(v),
+                }
+            }
+        }
+        report.total_violations = report.files.iter().map(|f| f.violations.len()).sum();
+    }
+}
+
+/// Applies `.slopchop_baseline.json` from the working directory, if present.
+///
+/// # Errors
+/// Returns error if the baseline file is unreadable.
+pub fn apply_local(report: &mut ScanReport) -> Result<()> {
+    if let Some(baseline) = Baseline::load(Path::new(BASELINE_FILE))? {
+        baseline.apply(report);
+    }
+    Ok(())
+}
+
+fn entry(path: &Path, v: &Violation) -> BaselineEntry {
+    BaselineEntry {
+        path: path.to_string_lossy().replace('\\', "/"),
+        rule: v.rule(),
+        message: v.message.clone(),
+    }
+}
+
+/// Messages embed line numbers and scores; mask digits so they still match
+/// after unrelated edits shift the code.
+fn key(e: &BaselineEntry) -> (String, String, String) {
+    let message = e
+        .message
+        .chars()
+        .map(|c| if c.is_ascii_digit() { '#' } else { c })
+        .collect();
+    (e.path.clone(), e.rule.clone(), message)
+}
@@ -0,0 +1,238 @@
+// src/bin/slopchop/args.rs
+//! Command-line arguments.
+
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand};
+
+use slopchop_core::cli::config::ConfigCommand;
+use slopchop_core::pack::order::FileOrder;
+use slopchop_core::pack::OutputFormat;
+use slopchop_core::reporting::ReportFormat;
+use slopchop_core::roadmap_v2::RoadmapV2Command;
+use slopchop_core::tokens::TokenModel;
+
+use crate::pack::{ExtraArgs, LimitArgs, OffArgs, RemoteArgs};
+use crate::scan::ScanArgs;
+
+#[derive(Parser)]
+#[command(
+    name = "slopchop",
+    version,
+    about = "Code quality guardian",
+    after_help = "Exit codes: 0 clean, 1 violations or a failed check command, 2 error."
+)]
+pub struct Cli {
+    #[command(subcommand)]
+    pub command: Option<Commands>,
+    #[arg(long)]
+    pub ui: bool,
+    #[arg(long)]
+    pub init: bool,
+    #[command(flatten)]
+    pub scan: ScanArgs,
+    /// Tokenizer for token counts [default: `[tokens] model` or cl100k].
+    #[arg(long, value_enum, global = true)]
+    pub model: Option<TokenModel>,
+}
+
+#[derive(Subcommand)]
+pub enum Commands {
+    Prompt {
+        #[arg(long, short)]
+        copy: bool,
+    },
+    /// Run the `check` commands, then the structural scan.
+    Check {
+        #[command(flatten)]
+        scan: ScanArgs,
+        /// Only scan files with uncommitted changes (staged, unstaged, or untracked).
+        #[arg(long)]
+        changed: bool,
+        /// Scan every file even if `[defaults.check]` sets changed.
+        #[arg(long, overrides_with = "changed")]
+        no_changed: bool,
+        /// Also scan files changed since this ref, e.g. `origin/main` (implies --changed).
+        #[arg(long, value_name = "REF")]
+        since: Option<String>,
+        /// Report format [default: `[defaults.check]` or text].
+        #[arg(long, value_enum)]
+        format: Option<ReportFormat>,
+    },
+    Fix,
+    /// Record current violations so only new ones fail.
+    Baseline,
+    /// Rank files by git churn times violations and complexity.
+    Hotspots {
+        /// How many files to show.
+        #[arg(long, short, default_value = "20")]
+        limit: usize,
+        #[arg(long)]
+        json: bool,
+    },
+    /// Show violations introduced and fixed since REF, or since the last
+    /// recorded scan when REF is omitted.
+    Diff {
+        rev: Option<String>,
+    },
+    /// Write a standalone HTML report.
+    Report {
+        /// Output file.
+        #[arg(long, value_name = "FILE")]
+        html: PathBuf,
+    },
+    /// Keep a warm scanner running for `check` and `pack` to use.
+    Daemon {
+        #[arg(long)]
+        stop: bool,
+    },
+    /// Apply an AI response (read from the clipboard by default).
+    Apply {
+        /// Review each file's diff and accept, skip, or edit it.
+        #[arg(long)]
+        review: bool,
+        /// Skip the review even if `[defaults.apply]` sets it.
+        #[arg(long, overrides_with = "review")]
+        no_review: bool,
+        /// Validate and show the diff of every file without writing anything.
+        #[arg(long, conflicts_with = "review")]
+        dry_run: bool,
+        /// Read the response from a file, e.g. a saved transcript.
+        #[arg(long, value_name = "FILE", conflicts_with_all = ["stdin", "url"])]
+        input: Option<PathBuf>,
+        /// Read the response from stdin; needs --force or --dry-run, as the
+        /// plan can't be confirmed.
+        #[arg(long, conflicts_with_all = ["url", "review"])]
+        stdin: bool,
+        /// Fetch the response from an https:// URL with curl; gist links
+        /// resolve to raw content.
+        #[arg(long, value_name = "URL")]
+        url: Option<String>,
+        /// Read a response split over several messages from the clipboard,
+        /// one part at a time, and merge the parts.
+        #[arg(long, conflicts_with_all = ["input", "stdin", "url"])]
+        multi: bool,
+        /// Keep running and apply each new payload copied to the clipboard.
+        #[arg(long, conflicts_with_all = ["input", "stdin", "url", "multi"])]
+        watch: bool,
+        /// Apply without asking to confirm the plan.
+        #[arg(long)]
+        force: bool,
+        /// Show past applies instead of applying.
+        #[arg(long, conflicts_with_all = ["review", "dry_run", "input", "stdin", "url", "multi", "watch", "force"])]
+        history: bool,
+    },
+    /// List recent packs and prompts, or copy one again.
+    History {
+        /// Entry to copy or print [default: newest].
+        id: Option<String>,
+        #[arg(long, short)]
+        copy: bool,
+        #[arg(long, short, conflicts_with = "copy")]
+        stdout: bool,
+    },
+    /// Roll back the last apply (or backup set ID) from its backup.
+    Undo {
+        /// Backup set to restore [default: newest].
+        id: Option<String>,
+        /// List backup sets instead of restoring one.
+        #[arg(long)]
+        list: bool,
+        /// Revert the commit the apply made instead of copying files back.
+        #[arg(long)]
+        revert: bool,
+    },
+    Clean {
+        #[arg(long, short)]
+        commit: bool,
+    },
+    /// Edit warden.toml interactively, or check it.
+    Config {
+        #[command(subcommand)]
+        action: Option<ConfigCommand>,
+        /// Print the JSON Schema of warden.toml.
+        #[arg(long)]
+        schema: bool,
+    },
+    Dashboard,
+    /// Update a project from an older version: roadmap, deprecated options, ignore patterns.
+    Migrate {
+        /// Report what would change without writing.
+        #[arg(long)]
+        dry_run: bool,
+    },
+    #[command(subcommand)]
+    Roadmap(RoadmapV2Command),
+    /// Pack the project (or a --remote repository) into context for an AI.
+    Pack {
+        #[arg(long, short)]
+        stdout: bool,
+        #[arg(long, short)]
+        copy: bool,
+        #[arg(long)]
+        noprompt: bool,
+        /// Pack format [default: `[defaults.pack]` or text].
+        #[arg(long, value_enum)]
+        format: Option<OutputFormat>,
+        #[arg(long)]
+        skeleton: bool,
+        #[arg(long)]
+        git_only: bool,
+        #[arg(long)]
+        no_git: bool,
+        #[arg(long)]
+        code_only: bool,
+        /// Leave out test files and tests/ directories.
+        #[arg(long)]
+        no_tests: bool,
+        /// Leave out generated files ([pack] generated globs).
+        #[arg(long)]
+        no_generated: bool,
+        #[arg(long, short)]
+        verbose: bool,
+        #[arg(long, value_name = "FILE")]
+        target: Option<PathBuf>,
+        #[arg(long, short, value_name = "FILE")]
+        focus: Vec<PathBuf>,
+        #[arg(long, default_value = "1")]
+        depth: usize,
+        #[command(flatten)]
+        limits: LimitArgs,
+        /// Pack the diff since BASE [default: HEAD] and the changed files instead of the repo.
+        #[arg(long, value_name = "BASE", num_args = 0..=1, default_missing_value = "HEAD")]
+        diff: Option<String>,
+        /// Print every packed file's token count and share of the total (also with --verbose).
+        #[arg(long)]
+        stats: bool,
+        /// Order of files in the pack [default: `[defaults.pack]` or path].
+        #[arg(long, value_enum)]
+        order: Option<FileOrder>,
+        #[command(flatten)]
+        extras: ExtraArgs,
+        #[command(flatten)]
+        off: OffArgs,
+        #[command(flatten)]
+        remote: RemoteArgs,
+    },
+    Trace {
+        #[arg(value_name = "FILE")]
+        file: PathBuf,
+        #[arg(long, short, default_value = "2")]
+        depth: usize,
+        #[arg(long, short, default_value = "4000")]
+        budget: usize,
+    },
+    /// Show token and file counts per directory under PATH.
+    Tokens {
+        path: Option<PathBuf>,
+        /// Directory levels to show.
+        #[arg(long, short, default_value = "2")]
+        depth: usize,
+        #[arg(long)]
+        json: bool,
+    },
+    Map {
+        #[arg(long, short)]
+        deps: bool,
+    },
+}
@@ -0,0 +1,289 @@
+// src/bin/slopchop/main.rs
+use std::fs;
+use std::io;
+use std::path::PathBuf;
+use std::process;
+
+use anyhow::Result;
+use clap::Parser;
+use colored::Colorize;
+
+use slopchop_core::apply::input::InputSource;
+use slopchop_core::apply::{ledger, undo};
+use slopchop_core::cli::{self, ApplyArgs, CheckArgs};
+use slopchop_core::clipboard;
+use slopchop_core::config::{files, Config};
+use slopchop_core::daemon;
+use slopchop_core::history;
+use slopchop_core::hotspots;
+use slopchop_core::project;
+use slopchop_core::roadmap_v2::handle_command;
+use slopchop_core::tokens::{report, Tokenizer};
+use slopchop_core::tui::state::App;
+use slopchop_core::wizard;
+use slopchop_core::workspace::Workspace;
+
+mod args;
+mod pack;
+mod scan;
+
+use args::{Cli, Commands};
+
+fn main() {
+    if let Err(e) = run() {
+        eprintln!("{} {e}", "error:".red().bold());
+        process::exit(2);
+    }
+}
+
+fn run() -> Result<()> {
+    let cli = Cli::parse();
+    if let Some(model) = cli.model {
+        Tokenizer::force(model);
+    }
+    if cli.init {
+        wizard::run()?;
+        return Ok(());
+    }
+    ensure_config_exists();
+    dispatch(&cli)
+}
+
+fn dispatch(cli: &Cli) -> Result<()> {
+    match &cli.command {
+        Some(cmd) => dispatch_command(cmd),
+        None if cli.ui => run_tui(),
+        None => scan::run(&cli.scan),
+    }
+}
+
+fn dispatch_command(cmd: &Commands) -> Result<()> {
+    match cmd {
+        Commands::Pack { .. } | Commands::Trace { .. } | Commands::Map { .. } => {
+            dispatch_analysis(cmd)
+        }
+
+        Commands::Hotspots { .. }
+        | Commands::Tokens { .. }
+        | Commands::Report { .. }
+        | Commands::Diff { .. }
+        | Commands::History { .. } => dispatch_reports(cmd),
+
+        Commands::Check { .. }
+        | Commands::Fix
+        | Commands::Baseline
+        | Commands::Clean { .. }
+        | Commands::Config { .. }
+        | Commands::Dashboard => dispatch_maintenance(cmd),
+
+        Commands::Apply { .. }
+        | Commands::Undo { .. }
+        | Commands::Prompt { .. }
+        | Commands::Daemon { .. } => dispatch_tools(cmd),
+
+        Commands::Roadmap(_) | Commands::Migrate { .. } => dispatch_project(cmd),
+    }
+}
+
+fn dispatch_maintenance(cmd: &Commands) -> Result<()> {
+    match cmd {
+        Commands::Check {
+            scan,
+            changed,
+            no_changed,
+            since,
+            format,
+        } => {
+            cli::handle_check(&CheckArgs {
+                fail_on: scan.fail_on,
+                max_violations: scan.max_violations,
+                changed: *changed,
+                no_changed: *no_changed,
+                since: since.clone(),
+                format: *format,
+                group_by: scan.group_by,
+                blame: scan.blame,
+                no_blame: scan.no_blame,
+                package: scan.package.clone(),
+            })?;
+            Ok(())
+        }
+        Commands::Fix => {
+            cli::handle_fix()?;
+            Ok(())
+        }
+        Commands::Baseline => {
+            cli::handle_baseline()?;
+            Ok(())
+        }
+        Commands::Config { action, schema } => {
+            cli::config::handle_config(action.as_ref(), *schema)?;
+            Ok(())
+        }
+        Commands::Dashboard => {
+            cli::handle_dashboard()?;
+            Ok(())
+        }
+        Commands::Clean { commit } => {
+            slopchop_core::clean::run(*commit)?;
+            Ok(())
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn dispatch_tools(cmd: &Commands) -> Result<()> {
+    match cmd {
+        Commands::Apply { .. } => dispatch_apply(cmd),
+        Commands::Undo { id, list, revert } => undo::run(id.as_deref(), *list, *revert),
+        Commands::Prompt { copy } => {
+            cli::handle_prompt(*copy)?;
+            Ok(())
+        }
+        Commands::Daemon { stop: false } => daemon::run(),
+        Commands::Daemon { stop: true } => daemon::client::stop(),
+        _ => unreachable!(),
+    }
+}
+
+fn dispatch_project(cmd: &Commands) -> Result<()> {
+    match cmd {
+        Commands::Roadmap(sub) => {
+            handle_command(sub.clone())?;
+            Ok(())
+        }
+        Commands::Migrate { dry_run } => {
+            slopchop_core::migrate::run(*dry_run)?;
+            Ok(())
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn dispatch_apply(cmd: &Commands) -> Result<()> {
+    match cmd {
+        Commands::Apply { history: true, .. } => ledger::print(),
+        Commands::Apply {
+            review,
+            no_review,
+            dry_run,
+            input,
+            stdin,
+            url,
+            multi,
+            watch,
+            force,
+            ..
+        } => {
+            cli::handle_apply(&ApplyArgs {
+                review: *review,
+                no_review: *no_review,
+                dry_run: *dry_run,
+                force: *force,
+                watch: *watch,
+                input: input_source(input.as_ref(), *stdin, url.as_ref(), *multi),
+            })?;
+            Ok(())
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn input_source(
+    input: Option<&PathBuf>,
+    stdin: bool,
+    url: Option<&String>,
+    multi: bool,
+) -> InputSource {
+    match (input, url) {
+        (Some(path), _) => InputSource::File(path.clone()),
+        (_, Some(url)) => InputSource::Url(url.clone()),
+        _ if stdin => InputSource::Stdin,
+        _ if multi => InputSource::ClipboardParts,
+        _ => InputSource::Clipboard,
+    }
+}
+
+fn dispatch_analysis(cmd: &Commands) -> Result<()> {
+    match cmd {
+        Commands::Trace {
+            file,
+            depth,
+            budget,
+        } => {
+            cli::handle_trace(file, *depth, *budget)?;
+            Ok(())
+        }
+        Commands::Map { deps } => {
+            cli::handle_map(*deps)?;
+            Ok(())
+        }
+        Commands::Pack { .. } => pack::dispatch(cmd),
+        _ => unreachable!(),
+    }
+}
+
+fn dispatch_reports(cmd: &Commands) -> Result<()> {
+    match cmd {
+        Commands::Hotspots { limit, json } => hotspots::run(*limit, *json),
+        Commands::Tokens { path, depth, json } => report::run(path.as_deref(), *depth, *json),
+        Commands::Report { html } => cli::handle_report(html),
+        Commands::Diff { rev } => history::diff::run(rev.as_deref()),
+        Commands::History { id, copy, stdout } => {
+            load_config();
+            clipboard::history::run(id.as_deref(), *copy, *stdout)
+        }
+        _ => unreachable!(),
+    }
+}
+
+fn run_tui() -> Result<()> {
+    use crossterm::event::{DisableMouseCapture, EnableMouseCapture};
+    use crossterm::execute;
+    use crossterm::terminal::{
+        disable_raw_mode, enable_raw_mode, EnterAlternateScreen, LeaveAlternateScreen,
+    };
+    use ratatui::backend::CrosstermBackend;
+    use ratatui::Terminal;
+
+    let config = load_config();
+    config.validate()?;
+    let workspace = Workspace::load(config);
+    workspace.validate_packages()?;
+    let report = workspace.scan(workspace.discover()?);
+
+    enable_raw_mode()?;
+    let mut stdout = io::stdout();
+    execute!(stdout, EnterAlternateScreen, EnableMouseCapture)?;
+    let mut term = Terminal::new(CrosstermBackend::new(stdout))?;
+
+    let mut app = App::new(report);
+    let _ = app.run(&mut term);
+
+    disable_raw_mode()?;
+    execute!(
+        term.backend_mut(),
+        LeaveAlternateScreen,
+        DisableMouseCapture
+    )?;
+    term.show_cursor()?;
+    Ok(())
+}
+
+fn load_config() -> Config {
+    let mut c = Config::new();
+    c.load_local_config();
+    c
+}
+
+fn ensure_config_exists() {
+    let path = files::local(files::CONFIG_FILE);
+    if path.exists() {
+        return;
+    }
+    let proj = project::ProjectType::detect();
+    let content = project::generate_toml(proj, project::Strictness::Standard);
+    if fs::write(&path, &content).is_ok() {
+        eprintln!("{}", format!("✓ Created {}", path.display()).dimmed());
+    }
+}
@@ -0,0 +1,178 @@
+// src/bin/slopchop/pack.rs
+//! `slopchop pack`: maps the parsed flags, then `[defaults.pack]`, onto
+//! [`PackArgs`].
+
+use anyhow::Result;
+use clap::Args;
+
+use slopchop_core::cli::{self, PackArgs};
+use slopchop_core::config::defaults::PackDefaults;
+use slopchop_core::config::GitMode;
+use slopchop_core::pack::budget::Budget;
+use slopchop_core::pack::comments::StripComments;
+use slopchop_core::pack::filter::FileFilter;
+use slopchop_core::pack::remote::Remote;
+use slopchop_core::pack::window;
+use slopchop_core::pack::{PackExtras, PackOutput};
+
+use crate::args::Commands;
+
+/// Token budget and context-window flags.
+#[derive(Args)]
+pub struct LimitArgs {
+    /// Skeletonize or drop files, lowest priority first, to fit this many tokens.
+    #[arg(long, value_name = "TOKENS")]
+    budget: Option<usize>,
+    /// Context window to warn against: 128k, 200k, 1m, or a token count [default: the model's].
+    #[arg(long, value_name = "SIZE", value_parser = window::parse)]
+    window: Option<usize>,
+    /// Fail when the pack is over --budget or the context window instead of warning.
+    #[arg(long)]
+    strict_budget: bool,
+}
+
+/// Flags that add to or rewrite the packed content.
+#[derive(Args)]
+pub struct ExtraArgs {
+    /// Open the pack with the branch, the last N [default: 5] commits, and uncommitted changes.
+    #[arg(long, value_name = "N", num_args = 0..=1, default_missing_value = "5")]
+    git_info: Option<usize>,
+    /// Prefix each line of a fully packed file with its line number.
+    #[arg(long)]
+    line_numbers: bool,
+    /// Drop comments from fully packed files, keeping doc comments unless `all`.
+    #[arg(long, value_enum, value_name = "MODE", num_args = 0..=1, default_missing_value = "keep-docs")]
+    strip_comments: Option<StripComments>,
+    /// List files left out of the pack (images, binaries, non-code) with sizes and types.
+    #[arg(long)]
+    assets: bool,
+    /// Leave secrets in the pack instead of replacing them with [REDACTED:<type>].
+    #[arg(long)]
+    no_redact: bool,
+}
+
+/// Flags that turn off, for one run, what `[defaults.pack]` turns on.
+#[derive(Args)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct OffArgs {
+    /// Write the pack to a file even if `[defaults.pack]` sets copy or stdout.
+    #[arg(long, conflicts_with_all = ["stdout", "copy"])]
+    file: bool,
+    #[arg(long, overrides_with = "noprompt")]
+    prompt: bool,
+    #[arg(long, overrides_with = "skeleton")]
+    no_skeleton: bool,
+    #[arg(long, overrides_with = "code_only")]
+    no_code_only: bool,
+    /// Keep test files in.
+    #[arg(long, overrides_with = "no_tests")]
+    tests: bool,
+    /// Keep generated files in.
+    #[arg(long, overrides_with = "no_generated")]
+    generated: bool,
+    #[arg(long, overrides_with = "line_numbers")]
+    no_line_numbers: bool,
+}
+
+/// `--remote` and `--ref`: the repository and revision `pack` reads
+/// instead of the current directory.
+#[derive(Args)]
+pub struct RemoteArgs {
+    /// Pack a shallow clone of this repository instead of the current directory.
+    #[arg(long, value_name = "URL")]
+    remote: Option<String>,
+    /// Branch, tag, or commit to pack with --remote [default: the default branch].
+    #[arg(long = "ref", value_name = "REF", requires = "remote")]
+    git_ref: Option<String>,
+}
+
+pub fn dispatch(cmd: &Commands) -> Result<()> {
+    if let Commands::Pack {
+        stdout,
+        copy,
+        noprompt,
+        format,
+        skeleton,
+        git_only,
+        no_git,
+        code_only,
+        no_tests,
+        no_generated,
+        verbose,
+        target,
+        focus,
+        depth,
+        limits,
+        diff,
+        stats,
+        order,
+        extras,
+        off,
+        remote,
+    } = cmd
+    {
+        let defaults = crate::load_config().defaults.pack;
+        let remote = remote.remote.as_ref().map(|url| Remote {
+            url: url.clone(),
+            git_ref: remote.git_ref.clone(),
+        });
+        let mut args = PackArgs {
+            output: PackOutput::from_flags(*stdout, *copy),
+            noprompt: *noprompt,
+            format: format
+                .clone()
+                .or_else(|| defaults.format.clone())
+                .unwrap_or_default(),
+            skeleton: *skeleton,
+            git_mode: GitMode::from_flags(*git_only, *no_git),
+            filter: FileFilter {
+                code_only: *code_only,
+                no_tests: *no_tests,
+                no_generated: *no_generated,
+            },
+            verbose: *verbose,
+            target: target.clone(),
+            focus: focus.clone(),
+            depth: *depth,
+            budget: Budget {
+                limit: limits.budget.or(defaults.budget),
+                window: limits.window,
+                strict: limits.strict_budget,
+            },
+            diff: diff.clone(),
+            stats: *stats,
+            order: order.or(defaults.order).unwrap_or_default(),
+            extras: PackExtras {
+                git_info: extras.git_info.or(defaults.git_info),
+                line_numbers: extras.line_numbers,
+                strip_comments: extras
+                    .strip_comments
+                    .or(defaults.strip_comments)
+                    .unwrap_or_default(),
+                assets: extras.assets,
+                no_redact: extras.no_redact,
+            },
+        };
+        fill_switches(&mut args, &defaults, off);
+        cli::handle_pack(args, remote.as_ref())?;
+    }
+    Ok(())
+}
+
+/// Turns on the switches `defaults` sets unless `off` turns them off, and
+/// takes its output when none of `--stdout`, `--copy`, or `--file` was given.
+fn fill_switches(args: &mut PackArgs, defaults: &PackDefaults, off: &OffArgs) {
+    if args.output == PackOutput::File && !off.file {
+        args.output = PackOutput::from_flags(defaults.stdout, defaults.copy);
+    }
+    args.noprompt |= unless(defaults.noprompt, off.prompt);
+    args.skeleton |= unless(defaults.skeleton, off.no_skeleton);
+    args.filter.code_only |= unless(defaults.code_only, off.no_code_only);
+    args.filter.no_tests |= unless(defaults.no_tests, off.tests);
+    args.filter.no_generated |= unless(defaults.no_generated, off.generated);
+    args.extras.line_numbers |= unless(defaults.line_numbers, off.no_line_numbers);
+}
+
+fn unless(default: bool, off: bool) -> bool {
+    default && !off
+}
@@ -0,0 +1,63 @@
+// src/bin/slopchop/scan.rs
+//! The bare `slopchop` scan, and the flags it shares with `slopchop check`.
+
+use std::process;
+
+use anyhow::Result;
+use clap::Args;
+
+use slopchop_core::baseline;
+use slopchop_core::blame;
+use slopchop_core::history;
+use slopchop_core::reporting::{self, GroupBy};
+use slopchop_core::types::FailOn;
+use slopchop_core::workspace::Workspace;
+
+/// Failure policy and report flags.
+#[derive(Args)]
+pub struct ScanArgs {
+    /// Lowest severity that fails the run [default: `[rules.fail]` or error].
+    #[arg(long, value_enum)]
+    pub fail_on: Option<FailOn>,
+    /// Failing violations tolerated before the run fails.
+    #[arg(long, value_name = "N")]
+    pub max_violations: Option<usize>,
+    /// List violations per file, or as counts per law or rule (text only)
+    /// [default: `[defaults.check]` or file].
+    #[arg(long, value_enum)]
+    pub group_by: Option<GroupBy>,
+    /// Show who last changed each violation's line (via `git blame`).
+    #[arg(long)]
+    pub blame: bool,
+    #[arg(long, overrides_with = "blame")]
+    pub no_blame: bool,
+    /// Only scan this workspace package (a directory with its own warden.toml).
+    #[arg(long, value_name = "NAME")]
+    pub package: Option<String>,
+}
+
+/// Scans the project, exiting with 1 if the report fails the policy.
+pub fn run(args: &ScanArgs) -> Result<()> {
+    let config = crate::load_config();
+    config.validate()?;
+    let defaults = config.defaults.check.clone();
+    let policy = config.rules.fail.clone();
+    let policy = policy.with_flags(args.fail_on, args.max_violations);
+    let workspace = Workspace::load(config);
+    workspace.validate_packages()?;
+    let files = workspace.discover()?;
+    let mut report = workspace.scan(workspace.filter(files, args.package.as_deref())?);
+    if args.package.is_none() {
+        history::record(&report);
+    }
+    baseline::apply_local(&mut report)?;
+    if args.blame || (defaults.blame && !args.no_blame) {
+        blame::attach(&mut report);
+    }
+    let group_by = args.group_by.or(defaults.group_by).unwrap_or_default();
+    reporting::print_report_by(&report, group_by)?;
+    if policy.fails(&report) {
+        process::exit(1);
+    }
+    Ok(())
+}
@@ -0,0 +1,86 @@
+// src/blame.rs
+//! `--blame`: attributes each violation to the last commit that touched its
+//! line, so fixes can be routed to the people who know the code.
+
+use crate::types::{FileReport, LineBlame, ScanReport};
+use rayon::prelude::*;
+use std::collections::BTreeMap;
+use std::path::Path;
+use std::process::Command;
+
+/// Fills `FileReport::blame` for every failing violation. Files git can't
+/// blame (untracked, outside a repository) are left without attribution.
+pub fn attach(report: &mut ScanReport) {
+    report
+        .files
+        .par_iter_mut()
+        .filter(|f| !f.violations.is_empty())
+        .for_each(attach_file);
+}
+
+fn attach_file(file: &mut FileReport) {
+    let mut rows: Vec<usize> = file.violations.iter().map(|v| v.row).collect();
+    rows.sort_unstable();
+    rows.dedup();
+    file.blame = blame_rows(&file.path, &rows).unwrap_or_default();
+}
+
+/// Runs one `git blame` covering all `rows` (0-based) of `path`.
+fn blame_rows(path: &Path, rows: &[usize]) -> Option<BTreeMap<usize, LineBlame>> {
+    let mut cmd = Command::new("git");
+    cmd.args(["blame", "--line-porcelain"]);
+    for row in rows {
+        cmd.arg(format!("-L{},{}", row + 1, row + 1));
+    }
+    let out = cmd.arg("--").arg(path).output().ok()?;
+    out.status
+        .success()
+        .then(|| parse_porcelain(&String::from_utf8_lossy(&out.stdout)))
+}
+
+/// Parses `git blame --line-porcelain` output, keyed by 0-based row.
+#[must_use]
+pub fn parse_porcelain(text: &str) -> BTreeMap<usize, LineBlame> {
+    let mut out = BTreeMap::new();
+    let mut current: Option<(usize, LineBlame)> = None;
+    for line in text.lines() {
+        if line.starts_with('\t') {
+            out.extend(current.take());
+            continue;
+        }
+        if let Some(entry) = header(line) {
+            current = Some(entry);
+            continue;
+        }
+        if let Some((_, blame)) = current.as_mut() {
+            set_field(blame, line);
+        }
+    }
+    out
+}
+
+fn set_field(blame: &mut LineBlame, line: &str) {
+    if let Some(author) = line.strip_prefix("author ") {
+        blame.author = author.to_string();
+    } else if let Some(time) = line.strip_prefix("author-time ") {
+        blame.date = time
+            .parse()
+            .map_or_else(|_| String::new(), crate::time::date);
+    }
+}
+
+/// A `<sha> <orig line> <final line> [<count>]` header.
+fn header(line: &str) -> Option<(usize, LineBlame)> {
+    let mut parts = line.split(' ');
+    let sha = parts.next()?;
+    if sha.len() < 40 || !sha.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return None;
+    }
+    let row = parts.nth(1)?.parse::<usize>().ok()?.checked_sub(1)?;
+    let blame = LineBlame {
+        commit: sha[..8].to_string(),
+        author: String::new(),
+        date: String::new(),
+    };
+    Some((row, blame))
+}
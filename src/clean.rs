@@ -7,6 +7,12 @@ use std::process::Command;
 
 const CONTEXT_FILE: &str = "context.txt";
 const GITIGNORE_FILE: &str = ".gitignore";
+/// Local files `clean` keeps out of git.
+const IGNORED: &[&str] = &[
+    CONTEXT_FILE,
+    crate::history::HISTORY_FILE,
+    crate::apply::ledger::LEDGER_FILE,
+];
 
 /// Runs the clean command: removes context.txt and ensures gitignore.
 ///
@@ -16,7 +22,7 @@ pub fn run(commit: bool) -> Result<()> {
     let mut actions = Vec::new();
 
     if ensure_gitignore()? {
-        actions.push("Added context.txt to .gitignore");
+        actions.push("Added local files to .gitignore");
     }
 
     if remove_context_file()? {
@@ -48,15 +54,23 @@ fn ensure_gitignore() -> Result<bool> {
         String::new()
     };
 
-    if content.lines().any(|line| line.trim() == CONTEXT_FILE) {
+    let missing: Vec<&str> = IGNORED
+        .iter()
+        .copied()
+        .filter(|name| !content.lines().any(|line| line.trim() == *name))
+        .collect();
+    if missing.is_empty() {
         return Ok(false);
     }
 
-    let new_content = if content.is_empty() || content.ends_with('\n') {
-        format!("{content}{CONTEXT_FILE}\n")
-    } else {
-        format!("{content}\n{CONTEXT_FILE}\n")
-    };
+    let mut new_content = content;
+    if !new_content.is_empty() && !new_content.ends_with('\n') {
+        new_content.push('\n');
+    }
+    for name in missing {
+        new_content.push_str(name);
+        new_content.push('\n');
+    }
 
     fs::write(path, new_content).context("Failed to write .gitignore")?;
     Ok(true)
@@ -0,0 +1,228 @@
+// src/cli/check.rs
+//! The `check` command: external check commands, then the structural scan.
+
+use crate::baseline;
+use crate::blame;
+use crate::config::commands::{ChangedFiles, CommandStep};
+use crate::config::defaults::CheckDefaults;
+use crate::config::Config;
+use crate::cost;
+use crate::daemon;
+use crate::error::Result;
+use crate::history;
+use crate::reporting::{self, GroupBy, ReportFormat};
+use crate::types::{FailOn, ScanReport};
+use crate::workspace::Workspace;
+use colored::Colorize;
+use std::io::Write;
+use std::path::PathBuf;
+
+#[derive(Debug, Clone)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct CheckArgs {
+    /// Overrides `[rules.fail] on`.
+    pub fail_on: Option<FailOn>,
+    /// Overrides `[rules.fail] max_violations`.
+    pub max_violations: Option<usize>,
+    /// Only scan files git reports as changed.
+    pub changed: bool,
+    /// Keeps `[defaults.check] changed` off.
+    pub no_changed: bool,
+    /// Also scan files changed since this ref (implies `changed`).
+    pub since: Option<String>,
+    /// Overrides `[defaults.check] format`.
+    pub format: Option<ReportFormat>,
+    /// Text output only; overrides `[defaults.check] group_by`.
+    pub group_by: Option<GroupBy>,
+    /// Attribute violations with `git blame`.
+    pub blame: bool,
+    /// Keeps `[defaults.check] blame` off.
+    pub no_blame: bool,
+    /// Only scan this workspace package.
+    pub package: Option<String>,
+}
+
+impl CheckArgs {
+    /// These flags with `[defaults.check]` filling in what they leave out.
+    #[must_use]
+    pub fn with_defaults(&self, defaults: &CheckDefaults) -> Self {
+        let mut args = self.clone();
+        args.format = self.format.or(defaults.format);
+        args.group_by = self.group_by.or(defaults.group_by);
+        args.changed |= defaults.changed && !self.no_changed;
+        args.blame |= defaults.blame && !self.no_blame;
+        args
+    }
+}
+
+/// Handles the check command, exiting with 1 if a command fails or the
+/// report fails the `[rules.fail]` policy. With machine-readable output,
+/// progress goes to stderr so stdout holds only the report.
+///
+/// # Errors
+/// Returns error if discovery, analysis, or external commands fail.
+pub fn handle_check(args: &CheckArgs) -> Result<()> {
+    let mut config = Config::new();
+    config.load_local_config();
+    config.validate()?;
+    let args = &args.with_defaults(&config.defaults.check);
+    let format = args.format.unwrap_or_default();
+    let quiet = format != ReportFormat::Text;
+    let policy = config.rules.fail.clone();
+    let policy = policy.with_flags(args.fail_on, args.max_violations);
+    let prices = config.tokens.prices.clone();
+
+    // 1. Run external check commands (cargo test, clippy, etc.)
+    status(quiet, "> Running 'check' pipeline...");
+    if let Some(steps) = config.commands.get("check") {
+        if !run_steps(steps, quiet)? {
+            std::process::exit(1);
+        }
+    }
+
+    // 2. Run internal structural scan
+    status(quiet, "> Running structural scan...");
+    let mut report = scan(config, args)?;
+    baseline::apply_local(&mut report)?;
+    if args.blame {
+        blame::attach(&mut report);
+    }
+
+    print(&report, args)?;
+    let estimate = cost::summary(report.total_tokens, &prices);
+    if format == ReportFormat::Text && !estimate.is_empty() {
+        println!("{estimate}");
+    }
+
+    if policy.fails(&report) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn print(report: &ScanReport, args: &CheckArgs) -> Result<()> {
+    match args.format.unwrap_or_default() {
+        ReportFormat::Text => {
+            reporting::print_report_by(report, args.group_by.unwrap_or_default())?;
+        }
+        ReportFormat::Json => reporting::json::print(report)?,
+        ReportFormat::CodeClimate => reporting::codeclimate::print(report)?,
+    }
+    Ok(())
+}
+
+fn status(quiet: bool, line: &str) {
+    if quiet {
+        eprintln!("{line}");
+    } else {
+        println!("{line}");
+    }
+}
+
+/// Scans through the daemon when one is running and there are no
+/// workspace packages, else in-process. Full scans are recorded in the
+/// history.
+fn scan(config: Config, args: &CheckArgs) -> Result<ScanReport> {
+    let changed = args.changed || args.since.is_some();
+    let scoped = changed || args.package.is_some();
+    let workspace = Workspace::load(config);
+    workspace.validate_packages()?;
+    let files = if changed {
+        Some(workspace.discover_changed(args.since.as_deref())?)
+    } else {
+        None
+    };
+    let daemon = if workspace.packages.is_empty() {
+        daemon::client::scan(&workspace.root, files.as_deref(), !scoped)
+    } else {
+        None
+    };
+    let report = match daemon {
+        Some(report) => report,
+        None => scan_locally(workspace, files, args.package.as_deref())?,
+    };
+    if !scoped {
+        history::record(&report);
+    }
+    Ok(report)
+}
+
+/// Scans `files` (cross-file checks off), or every discovered file, each
+/// with its package's config, keeping only `package` if given.
+fn scan_locally(
+    mut workspace: Workspace,
+    files: Option<Vec<PathBuf>>,
+    package: Option<&str>,
+) -> Result<ScanReport> {
+    let files = match files {
+        Some(files) => {
+            let configs = std::iter::once(&mut workspace.root)
+                .chain(workspace.packages.iter_mut().map(|p| &mut p.config));
+            for config in configs {
+                config.rules.cross_file.detect_dead_code = false;
+                config.rules.cross_file.detect_cycles = false;
+            }
+            files
+        }
+        None => workspace.discover()?,
+    };
+    let files = workspace.filter(files, package)?;
+    Ok(workspace.scan(files))
+}
+
+/// Runs `steps` in order, returning false at the first failure of a step
+/// without `continue_on_error`.
+fn run_steps(steps: &[CommandStep], quiet: bool) -> Result<bool> {
+    let mut changed = ChangedFiles::from_git();
+    for step in steps {
+        if !step.should_run(&mut changed) {
+            status(
+                quiet,
+                &format!("   > {} ... {}", step.run, "skipped (only_if)".yellow()),
+            );
+            continue;
+        }
+        if !run_check_command(step, quiet)? && !step.continue_on_error {
+            return Ok(false);
+        }
+    }
+    Ok(true)
+}
+
+/// Runs one configured step, returning whether it succeeded; a command
+/// that can't start fails. Its status lines go to stderr when `quiet`.
+fn run_check_command(step: &CommandStep, quiet: bool) -> Result<bool> {
+    let mut out: Box<dyn Write> = if quiet {
+        Box::new(std::io::stderr())
+    } else {
+        Box::new(std::io::stdout())
+    };
+    write!(out, "   > {} ... ", step.run)?;
+
+    // Flush to ensure the "..." appears before the command runs
+    let _ = out.flush();
+
+    let Some(mut command) = step.command() else {
+        writeln!(out, "{}", "skipped (empty)".yellow())?;
+        return Ok(true);
+    };
+
+    let output = match command.output() {
+        Ok(output) => output,
+        Err(e) => {
+            writeln!(out, "{}", format!("err (cannot start: {e})").red())?;
+            return Ok(false);
+        }
+    };
+
+    if output.status.success() {
+        writeln!(out, "{}", "ok".green())?;
+        Ok(true)
+    } else {
+        writeln!(out, "{}", "err".red())?;
+        writeln!(out, "{}", "--- STDERR ---".red())?;
+        writeln!(out, "{}", String::from_utf8_lossy(&output.stderr))?;
+        writeln!(out, "{}", "--------------".red())?;
+        Ok(false)
+    }
+}
@@ -0,0 +1,113 @@
+// src/cli/config.rs
+//! The `config` command: the interactive editor, `config validate`,
+//! `config preset`, and `config --schema`.
+
+use crate::config::validate::{self, Issue};
+use crate::config::{files, preset, schema};
+use crate::error::{Result, SlopChopError};
+use crate::project::Strictness;
+use clap::Subcommand;
+use colored::Colorize;
+use std::path::{Path, PathBuf};
+
+#[derive(Subcommand, Debug, Clone)]
+pub enum ConfigCommand {
+    /// Report unknown keys, wrong types, and deprecated options with their lines.
+    Validate {
+        /// [default: warden.toml, or a legacy slopchop.toml]
+        file: Option<PathBuf>,
+    },
+    /// Switch `[rules] preset`, dropping the limits it sets.
+    Preset {
+        #[arg(value_parser = ["strict", "standard", "relaxed"])]
+        name: String,
+    },
+}
+
+/// Handles `config`: the editor, or `validate`, `preset`, or `--schema`.
+///
+/// # Errors
+/// Returns error if the editor fails or the file cannot be read.
+pub fn handle_config(action: Option<&ConfigCommand>, schema: bool) -> Result<()> {
+    match action {
+        Some(ConfigCommand::Validate { file }) => match file {
+            Some(file) => handle_validate(file),
+            None => handle_validate(&files::local(files::CONFIG_FILE)),
+        },
+        Some(ConfigCommand::Preset { name }) => handle_preset(name),
+        None if schema => handle_schema(),
+        None => crate::tui::run_config(),
+    }
+}
+
+/// Checks the config file at `path` against the schema, printing each
+/// issue as `path:line`, and exits with 1 if any is an error.
+///
+/// # Errors
+/// Returns error if the file cannot be read.
+pub fn handle_validate(path: &Path) -> Result<()> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| SlopChopError::Other(format!("Cannot read {}: {e}", path.display())))?;
+    let issues = validate::check(&content);
+    if issues.is_empty() {
+        println!("{} {} is valid", "✓".green(), path.display());
+        return Ok(());
+    }
+    for issue in &issues {
+        let label = if issue.is_error() {
+            "error".red()
+        } else {
+            "warning".yellow()
+        };
+        let key = if issue.key.is_empty() {
+            String::new()
+        } else {
+            format!("`{}` ", issue.key)
+        };
+        println!(
+            "{}:{}: {label}: {key}{}",
+            path.display(),
+            issue.line,
+            issue.message
+        );
+    }
+    if issues.iter().any(Issue::is_error) {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+/// Sets `[rules] preset` in `warden.toml`, creating the file if needed,
+/// and removes the limits that would override it.
+///
+/// # Errors
+/// Returns error if the preset is unknown or the file cannot be read,
+/// parsed, or written.
+pub fn handle_preset(name: &str) -> Result<()> {
+    let strictness = Strictness::from_name(name).ok_or_else(|| {
+        SlopChopError::Other(format!(
+            "Unknown preset `{name}` (strict, standard, relaxed)"
+        ))
+    })?;
+    let path = files::local(files::CONFIG_FILE);
+    let content = std::fs::read_to_string(&path).unwrap_or_default();
+    let (content, removed) = preset::set(&content, strictness)
+        .map_err(|e| SlopChopError::Other(format!("{}: {e}", path.display())))?;
+    std::fs::write(&path, content)?;
+    println!("{} [rules] preset = \"{name}\"", "✓".green());
+    if !removed.is_empty() {
+        println!("  Removed {} so the preset applies.", removed.join(", "));
+    }
+    Ok(())
+}
+
+/// Prints the JSON Schema of `warden.toml`.
+///
+/// # Errors
+/// Returns error if the schema cannot be serialized.
+pub fn handle_schema() -> Result<()> {
+    let schema = serde_json::to_string_pretty(&schema::json())
+        .map_err(|e| SlopChopError::Other(format!("Failed to serialize schema: {e}")))?;
+    println!("{schema}");
+    Ok(())
+}
@@ -1,33 +1,41 @@
 // src/cli/handlers.rs
+use crate::analysis::cache::CACHE_DIR;
 use crate::analysis::RuleEngine;
 use crate::apply;
+use crate::apply::input::InputSource;
 use crate::apply::types::ApplyContext;
-use crate::config::Config;
+use crate::baseline::{Baseline, BASELINE_FILE};
+use crate::config::commands::{ChangedFiles, CommandStep};
+use crate::config::{Config, GitMode};
 use crate::error::Result;
-use crate::pack::{self, OutputFormat, PackOptions};
+use crate::pack::budget::Budget;
+use crate::pack::filter::FileFilter;
+use crate::pack::order::FileOrder;
+use crate::pack::remote::Remote;
+use crate::pack::{self, OutputFormat, PackExtras, PackOptions, PackOutput, SkeletonMode};
 use crate::prompt::PromptGenerator;
-use crate::reporting;
 use crate::trace::{self, TraceOptions};
-use std::io::Write;
-use std::path::{Path, PathBuf};
-use std::process::Command;
 use colored::Colorize;
+use std::path::{Path, PathBuf};
 
 #[allow(clippy::struct_excessive_bools)]
 #[derive(Debug, Clone)]
 pub struct PackArgs {
-    pub stdout: bool,
-    pub copy: bool,
+    pub output: PackOutput,
     pub noprompt: bool,
     pub format: OutputFormat,
     pub skeleton: bool,
-    pub git_only: bool,
-    pub no_git: bool,
-    pub code_only: bool,
+    pub git_mode: GitMode,
+    pub filter: FileFilter,
     pub verbose: bool,
     pub target: Option<PathBuf>,
     pub focus: Vec<PathBuf>,
     pub depth: usize,
+    pub budget: Budget,
+    pub diff: Option<String>,
+    pub stats: bool,
+    pub order: FileOrder,
+    pub extras: PackExtras,
 }
 
 /// Handles the initialization command.
@@ -42,93 +50,76 @@ pub fn handle_init(path: Option<PathBuf>) -> Result<()> {
     Ok(())
 }
 
-/// Handles the check command.
+/// Handles the baseline command: records every current violation so later
+/// scans only fail on new ones.
 ///
 /// # Errors
-/// Returns error if discovery, analysis, or external commands fail.
-pub fn handle_check() -> Result<()> {
+/// Returns error if discovery fails or the baseline cannot be written.
+pub fn handle_baseline() -> Result<()> {
     let mut config = Config::new();
     config.load_local_config();
-
-    // 1. Run external check commands (cargo test, clippy, etc.)
-    println!("> Running 'check' pipeline...");
-    if let Some(check_cmds) = config.commands.get("check") {
-        for cmd in check_cmds {
-            run_check_command(cmd)?;
-        }
-    }
-
-    // 2. Run internal structural scan
-    println!("> Running structural scan...");
-    let engine = RuleEngine::new(config.clone());
     let files = crate::discovery::discover(&config)?;
-    let report = engine.scan(files);
-
-    reporting::print_report(&report)?;
-
-    if report.has_errors() {
-        std::process::exit(1);
-    }
+    let report = RuleEngine::new(config).with_cache(CACHE_DIR).scan(files);
+
+    let baseline = Baseline::from_report(&report);
+    baseline.save(Path::new(BASELINE_FILE))?;
+    println!(
+        "{}",
+        format!(
+            "✓ Recorded {} violations in {BASELINE_FILE}",
+            baseline.violations.len()
+        )
+        .green()
+    );
     Ok(())
 }
 
-fn run_check_command(cmd: &str) -> Result<()> {
-    print!("   > {cmd} ... ");
-    
-    // Flush stdout to ensure the "..." appears before the command runs
-    let _ = std::io::stdout().flush();
-
-    let parts: Vec<&str> = cmd.split_whitespace().collect();
-    
-    let Some((prog, args)) = parts.split_first() else {
-        println!("{}", "skipped (empty)".yellow());
-        return Ok(());
-    };
-
-    let output = Command::new(prog).args(args).output()?;
-
-    if output.status.success() {
-        println!("{}", "ok".green());
-        Ok(())
-    } else {
-        println!("{}", "err".red());
-        println!("{}", "--- STDERR ---".red());
-        println!("{}", String::from_utf8_lossy(&output.stderr));
-        println!("{}", "--------------".red());
-        Err(crate::error::SlopChopError::Other(format!(
-            "Command failed: {cmd}"
-        )))
-    }
-}
-
-/// Handles the fix command.
+/// Handles the fix command: runs the `fix` steps in order, skipping those
+/// whose `only_if` fails, and exits with 1 at the first failed step
+/// without `continue_on_error`.
 ///
 /// # Errors
-/// Returns error if command execution fails.
+/// Returns error if the config cannot be loaded.
 pub fn handle_fix() -> Result<()> {
     let mut config = Config::new();
     config.load_local_config();
 
-    let Some(fix_cmds) = config.commands.get("fix") else {
-        println!("No 'fix' command configured in slopchop.toml");
+    let Some(steps) = config.commands.get("fix") else {
+        println!("No 'fix' command configured in warden.toml");
         return Ok(());
     };
 
-    for cmd in fix_cmds {
-        println!("Running: {cmd}");
-        let parts: Vec<&str> = cmd.split_whitespace().collect();
-        let Some((prog, args)) = parts.split_first() else {
+    let mut changed = ChangedFiles::from_git();
+    for step in steps {
+        if !step.should_run(&mut changed) {
+            println!("Skipping: {} (only_if)", step.run);
+            continue;
+        }
+        println!("Running: {}", step.run);
+        if run_fix_step(step) {
             continue;
-        };
-        
-        let status = Command::new(prog).args(args).status()?;
-        if !status.success() {
-            eprintln!("Command failed: {cmd}");
+        }
+        eprintln!("Command failed: {}", step.run);
+        if !step.continue_on_error {
+            std::process::exit(1);
         }
     }
     Ok(())
 }
 
+fn run_fix_step(step: &CommandStep) -> bool {
+    let Some(mut command) = step.command() else {
+        return true;
+    };
+    match command.status() {
+        Ok(status) => status.success(),
+        Err(e) => {
+            eprintln!("Cannot start `{}`: {e}", step.run);
+            false
+        }
+    }
+}
+
 /// Handles the dashboard command.
 ///
 /// # Errors
@@ -147,11 +138,16 @@ pub fn handle_dashboard() -> Result<()> {
 pub fn handle_prompt(copy: bool) -> Result<()> {
     let mut config = Config::new();
     config.load_local_config();
-    let gen = PromptGenerator::new(config.rules);
-    let prompt = gen.generate().map_err(|e| crate::error::SlopChopError::Other(e.to_string()))?;
-    
+    let gen =
+        PromptGenerator::new(config.rules).with_line_counts(config.apply.truncation.line_counts);
+    let prompt = gen
+        .generate()
+        .map_err(|e| crate::error::SlopChopError::Other(e.to_string()))?;
+
     if copy {
-        crate::clipboard::copy_to_clipboard(&prompt).map_err(|e| crate::error::SlopChopError::Other(e.to_string()))?;
+        crate::clipboard::history::record("prompt", &prompt);
+        crate::clipboard::copy_to_clipboard(&prompt)
+            .map_err(|e| crate::error::SlopChopError::Other(e.to_string()))?;
         println!("System prompt copied to clipboard.");
     } else {
         println!("{prompt}");
@@ -163,22 +159,32 @@ pub fn handle_prompt(copy: bool) -> Result<()> {
 ///
 /// # Errors
 /// Returns error if packing fails.
-pub fn handle_pack(args: PackArgs) -> Result<()> {
+pub fn handle_pack(args: PackArgs, remote: Option<&Remote>) -> Result<()> {
     let opts = PackOptions {
-        stdout: args.stdout,
-        copy: args.copy,
+        output: args.output,
         verbose: args.verbose,
         prompt: !args.noprompt,
         format: args.format,
-        skeleton: args.skeleton,
-        git_only: args.git_only,
-        no_git: args.no_git,
-        code_only: args.code_only,
+        skeleton: if args.skeleton {
+            SkeletonMode::All
+        } else {
+            SkeletonMode::Off
+        },
+        git_mode: args.git_mode,
+        filter: args.filter,
         target: args.target,
         focus: args.focus,
         depth: args.depth,
+        budget: args.budget,
+        diff: args.diff,
+        stats: args.stats,
+        order: args.order,
+        extras: args.extras,
     };
-    pack::run(&opts)?;
+    match remote {
+        Some(remote) => pack::remote::run(remote, &opts)?,
+        None => pack::run(&opts)?,
+    }
     Ok(())
 }
 
@@ -207,16 +213,43 @@ pub fn handle_map(deps: bool) -> Result<()> {
     Ok(())
 }
 
-/// Handles the apply command.
+/// `apply` flags.
+#[derive(Debug, Clone, Default)]
+#[allow(clippy::struct_excessive_bools)]
+pub struct ApplyArgs {
+    pub review: bool,
+    /// Keeps `[defaults.apply] review` off.
+    pub no_review: bool,
+    pub dry_run: bool,
+    /// Skip the plan confirmation.
+    pub force: bool,
+    /// Apply each new clipboard payload until interrupted.
+    pub watch: bool,
+    pub input: InputSource,
+}
+
+/// Handles the apply command, reading the payload from `args.input`, or
+/// from the clipboard as it changes with `--watch`. `[defaults.apply]`
+/// can turn on `review`, but not `force`.
 ///
 /// # Errors
 /// Returns error if application fails.
-pub fn handle_apply() -> Result<()> {
+pub fn handle_apply(args: &ApplyArgs) -> Result<()> {
     let mut config = Config::new();
     config.load_local_config();
-    let ctx = ApplyContext::new(&config);
-    
+    config.validate()?;
+    let mut ctx = ApplyContext::new(&config);
+    let defaults = &config.defaults.apply;
+    let stdin = matches!(args.input, InputSource::Stdin);
+    ctx.review = args.review || (defaults.review && !args.no_review && !args.dry_run && !stdin);
+    ctx.dry_run = args.dry_run;
+    ctx.force = args.force;
+    ctx.input = args.input.clone();
+    if args.watch {
+        return Ok(apply::watch::run(&ctx)?);
+    }
+
     let outcome = apply::run_apply(&ctx)?;
     apply::print_result(&outcome);
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,9 +1,14 @@
 // src/cli/mod.rs
 //! CLI command handlers.
 
+pub mod check;
+pub mod config;
 pub mod handlers;
+pub mod report;
 
+pub use check::{handle_check, CheckArgs};
 pub use handlers::{
-    handle_apply, handle_check, handle_dashboard, handle_fix, handle_map,
-    handle_pack, handle_prompt, handle_trace, PackArgs,
-};
\ No newline at end of file
+    handle_apply, handle_baseline, handle_dashboard, handle_fix, handle_map, handle_pack,
+    handle_prompt, handle_trace, ApplyArgs, PackArgs,
+};
+pub use report::handle_report;
@@ -0,0 +1,29 @@
+// src/cli/report.rs
+//! The `report` command: writes the scan as a standalone HTML page.
+
+use crate::baseline;
+use crate::config::Config;
+use crate::daemon;
+use crate::reporting::html;
+use anyhow::Result;
+use colored::Colorize;
+use std::path::Path;
+
+/// Scans the project and writes an HTML report to `out`.
+///
+/// # Errors
+/// Returns error if the scan fails or the file cannot be written.
+pub fn handle_report(out: &Path) -> Result<()> {
+    let mut config = Config::new();
+    config.load_local_config();
+    let mut report = daemon::scan_project(config)?;
+    baseline::apply_local(&mut report)?;
+    html::write(&report, out)?;
+    println!(
+        "{} Wrote report for {} files to {}",
+        "✓".green(),
+        report.files.len(),
+        out.display()
+    );
+    Ok(())
+}
@@ -0,0 +1,173 @@
+// src/clipboard/backend.rs
+//! Ordered clipboard backends (`[clipboard] backends`). Each one is tried
+//! in turn, and the copy reports which succeeded and why the ones before
+//! it did not.
+
+use std::env;
+use std::fmt::Write as _;
+use std::path::Path;
+
+use anyhow::{anyhow, Result};
+
+use super::tools;
+use crate::config::ClipboardBackend;
+
+/// A backend that was skipped or failed, and why.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Failure {
+    pub backend: ClipboardBackend,
+    pub reason: String,
+}
+
+/// The backend that took a copy, and the ones that failed before it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Copied {
+    pub backend: ClipboardBackend,
+    pub failures: Vec<Failure>,
+}
+
+impl Copied {
+    /// `via xclip`, followed by why earlier backends were passed over.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let mut out = format!("via {}", self.backend.name());
+        if !self.failures.is_empty() {
+            let _ = write!(out, " ({})", list(&self.failures, "; "));
+        }
+        out
+    }
+}
+
+/// The Linux chain when `[clipboard] backends` is empty: the session's
+/// own display server first.
+#[must_use]
+pub fn default_chain(wayland: bool) -> Vec<ClipboardBackend> {
+    use ClipboardBackend::{WlCopy, Xclip, Xsel};
+    if wayland {
+        vec![WlCopy, Xclip, Xsel]
+    } else {
+        vec![Xclip, Xsel, WlCopy]
+    }
+}
+
+/// The chain to copy through: `backends` if set, else the Linux default
+/// outside WSL. Empty means the platform's built-in clipboard.
+#[must_use]
+pub fn configured(backends: &[ClipboardBackend]) -> Vec<ClipboardBackend> {
+    if !backends.is_empty() {
+        return backends.to_vec();
+    }
+    if cfg!(target_os = "linux") && !is_wsl() {
+        return default_chain(env::var_os("WAYLAND_DISPLAY").is_some());
+    }
+    Vec::new()
+}
+
+/// True inside Windows Subsystem for Linux, where the Windows clipboard
+/// is used instead.
+#[must_use]
+pub fn is_wsl() -> bool {
+    if env::var("WSL_DISTRO_NAME").is_ok() {
+        return true;
+    }
+    if let Ok(version) = std::fs::read_to_string("/proc/version") {
+        let v = version.to_lowercase();
+        return v.contains("microsoft") || v.contains("wsl");
+    }
+    false
+}
+
+/// What the environment says is reachable from this session.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Session {
+    pub wayland: bool,
+    pub x11: bool,
+    pub tmux: bool,
+}
+
+impl Session {
+    #[must_use]
+    pub fn current() -> Self {
+        Self {
+            wayland: env::var_os("WAYLAND_DISPLAY").is_some(),
+            x11: env::var_os("DISPLAY").is_some(),
+            tmux: env::var_os("TMUX").is_some(),
+        }
+    }
+
+    /// Why `backend` cannot work in this session, without running it.
+    #[must_use]
+    pub fn unavailable(self, backend: ClipboardBackend) -> Option<&'static str> {
+        match backend {
+            ClipboardBackend::WlCopy if !self.wayland => {
+                Some("no Wayland display (WAYLAND_DISPLAY is unset)")
+            }
+            ClipboardBackend::Xclip | ClipboardBackend::Xsel if !self.x11 => {
+                Some("no X display (DISPLAY is unset)")
+            }
+            ClipboardBackend::Tmux if !self.tmux => Some("not inside tmux (TMUX is unset)"),
+            _ => None,
+        }
+    }
+}
+
+/// Copies `text` through the first backend in `chain` that works.
+///
+/// # Errors
+/// Returns error listing every backend's failure if none works.
+pub fn copy(text: &str, chain: &[ClipboardBackend]) -> Result<Copied> {
+    first_working(chain, |backend| tools::copy(backend, text))
+}
+
+/// Copies `path` as a `text/uri-list` file attachment. Only `wl-copy` and
+/// `xclip` can set that type, so other backends in `chain` are skipped.
+///
+/// # Errors
+/// Returns error listing every backend's failure if none works.
+pub fn copy_file(path: &Path, chain: &[ClipboardBackend]) -> Result<Copied> {
+    let uri = format!("file://{}", path.to_string_lossy());
+    first_working(chain, |backend| tools::copy_uri(backend, &uri))
+}
+
+/// Reads the clipboard through the first backend in `chain` that works.
+///
+/// # Errors
+/// Returns error listing every backend's failure if none works.
+pub fn read(chain: &[ClipboardBackend]) -> Result<String> {
+    let mut text = String::new();
+    first_working(chain, |backend| {
+        text = tools::read(backend)?;
+        Ok(())
+    })?;
+    Ok(text)
+}
+
+fn first_working(
+    chain: &[ClipboardBackend],
+    mut attempt: impl FnMut(ClipboardBackend) -> std::result::Result<(), String>,
+) -> Result<Copied> {
+    let session = Session::current();
+    let mut failures = Vec::new();
+    for &backend in chain {
+        let outcome = match session.unavailable(backend) {
+            Some(reason) => Err(reason.to_string()),
+            None => attempt(backend),
+        };
+        match outcome {
+            Ok(()) => return Ok(Copied { backend, failures }),
+            Err(reason) => failures.push(Failure { backend, reason }),
+        }
+    }
+    Err(anyhow!(
+        "No clipboard backend worked:\n  {}\nSet [clipboard] backends in warden.toml, or SLOPCHOP_CLIPBOARD=osc52 in a terminal that supports it.",
+        list(&failures, "\n  ")
+    ))
+}
+
+fn list(failures: &[Failure], sep: &str) -> String {
+    failures
+        .iter()
+        .map(|f| format!("{}: {}", f.backend.name(), f.reason))
+        .collect::<Vec<_>>()
+        .join(sep)
+}
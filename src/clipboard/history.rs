@@ -0,0 +1,187 @@
+// src/clipboard/history.rs
+//! Copies of recent packs and prompts, kept in `.slopchop_copies/` so
+//! `slopchop history` can send one again after a chat resets, without
+//! regenerating it from a different git state.
+//!
+//! Each entry is `<millis>-<kind>[-<commit>].txt` holding the exact text.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+
+use crate::tokens::Tokenizer;
+
+pub const HISTORY_DIR: &str = ".slopchop_copies";
+
+/// One saved copy.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Entry {
+    /// Milliseconds since the Unix epoch when it was saved.
+    pub id: String,
+    /// What produced it: `pack`, `prompt`, `fix`, or `feedback`.
+    pub kind: String,
+    /// Short `HEAD` at the time, when in a git repository.
+    pub commit: Option<String>,
+    pub path: PathBuf,
+}
+
+impl Entry {
+    fn parse(path: PathBuf) -> Option<Self> {
+        let stem = path.file_stem()?.to_str()?.to_string();
+        let mut parts = stem.splitn(3, '-');
+        let id = parts.next()?.to_string();
+        id.parse::<u64>().ok()?;
+        let kind = parts.next()?.to_string();
+        let commit = parts.next().map(str::to_string);
+        Some(Self {
+            id,
+            kind,
+            commit,
+            path,
+        })
+    }
+
+    /// The saved text.
+    ///
+    /// # Errors
+    /// Returns error if the entry cannot be read.
+    pub fn content(&self) -> Result<String> {
+        Ok(fs::read_to_string(&self.path)?)
+    }
+}
+
+/// Saves `content` under `root` and drops all but the newest `keep`
+/// entries. Keeping none saves nothing.
+///
+/// # Errors
+/// Returns error if the history directory cannot be written.
+pub fn save(
+    root: &Path,
+    kind: &str,
+    content: &str,
+    commit: Option<&str>,
+    keep: usize,
+) -> Result<Option<Entry>> {
+    if keep == 0 {
+        return Ok(None);
+    }
+    let dir = root.join(HISTORY_DIR);
+    fs::create_dir_all(&dir)?;
+    fs::write(dir.join(".gitignore"), "*\n")?;
+
+    let millis = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |d| d.as_millis());
+    let name = match commit {
+        Some(c) => format!("{millis}-{kind}-{c}.txt"),
+        None => format!("{millis}-{kind}.txt"),
+    };
+    let path = dir.join(name);
+    fs::write(&path, content)?;
+
+    for old in list(root)?.into_iter().skip(keep) {
+        fs::remove_file(old.path)?;
+    }
+    Ok(Entry::parse(path))
+}
+
+/// Lists the entries under `root`, newest first.
+///
+/// # Errors
+/// Returns error if the history directory exists but cannot be read.
+pub fn list(root: &Path) -> Result<Vec<Entry>> {
+    let dir = root.join(HISTORY_DIR);
+    if !dir.exists() {
+        return Ok(Vec::new());
+    }
+    let mut entries = Vec::new();
+    for entry in fs::read_dir(&dir)? {
+        let path = entry?.path();
+        if path.extension().is_some_and(|e| e == "txt") {
+            entries.extend(Entry::parse(path));
+        }
+    }
+    entries.sort_by_key(|e| std::cmp::Reverse(e.id.parse::<u64>().unwrap_or(0)));
+    Ok(entries)
+}
+
+/// True if `content` is one of the copies saved under `root`.
+#[must_use]
+pub fn contains(root: &Path, content: &str) -> bool {
+    list(root).is_ok_and(|entries| {
+        entries
+            .iter()
+            .any(|e| e.content().is_ok_and(|c| c == content))
+    })
+}
+
+/// Saves `content` in the local history, as many as `[clipboard] history`
+/// allows. History is a convenience, so failures are ignored.
+pub fn record(kind: &str, content: &str) {
+    let commit = crate::history::head().map(|c| c.chars().take(8).collect::<String>());
+    let keep = super::settings().history;
+    let _ = save(Path::new("."), kind, content, commit.as_deref(), keep);
+}
+
+/// Runs `slopchop history`: lists entries, or with `copy` puts `id`
+/// (default newest) back on the clipboard, or prints it with `stdout`.
+///
+/// # Errors
+/// Returns error if the entry does not exist or cannot be copied.
+pub fn run(id: Option<&str>, copy: bool, stdout: bool) -> Result<()> {
+    let entries = list(Path::new("."))?;
+    if !copy && !stdout {
+        print_entries(&entries);
+        return Ok(());
+    }
+    let entry = match id {
+        Some(id) => entries.iter().find(|e| e.id == id),
+        None => entries.first(),
+    }
+    .ok_or_else(|| anyhow!("No saved copy {}", id.unwrap_or("in history")))?;
+    let content = entry.content()?;
+    if stdout {
+        print!("{content}");
+        return Ok(());
+    }
+    let msg = super::smart_copy(&content)?;
+    println!(
+        "{}",
+        format!("✓ Copied {} {} again", entry.kind, entry.id).green()
+    );
+    println!("  ({msg})");
+    Ok(())
+}
+
+fn print_entries(entries: &[Entry]) {
+    if entries.is_empty() {
+        println!("No saved copies.");
+    }
+    for entry in entries {
+        let tokens = entry.content().map_or(0, |c| Tokenizer::count(&c));
+        println!(
+            "{}  {}  {:<8} {:>7} tokens  commit {}",
+            entry.id.bold(),
+            timestamp(&entry.id),
+            entry.kind,
+            tokens,
+            entry.commit.as_deref().unwrap_or("-")
+        );
+    }
+}
+
+/// `YYYY-MM-DD HH:MM` (UTC) for an entry id.
+#[must_use]
+pub fn timestamp(id: &str) -> String {
+    let secs = id.parse::<i64>().unwrap_or(0) / 1000;
+    let minutes = secs.rem_euclid(86_400) / 60;
+    format!(
+        "{} {:02}:{:02}",
+        crate::time::date(secs),
+        minutes / 60,
+        minutes % 60
+    )
+}
@@ -1,20 +1,11 @@
 // src/clipboard/linux.rs
+use crate::clipboard::backend::{self, is_wsl};
+use crate::config::ClipboardBackend;
 use anyhow::{Context, Result};
 use std::io::Write;
 use std::path::Path;
 use std::process::{Command, Stdio};
 
-fn is_wsl() -> bool {
-    if std::env::var("WSL_DISTRO_NAME").is_ok() {
-        return true;
-    }
-    if let Ok(version) = std::fs::read_to_string("/proc/version") {
-        let v = version.to_lowercase();
-        return v.contains("microsoft") || v.contains("wsl");
-    }
-    false
-}
-
 /// Copies the file at the given path to the clipboard.
 ///
 /// # Errors
@@ -27,38 +18,7 @@ pub fn copy_file_handle(path: &Path) -> Result<()> {
 }
 
 fn copy_file_handle_native(path: &Path) -> Result<()> {
-    let uri = format!("file://{}", path.to_string_lossy());
-
-    if try_wl_copy_uri(&uri).is_ok() {
-        return Ok(());
-    }
-    copy_uri_xclip(&uri)
-}
-
-fn try_wl_copy_uri(uri: &str) -> Result<()> {
-    let mut child = Command::new("wl-copy")
-        .args(["--type", "text/uri-list"])
-        .stdin(Stdio::piped())
-        .spawn()?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        write!(stdin, "{uri}")?;
-    }
-    child.wait()?;
-    Ok(())
-}
-
-fn copy_uri_xclip(uri: &str) -> Result<()> {
-    let mut child = Command::new("xclip")
-        .args(["-selection", "clipboard", "-t", "text/uri-list", "-i"])
-        .stdin(Stdio::piped())
-        .spawn()
-        .context("Failed to spawn xclip")?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        write!(stdin, "{uri}").context("Failed to write to xclip")?;
-    }
-    child.wait().context("Failed to wait for xclip")?;
+    backend::copy_file(path, &native_chain())?;
     Ok(())
 }
 
@@ -139,38 +99,12 @@ fn try_wsl_powershell(text: &str) -> Result<()> {
 }
 
 fn perform_copy_native(text: &str) -> Result<()> {
-    if try_xclip_copy(text).is_ok() {
-        return Ok(());
-    }
-    try_wl_copy(text)
-}
-
-fn try_xclip_copy(text: &str) -> Result<()> {
-    let mut child = Command::new("xclip")
-        .args(["-selection", "clipboard", "-in"])
-        .stdin(Stdio::piped())
-        .spawn()?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        stdin.write_all(text.as_bytes())?;
-    }
-    child.wait()?;
+    backend::copy(text, &native_chain())?;
     Ok(())
 }
 
-fn try_wl_copy(text: &str) -> Result<()> {
-    let mut child = Command::new("wl-copy")
-        .stdin(Stdio::piped())
-        .spawn()
-        .context("Failed to spawn wl-copy")?;
-
-    if let Some(mut stdin) = child.stdin.take() {
-        stdin
-            .write_all(text.as_bytes())
-            .context("Failed to write to wl-copy")?;
-    }
-    child.wait().context("Failed to wait for wl-copy")?;
-    Ok(())
+fn native_chain() -> Vec<ClipboardBackend> {
+    backend::default_chain(std::env::var_os("WAYLAND_DISPLAY").is_some())
 }
 
 /// Reads text from the system clipboard.
@@ -195,12 +129,5 @@ fn perform_read_wsl() -> Result<String> {
 }
 
 fn perform_read_native() -> Result<String> {
-    if let Ok(output) = Command::new("xclip")
-        .args(["-selection", "clipboard", "-out"])
-        .output()
-    {
-        return Ok(String::from_utf8_lossy(&output.stdout).to_string());
-    }
-    let output = Command::new("wl-paste").output()?;
-    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+    backend::read(&native_chain())
 }
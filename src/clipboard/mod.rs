@@ -1,12 +1,38 @@
 // src/clipboard/mod.rs
+pub mod backend;
+pub mod history;
+pub mod osc52;
 pub mod platform;
 pub mod temp;
+pub mod tools;
 
+use crate::config::{ClipboardSettings, CopyMode};
 use crate::tokens::Tokenizer;
-use anyhow::Result;
+use anyhow::{bail, Result};
 use std::path::Path;
+use std::sync::RwLock;
 
-/// Smartly copies text or file handles based on size.
+/// `[clipboard]`, set when the config is loaded.
+static SETTINGS: RwLock<Option<ClipboardSettings>> = RwLock::new(None);
+
+/// Sets how `smart_copy` copies, from `[clipboard]`.
+pub fn configure(settings: ClipboardSettings) {
+    if let Ok(mut current) = SETTINGS.write() {
+        *current = Some(settings);
+    }
+}
+
+/// True if content of `tokens` tokens is copied as a file attachment.
+#[must_use]
+pub fn copies_as_file(tokens: usize, settings: &ClipboardSettings) -> bool {
+    match settings.mode {
+        CopyMode::Auto => tokens >= settings.file_copy_threshold,
+        CopyMode::Text => false,
+        CopyMode::File => true,
+    }
+}
+
+/// Copies text, or a temp file holding it, by `[clipboard]` mode and size.
 ///
 /// # Errors
 /// Returns error if clipboard access fails or temp file creation fails.
@@ -17,31 +43,89 @@ pub fn smart_copy(text: &str) -> Result<String> {
     // 2. Check Size
     let token_count = Tokenizer::count(text);
 
-    if token_count < 1500 {
-        // Small? Text Copy.
-        platform::perform_copy(text)?;
-        Ok("Text copied to clipboard".to_string())
-    } else {
+    let settings = settings();
+    if !settings.tmux {
+        return copy_system(text, token_count, &settings);
+    }
+    // The tmux buffer is enough on its own when there is no system clipboard.
+    let tmux = tools::load_tmux_buffer(text);
+    match (copy_system(text, token_count, &settings), tmux) {
+        (Ok(msg), Ok(())) => Ok(format!("{msg}; also loaded into the tmux buffer")),
+        (Ok(msg), Err(e)) => Ok(format!("{msg}; tmux buffer not loaded: {e}")),
+        (Err(e), Ok(())) => Ok(format!(
+            "Text ({token_count} tokens) loaded into the tmux buffer; clipboard failed: {e}"
+        )),
+        (Err(e), Err(_)) => Err(e),
+    }
+}
+
+fn copy_system(text: &str, token_count: usize, settings: &ClipboardSettings) -> Result<String> {
+    // Over SSH there is no clipboard to hand a file to; send the text itself,
+    // unless `backends` sets the order. Too big for the terminal, it falls
+    // through to the system clipboard.
+    if settings.backends.is_empty() && osc52::is_active() {
+        match osc52::copy(text) {
+            Ok(()) => {
+                return Ok(format!(
+                    "Text ({token_count} tokens) sent to the terminal clipboard (OSC 52)"
+                ))
+            }
+            Err(e) => eprintln!("WARN: {e}; trying the system clipboard"),
+        }
+    }
+
+    let chain = backend::configured(&settings.backends);
+    if copies_as_file(token_count, settings) {
         // Huge? File Copy.
         let file_path = temp::write_to_temp(text)?;
-        platform::copy_file_handle(&file_path)?;
+        let via = if chain.is_empty() {
+            platform::copy_file_handle(&file_path)?;
+            String::new()
+        } else {
+            format!(" {}", backend::copy_file(&file_path, &chain)?.describe())
+        };
 
         let filename = file_path
             .file_name()
             .map_or_else(|| "temp_file".into(), |n| n.to_string_lossy());
 
         Ok(format!(
-            "Large content ({token_count} tokens). Copied as file attachment: {filename}"
+            "{token_count} tokens. Copied as file attachment{via}: {filename}"
         ))
+    } else {
+        // Small? Text Copy.
+        if chain.is_empty() {
+            platform::perform_copy(text)?;
+            return Ok("Text copied to clipboard".to_string());
+        }
+        let copied = backend::copy(text, &chain)?;
+        Ok(format!("Text copied to clipboard {}", copied.describe()))
     }
 }
 
+pub(crate) fn settings() -> ClipboardSettings {
+    SETTINGS
+        .read()
+        .ok()
+        .and_then(|s| s.clone())
+        .unwrap_or_default()
+}
+
 /// Copies a file path to clipboard so it can be pasted as a file attachment.
 ///
 /// # Errors
 /// Returns error if clipboard access fails.
 pub fn copy_file_path(path: &Path) -> Result<()> {
-    platform::copy_file_handle(path)
+    let backends = settings().backends;
+    if backends.is_empty() && osc52::is_active() {
+        bail!("File handles cannot be copied over OSC 52");
+    }
+    let chain = backend::configured(&backends);
+    if chain.is_empty() {
+        return platform::copy_file_handle(path);
+    }
+    backend::copy_file(path, &chain)?;
+    Ok(())
 }
 
 /// Wrapper for backward compatibility.
@@ -58,5 +142,9 @@ pub fn copy_to_clipboard(text: &str) -> Result<()> {
 /// # Errors
 /// Returns error if clipboard access fails.
 pub fn read_clipboard() -> Result<String> {
-    platform::perform_read()
+    let chain = backend::configured(&settings().backends);
+    if chain.is_empty() {
+        return platform::perform_read();
+    }
+    backend::read(&chain)
 }
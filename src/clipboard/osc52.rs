@@ -0,0 +1,89 @@
+// src/clipboard/osc52.rs
+//! OSC 52 clipboard: the terminal sets the clipboard from an escape
+//! sequence, so copying works over SSH and in headless sessions without a
+//! display or X forwarding. Used when `SSH_TTY` is set, or when
+//! `SLOPCHOP_CLIPBOARD=osc52`; `SLOPCHOP_CLIPBOARD=system` turns it off.
+//! Terminals drop sequences past a size of their own, so bigger copies are
+//! refused rather than lost silently.
+
+use std::env;
+use std::fs::OpenOptions;
+use std::io::{self, Write};
+
+use anyhow::{bail, Result};
+
+const BASE64: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// The most base64 a copy sends; tmux and several terminals stop near 100 KB.
+pub const MAX_ENCODED: usize = 100_000;
+
+/// True if copies should go through the terminal instead of the system
+/// clipboard.
+#[must_use]
+pub fn is_active() -> bool {
+    match env::var("SLOPCHOP_CLIPBOARD").as_deref() {
+        Ok("osc52") => true,
+        Ok("system") => false,
+        _ => env::var_os("SSH_TTY").is_some(),
+    }
+}
+
+/// True if `text` is small enough to send.
+#[must_use]
+pub fn fits(text: &str) -> bool {
+    text.len().div_ceil(3) * 4 <= MAX_ENCODED
+}
+
+/// Standard base64 with padding.
+#[must_use]
+pub fn encode(bytes: &[u8]) -> String {
+    let mut out = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let n = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |n, (i, &b)| n | u32::from(b) << (16 - 8 * i));
+        for i in 0..4 {
+            if i <= chunk.len() {
+                out.push(char::from(BASE64[(n >> (18 - 6 * i) & 63) as usize]));
+            } else {
+                out.push('=');
+            }
+        }
+    }
+    out
+}
+
+/// The escape sequence that copies `text`. Inside tmux it is wrapped so
+/// tmux passes it on to the outer terminal.
+#[must_use]
+pub fn sequence(text: &str, tmux: bool) -> String {
+    let osc = format!("\x1b]52;c;{}\x07", encode(text.as_bytes()));
+    if tmux {
+        format!("\x1bPtmux;{}\x1b\\", osc.replace('\x1b', "\x1b\x1b"))
+    } else {
+        osc
+    }
+}
+
+/// Copies `text` by writing the sequence to the terminal, or to stderr if
+/// there is no `/dev/tty`, so piped stdout stays clean.
+///
+/// # Errors
+/// Returns error if `text` does not [`fits`] or the sequence cannot be
+/// written.
+pub fn copy(text: &str) -> Result<()> {
+    if !fits(text) {
+        bail!(
+            "{} KB is more than OSC 52 can carry ({} KB once encoded)",
+            text.len() / 1000,
+            MAX_ENCODED / 1000
+        );
+    }
+    let seq = sequence(text, env::var_os("TMUX").is_some());
+    match OpenOptions::new().write(true).open("/dev/tty") {
+        Ok(mut tty) => tty.write_all(seq.as_bytes())?,
+        Err(_) => io::stderr().write_all(seq.as_bytes())?,
+    }
+    Ok(())
+}
@@ -0,0 +1,102 @@
+// src/clipboard/tools.rs
+//! Running the clipboard tools behind each `[clipboard] backends` entry.
+//! Failures are plain reasons, collected by `backend` into diagnostics.
+
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+use super::osc52;
+use crate::config::ClipboardBackend;
+
+/// Copies `text` with `backend`.
+///
+/// # Errors
+/// Returns why the backend could not take it.
+pub fn copy(backend: ClipboardBackend, text: &str) -> Result<(), String> {
+    match backend {
+        ClipboardBackend::WlCopy => pipe("wl-copy", &[], text),
+        ClipboardBackend::Xclip => pipe("xclip", &["-selection", "clipboard", "-in"], text),
+        ClipboardBackend::Xsel => pipe("xsel", &["--clipboard", "--input"], text),
+        ClipboardBackend::Tmux => load_tmux_buffer(text),
+        ClipboardBackend::Osc52 => osc52::copy(text).map_err(|e| e.to_string()),
+    }
+}
+
+/// Copies `uri` as `text/uri-list`, which only `wl-copy` and `xclip` can
+/// set.
+///
+/// # Errors
+/// Returns why the backend could not take it.
+pub fn copy_uri(backend: ClipboardBackend, uri: &str) -> Result<(), String> {
+    match backend {
+        ClipboardBackend::WlCopy => pipe("wl-copy", &["--type", "text/uri-list"], uri),
+        ClipboardBackend::Xclip => pipe(
+            "xclip",
+            &["-selection", "clipboard", "-t", "text/uri-list", "-i"],
+            uri,
+        ),
+        _ => Err("cannot copy file attachments".to_string()),
+    }
+}
+
+/// Loads `text` into a tmux paste buffer, for `prefix ]` to paste.
+///
+/// # Errors
+/// Returns why tmux could not take it.
+pub fn load_tmux_buffer(text: &str) -> Result<(), String> {
+    pipe("tmux", &["load-buffer", "-"], text)
+}
+
+/// Reads the clipboard with `backend`.
+///
+/// # Errors
+/// Returns why the backend could not be read.
+pub fn read(backend: ClipboardBackend) -> Result<String, String> {
+    let (program, args): (&str, &[&str]) = match backend {
+        ClipboardBackend::WlCopy => ("wl-paste", &["--no-newline"]),
+        ClipboardBackend::Xclip => ("xclip", &["-selection", "clipboard", "-out"]),
+        ClipboardBackend::Xsel => ("xsel", &["--clipboard", "--output"]),
+        ClipboardBackend::Tmux => ("tmux", &["save-buffer", "-"]),
+        ClipboardBackend::Osc52 => return Err("cannot read the clipboard".to_string()),
+    };
+    let output = Command::new(program)
+        .args(args)
+        .stdin(Stdio::null())
+        .output()
+        .map_err(|e| spawn_error(program, &e))?;
+    if !output.status.success() {
+        return Err(format!("exited with {}", output.status));
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).to_string())
+}
+
+/// Runs `program` with `input` on stdin. Its output is discarded rather
+/// than captured: `wl-copy` and `xclip` fork to keep serving the
+/// selection, and would hold a captured pipe open.
+fn pipe(program: &str, args: &[&str], input: &str) -> Result<(), String> {
+    let mut child = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+        .map_err(|e| spawn_error(program, &e))?;
+    if let Some(mut stdin) = child.stdin.take() {
+        stdin
+            .write_all(input.as_bytes())
+            .map_err(|e| format!("failed to write to {program}: {e}"))?;
+    }
+    let status = child.wait().map_err(|e| e.to_string())?;
+    if !status.success() {
+        return Err(format!("exited with {status}"));
+    }
+    Ok(())
+}
+
+fn spawn_error(program: &str, err: &io::Error) -> String {
+    if err.kind() == io::ErrorKind::NotFound {
+        "not installed".to_string()
+    } else {
+        format!("failed to run {program}: {err}")
+    }
+}
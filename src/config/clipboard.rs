@@ -0,0 +1,91 @@
+// src/config/clipboard.rs
+//! The `[clipboard]` section: how copies are made and which tools make them.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// How `--copy` puts content on the clipboard (`[clipboard]`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ClipboardSettings {
+    /// In `auto` mode, content of at least this many tokens is copied as a
+    /// file attachment instead of text.
+    #[serde(default = "default_file_copy_threshold")]
+    pub file_copy_threshold: usize,
+    #[serde(default)]
+    pub mode: CopyMode,
+    /// Recent packs and prompts kept for `slopchop history`; 0 keeps none.
+    #[serde(default = "default_history")]
+    pub history: usize,
+    /// Also load every copy into the tmux paste buffer.
+    #[serde(default)]
+    pub tmux: bool,
+    /// Backends to try, in order; empty picks the platform's own.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub backends: Vec<ClipboardBackend>,
+}
+
+impl Default for ClipboardSettings {
+    fn default() -> Self {
+        Self {
+            file_copy_threshold: default_file_copy_threshold(),
+            mode: CopyMode::default(),
+            history: default_history(),
+            tmux: false,
+            backends: Vec::new(),
+        }
+    }
+}
+
+fn default_file_copy_threshold() -> usize {
+    1500
+}
+
+fn default_history() -> usize {
+    20
+}
+
+impl ClipboardSettings {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Whether copies are text or file attachments (`[clipboard] mode`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum CopyMode {
+    /// Text below `file_copy_threshold`, a file at or above it.
+    #[default]
+    Auto,
+    Text,
+    File,
+}
+
+/// A clipboard tool in the `[clipboard] backends` chain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ClipboardBackend {
+    #[serde(rename = "wl-copy")]
+    WlCopy,
+    #[serde(rename = "xclip")]
+    Xclip,
+    #[serde(rename = "xsel")]
+    Xsel,
+    /// The tmux paste buffer (`tmux load-buffer`).
+    #[serde(rename = "tmux")]
+    Tmux,
+    #[serde(rename = "osc52")]
+    Osc52,
+}
+
+impl ClipboardBackend {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::WlCopy => "wl-copy",
+            Self::Xclip => "xclip",
+            Self::Xsel => "xsel",
+            Self::Tmux => "tmux",
+            Self::Osc52 => "osc52",
+        }
+    }
+}
@@ -0,0 +1,219 @@
+// src/config/commands.rs
+//! `[commands]` steps. Each is a command line, or a table that also sets
+//! `cwd`, `env`, `continue_on_error`, and an `only_if` condition:
+//!
+//! ```toml
+//! [commands]
+//! fix = [
+//!     { run = "cargo fmt", only_if = { exists = "Cargo.toml" } },
+//!     { run = "npx prettier --write .", cwd = "web", only_if = { changed = "*.ts" } },
+//! ]
+//! ```
+
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::discovery;
+use crate::ignore::IgnoreSet;
+
+/// A `[commands]` value: one step or a list of them. `List` comes first
+/// because serde would also read a list as the fields of one step.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum CommandEntry {
+    List(Vec<StepEntry>),
+    Single(StepEntry),
+}
+
+impl CommandEntry {
+    #[must_use]
+    pub fn into_vec(self) -> Vec<CommandStep> {
+        match self {
+            Self::Single(s) => vec![s.into()],
+            Self::List(v) => v.into_iter().map(CommandStep::from).collect(),
+        }
+    }
+}
+
+/// A step as written: a bare command line or a table.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+#[serde(untagged)]
+pub enum StepEntry {
+    Line(String),
+    Step(CommandStep),
+}
+
+/// One command of a pipeline.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CommandStep {
+    /// The command line, split on whitespace (no shell).
+    pub run: String,
+    /// Directory to run in, relative to the project root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<PathBuf>,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub env: BTreeMap<String, String>,
+    /// Go on to the next step if this one fails or can't start.
+    #[serde(default, skip_serializing_if = "is_false")]
+    pub continue_on_error: bool,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub only_if: Option<Condition>,
+}
+
+/// When a step runs; every key given must hold.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Condition {
+    /// A path, relative to the project root, that must exist.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub exists: Option<String>,
+    /// A `.wardenignore`-style pattern some changed file must match.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub changed: Option<String>,
+}
+
+#[allow(clippy::trivially_copy_pass_by_ref)]
+fn is_false(b: &bool) -> bool {
+    !b
+}
+
+impl From<StepEntry> for CommandStep {
+    fn from(entry: StepEntry) -> Self {
+        match entry {
+            StepEntry::Line(run) => Self::line(run),
+            StepEntry::Step(step) => step,
+        }
+    }
+}
+
+impl From<CommandStep> for StepEntry {
+    fn from(step: CommandStep) -> Self {
+        if step == CommandStep::line(step.run.clone()) {
+            Self::Line(step.run)
+        } else {
+            Self::Step(step)
+        }
+    }
+}
+
+impl CommandStep {
+    /// A plain command line.
+    #[must_use]
+    pub fn line(run: impl Into<String>) -> Self {
+        Self {
+            run: run.into(),
+            ..Self::default()
+        }
+    }
+
+    /// This step running `run` instead, with the same options.
+    #[must_use]
+    pub fn with_run(&self, run: impl Into<String>) -> Self {
+        Self {
+            run: run.into(),
+            ..self.clone()
+        }
+    }
+
+    /// `run` split into the program and its arguments. Quotes group words,
+    /// and a backslash escapes a quote, a space, or itself; nothing else is
+    /// interpreted.
+    #[must_use]
+    pub fn args(&self) -> Vec<String> {
+        split_args(&self.run)
+    }
+
+    /// The process for `run` with `cwd` and `env` set, or `None` if `run`
+    /// is empty.
+    #[must_use]
+    pub fn command(&self) -> Option<Command> {
+        self.command_with(&self.args())
+    }
+
+    /// [`CommandStep::command`] running `args` in place of `run`.
+    #[must_use]
+    pub fn command_with(&self, args: &[String]) -> Option<Command> {
+        let (program, rest) = args.split_first()?;
+        let mut command = Command::new(program);
+        command.args(rest).envs(&self.env);
+        if let Some(cwd) = &self.cwd {
+            command.current_dir(cwd);
+        }
+        Some(command)
+    }
+
+    /// True if `only_if` holds, or there is none.
+    pub fn should_run(&self, changed: &mut ChangedFiles) -> bool {
+        self.only_if.as_ref().is_none_or(|c| c.holds(changed))
+    }
+}
+
+impl Condition {
+    fn holds(&self, changed: &mut ChangedFiles) -> bool {
+        let exists = self.exists.as_ref().is_none_or(|p| Path::new(p).exists());
+        exists && self.changed.as_ref().is_none_or(|p| changed.any_match(p))
+    }
+}
+
+/// The files `only_if = { changed = ... }` looks at: given up front, or
+/// asked of git the first time a step needs them.
+#[derive(Debug, Default)]
+pub struct ChangedFiles {
+    files: Option<Vec<String>>,
+}
+
+impl ChangedFiles {
+    /// Files git reports as changed (staged, unstaged, or untracked).
+    #[must_use]
+    pub fn from_git() -> Self {
+        Self::default()
+    }
+
+    #[must_use]
+    pub fn listed(files: &[String]) -> Self {
+        Self {
+            files: Some(files.to_vec()),
+        }
+    }
+
+    fn any_match(&mut self, pattern: &str) -> bool {
+        let set = IgnoreSet::from_patterns(&[pattern]);
+        let files = self.files.get_or_insert_with(|| {
+            discovery::changed_files(None)
+                .map(|found| found.iter().map(|p| p.to_string_lossy().into()).collect())
+                .unwrap_or_default()
+        });
+        files.iter().any(|f| set.is_ignored(f))
+    }
+}
+
+fn split_args(line: &str) -> Vec<String> {
+    let mut args = Vec::new();
+    let mut word: Option<String> = None;
+    let mut quote = None;
+    let mut chars = line.chars().peekable();
+    while let Some(c) = chars.next() {
+        match (quote, c) {
+            (None, c) if c.is_whitespace() => args.extend(word.take()),
+            (None, '\'' | '"') => quote = Some(c),
+            (Some(q), c) if c == q => quote = None,
+            (_, '\\') if chars.peek().is_some_and(|n| is_escapable(*n)) => {
+                word.get_or_insert_default().extend(chars.next());
+                continue;
+            }
+            _ => word.get_or_insert_default().push(c),
+        }
+        if quote.is_some() {
+            word.get_or_insert_default();
+        }
+    }
+    args.extend(word);
+    args
+}
+
+fn is_escapable(c: char) -> bool {
+    matches!(c, '\'' | '"' | '\\') || c.is_whitespace()
+}
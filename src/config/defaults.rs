@@ -0,0 +1,81 @@
+// src/config/defaults.rs
+//! `[defaults.<command>]`: the flags `pack`, `check`, and `apply` use when
+//! the command line leaves them out. Any value given on the command line
+//! wins, and each switch set here has a flag that turns it off for one run
+//! (`--no-skeleton`, `--tests`, `--no-blame`, `--no-review`, ...).
+
+use crate::pack::comments::StripComments;
+use crate::pack::order::FileOrder;
+use crate::pack::OutputFormat;
+use crate::reporting::{GroupBy, ReportFormat};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CommandDefaults {
+    #[serde(default)]
+    pub pack: PackDefaults,
+    #[serde(default)]
+    pub check: CheckDefaults,
+    #[serde(default)]
+    pub apply: ApplyDefaults,
+}
+
+impl CommandDefaults {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// `[defaults.pack]`, named after the flags.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PackDefaults {
+    /// Copy the pack to the clipboard unless `--stdout` is given.
+    #[serde(default)]
+    pub copy: bool,
+    #[serde(default)]
+    pub stdout: bool,
+    #[serde(default)]
+    pub noprompt: bool,
+    pub format: Option<OutputFormat>,
+    #[serde(default)]
+    pub skeleton: bool,
+    #[serde(default)]
+    pub code_only: bool,
+    #[serde(default)]
+    pub no_tests: bool,
+    #[serde(default)]
+    pub no_generated: bool,
+    pub order: Option<FileOrder>,
+    pub budget: Option<usize>,
+    /// Commits to list, as `--git-info N`.
+    pub git_info: Option<usize>,
+    #[serde(default)]
+    pub line_numbers: bool,
+    pub strip_comments: Option<StripComments>,
+}
+
+/// `[defaults.check]`, also used by the bare `slopchop` scan.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CheckDefaults {
+    pub format: Option<ReportFormat>,
+    pub group_by: Option<GroupBy>,
+    #[serde(default)]
+    pub blame: bool,
+    #[serde(default)]
+    pub changed: bool,
+}
+
+/// `[defaults.apply]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ApplyDefaults {
+    /// Review each file, except with `--dry-run` or `--stdin`.
+    #[serde(default)]
+    pub review: bool,
+    /// Ignored: skipping the confirmation takes `--force` on each run.
+    #[deprecated(note = "skipping the confirmation takes `--force` on each run")]
+    #[serde(default)]
+    pub force: bool,
+}
@@ -0,0 +1,84 @@
+// src/config/env.rs
+//! Config overrides from the environment, applied after `warden.toml`
+//! and everything it extends, so CI can tighten or relax rules without a
+//! commit.
+//!
+//! `SLOPCHOP_<SECTION>__<KEY>=value` sets `key` in `[section]`: the prefix
+//! is dropped, `__` separates table levels, and names are lowercased. So
+//! `SLOPCHOP_RULES__MAX_FILE_TOKENS=3000` is `[rules] max_file_tokens =
+//! 3000`, and `SLOPCHOP_RULES__PYTHON__MAX_NESTING_DEPTH=5` reaches
+//! `[rules.python]`. Values are read as TOML (`3000`, `true`, `["a"]`),
+//! falling back to a plain string. Variables without `__`, such as
+//! `SLOPCHOP_CLIPBOARD`, are not config overrides.
+
+use toml::{Table, Value};
+
+pub const ENV_PREFIX: &str = "SLOPCHOP_";
+
+/// One override: the key path and its value.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Override {
+    pub path: Vec<String>,
+    pub value: Value,
+}
+
+/// The overrides among `vars`, sorted by variable name so the result does
+/// not depend on environment order.
+#[must_use]
+pub fn overrides(vars: impl IntoIterator<Item = (String, String)>) -> Vec<Override> {
+    let mut found: Vec<(String, Override)> = vars
+        .into_iter()
+        .filter_map(|(name, raw)| {
+            let rest = name.strip_prefix(ENV_PREFIX)?;
+            let path: Vec<String> = rest.split("__").map(str::to_lowercase).collect();
+            if path.len() < 2 || path.iter().any(String::is_empty) {
+                return None;
+            }
+            let value = parse_value(&raw);
+            Some((name, Override { path, value }))
+        })
+        .collect();
+    found.sort_by(|a, b| a.0.cmp(&b.0));
+    found.into_iter().map(|(_, o)| o).collect()
+}
+
+/// The overrides set in this process's environment.
+#[must_use]
+pub fn from_env() -> Vec<Override> {
+    overrides(std::env::vars())
+}
+
+fn parse_value(raw: &str) -> Value {
+    toml::from_str::<Table>(&format!("v = {raw}"))
+        .ok()
+        .and_then(|mut t| t.remove("v"))
+        .unwrap_or_else(|| Value::String(raw.to_string()))
+}
+
+/// Sets each override in `table`, creating tables along its path and
+/// replacing any non-table value in the way.
+pub fn apply(table: &mut Table, overrides: &[Override]) {
+    for o in overrides {
+        set(table, &o.path, o.value.clone());
+    }
+}
+
+fn set(table: &mut Table, path: &[String], value: Value) {
+    match path {
+        [] => {}
+        [key] => {
+            table.insert(key.clone(), value);
+        }
+        [part, rest @ ..] => {
+            let entry = table
+                .entry(part.clone())
+                .or_insert_with(|| Value::Table(Table::new()));
+            if !entry.is_table() {
+                *entry = Value::Table(Table::new());
+            }
+            if let Value::Table(next) = entry {
+                set(next, rest, value);
+            }
+        }
+    }
+}
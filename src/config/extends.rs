@@ -0,0 +1,120 @@
+// src/config/extends.rs
+//! `extends`: a config file builds on other files or built-in presets.
+//!
+//! `extends = "../slopchop.base.toml"` or `extends = ["preset:strict",
+//! "team.toml"]`. Bases are merged in order, then the file itself on top.
+//! Tables merge key by key, so a repo overrides single limits; any other
+//! value, arrays included, replaces the inherited one. Paths are relative
+//! to the file that names them.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use toml::{Table, Value};
+
+use super::files;
+use crate::project::Strictness;
+
+/// Prefix naming a built-in preset instead of a file.
+pub const PRESET_PREFIX: &str = "preset:";
+
+/// How deep `extends` chains may go before they are taken as a cycle.
+const MAX_DEPTH: usize = 8;
+
+/// Merges everything `table` extends under it, resolving paths against
+/// `dir`. The result no longer has an `extends` key.
+///
+/// # Errors
+/// Returns a message if any base cannot be read or parsed.
+pub fn resolve(table: Table, dir: &Path) -> Result<Table, String> {
+    resolve_at(table, dir, 0)
+}
+
+/// The files `table` extends, directly or through other bases, resolved
+/// against `dir`. Presets and bases that cannot be read are left out.
+#[must_use]
+pub fn files(table: &Table, dir: &Path) -> Vec<PathBuf> {
+    let mut found = Vec::new();
+    collect_files(table, dir, 0, &mut found);
+    found
+}
+
+fn collect_files(table: &Table, dir: &Path, depth: usize, found: &mut Vec<PathBuf>) {
+    let Some(extends) = table.get("extends").filter(|_| depth < MAX_DEPTH) else {
+        return;
+    };
+    let names = names(extends.clone()).unwrap_or_default();
+    for name in names.iter().filter(|n| !n.starts_with(PRESET_PREFIX)) {
+        let path = files::resolve(&dir.join(name));
+        let Ok(content) = fs::read_to_string(&path) else {
+            continue;
+        };
+        let base_dir = path.parent().map_or_else(PathBuf::new, Path::to_path_buf);
+        found.push(path);
+        if let Ok(base) = toml::from_str::<Table>(&content) {
+            collect_files(&base, &base_dir, depth + 1, found);
+        }
+    }
+}
+
+fn parse_at(content: &str, dir: &Path, depth: usize) -> Result<Table, String> {
+    let table: Table = toml::from_str(content).map_err(|e| e.to_string())?;
+    resolve_at(table, dir, depth)
+}
+
+fn resolve_at(mut table: Table, dir: &Path, depth: usize) -> Result<Table, String> {
+    let Some(extends) = table.remove("extends") else {
+        return Ok(table);
+    };
+    if depth >= MAX_DEPTH {
+        return Err(format!(
+            "`extends` nested more than {MAX_DEPTH} deep; is there a cycle?"
+        ));
+    }
+    let mut merged = Table::new();
+    for base in names(extends)? {
+        merge(&mut merged, load_base(&base, dir, depth)?);
+    }
+    merge(&mut merged, table);
+    Ok(merged)
+}
+
+fn names(extends: Value) -> Result<Vec<String>, String> {
+    match extends {
+        Value::String(name) => Ok(vec![name]),
+        Value::Array(items) => items
+            .into_iter()
+            .map(|v| match v {
+                Value::String(name) => Ok(name),
+                other => Err(format!("`extends` entries must be strings, not {other}")),
+            })
+            .collect(),
+        other => Err(format!("`extends` must be a string or list, not {other}")),
+    }
+}
+
+fn load_base(name: &str, dir: &Path, depth: usize) -> Result<Table, String> {
+    if let Some(preset) = name.strip_prefix(PRESET_PREFIX) {
+        let strictness = Strictness::from_name(preset)
+            .ok_or_else(|| format!("Unknown preset `{preset}` (strict, standard, relaxed)"))?;
+        return parse_at(&crate::project::rules_toml(strictness), dir, depth + 1);
+    }
+    let path = files::resolve(&dir.join(name));
+    let content =
+        fs::read_to_string(&path).map_err(|e| format!("Cannot read {}: {e}", path.display()))?;
+    let base_dir = path.parent().map_or_else(PathBuf::new, Path::to_path_buf);
+    parse_at(&content, &base_dir, depth + 1).map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Merges `over` into `base`: nested tables key by key, anything else
+/// replaced.
+pub fn merge(base: &mut Table, over: Table) {
+    for (key, value) in over {
+        match (base.get_mut(&key), value) {
+            (Some(Value::Table(inner)), Value::Table(value)) => merge(inner, value),
+            (_, value) => {
+                base.insert(key, value);
+            }
+        }
+    }
+}
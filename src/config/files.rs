@@ -0,0 +1,60 @@
+// src/config/files.rs
+//! The project files slopchop reads, under their current names. Older
+//! versions used the slopchop names; a file still under one is read until
+//! `slopchop migrate` renames it, and the current name wins when both exist.
+
+use std::path::{Path, PathBuf};
+
+pub const CONFIG_FILE: &str = "warden.toml";
+pub const IGNORE_FILE: &str = ".wardenignore";
+pub const INTENT_FILE: &str = ".warden_intent";
+
+/// Each legacy name and the name that replaced it.
+pub const RENAMES: [(&str, &str); 3] = [
+    ("slopchop.toml", CONFIG_FILE),
+    (".slopchopignore", IGNORE_FILE),
+    (".slopchop_intent", INTENT_FILE),
+];
+
+/// The file `name` in `dir`, or the file under its legacy name when only
+/// that one exists.
+#[must_use]
+pub fn find(dir: &Path, name: &str) -> PathBuf {
+    resolve(&dir.join(name))
+}
+
+/// The file `name` in the current directory, as [`find`] picks it.
+#[must_use]
+pub fn local(name: &str) -> PathBuf {
+    find(Path::new(""), name)
+}
+
+/// `path`, or its sibling under the other of its two names when only that
+/// one exists.
+#[must_use]
+pub fn resolve(path: &Path) -> PathBuf {
+    if path.exists() {
+        return path.to_path_buf();
+    }
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return path.to_path_buf();
+    };
+    RENAMES
+        .iter()
+        .find_map(|&(old, new)| {
+            if name == new {
+                Some(old)
+            } else {
+                (name == old).then_some(new)
+            }
+        })
+        .map(|other| path.with_file_name(other))
+        .filter(|other| other.exists())
+        .unwrap_or_else(|| path.to_path_buf())
+}
+
+/// True if `name` is a config file name, current or legacy.
+#[must_use]
+pub fn is_config(name: &str) -> bool {
+    name == CONFIG_FILE || name == RENAMES[0].0
+}
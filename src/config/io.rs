@@ -1,14 +1,20 @@
 // src/config/io.rs
-use super::types::{CommandEntry, Config, Preferences, RuleConfig, SlopChopToml};
+use super::commands::{CommandEntry, CommandStep, StepEntry};
+use super::env::{self, Override};
+use super::extends;
+use super::files;
+use super::preset;
+use super::rule_config::RuleConfig;
+use super::rules::RulesFile;
+use super::types::{Config, Preferences, SlopChopToml};
 use crate::error::Result;
-use crate::project::{self, ProjectType};
-use regex::Regex;
+use crate::project;
 use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 
 pub fn load_ignore_file(config: &mut Config) {
-    let Ok(content) = fs::read_to_string(".slopchopignore") else {
+    let Ok(content) = fs::read_to_string(files::local(files::IGNORE_FILE)) else {
         return;
     };
     for line in content.lines() {
@@ -17,31 +23,64 @@ pub fn load_ignore_file(config: &mut Config) {
 }
 
 pub fn process_ignore_line(config: &mut Config, line: &str) {
-    let trimmed = line.trim();
-    if trimmed.is_empty() || trimmed.starts_with('#') {
-        return;
-    }
-    if let Ok(re) = Regex::new(trimmed) {
-        config.exclude_patterns.push(re);
-    }
+    config.files.exclude_patterns.add(line);
 }
 
+/// Loads `warden.toml` (or the legacy `slopchop.toml`), then the
+/// `SLOPCHOP_<SECTION>__<KEY>` environment overrides (see [`env`]), which
+/// apply even without a file.
 pub fn load_toml_config(config: &mut Config) {
-    if !Path::new("slopchop.toml").exists() {
-        return;
+    let overrides = env::from_env();
+    let path = files::local(files::CONFIG_FILE);
+    let content = fs::read_to_string(&path).unwrap_or_default();
+    if path.exists() {
+        config.sources.push(path);
     }
-    let Ok(content) = fs::read_to_string("slopchop.toml") else {
+    if content.is_empty() && overrides.is_empty() {
         return;
-    };
-    parse_toml(config, &content);
+    }
+    parse_toml_with(config, &content, Path::new("."), &overrides);
 }
 
 pub fn parse_toml(config: &mut Config, content: &str) {
-    let Ok(parsed) = toml::from_str::<SlopChopToml>(content) else {
+    parse_toml_in(config, content, Path::new("."));
+}
+
+/// Parses `content`, resolving `extends` paths against `dir`. A base that
+/// cannot be loaded is skipped with a warning.
+pub fn parse_toml_in(config: &mut Config, content: &str, dir: &Path) {
+    parse_toml_with(config, content, dir, &[]);
+}
+
+/// Parses `content` as [`parse_toml_in`] does, then sets `overrides` on top.
+pub fn parse_toml_with(config: &mut Config, content: &str, dir: &Path, overrides: &[Override]) {
+    let Ok(table) = toml::from_str::<toml::Table>(content) else {
+        return;
+    };
+    for base in extends::files(&table, dir) {
+        if !config.sources.contains(&base) {
+            config.sources.push(base);
+        }
+    }
+    let table = extends::resolve(table.clone(), dir).unwrap_or_else(|e| {
+        eprintln!("WARN: Ignoring `extends`: {e}");
+        let mut table = table;
+        table.remove("extends");
+        table
+    });
+    let Some(parsed) = with_overrides(table, overrides) else {
         return;
     };
     config.rules = parsed.rules;
     config.preferences = parsed.preferences;
+    config.apply = parsed.apply;
+    config.hooks = parsed.hooks;
+    config.git = parsed.git;
+    config.verify = parsed.verify;
+    config.pack = parsed.pack;
+    config.tokens = parsed.tokens;
+    config.clipboard = parsed.clipboard;
+    config.defaults = parsed.defaults;
     config.commands = parsed
         .commands
         .into_iter()
@@ -49,17 +88,63 @@ pub fn parse_toml(config: &mut Config, content: &str) {
         .collect();
 }
 
+/// Deserializes `table` with `overrides` set, or without them, after a
+/// warning, if they don't fit the schema.
+fn with_overrides(table: toml::Table, overrides: &[Override]) -> Option<SlopChopToml> {
+    if !overrides.is_empty() {
+        let mut changed = table.clone();
+        env::apply(&mut changed, overrides);
+        match deserialize(changed) {
+            Ok(parsed) => return Some(parsed),
+            Err(e) => eprintln!("WARN: Ignoring {}* overrides: {e}", env::ENV_PREFIX),
+        }
+    }
+    deserialize(table).ok()
+}
+
+/// Deserializes `table` after filling in what `[rules] preset` sets.
+fn deserialize(mut table: toml::Table) -> std::result::Result<SlopChopToml, toml::de::Error> {
+    preset::expand(&mut table);
+    toml::Value::Table(table).try_into()
+}
+
+/// Appends custom rules from every `*.toml` file in `dir`.
+pub fn load_rules_dir(config: &mut Config, dir: &Path) {
+    let Ok(entries) = fs::read_dir(dir) else {
+        return;
+    };
+    let mut paths: Vec<_> = entries
+        .flatten()
+        .map(|e| e.path())
+        .filter(|p| p.extension().is_some_and(|e| e == "toml"))
+        .collect();
+    paths.sort();
+    for path in paths {
+        load_rules_file(config, &path);
+    }
+}
+
+fn load_rules_file(config: &mut Config, path: &Path) {
+    let Ok(content) = fs::read_to_string(path) else {
+        return;
+    };
+    match toml::from_str::<RulesFile>(&content) {
+        Ok(file) => config.rules.custom.extend(file.custom),
+        Err(e) => eprintln!("WARN: Failed to parse {}: {e}", path.display()),
+    }
+}
+
 pub fn apply_project_defaults(config: &mut Config) {
     if config.commands.contains_key("check") {
         return;
     }
-    let defaults = project_defaults(ProjectType::detect());
-    for (k, v) in defaults {
-        config.commands.entry(k).or_insert(v);
+    for (k, steps) in project::default_commands(Path::new(".")) {
+        config.commands.entry(k).or_insert(steps);
     }
 }
 
-/// Saves the configuration to the file system.
+/// Saves the configuration to the file system, with the other sections
+/// as `saved` has them.
 ///
 /// # Errors
 /// Returns an error if the config cannot be serialized or written to disk.
@@ -67,60 +152,60 @@ pub fn apply_project_defaults(config: &mut Config) {
 pub fn save_to_file(
     rules: &RuleConfig,
     prefs: &Preferences,
-    commands: &HashMap<String, Vec<String>>,
+    commands: &HashMap<String, Vec<CommandStep>>,
+    saved: &SlopChopToml,
 ) -> Result<()> {
     let cmd_entries: HashMap<String, CommandEntry> = commands
         .iter()
-        .map(|(k, v)| (k.clone(), CommandEntry::List(v.clone())))
+        .map(|(k, v)| {
+            let steps = v.iter().cloned().map(StepEntry::from).collect();
+            (k.clone(), CommandEntry::List(steps))
+        })
         .collect();
 
+    let saved = saved.clone();
     let toml_struct = SlopChopToml {
+        extends: saved.extends,
         rules: rules.clone(),
         preferences: prefs.clone(),
         commands: cmd_entries,
+        apply: saved.apply,
+        hooks: saved.hooks,
+        git: saved.git,
+        verify: saved.verify,
+        pack: saved.pack,
+        tokens: saved.tokens,
+        clipboard: saved.clipboard,
+        defaults: saved.defaults,
     };
 
     let content = toml::to_string_pretty(&toml_struct).map_err(|e| {
         crate::error::SlopChopError::Other(format!("Failed to serialize config: {e}"))
     })?;
 
-    fs::write("slopchop.toml", content)?;
+    fs::write(files::local(files::CONFIG_FILE), content)?;
     Ok(())
 }
 
-fn project_defaults(project: ProjectType) -> HashMap<String, Vec<String>> {
-    let mut m = HashMap::new();
-    match project {
-        ProjectType::Rust => {
-            m.insert(
-                "check".into(),
-                vec![
-                    "cargo clippy --all-targets -- -D warnings -W clippy::pedantic".into(),
-                    "cargo test".into(),
-                ],
-            );
-            m.insert("fix".into(), vec!["cargo fmt".into()]);
-        }
-        ProjectType::Node => {
-            let npx = project::npx_cmd();
-            m.insert(
-                "check".into(),
-                vec![format!("{npx} @biomejs/biome check src/")],
-            );
-            m.insert(
-                "fix".into(),
-                vec![format!("{npx} @biomejs/biome check --write src/")],
-            );
-        }
-        ProjectType::Python => {
-            m.insert("check".into(), vec!["ruff check .".into()]);
-            m.insert("fix".into(), vec!["ruff check --fix .".into()]);
-        }
-        ProjectType::Go => {
-            m.insert("check".into(), vec!["go vet ./...".into()]);
-            m.insert("fix".into(), vec!["go fmt ./...".into()]);
-        }
-        ProjectType::Unknown => {}
-    }
-    m
+/// The current config file, whose `extends`, `[apply]`, `[hooks]`,
+/// `[git]`, `[verify.*]`, `[pack]`, `[tokens]`, `[clipboard]` and
+/// `[defaults.*]` are kept when the editor rewrites it; empty if there is
+/// no file.
+///
+/// # Errors
+/// Returns an error if the file cannot be read or parsed, since saving
+/// over it would lose those sections.
+pub fn saved_sections() -> Result<SlopChopToml> {
+    let path = files::local(files::CONFIG_FILE);
+    let content = match fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(SlopChopToml::default()),
+        Err(e) => return Err(e.into()),
+    };
+    toml::from_str(&content).map_err(|e| {
+        crate::error::SlopChopError::Other(format!(
+            "{} is not valid, so saving would lose its other sections: {e}",
+            path.display()
+        ))
+    })
 }
@@ -0,0 +1,97 @@
+// src/config/languages.rs
+//! `[rules.rust]`, `[rules.python]`, `[rules.typescript]`, `[rules.bash]`
+//! and `[rules.zig]`: limits for one language that replace the global
+//! `[rules]` ones for files of that language.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::rule_config::RuleConfig;
+use crate::lang::Lang;
+
+/// The overrides for one language; unset fields keep the global value.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LangRules {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cyclomatic_complexity: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_cognitive_complexity: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_nesting_depth: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_function_args: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_function_lines: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_file_functions: Option<usize>,
+    /// Replaces this language's entry in `[rules.banned_calls]`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub banned_calls: Option<Vec<String>>,
+}
+
+impl LangRules {
+    /// Writes the set fields over `rules`, whose `banned_calls` are keyed
+    /// by `key`.
+    pub fn apply_to(&self, rules: &mut RuleConfig, key: &str) {
+        let limits = [
+            (
+                self.max_cyclomatic_complexity,
+                &mut rules.functions.max_cyclomatic_complexity,
+            ),
+            (
+                self.max_cognitive_complexity,
+                &mut rules.functions.max_cognitive_complexity,
+            ),
+            (
+                self.max_nesting_depth,
+                &mut rules.functions.max_nesting_depth,
+            ),
+            (
+                self.max_function_args,
+                &mut rules.functions.max_function_args,
+            ),
+            (
+                self.max_function_lines,
+                &mut rules.functions.max_function_lines,
+            ),
+            (self.max_file_functions, &mut rules.size.max_file_functions),
+        ];
+        for (value, limit) in limits {
+            if let Some(value) = value {
+                *limit = value;
+            }
+        }
+        if let Some(banned) = &self.banned_calls {
+            rules.banned_calls.insert(key.to_string(), banned.clone());
+        }
+    }
+}
+
+/// The per-language tables, flattened into `[rules]`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct LanguageRules {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rust: Option<LangRules>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub python: Option<LangRules>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub typescript: Option<LangRules>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bash: Option<LangRules>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub zig: Option<LangRules>,
+}
+
+impl LanguageRules {
+    /// The overrides for `lang`, if its table is present.
+    #[must_use]
+    pub fn get(&self, lang: Lang) -> Option<&LangRules> {
+        match lang {
+            Lang::Rust => self.rust.as_ref(),
+            Lang::Python => self.python.as_ref(),
+            Lang::TypeScript => self.typescript.as_ref(),
+            Lang::Bash => self.bash.as_ref(),
+            #[cfg(feature = "zig")]
+            Lang::Zig => self.zig.as_ref(),
+        }
+    }
+}
@@ -0,0 +1,123 @@
+// src/config/limits.rs
+//! Groups of `[rules]` keys. Each is flattened into [`RuleConfig`], so the
+//! keys stay directly under `[rules]`.
+//!
+//! [`RuleConfig`]: super::RuleConfig
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// How big a file may be.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct SizeLimits {
+    #[serde(default = "default_max_tokens")]
+    pub max_file_tokens: usize,
+    /// Files larger than this are skipped with a note instead of analyzed.
+    #[serde(default = "default_max_bytes")]
+    pub max_file_bytes: u64,
+    /// Functions one file may define.
+    #[serde(default = "default_max_functions")]
+    pub max_file_functions: usize,
+}
+
+impl Default for SizeLimits {
+    fn default() -> Self {
+        Self {
+            max_file_tokens: default_max_tokens(),
+            max_file_bytes: default_max_bytes(),
+            max_file_functions: default_max_functions(),
+        }
+    }
+}
+
+/// How complex a function may be.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct FunctionLimits {
+    #[serde(default = "default_max_complexity")]
+    pub max_cyclomatic_complexity: usize,
+    #[serde(default = "default_max_cognitive")]
+    pub max_cognitive_complexity: usize,
+    #[serde(default = "default_max_depth")]
+    pub max_nesting_depth: usize,
+    #[serde(default = "default_max_args")]
+    pub max_function_args: usize,
+    #[serde(default = "default_max_words")]
+    pub max_function_words: usize,
+    #[serde(default = "default_max_lines")]
+    pub max_function_lines: usize,
+}
+
+impl Default for FunctionLimits {
+    fn default() -> Self {
+        Self {
+            max_cyclomatic_complexity: default_max_complexity(),
+            max_cognitive_complexity: default_max_cognitive(),
+            max_nesting_depth: default_max_depth(),
+            max_function_args: default_max_args(),
+            max_function_words: default_max_words(),
+            max_function_lines: default_max_lines(),
+        }
+    }
+}
+
+/// The checks that look across files; both off by default.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CrossFileChecks {
+    #[serde(default)]
+    pub detect_dead_code: bool,
+    #[serde(default)]
+    pub detect_cycles: bool,
+}
+
+/// Paths exempt from single rules, as gitignore-style patterns.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ExemptPaths {
+    #[serde(default)]
+    pub ignore_naming_on: Vec<String>,
+    #[serde(default = "default_ignore_tokens")]
+    pub ignore_tokens_on: Vec<String>,
+    /// Paths where `todo!`, `unimplemented!` and `panic!` are allowed.
+    #[serde(default)]
+    pub ignore_panics_on: Vec<String>,
+}
+
+impl Default for ExemptPaths {
+    fn default() -> Self {
+        Self {
+            ignore_naming_on: Vec::new(),
+            ignore_tokens_on: default_ignore_tokens(),
+            ignore_panics_on: Vec::new(),
+        }
+    }
+}
+
+const fn default_max_tokens() -> usize {
+    2000
+}
+const fn default_max_bytes() -> u64 {
+    1_000_000
+}
+const fn default_max_functions() -> usize {
+    40
+}
+const fn default_max_complexity() -> usize {
+    8
+}
+const fn default_max_cognitive() -> usize {
+    15
+}
+const fn default_max_depth() -> usize {
+    3
+}
+const fn default_max_args() -> usize {
+    5
+}
+const fn default_max_words() -> usize {
+    5
+}
+const fn default_max_lines() -> usize {
+    100
+}
+pub(crate) fn default_ignore_tokens() -> Vec<String> {
+    vec!["README.md".into(), "*.lock".into(), "*-lock.*".into()]
+}
@@ -1,11 +1,42 @@
 // src/config/mod.rs
+pub mod clipboard;
+pub mod commands;
+pub mod defaults;
+pub mod env;
+pub mod extends;
+pub mod files;
 pub mod io;
+pub mod languages;
+pub mod limits;
+pub mod preferences;
+pub mod preset;
+pub mod rule_config;
+pub mod rules;
+pub mod schema;
+pub mod sections;
 pub mod types;
+pub mod validate;
+pub mod verify;
 
-pub use self::types::{
-    CommandEntry, Config, GitMode, Preferences, RuleConfig, SlopChopToml, Theme,
+pub use self::clipboard::{ClipboardBackend, ClipboardSettings, CopyMode};
+pub use self::commands::{CommandEntry, CommandStep};
+pub use self::defaults::CommandDefaults;
+pub use self::languages::{LangRules, LanguageRules};
+pub use self::limits::{CrossFileChecks, ExemptPaths, FunctionLimits, SizeLimits};
+pub use self::rule_config::RuleConfig;
+pub use self::rules::{CustomRule, DebtConfig, FailPolicy, RulesFile, TestRules};
+pub use self::sections::{
+    ApplySettings, GitSettings, Hooks, LineEndings, PackSettings, TokenSettings, TruncationSettings,
 };
-use crate::error::Result;
+pub use self::types::{CheckMode, Config, FileFilter, GitMode, Preferences, SlopChopToml, Theme};
+use self::validate::IssueKind;
+pub use self::verify::VerifyPipeline;
+use crate::error::{Result, SlopChopError};
+use crate::tokens::Tokenizer;
+use std::path::{Path, PathBuf};
+
+/// Directory of extra custom rule files, merged into `[[rules.custom]]`.
+pub const RULES_DIR: &str = "slopchop_rules";
 
 impl Config {
     #[must_use]
@@ -13,17 +44,23 @@ impl Config {
         Self::default()
     }
 
-    /// Validates configuration.
+    /// Checks the files in [`Config::sources`] for the syntax and type
+    /// errors that make loading ignore them, and warns about deprecated
+    /// options. Unknown keys are left to `slopchop config validate`.
     /// # Errors
-    /// Returns Ok.
+    /// Returns error listing each such problem with its file and line.
     pub fn validate(&self) -> Result<()> {
-        Ok(())
+        validate_sources(&self.sources)
     }
 
     pub fn load_local_config(&mut self) {
         io::load_ignore_file(self);
         io::load_toml_config(self);
+        io::load_rules_dir(self, Path::new(RULES_DIR));
         io::apply_project_defaults(self);
+        crate::migrate::legacy::warn_legacy(self);
+        Tokenizer::configure(self.tokens.model);
+        crate::clipboard::configure(self.clipboard.clone());
     }
 
     pub fn process_ignore_line(&mut self, line: &str) {
@@ -35,18 +72,48 @@ impl Config {
     }
 }
 
+/// Checks each of `paths` as [`Config::validate`] does.
+/// # Errors
+/// Returns error listing each syntax or type error with its file and line.
+pub fn validate_sources(paths: &[PathBuf]) -> Result<()> {
+    let mut errors = Vec::new();
+    for path in paths {
+        let Ok(content) = std::fs::read_to_string(path) else {
+            continue;
+        };
+        for issue in validate::check(&content) {
+            match issue.kind {
+                IssueKind::Syntax | IssueKind::Type => {
+                    errors.push(format!("{}: {issue}", path.display()));
+                }
+                IssueKind::Deprecated => eprintln!("WARN: {}: {issue}", path.display()),
+                IssueKind::UnknownKey => {}
+            }
+        }
+    }
+    if errors.is_empty() {
+        return Ok(());
+    }
+    Err(SlopChopError::Other(format!(
+        "Config is not valid:\n  {}",
+        errors.join("\n  ")
+    )))
+}
+
 pub use crate::constants::{
     BIN_EXT_PATTERN, CODE_BARE_PATTERN, CODE_EXT_PATTERN, PRUNE_DIRS, SECRET_PATTERN,
 };
 
-/// Saves the current configuration to `slopchop.toml`.
+/// Saves the current configuration to the config file, keeping the other
+/// sections as `saved` (from [`io::saved_sections`]) has them.
 /// # Errors
 /// Returns error if file write fails or serialization fails.
 #[allow(clippy::implicit_hasher)]
 pub fn save_to_file(
     rules: &RuleConfig,
     prefs: &Preferences,
-    commands: &std::collections::HashMap<String, Vec<String>>,
+    commands: &std::collections::HashMap<String, Vec<CommandStep>>,
+    saved: &SlopChopToml,
 ) -> Result<()> {
-    io::save_to_file(rules, prefs, commands)
+    io::save_to_file(rules, prefs, commands, saved)
 }
@@ -0,0 +1,114 @@
+// src/config/preferences.rs
+//! `[preferences]`: how `apply` and the TUI behave, as opposed to `[rules]`.
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema, Default)]
+pub enum Theme {
+    Nasa,
+    #[default]
+    Cyberpunk,
+    Corporate,
+}
+
+/// How a pre-write check treats what it finds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, JsonSchema, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum CheckMode {
+    /// Reject the payload.
+    #[default]
+    Deny,
+    /// Print a warning and apply anyway.
+    Warn,
+    /// Skip the check.
+    Off,
+}
+
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct Preferences {
+    #[serde(default)]
+    pub theme: Theme,
+    #[serde(default = "default_auto_copy")]
+    pub auto_copy: bool,
+    #[serde(default)]
+    pub auto_format: bool,
+    #[serde(default)]
+    pub auto_commit: bool,
+    #[serde(default = "default_commit_prefix")]
+    pub commit_prefix: String,
+    #[serde(default)]
+    pub allow_dirty_git: bool,
+    #[serde(default)]
+    pub system_bell: bool,
+    #[serde(default = "default_backup_retention")]
+    pub backup_retention: usize,
+    #[serde(default = "default_progress_bars")]
+    pub progress_bars: bool,
+    /// Apply files that changed since they were packed, with a warning.
+    #[serde(default)]
+    pub allow_stale_apply: bool,
+    /// What `apply` does with files that don't parse.
+    #[serde(default)]
+    pub syntax_check: CheckMode,
+    /// Formatter command per file extension, run on applied files when
+    /// `auto_format` is on.
+    #[serde(default = "default_formatters")]
+    pub formatters: BTreeMap<String, String>,
+    /// Seconds each verification command may run before it is killed;
+    /// 0 waits forever.
+    #[serde(default = "default_verify_timeout")]
+    pub verify_timeout: u64,
+}
+
+impl Default for Preferences {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            auto_copy: true,
+            auto_format: false,
+            auto_commit: false,
+            commit_prefix: default_commit_prefix(),
+            allow_dirty_git: false,
+            system_bell: false,
+            backup_retention: default_backup_retention(),
+            progress_bars: true,
+            allow_stale_apply: false,
+            syntax_check: CheckMode::default(),
+            formatters: default_formatters(),
+            verify_timeout: default_verify_timeout(),
+        }
+    }
+}
+
+fn default_auto_copy() -> bool {
+    true
+}
+fn default_progress_bars() -> bool {
+    true
+}
+fn default_backup_retention() -> usize {
+    5
+}
+fn default_commit_prefix() -> String {
+    "AI: ".to_string()
+}
+fn default_verify_timeout() -> u64 {
+    600
+}
+
+/// Formatters used when `auto_format` is on and none are configured.
+fn default_formatters() -> BTreeMap<String, String> {
+    [
+        ("rs", "rustfmt --edition 2021"),
+        ("py", "black -q"),
+        ("ts", "prettier --write"),
+        ("tsx", "prettier --write"),
+        ("js", "prettier --write"),
+        ("jsx", "prettier --write"),
+    ]
+    .into_iter()
+    .map(|(ext, cmd)| (ext.to_string(), cmd.to_string()))
+    .collect()
+}
@@ -0,0 +1,66 @@
+// src/config/preset.rs
+//! `[rules] preset`: a strictness level whose limits fill in the rule keys
+//! a config leaves out, and `slopchop config preset` to switch it.
+
+use toml::Table;
+use toml_edit::{value, DocumentMut};
+
+use crate::project::{self, Strictness};
+
+/// The limits that differ between presets. Switching presets removes
+/// them from `[rules]`, since explicit keys win over the preset.
+pub const PRESET_KEYS: [&str; 3] = [
+    "max_file_tokens",
+    "max_cyclomatic_complexity",
+    "max_nesting_depth",
+];
+
+/// The `[rules]` keys `strictness` sets.
+#[must_use]
+pub fn rules(strictness: Strictness) -> Table {
+    toml::from_str::<Table>(&project::rules_toml(strictness))
+        .ok()
+        .and_then(|mut t| t.remove("rules"))
+        .and_then(|rules| match rules {
+            toml::Value::Table(rules) => Some(rules),
+            _ => None,
+        })
+        .unwrap_or_default()
+}
+
+/// Fills in the keys `[rules] preset` sets wherever `table` leaves them
+/// out. An unknown preset is left for deserialization to reject.
+pub fn expand(table: &mut Table) {
+    let Some(toml::Value::Table(rules_table)) = table.get_mut("rules") else {
+        return;
+    };
+    let preset = rules_table.get("preset").and_then(toml::Value::as_str);
+    let Some(strictness) = preset.and_then(Strictness::from_name) else {
+        return;
+    };
+    for (key, limit) in rules(strictness) {
+        rules_table.entry(key).or_insert(limit);
+    }
+}
+
+/// `content` with `[rules] preset` set to `strictness` and the
+/// [`PRESET_KEYS`] it sets removed. Returns the new content and the keys
+/// removed.
+///
+/// # Errors
+/// Returns a message if `content` isn't valid TOML or `rules` isn't a table.
+pub fn set(content: &str, strictness: Strictness) -> Result<(String, Vec<String>), String> {
+    let mut doc: DocumentMut = content.parse().map_err(|e| format!("{e}"))?;
+    let rules_table = doc
+        .entry("rules")
+        .or_insert(toml_edit::table())
+        .as_table_like_mut()
+        .ok_or("`rules` is not a table")?;
+    let removed: Vec<String> = PRESET_KEYS
+        .into_iter()
+        .filter(|key| rules_table.remove(key).is_some())
+        .map(String::from)
+        .collect();
+    rules_table.insert("preset", value(strictness.name()));
+    Ok((doc.to_string(), removed))
+}
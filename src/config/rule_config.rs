@@ -0,0 +1,91 @@
+// src/config/rule_config.rs
+//! `[rules]`: the limits every scan checks.
+use super::languages::LanguageRules;
+use super::limits::{CrossFileChecks, ExemptPaths, FunctionLimits, SizeLimits};
+use super::rules::{CustomRule, DebtConfig, FailPolicy, TestRules};
+use crate::lang::Lang;
+use crate::project::Strictness;
+use crate::types::Severity;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct RuleConfig {
+    /// Fills in the limits this table leaves out.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub preset: Option<Strictness>,
+    #[serde(flatten)]
+    pub size: SizeLimits,
+    #[serde(flatten)]
+    pub functions: FunctionLimits,
+    #[serde(default = "default_max_fields")]
+    pub max_struct_fields: usize,
+    #[serde(default)]
+    pub max_unsafe_blocks: usize,
+    #[serde(flatten)]
+    pub cross_file: CrossFileChecks,
+    /// Extra banned calls per language key (`rs`, `py`, `ts`, `sh`).
+    #[serde(default)]
+    pub banned_calls: HashMap<String, Vec<String>>,
+    #[serde(flatten)]
+    pub exempt: ExemptPaths,
+    #[serde(default)]
+    pub custom: Vec<CustomRule>,
+    /// Per-language doc coverage (`rs = "warning"`), keyed like `banned_calls`.
+    #[serde(default)]
+    pub require_docs: HashMap<String, Severity>,
+    /// Severity overrides keyed by rule (`complexity`, `paranoia`, custom names).
+    #[serde(default)]
+    pub severity: HashMap<String, Severity>,
+    #[serde(default)]
+    pub debt: DebtConfig,
+    #[serde(default)]
+    pub tests: TestRules,
+    #[serde(default)]
+    pub fail: FailPolicy,
+    /// `[rules.rust]`, `[rules.python]`, ...: per-language limits.
+    #[serde(flatten)]
+    pub languages: LanguageRules,
+}
+
+impl Default for RuleConfig {
+    fn default() -> Self {
+        Self {
+            preset: None,
+            size: SizeLimits::default(),
+            functions: FunctionLimits::default(),
+            max_struct_fields: default_max_fields(),
+            max_unsafe_blocks: 0,
+            cross_file: CrossFileChecks::default(),
+            banned_calls: HashMap::new(),
+            exempt: ExemptPaths::default(),
+            custom: Vec::new(),
+            require_docs: HashMap::new(),
+            severity: HashMap::new(),
+            debt: DebtConfig::default(),
+            tests: TestRules::default(),
+            fail: FailPolicy::default(),
+            languages: LanguageRules::default(),
+        }
+    }
+}
+
+impl RuleConfig {
+    /// These rules with the `[rules.<language>]` overrides for `lang`
+    /// applied; borrowed when it has none.
+    #[must_use]
+    pub fn for_lang(&self, lang: Lang) -> Cow<'_, Self> {
+        let Some(overrides) = self.languages.get(lang) else {
+            return Cow::Borrowed(self);
+        };
+        let mut rules = self.clone();
+        overrides.apply_to(&mut rules, lang.config_key());
+        Cow::Owned(rules)
+    }
+}
+
+const fn default_max_fields() -> usize {
+    15
+}
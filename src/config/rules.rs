@@ -0,0 +1,121 @@
+// src/config/rules.rs
+//! Sub-sections of `[rules]` and custom rule definitions.
+use crate::types::{FailOn, ScanReport, Severity};
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A user-defined rule: a raw tree-sitter query plus the message to report.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct CustomRule {
+    pub name: String,
+    /// Language key (`rs`, `py`, `ts`, `sh`).
+    pub lang: String,
+    pub query: String,
+    pub message: String,
+    #[serde(default)]
+    pub severity: Severity,
+}
+
+/// A file in `slopchop_rules/` holding extra custom rules.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
+pub struct RulesFile {
+    #[serde(default)]
+    pub custom: Vec<CustomRule>,
+}
+
+/// `[rules.tests]`: the profile applied inside test code.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct TestRules {
+    /// Rule keys that don't apply to tests.
+    #[serde(default = "default_test_skip")]
+    pub skip: Vec<String>,
+    /// Severity overrides for tests, applied after `[rules.severity]`.
+    #[serde(default)]
+    pub severity: HashMap<String, Severity>,
+}
+
+impl Default for TestRules {
+    fn default() -> Self {
+        Self {
+            skip: default_test_skip(),
+            severity: HashMap::new(),
+        }
+    }
+}
+
+fn default_test_skip() -> Vec<String> {
+    vec!["paranoia".into()]
+}
+
+/// `[rules.debt]`: TODO/FIXME tracking.
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema)]
+pub struct DebtConfig {
+    #[serde(default)]
+    pub enabled: bool,
+    #[serde(default = "default_debt_markers")]
+    pub markers: Vec<String>,
+    /// Files with more markers than this fail the `debt` rule.
+    #[serde(default)]
+    pub max_per_file: Option<usize>,
+}
+
+impl Default for DebtConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            markers: default_debt_markers(),
+            max_per_file: None,
+        }
+    }
+}
+
+fn default_debt_markers() -> Vec<String> {
+    vec!["TODO".into(), "FIXME".into(), "HACK".into()]
+}
+
+/// `[rules.fail]`: when a scan counts as failed, so teams can ratchet
+/// down from an existing violation count instead of failing outright.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, JsonSchema)]
+pub struct FailPolicy {
+    /// Lowest failing severity; `--fail-on` overrides it.
+    #[serde(default)]
+    pub on: FailOn,
+    /// Failing violations tolerated before the run fails; `--max-violations`
+    /// overrides it.
+    #[serde(default)]
+    pub max_violations: usize,
+    /// Per-rule allowances keyed like `[rules.severity]` (`complexity = 12`).
+    /// A rule within its allowance doesn't count toward `max_violations`.
+    #[serde(default)]
+    pub per_law: HashMap<String, usize>,
+}
+
+impl FailPolicy {
+    /// Applies command-line overrides.
+    #[must_use]
+    pub fn with_flags(mut self, on: Option<FailOn>, max_violations: Option<usize>) -> Self {
+        self.on = on.unwrap_or(self.on);
+        self.max_violations = max_violations.unwrap_or(self.max_violations);
+        self
+    }
+
+    /// Whether `report` fails under this policy.
+    #[must_use]
+    pub fn fails(&self, report: &ScanReport) -> bool {
+        let Some(threshold) = self.on.threshold() else {
+            return false;
+        };
+        let mut counts: HashMap<String, usize> = HashMap::new();
+        let failing = report.files.iter().flat_map(|f| &f.violations);
+        for v in failing.filter(|v| v.severity >= threshold && !v.is_note()) {
+            *counts.entry(v.rule()).or_default() += 1;
+        }
+        let counted: usize = counts
+            .iter()
+            .filter(|(rule, n)| self.per_law.get(*rule).is_none_or(|max| *n > max))
+            .map(|(_, n)| n)
+            .sum();
+        counted > self.max_violations
+    }
+}
@@ -0,0 +1,45 @@
+// src/config/schema.rs
+//! JSON Schema for `warden.toml`, generated from the config types, for
+//! editor autocompletion (`slopchop config --schema`) and for
+//! `slopchop config validate`.
+
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
+use serde_json::{json, Value};
+
+use super::types::SlopChopToml;
+
+/// The schema of `warden.toml`. Sections reject keys they don't define,
+/// so editors flag typos as well as wrong types.
+#[must_use]
+pub fn json() -> Value {
+    let mut schema = serde_json::to_value(schemars::schema_for!(SlopChopToml)).unwrap_or_default();
+    close_objects(&mut schema);
+    schema
+}
+
+/// Sets `additionalProperties: false` on every object with fixed properties;
+/// maps such as `[commands]` keep their open value schema.
+fn close_objects(schema: &mut Value) {
+    match schema {
+        Value::Object(map) => {
+            if map.contains_key("properties") && !map.contains_key("additionalProperties") {
+                map.insert("additionalProperties".into(), Value::Bool(false));
+            }
+            map.values_mut().for_each(close_objects);
+        }
+        Value::Array(items) => items.iter_mut().for_each(close_objects),
+        _ => {}
+    }
+}
+
+/// `extends`: a path or preset name, or a list of them.
+pub(crate) fn extends(_: &mut SchemaGenerator) -> Schema {
+    let schema = json!({
+        "anyOf": [
+            { "type": "string" },
+            { "type": "array", "items": { "type": "string" } }
+        ]
+    });
+    serde_json::from_value(schema).unwrap_or(Schema::Bool(true))
+}
@@ -0,0 +1,198 @@
+// src/config/sections.rs
+//! Top-level `warden.toml` sections that drive `apply` and `pack`:
+//! `[apply]`, `[hooks]`, `[git]`, `[pack]` and `[tokens]`.
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::tokens::TokenModel;
+
+/// What `apply` accepts from a payload (`[apply]`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct ApplySettings {
+    /// Globs every touched file must match, e.g. `src/**`; empty allows all.
+    #[serde(default)]
+    pub allowed_paths: Vec<String>,
+    #[serde(default)]
+    pub truncation: TruncationSettings,
+    /// Line endings of written files; `preserve` keeps each file's own.
+    #[serde(default)]
+    pub line_endings: LineEndings,
+    /// Globs of files made executable when written, e.g. `scripts/*.sh`.
+    #[serde(default)]
+    pub executable: Vec<String>,
+}
+
+/// Line endings `apply` writes (`[apply] line_endings`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum LineEndings {
+    /// Match the file being replaced; new files keep what was sent.
+    #[default]
+    Preserve,
+    Lf,
+    Crlf,
+}
+
+/// Tuning for truncation detection (`[apply.truncation]`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TruncationSettings {
+    /// Extra markers to flag, e.g. `"// snip"`.
+    #[serde(default)]
+    pub add: Vec<String>,
+    /// Built-in markers to stop flagging.
+    #[serde(default)]
+    pub remove: Vec<String>,
+    /// Extensions (`md`, `py`, ...) that are never checked.
+    #[serde(default)]
+    pub skip_langs: Vec<String>,
+    /// Also reject a long file replaced by one under half its length.
+    #[serde(default)]
+    pub strict: bool,
+    /// Ask for `[lines:N]` in the manifest and reject files that don't
+    /// match it. Off by default: models often miscount, and a payload cut
+    /// off without a marker usually fails the syntax check anyway.
+    #[serde(default)]
+    pub line_counts: bool,
+}
+
+impl ApplySettings {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// Commands run at fixed points of `apply` (`[hooks]`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Hooks {
+    /// Run after a successful write, before verification, e.g. `cargo fmt`.
+    #[serde(default)]
+    pub post_apply: Vec<String>,
+}
+
+impl Hooks {
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.post_apply.is_empty()
+    }
+}
+
+/// What `apply` does in git after committing (`[git]`).
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct GitSettings {
+    /// Open a draft pull/merge request for the pushed commit.
+    #[serde(default)]
+    pub create_pr: bool,
+    /// Commit message template; see `apply::commit_message`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit_template: Option<String>,
+    /// Sign commits (`git commit -S`) with git's own signing setup.
+    #[serde(default)]
+    pub sign: bool,
+    /// Add a `Signed-off-by` trailer (`git commit --signoff`).
+    #[serde(default)]
+    pub signoff: bool,
+}
+
+impl GitSettings {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// How `pack` writes its output (`[pack]`).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PackSettings {
+    /// Split a bigger context into `context_partN.txt` files of at most
+    /// this many tokens each.
+    #[serde(default)]
+    pub chunk_tokens: Option<usize>,
+    /// Pack files bigger than this many tokens as skeletons, marked so the
+    /// AI knows to ask for the full file.
+    #[serde(default)]
+    pub skeleton_over: Option<usize>,
+    /// File whose text replaces the built-in prompt above the packed files.
+    #[serde(default)]
+    pub header_template: Option<String>,
+    /// Open the pack with a tree of every discovered file.
+    #[serde(default = "default_tree")]
+    pub tree: bool,
+    /// Globs of generated files, left out by `pack --no-generated`.
+    #[serde(default = "default_generated")]
+    pub generated: Vec<String>,
+    /// Replace secrets with `[REDACTED:<type>]` placeholders; `pack
+    /// --no-redact` turns it off for one run.
+    #[serde(default = "default_redact")]
+    pub redact: bool,
+}
+
+impl Default for PackSettings {
+    fn default() -> Self {
+        Self {
+            chunk_tokens: None,
+            skeleton_over: None,
+            header_template: None,
+            tree: default_tree(),
+            generated: default_generated(),
+            redact: default_redact(),
+        }
+    }
+}
+
+fn default_tree() -> bool {
+    true
+}
+
+fn default_redact() -> bool {
+    true
+}
+
+pub(crate) fn default_generated() -> Vec<String> {
+    [
+        "**/generated/**",
+        "**/*.generated.*",
+        "**/*_generated.*",
+        "**/*.pb.go",
+        "**/*_pb2.py",
+        "**/*.g.dart",
+        "**/*.min.js",
+        "**/*.min.css",
+        "**/*.lock",
+        "**/package-lock.json",
+        "**/pnpm-lock.yaml",
+    ]
+    .iter()
+    .map(|g| (*g).to_string())
+    .collect()
+}
+
+impl PackSettings {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// How tokens are counted and priced (`[tokens]`).
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TokenSettings {
+    /// Tokenizer for pack sizes, clipboard counts, and the atomicity law.
+    #[serde(default)]
+    pub model: TokenModel,
+    /// Context window pack warns against; defaults to the model's.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub window: Option<usize>,
+    /// USD per million input tokens, by model label (`[tokens.prices]`).
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub prices: BTreeMap<String, f64>,
+}
+
+impl TokenSettings {
+    #[must_use]
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
@@ -1,177 +1,96 @@
 // src/config/types.rs
+use super::clipboard::ClipboardSettings;
+use super::commands::{CommandEntry, CommandStep};
+use super::defaults::CommandDefaults;
+pub use super::preferences::{CheckMode, Preferences, Theme};
+use super::rule_config::RuleConfig;
+use super::sections::{ApplySettings, GitSettings, Hooks, PackSettings, TokenSettings};
+use super::verify::VerifyPipeline;
+use crate::ignore::IgnoreSet;
 use regex::Regex;
+use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
 
-#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Default)]
-pub enum Theme {
-    Nasa,
-    #[default]
-    Cyberpunk,
-    Corporate,
-}
-
-#[allow(clippy::struct_excessive_bools)]
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Preferences {
-    #[serde(default)]
-    pub theme: Theme,
-    #[serde(default = "default_auto_copy")]
-    pub auto_copy: bool,
-    #[serde(default)]
-    pub auto_format: bool,
-    #[serde(default)]
-    pub auto_commit: bool,
-    #[serde(default = "default_commit_prefix")]
-    pub commit_prefix: String,
-    #[serde(default)]
-    pub allow_dirty_git: bool,
-    #[serde(default)]
-    pub system_bell: bool,
-    #[serde(default = "default_backup_retention")]
-    pub backup_retention: usize,
-    #[serde(default = "default_progress_bars")]
-    pub progress_bars: bool,
-}
-
-impl Default for Preferences {
-    fn default() -> Self {
-        Self {
-            theme: Theme::default(),
-            auto_copy: true,
-            auto_format: false,
-            auto_commit: false,
-            commit_prefix: default_commit_prefix(),
-            allow_dirty_git: false,
-            system_bell: false,
-            backup_retention: default_backup_retention(),
-            progress_bars: true,
-        }
-    }
-}
-
-fn default_auto_copy() -> bool {
-    true
-}
-fn default_progress_bars() -> bool {
-    true
-}
-fn default_backup_retention() -> usize {
-    5
-}
-fn default_commit_prefix() -> String {
-    "AI: ".to_string()
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct RuleConfig {
-    #[serde(default = "default_max_tokens")]
-    pub max_file_tokens: usize,
-    #[serde(default = "default_max_complexity")]
-    pub max_cyclomatic_complexity: usize,
-    #[serde(default = "default_max_depth")]
-    pub max_nesting_depth: usize,
-    #[serde(default = "default_max_args")]
-    pub max_function_args: usize,
-    #[serde(default = "default_max_words")]
-    pub max_function_words: usize,
-    #[serde(default)]
-    pub ignore_naming_on: Vec<String>,
-    #[serde(default = "default_ignore_tokens")]
-    pub ignore_tokens_on: Vec<String>,
-}
-
-impl Default for RuleConfig {
-    fn default() -> Self {
-        Self {
-            max_file_tokens: default_max_tokens(),
-            max_cyclomatic_complexity: default_max_complexity(),
-            max_nesting_depth: default_max_depth(),
-            max_function_args: default_max_args(),
-            max_function_words: default_max_words(),
-            ignore_naming_on: Vec::new(),
-            ignore_tokens_on: default_ignore_tokens(),
-        }
-    }
-}
-
-const fn default_max_tokens() -> usize {
-    2000
-}
-const fn default_max_complexity() -> usize {
-    8
-}
-const fn default_max_depth() -> usize {
-    3
-}
-const fn default_max_args() -> usize {
-    5
-}
-const fn default_max_words() -> usize {
-    5
-}
-fn default_ignore_tokens() -> Vec<String> {
-    vec!["README.md".to_string(), "lock".to_string()]
-}
-
-/// Helper enum to deserialize commands as either a single string or a list of strings.
-#[derive(Debug, Clone, Serialize, Deserialize)]
-#[serde(untagged)]
-pub enum CommandEntry {
-    Single(String),
-    List(Vec<String>),
-}
-
-impl CommandEntry {
-    #[must_use]
-    pub fn into_vec(self) -> Vec<String> {
-        match self {
-            Self::Single(s) => vec![s],
-            Self::List(v) => v,
-        }
-    }
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+#[derive(Debug, Clone, Serialize, Deserialize, JsonSchema, Default)]
 pub struct SlopChopToml {
+    /// Files or `preset:<level>` names this config builds on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    #[schemars(schema_with = "super::schema::extends")]
+    pub extends: Option<toml::Value>,
     #[serde(default)]
     pub rules: RuleConfig,
     #[serde(default)]
     pub preferences: Preferences,
     #[serde(default)]
     pub commands: HashMap<String, CommandEntry>,
+    #[serde(default, skip_serializing_if = "ApplySettings::is_default")]
+    pub apply: ApplySettings,
+    #[serde(default, skip_serializing_if = "Hooks::is_empty")]
+    pub hooks: Hooks,
+    #[serde(default, skip_serializing_if = "GitSettings::is_default")]
+    pub git: GitSettings,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub verify: BTreeMap<String, VerifyPipeline>,
+    #[serde(default, skip_serializing_if = "PackSettings::is_default")]
+    pub pack: PackSettings,
+    #[serde(default, skip_serializing_if = "TokenSettings::is_default")]
+    pub tokens: TokenSettings,
+    #[serde(default, skip_serializing_if = "ClipboardSettings::is_default")]
+    pub clipboard: ClipboardSettings,
+    #[serde(default, skip_serializing_if = "CommandDefaults::is_default")]
+    pub defaults: CommandDefaults,
 }
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Default)]
 pub enum GitMode {
+    #[default]
     Auto,
     Yes,
     No,
 }
 
-#[derive(Debug, Clone)]
-pub struct Config {
+impl GitMode {
+    /// The mode for `--git-only` / `--no-git`; `--git-only` wins.
+    #[must_use]
+    pub fn from_flags(git_only: bool, no_git: bool) -> Self {
+        match (git_only, no_git) {
+            (true, _) => Self::Yes,
+            (_, true) => Self::No,
+            _ => Self::Auto,
+        }
+    }
+}
+
+/// Which files discovery picks up.
+#[derive(Debug, Clone, Default)]
+pub struct FileFilter {
     pub git_mode: GitMode,
     pub include_patterns: Vec<Regex>,
-    pub exclude_patterns: Vec<Regex>,
+    /// `.wardenignore`, with gitignore semantics.
+    pub exclude_patterns: IgnoreSet,
     pub code_only: bool,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Config {
+    pub files: FileFilter,
     pub verbose: bool,
     pub rules: RuleConfig,
     pub preferences: Preferences,
-    pub commands: HashMap<String, Vec<String>>,
-}
-
-impl Default for Config {
-    fn default() -> Self {
-        Self {
-            git_mode: GitMode::Auto,
-            include_patterns: Vec::new(),
-            exclude_patterns: Vec::new(),
-            code_only: false,
-            verbose: false,
-            rules: RuleConfig::default(),
-            preferences: Preferences::default(),
-            commands: HashMap::new(),
-        }
-    }
+    pub commands: HashMap<String, Vec<CommandStep>>,
+    pub apply: ApplySettings,
+    pub hooks: Hooks,
+    pub git: GitSettings,
+    /// `[verify.*]` pipelines; when empty, `commands.check` runs instead.
+    pub verify: BTreeMap<String, VerifyPipeline>,
+    pub pack: PackSettings,
+    pub tokens: TokenSettings,
+    pub clipboard: ClipboardSettings,
+    /// `[defaults.*]`: flags commands use when the command line omits them.
+    pub defaults: CommandDefaults,
+    /// The config files this was read from: `warden.toml`, the files it
+    /// extends, and package configs. See [`Config::validate`].
+    pub sources: Vec<PathBuf>,
 }
@@ -0,0 +1,134 @@
+// src/config/validate/document.rs
+//! A TOML document as JSON, to check against the schema, plus the line of
+//! every key so issues can point at it.
+
+use std::collections::HashMap;
+
+use serde_json::{Map, Value};
+use toml_edit::{ImDocument, Item, TableLike};
+
+/// A parsed document and the line of each key path (`rules.custom[0].name`).
+pub struct Document {
+    pub value: Value,
+    pub lines: HashMap<String, usize>,
+}
+
+impl Document {
+    /// Parses `content`.
+    ///
+    /// # Errors
+    /// Returns the line and message of a TOML syntax error.
+    pub fn parse(content: &str) -> Result<Self, (usize, String)> {
+        let doc = ImDocument::parse(content).map_err(|e| {
+            let line = e.span().map_or(1, |s| line_at(content, s.start));
+            (line, e.message().trim().to_string())
+        })?;
+        let mut reader = Reader {
+            content,
+            lines: HashMap::new(),
+        };
+        let value = reader.table(doc.as_table(), "");
+        Ok(Self {
+            value,
+            lines: reader.lines,
+        })
+    }
+
+    /// The line of `path`, or of its nearest recorded parent.
+    #[must_use]
+    pub fn line_of(&self, path: &str) -> usize {
+        let mut path = path;
+        loop {
+            if let Some(&line) = self.lines.get(path) {
+                return line;
+            }
+            match path.rfind(['.', '[']) {
+                Some(end) => path = &path[..end],
+                None => return 1,
+            }
+        }
+    }
+}
+
+/// Joins a key onto a dotted path.
+#[must_use]
+pub fn join(path: &str, key: &str) -> String {
+    if path.is_empty() {
+        key.to_string()
+    } else {
+        format!("{path}.{key}")
+    }
+}
+
+fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset.min(content.len())].matches('\n').count() + 1
+}
+
+struct Reader<'c> {
+    content: &'c str,
+    lines: HashMap<String, usize>,
+}
+
+impl Reader<'_> {
+    fn table(&mut self, table: &dyn TableLike, path: &str) -> Value {
+        let mut map = Map::new();
+        for (key, item) in table.iter() {
+            let child = join(path, key);
+            let span = table.get_key_value(key).and_then(|(k, _)| k.span());
+            self.mark(&child, span.or_else(|| item.span()));
+            map.insert(key.to_string(), self.item(item, &child));
+        }
+        Value::Object(map)
+    }
+
+    fn item(&mut self, item: &Item, path: &str) -> Value {
+        match item {
+            Item::Value(value) => self.value(value, path),
+            Item::Table(table) => self.table(table, path),
+            Item::ArrayOfTables(tables) => {
+                let mut items = Vec::new();
+                for (i, table) in tables.iter().enumerate() {
+                    let child = format!("{path}[{i}]");
+                    self.mark(&child, table.span());
+                    items.push(self.table(table, &child));
+                }
+                Value::Array(items)
+            }
+            Item::None => Value::Null,
+        }
+    }
+
+    fn value(&mut self, value: &toml_edit::Value, path: &str) -> Value {
+        match value {
+            toml_edit::Value::InlineTable(table) => self.table(table, path),
+            toml_edit::Value::Array(array) => {
+                let mut items = Vec::new();
+                for (i, item) in array.iter().enumerate() {
+                    let child = format!("{path}[{i}]");
+                    self.mark(&child, item.span());
+                    items.push(self.value(item, &child));
+                }
+                Value::Array(items)
+            }
+            scalar => scalar_value(scalar),
+        }
+    }
+
+    fn mark(&mut self, path: &str, span: Option<std::ops::Range<usize>>) {
+        if let Some(span) = span {
+            let line = line_at(self.content, span.start);
+            self.lines.insert(path.to_string(), line);
+        }
+    }
+}
+
+fn scalar_value(value: &toml_edit::Value) -> Value {
+    match value {
+        toml_edit::Value::String(s) => Value::String(s.value().clone()),
+        toml_edit::Value::Integer(i) => Value::from(*i.value()),
+        toml_edit::Value::Float(f) => Value::from(*f.value()),
+        toml_edit::Value::Boolean(b) => Value::Bool(*b.value()),
+        toml_edit::Value::Datetime(d) => Value::String(d.value().to_string()),
+        toml_edit::Value::Array(_) | toml_edit::Value::InlineTable(_) => Value::Null,
+    }
+}
@@ -0,0 +1,118 @@
+// src/config/validate/expect.rs
+//! Matching a JSON value against one schema's `type`, `enum` and
+//! `minimum`, and describing what it expected.
+
+use serde_json::{Map, Value};
+
+/// Why `value` doesn't fit `schema`'s own type, allowed values, or minimum.
+#[must_use]
+pub fn mismatch(value: &Value, schema: &Value) -> Option<String> {
+    if !types_match(value, schema) {
+        return Some(format!(
+            "expected {}, found {}",
+            expected(schema),
+            kind(value)
+        ));
+    }
+    if let Some(choices) = schema.get("enum").and_then(Value::as_array) {
+        if !choices.contains(value) {
+            return Some(format!("expected {}, found {value}", expected(schema)));
+        }
+    }
+    let min = schema.get("minimum").and_then(Value::as_f64)?;
+    (value.as_f64()? < min).then(|| format!("must be at least {min}, found {value}"))
+}
+
+/// True if `schema` names no type or one `value` has.
+#[must_use]
+pub fn types_match(value: &Value, schema: &Value) -> bool {
+    match schema.get("type") {
+        Some(Value::String(name)) => is_type(value, name),
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .any(|name| is_type(value, name)),
+        _ => true,
+    }
+}
+
+fn is_type(value: &Value, name: &str) -> bool {
+    match name {
+        "integer" => value.is_i64(),
+        "number" => value.is_number(),
+        "string" => value.is_string(),
+        "boolean" => value.is_boolean(),
+        "array" => value.is_array(),
+        "object" => value.is_object(),
+        _ => false,
+    }
+}
+
+/// What `schema` accepts: its allowed values, or its type.
+#[must_use]
+pub fn expected(schema: &Value) -> String {
+    if let Some(choices) = schema.get("enum").and_then(Value::as_array) {
+        let choices: Vec<String> = choices.iter().map(Value::to_string).collect();
+        return choices.join(" or ");
+    }
+    match schema.get("type") {
+        Some(Value::String(name)) => with_article(name),
+        Some(Value::Array(names)) => names
+            .iter()
+            .filter_map(Value::as_str)
+            .filter(|name| *name != "null")
+            .map(with_article)
+            .collect::<Vec<_>>()
+            .join(" or "),
+        _ => "a value".to_string(),
+    }
+}
+
+fn with_article(name: &str) -> String {
+    match name {
+        "object" => "a table".to_string(),
+        "integer" | "array" => format!("an {name}"),
+        _ => format!("a {name}"),
+    }
+}
+
+/// The TOML name of `value`'s type.
+#[must_use]
+pub fn kind(value: &Value) -> &'static str {
+    match value {
+        Value::Object(_) => "a table",
+        Value::Array(_) => "an array",
+        Value::String(_) => "a string",
+        Value::Bool(_) => "a boolean",
+        Value::Number(n) if n.is_f64() => "a float",
+        Value::Number(_) => "an integer",
+        Value::Null => "nothing",
+    }
+}
+
+/// The known key within two edits of `key`, if any.
+#[must_use]
+pub fn closest_key<'m>(key: &str, known: &'m Map<String, Value>) -> Option<&'m str> {
+    known
+        .keys()
+        .map(|k| (distance(key, k), k))
+        .filter(|(d, _)| *d <= 2)
+        .min_by_key(|(d, _)| *d)
+        .map(|(_, k)| k.as_str())
+}
+
+fn distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut row: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let cost = usize::from(ca != *cb);
+            let next = (row[j + 1] + 1).min(row[j] + 1).min(diagonal + cost);
+            diagonal = row[j + 1];
+            row[j + 1] = next;
+        }
+    }
+    row[b.len()]
+}
@@ -0,0 +1,217 @@
+// src/config/validate/mod.rs
+//! Checks a config file against [`schema::json`], reporting every unknown
+//! key, wrong type, and deprecated option with its line. Loading stops at
+//! the first type error and drops the whole file, so this is the way to
+//! see all of them.
+
+pub mod document;
+pub mod expect;
+
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use self::document::{join, Document};
+use self::expect::{closest_key, expected, kind, mismatch, types_match};
+use super::schema;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IssueKind {
+    /// The file isn't valid TOML.
+    Syntax,
+    UnknownKey,
+    /// A value of the wrong type, or outside its allowed values.
+    Type,
+    Deprecated,
+}
+
+/// One problem found in a config file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Issue {
+    pub kind: IssueKind,
+    /// Dotted path to the key (`rules.max_file_tokens`); empty for syntax
+    /// errors.
+    pub key: String,
+    pub line: usize,
+    pub message: String,
+}
+
+impl Issue {
+    /// Deprecated options still load; everything else is an error.
+    #[must_use]
+    pub fn is_error(&self) -> bool {
+        self.kind != IssueKind::Deprecated
+    }
+}
+
+impl fmt::Display for Issue {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.key.is_empty() {
+            write!(f, "line {}: {}", self.line, self.message)
+        } else {
+            write!(f, "line {}: `{}` {}", self.line, self.key, self.message)
+        }
+    }
+}
+
+/// Checks `content` against the `warden.toml` schema.
+#[must_use]
+pub fn check(content: &str) -> Vec<Issue> {
+    check_with(&schema::json(), content)
+}
+
+/// Checks `content` against `schema`.
+#[must_use]
+pub fn check_with(schema: &Value, content: &str) -> Vec<Issue> {
+    let doc = match Document::parse(content) {
+        Ok(doc) => doc,
+        Err((line, message)) => {
+            return vec![Issue {
+                kind: IssueKind::Syntax,
+                key: String::new(),
+                line,
+                message,
+            }]
+        }
+    };
+    let mut checker = Checker {
+        root: schema,
+        doc: &doc,
+        issues: Vec::new(),
+    };
+    checker.check(&doc.value, schema, "");
+    checker.issues.sort_by_key(|i| i.line);
+    checker.issues
+}
+
+struct Checker<'a> {
+    root: &'a Value,
+    doc: &'a Document,
+    issues: Vec<Issue>,
+}
+
+impl<'a> Checker<'a> {
+    fn check(&mut self, value: &Value, schema: &'a Value, path: &str) {
+        self.check_deprecated(schema, path);
+        let schema = self.resolve(schema);
+        let all = schema.get("allOf").and_then(Value::as_array);
+        for s in all.into_iter().flatten() {
+            self.check(value, s, path);
+        }
+        let any = schema.get("anyOf").or_else(|| schema.get("oneOf"));
+        if let Some(branches) = any.and_then(Value::as_array) {
+            return self.check_any(value, branches, path);
+        }
+        if let Some(problem) = mismatch(value, schema) {
+            return self.push(IssueKind::Type, path, problem);
+        }
+        match (value, schema.get("items")) {
+            (Value::Object(map), _) => self.check_table(map, schema, path),
+            (Value::Array(items), Some(each)) => {
+                for (i, item) in items.iter().enumerate() {
+                    self.check(item, each, &format!("{path}[{i}]"));
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn check_deprecated(&mut self, schema: &Value, path: &str) {
+        if schema.get("deprecated") != Some(&Value::Bool(true)) {
+            return;
+        }
+        let note = schema.get("description").and_then(Value::as_str);
+        let message = note.map_or("is deprecated".into(), |n| format!("is deprecated: {n}"));
+        self.push(IssueKind::Deprecated, path, message);
+    }
+
+    /// Passes if any branch does. Otherwise a table or array is checked
+    /// against the first branch of its type, and a scalar is reported with
+    /// everything the branches accept.
+    fn check_any(&mut self, value: &Value, branches: &'a [Value], path: &str) {
+        let mut closest = None;
+        for branch in branches {
+            let issues = self.check_branch(value, branch, path);
+            if !issues.iter().any(Issue::is_error) {
+                self.issues.extend(issues);
+                return;
+            }
+            if closest.is_none() && self.nests_in(value, branch) {
+                closest = Some(issues);
+            }
+        }
+        match closest {
+            Some(issues) => self.issues.extend(issues),
+            None => self.push_expected(value, branches, path),
+        }
+    }
+
+    fn check_branch(&self, value: &Value, branch: &'a Value, path: &str) -> Vec<Issue> {
+        let mut sub = Checker {
+            issues: Vec::new(),
+            ..*self
+        };
+        sub.check(value, branch, path);
+        sub.issues
+    }
+
+    /// True if `value` is a table or array and `branch` takes its type.
+    fn nests_in(&self, value: &Value, branch: &'a Value) -> bool {
+        (value.is_object() || value.is_array()) && types_match(value, self.resolve(branch))
+    }
+
+    fn push_expected(&mut self, value: &Value, branches: &'a [Value], path: &str) {
+        let branches: Vec<&Value> = branches.iter().map(|b| self.resolve(b)).collect();
+        let accepted: Vec<String> = branches.iter().map(|b| expected(b)).collect();
+        let found = if branches.iter().any(|b| types_match(value, b)) {
+            value.to_string()
+        } else {
+            kind(value).to_string()
+        };
+        let message = format!("expected {}, found {found}", accepted.join(" or "));
+        self.push(IssueKind::Type, path, message);
+    }
+
+    fn check_table(&mut self, map: &Map<String, Value>, schema: &'a Value, path: &str) {
+        let known = schema.get("properties").and_then(Value::as_object);
+        for (key, value) in map {
+            let child = join(path, key);
+            if let Some(prop) = known.and_then(|k| k.get(key)) {
+                self.check(value, prop, &child);
+                continue;
+            }
+            match schema.get("additionalProperties") {
+                Some(Value::Bool(false)) => self.push_unknown(key, known, &child),
+                Some(open @ Value::Object(_)) => self.check(value, open, &child),
+                _ => {}
+            }
+        }
+    }
+
+    fn push_unknown(&mut self, key: &str, known: Option<&Map<String, Value>>, path: &str) {
+        let message = match known.and_then(|k| closest_key(key, k)) {
+            Some(near) => format!("is not a known key (did you mean `{near}`?)"),
+            None => "is not a known key".to_string(),
+        };
+        self.push(IssueKind::UnknownKey, path, message);
+    }
+
+    /// Follows `$ref`s into the schema's definitions.
+    fn resolve(&self, schema: &'a Value) -> &'a Value {
+        let name = schema.get("$ref").and_then(Value::as_str);
+        let name = name.and_then(|r| r.strip_prefix("#/definitions/"));
+        match name.and_then(|n| self.root.get("definitions")?.get(n)) {
+            Some(target) => self.resolve(target),
+            None => schema,
+        }
+    }
+
+    fn push(&mut self, kind: IssueKind, path: &str, message: String) {
+        self.issues.push(Issue {
+            kind,
+            key: path.to_string(),
+            line: self.doc.line_of(path),
+            message,
+        });
+    }
+}
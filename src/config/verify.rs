@@ -0,0 +1,74 @@
+// src/config/verify.rs
+//! `[verify.<name>]` pipelines: which checks an apply runs, by the
+//! languages it touched.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+/// A verification pipeline (`[verify.<name>]`), run when an apply touches a
+/// file with one of its extensions.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct VerifyPipeline {
+    /// Extensions that trigger the pipeline; defaults by name for `rust`,
+    /// `node`, `python` and `go`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub extensions: Vec<String>,
+    #[serde(default)]
+    pub commands: Vec<String>,
+    /// Directory the commands run in, relative to the project root.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cwd: Option<PathBuf>,
+    /// Narrow `cargo`/`npm` commands to the touched crates or packages.
+    #[serde(default)]
+    pub scoped: bool,
+}
+
+impl VerifyPipeline {
+    /// True if pipeline `name` runs for a file with extension `ext`.
+    #[must_use]
+    pub fn matches(&self, name: &str, ext: &str) -> bool {
+        if self.extensions.is_empty() {
+            default_extensions(name).contains(&ext)
+        } else {
+            self.extensions.iter().any(|e| e == ext)
+        }
+    }
+
+    /// True if pipeline `name` runs for a change to `path`: a file with one
+    /// of its extensions, or the manifest of such files, like `Cargo.toml`.
+    #[must_use]
+    pub fn matches_path(&self, name: &str, path: &Path) -> bool {
+        let ext = path.extension().and_then(|e| e.to_str());
+        let file = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .unwrap_or_default();
+        ext.into_iter()
+            .chain(manifest_extensions(file).iter().copied())
+            .any(|e| self.matches(name, e))
+    }
+}
+
+/// The source extensions whose build a manifest or lockfile describes.
+fn manifest_extensions(file: &str) -> &'static [&'static str] {
+    match file {
+        "Cargo.toml" | "Cargo.lock" => default_extensions("rust"),
+        "package.json" | "package-lock.json" | "pnpm-lock.yaml" | "yarn.lock" | "tsconfig.json" => {
+            default_extensions("node")
+        }
+        "pyproject.toml" | "requirements.txt" | "poetry.lock" => default_extensions("python"),
+        "go.mod" | "go.sum" => default_extensions("go"),
+        _ => &[],
+    }
+}
+
+fn default_extensions(name: &str) -> &'static [&'static str] {
+    match name {
+        "rust" => &["rs"],
+        "node" => &["ts", "tsx", "js", "jsx", "mjs", "cjs"],
+        "python" => &["py"],
+        "go" => &["go"],
+        _ => &[],
+    }
+}
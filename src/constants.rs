@@ -18,6 +18,8 @@ pub const PRUNE_DIRS: &[&str] = &[
     "coverage",
     "vendor",
     ".slopchop_apply_backup",
+    ".slopchop_cache",
+    ".slopchop_copies",
 ];
 
 pub const PRUNE_FILES: &[&str] = &[
@@ -28,9 +30,11 @@ pub const PRUNE_FILES: &[&str] = &[
     "bun.lockb",
     "go.sum",
     "Gemfile.lock",
+    ".slopchop_history.jsonl",
+    ".slopchop_apply_history.jsonl",
 ];
 
-pub const SKIP_DIRS: &[&str] = &["tests", "test", "spec", "docs", "examples", "fixtures"];
+pub const SKIP_DIRS: &[&str] = &["docs", "examples", "fixtures"];
 
 pub const BIN_EXT_PATTERN: &str =
     r"(?i)\.(png|jpg|gif|svg|ico|webp|woff2?|ttf|pdf|mp4|zip|gz|tar|exe|dll|so|dylib|class|pyc)$";
@@ -38,7 +42,25 @@ pub const BIN_EXT_PATTERN: &str =
 pub const SECRET_PATTERN: &str =
     r"(?i)(^\.?env(\..*)?$|/\.?env(\..*)?$|(^|/)(id_rsa|id_ed25519|.*\.(pem|p12|key|pfx))$)";
 
-pub const CODE_EXT_PATTERN: &str = r"(?i)\.(rs|go|py|js|jsx|ts|tsx|java|c|cpp|h|hpp|cs|php|rb|sh|sql|html|css|scss|json|toml|yaml|md)$";
+/// Secrets inside packed files, by type. [`SECRET_PATTERN`] keeps secret
+/// files out of the pack; these catch what is left in the files that are.
+pub const SECRET_CONTENT_PATTERNS: &[(&str, &str)] = &[
+    (
+        "private-key",
+        r"-----BEGIN [A-Z ]*PRIVATE KEY-----[\s\S]*?-----END [A-Z ]*PRIVATE KEY-----",
+    ),
+    ("aws-key", r"\b(?:AKIA|ASIA)[0-9A-Z]{16}\b"),
+    ("github-token", r"\bgh[pousr]_[A-Za-z0-9]{36,}\b"),
+    ("slack-token", r"\bxox[abprs]-[A-Za-z0-9-]{10,}"),
+    ("google-key", r"\bAIza[0-9A-Za-z_-]{35}\b"),
+    ("api-key", r"\bsk-(?:ant-|proj-)?[A-Za-z0-9_-]{20,}"),
+    (
+        "connection-string",
+        r#"\b[a-zA-Z][a-zA-Z0-9+.-]*://[^\s:/@'"]+:[^\s@'"]+@[^\s'"]+"#,
+    ),
+];
+
+pub const CODE_EXT_PATTERN: &str = r"(?i)\.(rs|go|py|js|jsx|ts|tsx|vue|svelte|java|c|cpp|h|hpp|cs|php|rb|sh|zig|sql|html|css|scss|json|toml|yaml|md)$";
 
 pub const CODE_BARE_PATTERN: &str = r"(?i)(Makefile|Dockerfile|CMakeLists\.txt)$";
 
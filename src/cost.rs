@@ -0,0 +1,44 @@
+// src/cost.rs
+//! Input-cost estimates for a token count, from the `[tokens.prices]` table
+//! of USD per million input tokens, shown after `pack` and `check`.
+
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+
+use colored::Colorize;
+
+/// Dollars `tokens` input tokens cost at `per_million` dollars per million.
+#[must_use]
+#[allow(clippy::cast_precision_loss)]
+pub fn estimate(tokens: usize, per_million: f64) -> f64 {
+    tokens as f64 * per_million / 1_000_000.0
+}
+
+/// One line per priced model, e.g. `~$0.43 at input pricing for gpt-4o`.
+#[must_use]
+pub fn render(tokens: usize, prices: &BTreeMap<String, f64>) -> Vec<String> {
+    prices
+        .iter()
+        .map(|(model, &price)| {
+            let cost = estimate(tokens, price);
+            let cost = if cost > 0.0 && cost < 0.01 {
+                "<$0.01".to_string()
+            } else {
+                format!("~${cost:.2}")
+            };
+            format!("{cost} at input pricing for {model}")
+        })
+        .collect()
+}
+
+/// The estimates as dimmed lines, each after a newline; empty when no
+/// prices are configured.
+#[must_use]
+pub fn summary(tokens: usize, prices: &BTreeMap<String, f64>) -> String {
+    render(tokens, prices)
+        .into_iter()
+        .fold(String::new(), |mut out, line| {
+            let _ = write!(out, "\n{}", format!("💵 {line}").dimmed());
+            out
+        })
+}
@@ -0,0 +1,97 @@
+// src/daemon/auth.rs
+//! What keeps other local users and stale clients out of a daemon: a
+//! random token in an owner-only file, and fingerprints of the version and
+//! rules a scan is meant for.
+
+use crate::analysis::cache::fnv1a;
+use crate::config::RuleConfig;
+use crate::tokens::Tokenizer;
+use anyhow::{anyhow, Result};
+use std::fmt::Write as _;
+use std::fs;
+use std::io::{Read, Write};
+use std::net::SocketAddr;
+use std::path::Path;
+
+/// The version a daemon and its clients must share.
+pub const VERSION: &str = env!("CARGO_PKG_VERSION");
+
+/// Where to reach a daemon and the token it wants.
+#[derive(Debug, Clone)]
+pub struct Handle {
+    pub addr: SocketAddr,
+    pub token: String,
+}
+
+impl Handle {
+    /// Reads the address file: the address, then the token on its own line.
+    #[must_use]
+    pub fn read(path: &Path) -> Option<Self> {
+        let content = fs::read_to_string(path).ok()?;
+        let mut lines = content.lines();
+        let addr = lines.next()?.trim().parse().ok()?;
+        let token = lines.next()?.trim().to_string();
+        Some(Self { addr, token })
+    }
+
+    /// Writes the address file readable by its owner only, replacing any
+    /// left by an earlier daemon.
+    ///
+    /// # Errors
+    /// Returns error if the file cannot be written.
+    pub fn write(&self, path: &Path) -> Result<()> {
+        let _ = fs::remove_file(path);
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create_new(true);
+        #[cfg(unix)]
+        std::os::unix::fs::OpenOptionsExt::mode(&mut options, 0o600);
+        let mut file = options
+            .open(path)
+            .map_err(|e| anyhow!("Cannot write {}: {e}", path.display()))?;
+        writeln!(file, "{}\n{}", self.addr, self.token)?;
+        Ok(())
+    }
+}
+
+/// 128 random bits as hex, from the OS where it offers a device for them,
+/// else from the randomly keyed std hasher.
+#[must_use]
+pub fn new_token() -> String {
+    let mut bytes = [0u8; 16];
+    let from_os = fs::File::open("/dev/urandom").and_then(|mut f| f.read_exact(&mut bytes));
+    if from_os.is_err() {
+        bytes = keyed_bytes();
+    }
+    bytes.iter().fold(String::new(), |mut out, b| {
+        let _ = write!(out, "{b:02x}");
+        out
+    })
+}
+
+fn keyed_bytes() -> [u8; 16] {
+    use std::hash::{BuildHasher, Hasher};
+    let mut bytes = [0u8; 16];
+    for chunk in bytes.chunks_mut(8) {
+        let mut hasher = std::collections::hash_map::RandomState::new().build_hasher();
+        hasher.write_u32(std::process::id());
+        hasher.write_u128(
+            std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_or(0, |d| d.as_nanos()),
+        );
+        chunk.copy_from_slice(&hasher.finish().to_le_bytes());
+    }
+    bytes
+}
+
+/// Stands for `rules` (and the token model) in a scan request, so a daemon
+/// started under other rules is never asked to scan for them.
+#[must_use]
+pub fn fingerprint(rules: &RuleConfig) -> u64 {
+    let json = serde_json::to_value(rules).unwrap_or_default().to_string();
+    fnv1a(&[
+        VERSION.as_bytes(),
+        Tokenizer::model().name().as_bytes(),
+        json.as_bytes(),
+    ])
+}
@@ -0,0 +1,74 @@
+// src/daemon/client.rs
+//! Client side of the daemon protocol.
+
+use super::auth::{self, Handle, VERSION};
+use super::{send, Envelope, Request, Response, ADDR_FILE};
+use crate::analysis::cache::CACHE_DIR;
+use crate::config::Config;
+use crate::types::ScanReport;
+use anyhow::{anyhow, Result};
+use std::io::{BufRead, BufReader};
+use std::net::TcpStream;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+const CONNECT_TIMEOUT: Duration = Duration::from_millis(200);
+/// How long to wait for an answer; a full scan of a large tree is slow.
+const RESPONSE_TIMEOUT: Duration = Duration::from_mins(5);
+
+/// The daemon serving the current directory, if one was started.
+#[must_use]
+pub fn running() -> Option<Handle> {
+    Handle::read(&Path::new(CACHE_DIR).join(ADDR_FILE))
+}
+
+/// Sends one request and waits for the answer.
+///
+/// # Errors
+/// Returns error if the daemon is unreachable or answers garbage.
+pub fn request(handle: &Handle, request: Request) -> Result<Response> {
+    let stream = TcpStream::connect_timeout(&handle.addr, CONNECT_TIMEOUT)?;
+    stream.set_read_timeout(Some(RESPONSE_TIMEOUT))?;
+    let envelope = Envelope {
+        token: handle.token.clone(),
+        version: VERSION.to_string(),
+        request,
+    };
+    send(&stream, &envelope)?;
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)?;
+    Ok(serde_json::from_str(&line)?)
+}
+
+/// Scans through the running daemon. `None` means there is no usable daemon
+/// (none running, or one with another version or other rules than
+/// `config`) and the caller should scan in-process.
+#[must_use]
+pub fn scan(config: &Config, files: Option<&[PathBuf]>, cross_file: bool) -> Option<ScanReport> {
+    let handle = running()?;
+    let request = Request::Scan {
+        files: files.map(<[PathBuf]>::to_vec),
+        cross_file,
+        rules: auth::fingerprint(&config.rules),
+    };
+    match self::request(&handle, request) {
+        Ok(Response::Report { report }) => Some(report),
+        Ok(Response::Error { message } | Response::Mismatch { message }) => {
+            eprintln!("WARN: Daemon scan skipped, scanning locally: {message}");
+            None
+        }
+        _ => None,
+    }
+}
+
+/// Asks the running daemon to exit.
+///
+/// # Errors
+/// Returns error if no daemon is running or it cannot be reached.
+pub fn stop() -> Result<()> {
+    let handle = running().ok_or_else(|| anyhow!("No daemon is running here"))?;
+    match request(&handle, Request::Shutdown)? {
+        Response::Error { message } => Err(anyhow!("{message}")),
+        _ => Ok(()),
+    }
+}
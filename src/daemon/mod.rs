@@ -0,0 +1,269 @@
+// src/daemon/mod.rs
+//! `slopchop daemon`: a long-lived process that keeps the parsers, the rule
+//! engines and the scan cache warm, so small incremental checks skip the
+//! cold start. Each client is answered on its own thread.
+//!
+//! The protocol is one JSON [`Envelope`] per line over a localhost TCP
+//! connection, answered by one JSON [`Response`] line. The daemon writes its
+//! address and a random token to `.slopchop_cache/daemon.addr`, readable by
+//! its owner only, and ignores requests without that token. Clients fall
+//! back to scanning in-process when the daemon is missing, unreachable, or
+//! runs another version or other rules.
+
+pub mod auth;
+pub mod client;
+mod warm;
+
+use self::auth::{Handle, VERSION};
+use self::warm::Engines;
+use crate::analysis::cache::CACHE_DIR;
+use crate::analysis::RuleEngine;
+use crate::config::Config;
+use crate::discovery;
+use crate::types::ScanReport;
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, PoisonError};
+use std::thread;
+use std::time::Duration;
+
+const ADDR_FILE: &str = "daemon.addr";
+/// How long a client may take to send its request, so an idle connection
+/// can't hold up the others.
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(2);
+/// The longest request line read.
+const MAX_REQUEST: u64 = 1 << 20;
+
+/// A request with what the daemon checks before acting on it.
+#[derive(Debug, Serialize, Deserialize)]
+pub struct Envelope {
+    /// The token from the address file.
+    pub token: String,
+    /// The client's slopchop version.
+    pub version: String,
+    #[serde(flatten)]
+    pub request: Request,
+}
+
+/// A client request.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "cmd", rename_all = "snake_case")]
+pub enum Request {
+    Ping,
+    /// Scans `files`, or every discovered file when omitted.
+    Scan {
+        #[serde(default)]
+        files: Option<Vec<PathBuf>>,
+        /// Run dead-code and cycle detection (meaningless on partial sets).
+        #[serde(default = "cross_file_default")]
+        cross_file: bool,
+        /// [`auth::fingerprint`] of the client's rules.
+        rules: u64,
+    },
+    Shutdown,
+}
+
+fn cross_file_default() -> bool {
+    true
+}
+
+/// The daemon's answer to a [`Request`]. Externally tagged, since internally
+/// tagged enums cannot carry the report's `u128` duration.
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Response {
+    Ok,
+    Report {
+        report: ScanReport,
+    },
+    /// The daemon runs another version or other rules than the client; the
+    /// client should scan in-process.
+    Mismatch {
+        message: String,
+    },
+    Error {
+        message: String,
+    },
+}
+
+/// Server state kept warm between requests.
+pub struct Daemon {
+    cache_dir: PathBuf,
+    token: String,
+    engines: Mutex<Engines>,
+    stop: AtomicBool,
+}
+
+impl Daemon {
+    /// A daemon answering only requests that carry `token`.
+    #[must_use]
+    pub fn new(cache_dir: &Path, token: &str) -> Self {
+        Self {
+            cache_dir: cache_dir.to_path_buf(),
+            token: token.to_string(),
+            engines: Mutex::new(Engines::default()),
+            stop: AtomicBool::new(false),
+        }
+    }
+
+    /// Answers connections on `listener`, each on its own thread, until a
+    /// shutdown request arrives. Requests still running are answered first.
+    ///
+    /// # Errors
+    /// Returns error if accepting a connection fails.
+    pub fn serve(&self, listener: &TcpListener) -> Result<()> {
+        let addr = listener.local_addr()?;
+        thread::scope(|scope| {
+            for stream in listener.incoming() {
+                if self.stop.load(Ordering::SeqCst) {
+                    break;
+                }
+                let stream = stream?;
+                scope.spawn(move || self.serve_client(&stream, addr));
+            }
+            Ok(())
+        })
+    }
+
+    fn serve_client(&self, stream: &TcpStream, addr: SocketAddr) {
+        if !self.handle(stream) {
+            self.stop.store(true, Ordering::SeqCst);
+            // Wake the accept loop so it sees the flag.
+            let _ = TcpStream::connect(addr);
+        }
+    }
+
+    /// Returns false once the daemon should stop.
+    fn handle(&self, stream: &TcpStream) -> bool {
+        let mut line = String::new();
+        let read = stream
+            .set_read_timeout(Some(REQUEST_TIMEOUT))
+            .and_then(|()| stream.set_write_timeout(Some(REQUEST_TIMEOUT)))
+            .and_then(|()| BufReader::new(stream.take(MAX_REQUEST)).read_line(&mut line));
+        if read.is_err() {
+            return true;
+        }
+        let (response, keep_running) = match serde_json::from_str::<Envelope>(&line) {
+            Ok(envelope) if envelope.token != self.token => (unauthorized(), true),
+            Ok(Envelope {
+                request: Request::Shutdown,
+                ..
+            }) => (Response::Ok, false),
+            Ok(envelope) if envelope.version != VERSION => (mismatch("version"), true),
+            Ok(envelope) => (self.respond(envelope.request), true),
+            Err(e) => (error(&e), true),
+        };
+        if let Err(e) = send(stream, &response) {
+            eprintln!("WARN: Could not answer daemon client: {e}");
+        }
+        keep_running
+    }
+
+    fn respond(&self, request: Request) -> Response {
+        match request {
+            Request::Scan {
+                files,
+                cross_file,
+                rules,
+            } => {
+                let mut config = Config::new();
+                config.load_local_config();
+                if auth::fingerprint(&config.rules) != rules {
+                    return mismatch("rules");
+                }
+                match self.scan(config, files, rules, cross_file) {
+                    Ok(report) => Response::Report { report },
+                    Err(e) => error(&e),
+                }
+            }
+            Request::Ping | Request::Shutdown => Response::Ok,
+        }
+    }
+
+    /// Scans with the warm engine for `rules`, discovering files with the
+    /// config reloaded for this request.
+    fn scan(
+        &self,
+        config: Config,
+        files: Option<Vec<PathBuf>>,
+        rules: u64,
+        cross_file: bool,
+    ) -> Result<ScanReport> {
+        let files = match files {
+            Some(files) => files,
+            None => discovery::discover(&config)?,
+        };
+        let engine = self
+            .engines
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(&self.cache_dir, config, rules, cross_file);
+        Ok(engine.scan(files))
+    }
+}
+
+fn error(e: &impl std::fmt::Display) -> Response {
+    Response::Error {
+        message: e.to_string(),
+    }
+}
+
+fn unauthorized() -> Response {
+    Response::Error {
+        message: "Unauthorized: wrong daemon token".to_string(),
+    }
+}
+
+fn mismatch(what: &str) -> Response {
+    Response::Mismatch {
+        message: format!("The daemon runs a different {what}"),
+    }
+}
+
+fn send(mut stream: &TcpStream, message: &impl Serialize) -> Result<()> {
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    stream.write_all(line.as_bytes())?;
+    Ok(())
+}
+
+/// Scans every discovered file, through the daemon if one is running.
+///
+/// # Errors
+/// Returns error if discovery fails.
+pub fn scan_project(config: Config) -> Result<ScanReport> {
+    if let Some(report) = client::scan(&config, None, true) {
+        return Ok(report);
+    }
+    let files = discovery::discover(&config)?;
+    Ok(RuleEngine::new(config).with_cache(CACHE_DIR).scan(files))
+}
+
+/// Runs the daemon for the current directory until `slopchop daemon --stop`.
+///
+/// # Errors
+/// Returns error if the socket or address file cannot be set up.
+pub fn run() -> Result<()> {
+    let dir = Path::new(CACHE_DIR);
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let handle = Handle {
+        addr: listener.local_addr()?,
+        token: auth::new_token(),
+    };
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(".gitignore"), "*\n")?;
+    handle.write(&dir.join(ADDR_FILE))?;
+    println!(
+        "slopchop daemon listening on {} (stop with `slopchop daemon --stop`)",
+        handle.addr
+    );
+
+    let result = Daemon::new(dir, &handle.token).serve(&listener);
+    let _ = fs::remove_file(dir.join(ADDR_FILE));
+    result
+}
@@ -0,0 +1,53 @@
+// src/daemon/warm.rs
+//! The rule engines a daemon keeps between requests.
+
+use crate::analysis::cache::ScanCache;
+use crate::analysis::RuleEngine;
+use crate::config::Config;
+use std::collections::HashMap;
+use std::path::Path;
+use std::sync::Arc;
+
+/// Engines built for one set of rules, reused until their fingerprint
+/// changes. Building an engine compiles the custom and debt patterns, so
+/// only the first request after a rules change pays for it.
+#[derive(Default)]
+pub struct Engines {
+    rules: u64,
+    cache: Option<Arc<ScanCache>>,
+    /// Keyed by whether the engine runs the cross-file passes.
+    built: HashMap<bool, Arc<RuleEngine>>,
+}
+
+impl Engines {
+    /// The engine for `config`, whose rules have the fingerprint `rules`.
+    /// The scan cache in `cache_dir` is reloaded only when the cached
+    /// rules changed too.
+    pub fn get(
+        &mut self,
+        cache_dir: &Path,
+        mut config: Config,
+        rules: u64,
+        cross_file: bool,
+    ) -> Arc<RuleEngine> {
+        if self.rules != rules {
+            self.built.clear();
+            self.rules = rules;
+        }
+        if let Some(engine) = self.built.get(&cross_file) {
+            return Arc::clone(engine);
+        }
+        if !cross_file {
+            config.rules.cross_file.detect_dead_code = false;
+            config.rules.cross_file.detect_cycles = false;
+        }
+        let cache = match &self.cache {
+            Some(cache) if cache.is_for(&config.rules) => Arc::clone(cache),
+            _ => Arc::new(ScanCache::load(cache_dir, &config.rules)),
+        };
+        self.cache = Some(Arc::clone(&cache));
+        let engine = Arc::new(RuleEngine::new(config).with_shared_cache(cache));
+        self.built.insert(cross_file, Arc::clone(&engine));
+        engine
+    }
+}
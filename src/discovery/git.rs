@@ -0,0 +1,116 @@
+// src/discovery/git.rs
+//! Git-backed file enumeration.
+
+use super::{filter_config, filter_pruned, is_known_code};
+use crate::config::Config;
+use crate::error::{Result, SlopChopError};
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub fn in_git_repo() -> bool {
+    Command::new("git")
+        .args(["rev-parse", "--is-inside-work-tree"])
+        .output()
+        .map(|o| o.status.success())
+        .unwrap_or(false)
+}
+
+pub fn git_ls_files() -> Result<Vec<PathBuf>> {
+    git_paths(&["ls-files", "-z", "-c", "-o", "--exclude-standard", "."])
+}
+
+/// Files with staged, unstaged, or untracked changes, plus (with `since`)
+/// files changed on this branch since it diverged from that ref.
+///
+/// # Errors
+/// Returns error if this is not a git repository or a git command fails,
+/// e.g. because `since` is not a valid ref.
+pub fn changed_files(since: Option<&str>) -> Result<HashSet<PathBuf>> {
+    if !in_git_repo() {
+        return Err(SlopChopError::NotInGitRepo);
+    }
+    let mut found: HashSet<PathBuf> = HashSet::new();
+    found.extend(git_paths(&["diff", "-z", "--name-only", "--relative"])?);
+    found.extend(git_paths(&[
+        "diff",
+        "-z",
+        "--name-only",
+        "--relative",
+        "--cached",
+    ])?);
+    found.extend(git_paths(&[
+        "ls-files",
+        "-z",
+        "-o",
+        "--exclude-standard",
+        ".",
+    ])?);
+    if let Some(base) = since {
+        let range = format!("{base}...HEAD");
+        found.extend(git_paths(&[
+            "diff",
+            "-z",
+            "--name-only",
+            "--relative",
+            &range,
+        ])?);
+    }
+    // Deleted files show up in diffs but have nothing left to scan.
+    found.retain(|p| p.is_file());
+    Ok(found)
+}
+
+/// Discovery run against the tree at `rev` instead of the working tree:
+/// the code files it would scan, with their contents at that revision.
+///
+/// # Errors
+/// Returns error if this is not a git repository or `rev` does not resolve.
+pub fn discover_at(config: &Config, rev: &str) -> Result<Vec<(PathBuf, String)>> {
+    if !in_git_repo() {
+        return Err(SlopChopError::NotInGitRepo);
+    }
+    let listed = git_paths(&["ls-tree", "-r", "-z", "--name-only", rev])?;
+    let code = filter_pruned(listed)
+        .into_iter()
+        .filter(|p| is_known_code(&p.to_string_lossy()))
+        .collect();
+    filter_config(code, config)?
+        .into_iter()
+        .map(|path| show(rev, &path).map(|content| (path, content)))
+        .collect()
+}
+
+/// The content of `path` (relative to the working directory) at `rev`.
+fn show(rev: &str, path: &Path) -> Result<String> {
+    let spec = format!("{rev}:./{}", path.to_string_lossy().replace('\\', "/"));
+    let out = Command::new("git").args(["show", &spec]).output()?;
+    if !out.status.success() {
+        return Err(SlopChopError::Other(format!(
+            "git show {spec} failed: {}",
+            String::from_utf8_lossy(&out.stderr).trim()
+        )));
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).into_owned())
+}
+
+fn git_paths(args: &[&str]) -> Result<Vec<PathBuf>> {
+    let out = Command::new("git").args(args).output()?;
+
+    if !out.status.success() {
+        return Err(SlopChopError::Other(format!(
+            "git {} failed: {}",
+            args.first().unwrap_or(&""),
+            String::from_utf8_lossy(&out.stderr).trim()
+        )));
+    }
+
+    let paths = out
+        .stdout
+        .split(|&b| b == 0)
+        .filter(|chunk| !chunk.is_empty())
+        .map(|chunk| PathBuf::from(String::from_utf8_lossy(chunk).as_ref()))
+        .collect();
+
+    Ok(paths)
+}
@@ -1,17 +1,21 @@
-// src/discovery.rs
+// src/discovery/mod.rs
 use crate::config::{
     Config, GitMode, BIN_EXT_PATTERN, CODE_BARE_PATTERN, CODE_EXT_PATTERN, SECRET_PATTERN,
 };
 use crate::constants::should_prune;
 use crate::error::{Result, SlopChopError};
 use regex::Regex;
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::fs;
 use std::path::{Path, PathBuf};
-use std::process::Command;
 use std::sync::LazyLock;
 use walkdir::WalkDir;
 
+mod git;
+
+pub use git::{changed_files, discover_at};
+use git::{git_ls_files, in_git_repo};
+
 /// Runs the full file discovery pipeline: Enumerate -> Heuristics -> Filter.
 ///
 /// # Errors
@@ -23,10 +27,49 @@ pub fn discover(config: &Config) -> Result<Vec<PathBuf>> {
     Ok(final_files)
 }
 
+/// Keeps the `files` that `config` selects, as the last step of
+/// [`discover`] does.
+///
+/// # Errors
+/// Returns error if the built-in patterns fail to compile.
+pub fn select(files: Vec<PathBuf>, config: &Config) -> Result<Vec<PathBuf>> {
+    filter_config(files, config)
+}
+
+/// Runs discovery, keeping only the files reported by [`changed_files`].
+///
+/// # Errors
+/// Returns error if discovery fails or git cannot list the changes.
+pub fn discover_changed(config: &Config, since: Option<&str>) -> Result<Vec<PathBuf>> {
+    let changed = changed_files(since)?;
+    let files = discover(config)?;
+    Ok(files.into_iter().filter(|p| changed.contains(p)).collect())
+}
+
+/// Enumerated files that discovery left out (binaries, non-code files,
+/// heuristic rejects), apart from secret files and `exclude` matches.
+///
+/// # Errors
+/// Returns error if enumeration fails.
+pub fn skipped(config: &Config, kept: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let kept: HashSet<&PathBuf> = kept.iter().collect();
+    let secret_re = Regex::new(SECRET_PATTERN)?;
+    let listed = |p: &PathBuf| {
+        let s = p.to_string_lossy().replace('\\', "/");
+        !kept.contains(p)
+            && !secret_re.is_match(&s)
+            && !config.files.exclude_patterns.is_ignored(&s)
+    };
+    Ok(enumerate_files(config)?
+        .into_iter()
+        .filter(listed)
+        .collect())
+}
+
 // --- Enumeration ---
 
 fn enumerate_files(config: &Config) -> Result<Vec<PathBuf>> {
-    match config.git_mode {
+    match config.files.git_mode {
         GitMode::Yes => enumerate_git_required(),
         GitMode::No => Ok(walk_filesystem(config.verbose)),
         GitMode::Auto => Ok(enumerate_auto(config.verbose)),
@@ -81,36 +124,6 @@ where
     (paths, errors)
 }
 
-fn in_git_repo() -> bool {
-    Command::new("git")
-        .args(["rev-parse", "--is-inside-work-tree"])
-        .output()
-        .map(|o| o.status.success())
-        .unwrap_or(false)
-}
-
-fn git_ls_files() -> Result<Vec<PathBuf>> {
-    let out = Command::new("git")
-        .args(["ls-files", "-z", "-c", "-o", "--exclude-standard", "."])
-        .output()?;
-
-    if !out.status.success() {
-        return Err(SlopChopError::Other(format!(
-            "git ls-files failed: {}",
-            out.status
-        )));
-    }
-
-    let paths = out
-        .stdout
-        .split(|&b| b == 0)
-        .filter(|chunk| !chunk.is_empty())
-        .map(|chunk| PathBuf::from(String::from_utf8_lossy(chunk).as_ref()))
-        .collect();
-
-    Ok(paths)
-}
-
 fn filter_pruned(paths: Vec<PathBuf>) -> Vec<PathBuf> {
     paths
         .into_iter()
@@ -209,12 +222,12 @@ fn filter_config(files: Vec<PathBuf>, config: &Config) -> Result<Vec<PathBuf>> {
         config,
         bin_re: Regex::new(BIN_EXT_PATTERN)?,
         secret_re: Regex::new(SECRET_PATTERN)?,
-        code_re: if config.code_only {
+        code_re: if config.files.code_only {
             Some(Regex::new(CODE_EXT_PATTERN)?)
         } else {
             None
         },
-        bare_re: if config.code_only {
+        bare_re: if config.files.code_only {
             Some(Regex::new(CODE_BARE_PATTERN)?)
         } else {
             None
@@ -232,12 +245,12 @@ fn should_keep_config(path: &Path, ctx: &FilterContext) -> bool {
 
     if ctx.secret_re.is_match(&s)
         || ctx.bin_re.is_match(&s)
-        || ctx.config.exclude_patterns.iter().any(|p| p.is_match(&s))
+        || ctx.config.files.exclude_patterns.is_ignored(&s)
     {
         return false;
     }
 
-    if ctx.config.code_only {
+    if ctx.config.files.code_only {
         let is_code = ctx.code_re.as_ref().is_some_and(|r| r.is_match(&s))
             || ctx.bare_re.as_ref().is_some_and(|r| r.is_match(&s));
         if !is_code {
@@ -245,6 +258,11 @@ fn should_keep_config(path: &Path, ctx: &FilterContext) -> bool {
         }
     }
 
-    ctx.config.include_patterns.is_empty()
-        || ctx.config.include_patterns.iter().any(|p| p.is_match(&s))
+    ctx.config.files.include_patterns.is_empty()
+        || ctx
+            .config
+            .files
+            .include_patterns
+            .iter()
+            .any(|p| p.is_match(&s))
 }
@@ -44,4 +44,4 @@ impl From<anyhow::Error> for SlopChopError {
     fn from(e: anyhow::Error) -> Self {
         SlopChopError::Other(e.to_string())
     }
-}
\ No newline at end of file
+}
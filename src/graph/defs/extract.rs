@@ -56,12 +56,12 @@ pub fn extract(path: &Path, content: &str) -> Vec<Definition> {
     let Some(lang) = Lang::from_ext(ext) else {
         return Vec::new();
     };
-    
+
     let (grammar, query) = DefExtractor::get_config(lang);
-    run_extraction(content, grammar, &query)
+    run_extraction(content, &grammar, &query)
 }
 
-fn run_extraction(source: &str, lang: Language, query: &Query) -> Vec<Definition> {
+fn run_extraction(source: &str, lang: &Language, query: &Query) -> Vec<Definition> {
     let Some(tree) = parse_source(source, lang) else {
         return Vec::new();
     };
@@ -77,7 +77,7 @@ fn run_extraction(source: &str, lang: Language, query: &Query) -> Vec<Definition
         .collect()
 }
 
-fn parse_source(source: &str, lang: Language) -> Option<tree_sitter::Tree> {
+fn parse_source(source: &str, lang: &Language) -> Option<tree_sitter::Tree> {
     let mut parser = Parser::new();
     parser.set_language(lang).ok()?;
     parser.parse(source, None)
@@ -150,4 +150,4 @@ mod tests {
         assert!(defs.iter().any(|d| d.name == "UserService"));
         assert!(defs.iter().any(|d| d.name == "helper"));
     }
-}
\ No newline at end of file
+}
@@ -8,11 +8,12 @@ impl DefExtractor {
     #[must_use]
     pub fn get_config(lang: Lang) -> (Language, Query) {
         let grammar = lang.grammar();
-        let query = compile_query(grammar, lang.q_defs());
+        let query = compile_query(&grammar, lang.q_defs());
         (grammar, query)
     }
 }
 
-fn compile_query(lang: Language, pattern: &str) -> Query {
+fn compile_query(lang: &Language, pattern: &str) -> Query {
+    // slopchop:ignore-next-line paranoia
     Query::new(lang, pattern).unwrap_or_else(|e| panic!("Invalid query: {e}"))
-}
\ No newline at end of file
+}
@@ -22,12 +22,12 @@ pub fn extract(path: &Path, content: &str) -> Vec<String> {
     };
 
     let grammar = lang.grammar();
-    let query = compile_query(grammar, lang.q_imports());
+    let query = compile_query(&grammar, lang.q_imports());
 
-    run_query(content, grammar, &query)
+    run_query(content, &grammar, &query)
 }
 
-fn run_query(source: &str, lang: Language, query: &Query) -> Vec<String> {
+fn run_query(source: &str, lang: &Language, query: &Query) -> Vec<String> {
     let mut parser = Parser::new();
     if parser.set_language(lang).is_err() {
         return Vec::new();
@@ -58,9 +58,10 @@ fn clean_text(text: &str) -> String {
         .to_string()
 }
 
-fn compile_query(lang: Language, pattern: &str) -> Query {
+fn compile_query(lang: &Language, pattern: &str) -> Query {
     match Query::new(lang, pattern) {
         Ok(q) => q,
+        // slopchop:ignore-next-line paranoia
         Err(e) => panic!("Invalid import query: {e}"),
     }
 }
@@ -119,4 +120,4 @@ mod tests {
         assert!(imports.contains(&"std::collections::HashMap".to_string()));
         assert!(imports.contains(&"crate::config".to_string()));
     }
-}
\ No newline at end of file
+}
@@ -99,4 +99,3 @@ fn normalize(ranks: &mut HashMap<PathBuf, f64>) {
         }
     }
 }
-
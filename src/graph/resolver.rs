@@ -13,7 +13,7 @@ use std::path::{Path, PathBuf};
 #[must_use]
 pub fn resolve(project_root: &Path, current_file: &Path, import_str: &str) -> Option<PathBuf> {
     let ext = current_file.extension().and_then(|s| s.to_str())?;
-    
+
     match ext {
         "rs" => resolve_rust(project_root, current_file, import_str),
         "ts" | "tsx" | "js" | "jsx" => resolve_js(project_root, current_file, import_str),
@@ -61,7 +61,7 @@ fn resolve_super_path(current: &Path, import: &str) -> Option<PathBuf> {
     if parts.is_empty() {
         return None;
     }
-    
+
     check_variations(dir, &parts, "rs")
 }
 
@@ -85,7 +85,7 @@ fn resolve_js(_root: &Path, current: &Path, import: &str) -> Option<PathBuf> {
 
     let parent = current.parent()?;
     let path = parent.join(import);
-    
+
     if let Some(p) = check_js_file(&path) {
         return Some(p);
     }
@@ -151,7 +151,7 @@ fn check_variations(base: &Path, parts: &[&str], ext: &str) -> Option<PathBuf> {
         "py" => "__init__.py",
         _ => return None,
     };
-    
+
     let index_path = current.join(index_name);
     if index_path.exists() {
         return Some(index_path);
@@ -163,18 +163,18 @@ fn check_variations(base: &Path, parts: &[&str], ext: &str) -> Option<PathBuf> {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use tempfile::tempdir;
-    use std::fs;
     use anyhow::Result;
+    use std::fs;
+    use tempfile::tempdir;
 
     #[test]
     fn test_resolve_rust_mod_relative() -> Result<()> {
         let temp = tempdir()?;
         let root = temp.path();
-        
+
         let src = root.join("src");
         fs::create_dir_all(&src)?;
-        
+
         let main = src.join("main.rs");
         let util = src.join("util.rs");
         fs::write(&main, "mod util;")?;
@@ -189,14 +189,14 @@ mod tests {
     fn test_resolve_rust_crate() -> Result<()> {
         let temp = tempdir()?;
         let root = temp.path();
-        
+
         let src = root.join("src");
         let conf = src.join("config");
         fs::create_dir_all(&conf)?;
-        
+
         let lib = src.join("lib.rs");
         let types = conf.join("types.rs");
-        
+
         fs::write(&lib, "use crate::config::types;")?;
         fs::write(&types, "// types")?;
 
@@ -209,14 +209,14 @@ mod tests {
     fn test_resolve_rust_mod_index() -> Result<()> {
         let temp = tempdir()?;
         let root = temp.path();
-        
+
         let src = root.join("src");
         let utils = src.join("utils");
         fs::create_dir_all(&utils)?;
-        
+
         let main = src.join("main.rs");
         let mod_rs = utils.join("mod.rs");
-        
+
         fs::write(&main, "mod utils;")?;
         fs::write(&mod_rs, "// mod.rs")?;
 
@@ -229,15 +229,15 @@ mod tests {
     fn test_resolve_rust_super() -> Result<()> {
         let temp = tempdir()?;
         let root = temp.path();
-        
+
         // Structure: src/lib.rs, src/parent/child.rs
         let src = root.join("src");
         let parent = src.join("parent");
         fs::create_dir_all(&parent)?;
-        
+
         let lib = src.join("lib.rs");
         let child = parent.join("child.rs");
-        
+
         fs::write(&lib, "// lib")?;
         fs::write(&child, "use super::lib;")?;
 
@@ -250,10 +250,10 @@ mod tests {
     fn test_resolve_rust_self() -> Result<()> {
         let temp = tempdir()?;
         let root = temp.path();
-        
+
         let src = root.join("src");
         fs::create_dir_all(&src)?;
-        
+
         let main = src.join("main.rs");
         let util = src.join("util.rs");
         fs::write(&main, "")?;
@@ -268,7 +268,7 @@ mod tests {
     fn test_resolve_js_relative_extension() -> Result<()> {
         let temp = tempdir()?;
         let root = temp.path();
-        
+
         let app = root.join("app.ts");
         let cmp = root.join("cmp.tsx");
         fs::write(&app, "")?;
@@ -278,4 +278,4 @@ mod tests {
         assert_eq!(resolved, Some(cmp));
         Ok(())
     }
-}
\ No newline at end of file
+}
@@ -0,0 +1,145 @@
+// src/history/diff.rs
+//! `slopchop diff [REF]`: violations introduced and fixed relative to a git
+//! ref, or count changes since the last recorded scan.
+
+use super::{load, Snapshot, HISTORY_FILE};
+use crate::analysis::RuleEngine;
+use crate::baseline::Baseline;
+use crate::config::Config;
+use crate::daemon;
+use crate::discovery;
+use crate::types::{ScanReport, Violation};
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use std::collections::BTreeMap;
+use std::fmt::Display;
+use std::path::{Path, PathBuf};
+
+/// Violations present in one scan but not the other.
+#[derive(Debug, Default)]
+pub struct ViolationDiff {
+    pub introduced: Vec<(PathBuf, Violation)>,
+    pub fixed: Vec<(PathBuf, Violation)>,
+}
+
+/// Matches violations the way the baseline does (path, rule, message with
+/// digits masked), so moved code is neither new nor fixed.
+#[must_use]
+pub fn compare(old: &ScanReport, new: &ScanReport) -> ViolationDiff {
+    ViolationDiff {
+        introduced: unmatched(new, old),
+        fixed: unmatched(old, new),
+    }
+}
+
+/// Violations in `report` with no counterpart in `other`.
+fn unmatched(report: &ScanReport, other: &ScanReport) -> Vec<(PathBuf, Violation)> {
+    let mut report = report.clone();
+    Baseline::from_report(other).apply(&mut report);
+    report
+        .files
+        .into_iter()
+        .flat_map(|f| f.violations.into_iter().map(move |v| (f.path.clone(), v)))
+        .collect()
+}
+
+/// Count changes between two snapshots, omitting unchanged keys.
+#[must_use]
+pub fn count_changes<K: Ord + Clone>(
+    old: &BTreeMap<K, usize>,
+    new: &BTreeMap<K, usize>,
+) -> Vec<(K, usize, usize)> {
+    let keys: std::collections::BTreeSet<&K> = old.keys().chain(new.keys()).collect();
+    keys.into_iter()
+        .map(|k| {
+            let count = |m: &BTreeMap<K, usize>| m.get(k).copied().unwrap_or(0);
+            (k.clone(), count(old), count(new))
+        })
+        .filter(|(_, a, b)| a != b)
+        .collect()
+}
+
+/// Runs the diff command, exiting with 1 if violations were introduced.
+///
+/// # Errors
+/// Returns error if either scan fails, `rev` does not resolve, or (without
+/// `rev`) no scan has been recorded yet.
+pub fn run(rev: Option<&str>) -> Result<()> {
+    let mut config = Config::new();
+    config.load_local_config();
+    let current = daemon::scan_project(config.clone())?;
+    let worse = match rev {
+        Some(rev) => against_ref(config, &current, rev)?,
+        None => against_history(&current)?,
+    };
+    if worse {
+        std::process::exit(1);
+    }
+    Ok(())
+}
+
+fn against_ref(config: Config, current: &ScanReport, rev: &str) -> Result<bool> {
+    let sources = discovery::discover_at(&config, rev)?;
+    let old = RuleEngine::new(config).scan_sources(sources);
+    let diff = compare(&old, current);
+    println!("Comparing working tree against {}", rev.bold());
+    print_violations(&"NEW".red().bold(), &diff.introduced);
+    print_violations(&"FIXED".green().bold(), &diff.fixed);
+    println!(
+        "\n{} introduced, {} fixed.",
+        diff.introduced.len(),
+        diff.fixed.len()
+    );
+    Ok(!diff.introduced.is_empty())
+}
+
+fn print_violations(label: &impl Display, list: &[(PathBuf, Violation)]) {
+    if list.is_empty() {
+        return;
+    }
+    println!("\n{label} ({})", list.len());
+    for (path, v) in list {
+        println!(
+            "  {}:{} {}: {}",
+            path.display(),
+            v.row + 1,
+            v.law.dimmed(),
+            v.message
+        );
+    }
+}
+
+fn against_history(current: &ScanReport) -> Result<bool> {
+    let history = load(Path::new(HISTORY_FILE))?;
+    let last = history
+        .last()
+        .ok_or_else(|| anyhow!("No scan history yet; run `slopchop check` first"))?;
+    let now = Snapshot::from_report(current);
+    let commit = last.commit.as_deref().map_or("", |c| &c[..c.len().min(8)]);
+    println!(
+        "Violations since last recorded scan {commit}: {} -> {}",
+        last.violations, now.violations
+    );
+    print_counts("By law", count_changes(&last.laws, &now.laws));
+    let files = count_changes(&last.files, &now.files);
+    let files = files
+        .into_iter()
+        .map(|(p, a, b)| (p.display().to_string(), a, b));
+    print_counts("By file", files.collect());
+    Ok(now.violations > last.violations)
+}
+
+fn print_counts(title: &str, changes: Vec<(String, usize, usize)>) {
+    if changes.is_empty() {
+        return;
+    }
+    println!("\n{}", title.bold());
+    for (key, old, new) in changes {
+        let line = format!("  {key}: {old} -> {new}");
+        if new > old {
+            println!("{}", line.red());
+        } else {
+            println!("{}", line.green());
+        }
+    }
+}
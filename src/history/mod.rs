@@ -0,0 +1,142 @@
+// src/history/mod.rs
+//! Scan history: a summary of each full scan, appended to
+//! `.slopchop_history.jsonl` so violation counts can be tracked over time.
+//! Only the newest [`MAX_SNAPSHOTS`] are kept.
+
+pub mod diff;
+
+use crate::error::{Result, SlopChopError};
+use crate::types::ScanReport;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+
+pub const HISTORY_FILE: &str = ".slopchop_history.jsonl";
+
+/// How many snapshots the history file keeps.
+pub const MAX_SNAPSHOTS: usize = 1000;
+
+/// Violation counts from one scan, before baselines are applied.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Snapshot {
+    /// Seconds since the Unix epoch.
+    pub timestamp: u64,
+    /// `HEAD` at the time of the scan, when in a git repository.
+    pub commit: Option<String>,
+    pub violations: usize,
+    /// Counts per law; laws without violations are omitted.
+    pub laws: BTreeMap<String, usize>,
+    /// Counts per file; clean files are omitted.
+    pub files: BTreeMap<PathBuf, usize>,
+}
+
+impl Snapshot {
+    /// Summarizes `report`, stamped with the current time.
+    #[must_use]
+    pub fn from_report(report: &ScanReport) -> Self {
+        let mut laws = BTreeMap::new();
+        let mut files = BTreeMap::new();
+        for f in report.files.iter().filter(|f| !f.violations.is_empty()) {
+            files.insert(f.path.clone(), f.violations.len());
+            for v in &f.violations {
+                *laws.entry(v.law.to_string()).or_default() += 1;
+            }
+        }
+        Self {
+            timestamp: crate::time::now(),
+            commit: None,
+            violations: files.values().sum(),
+            laws,
+            files,
+        }
+    }
+
+    /// Appends this snapshot as one JSON line.
+    ///
+    /// # Errors
+    /// Returns error if the history file cannot be written.
+    pub fn append(&self, path: &Path) -> Result<()> {
+        let line = serde_json::to_string(self).map_err(|e| SlopChopError::Other(e.to_string()))?;
+        let io_err = |source| SlopChopError::Io {
+            source,
+            path: path.to_path_buf(),
+        };
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(path)
+            .map_err(io_err)?;
+        writeln!(file, "{line}").map_err(io_err)
+    }
+}
+
+/// Reads every snapshot in `path`, oldest first. A missing file is empty
+/// history; unparseable lines are skipped.
+///
+/// # Errors
+/// Returns error if the file exists but cannot be read.
+pub fn load(path: &Path) -> Result<Vec<Snapshot>> {
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    let content = fs::read_to_string(path).map_err(|source| SlopChopError::Io {
+        source,
+        path: path.to_path_buf(),
+    })?;
+    Ok(content
+        .lines()
+        .filter_map(|l| serde_json::from_str(l).ok())
+        .collect())
+}
+
+/// Drops all but the newest `keep` lines of `path`, if it has more.
+///
+/// # Errors
+/// Returns error if the file cannot be read or rewritten.
+pub fn trim(path: &Path, keep: usize) -> Result<()> {
+    let io_err = |source| SlopChopError::Io {
+        source,
+        path: path.to_path_buf(),
+    };
+    let content = fs::read_to_string(path).map_err(io_err)?;
+    let lines: Vec<&str> = content.lines().collect();
+    if lines.len() <= keep {
+        return Ok(());
+    }
+    let kept = lines[lines.len() - keep..]
+        .iter()
+        .fold(String::new(), |mut out, l| {
+            out.push_str(l);
+            out.push('\n');
+            out
+        });
+    fs::write(path, kept).map_err(io_err)
+}
+
+/// Appends a snapshot of `report` to the local history file. History is a
+/// side record, so failing to write it only warns.
+pub fn record(report: &ScanReport) {
+    let mut snapshot = Snapshot::from_report(report);
+    snapshot.commit = head();
+    let path = Path::new(HISTORY_FILE);
+    if let Err(e) = snapshot
+        .append(path)
+        .and_then(|()| trim(path, MAX_SNAPSHOTS))
+    {
+        eprintln!("WARN: Scan history not saved: {e}");
+    }
+}
+
+/// The full `HEAD` commit, when in a git repository.
+pub(crate) fn head() -> Option<String> {
+    let out = Command::new("git")
+        .args(["rev-parse", "HEAD"])
+        .output()
+        .ok()?;
+    out.status
+        .success()
+        .then(|| String::from_utf8_lossy(&out.stdout).trim().to_string())
+}
@@ -0,0 +1,120 @@
+// src/hotspots.rs
+//! `slopchop hotspots`: ranks files by git churn times how hard they are to
+//! work with, so refactoring effort goes where the code changes most.
+
+use crate::config::Config;
+use crate::daemon;
+use crate::types::ScanReport;
+use anyhow::{anyhow, Result};
+use colored::Colorize;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::process::Command;
+
+/// Each violation weighs as much as this many complexity points.
+const VIOLATION_WEIGHT: usize = 5;
+
+/// One ranked file.
+#[derive(Debug, Clone, Serialize)]
+pub struct Hotspot {
+    pub path: PathBuf,
+    pub commits: usize,
+    pub violations: usize,
+    pub complexity: usize,
+    /// `commits * (complexity + violations * 5)`.
+    pub score: usize,
+}
+
+/// Commits touching each file, from `git log` (merges excluded).
+///
+/// # Errors
+/// Returns error if git cannot be run or this is not a repository.
+pub fn churn() -> Result<HashMap<PathBuf, usize>> {
+    let out = Command::new("git")
+        .args(["-c", "core.quotePath=false", "log", "--no-merges"])
+        .args(["--format=", "--name-only", "--relative"])
+        .output()?;
+    if !out.status.success() {
+        let err = String::from_utf8_lossy(&out.stderr);
+        return Err(anyhow!("git log failed: {}", err.trim()));
+    }
+    let mut counts = HashMap::new();
+    for line in String::from_utf8_lossy(&out.stdout).lines() {
+        if !line.is_empty() {
+            *counts.entry(PathBuf::from(line)).or_insert(0) += 1;
+        }
+    }
+    Ok(counts)
+}
+
+/// Ranks the scanned files, highest score first. Files that never changed
+/// or have nothing to fix score zero and are left out.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn rank(report: &ScanReport, churn: &HashMap<PathBuf, usize>) -> Vec<Hotspot> {
+    let mut spots: Vec<Hotspot> = report
+        .files
+        .iter()
+        .map(|f| {
+            let commits = churn.get(&f.path).copied().unwrap_or(0);
+            let violations = f.violations.len();
+            let complexity = f.complexity_score;
+            Hotspot {
+                path: f.path.clone(),
+                commits,
+                violations,
+                complexity,
+                score: commits * (complexity + violations * VIOLATION_WEIGHT),
+            }
+        })
+        .filter(|h| h.score > 0)
+        .collect();
+    spots.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.path.cmp(&b.path)));
+    spots
+}
+
+/// Scans the project and prints the top `limit` hotspots.
+///
+/// # Errors
+/// Returns error if discovery or git fails.
+pub fn run(limit: usize, json: bool) -> Result<()> {
+    let mut config = Config::new();
+    config.load_local_config();
+    let report = daemon::scan_project(config)?;
+    let mut spots = rank(&report, &churn()?);
+    spots.truncate(limit);
+
+    if json {
+        println!("{}", serde_json::to_string_pretty(&spots)?);
+    } else {
+        print_table(&spots);
+    }
+    Ok(())
+}
+
+fn print_table(spots: &[Hotspot]) {
+    if spots.is_empty() {
+        println!(
+            "{}",
+            "No hotspots: nothing changed has anything to fix.".green()
+        );
+        return;
+    }
+    let header = format!(
+        "{:>4}  {:>7}  {:>7}  {:>10}  {:>10}  FILE",
+        "#", "SCORE", "COMMITS", "VIOLATIONS", "COMPLEXITY"
+    );
+    println!("{}", header.bold());
+    for (i, h) in spots.iter().enumerate() {
+        println!(
+            "{:>4}  {:>7}  {:>7}  {:>10}  {:>10}  {}",
+            i + 1,
+            h.score,
+            h.commits,
+            h.violations,
+            h.complexity,
+            h.path.display()
+        );
+    }
+}
@@ -0,0 +1,179 @@
+// src/ignore.rs
+//! Gitignore-style path patterns, shared by `.wardenignore`, the
+//! `ignore_*_on` rule lists, and `[pack] generated`.
+//!
+//! A pattern without a slash matches a file or directory name at any
+//! depth; a leading or inner slash anchors it to the project root, and a
+//! trailing slash matches directories only. `*` and `?` stay within a
+//! directory, `**` spans any number of them, and `[a-z]` is a character
+//! class. `!` re-includes what an earlier pattern ignored: the last
+//! matching pattern wins, but nothing under an ignored directory comes
+//! back.
+
+use regex::Regex;
+
+/// An ordered list of ignore patterns.
+#[derive(Debug, Clone, Default)]
+pub struct IgnoreSet {
+    patterns: Vec<Pattern>,
+}
+
+#[derive(Debug, Clone)]
+struct Pattern {
+    regex: Regex,
+    negated: bool,
+    dir_only: bool,
+}
+
+impl IgnoreSet {
+    /// Parses the lines of an ignore file.
+    #[must_use]
+    pub fn parse(content: &str) -> Self {
+        let mut set = Self::default();
+        content.lines().for_each(|line| set.add(line));
+        set
+    }
+
+    /// One pattern per entry, as in the `ignore_*_on` lists.
+    #[must_use]
+    pub fn from_patterns<S: AsRef<str>>(patterns: &[S]) -> Self {
+        let mut set = Self::default();
+        for p in patterns {
+            set.add(p.as_ref());
+        }
+        set
+    }
+
+    /// Adds one ignore-file line. Blank lines, `#` comments, and patterns
+    /// that don't compile are skipped.
+    pub fn add(&mut self, line: &str) {
+        if let Some(pattern) = Pattern::parse(line) {
+            self.patterns.push(pattern);
+        }
+    }
+
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.patterns.is_empty()
+    }
+
+    /// True if the file at `path`, relative to the project root, is
+    /// ignored itself or through one of its directories.
+    #[must_use]
+    pub fn is_ignored(&self, path: &str) -> bool {
+        if self.patterns.is_empty() {
+            return false;
+        }
+        let path = path.replace('\\', "/");
+        let path = path.trim_start_matches("./");
+        let dir_ignored = path
+            .match_indices('/')
+            .any(|(end, _)| self.decide(&path[..end], true) == Some(true));
+        dir_ignored || self.decide(path, false) == Some(true)
+    }
+
+    /// Whether the last pattern matching `path` ignores it, if any does.
+    fn decide(&self, path: &str, is_dir: bool) -> Option<bool> {
+        self.patterns
+            .iter()
+            .rev()
+            .find(|p| (is_dir || !p.dir_only) && p.regex.is_match(path))
+            .map(|p| !p.negated)
+    }
+}
+
+impl Pattern {
+    fn parse(line: &str) -> Option<Self> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return None;
+        }
+        let (negated, line) = match line.strip_prefix('!') {
+            Some(rest) => (true, rest),
+            None => (false, line),
+        };
+        let dir_only = line.ends_with('/');
+        let body = line.trim_end_matches('/');
+        let anchored = body.contains('/');
+        let body = body.trim_start_matches('/');
+        if body.is_empty() {
+            return None;
+        }
+        let prefix = if anchored { "^" } else { "^(?:.*/)?" };
+        let regex = Regex::new(&format!("{prefix}{}$", translate(body))).ok()?;
+        Some(Self {
+            regex,
+            negated,
+            dir_only,
+        })
+    }
+}
+
+/// The regex for the glob `pattern`, matching whole paths.
+#[must_use]
+pub fn glob_regex(pattern: &str) -> Option<Regex> {
+    Regex::new(&format!("^{}$", translate(pattern))).ok()
+}
+
+fn translate(glob: &str) -> String {
+    let mut re = String::new();
+    let mut rest = glob;
+    while !rest.is_empty() {
+        let (piece, len) = glob_token(rest);
+        re.push_str(&piece);
+        rest = &rest[len..];
+    }
+    re
+}
+
+/// The regex for the glob token at the start of `rest`, and its length.
+fn glob_token(rest: &str) -> (String, usize) {
+    if let Some(token) = star_token(rest) {
+        return token;
+    }
+    let mut chars = rest.chars();
+    match chars.next() {
+        Some('?') => ("[^/]".to_string(), 1),
+        Some('[') => class_token(rest),
+        Some('\\') => chars.next().map_or((String::new(), 1), |c| {
+            (regex::escape(&c.to_string()), 1 + c.len_utf8())
+        }),
+        Some(c) => (regex::escape(&c.to_string()), c.len_utf8()),
+        None => (String::new(), 0),
+    }
+}
+
+fn star_token(rest: &str) -> Option<(String, usize)> {
+    if rest.starts_with("**/") {
+        return Some(("(?:.*/)?".to_string(), 3));
+    }
+    if rest.starts_with("**") {
+        return Some((".*".to_string(), 2));
+    }
+    rest.starts_with('*').then(|| ("[^/]*".to_string(), 1))
+}
+
+/// `[...]` as a class that never matches `/`, or a literal `[` if unclosed.
+fn class_token(rest: &str) -> (String, usize) {
+    let Some(end) = rest[1..].find(']').map(|i| i + 1) else {
+        return (regex::escape("["), 1);
+    };
+    let body = &rest[1..end];
+    let (negated, body) = match body.strip_prefix(['!', '^']) {
+        Some(inner) => (true, inner),
+        None => (false, body),
+    };
+    let body: String = body
+        .chars()
+        .map(|c| match c {
+            '\\' | '[' | '&' | '~' | '^' => format!("\\{c}"),
+            c => c.to_string(),
+        })
+        .collect();
+    let class = if negated {
+        format!("[^/{body}]")
+    } else {
+        format!("[{body}]")
+    };
+    (class, end + 1)
+}
@@ -0,0 +1,40 @@
+// src/lang/bash.rs
+use super::Queries;
+
+/// Dangerous shell constructs. Capture names double as hazard identifiers.
+const HAZARDS: &str = r#"
+    (command name: (command_name (word) @cmd) (#eq? @cmd "eval")) @eval
+    (command
+      argument: (simple_expansion (special_variable_name) @var)
+      (#match? @var "^[@*]$")) @unquoted_args
+    (command
+      name: (command_name (word) @cmd)
+      argument: (word) @flag
+      argument: [(simple_expansion) (expansion) (string (simple_expansion)) (string (expansion))] @target
+      (#eq? @cmd "rm")
+      (#match? @flag "^-[a-zA-Z]*[rR]")
+      (#not-match? @target ":[?]")) @rm_var
+"#;
+
+pub const QUERIES: Queries = Queries {
+    naming: "(function_definition name: (word) @name)",
+    complexity: r#"
+        (if_statement) @branch
+        (elif_clause) @branch
+        (for_statement) @branch
+        (c_style_for_statement) @branch
+        (while_statement) @branch
+        (case_item) @branch
+        (list ["&&" "||"]) @branch
+    "#,
+    banned: Some(HAZARDS),
+    imports: r#"
+        (command
+          name: (command_name) @func
+          argument: (word) @import
+          (#match? @func "^(source|[.])$"))
+    "#,
+    defs: "(function_definition name: (word) @name) @sig",
+    skeleton: "(function_definition body: (compound_statement) @body)",
+    calls: "(command name: (command_name) @call)",
+};
@@ -0,0 +1,130 @@
+// src/lang/mod.rs
+//! Supported languages and the tree-sitter queries that drive analysis.
+
+mod bash;
+mod python;
+mod rust;
+mod typescript;
+#[cfg(feature = "zig")]
+mod zig;
+
+use tree_sitter::Language;
+
+/// The query set for one language.
+pub struct Queries {
+    pub naming: &'static str,
+    pub complexity: &'static str,
+    pub banned: Option<&'static str>,
+    pub imports: &'static str,
+    pub defs: &'static str,
+    pub skeleton: &'static str,
+    /// Call targets, captured as `@call` (or `@macro` for Rust macros).
+    pub calls: &'static str,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Lang {
+    Rust,
+    Python,
+    TypeScript,
+    Bash,
+    #[cfg(feature = "zig")]
+    Zig,
+}
+
+impl Lang {
+    #[must_use]
+    pub fn from_ext(ext: &str) -> Option<Self> {
+        match ext {
+            "rs" => Some(Self::Rust),
+            "py" => Some(Self::Python),
+            "ts" | "tsx" | "js" | "jsx" => Some(Self::TypeScript),
+            "sh" | "bash" => Some(Self::Bash),
+            #[cfg(feature = "zig")]
+            "zig" => Some(Self::Zig),
+            _ => None,
+        }
+    }
+
+    /// The key used for per-language sections in `warden.toml`.
+    #[must_use]
+    pub fn config_key(&self) -> &'static str {
+        match self {
+            Self::Rust => "rs",
+            Self::Python => "py",
+            Self::TypeScript => "ts",
+            Self::Bash => "sh",
+            #[cfg(feature = "zig")]
+            Self::Zig => "zig",
+        }
+    }
+
+    #[must_use]
+    pub fn grammar(&self) -> Language {
+        match self {
+            Self::Rust => tree_sitter_rust::LANGUAGE.into(),
+            Self::Python => tree_sitter_python::LANGUAGE.into(),
+            Self::TypeScript => tree_sitter_typescript::LANGUAGE_TYPESCRIPT.into(),
+            Self::Bash => tree_sitter_bash::LANGUAGE.into(),
+            #[cfg(feature = "zig")]
+            Self::Zig => tree_sitter_zig::LANGUAGE.into(),
+        }
+    }
+
+    #[must_use]
+    pub fn skeleton_replacement(&self) -> &'static str {
+        match self {
+            Self::Python => "...",
+            _ => "{ ... }",
+        }
+    }
+
+    #[must_use]
+    pub fn queries(&self) -> &'static Queries {
+        match self {
+            Self::Rust => &rust::QUERIES,
+            Self::Python => &python::QUERIES,
+            Self::TypeScript => &typescript::QUERIES,
+            Self::Bash => &bash::QUERIES,
+            #[cfg(feature = "zig")]
+            Self::Zig => &zig::QUERIES,
+        }
+    }
+
+    // --- QUERIES ---
+
+    #[must_use]
+    pub fn q_naming(&self) -> &'static str {
+        self.queries().naming
+    }
+
+    #[must_use]
+    pub fn q_complexity(&self) -> &'static str {
+        self.queries().complexity
+    }
+
+    #[must_use]
+    pub fn q_banned(&self) -> Option<&'static str> {
+        self.queries().banned
+    }
+
+    #[must_use]
+    pub fn q_imports(&self) -> &'static str {
+        self.queries().imports
+    }
+
+    #[must_use]
+    pub fn q_defs(&self) -> &'static str {
+        self.queries().defs
+    }
+
+    #[must_use]
+    pub fn q_skeleton(&self) -> &'static str {
+        self.queries().skeleton
+    }
+
+    #[must_use]
+    pub fn q_calls(&self) -> &'static str {
+        self.queries().calls
+    }
+}
@@ -0,0 +1,25 @@
+// src/lang/python.rs
+use super::Queries;
+
+pub const QUERIES: Queries = Queries {
+    naming: "(function_definition name: (identifier) @name)",
+    complexity: r"
+        (if_statement) @branch
+        (for_statement) @branch
+        (while_statement) @branch
+        (except_clause) @branch
+        (boolean_operator) @branch
+    ",
+    banned: None,
+    imports: r"
+        (import_statement name: (dotted_name) @import)
+        (aliased_import name: (dotted_name) @import)
+        (import_from_statement module_name: (dotted_name) @import)
+    ",
+    defs: r"
+        (function_definition name: (identifier) @name) @sig
+        (class_definition name: (identifier) @name) @sig
+    ",
+    skeleton: "(function_definition body: (block) @body)",
+    calls: "(call function: (_) @call)",
+};
@@ -0,0 +1,40 @@
+// src/lang/rust.rs
+use super::Queries;
+
+pub const QUERIES: Queries = Queries {
+    naming: "(function_item name: (identifier) @name)",
+    complexity: r#"
+        (if_expression) @branch
+        (match_arm) @branch
+        (while_expression) @branch
+        (for_expression) @branch
+        (binary_expression operator: ["&&" "||"]) @branch
+    "#,
+    banned: Some(
+        r#"
+        (call_expression function: (field_expression field: (field_identifier) @method)) @call
+        (macro_invocation
+            macro: (identifier) @panic_macro
+            (#match? @panic_macro "^(todo|unimplemented|panic)$"))
+    "#,
+    ),
+    imports: r"
+        (use_declaration argument: (_) @import)
+        (mod_item name: (identifier) @mod)
+    ",
+    defs: r"
+        (function_item name: (identifier) @name) @sig
+        (struct_item name: (type_identifier) @name) @sig
+        (enum_item name: (type_identifier) @name) @sig
+        (trait_item name: (type_identifier) @name) @sig
+        (impl_item type: (type_identifier) @name) @sig
+        (const_item name: (identifier) @name) @sig
+        (static_item name: (identifier) @name) @sig
+        (type_item name: (type_identifier) @name) @sig
+    ",
+    skeleton: "(function_item body: (block) @body)",
+    calls: r"
+        (call_expression function: (_) @call)
+        (macro_invocation macro: (_) @macro)
+    ",
+};
@@ -0,0 +1,42 @@
+// src/lang/typescript.rs
+use super::Queries;
+
+pub const QUERIES: Queries = Queries {
+    naming: r"
+        (function_declaration name: (identifier) @name)
+        (method_definition name: (property_identifier) @name)
+        (variable_declarator name: (identifier) @name value: [(arrow_function) (function_expression)])
+    ",
+    complexity: r#"
+        (if_statement) @branch
+        (for_statement) @branch
+        (for_in_statement) @branch
+        (while_statement) @branch
+        (do_statement) @branch
+        (switch_case) @branch
+        (catch_clause) @branch
+        (ternary_expression) @branch
+        (binary_expression operator: ["&&" "||" "??"]) @branch
+    "#,
+    banned: None,
+    imports: r#"
+        (import_statement source: (string) @import)
+        (export_statement source: (string) @import)
+        (call_expression
+          function: (identifier) @func
+          arguments: (arguments (string) @import)
+          (#eq? @func "require"))
+    "#,
+    defs: r"
+        (function_declaration name: (identifier) @name) @sig
+        (class_declaration name: (type_identifier) @name) @sig
+        (interface_declaration name: (type_identifier) @name) @sig
+        (type_alias_declaration name: (type_identifier) @name) @sig
+    ",
+    skeleton: r"
+        (function_declaration body: (statement_block) @body)
+        (method_definition body: (statement_block) @body)
+        (arrow_function body: (statement_block) @body)
+    ",
+    calls: "(call_expression function: (_) @call)",
+};
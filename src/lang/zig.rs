@@ -0,0 +1,24 @@
+// src/lang/zig.rs
+use super::Queries;
+
+pub const QUERIES: Queries = Queries {
+    naming: "(function_declaration name: (identifier) @name)",
+    complexity: r#"
+        (if_statement) @branch
+        (if_expression) @branch
+        (for_statement) @branch
+        (while_statement) @branch
+        (switch_case) @branch
+        (binary_expression operator: ["and" "or"]) @branch
+    "#,
+    banned: None,
+    imports: r#"
+        (builtin_function
+          (builtin_identifier) @func
+          (arguments (string) @import)
+          (#eq? @func "@import"))
+    "#,
+    defs: "(function_declaration name: (identifier) @name) @sig",
+    skeleton: "(function_declaration body: (block) @body)",
+    calls: "(call_expression function: (_) @call)",
+};
@@ -1,16 +1,24 @@
 // src/lib.rs
 pub mod analysis;
 pub mod apply;
+pub mod baseline;
+pub mod blame;
 pub mod clean;
 pub mod cli;
 pub mod clipboard;
 pub mod config;
 pub mod constants;
+pub mod cost;
+pub mod daemon;
 pub mod detection;
 pub mod discovery;
 pub mod error;
 pub mod graph;
+pub mod history;
+pub mod hotspots;
+pub mod ignore;
 pub mod lang;
+pub mod migrate;
 pub mod pack;
 pub mod project;
 pub mod prompt;
@@ -18,8 +26,10 @@ pub mod reporting;
 pub mod roadmap_v2;
 pub mod skeleton;
 pub mod spinner;
+pub mod time;
 pub mod tokens;
 pub mod trace;
 pub mod tui;
 pub mod types;
-pub mod wizard;
\ No newline at end of file
+pub mod wizard;
+pub mod workspace;
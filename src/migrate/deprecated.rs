@@ -0,0 +1,55 @@
+// src/migrate/deprecated.rs
+//! Drops the `warden.toml` options the schema marks deprecated, such as
+//! `[defaults.apply] force`, which load with a warning and do nothing.
+
+use std::fs;
+use std::path::Path;
+
+use toml_edit::{DocumentMut, Item, TableLike};
+
+use crate::config::files::{self, CONFIG_FILE};
+use crate::config::validate::{self, IssueKind};
+use crate::error::{Result, SlopChopError};
+
+/// Removes the deprecated options of `warden.toml` in `dir`, keeping its
+/// layout and comments.
+///
+/// # Errors
+/// Returns error if the file cannot be parsed or written.
+pub fn remove(dir: &Path, dry_run: bool, changes: &mut Vec<String>) -> Result<()> {
+    let path = files::find(dir, CONFIG_FILE);
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let keys: Vec<String> = validate::check(&content)
+        .into_iter()
+        .filter(|i| i.kind == IssueKind::Deprecated && !i.key.contains('['))
+        .map(|i| i.key)
+        .collect();
+    if keys.is_empty() {
+        return Ok(());
+    }
+    let mut doc: DocumentMut = content
+        .parse()
+        .map_err(|e| SlopChopError::Other(format!("{CONFIG_FILE}: {e}")))?;
+    for key in keys {
+        if remove_key(doc.as_table_mut(), &key) {
+            changes.push(format!("{CONFIG_FILE}: removed deprecated `{key}`"));
+        }
+    }
+    if !dry_run {
+        fs::write(&path, doc.to_string())?;
+    }
+    Ok(())
+}
+
+/// Removes the dotted `key` from `table`, returning whether it was there.
+fn remove_key(table: &mut dyn TableLike, key: &str) -> bool {
+    match key.split_once('.') {
+        Some((head, rest)) => table
+            .get_mut(head)
+            .and_then(Item::as_table_like_mut)
+            .is_some_and(|inner| remove_key(inner, rest)),
+        None => table.remove(key).is_some(),
+    }
+}
@@ -0,0 +1,120 @@
+// src/migrate/legacy.rs
+//! Recognizes the regexes older versions took as ignore patterns, warns
+//! about them at load, and finds the glob each was meant as.
+
+use std::fs;
+use std::iter::Peekable;
+use std::str::Chars;
+use std::sync::Once;
+
+use crate::config::files::{self, IGNORE_FILE};
+use crate::config::Config;
+
+/// Marks of a regex, which older versions took in `.slopchopignore`, that
+/// no glob contains. `.*` and braces are left out: `*-lock.*` and
+/// `*.{js,ts}` are globs.
+const REGEX_MARKS: [&str; 5] = ["\\.", "\\d", "\\w", "(", "|"];
+
+/// True if `pattern` reads as one of the regexes older versions took,
+/// such as `.*\.log` or `^target`, rather than as a glob.
+#[must_use]
+pub fn looks_like_regex(pattern: &str) -> bool {
+    let p = pattern.trim();
+    p.starts_with('^')
+        || p.ends_with('$')
+        || p.starts_with(".*")
+        || REGEX_MARKS.iter().any(|m| p.contains(m))
+}
+
+/// The glob meaning what the legacy regex `pattern` was most likely meant
+/// to, or `None` if it isn't one or has no glob equivalent: `.*\.log`
+/// and `\.log$` become `*.log`, and `^target` becomes `/target`.
+#[must_use]
+pub fn legacy_glob(pattern: &str) -> Option<String> {
+    let p = pattern.trim();
+    if !looks_like_regex(p) {
+        return None;
+    }
+    let body = p.strip_prefix('^').unwrap_or(p);
+    let body = body.strip_suffix('$').unwrap_or(body);
+    let mut glob = regex_to_glob(body)?;
+    if !glob.contains('/') {
+        let lead = if p.starts_with('^') { '/' } else { '*' };
+        if !glob.starts_with(['*', '/']) {
+            glob.insert(0, lead);
+        }
+    }
+    (!glob.trim_matches(['*', '/']).is_empty()).then_some(glob)
+}
+
+fn regex_to_glob(regex: &str) -> Option<String> {
+    let mut glob = String::new();
+    let mut chars = regex.chars().peekable();
+    while let Some(c) = chars.next() {
+        glob.push_str(&regex_piece(c, &mut chars)?);
+    }
+    Some(glob)
+}
+
+/// The glob for the regex piece starting with `c`, or `None` if it has none.
+fn regex_piece(c: char, rest: &mut Peekable<Chars>) -> Option<String> {
+    let piece = match c {
+        '\\' => rest
+            .next()
+            .filter(|c| !c.is_ascii_alphanumeric())?
+            .to_string(),
+        '.' if rest.next_if_eq(&'*').is_some() => "*".to_string(),
+        '.' if rest.next_if_eq(&'+').is_some() => "?*".to_string(),
+        '.' => "?".to_string(),
+        '(' | ')' | '|' | '+' | '{' | '}' | '$' | '^' => return None,
+        c => c.to_string(),
+    };
+    Some(piece)
+}
+
+/// Warns, once per process, about `.wardenignore` lines and `ignore_*_on`
+/// entries still written as the regexes older versions took.
+pub fn warn_legacy(config: &Config) {
+    static WARNED: Once = Once::new();
+    WARNED.call_once(|| {
+        let file = fs::read_to_string(files::local(IGNORE_FILE)).unwrap_or_default();
+        for warning in warnings(config, &file) {
+            eprintln!("WARN: {warning}");
+        }
+    });
+}
+
+/// What [`warn_legacy`] says about `config` and the `.wardenignore`
+/// content `ignore_file`.
+#[must_use]
+pub fn warnings(config: &Config, ignore_file: &str) -> Vec<String> {
+    let lines = ignore_file
+        .lines()
+        .filter(|l| !l.trim_start().starts_with('#'))
+        .map(|l| (".wardenignore line", l));
+    let exempt = &config.rules.exempt;
+    let entries = [
+        ("ignore_naming_on entry", &exempt.ignore_naming_on),
+        ("ignore_tokens_on entry", &exempt.ignore_tokens_on),
+        ("ignore_panics_on entry", &exempt.ignore_panics_on),
+    ]
+    .into_iter()
+    .flat_map(|(source, list)| list.iter().map(move |p| (source, p.as_str())));
+    lines
+        .chain(entries)
+        .filter_map(|(source, pattern)| regex_warning(source, pattern.trim()))
+        .collect()
+}
+
+fn regex_warning(source: &str, pattern: &str) -> Option<String> {
+    if !looks_like_regex(pattern) {
+        return None;
+    }
+    let fix = legacy_glob(pattern).map_or_else(
+        || "Rewrite it as a glob.".to_string(),
+        |glob| format!("Run `slopchop migrate` to rewrite it as `{glob}`."),
+    );
+    Some(format!(
+        "{source} `{pattern}` looks like a regex, but patterns are gitignore-style globs. {fix}"
+    ))
+}
@@ -0,0 +1,57 @@
+// src/migrate/mod.rs
+//! `slopchop migrate`: brings a project set up by an older version up to
+//! date and reports each change:
+//!
+//! - `slopchop.toml`, `.slopchopignore` and `.slopchop_intent` take their
+//!   warden names (see [`rename`]);
+//! - the roadmap moves into `tasks.toml`, out of `warden.toml` or a
+//!   hand-kept `ROADMAP.md` (see [`roadmap`]);
+//! - options the schema marks deprecated leave `warden.toml` (see
+//!   [`deprecated`]);
+//! - regex and substring ignore patterns become globs (see [`patterns`]).
+
+pub mod deprecated;
+pub mod legacy;
+pub mod patterns;
+pub mod rename;
+pub mod roadmap;
+
+use std::path::Path;
+
+use colored::Colorize;
+
+use crate::error::Result;
+
+/// Migrates the current directory and prints what changed.
+///
+/// # Errors
+/// Returns error if a file cannot be read, parsed, or written.
+pub fn run(dry_run: bool) -> Result<()> {
+    let changes = run_in(Path::new("."), dry_run)?;
+    if changes.is_empty() {
+        println!("{} Nothing to migrate.", "✓".green());
+        return Ok(());
+    }
+    let mark = if dry_run { "-".yellow() } else { "✓".green() };
+    for change in changes {
+        println!("{mark} {change}");
+    }
+    if dry_run {
+        println!("Dry run: nothing was written.");
+    }
+    Ok(())
+}
+
+/// Migrates the project in `dir`, returning one line per change. With
+/// `dry_run`, nothing is written.
+///
+/// # Errors
+/// Returns error if a file cannot be read, parsed, or written.
+pub fn run_in(dir: &Path, dry_run: bool) -> Result<Vec<String>> {
+    let mut changes = Vec::new();
+    rename::rename(dir, dry_run, &mut changes)?;
+    roadmap::migrate(dir, dry_run, &mut changes)?;
+    deprecated::remove(dir, dry_run, &mut changes)?;
+    patterns::rewrite(dir, dry_run, &mut changes)?;
+    Ok(changes)
+}
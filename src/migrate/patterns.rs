@@ -0,0 +1,130 @@
+// src/migrate/patterns.rs
+//! Rewrites the ignore patterns older versions read differently: regexes
+//! in `.wardenignore` and the `ignore_*_on` lists, and `ignore_*_on`
+//! words that used to match anywhere in a path.
+
+use std::fs;
+use std::path::Path;
+
+use toml_edit::{Array, DocumentMut};
+use walkdir::WalkDir;
+
+use super::legacy::legacy_glob;
+use crate::config::files::{self, CONFIG_FILE, IGNORE_FILE};
+use crate::constants::should_prune;
+use crate::error::{Result, SlopChopError};
+use crate::ignore::IgnoreSet;
+
+const LISTS: [&str; 3] = ["ignore_naming_on", "ignore_tokens_on", "ignore_panics_on"];
+
+/// Rewrites the patterns of `.wardenignore` and `[rules]` in `dir`.
+///
+/// # Errors
+/// Returns error if a file cannot be read, parsed, or written.
+pub fn rewrite(dir: &Path, dry_run: bool, changes: &mut Vec<String>) -> Result<()> {
+    rewrite_ignore_file(dir, dry_run, changes)?;
+    rewrite_lists(dir, dry_run, changes)
+}
+
+/// Turns the regex lines of `.wardenignore` into globs.
+fn rewrite_ignore_file(dir: &Path, dry_run: bool, changes: &mut Vec<String>) -> Result<()> {
+    let path = files::find(dir, IGNORE_FILE);
+    let Ok(content) = fs::read_to_string(&path) else {
+        return Ok(());
+    };
+    let before = changes.len();
+    let mut lines: Vec<String> = Vec::new();
+    for line in content.lines() {
+        match rewrite_line(line) {
+            Some(glob) => {
+                changes.push(format!("{IGNORE_FILE}: `{}` -> `{glob}`", line.trim()));
+                lines.push(glob);
+            }
+            None => lines.push(line.to_string()),
+        }
+    }
+    if changes.len() > before && !dry_run {
+        let newline = if content.ends_with('\n') { "\n" } else { "" };
+        fs::write(&path, format!("{}{newline}", lines.join("\n")))?;
+    }
+    Ok(())
+}
+
+fn rewrite_line(line: &str) -> Option<String> {
+    let line = line.trim();
+    if line.starts_with('#') {
+        return None;
+    }
+    let (bang, body) = line
+        .strip_prefix('!')
+        .map_or(("", line), |rest| ("!", rest));
+    legacy_glob(body).map(|glob| format!("{bang}{glob}"))
+}
+
+/// Rewrites the `ignore_*_on` entries of `warden.toml`, keeping its
+/// layout and comments.
+fn rewrite_lists(dir: &Path, dry_run: bool, changes: &mut Vec<String>) -> Result<()> {
+    let config_path = files::find(dir, CONFIG_FILE);
+    let Ok(content) = fs::read_to_string(&config_path) else {
+        return Ok(());
+    };
+    let mut doc: DocumentMut = content
+        .parse()
+        .map_err(|e| SlopChopError::Other(format!("{CONFIG_FILE}: {e}")))?;
+    let Some(rules) = doc.get_mut("rules").and_then(|r| r.as_table_like_mut()) else {
+        return Ok(());
+    };
+    let before = changes.len();
+    let paths = tree(dir);
+    for key in LISTS {
+        if let Some(list) = rules.get_mut(key).and_then(|l| l.as_array_mut()) {
+            rewrite_list(key, list, &paths, changes);
+        }
+    }
+    if changes.len() > before && !dry_run {
+        fs::write(&config_path, doc.to_string())?;
+    }
+    Ok(())
+}
+
+fn rewrite_list(key: &str, list: &mut Array, paths: &[String], changes: &mut Vec<String>) {
+    for item in list.iter_mut() {
+        let Some(old) = item.as_str().map(str::to_string) else {
+            continue;
+        };
+        let Some(glob) = legacy_glob(&old).or_else(|| substring_glob(&old, paths)) else {
+            continue;
+        };
+        changes.push(format!("{CONFIG_FILE}: {key} `{old}` -> `{glob}`"));
+        let decor = item.decor().clone();
+        *item = glob.into();
+        *item.decor_mut() = decor;
+    }
+}
+
+/// `*word*` for a plain `word` that names nothing in the tree but is part
+/// of some path, which it matched when entries were substrings.
+fn substring_glob(word: &str, paths: &[String]) -> Option<String> {
+    if word.is_empty() || word.contains(['*', '?', '[', '/', '!']) {
+        return None;
+    }
+    let as_name = IgnoreSet::from_patterns(&[word]);
+    let names_nothing = !paths.iter().any(|p| as_name.is_ignored(p));
+    let in_a_path = paths.iter().any(|p| p.contains(word));
+    (names_nothing && in_a_path).then(|| format!("*{word}*"))
+}
+
+/// The files under `dir`, relative to it, outside the directories
+/// discovery never enters.
+fn tree(dir: &Path) -> Vec<String> {
+    WalkDir::new(dir)
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !should_prune(&e.file_name().to_string_lossy()))
+        .filter_map(std::result::Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let rel = e.path().strip_prefix(dir).ok()?;
+            Some(rel.to_string_lossy().replace('\\', "/"))
+        })
+        .collect()
+}
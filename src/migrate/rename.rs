@@ -0,0 +1,51 @@
+// src/migrate/rename.rs
+//! The project files older versions kept under slopchop names:
+//! `slopchop.toml`, `.slopchopignore` and `.slopchop_intent` become
+//! `warden.toml`, `.wardenignore` and `.warden_intent`, in the root and
+//! in every package.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::config::files::RENAMES;
+use crate::error::Result;
+use crate::workspace;
+
+/// Renames the legacy files in `dir` and its packages. A file whose
+/// current name is taken is left alone and reported.
+///
+/// # Errors
+/// Returns error if a file cannot be renamed.
+pub fn rename(dir: &Path, dry_run: bool, changes: &mut Vec<String>) -> Result<()> {
+    let packages = workspace::package_dirs(dir);
+    for prefix in std::iter::once(PathBuf::new()).chain(packages) {
+        for (old, new) in RENAMES {
+            rename_one(dir, &prefix.join(old), &prefix.join(new), dry_run, changes)?;
+        }
+    }
+    Ok(())
+}
+
+fn rename_one(
+    dir: &Path,
+    old: &Path,
+    new: &Path,
+    dry_run: bool,
+    changes: &mut Vec<String>,
+) -> Result<()> {
+    if !dir.join(old).exists() {
+        return Ok(());
+    }
+    let (old_name, new_name) = (old.display(), new.display());
+    if dir.join(new).exists() {
+        changes.push(format!(
+            "{old_name}: kept, {new_name} exists and is read instead"
+        ));
+        return Ok(());
+    }
+    if !dry_run {
+        fs::rename(dir.join(old), dir.join(new))?;
+    }
+    changes.push(format!("{old_name}: renamed to {new_name}"));
+    Ok(())
+}
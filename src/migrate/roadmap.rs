@@ -0,0 +1,143 @@
+// src/migrate/roadmap.rs
+//! The roadmap's older homes: `warden.toml`, where `apply` used to save
+//! it, and a hand-kept `ROADMAP.md` checklist from before `tasks.toml`,
+//! which `roadmap generate` now writes.
+
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+use toml_edit::DocumentMut;
+
+use crate::config::files::{self, CONFIG_FILE};
+use crate::error::{Result, SlopChopError};
+use crate::roadmap_v2::cli::migrate::parse_legacy_roadmap;
+use crate::roadmap_v2::store::DEFAULT_PATH as TASKS_FILE;
+use crate::roadmap_v2::types::{RoadmapMeta, TaskStore};
+
+const ROADMAP_FILE: &str = "ROADMAP.md";
+
+/// The roadmap tables older versions wrote into `warden.toml`.
+const ROADMAP_KEYS: [&str; 3] = ["meta", "sections", "tasks"];
+
+/// Moves the roadmap in `dir` into `tasks.toml`.
+///
+/// # Errors
+/// Returns error if a file cannot be read, parsed, or written.
+pub fn migrate(dir: &Path, dry_run: bool, changes: &mut Vec<String>) -> Result<()> {
+    let tasks_path = dir.join(TASKS_FILE);
+    let mut store = TaskStore::load(Some(&tasks_path))?;
+    let from_markdown = from_markdown(dir, &tasks_path, &mut store, changes);
+    let from_config = from_config(dir, dry_run, &mut store, changes)?;
+    if (from_markdown || from_config) && !dry_run {
+        store.save(Some(&tasks_path))?;
+    }
+    Ok(())
+}
+
+/// Reads the tasks of `ROADMAP.md` into `store` if there is no
+/// `tasks.toml` yet; once there is, `ROADMAP.md` is generated from it.
+/// Returns whether there were any.
+fn from_markdown(
+    dir: &Path,
+    tasks_path: &Path,
+    store: &mut TaskStore,
+    changes: &mut Vec<String>,
+) -> bool {
+    if tasks_path.exists() {
+        return false;
+    }
+    let Ok(content) = fs::read_to_string(dir.join(ROADMAP_FILE)) else {
+        return false;
+    };
+    let legacy = parse_legacy_roadmap(&content);
+    if legacy.tasks.is_empty() {
+        return false;
+    }
+    changes.push(format!(
+        "{TASKS_FILE}: added {} task(s) and {} section(s) from {ROADMAP_FILE}",
+        legacy.tasks.len(),
+        legacy.sections.len()
+    ));
+    *store = legacy;
+    true
+}
+
+/// Moves `[meta]`, `[[sections]]` and `[[tasks]]` from `warden.toml`
+/// into `store`, keeping what it already has. Returns whether there were
+/// any.
+fn from_config(
+    dir: &Path,
+    dry_run: bool,
+    store: &mut TaskStore,
+    changes: &mut Vec<String>,
+) -> Result<bool> {
+    let config_path = files::find(dir, CONFIG_FILE);
+    let Ok(content) = fs::read_to_string(&config_path) else {
+        return Ok(false);
+    };
+    let invalid = |e: &dyn std::fmt::Display| SlopChopError::Other(format!("{CONFIG_FILE}: {e}"));
+    let table: toml::Table = toml::from_str(&content).map_err(|e| invalid(&e))?;
+    let mut doc: DocumentMut = content.parse().map_err(|e| invalid(&e))?;
+
+    let mut legacy = toml::Table::new();
+    for key in ROADMAP_KEYS {
+        if let Some(value) = table.get(key) {
+            legacy.insert(key.to_string(), value.clone());
+            doc.remove(key);
+        }
+    }
+    if legacy.is_empty() {
+        return Ok(false);
+    }
+    let removed: Vec<&str> = ROADMAP_KEYS
+        .into_iter()
+        .filter(|k| legacy.contains_key(*k))
+        .collect();
+    if !legacy.contains_key("meta") {
+        let meta = toml::Value::try_from(RoadmapMeta::default()).map_err(|e| invalid(&e))?;
+        legacy.insert("meta".to_string(), meta);
+    }
+    let moved: TaskStore = toml::Value::Table(legacy)
+        .try_into()
+        .map_err(|e| invalid(&format!("roadmap tables are invalid: {e}")))?;
+
+    let offered = moved.tasks.len();
+    let (sections, tasks) = merge(store, moved);
+    let mut added =
+        format!("{TASKS_FILE}: added {tasks} task(s) and {sections} section(s) from {CONFIG_FILE}");
+    if tasks < offered {
+        let _ = write!(added, " ({} already there)", offered - tasks);
+    }
+    changes.push(added);
+    changes.push(format!("{CONFIG_FILE}: removed `{}`", removed.join("`, `")));
+
+    if !dry_run {
+        fs::write(&config_path, doc.to_string())?;
+    }
+    Ok(true)
+}
+
+/// Adds the sections and tasks of `from` whose ids `store` lacks, and its
+/// title if `store` has none. Returns how many sections and tasks were
+/// added.
+pub fn merge(store: &mut TaskStore, from: TaskStore) -> (usize, usize) {
+    if store.meta.title.is_empty() {
+        store.meta = from.meta;
+    }
+    let before = (store.sections.len(), store.tasks.len());
+    for section in from.sections {
+        if !store.sections.iter().any(|s| s.id == section.id) {
+            store.sections.push(section);
+        }
+    }
+    for task in from.tasks {
+        if !store.tasks.iter().any(|t| t.id == task.id) {
+            store.tasks.push(task);
+        }
+    }
+    (
+        store.sections.len() - before.0,
+        store.tasks.len() - before.1,
+    )
+}
@@ -0,0 +1,137 @@
+// src/pack/assets.rs
+//! `pack --assets`: lists the files discovery left out (images, binaries,
+//! files outside `--code-only`) with their sizes and types, so the AI knows
+//! they exist.
+
+use std::fmt::Write;
+use std::fs;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use super::OutputFormat;
+use crate::config::Config;
+use crate::discovery;
+
+/// A file that is in the project but not in the pack.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Asset {
+    pub path: PathBuf,
+    pub bytes: u64,
+    pub kind: &'static str,
+}
+
+const KINDS: &[(&str, &[&str])] = &[
+    (
+        "image",
+        &["png", "jpg", "jpeg", "gif", "svg", "ico", "webp", "bmp"],
+    ),
+    ("font", &["woff", "woff2", "ttf", "otf", "eot"]),
+    (
+        "archive",
+        &["zip", "gz", "tar", "tgz", "bz2", "xz", "7z", "jar"],
+    ),
+    ("media", &["mp3", "mp4", "wav", "ogg", "webm", "mov"]),
+    (
+        "document",
+        &["pdf", "doc", "docx", "xls", "xlsx", "ppt", "pptx"],
+    ),
+    (
+        "compiled",
+        &[
+            "exe", "dll", "so", "dylib", "o", "a", "class", "pyc", "wasm",
+        ],
+    ),
+];
+
+/// What kind of file `path` is, by extension, else by sniffing its content.
+#[must_use]
+pub fn kind(path: &Path) -> &'static str {
+    let ext = path
+        .extension()
+        .map(|e| e.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    if let Some((kind, _)) = KINDS.iter().find(|(_, exts)| exts.contains(&ext.as_str())) {
+        return kind;
+    }
+    if is_binary(path) {
+        "binary"
+    } else {
+        "text"
+    }
+}
+
+fn is_binary(path: &Path) -> bool {
+    let mut head = [0u8; 8192];
+    let n = fs::File::open(path)
+        .and_then(|mut f| f.read(&mut head))
+        .unwrap_or(0);
+    head[..n].contains(&0)
+}
+
+/// The discovered-but-skipped files, sorted by path.
+///
+/// # Errors
+/// Returns error if enumeration fails.
+pub fn collect(config: &Config, files: &[PathBuf]) -> Result<Vec<Asset>> {
+    let mut assets: Vec<Asset> = discovery::skipped(config, files)?
+        .into_iter()
+        .map(|path| Asset {
+            bytes: fs::metadata(&path).map_or(0, |m| m.len()),
+            kind: kind(&path),
+            path,
+        })
+        .collect();
+    assets.sort_by(|a, b| a.path.cmp(&b.path));
+    Ok(assets)
+}
+
+/// One line per asset: path, type, and size.
+#[must_use]
+pub fn render(assets: &[Asset]) -> String {
+    let width = assets
+        .iter()
+        .map(|a| a.path.to_string_lossy().len())
+        .max()
+        .unwrap_or(0);
+    let mut out = String::new();
+    for a in assets {
+        let path = a.path.to_string_lossy().replace('\\', "/");
+        let _ = writeln!(out, "{path:<width$}  {:<8}  {}", a.kind, size(a.bytes));
+    }
+    out
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn size(bytes: u64) -> String {
+    match bytes {
+        0..1024 => format!("{bytes} B"),
+        1024..1_048_576 => format!("{:.1} KB", bytes as f64 / 1024.0),
+        _ => format!("{:.1} MB", bytes as f64 / 1_048_576.0),
+    }
+}
+
+/// Writes the assets section; nothing if every file was packed.
+///
+/// # Errors
+/// Returns error if enumeration or writing fails.
+pub fn write(
+    out: &mut String,
+    config: &Config,
+    files: &[PathBuf],
+    format: &OutputFormat,
+) -> Result<()> {
+    let assets = collect(config, files)?;
+    if assets.is_empty() {
+        return Ok(());
+    }
+    let list = render(&assets);
+    match format {
+        OutputFormat::Text => writeln!(out, "# ═══ ASSETS (not packed) ═══\n\n{list}")?,
+        OutputFormat::Xml => writeln!(out, "<assets><![CDATA[\n{list}]]></assets>")?,
+        OutputFormat::ClaudeXml => writeln!(out, "<assets>\n{list}</assets>")?,
+        OutputFormat::Openai => writeln!(out, "## Assets (not packed)\n\n```text\n{list}```\n")?,
+    }
+    Ok(())
+}
@@ -0,0 +1,239 @@
+// src/pack/budget.rs
+//! `pack --budget`: when the context is over the token budget, files are
+//! skeletonized or dropped, lowest priority first, until it fits.
+
+use std::cmp::Reverse;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::{cache, filter, FocusContext, PackOptions};
+use crate::config::Config;
+use crate::discovery;
+use crate::tokens::Tokenizer;
+
+/// Token limits for the pack (`--budget`, `--window`, `--strict-budget`).
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// Trim files, lowest priority first, to fit this many tokens.
+    pub limit: Option<usize>,
+    /// Context window to warn against; else `[tokens] window` or the model's.
+    pub window: Option<usize>,
+    /// Fail instead of warning when the pack is over the window or limit.
+    pub strict: bool,
+}
+
+/// How much a file matters to the pack, lowest first.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Tier {
+    Docs,
+    Tests,
+    Other,
+    Core,
+    Changed,
+    Focused,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TrimAction {
+    Skeleton,
+    Drop,
+}
+
+/// A file cut down to fit the budget, and the tokens that saved.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Trim {
+    pub path: PathBuf,
+    pub action: TrimAction,
+    pub saved: usize,
+}
+
+/// A packed file's priority and its size in full and as a skeleton.
+#[derive(Debug, Clone)]
+pub struct Entry {
+    pub path: PathBuf,
+    pub tier: Tier,
+    pub full: usize,
+    pub skeleton: usize,
+    /// Already packed as a skeleton (`--skeleton`, `--target`, focus).
+    pub skeletonized: bool,
+}
+
+impl Entry {
+    fn cost(&self) -> usize {
+        if self.skeletonized {
+            self.skeleton
+        } else {
+            self.full
+        }
+    }
+}
+
+/// Generates the pack, trimmed to `opts.budget.limit` if it is over, and lists
+/// what was trimmed.
+///
+/// # Errors
+/// Returns error if file reading fails.
+pub fn generate(
+    files: &[PathBuf],
+    opts: &PackOptions,
+    config: &Config,
+) -> Result<(String, Vec<Trim>)> {
+    let content = super::generate_content(files, opts, config)?;
+    let over = opts.budget.limit.map_or(0, |budget| {
+        Tokenizer::count(&content).saturating_sub(budget)
+    });
+    if over == 0 {
+        return Ok((content, Vec::new()));
+    }
+
+    let (focus, pack_files) = super::build_focus_context(files, opts);
+    let changed: HashSet<PathBuf> = discovery::changed_files(None).unwrap_or_default();
+    let entries: Vec<Entry> = pack_files
+        .iter()
+        .map(|p| entry(p, &focus, &changed, opts))
+        .collect();
+    let trims = plan(&entries, over);
+
+    let (focus, kept) = apply(&entries, &trims);
+    let content = super::render(files, &kept, &focus, opts, config)?;
+    Ok((content, trims))
+}
+
+/// Where `path` falls in the priority order: focused files, then changed
+/// files, core source, everything else, tests, and docs last.
+#[must_use]
+#[allow(clippy::implicit_hasher)]
+pub fn tier(path: &Path, focused: bool, changed: &HashSet<PathBuf>) -> Tier {
+    if focused {
+        return Tier::Focused;
+    }
+    if changed.contains(path) {
+        return Tier::Changed;
+    }
+    if is_doc(path) {
+        return Tier::Docs;
+    }
+    if filter::is_test(path) {
+        return Tier::Tests;
+    }
+    if filter::has_dir(path, "src") || filter::has_dir(path, "lib") {
+        return Tier::Core;
+    }
+    Tier::Other
+}
+
+fn is_doc(path: &Path) -> bool {
+    let ext = path.extension().map(|e| e.to_string_lossy().to_lowercase());
+    matches!(ext.as_deref(), Some("md" | "txt" | "rst" | "adoc")) || filter::has_dir(path, "docs")
+}
+
+/// Picks trims, lowest tier and biggest file first, until `over` tokens
+/// are saved. Each file is skeletonized if that helps, else dropped; if
+/// that is not enough, skeletonized files are dropped in the same order.
+#[must_use]
+pub fn plan(entries: &[Entry], over: usize) -> Vec<Trim> {
+    let mut order: Vec<&Entry> = entries.iter().collect();
+    order.sort_by_key(|e| (e.tier, Reverse(e.cost())));
+
+    let mut remaining = over;
+    let mut trims = Vec::new();
+    for e in &order {
+        if remaining == 0 {
+            return trims;
+        }
+        let trim = first_trim(e);
+        remaining = remaining.saturating_sub(trim.saved);
+        trims.push(trim);
+    }
+
+    for (e, trim) in order.iter().zip(trims.iter_mut()) {
+        if remaining == 0 {
+            break;
+        }
+        if trim.action == TrimAction::Skeleton {
+            remaining = remaining.saturating_sub(e.skeleton);
+            trim.action = TrimAction::Drop;
+            trim.saved = e.cost();
+        }
+    }
+    trims
+}
+
+fn first_trim(e: &Entry) -> Trim {
+    let (action, saved) = if !e.skeletonized && e.skeleton < e.full {
+        (TrimAction::Skeleton, e.full - e.skeleton)
+    } else {
+        (TrimAction::Drop, e.cost())
+    };
+    Trim {
+        path: e.path.clone(),
+        action,
+        saved,
+    }
+}
+
+fn entry(
+    path: &Path,
+    focus: &FocusContext,
+    changed: &HashSet<PathBuf>,
+    opts: &PackOptions,
+) -> Entry {
+    let content = fs::read_to_string(path).unwrap_or_default();
+    let focused = focus.foveal.contains(path);
+    let skeletonized = super::formats::packs_as_skeleton(path, focus, opts);
+    Entry {
+        path: path.to_path_buf(),
+        tier: tier(path, focused, changed),
+        full: cache::tokens(path, &content),
+        skeleton: cache::skeleton_tokens(path, &content),
+        skeletonized,
+    }
+}
+
+/// The files left after `trims`, split into full and skeleton sets.
+fn apply(entries: &[Entry], trims: &[Trim]) -> (FocusContext, Vec<PathBuf>) {
+    let action = |p: &Path| trims.iter().find(|t| t.path == p).map(|t| t.action);
+    let mut focus = FocusContext {
+        foveal: HashSet::new(),
+        peripheral: HashSet::new(),
+    };
+    let mut kept = Vec::new();
+    for e in entries {
+        let set = match action(&e.path) {
+            Some(TrimAction::Drop) => continue,
+            Some(TrimAction::Skeleton) => &mut focus.peripheral,
+            None if e.skeletonized => &mut focus.peripheral,
+            None => &mut focus.foveal,
+        };
+        set.insert(e.path.clone());
+        kept.push(e.path.clone());
+    }
+    (focus, kept)
+}
+
+/// Prints what was trimmed to fit the budget.
+pub fn print(trims: &[Trim], budget: usize) {
+    if trims.is_empty() {
+        return;
+    }
+    let saved: usize = trims.iter().map(|t| t.saved).sum();
+    eprintln!(
+        "{}",
+        format!(
+            "✂️  Over the {budget} token budget; trimmed {} files (~{saved} tokens):",
+            trims.len()
+        )
+        .yellow()
+    );
+    for t in trims {
+        let label = match t.action {
+            TrimAction::Skeleton => "skeleton",
+            TrimAction::Drop => "dropped",
+        };
+        eprintln!("   {label:<8} {} (-{})", t.path.display(), t.saved);
+    }
+}
@@ -0,0 +1,137 @@
+// src/pack/cache.rs
+//! Pack cache: each file's token count and skeleton, keyed by a hash of its
+//! path, content, and the token model, so repeated packs only re-tokenize and re-skeletonize
+//! files that changed. Stored next to the scan cache; entries not used by a
+//! pack are dropped when it is saved.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use std::sync::{LazyLock, Mutex};
+
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::analysis::cache::{fnv1a, CACHE_DIR};
+use crate::skeleton;
+use crate::tokens::Tokenizer;
+
+const CACHE_FILE: &str = "pack.json";
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct Entry {
+    tokens: Option<usize>,
+    skeleton: Option<String>,
+    skeleton_tokens: Option<usize>,
+}
+
+#[derive(Default, Serialize, Deserialize)]
+struct Stored {
+    version: String,
+    files: HashMap<u64, Entry>,
+}
+
+#[derive(Default)]
+struct Memo {
+    loaded: HashMap<u64, Entry>,
+    used: HashMap<u64, Entry>,
+}
+
+static MEMO: LazyLock<Mutex<Memo>> = LazyLock::new(|| Mutex::new(load(Path::new(CACHE_DIR))));
+
+fn load(dir: &Path) -> Memo {
+    let stored: Stored = fs::read_to_string(dir.join(CACHE_FILE))
+        .ok()
+        .and_then(|s| serde_json::from_str(&s).ok())
+        .unwrap_or_default();
+    let loaded = if stored.version == env!("CARGO_PKG_VERSION") {
+        stored.files
+    } else {
+        HashMap::new()
+    };
+    Memo {
+        loaded,
+        used: HashMap::new(),
+    }
+}
+
+/// Token count of `content`, the text of `path`.
+#[must_use]
+pub fn tokens(path: &Path, content: &str) -> usize {
+    cached(
+        path,
+        content,
+        |e| &mut e.tokens,
+        || Tokenizer::count(content),
+    )
+}
+
+/// Skeleton of `content`, the text of `path`.
+#[must_use]
+pub fn skeleton(path: &Path, content: &str) -> String {
+    cached(
+        path,
+        content,
+        |e| &mut e.skeleton,
+        || skeleton::clean(path, content),
+    )
+}
+
+/// Token count of the skeleton of `content`.
+#[must_use]
+pub fn skeleton_tokens(path: &Path, content: &str) -> usize {
+    cached(
+        path,
+        content,
+        |e| &mut e.skeleton_tokens,
+        || Tokenizer::count(&skeleton(path, content)),
+    )
+}
+
+/// Looks up one field of the entry for `path` and `content`, computing it
+/// with `make` if it is missing.
+fn cached<T: Clone>(
+    path: &Path,
+    content: &str,
+    field: impl Fn(&mut Entry) -> &mut Option<T>,
+    make: impl FnOnce() -> T,
+) -> T {
+    let key = fnv1a(&[
+        Tokenizer::model().name().as_bytes(),
+        path.to_string_lossy().as_bytes(),
+        content.as_bytes(),
+    ]);
+    let hit = MEMO.lock().ok().and_then(|mut memo| {
+        let entry = memo.loaded.remove(&key).unwrap_or_default();
+        let entry = memo.used.entry(key).or_insert(entry);
+        field(entry).clone()
+    });
+    if let Some(value) = hit {
+        return value;
+    }
+    let value = make();
+    if let Ok(mut memo) = MEMO.lock() {
+        *field(memo.used.entry(key).or_default()) = Some(value.clone());
+    }
+    value
+}
+
+/// Writes the entries used since the cache was loaded.
+///
+/// # Errors
+/// Returns error if the cache directory or file cannot be written.
+pub fn save() -> Result<()> {
+    let files = MEMO.lock().map(|m| m.used.clone()).unwrap_or_default();
+    if files.is_empty() {
+        return Ok(());
+    }
+    let stored = Stored {
+        version: env!("CARGO_PKG_VERSION").to_string(),
+        files,
+    };
+    let dir = Path::new(CACHE_DIR);
+    fs::create_dir_all(dir)?;
+    fs::write(dir.join(".gitignore"), "*\n")?;
+    fs::write(dir.join(CACHE_FILE), serde_json::to_string(&stored)?)?;
+    Ok(())
+}
@@ -0,0 +1,138 @@
+// src/pack/chunk.rs
+//! Splits a context too big for one attachment into numbered parts, each
+//! saying where it falls, written as `context_partN.txt`.
+
+use std::fs;
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::stats;
+use crate::clipboard;
+use crate::tokens::Tokenizer;
+
+/// Tokens kept free in each part for its header and footer.
+const HEADER_RESERVE: usize = 64;
+
+/// Splits `content` into parts of about `limit` tokens, breaking between
+/// packed files where possible, and heads each with its position.
+#[must_use]
+pub fn split(content: &str, limit: usize) -> Vec<String> {
+    let limit = limit.saturating_sub(HEADER_RESERVE).max(1);
+    let mut units: Vec<String> = Vec::new();
+    let mut unit = String::new();
+    for line in content.split_inclusive('\n') {
+        if stats::opens_file(line) {
+            units.push(std::mem::take(&mut unit));
+        }
+        unit.push_str(line);
+    }
+    units.push(unit);
+
+    let pieces: Vec<String> = units
+        .iter()
+        .flat_map(|u| fill(u.split_inclusive('\n'), limit))
+        .collect();
+    let bodies = fill(pieces.iter().map(String::as_str), limit);
+    let total = bodies.len();
+    bodies
+        .iter()
+        .enumerate()
+        .map(|(i, body)| with_header(body, i + 1, total))
+        .collect()
+}
+
+/// Joins `items` greedily into strings of at most `limit` tokens; an item
+/// bigger than `limit` stands alone.
+fn fill<'a>(items: impl Iterator<Item = &'a str>, limit: usize) -> Vec<String> {
+    let mut out = Vec::new();
+    let mut current = String::new();
+    let mut tokens = 0;
+    for item in items.filter(|i| !i.is_empty()) {
+        let n = Tokenizer::count(item);
+        if tokens + n > limit && !current.is_empty() {
+            out.push(std::mem::take(&mut current));
+            tokens = 0;
+        }
+        current.push_str(item);
+        tokens += n;
+    }
+    if !current.is_empty() {
+        out.push(current);
+    }
+    out
+}
+
+fn with_header(body: &str, part: usize, total: usize) -> String {
+    if part < total {
+        format!(
+            "# ═══ CONTEXT PART {part} OF {total} ═══\n\
+             # More parts follow. Reply only \"ok, next\" until you have all {total}.\n\n\
+             {body}\n# ═══ END OF PART {part} OF {total} ═══\n"
+        )
+    } else {
+        format!(
+            "# ═══ CONTEXT PART {part} OF {total} ═══\n\
+             # This is the last part; you now have the whole context.\n\n{body}"
+        )
+    }
+}
+
+/// Writes the parts to `dir` as `context_partN.txt`, removing leftover
+/// parts from an earlier, longer split.
+///
+/// # Errors
+/// Returns error if a part cannot be written.
+pub fn write_parts(parts: &[String], dir: &Path) -> Result<Vec<PathBuf>> {
+    let mut paths = Vec::new();
+    for (i, part) in parts.iter().enumerate() {
+        let path = dir.join(part_name(i + 1));
+        fs::write(&path, part)?;
+        paths.push(path);
+    }
+    let mut stale = parts.len() + 1;
+    while fs::remove_file(dir.join(part_name(stale))).is_ok() {
+        stale += 1;
+    }
+    Ok(paths)
+}
+
+fn part_name(n: usize) -> String {
+    format!("context_part{n}.txt")
+}
+
+/// Writes the parts and, with `copy`, copies them one at a time, waiting
+/// for Enter before each one after the first.
+///
+/// # Errors
+/// Returns error if writing, reading stdin, or the clipboard fails.
+pub fn output(content: &str, limit: usize, copy: bool, info: &str) -> Result<()> {
+    let parts = split(content, limit);
+    for path in write_parts(&parts, Path::new("."))? {
+        println!("✅ Generated '{}'", path.display());
+    }
+    println!("{info} in {} parts of up to {limit}", parts.len());
+    if copy {
+        copy_parts(&parts, &mut io::stdin().lock())?;
+    }
+    Ok(())
+}
+
+fn copy_parts(parts: &[String], input: &mut impl BufRead) -> Result<()> {
+    let total = parts.len();
+    for (i, part) in parts.iter().enumerate() {
+        if i > 0 {
+            print!("Press Enter to copy part {}/{total} (q to stop): ", i + 1);
+            io::stdout().flush()?;
+            let mut line = String::new();
+            if input.read_line(&mut line)? == 0 || line.trim() == "q" {
+                break;
+            }
+        }
+        clipboard::smart_copy(part)?;
+        println!("{}", format!("✓ Copied part {}/{total}", i + 1).green());
+    }
+    Ok(())
+}
@@ -0,0 +1,204 @@
+// src/pack/comments.rs
+//! `pack --strip-comments [keep-docs|all]`: drops comments from fully
+//! packed files to save tokens. Lines left empty are removed, but each kept
+//! line remembers its number for `--line-numbers`.
+//!
+//! Stripped files are marked in the pack and listed in the cache, so
+//! `apply` can refuse a whole-file reply that would drop their comments.
+
+use std::fs;
+use std::ops::Range;
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum;
+use colored::Colorize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tree_sitter::{Node, Parser};
+
+use super::{formats, PackOptions};
+use crate::analysis::cache::CACHE_DIR;
+use crate::lang::Lang;
+use crate::tokens::Tokenizer;
+
+/// Lists the files the last pack stripped, one per line, in `CACHE_DIR`.
+pub const STRIPPED_FILE: &str = "stripped.txt";
+
+/// Opens a pack with stripped files in it.
+pub const NOTE: &str = "Files marked [STRIPPED] are shown without their comments. Change them only with #__SLOPCHOP_EDIT__# blocks; a whole file would delete the comments and is rejected.";
+
+/// Marks one stripped file in the `claude-xml` and `openai` formats.
+pub const FILE_NOTE: &str =
+    "Comments stripped; change this file only with #__SLOPCHOP_EDIT__# blocks.";
+
+/// Which comments `pack` removes.
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum StripComments {
+    #[default]
+    Off,
+    /// Everything but doc comments.
+    KeepDocs,
+    /// Doc comments too.
+    All,
+}
+
+/// `content` without its comments, as `(line number, text)` pairs. Lines
+/// that held only comments are dropped; files in unsupported languages
+/// come back unchanged.
+#[must_use]
+pub fn strip_lines(path: &Path, content: &str, mode: StripComments) -> Vec<(usize, String)> {
+    let ranges = comment_ranges(path, content, mode);
+    let mut out = Vec::new();
+    let mut offset = 0;
+    for (i, raw) in content.split_inclusive('\n').enumerate() {
+        let line = raw.trim_end_matches(['\n', '\r']);
+        let span = offset..offset + line.len();
+        offset += raw.len();
+        let touched = ranges
+            .iter()
+            .any(|r| r.start < span.end && r.end > span.start);
+        if !touched {
+            out.push((i + 1, line.to_string()));
+            continue;
+        }
+        let kept = cut(content, &span, &ranges);
+        if !kept.trim().is_empty() {
+            out.push((i + 1, kept.trim_end().to_string()));
+        }
+    }
+    out
+}
+
+/// True if `lines` from [`strip_lines`] differ from `content`.
+#[must_use]
+pub fn changed(content: &str, lines: &[(usize, String)]) -> bool {
+    lines.len() != content.lines().count()
+        || lines.iter().zip(content.lines()).any(|((_, a), b)| a != b)
+}
+
+/// `content` without its comments.
+#[must_use]
+pub fn strip(path: &Path, content: &str, mode: StripComments) -> String {
+    strip_lines(path, content, mode)
+        .into_iter()
+        .map(|(_, line)| line + "\n")
+        .collect()
+}
+
+/// Records the files packed in full that lost comments, and prints how
+/// many tokens stripping saved on them.
+pub fn finish(files: &[PathBuf], opts: &PackOptions) {
+    let mode = opts.extras.strip_comments;
+    let (focus, pack_files) = super::build_focus_context(files, opts);
+    let mut saved = 0;
+    let mut stripped = Vec::new();
+    for (path, content) in pack_files
+        .iter()
+        .filter(|p| mode != StripComments::Off && !formats::packs_as_skeleton(p, &focus, opts))
+        .filter_map(|p| Some((p, fs::read_to_string(p).ok()?)))
+    {
+        let text = strip(path, &content, mode);
+        if text != content {
+            saved += Tokenizer::count(&content).saturating_sub(Tokenizer::count(&text));
+            stripped.push(path.to_string_lossy().replace('\\', "/"));
+        }
+    }
+    if let Err(e) = record(Path::new("."), &stripped) {
+        eprintln!("WARN: Could not record stripped files: {e}");
+    }
+    if mode != StripComments::Off {
+        let note = format!("✂️  Stripped comments (~{saved} tokens saved)");
+        eprintln!("{}", note.dimmed());
+    }
+}
+
+/// Replaces the list of stripped files under `root`.
+///
+/// # Errors
+/// Returns error if the cache directory cannot be written.
+pub fn record(root: &Path, paths: &[String]) -> std::io::Result<()> {
+    let file = root.join(CACHE_DIR).join(STRIPPED_FILE);
+    if paths.is_empty() {
+        return match fs::remove_file(file) {
+            Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+            _ => Ok(()),
+        };
+    }
+    fs::create_dir_all(root.join(CACHE_DIR))?;
+    fs::write(file, paths.join("\n") + "\n")
+}
+
+/// The files the last pack under `root` stripped.
+#[must_use]
+pub fn stripped(root: &Path) -> Vec<String> {
+    fs::read_to_string(root.join(CACHE_DIR).join(STRIPPED_FILE))
+        .map(|s| s.lines().map(str::to_string).collect())
+        .unwrap_or_default()
+}
+
+/// The text of `span` with the parts inside `ranges` cut out.
+fn cut(content: &str, span: &Range<usize>, ranges: &[Range<usize>]) -> String {
+    let mut kept = String::new();
+    let mut pos = span.start;
+    for r in ranges
+        .iter()
+        .filter(|r| r.start < span.end && r.end > span.start)
+    {
+        if r.start > pos {
+            kept.push_str(&content[pos..r.start]);
+        }
+        pos = pos.max(r.end.min(span.end));
+    }
+    if pos < span.end {
+        kept.push_str(&content[pos..span.end]);
+    }
+    kept
+}
+
+/// Byte ranges of the comments to remove, in order.
+fn comment_ranges(path: &Path, content: &str, mode: StripComments) -> Vec<Range<usize>> {
+    let lang = path
+        .extension()
+        .and_then(|e| e.to_str())
+        .and_then(Lang::from_ext);
+    let mut parser = Parser::new();
+    let tree = lang
+        .filter(|l| parser.set_language(&l.grammar()).is_ok() && mode != StripComments::Off)
+        .and_then(|_| parser.parse(content, None));
+    let Some(tree) = tree else {
+        return Vec::new();
+    };
+    let mut ranges = Vec::new();
+    let mut stack = vec![tree.root_node()];
+    while let Some(node) = stack.pop() {
+        if !node.kind().contains("comment") {
+            let mut cursor = node.walk();
+            stack.extend(node.children(&mut cursor));
+            continue;
+        }
+        if removable(node, content, mode) {
+            ranges.push(node.byte_range());
+        }
+    }
+    ranges.sort_by_key(|r| r.start);
+    ranges
+}
+
+fn removable(node: Node, content: &str, mode: StripComments) -> bool {
+    let text = &content[node.byte_range()];
+    if node.start_byte() == 0 && text.starts_with("#!") {
+        return false;
+    }
+    mode == StripComments::All || !is_doc(text)
+}
+
+/// `///`, `//!`, `/**`, and `/*!` comments.
+fn is_doc(text: &str) -> bool {
+    (text.starts_with("///") && !text.starts_with("////"))
+        || text.starts_with("//!")
+        || (text.starts_with("/**") && !text.starts_with("/**/"))
+        || text.starts_with("/*!")
+}
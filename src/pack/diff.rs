@@ -0,0 +1,72 @@
+// src/pack/diff.rs
+//! `pack --diff [BASE]`: the diff since `BASE`, the changed files in full,
+//! and their dependencies as skeletons, instead of the whole repo.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+use std::process::Command;
+
+use anyhow::{bail, Result};
+
+use super::{presets, OutputFormat, PackOptions};
+use crate::discovery;
+
+/// `opts` with the files changed since `opts.diff` added to the focus, so
+/// they are packed in full and everything unrelated is left out.
+///
+/// # Errors
+/// Returns error if git fails, e.g. on an unknown ref, or nothing changed.
+pub fn focus_changes(opts: &PackOptions, files: &[PathBuf]) -> Result<PackOptions> {
+    let mut opts = opts.clone();
+    let Some(base) = &opts.diff else {
+        return Ok(opts);
+    };
+    let changed = discovery::changed_files(Some(base))?;
+    let before = opts.focus.len();
+    opts.focus
+        .extend(files.iter().filter(|f| changed.contains(*f)).cloned());
+    if opts.focus.len() == before {
+        bail!("No changes since {base}.");
+    }
+    Ok(opts)
+}
+
+/// The diff from where this branch left `base` to the working tree.
+///
+/// # Errors
+/// Returns error if a git command fails.
+pub fn diff_text(base: &str) -> Result<String> {
+    let merge_base = git(&["merge-base", base, "HEAD"])?;
+    git(&["diff", "--relative", merge_base.trim()])
+}
+
+/// Writes the diff section that opens a `--diff` pack.
+///
+/// # Errors
+/// Returns error if the diff cannot be read.
+pub fn write_section(out: &mut String, base: &str, format: &OutputFormat) -> Result<()> {
+    let diff = diff_text(base)?;
+    match format {
+        OutputFormat::Text => writeln!(out, "# ═══ DIFF (since {base}) ═══\n\n{diff}")?,
+        OutputFormat::Xml => writeln!(
+            out,
+            "<diff base=\"{base}\"><![CDATA[{}]]></diff>",
+            diff.replace("]]>", "]]]]><![CDATA[>")
+        )?,
+        OutputFormat::ClaudeXml => writeln!(out, "<diff base=\"{base}\">\n{diff}</diff>")?,
+        OutputFormat::Openai => {
+            let fence = presets::fence(&diff);
+            writeln!(out, "## Diff since {base}\n\n{fence}diff\n{diff}{fence}\n")?;
+        }
+    }
+    Ok(())
+}
+
+pub(super) fn git(args: &[&str]) -> Result<String> {
+    let out = Command::new("git").args(args).output()?;
+    if !out.status.success() {
+        let err = String::from_utf8_lossy(&out.stderr);
+        bail!("git {} failed: {}", args.join(" "), err.trim());
+    }
+    Ok(String::from_utf8_lossy(&out.stdout).into_owned())
+}
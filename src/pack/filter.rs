@@ -0,0 +1,56 @@
+// src/pack/filter.rs
+//! `pack --code-only`, `--no-tests`, and `--no-generated`: common ways to
+//! trim the context without writing ignore patterns.
+
+use std::path::{Path, PathBuf};
+
+use crate::ignore::IgnoreSet;
+
+/// Which kinds of discovered files are left out of the pack.
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FileFilter {
+    /// Only source code (`--code-only`).
+    pub code_only: bool,
+    /// Leave out test files and `tests/` directories (`--no-tests`).
+    pub no_tests: bool,
+    /// Leave out files matching `[pack] generated` (`--no-generated`).
+    pub no_generated: bool,
+}
+
+/// `files` without the tests and generated files `filter` excludes.
+#[must_use]
+pub fn apply(files: Vec<PathBuf>, filter: FileFilter, generated: &[String]) -> Vec<PathBuf> {
+    let generated = IgnoreSet::from_patterns(generated);
+    files
+        .into_iter()
+        .filter(|f| !(filter.no_tests && is_test(f)))
+        .filter(|f| !(filter.no_generated && generated.is_ignored(&f.to_string_lossy())))
+        .collect()
+}
+
+/// True for files under `tests`/`test` and for `test_*`, `*_test.*`,
+/// `*.test.*`, and `*.spec.*` names.
+#[must_use]
+pub fn is_test(path: &Path) -> bool {
+    let name = path
+        .file_name()
+        .map_or(String::new(), |n| n.to_string_lossy().to_lowercase());
+    has_dir(path, "tests")
+        || has_dir(path, "test")
+        || name.starts_with("test_")
+        || ["_test.", ".test.", ".spec."]
+            .iter()
+            .any(|m| name.contains(m))
+}
+
+/// True if `path` is matched by the `generated` patterns, read like
+/// `.wardenignore` lines.
+#[must_use]
+pub fn is_generated(path: &Path, generated: &[String]) -> bool {
+    IgnoreSet::from_patterns(generated).is_ignored(&path.to_string_lossy())
+}
+
+pub(super) fn has_dir(path: &Path, name: &str) -> bool {
+    path.components().any(|c| c.as_os_str() == name)
+}
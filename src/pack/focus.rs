@@ -2,11 +2,45 @@
 //! Focus mode computation for foveal/peripheral packing.
 
 use std::collections::{HashMap, HashSet};
+use std::env;
 use std::fs;
-use std::path::PathBuf;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::{bail, Result};
 
 use crate::graph::rank::RepoGraph;
 
+/// Maps each `--focus` path onto the discovered file it names, accepting
+/// `./` prefixes, `\` separators, and absolute paths inside the project.
+///
+/// # Errors
+/// Returns error if a focus path is not among the discovered files.
+pub fn resolve(focus: &[PathBuf], files: &[PathBuf]) -> Result<Vec<PathBuf>> {
+    let cwd = env::current_dir().unwrap_or_default();
+    let known: HashMap<PathBuf, &PathBuf> = files.iter().map(|f| (normalize(f, &cwd), f)).collect();
+    let mut resolved = Vec::new();
+    for f in focus {
+        let Some(found) = known.get(&normalize(f, &cwd)) else {
+            bail!(
+                "Focus file not found (or excluded from the pack): {}",
+                f.display()
+            );
+        };
+        resolved.push((*found).clone());
+    }
+    Ok(resolved)
+}
+
+/// `path` relative to `cwd`, with `/` separators and no `./` parts.
+#[must_use]
+pub fn normalize(path: &Path, cwd: &Path) -> PathBuf {
+    let path = PathBuf::from(path.to_string_lossy().replace('\\', "/"));
+    let rel = path.strip_prefix(cwd).unwrap_or(&path);
+    rel.components()
+        .filter(|c| !matches!(c, Component::CurDir))
+        .collect()
+}
+
 /// Computes foveal (full) and peripheral (skeleton) file sets.
 #[must_use]
 pub fn compute_sets(
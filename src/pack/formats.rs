@@ -4,9 +4,27 @@ use std::fs;
 use std::path::{Path, PathBuf};
 
 use anyhow::Result;
-
-use super::{FocusContext, PackOptions};
-use crate::skeleton;
+use clap::ValueEnum;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use super::cache;
+use super::comments::{self, StripComments};
+use super::{FocusContext, PackExtras, PackOptions, SkeletonMode};
+use crate::apply::manifest;
+
+/// The layout of a pack (`--format`).
+#[derive(Debug, Clone, PartialEq, Eq, ValueEnum, Default, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "kebab-case")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Xml,
+    /// Anthropic's long-context layout: `<document>`s with `<source>`.
+    ClaudeXml,
+    /// Markdown headers and fenced code.
+    Openai,
+}
 
 /// Packs files into the `SlopChop` format.
 ///
@@ -14,8 +32,37 @@ use crate::skeleton;
 /// Returns an error if file reading fails.
 pub fn pack_slopchop(files: &[PathBuf], out: &mut String, opts: &PackOptions) -> Result<()> {
     for path in files {
-        write_slopchop_file(out, path, should_skeletonize(path, opts))?;
+        match oversized(path, opts) {
+            Some(tokens) => write_oversized(out, path, tokens)?,
+            None => write_slopchop_file(out, path, should_skeletonize(path, opts), opts)?,
+        }
+    }
+    Ok(())
+}
+
+/// The token count of `path` if it is over the `SkeletonMode::Over` limit
+/// and would otherwise be packed in full.
+pub(super) fn oversized(path: &Path, opts: &PackOptions) -> Option<usize> {
+    let SkeletonMode::Over(limit) = opts.skeleton else {
+        return None;
+    };
+    if should_skeletonize(path, opts) {
+        return None;
+    }
+    let tokens = cache::tokens(path, &fs::read_to_string(path).ok()?);
+    (tokens > limit).then_some(tokens)
+}
+
+fn write_oversized(out: &mut String, path: &Path, tokens: usize) -> Result<()> {
+    let p_str = path.to_string_lossy().replace('\\', "/");
+    writeln!(
+        out,
+        "#__SLOPCHOP_FILE__# {p_str} [SKELETON: {tokens} tokens; ask for the full file to edit it]"
+    )?;
+    if let Ok(content) = fs::read_to_string(path) {
+        out.push_str(&cache::skeleton(path, &content));
     }
+    writeln!(out, "\n#__SLOPCHOP_END__#\n")?;
     Ok(())
 }
 
@@ -33,13 +80,18 @@ pub fn pack_slopchop_focus(
         return pack_slopchop(files, out, opts);
     }
 
-    write_foveal_section(out, files, focus)?;
+    write_foveal_section(out, files, focus, opts)?;
     write_peripheral_section(out, files, focus)?;
 
     Ok(())
 }
 
-fn write_foveal_section(out: &mut String, files: &[PathBuf], focus: &FocusContext) -> Result<()> {
+fn write_foveal_section(
+    out: &mut String,
+    files: &[PathBuf],
+    focus: &FocusContext,
+    opts: &PackOptions,
+) -> Result<()> {
     let foveal: Vec<_> = files.iter().filter(|f| focus.foveal.contains(*f)).collect();
     if foveal.is_empty() {
         return Ok(());
@@ -47,7 +99,7 @@ fn write_foveal_section(out: &mut String, files: &[PathBuf], focus: &FocusContex
 
     writeln!(out, "# ═══ FOVEAL (full content) ═══\n")?;
     for path in foveal {
-        write_slopchop_file(out, path, false)?;
+        write_slopchop_file(out, path, false, opts)?;
     }
     Ok(())
 }
@@ -72,14 +124,30 @@ fn write_peripheral_section(
     Ok(())
 }
 
-fn write_slopchop_file(out: &mut String, path: &Path, skeletonize: bool) -> Result<()> {
+fn write_slopchop_file(
+    out: &mut String,
+    path: &Path,
+    skeletonize: bool,
+    opts: &PackOptions,
+) -> Result<()> {
     let p_str = path.to_string_lossy().replace('\\', "/");
-    writeln!(out, "#__SLOPCHOP_FILE__# {p_str}")?;
 
     match fs::read_to_string(path) {
-        Ok(content) if skeletonize => out.push_str(&skeleton::clean(path, &content)),
-        Ok(content) => out.push_str(&content),
-        Err(e) => writeln!(out, "// <ERROR READING FILE: {e}>")?,
+        Ok(content) if skeletonize => {
+            writeln!(out, "#__SLOPCHOP_FILE__# {p_str}")?;
+            out.push_str(&cache::skeleton(path, &content));
+        }
+        Ok(content) => {
+            let base = manifest::base_hash(&content);
+            let (text, stripped) = full_text(path, content, opts);
+            let mark = if stripped { " [STRIPPED]" } else { "" };
+            writeln!(out, "#__SLOPCHOP_FILE__# {p_str} [base:{base}]{mark}")?;
+            out.push_str(&text);
+        }
+        Err(e) => writeln!(
+            out,
+            "#__SLOPCHOP_FILE__# {p_str}\n// <ERROR READING FILE: {e}>"
+        )?,
     }
 
     writeln!(out, "\n#__SLOPCHOP_END__#\n")?;
@@ -91,7 +159,7 @@ fn write_slopchop_file_skeleton(out: &mut String, path: &Path) -> Result<()> {
     writeln!(out, "#__SLOPCHOP_FILE__# {p_str} [SKELETON]")?;
 
     match fs::read_to_string(path) {
-        Ok(content) => out.push_str(&skeleton::clean(path, &content)),
+        Ok(content) => out.push_str(&cache::skeleton(path, &content)),
         Err(e) => writeln!(out, "// <ERROR READING FILE: {e}>")?,
     }
 
@@ -99,88 +167,79 @@ fn write_slopchop_file_skeleton(out: &mut String, path: &Path) -> Result<()> {
     Ok(())
 }
 
-/// Packs files into an XML format.
-///
-/// # Errors
-/// Returns an error if file reading fails.
-pub fn pack_xml(files: &[PathBuf], out: &mut String, opts: &PackOptions) -> Result<()> {
-    writeln!(out, "<documents>")?;
-    for path in files {
-        write_xml_doc(out, path, should_skeletonize(path, opts), None)?;
+const LINE_NUMBERS_NOTE: &str =
+    "Line numbers (`N | `) are for reference only; never include them in replies.";
+
+/// What the reader needs to know about how `full_text` changed files.
+pub(super) fn notes(extras: PackExtras) -> String {
+    let mut out = String::new();
+    if extras.line_numbers {
+        out.push_str(LINE_NUMBERS_NOTE);
+        out.push_str("\n\n");
     }
-    writeln!(out, "</documents>")?;
-    Ok(())
+    if extras.strip_comments != StripComments::Off {
+        out.push_str(comments::NOTE);
+        out.push_str("\n\n");
+    }
+    out
 }
 
-/// Packs files into XML format with focus awareness.
-///
-/// # Errors
-/// Returns an error if file reading fails.
-pub fn pack_xml_focus(
-    files: &[PathBuf],
-    out: &mut String,
-    opts: &PackOptions,
-    focus: &FocusContext,
-) -> Result<()> {
-    if focus.foveal.is_empty() && focus.peripheral.is_empty() {
-        return pack_xml(files, out, opts);
+/// A full file's text, with comments stripped and lines numbered if asked,
+/// and whether stripping removed anything.
+pub(super) fn full_text(path: &Path, content: String, opts: &PackOptions) -> (String, bool) {
+    let PackExtras {
+        line_numbers,
+        strip_comments,
+        ..
+    } = opts.extras;
+    if strip_comments == StripComments::Off && !line_numbers {
+        return (content, false);
     }
-
-    writeln!(out, "<documents>")?;
-    write_xml_foveal(out, files, focus)?;
-    write_xml_peripheral(out, files, focus)?;
-    writeln!(out, "</documents>")?;
-
-    Ok(())
+    let lines = comments::strip_lines(path, &content, strip_comments);
+    let stripped = comments::changed(&content, &lines);
+    if !line_numbers {
+        return (
+            lines.into_iter().map(|(_, line)| line + "\n").collect(),
+            stripped,
+        );
+    }
+    (number(&lines), stripped)
 }
 
-fn write_xml_foveal(out: &mut String, files: &[PathBuf], focus: &FocusContext) -> Result<()> {
-    for path in files.iter().filter(|f| focus.foveal.contains(*f)) {
-        write_xml_doc(out, path, false, Some("foveal"))?;
-    }
-    Ok(())
+/// `content` with each line prefixed by its number, right-aligned.
+#[must_use]
+pub fn number_lines(content: &str) -> String {
+    let lines: Vec<_> = content
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line.to_string()))
+        .collect();
+    number(&lines)
 }
 
-fn write_xml_peripheral(out: &mut String, files: &[PathBuf], focus: &FocusContext) -> Result<()> {
-    for path in files.iter().filter(|f| focus.peripheral.contains(*f)) {
-        write_xml_doc(out, path, true, Some("peripheral"))?;
+fn number(lines: &[(usize, String)]) -> String {
+    let width = lines.last().map_or(1, |(n, _)| n.to_string().len());
+    let mut out = String::new();
+    for (n, line) in lines {
+        let _ = writeln!(out, "{n:>width$} | {line}");
     }
-    Ok(())
+    out
 }
 
-fn write_xml_doc(
-    out: &mut String,
-    path: &Path,
-    skeletonize: bool,
-    focus_attr: Option<&str>,
-) -> Result<()> {
-    let p_str = path.to_string_lossy().replace('\\', "/");
-    let attr = focus_attr.map_or(String::new(), |f| format!(" focus=\"{f}\""));
-
-    writeln!(out, "  <document path=\"{p_str}\"{attr}><![CDATA[")?;
-
-    match fs::read_to_string(path) {
-        Ok(content) => {
-            let text = if skeletonize {
-                skeleton::clean(path, &content)
-            } else {
-                content
-            };
-            out.push_str(&text.replace("]]>", "]]]]><![CDATA[>"));
-        }
-        Err(e) => writeln!(out, "<!-- ERROR: {e} -->")?,
+/// True if `path` goes into the pack as a skeleton rather than in full.
+pub(super) fn packs_as_skeleton(path: &Path, focus: &FocusContext, opts: &PackOptions) -> bool {
+    if focus.peripheral.contains(path) {
+        return true;
     }
-
-    writeln!(out, "]]></document>")?;
-    Ok(())
+    focus.foveal.is_empty() && (should_skeletonize(path, opts) || oversized(path, opts).is_some())
 }
 
-fn should_skeletonize(path: &Path, opts: &PackOptions) -> bool {
-    if opts.skeleton {
+pub(super) fn should_skeletonize(path: &Path, opts: &PackOptions) -> bool {
+    if opts.skeleton == SkeletonMode::All {
         return true;
     }
     if let Some(target) = &opts.target {
         return !path.ends_with(target);
     }
     false
-}
\ No newline at end of file
+}
@@ -0,0 +1,77 @@
+// src/pack/git_info.rs
+//! `pack --git-info [N]`: the current branch, the last N commit subjects,
+//! and the uncommitted changes, so the AI knows what is in flight.
+
+use std::fmt::Write;
+
+use anyhow::Result;
+
+use super::{diff, OutputFormat};
+
+/// Where the repository stands right now.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct GitInfo {
+    pub branch: String,
+    /// `<short hash> <subject>`, newest first.
+    pub commits: Vec<String>,
+    /// `git status --short` lines.
+    pub changes: Vec<String>,
+}
+
+/// Reads the branch, the last `commits` commits, and the uncommitted
+/// changes; `None` outside a git repository.
+#[must_use]
+pub fn collect(commits: usize) -> Option<GitInfo> {
+    let branch = diff::git(&["branch", "--show-current"]).ok()?;
+    let branch = match branch.trim() {
+        "" => "(detached HEAD)".to_string(),
+        name => name.to_string(),
+    };
+    let n = commits.to_string();
+    let log = diff::git(&["log", "-n", &n, "--format=%h %s"]).unwrap_or_default();
+    let status = diff::git(&["status", "--short", "--untracked-files=all"]).ok()?;
+    Some(GitInfo {
+        branch,
+        commits: log.lines().map(str::to_string).collect(),
+        changes: status.lines().map(str::to_string).collect(),
+    })
+}
+
+/// The section body, one block per kind of information.
+#[must_use]
+pub fn render(info: &GitInfo) -> String {
+    let mut out = format!("Branch: {}\n", info.branch);
+    if !info.commits.is_empty() {
+        out.push_str("\nRecent commits:\n");
+    }
+    for c in &info.commits {
+        let _ = writeln!(out, "  {c}");
+    }
+    if info.changes.is_empty() {
+        out.push_str("\nUncommitted changes: none\n");
+    } else {
+        let _ = writeln!(out, "\nUncommitted changes ({}):", info.changes.len());
+    }
+    for c in &info.changes {
+        let _ = writeln!(out, "  {c}");
+    }
+    out
+}
+
+/// Writes the git section; nothing outside a git repository.
+///
+/// # Errors
+/// Returns error if writing fails.
+pub fn write(out: &mut String, commits: usize, format: &OutputFormat) -> Result<()> {
+    let Some(info) = collect(commits) else {
+        return Ok(());
+    };
+    let body = render(&info);
+    match format {
+        OutputFormat::Text => writeln!(out, "# ═══ GIT ═══\n\n{body}")?,
+        OutputFormat::Xml => writeln!(out, "<git><![CDATA[\n{body}]]></git>")?,
+        OutputFormat::ClaudeXml => writeln!(out, "<git_status>\n{body}</git_status>")?,
+        OutputFormat::Openai => writeln!(out, "## Git status\n\n```text\n{body}```\n")?,
+    }
+    Ok(())
+}
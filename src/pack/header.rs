@@ -0,0 +1,88 @@
+// src/pack/header.rs
+//! The text above the packed files: the built-in prompt and violations, or
+//! `[pack] header_template` filled in.
+//!
+//! Template placeholders are `{prompt}` (the built-in system prompt),
+//! `{rules}` (the rules summary), `{violations}` (active violations and
+//! known debt), `{files}` (the packed paths, one per line) and `{date}`
+//! (today, as `YYYY-MM-DD`).
+
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use anyhow::{Context, Result};
+use regex::{Captures, Regex};
+
+use super::violations;
+use crate::config::Config;
+use crate::prompt::PromptGenerator;
+
+/// Values for the template placeholders.
+#[derive(Debug, Clone, Default)]
+pub struct HeaderVars {
+    pub prompt: String,
+    pub rules: String,
+    pub violations: String,
+    pub files: String,
+    pub date: String,
+}
+
+/// Writes the header for a pack of `pack_files`; violations are scanned
+/// across all `files`.
+///
+/// # Errors
+/// Returns error if the template cannot be read.
+pub fn write(
+    ctx: &mut String,
+    files: &[PathBuf],
+    pack_files: &[PathBuf],
+    config: &Config,
+) -> Result<()> {
+    let gen = PromptGenerator::new(config.rules.clone())
+        .with_line_counts(config.apply.truncation.line_counts);
+    let mut found = String::new();
+    violations::inject(&mut found, files, config)?;
+
+    let Some(path) = &config.pack.header_template else {
+        writeln!(ctx, "{}", gen.wrap_header()?)?;
+        let rule = "═".repeat(67);
+        writeln!(ctx, "\n{rule}\nBEGIN CODEBASE\n{rule}\n")?;
+        ctx.push_str(&found);
+        return Ok(());
+    };
+
+    let template = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read header template {path}"))?;
+    let secs = SystemTime::now().duration_since(UNIX_EPOCH)?.as_secs();
+    let vars = HeaderVars {
+        prompt: gen.generate()?,
+        rules: gen.generate_reminder()?,
+        violations: found,
+        files: pack_files
+            .iter()
+            .map(|p| p.to_string_lossy().replace('\\', "/") + "\n")
+            .collect(),
+        date: crate::time::date(i64::try_from(secs).unwrap_or(0)),
+    };
+    ctx.push_str(&render(&template, &vars));
+    Ok(())
+}
+
+/// Fills the placeholders in `template`. Text substituted in is not
+/// scanned again, so a `{date}` inside a file list stays as it is.
+#[must_use]
+pub fn render(template: &str, vars: &HeaderVars) -> String {
+    let Ok(re) = Regex::new(r"\{(prompt|rules|violations|files|date)\}") else {
+        return template.to_string();
+    };
+    re.replace_all(template, |caps: &Captures| match &caps[1] {
+        "prompt" => vars.prompt.clone(),
+        "rules" => vars.rules.clone(),
+        "violations" => vars.violations.clone(),
+        "files" => vars.files.clone(),
+        _ => vars.date.clone(),
+    })
+    .into_owned()
+}
@@ -1,45 +1,90 @@
 // src/pack/mod.rs
+pub mod assets;
+pub mod budget;
+pub mod cache;
+pub mod chunk;
+pub mod comments;
+pub mod diff;
+pub mod filter;
 pub mod focus;
 pub mod formats;
+pub mod git_info;
+pub mod header;
+pub mod order;
+mod output;
+pub mod presets;
+pub mod redact;
+pub mod remote;
+pub mod stats;
+pub mod tree;
+mod violations;
+pub mod window;
+pub mod xml;
 
 use std::collections::HashSet;
 use std::fmt::Write;
-use std::fs;
 use std::path::PathBuf;
 
 use anyhow::Result;
-use clap::ValueEnum;
-use colored::Colorize;
 
-use crate::analysis::RuleEngine;
-use crate::clipboard;
 use crate::config::{Config, GitMode};
 use crate::discovery;
 use crate::prompt::PromptGenerator;
 use crate::tokens::Tokenizer;
-
-#[derive(Debug, Clone, ValueEnum, Default)]
-pub enum OutputFormat {
-    #[default]
-    Text,
-    Xml,
-}
+use budget::Budget;
+use comments::StripComments;
+use filter::FileFilter;
+pub use formats::OutputFormat;
+use order::FileOrder;
+pub use output::PackOutput;
 
 #[allow(clippy::struct_excessive_bools)]
-#[derive(Default)]
+#[derive(Debug, Clone, Default)]
 pub struct PackOptions {
-    pub stdout: bool,
-    pub copy: bool,
+    pub output: PackOutput,
     pub verbose: bool,
     pub prompt: bool,
     pub format: OutputFormat,
-    pub skeleton: bool,
-    pub git_only: bool,
-    pub no_git: bool,
-    pub code_only: bool,
+    pub skeleton: SkeletonMode,
+    pub git_mode: GitMode,
+    pub filter: FileFilter,
     pub target: Option<PathBuf>,
     pub focus: Vec<PathBuf>,
     pub depth: usize,
+    /// Token limit, context window, and whether going over them fails.
+    pub budget: Budget,
+    /// Pack the diff since this ref and the files it touches.
+    pub diff: Option<String>,
+    /// Print each packed file's token count.
+    pub stats: bool,
+    pub order: FileOrder,
+    pub extras: PackExtras,
+}
+
+/// Optional annotations and trimming of the packed text.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PackExtras {
+    /// Open with the branch, this many recent commits, and uncommitted changes.
+    pub git_info: Option<usize>,
+    /// Prefix each line of a fully packed file with its number.
+    pub line_numbers: bool,
+    /// Drop comments from fully packed files.
+    pub strip_comments: StripComments,
+    /// List the files left out of the pack, with sizes and types.
+    pub assets: bool,
+    /// Keep secrets as they are instead of redacting them.
+    pub no_redact: bool,
+}
+
+/// Which files `pack` reduces to skeletons.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SkeletonMode {
+    #[default]
+    Off,
+    /// Every file (`--skeleton`).
+    All,
+    /// Files over this many tokens (`[pack] skeleton_over`).
+    Over(usize),
 }
 
 /// Internal struct to pass focus information to format functions.
@@ -53,25 +98,54 @@ pub struct FocusContext {
 /// # Errors
 /// Returns error if configuration, discovery, or output fails.
 pub fn run(options: &PackOptions) -> Result<()> {
-    let config = setup_config(options)?;
-    print_start_message(options);
+    let (content, tokens, config) = build(options)?;
+    output::write(&content, tokens, options, &config)
+}
+
+/// Packs the current directory: the content, its token count, and the
+/// config it was packed with.
+fn build(options: &PackOptions) -> Result<(String, usize, Config)> {
+    build_with(options, setup_config(options)?)
+}
 
+/// Packs the current directory under `config`.
+fn build_with(options: &PackOptions, config: Config) -> Result<(String, usize, Config)> {
     let files = discovery::discover(&config)?;
+    let files = filter::apply(files, options.filter, &config.pack.generated);
+    let mut options = diff::focus_changes(options, &files)?;
+    options.focus = focus::resolve(&options.focus, &files)?;
+    if let (SkeletonMode::Off, Some(limit)) = (options.skeleton, config.pack.skeleton_over) {
+        options.skeleton = SkeletonMode::Over(limit);
+    }
+    let options = &options;
+    print_start_message(options);
+
     if options.verbose {
         eprintln!("📦 Discovered {} files...", files.len());
     }
 
-    let content = generate_content(&files, options, &config)?;
+    let (content, trims) = budget::generate(&files, options, &config)?;
+    comments::finish(&files, options);
+    budget::print(&trims, options.budget.limit.unwrap_or_default());
+    let content = redact::for_pack(content, !options.extras.no_redact && config.pack.redact);
     let token_count = Tokenizer::count(&content);
-
-    output_result(&content, token_count, options)
+    if options.stats || options.verbose {
+        stats::print(&content, token_count);
+    }
+    if let Err(e) = cache::save() {
+        eprintln!("WARN: Could not write pack cache: {e}");
+    }
+    window::check(token_count, options.budget, &config)?;
+    Ok((content, token_count, config))
 }
 
 fn print_start_message(options: &PackOptions) {
-    if options.stdout || options.copy {
+    if options.output != PackOutput::File {
         return;
     }
-    if !options.focus.is_empty() {
+    if let Some(base) = &options.diff {
+        println!("🔍 Packing changes since {base}...");
+    } else if !options.focus.is_empty() {
         let names: Vec<_> = options
             .focus
             .iter()
@@ -88,12 +162,8 @@ fn print_start_message(options: &PackOptions) {
 fn setup_config(opts: &PackOptions) -> Result<Config> {
     let mut config = Config::new();
     config.verbose = opts.verbose;
-    config.code_only = opts.code_only;
-    config.git_mode = match (opts.git_only, opts.no_git) {
-        (true, _) => GitMode::Yes,
-        (_, true) => GitMode::No,
-        _ => GitMode::Auto,
-    };
+    config.files.code_only = opts.filter.code_only;
+    config.files.git_mode = opts.git_mode.clone();
     config.load_local_config();
     config.validate()?;
     Ok(config)
@@ -104,16 +174,38 @@ fn setup_config(opts: &PackOptions) -> Result<Config> {
 /// # Errors
 /// Returns error if file reading fails.
 pub fn generate_content(files: &[PathBuf], opts: &PackOptions, config: &Config) -> Result<String> {
-    let mut ctx = String::with_capacity(100_000);
-
     let (focus_ctx, pack_files) = build_focus_context(files, opts);
+    render(files, &pack_files, &focus_ctx, opts, config)
+}
+
+fn render(
+    files: &[PathBuf],
+    pack_files: &[PathBuf],
+    focus_ctx: &FocusContext,
+    opts: &PackOptions,
+    config: &Config,
+) -> Result<String> {
+    let mut ctx = String::with_capacity(100_000);
 
     if opts.prompt {
-        write_header(&mut ctx, config)?;
-        inject_violations(&mut ctx, files, config)?;
+        header::write(&mut ctx, files, pack_files, config)?;
+    }
+    if let Some(commits) = opts.extras.git_info {
+        git_info::write(&mut ctx, commits, &opts.format)?;
+    }
+    ctx.push_str(&formats::notes(opts.extras));
+    if config.pack.tree {
+        tree::write(&mut ctx, files, pack_files, focus_ctx, opts)?;
+    }
+    if opts.extras.assets {
+        assets::write(&mut ctx, config, files, &opts.format)?;
+    }
+    if let Some(base) = &opts.diff {
+        diff::write_section(&mut ctx, base, &opts.format)?;
     }
 
-    pack_files_to_output(&pack_files, &mut ctx, opts, &focus_ctx)?;
+    let pack_files = order::sort(pack_files, opts.order);
+    pack_files_to_output(&pack_files, &mut ctx, opts, focus_ctx)?;
 
     if opts.prompt {
         write_footer(&mut ctx, config)?;
@@ -145,47 +237,15 @@ fn pack_files_to_output(
 ) -> Result<()> {
     match opts.format {
         OutputFormat::Text => formats::pack_slopchop_focus(files, ctx, opts, focus),
-        OutputFormat::Xml => formats::pack_xml_focus(files, ctx, opts, focus),
+        OutputFormat::Xml => xml::pack_xml_focus(files, ctx, opts, focus),
+        OutputFormat::ClaudeXml => presets::pack_claude(files, ctx, opts, focus),
+        OutputFormat::Openai => presets::pack_openai(files, ctx, opts, focus),
     }
 }
 
-fn inject_violations(ctx: &mut String, files: &[PathBuf], config: &Config) -> Result<()> {
-    let engine = RuleEngine::new(config.clone());
-    let report = engine.scan(files.to_vec());
-
-    if !report.has_errors() {
-        return Ok(());
-    }
-
-    writeln!(ctx, "{}", "═".repeat(67))?;
-    writeln!(ctx, "⚠️  ACTIVE VIOLATIONS (PRIORITY FIX REQUIRED)")?;
-    writeln!(ctx, "{}\n", "═".repeat(67))?;
-
-    for file in report.files.iter().filter(|f| !f.is_clean()) {
-        for v in &file.violations {
-            writeln!(ctx, "FILE: {}", file.path.display())?;
-            writeln!(ctx, "LAW:  {} | LINE: {} | {}", v.law, v.row + 1, v.message)?;
-            writeln!(ctx, "{}", "─".repeat(40))?;
-        }
-    }
-    writeln!(ctx)?;
-    Ok(())
-}
-
-fn write_header(ctx: &mut String, config: &Config) -> Result<()> {
-    let gen = PromptGenerator::new(config.rules.clone());
-    writeln!(ctx, "{}", gen.wrap_header()?)?;
-    writeln!(
-        ctx,
-        "\n{}\nBEGIN CODEBASE\n{}\n",
-        "═".repeat(67),
-        "═".repeat(67)
-    )?;
-    Ok(())
-}
-
 fn write_footer(ctx: &mut String, config: &Config) -> Result<()> {
-    let gen = PromptGenerator::new(config.rules.clone());
+    let gen = PromptGenerator::new(config.rules.clone())
+        .with_line_counts(config.apply.truncation.line_counts);
     writeln!(
         ctx,
         "\n{}\nEND CODEBASE\n{}\n",
@@ -195,40 +255,3 @@ fn write_footer(ctx: &mut String, config: &Config) -> Result<()> {
     writeln!(ctx, "{}", gen.generate_reminder()?)?;
     Ok(())
 }
-
-fn output_result(content: &str, tokens: usize, opts: &PackOptions) -> Result<()> {
-    let info = format!(
-        "\n📊 Context Size: {} tokens",
-        tokens.to_string().yellow().bold()
-    );
-
-    if opts.stdout {
-        print!("{content}");
-        eprintln!("{info}");
-        return Ok(());
-    }
-
-    if opts.copy {
-        let msg = clipboard::smart_copy(content)?;
-        println!("{}", "✓ Copied to clipboard".green());
-        println!("  ({msg})");
-        println!("{info}");
-        return Ok(());
-    }
-
-    write_to_file(content, &info)
-}
-
-fn write_to_file(content: &str, info: &str) -> Result<()> {
-    let output_path = PathBuf::from("context.txt");
-    fs::write(&output_path, content)?;
-    println!("✅ Generated 'context.txt'");
-
-    if let Ok(abs) = fs::canonicalize(&output_path) {
-        if clipboard::copy_file_path(&abs).is_ok() {
-            println!("{}", "📎 File path copied to clipboard".cyan());
-        }
-    }
-    println!("{info}");
-    Ok(())
-}
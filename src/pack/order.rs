@@ -0,0 +1,120 @@
+// src/pack/order.rs
+//! `pack --order`: the order files go into the pack. Models attend to a
+//! long context unevenly, so placement matters.
+
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fs;
+use std::path::PathBuf;
+use std::process::Command;
+
+use clap::ValueEnum;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::discovery;
+use crate::graph::rank::RepoGraph;
+
+/// Commits read for `recent`; older changes count as never.
+const RECENT_COMMITS: &str = "1000";
+
+#[derive(
+    Debug, Clone, Copy, ValueEnum, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum FileOrder {
+    /// Alphabetical by path.
+    #[default]
+    Path,
+    /// Biggest first.
+    Size,
+    /// Uncommitted changes, then the most recently committed.
+    Recent,
+    /// Dependencies before their dependents: leaf modules first, entry
+    /// points last.
+    Topo,
+}
+
+/// `files` in `order`.
+#[must_use]
+pub fn sort(files: &[PathBuf], order: FileOrder) -> Vec<PathBuf> {
+    let mut sorted = files.to_vec();
+    sorted.sort();
+    match order {
+        FileOrder::Path => sorted,
+        FileOrder::Size => {
+            sorted.sort_by_key(|f| Reverse(fs::metadata(f).map_or(0, |m| m.len())));
+            sorted
+        }
+        FileOrder::Recent => {
+            let changed = discovery::changed_files(None).unwrap_or_default();
+            let times = commit_times();
+            sorted.sort_by_key(|f| {
+                Reverse((changed.contains(f), times.get(f).copied().unwrap_or(0)))
+            });
+            sorted
+        }
+        FileOrder::Topo => topo(&sorted),
+    }
+}
+
+/// When each file was last committed, from recent `git log` history.
+fn commit_times() -> HashMap<PathBuf, u64> {
+    let mut times = HashMap::new();
+    let Ok(out) = Command::new("git")
+        .args([
+            "log",
+            "-n",
+            RECENT_COMMITS,
+            "--format=\u{1}%ct",
+            "--name-only",
+            "--relative",
+        ])
+        .output()
+    else {
+        return times;
+    };
+    let mut current = 0;
+    for line in String::from_utf8_lossy(&out.stdout).lines() {
+        if let Some(stamp) = line.strip_prefix('\u{1}') {
+            current = stamp.trim().parse().unwrap_or(0);
+        } else if !line.is_empty() {
+            times.entry(PathBuf::from(line)).or_insert(current);
+        }
+    }
+    times
+}
+
+/// Orders `files` so each comes after the files it depends on. In a cycle,
+/// the file with the fewest unplaced dependencies goes first.
+#[must_use]
+pub fn topo(files: &[PathBuf]) -> Vec<PathBuf> {
+    let contents: Vec<(PathBuf, String)> = files
+        .iter()
+        .filter_map(|p| fs::read_to_string(p).ok().map(|c| (p.clone(), c)))
+        .collect();
+    let graph = RepoGraph::build(&contents);
+    let known: HashSet<&PathBuf> = files.iter().collect();
+    let mut pending: BTreeMap<PathBuf, HashSet<PathBuf>> = files
+        .iter()
+        .map(|f| {
+            let deps = graph.dependencies(f);
+            let deps = deps.into_iter().filter(|d| d != f && known.contains(d));
+            (f.clone(), deps.collect())
+        })
+        .collect();
+
+    let mut sorted = Vec::with_capacity(files.len());
+    while let Some(next) = pending
+        .iter()
+        .min_by_key(|(path, deps)| (deps.len(), *path))
+        .map(|(path, _)| path.clone())
+    {
+        pending.remove(&next);
+        for deps in pending.values_mut() {
+            deps.remove(&next);
+        }
+        sorted.push(next);
+    }
+    sorted
+}
@@ -0,0 +1,83 @@
+// src/pack/output.rs
+//! Where the finished pack goes: stdout, the clipboard, or `context.txt`.
+
+use std::fs;
+use std::path::PathBuf;
+
+use anyhow::Result;
+use colored::Colorize;
+
+use super::{chunk, PackOptions};
+use crate::clipboard;
+use crate::config::Config;
+use crate::cost;
+
+/// Where `pack` sends the context.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PackOutput {
+    /// `context.txt`, or numbered parts when chunked.
+    #[default]
+    File,
+    Stdout,
+    Clipboard,
+}
+
+impl PackOutput {
+    /// The output for `--stdout` / `--copy`; `--stdout` wins.
+    #[must_use]
+    pub fn from_flags(stdout: bool, copy: bool) -> Self {
+        match (stdout, copy) {
+            (true, _) => Self::Stdout,
+            (_, true) => Self::Clipboard,
+            _ => Self::File,
+        }
+    }
+}
+
+pub(super) fn write(
+    content: &str,
+    tokens: usize,
+    opts: &PackOptions,
+    config: &Config,
+) -> Result<()> {
+    let info = format!(
+        "\n📊 Context Size: {} tokens{}",
+        tokens.to_string().yellow().bold(),
+        cost::summary(tokens, &config.tokens.prices)
+    );
+
+    if opts.output == PackOutput::Stdout {
+        print!("{content}");
+        eprintln!("{info}");
+        return Ok(());
+    }
+
+    clipboard::history::record("pack", content);
+    if let Some(limit) = config.pack.chunk_tokens.filter(|&limit| tokens > limit) {
+        return chunk::output(content, limit, opts.output == PackOutput::Clipboard, &info);
+    }
+
+    if opts.output == PackOutput::Clipboard {
+        let msg = clipboard::smart_copy(content)?;
+        println!("{}", "✓ Copied to clipboard".green());
+        println!("  ({msg})");
+        println!("{info}");
+        return Ok(());
+    }
+
+    write_to_file(content, &info)
+}
+
+fn write_to_file(content: &str, info: &str) -> Result<()> {
+    let output_path = PathBuf::from("context.txt");
+    fs::write(&output_path, content)?;
+    println!("✅ Generated 'context.txt'");
+
+    if let Ok(abs) = fs::canonicalize(&output_path) {
+        if clipboard::copy_file_path(&abs).is_ok() {
+            println!("{}", "📎 File path copied to clipboard".cyan());
+        }
+    }
+    println!("{info}");
+    Ok(())
+}
@@ -0,0 +1,121 @@
+// src/pack/presets.rs
+//! Provider-shaped pack formats. `claude-xml` follows Anthropic's
+//! long-context layout: numbered `<document>`s with a `<source>` and
+//! `<document_content>`. `openai` uses markdown: a header per file and a
+//! fenced code block.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use super::{cache, comments, formats, FocusContext, PackOptions};
+use crate::apply::manifest;
+
+/// Packs files as Anthropic-style `<documents>`.
+///
+/// # Errors
+/// Returns error if writing to the buffer fails.
+pub fn pack_claude(
+    files: &[PathBuf],
+    out: &mut String,
+    opts: &PackOptions,
+    focus: &FocusContext,
+) -> Result<()> {
+    writeln!(out, "<documents>")?;
+    for (i, path) in files.iter().enumerate() {
+        let (text, note, base) = file_text(path, opts, focus);
+        writeln!(
+            out,
+            "<document index=\"{}\">\n<source>{}{}</source>",
+            i + 1,
+            display(path),
+            base_tag(base.as_deref())
+        )?;
+        if let Some(note) = note {
+            writeln!(out, "<note>{note}</note>")?;
+        }
+        writeln!(
+            out,
+            "<document_content>\n{}\n</document_content>\n</document>",
+            text.trim_end()
+        )?;
+    }
+    writeln!(out, "</documents>")?;
+    Ok(())
+}
+
+/// Packs files as markdown sections with fenced code.
+///
+/// # Errors
+/// Returns error if writing to the buffer fails.
+pub fn pack_openai(
+    files: &[PathBuf],
+    out: &mut String,
+    opts: &PackOptions,
+    focus: &FocusContext,
+) -> Result<()> {
+    for path in files {
+        let (text, note, base) = file_text(path, opts, focus);
+        let tag = base_tag(base.as_deref());
+        writeln!(out, "## File: {}{tag}\n", display(path))?;
+        if let Some(note) = note {
+            writeln!(out, "> {note}\n")?;
+        }
+        let lang = path
+            .extension()
+            .map_or("text".into(), |e| e.to_string_lossy());
+        let fence = fence(&text);
+        writeln!(out, "{fence}{lang}\n{}\n{fence}\n", text.trim_end())?;
+    }
+    Ok(())
+}
+
+/// A markdown code fence longer than any backtick run in `text`.
+#[must_use]
+pub fn fence(text: &str) -> String {
+    let longest = text.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    "`".repeat((longest + 1).max(3))
+}
+
+/// The text to pack for `path`, a note if it is not the full file, and the
+/// base hash to edit it against when it is.
+fn file_text(
+    path: &Path,
+    opts: &PackOptions,
+    focus: &FocusContext,
+) -> (String, Option<String>, Option<String>) {
+    let content = match fs::read_to_string(path) {
+        Ok(content) => content,
+        Err(e) => {
+            return (
+                String::new(),
+                Some(format!("Error reading file: {e}")),
+                None,
+            )
+        }
+    };
+    if !formats::packs_as_skeleton(path, focus, opts) {
+        let base = manifest::base_hash(&content);
+        let (text, stripped) = formats::full_text(path, content, opts);
+        let note = stripped.then(|| comments::FILE_NOTE.to_string());
+        return (text, note, Some(base));
+    }
+    let note = match formats::oversized(path, opts) {
+        Some(tokens) => {
+            format!("Skeleton of a {tokens}-token file; ask for the full file to edit it.")
+        }
+        None => "Skeleton: signatures only; ask for the full file to edit it.".to_string(),
+    };
+    (cache::skeleton(path, &content), Some(note), None)
+}
+
+/// The ` [base:HASH]` tag the manifest copies, or nothing for skeletons.
+fn base_tag(base: Option<&str>) -> String {
+    base.map(|b| format!(" [base:{b}]")).unwrap_or_default()
+}
+
+fn display(path: &Path) -> String {
+    path.to_string_lossy().replace('\\', "/")
+}
@@ -0,0 +1,111 @@
+// src/pack/redact.rs
+//! Redacts secrets from packed content before it leaves the machine: keys,
+//! tokens, connection strings with passwords, and high-entropy strings.
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::LazyLock;
+
+use colored::Colorize;
+use regex::{Captures, Regex};
+
+use crate::constants::SECRET_CONTENT_PATTERNS;
+
+/// How every placeholder starts: `[REDACTED:<type>]`.
+pub const PLACEHOLDER: &str = "[REDACTED:";
+
+/// A quoted or assigned run of key-like characters.
+const HIGH_ENTROPY_PATTERN: &str = r#"(["'=:]\s*["']?)([A-Za-z0-9+/_-]{32,}={0,2})"#;
+
+/// Bits per character above which a key-like string counts as a secret.
+/// Hex digests top out at 4.0; random base64 keys run well above it.
+const ENTROPY_THRESHOLD: f64 = 4.2;
+
+static PATTERNS: LazyLock<Vec<(&str, Regex)>> = LazyLock::new(|| {
+    SECRET_CONTENT_PATTERNS
+        .iter()
+        .filter_map(|(kind, p)| Regex::new(p).ok().map(|re| (*kind, re)))
+        .collect()
+});
+
+static HIGH_ENTROPY_RE: LazyLock<Option<Regex>> =
+    LazyLock::new(|| Regex::new(HIGH_ENTROPY_PATTERN).ok());
+
+/// The pack text to send: redacted with a summary printed, or as it is
+/// with a warning when `enabled` is off.
+#[must_use]
+pub fn for_pack(content: String, enabled: bool) -> String {
+    if !enabled {
+        eprintln!("⚠️  Secret redaction is off for this pack.");
+        return content;
+    }
+    let (text, counts) = redact(&content);
+    print(&counts);
+    text
+}
+
+/// `content` with every secret replaced by `[REDACTED:<type>]`, and how
+/// many of each type were found.
+#[must_use]
+pub fn redact(content: &str) -> (String, BTreeMap<&'static str, usize>) {
+    let mut counts: HashMap<&'static str, usize> = HashMap::new();
+    let mut text = content.to_string();
+    for (kind, re) in PATTERNS.iter() {
+        let hits = re.find_iter(&text).count();
+        if hits > 0 {
+            text = re
+                .replace_all(&text, format!("{PLACEHOLDER}{kind}]"))
+                .into_owned();
+            *counts.entry(kind).or_default() += hits;
+        }
+    }
+    if let Some(re) = HIGH_ENTROPY_RE.as_ref() {
+        text = re
+            .replace_all(&text, |caps: &Captures| {
+                if !is_high_entropy(&caps[2]) {
+                    return caps[0].to_string();
+                }
+                *counts.entry("high-entropy").or_default() += 1;
+                format!("{}{PLACEHOLDER}high-entropy]", &caps[1])
+            })
+            .into_owned();
+    }
+    (text, counts.into_iter().collect())
+}
+
+/// True for long strings that mix letters and digits with high Shannon
+/// entropy.
+#[must_use]
+pub fn is_high_entropy(s: &str) -> bool {
+    let mixed = s.chars().any(|c| c.is_ascii_digit())
+        && s.chars().any(|c| c.is_ascii_uppercase())
+        && s.chars().any(|c| c.is_ascii_lowercase());
+    mixed && entropy(s) > ENTROPY_THRESHOLD
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn entropy(s: &str) -> f64 {
+    let mut freq: HashMap<char, usize> = HashMap::new();
+    for c in s.chars() {
+        *freq.entry(c).or_default() += 1;
+    }
+    let len = s.chars().count() as f64;
+    freq.values()
+        .map(|&n| {
+            let p = n as f64 / len;
+            -p * p.log2()
+        })
+        .sum()
+}
+
+/// Prints a summary of what was redacted.
+pub fn print(counts: &BTreeMap<&str, usize>) {
+    if counts.is_empty() {
+        return;
+    }
+    let total: usize = counts.values().sum();
+    let kinds: Vec<String> = counts.iter().map(|(k, n)| format!("{k} ×{n}")).collect();
+    eprintln!(
+        "{}",
+        format!("🔒 Redacted {total} secrets: {}", kinds.join(", ")).yellow()
+    );
+}
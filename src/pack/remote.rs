@@ -0,0 +1,97 @@
+// src/pack/remote.rs
+//! `pack --remote URL [--ref REF]`: packs a shallow clone of another
+//! repository, then removes it. The output still lands here.
+//!
+//! The clone is packed under the caller's config, never its own: a fetched
+//! `warden.toml` could otherwise point `[pack] header_template` or
+//! `extends` at any local file and pull it into the pack.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use anyhow::{Context, Result};
+use tempfile::TempDir;
+
+use super::{diff, output, PackOptions};
+use crate::config::Config;
+use crate::ignore::IgnoreSet;
+
+/// A repository to pack instead of the current directory.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Remote {
+    pub url: String,
+    /// Branch, tag, or commit; the default branch if `None`.
+    pub git_ref: Option<String>,
+}
+
+/// Packs `remote` and writes the result as `pack` normally would.
+///
+/// # Errors
+/// Returns error if the clone fails or packing fails.
+pub fn run(remote: &Remote, opts: &PackOptions) -> Result<()> {
+    let (content, tokens, config) = build(remote, opts)?;
+    output::write(&content, tokens, opts, &config)
+}
+
+/// Packs `remote` under the config of the current directory: the content,
+/// its token count, and that config.
+///
+/// # Errors
+/// Returns error if the config is invalid, the clone fails, or packing does.
+pub fn build(remote: &Remote, opts: &PackOptions) -> Result<(String, usize, Config)> {
+    let config = caller_config(opts, &env::current_dir()?)?;
+    within(remote, || super::build_with(opts, config))
+}
+
+/// The config loaded here, with paths anchored here and without the
+/// `.wardenignore` patterns, which are about this tree, not the clone.
+fn caller_config(opts: &PackOptions, home: &Path) -> Result<Config> {
+    let mut config = super::setup_config(opts)?;
+    config.files.exclude_patterns = IgnoreSet::default();
+    if let Some(template) = &mut config.pack.header_template {
+        *template = home.join(&*template).to_string_lossy().into_owned();
+    }
+    Ok(config)
+}
+
+/// Runs `f` inside a fresh shallow checkout of `remote`, then returns to
+/// the current directory and deletes the checkout, even if `f` fails.
+///
+/// # Errors
+/// Returns error if the clone fails or `f` does.
+pub fn within<T>(remote: &Remote, f: impl FnOnce() -> Result<T>) -> Result<T> {
+    let checkout = Checkout {
+        dir: tempfile::Builder::new()
+            .prefix("slopchop_remote_")
+            .tempdir()?,
+        home: env::current_dir()?,
+    };
+    let target = remote.git_ref.as_deref().unwrap_or("HEAD");
+    eprintln!("🌐 Fetching {} ({target})...", remote.url);
+    clone(&remote.url, target, checkout.dir.path())?;
+    env::set_current_dir(checkout.dir.path())?;
+    f()
+}
+
+/// Restores the working directory when dropped; the checkout itself goes
+/// with its `TempDir` right after.
+struct Checkout {
+    dir: TempDir,
+    home: PathBuf,
+}
+
+impl Drop for Checkout {
+    fn drop(&mut self) {
+        let _ = env::set_current_dir(&self.home);
+    }
+}
+
+/// Fetches just `target` so commits and tags work as well as branches.
+fn clone(url: &str, target: &str, dir: &Path) -> Result<()> {
+    let dir = dir.to_string_lossy();
+    diff::git(&["init", "-q", &dir])?;
+    diff::git(&["-C", &dir, "fetch", "-q", "--depth", "1", url, target])
+        .with_context(|| format!("Could not fetch {target} from {url}"))?;
+    diff::git(&["-C", &dir, "checkout", "-q", "FETCH_HEAD"])?;
+    Ok(())
+}
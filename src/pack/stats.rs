@@ -0,0 +1,142 @@
+// src/pack/stats.rs
+//! `pack --stats`: what each packed file costs, biggest first.
+
+use crate::tokens::Tokenizer;
+
+const TEXT_START: &str = "#__SLOPCHOP_FILE__# ";
+const TEXT_END: &str = "#__SLOPCHOP_END__#";
+const XML_START: &str = "<document path=\"";
+const XML_END: &str = "]]></document>";
+const CLAUDE_START: &str = "<source>";
+const CLAUDE_END: &str = "</document>";
+const OPENAI_START: &str = "## File: ";
+
+/// A packed file and the tokens it takes up.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileStat {
+    pub path: String,
+    pub tokens: usize,
+}
+
+/// A file being read out of the pack, up to its closing line.
+struct Block {
+    path: String,
+    text: String,
+    /// The line that closes it; for markdown, the fence it opened with.
+    end: Option<String>,
+    fenced: bool,
+}
+
+impl Block {
+    /// Adds `line`, returning true if it closes the block.
+    fn push(&mut self, line: &str) -> bool {
+        self.text.push_str(line);
+        self.text.push('\n');
+        let trimmed = line.trim_start();
+        match &self.end {
+            Some(end) if self.fenced => trimmed == end,
+            Some(end) => trimmed.starts_with(end.as_str()),
+            None if trimmed.starts_with("```") => {
+                let fence = trimmed.chars().take_while(|&c| c == '`').collect();
+                self.end = Some(fence);
+                false
+            }
+            None => false,
+        }
+    }
+}
+
+/// Token counts of the files in a generated pack, biggest first.
+#[must_use]
+pub fn file_stats(content: &str) -> Vec<FileStat> {
+    let mut stats = Vec::new();
+    let mut current: Option<Block> = None;
+    for line in content.lines() {
+        if let Some(block) = open_block(line) {
+            current = Some(block);
+            continue;
+        }
+        let Some(block) = current.as_mut() else {
+            continue;
+        };
+        if block.push(line) {
+            stats.push(FileStat {
+                path: std::mem::take(&mut block.path),
+                tokens: Tokenizer::count(&block.text),
+            });
+            current = None;
+        }
+    }
+    stats.sort_by(|a, b| b.tokens.cmp(&a.tokens).then_with(|| a.path.cmp(&b.path)));
+    stats
+}
+
+/// The block a file header line opens, in any pack format.
+fn open_block(line: &str) -> Option<Block> {
+    let trimmed = line.trim_start();
+    let (path, end) = if let Some(rest) = trimmed.strip_prefix(TEXT_START) {
+        (rest.split_whitespace().next()?, Some(TEXT_END))
+    } else if let Some(rest) = trimmed.strip_prefix(XML_START) {
+        (rest.split('"').next()?, Some(XML_END))
+    } else if let Some(rest) = trimmed.strip_prefix(CLAUDE_START) {
+        (
+            without_base(rest.strip_suffix("</source>")?),
+            Some(CLAUDE_END),
+        )
+    } else {
+        (
+            without_base(trimmed.strip_prefix(OPENAI_START)?.trim()),
+            None,
+        )
+    };
+    Some(Block {
+        path: path.to_string(),
+        text: format!("{line}\n"),
+        end: end.map(str::to_string),
+        fenced: end.is_none(),
+    })
+}
+
+/// `header` without a trailing ` [base:HASH]` tag.
+fn without_base(header: &str) -> &str {
+    header
+        .rsplit_once(" [base:")
+        .map_or(header, |(path, _)| path)
+}
+
+/// True if `line` starts a packed file, in any pack format.
+#[must_use]
+pub fn opens_file(line: &str) -> bool {
+    let trimmed = line.trim_start();
+    [TEXT_START, XML_START, "<document index=", OPENAI_START]
+        .iter()
+        .any(|s| trimmed.starts_with(s))
+}
+
+/// Prints each file's tokens and share of the `total` context.
+pub fn print(content: &str, total: usize) {
+    let stats = file_stats(content);
+    eprintln!("\n📊 Tokens by file:");
+    for s in &stats {
+        eprintln!(
+            "{:>9}  {:>5.1}%  {}",
+            s.tokens,
+            percent(s.tokens, total),
+            s.path
+        );
+    }
+    let packed: usize = stats.iter().map(|s| s.tokens).sum();
+    eprintln!(
+        "{:>9}  {:>5.1}%  (prompt and other text)",
+        total.saturating_sub(packed),
+        percent(total.saturating_sub(packed), total)
+    );
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn percent(part: usize, total: usize) -> f64 {
+    if total == 0 {
+        return 0.0;
+    }
+    part as f64 * 100.0 / total as f64
+}
@@ -0,0 +1,133 @@
+// src/pack/tree.rs
+//! The project tree that opens a pack: every discovered file with its
+//! token count, per-directory totals, and whether it was packed in full,
+//! as a skeleton, or left out.
+
+use std::collections::{BTreeMap, HashSet};
+use std::fmt::Write;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+use anyhow::Result;
+
+use super::{cache, formats, FocusContext, OutputFormat, PackOptions};
+
+/// How a file went into the pack.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Packed {
+    Full,
+    Skeleton,
+    Omitted,
+}
+
+#[derive(Default)]
+struct Dir {
+    tokens: usize,
+    dirs: BTreeMap<String, Dir>,
+    files: BTreeMap<String, (usize, Packed)>,
+}
+
+impl Dir {
+    fn insert(&mut self, parts: &[String], tokens: usize, packed: Packed) {
+        self.tokens += tokens;
+        match parts {
+            [] => {}
+            [name] => {
+                self.files.insert(name.clone(), (tokens, packed));
+            }
+            [dir, rest @ ..] => self
+                .dirs
+                .entry(dir.clone())
+                .or_default()
+                .insert(rest, tokens, packed),
+        }
+    }
+
+    fn render(&self, out: &mut String, depth: usize) {
+        let indent = "  ".repeat(depth);
+        for (name, dir) in &self.dirs {
+            let _ = writeln!(out, "{indent}{name}/ ({})", dir.tokens);
+            dir.render(out, depth + 1);
+        }
+        for (name, (tokens, packed)) in &self.files {
+            let mark = match packed {
+                Packed::Full => "",
+                Packed::Skeleton => " [skeleton]",
+                Packed::Omitted => " [omitted]",
+            };
+            let _ = writeln!(out, "{indent}{name} ({tokens}){mark}");
+        }
+    }
+}
+
+/// Renders `entries` (path, tokens, how it was packed) as an indented tree
+/// relative to their common directory.
+#[must_use]
+pub fn render(entries: &[(PathBuf, usize, Packed)]) -> String {
+    let root = common_root(entries.iter().map(|(p, _, _)| p.as_path()));
+    let mut top = Dir::default();
+    for (path, tokens, packed) in entries {
+        let rel = path.strip_prefix(&root).unwrap_or(path);
+        let parts: Vec<String> = rel
+            .components()
+            .filter(|c| matches!(c, Component::Normal(_)))
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        top.insert(&parts, *tokens, *packed);
+    }
+    let mut out = format!("./ ({})\n", top.tokens);
+    top.render(&mut out, 1);
+    out
+}
+
+fn common_root<'a>(mut paths: impl Iterator<Item = &'a Path>) -> PathBuf {
+    let Some(first) = paths.next() else {
+        return PathBuf::new();
+    };
+    let mut root = first.parent().map(Path::to_path_buf).unwrap_or_default();
+    for path in paths {
+        while !path.starts_with(&root) {
+            if !root.pop() {
+                return PathBuf::new();
+            }
+        }
+    }
+    root
+}
+
+/// Writes the tree of all discovered `files`, marking those not packed in
+/// full.
+///
+/// # Errors
+/// Returns error if writing to the buffer fails.
+pub fn write(
+    out: &mut String,
+    files: &[PathBuf],
+    pack_files: &[PathBuf],
+    focus: &FocusContext,
+    opts: &PackOptions,
+) -> Result<()> {
+    let packed: HashSet<&PathBuf> = pack_files.iter().collect();
+    let entries: Vec<_> = files
+        .iter()
+        .map(|path| {
+            let tokens = fs::read_to_string(path).map_or(0, |c| cache::tokens(path, &c));
+            let how = if !packed.contains(path) {
+                Packed::Omitted
+            } else if formats::packs_as_skeleton(path, focus, opts) {
+                Packed::Skeleton
+            } else {
+                Packed::Full
+            };
+            (path.clone(), tokens, how)
+        })
+        .collect();
+    let tree = render(&entries);
+    match opts.format {
+        OutputFormat::Text => writeln!(out, "# ═══ PROJECT TREE (tokens) ═══\n\n{tree}")?,
+        OutputFormat::Xml => writeln!(out, "<tree><![CDATA[\n{tree}]]></tree>")?,
+        OutputFormat::ClaudeXml => writeln!(out, "<project_tree>\n{tree}</project_tree>")?,
+        OutputFormat::Openai => writeln!(out, "## Project tree (tokens)\n\n```text\n{tree}```\n")?,
+    }
+    Ok(())
+}
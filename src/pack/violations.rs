@@ -0,0 +1,59 @@
+// src/pack/violations.rs
+//! The active violations and known debt listed at the top of a prompt pack.
+
+use std::fmt::Write;
+use std::path::PathBuf;
+
+use anyhow::Result;
+
+use crate::analysis::cache::CACHE_DIR;
+use crate::analysis::RuleEngine;
+use crate::config::Config;
+use crate::daemon;
+use crate::types::ScanReport;
+
+pub(super) fn inject(ctx: &mut String, files: &[PathBuf], config: &Config) -> Result<()> {
+    let report = daemon::client::scan(config, Some(files), true).unwrap_or_else(|| {
+        RuleEngine::new(config.clone())
+            .with_cache(CACHE_DIR)
+            .scan(files.to_vec())
+    });
+    write_violations(ctx, &report)?;
+    write_debt(ctx, &report)
+}
+
+fn write_violations(ctx: &mut String, report: &ScanReport) -> Result<()> {
+    if report.total_violations == 0 {
+        return Ok(());
+    }
+
+    writeln!(ctx, "{}", "═".repeat(67))?;
+    writeln!(ctx, "⚠️  ACTIVE VIOLATIONS (PRIORITY FIX REQUIRED)")?;
+    writeln!(ctx, "{}\n", "═".repeat(67))?;
+
+    for file in report.files.iter().filter(|f| !f.is_clean()) {
+        for v in &file.violations {
+            writeln!(ctx, "FILE: {}", file.path.display())?;
+            writeln!(ctx, "LAW:  {} | LINE: {} | {}", v.law, v.row + 1, v.message)?;
+            writeln!(ctx, "{}", "─".repeat(40))?;
+        }
+    }
+    writeln!(ctx)?;
+    Ok(())
+}
+
+fn write_debt(ctx: &mut String, report: &ScanReport) -> Result<()> {
+    if report.debt_count() == 0 {
+        return Ok(());
+    }
+
+    writeln!(ctx, "📝 KNOWN DEBT ({} markers)", report.debt_count())?;
+    for file in report.files.iter().filter(|f| !f.debt.is_empty()) {
+        writeln!(ctx, "FILE: {} ({})", file.path.display(), file.debt.len())?;
+        for d in &file.debt {
+            writeln!(ctx, "  LINE {}: {} {}", d.row + 1, d.marker, d.text)?;
+        }
+    }
+    writeln!(ctx)?;
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// src/pack/window.rs
+//! Context-window checks: pack warns when the output nears or exceeds the
+//! model's window and, with `--strict-budget`, fails instead.
+
+use anyhow::{bail, Result};
+use colored::Colorize;
+
+use super::budget::Budget;
+use crate::config::Config;
+use crate::tokens::Tokenizer;
+
+/// Share of the window at which pack starts warning.
+pub const WARN_PERCENT: usize = 80;
+
+/// Parses a window size: `128k`, `200k`, `1m`, or a plain token count.
+///
+/// # Errors
+/// Returns error if `s` is not a positive size.
+pub fn parse(s: &str) -> Result<usize, String> {
+    let lower = s.trim().to_lowercase();
+    let (digits, scale) = match lower.strip_suffix('k') {
+        Some(d) => (d, 1_000),
+        None => lower
+            .strip_suffix('m')
+            .map_or((lower.as_str(), 1), |d| (d, 1_000_000)),
+    };
+    match digits.parse::<usize>() {
+        Ok(n) if n > 0 => Ok(n * scale),
+        _ => Err(format!(
+            "invalid window '{s}' (expected e.g. 128k, 200k, 1m)"
+        )),
+    }
+}
+
+/// The window to check against: `--window`, else `[tokens] window`, else
+/// the token model's.
+#[must_use]
+pub fn size(budget: Budget, config: &Config) -> usize {
+    budget
+        .window
+        .or(config.tokens.window)
+        .unwrap_or_else(|| Tokenizer::model().context_window())
+}
+
+/// Percentage of `window` that `tokens` fill, rounded down.
+#[must_use]
+pub fn percent(tokens: usize, window: usize) -> usize {
+    tokens.saturating_mul(100) / window.max(1)
+}
+
+/// Warns if the pack nears or exceeds the window.
+///
+/// # Errors
+/// With `--strict-budget`, returns error if the pack is over the window or
+/// over `--budget`.
+pub fn check(tokens: usize, budget: Budget, config: &Config) -> Result<()> {
+    let window = size(budget, config);
+    let used = percent(tokens, window);
+    if tokens > window {
+        let msg = format!(
+            "⚠️  Pack is {tokens} tokens, {used}% of the {window}-token context window ({} over)",
+            tokens - window
+        );
+        eprintln!("{}", msg.red().bold());
+    } else if used >= WARN_PERCENT {
+        let msg =
+            format!("⚠️  Pack is {tokens} tokens, {used}% of the {window}-token context window");
+        eprintln!("{}", msg.yellow());
+    }
+    if !budget.strict {
+        return Ok(());
+    }
+    if tokens > window {
+        bail!("Pack exceeds the {window}-token context window ({tokens} tokens)");
+    }
+    if let Some(limit) = budget.limit.filter(|&l| tokens > l) {
+        bail!("Pack exceeds the {limit}-token budget ({tokens} tokens)");
+    }
+    Ok(())
+}
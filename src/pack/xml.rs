@@ -0,0 +1,113 @@
+// src/pack/xml.rs
+//! `pack --format xml`: one CDATA `<document>` per file.
+
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::Result;
+
+use super::cache;
+use super::formats::{full_text, oversized, should_skeletonize};
+use super::{FocusContext, PackOptions};
+use crate::apply::manifest;
+
+/// Packs files into an XML format.
+///
+/// # Errors
+/// Returns an error if file reading fails.
+pub fn pack_xml(files: &[PathBuf], out: &mut String, opts: &PackOptions) -> Result<()> {
+    writeln!(out, "<documents>")?;
+    for path in files {
+        match oversized(path, opts) {
+            Some(tokens) => {
+                let attr = format!(" skeleton=\"oversized\" tokens=\"{tokens}\"");
+                write_xml_doc(out, path, true, &attr, opts)?;
+            }
+            None => write_xml_doc(out, path, should_skeletonize(path, opts), "", opts)?,
+        }
+    }
+    writeln!(out, "</documents>")?;
+    Ok(())
+}
+
+/// Packs files into XML format with focus awareness.
+///
+/// # Errors
+/// Returns an error if file reading fails.
+pub fn pack_xml_focus(
+    files: &[PathBuf],
+    out: &mut String,
+    opts: &PackOptions,
+    focus: &FocusContext,
+) -> Result<()> {
+    if focus.foveal.is_empty() && focus.peripheral.is_empty() {
+        return pack_xml(files, out, opts);
+    }
+
+    writeln!(out, "<documents>")?;
+    write_xml_foveal(out, files, focus, opts)?;
+    write_xml_peripheral(out, files, focus, opts)?;
+    writeln!(out, "</documents>")?;
+
+    Ok(())
+}
+
+fn write_xml_foveal(
+    out: &mut String,
+    files: &[PathBuf],
+    focus: &FocusContext,
+    opts: &PackOptions,
+) -> Result<()> {
+    for path in files.iter().filter(|f| focus.foveal.contains(*f)) {
+        write_xml_doc(out, path, false, " focus=\"foveal\"", opts)?;
+    }
+    Ok(())
+}
+
+fn write_xml_peripheral(
+    out: &mut String,
+    files: &[PathBuf],
+    focus: &FocusContext,
+    opts: &PackOptions,
+) -> Result<()> {
+    for path in files.iter().filter(|f| focus.peripheral.contains(*f)) {
+        write_xml_doc(out, path, true, " focus=\"peripheral\"", opts)?;
+    }
+    Ok(())
+}
+
+fn write_xml_doc(
+    out: &mut String,
+    path: &Path,
+    skeletonize: bool,
+    attr: &str,
+    opts: &PackOptions,
+) -> Result<()> {
+    let p_str = path.to_string_lossy().replace('\\', "/");
+    let open = |out: &mut String, mark: &str| {
+        writeln!(out, "  <document path=\"{p_str}\"{attr}{mark}><![CDATA[")
+    };
+
+    match fs::read_to_string(path) {
+        Ok(content) => {
+            let (text, mark) = if skeletonize {
+                (cache::skeleton(path, &content), String::new())
+            } else {
+                let base = format!(" base=\"{}\"", manifest::base_hash(&content));
+                let (text, stripped) = full_text(path, content, opts);
+                let stripped = if stripped { " stripped=\"true\"" } else { "" };
+                (text, format!("{base}{stripped}"))
+            };
+            open(out, &mark)?;
+            out.push_str(&text.replace("]]>", "]]]]><![CDATA[>"));
+        }
+        Err(e) => {
+            open(out, "")?;
+            writeln!(out, "<!-- ERROR: {e} -->")?;
+        }
+    }
+
+    writeln!(out, "]]></document>")?;
+    Ok(())
+}
@@ -0,0 +1,228 @@
+// src/project/mod.rs
+//! Project detection, and the `warden.toml` that `--init` writes for what
+//! it finds.
+
+mod profile;
+mod template;
+
+pub use self::profile::default_commands;
+pub use self::template::{generate_toml, generate_toml_in, rules_toml};
+
+use crate::constants;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProjectType {
+    Rust,
+    Node,
+    Deno,
+    Bun,
+    Python,
+    Go,
+    Maven,
+    Gradle,
+    DotNet,
+    /// More than one project, or one below the root: see [`members`].
+    Mixed,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum Strictness {
+    Strict,
+    Standard,
+    Relaxed,
+}
+
+impl Strictness {
+    /// The level called `name` (`strict`, `standard`, `relaxed`).
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "strict" => Some(Self::Strict),
+            "standard" => Some(Self::Standard),
+            "relaxed" => Some(Self::Relaxed),
+            _ => None,
+        }
+    }
+
+    /// The name `from_name` reads.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Strict => "strict",
+            Self::Standard => "standard",
+            Self::Relaxed => "relaxed",
+        }
+    }
+}
+
+/// A project found by [`members`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Member {
+    pub kind: ProjectType,
+    /// Relative to the root; empty for the root itself.
+    pub dir: PathBuf,
+}
+
+/// Marker files by type. Within a group the first type found wins, so a
+/// Deno or Bun project isn't also taken for Node.
+const MARKERS: &[&[(ProjectType, &[&str])]] = &[
+    &[(ProjectType::Rust, &["Cargo.toml"])],
+    &[
+        (ProjectType::Deno, &["deno.json", "deno.jsonc"]),
+        (ProjectType::Bun, &["bun.lockb", "bun.lock", "bunfig.toml"]),
+        (ProjectType::Node, &["package.json"]),
+    ],
+    &[(
+        ProjectType::Python,
+        &["pyproject.toml", "requirements.txt", "Pipfile", "setup.py"],
+    )],
+    &[(ProjectType::Go, &["go.mod"])],
+    &[
+        (ProjectType::Maven, &["pom.xml"]),
+        (
+            ProjectType::Gradle,
+            &[
+                "build.gradle",
+                "build.gradle.kts",
+                "settings.gradle",
+                "settings.gradle.kts",
+            ],
+        ),
+    ],
+];
+
+/// Extensions of .NET solution and project files.
+const DOTNET_EXTENSIONS: &[&str] = &["sln", "csproj", "fsproj", "vbproj"];
+
+impl ProjectType {
+    /// Detects project type from current directory.
+    #[must_use]
+    pub fn detect() -> Self {
+        Self::detect_in(Path::new("."))
+    }
+
+    /// Detects project type in a specific directory.
+    #[must_use]
+    pub fn detect_in(root: &Path) -> Self {
+        match members(root).as_slice() {
+            [] => Self::Unknown,
+            [only] if only.dir.as_os_str().is_empty() => only.kind,
+            _ => Self::Mixed,
+        }
+    }
+
+    /// Detects if this is a TypeScript project
+    #[must_use]
+    pub fn is_typescript() -> bool {
+        is_typescript_in(Path::new("."))
+    }
+
+    /// The lowercase name used for generated `[verify.*]` pipelines
+    /// (`rust`, `dotnet`, ...).
+    #[must_use]
+    pub fn name(self) -> String {
+        format!("{self:?}").to_lowercase()
+    }
+}
+
+/// Every project under `root`: the root's own, then those in directories
+/// up to two levels down (`web/`, `services/api/`) of a type the root
+/// isn't, since a workspace root already covers its own members.
+#[must_use]
+pub fn members(root: &Path) -> Vec<Member> {
+    let kinds = kinds_in(root);
+    let mut found: Vec<Member> = kinds
+        .iter()
+        .map(|&kind| Member {
+            kind,
+            dir: PathBuf::new(),
+        })
+        .collect();
+    collect_members(root, Path::new(""), 2, &kinds, &mut found);
+    found
+}
+
+fn collect_members(
+    root: &Path,
+    rel: &Path,
+    depth: usize,
+    skip: &[ProjectType],
+    found: &mut Vec<Member>,
+) {
+    for dir in subdirs(&root.join(rel)).into_iter().map(|d| rel.join(d)) {
+        let kinds = kinds_in(&root.join(&dir));
+        if kinds.is_empty() && depth > 1 {
+            collect_members(root, &dir, depth - 1, skip, found);
+        }
+        let new = kinds.into_iter().filter(|k| !skip.contains(k));
+        found.extend(new.map(|kind| Member {
+            kind,
+            dir: dir.clone(),
+        }));
+    }
+}
+
+/// The names of the directories in `dir` worth looking into, sorted.
+fn subdirs(dir: &Path) -> Vec<String> {
+    let Ok(entries) = dir.read_dir() else {
+        return Vec::new();
+    };
+    let mut names: Vec<String> = entries
+        .flatten()
+        .filter(|e| e.file_type().is_ok_and(|t| t.is_dir()))
+        .filter_map(|e| e.file_name().to_str().map(String::from))
+        .filter(|n| !n.starts_with('.') && !constants::should_prune(n))
+        .collect();
+    names.sort();
+    names
+}
+
+/// The types whose marker files are in `dir` itself.
+fn kinds_in(dir: &Path) -> Vec<ProjectType> {
+    let mut kinds: Vec<ProjectType> = MARKERS
+        .iter()
+        .filter_map(|group| {
+            group
+                .iter()
+                .find(|(_, files)| files.iter().any(|f| dir.join(f).exists()))
+                .map(|(kind, _)| *kind)
+        })
+        .collect();
+    if has_extension(dir, DOTNET_EXTENSIONS) {
+        kinds.push(ProjectType::DotNet);
+    }
+    kinds
+}
+
+fn has_extension(dir: &Path, extensions: &[&str]) -> bool {
+    dir.read_dir().is_ok_and(|entries| {
+        entries.flatten().any(|e| {
+            e.path()
+                .extension()
+                .and_then(|ext| ext.to_str())
+                .is_some_and(|ext| extensions.contains(&ext))
+        })
+    })
+}
+
+/// True if the Node project in `dir` uses TypeScript.
+#[must_use]
+pub fn is_typescript_in(dir: &Path) -> bool {
+    dir.join("tsconfig.json").exists()
+        || dir.join("tsconfig.node.json").exists()
+        || has_extension(&dir.join("src"), &["ts", "tsx"])
+}
+
+#[must_use]
+pub fn npx_cmd() -> &'static str {
+    if cfg!(windows) {
+        "npx.cmd"
+    } else {
+        "npx"
+    }
+}
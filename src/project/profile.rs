@@ -0,0 +1,219 @@
+// src/project/profile.rs
+//! The commands and ignore patterns each project type starts with.
+
+use super::{is_typescript_in, members, npx_cmd, ProjectType};
+use crate::config::CommandStep;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+const NODE_EXTENSIONS: &[&str] = &["ts", "tsx", "js", "jsx", "mjs", "cjs"];
+const JS_TEST_NAMES: &[&str] = &["*.test.*", "__tests__"];
+
+/// What one project type brings to the generated config.
+pub struct Profile {
+    pub check: Vec<String>,
+    pub fix: Vec<String>,
+    /// Extensions its `[verify.*]` pipeline runs for.
+    pub extensions: &'static [&'static str],
+    /// Added to `ignore_naming_on`.
+    pub naming: &'static [&'static str],
+    /// Added to `ignore_tokens_on`.
+    pub tokens: &'static [&'static str],
+    /// Added to `[pack] generated`.
+    pub generated: &'static [&'static str],
+}
+
+impl Profile {
+    fn new(check: Vec<String>, fix: Vec<String>, extensions: &'static [&'static str]) -> Self {
+        Self {
+            check,
+            fix,
+            extensions,
+            naming: &[],
+            tokens: &[],
+            generated: &[],
+        }
+    }
+
+    /// The profile of the `kind` project in `dir`; `Mixed` and `Unknown`
+    /// have none.
+    #[must_use]
+    pub fn of(kind: ProjectType, dir: &Path) -> Option<Self> {
+        PROFILES
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, build)| build(dir))
+    }
+}
+
+type Build = fn(&Path) -> Profile;
+
+const PROFILES: [(ProjectType, Build); 9] = [
+    (ProjectType::Rust, rust),
+    (ProjectType::Node, node),
+    (ProjectType::Deno, deno),
+    (ProjectType::Bun, bun),
+    (ProjectType::Python, python),
+    (ProjectType::Go, go),
+    (ProjectType::Maven, maven),
+    (ProjectType::Gradle, gradle),
+    (ProjectType::DotNet, dotnet),
+];
+
+fn lines(commands: &[&str]) -> Vec<String> {
+    commands.iter().map(ToString::to_string).collect()
+}
+
+fn rust(_: &Path) -> Profile {
+    Profile::new(
+        lines(&[
+            "cargo clippy --all-targets -- -D warnings -W clippy::pedantic",
+            "cargo test",
+        ]),
+        lines(&["cargo fmt"]),
+        &["rs"],
+    )
+}
+
+fn node(dir: &Path) -> Profile {
+    let npx = npx_cmd();
+    let (check, fix) = if is_typescript_in(dir) {
+        (
+            format!("{npx} @biomejs/biome check src/"),
+            format!("{npx} @biomejs/biome check --write src/"),
+        )
+    } else {
+        (
+            format!("{npx} eslint src/"),
+            format!("{npx} eslint --fix src/"),
+        )
+    };
+    Profile {
+        naming: JS_TEST_NAMES,
+        ..Profile::new(vec![check], vec![fix], NODE_EXTENSIONS)
+    }
+}
+
+fn deno(_: &Path) -> Profile {
+    Profile {
+        naming: JS_TEST_NAMES,
+        ..Profile::new(
+            lines(&["deno lint", "deno fmt --check"]),
+            lines(&["deno fmt", "deno lint --fix"]),
+            NODE_EXTENSIONS,
+        )
+    }
+}
+
+fn bun(_: &Path) -> Profile {
+    Profile {
+        naming: JS_TEST_NAMES,
+        tokens: &["bun.lockb"],
+        ..Profile::new(
+            lines(&["bunx @biomejs/biome check src/"]),
+            lines(&["bunx @biomejs/biome check --write src/"]),
+            NODE_EXTENSIONS,
+        )
+    }
+}
+
+/// Ruff, through `poetry run` in a Poetry project.
+fn python(dir: &Path) -> Profile {
+    let run = if is_poetry(dir) { "poetry run " } else { "" };
+    Profile {
+        naming: &["test_*.py", "conftest.py"],
+        generated: &["**/*_pb2_grpc.py"],
+        ..Profile::new(
+            vec![format!("{run}ruff check .")],
+            vec![format!("{run}ruff check --fix .")],
+            &["py"],
+        )
+    }
+}
+
+fn is_poetry(dir: &Path) -> bool {
+    dir.join("poetry.lock").exists()
+        || fs::read_to_string(dir.join("pyproject.toml")).is_ok_and(|c| c.contains("[tool.poetry"))
+}
+
+fn go(_: &Path) -> Profile {
+    Profile {
+        tokens: &["go.sum"],
+        ..Profile::new(
+            lines(&["go vet ./...", "go test ./..."]),
+            lines(&["go fmt ./..."]),
+            &["go"],
+        )
+    }
+}
+
+fn maven(dir: &Path) -> Profile {
+    let mvn = wrapper(dir, "mvnw", "mvn");
+    Profile {
+        naming: &["test"],
+        ..Profile::new(
+            vec![format!("{mvn} -q -B test")],
+            Vec::new(),
+            &["java", "kt"],
+        )
+    }
+}
+
+fn gradle(dir: &Path) -> Profile {
+    let gradle = wrapper(dir, "gradlew", "gradle");
+    Profile {
+        naming: &["test"],
+        ..Profile::new(
+            vec![format!("{gradle} check")],
+            Vec::new(),
+            &["java", "kt", "kts"],
+        )
+    }
+}
+
+fn dotnet(_: &Path) -> Profile {
+    Profile {
+        naming: &["*.Tests", "*Tests.cs"],
+        tokens: &["packages.lock.json"],
+        generated: &["**/*.Designer.cs", "**/*.g.cs", "**/obj/**"],
+        ..Profile::new(
+            lines(&["dotnet build", "dotnet test --no-build"]),
+            lines(&["dotnet format"]),
+            &["cs", "fs", "vb"],
+        )
+    }
+}
+
+/// `./<script>` if `dir` has the build tool's wrapper script (outside
+/// Windows, where it would be a `.cmd` or `.bat`), else `tool`.
+fn wrapper(dir: &Path, script: &str, tool: &str) -> String {
+    if !cfg!(windows) && dir.join(script).exists() {
+        format!("./{script}")
+    } else {
+        tool.to_string()
+    }
+}
+
+/// The `check` and `fix` commands for the project in `root`, used when
+/// `warden.toml` sets none. In a mixed repo each step runs in its
+/// project's directory.
+#[must_use]
+pub fn default_commands(root: &Path) -> HashMap<String, Vec<CommandStep>> {
+    let mut commands: HashMap<String, Vec<CommandStep>> = HashMap::new();
+    for member in members(root) {
+        let Some(profile) = Profile::of(member.kind, &root.join(&member.dir)) else {
+            continue;
+        };
+        let cwd = (!member.dir.as_os_str().is_empty()).then_some(member.dir);
+        for (name, runs) in [("check", profile.check), ("fix", profile.fix)] {
+            let steps = commands.entry(name.to_string()).or_default();
+            steps.extend(runs.into_iter().map(|run| CommandStep {
+                cwd: cwd.clone(),
+                ..CommandStep::line(run)
+            }));
+        }
+    }
+    commands.retain(|_, steps| !steps.is_empty());
+    commands
+}
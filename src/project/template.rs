@@ -0,0 +1,198 @@
+// src/project/template.rs
+//! The `warden.toml` that `--init` writes.
+
+use super::profile::Profile;
+use super::{members, Member, ProjectType, Strictness};
+use crate::config::limits::default_ignore_tokens;
+use crate::config::sections::default_generated;
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+/// `ignore_naming_on` before any project type adds to it.
+const NAMING: &[&str] = &["tests", "spec", "*.spec.*", "*_test.*"];
+
+#[must_use]
+pub fn generate_toml(project: ProjectType, strictness: Strictness) -> String {
+    generate_toml_in(Path::new("."), project, strictness)
+}
+
+/// The config for the `project` in `root`. A `Mixed` project runs each
+/// member's commands in its directory and gets a `[verify.*]` pipeline
+/// per member.
+#[must_use]
+pub fn generate_toml_in(root: &Path, project: ProjectType, strictness: Strictness) -> String {
+    let found = match project {
+        ProjectType::Mixed => members(root),
+        kind => vec![Member {
+            kind,
+            dir: PathBuf::new(),
+        }],
+    };
+    let profiled: Vec<(Member, Profile)> = found
+        .into_iter()
+        .filter_map(|m| Profile::of(m.kind, &root.join(&m.dir)).map(|p| (m, p)))
+        .collect();
+
+    let naming = merged(NAMING.iter().map(ToString::to_string), &profiled, |p| {
+        p.naming
+    });
+    let mut out = format!("# warden.toml\n{}", rules_with(strictness, &naming));
+    if profiled.iter().any(|(_, p)| !p.tokens.is_empty()) {
+        let tokens = merged(default_ignore_tokens(), &profiled, |p| p.tokens);
+        let _ = write!(out, "\nignore_tokens_on = {}", inline(&tokens));
+    }
+    let _ = write!(out, "\n\n{}\n", commands_section(&profiled));
+    if project == ProjectType::Mixed {
+        for (m, p) in &profiled {
+            verify_section(&mut out, m, p);
+        }
+    }
+    if profiled.iter().any(|(_, p)| !p.generated.is_empty()) {
+        let generated = merged(default_generated(), &profiled, |p| p.generated);
+        let _ = write!(out, "\n[pack]\ngenerated = {}\n", multiline(&generated));
+    }
+    out
+}
+
+/// The `[rules]` table for `strictness`, also the `preset:<level>` base.
+#[must_use]
+pub fn rules_toml(strictness: Strictness) -> String {
+    let naming: Vec<String> = NAMING.iter().map(ToString::to_string).collect();
+    rules_with(strictness, &naming)
+}
+
+fn rules_with(strictness: Strictness, naming: &[String]) -> String {
+    let (tokens, complexity, depth) = match strictness {
+        Strictness::Strict => (1500, 4, 2),
+        Strictness::Standard => (2000, 8, 3),
+        Strictness::Relaxed => (3000, 12, 4),
+    };
+
+    format!(
+        r"[rules]
+max_file_tokens = {tokens}
+max_cyclomatic_complexity = {complexity}
+max_nesting_depth = {depth}
+max_function_args = 5
+max_function_words = 5
+ignore_naming_on = {}",
+        inline(naming)
+    )
+}
+
+/// `base` followed by what each profile adds through `extra`, without
+/// repeats.
+fn merged(
+    base: impl IntoIterator<Item = String>,
+    profiled: &[(Member, Profile)],
+    extra: fn(&Profile) -> &'static [&'static str],
+) -> Vec<String> {
+    let mut all: Vec<String> = base.into_iter().collect();
+    for pattern in profiled.iter().flat_map(|(_, p)| extra(p)) {
+        if !all.iter().any(|p| p == pattern) {
+            all.push((*pattern).to_string());
+        }
+    }
+    all
+}
+
+fn commands_section(profiled: &[(Member, Profile)]) -> String {
+    if profiled.is_empty() {
+        return r#"# No project type detected. Configure commands manually:
+# [commands]
+# check = "your-lint-command"
+# fix = "your-fix-command""#
+            .to_string();
+    }
+    let check = steps(profiled, |p| &p.check);
+    let fix = steps(profiled, |p| &p.fix);
+    let mut out = String::from("[commands]");
+    for (name, steps) in [("check", check), ("fix", fix)] {
+        match steps.as_slice() {
+            [] => {}
+            [one] => {
+                let _ = write!(out, "\n{name} = {one}");
+            }
+            _ => {
+                let _ = write!(out, "\n{name} = {}", multiline_raw(&steps));
+            }
+        }
+    }
+    out
+}
+
+/// The steps `pick` names across all members.
+fn steps(profiled: &[(Member, Profile)], pick: impl Fn(&Profile) -> &Vec<String>) -> Vec<String> {
+    profiled
+        .iter()
+        .flat_map(|(m, p)| pick(p).iter().map(|run| step(m, run)))
+        .collect()
+}
+
+/// `run` as a `[commands]` step: a plain line at the root, or a table
+/// running in the member's directory.
+fn step(member: &Member, run: &str) -> String {
+    if member.dir.as_os_str().is_empty() {
+        quote(run)
+    } else {
+        format!(
+            "{{ run = {}, cwd = {} }}",
+            quote(run),
+            quote(&dir_of(member))
+        )
+    }
+}
+
+fn verify_section(out: &mut String, member: &Member, profile: &Profile) {
+    if profile.check.is_empty() {
+        return;
+    }
+    let dir = dir_of(member);
+    let extensions: Vec<String> = profile.extensions.iter().map(ToString::to_string).collect();
+    if dir.is_empty() {
+        let _ = write!(out, "\n[verify.{}]\n", member.kind.name());
+    } else {
+        let name = format!("{}-{}", member.kind.name(), dir.replace('/', "-"));
+        let _ = write!(out, "\n[verify.{}]\ncwd = {}\n", key(&name), quote(&dir));
+    }
+    let _ = writeln!(out, "extensions = {}", inline(&extensions));
+    let _ = writeln!(out, "commands = {}", inline(&profile.check));
+}
+
+fn dir_of(member: &Member) -> String {
+    member.dir.to_string_lossy().replace('\\', "/")
+}
+
+/// `name` as a TOML key, quoted unless it is a bare key.
+fn key(name: &str) -> String {
+    let bare = name
+        .chars()
+        .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+    if bare {
+        name.to_string()
+    } else {
+        quote(name)
+    }
+}
+
+fn quote(s: &str) -> String {
+    toml::Value::String(s.to_string()).to_string()
+}
+
+fn inline(items: &[String]) -> String {
+    let quoted: Vec<String> = items.iter().map(|s| quote(s)).collect();
+    format!("[{}]", quoted.join(", "))
+}
+
+fn multiline(items: &[String]) -> String {
+    let quoted: Vec<String> = items.iter().map(|s| quote(s)).collect();
+    multiline_raw(&quoted)
+}
+
+fn multiline_raw(items: &[String]) -> String {
+    let body = items.iter().fold(String::new(), |mut body, s| {
+        let _ = writeln!(body, "    {s},");
+        body
+    });
+    format!("[\n{body}]")
+}
@@ -4,12 +4,24 @@ use anyhow::Result;
 
 pub struct PromptGenerator {
     config: RuleConfig,
+    line_counts: bool,
 }
 
 impl PromptGenerator {
     #[must_use]
     pub fn new(config: RuleConfig) -> Self {
-        Self { config }
+        Self {
+            config,
+            line_counts: false,
+        }
+    }
+
+    /// Also asks for `[lines:N]` on each manifest entry
+    /// (`[apply.truncation] line_counts`).
+    #[must_use]
+    pub fn with_line_counts(mut self, line_counts: bool) -> Self {
+        self.line_counts = line_counts;
+        self
     }
 
     /// Generates the full system prompt.
@@ -34,11 +46,13 @@ impl PromptGenerator {
     }
 
     fn build_system_prompt(&self) -> String {
-        let tokens = self.config.max_file_tokens;
-        let complexity = self.config.max_cyclomatic_complexity;
-        let depth = self.config.max_nesting_depth;
-        let args = self.config.max_function_args;
-        let output_format = build_output_format();
+        let tokens = self.config.size.max_file_tokens;
+        let complexity = self.config.functions.max_cyclomatic_complexity;
+        let cognitive = self.config.functions.max_cognitive_complexity;
+        let depth = self.config.functions.max_nesting_depth;
+        let args = self.config.functions.max_function_args;
+        let fields = self.config.max_struct_fields;
+        let output_format = build_output_format(self.line_counts);
 
         format!(
             r"🛡️ SYSTEM MANDATE: THE SLOPCHOP PROTOCOL
@@ -53,8 +67,10 @@ THE 3 LAWS (Non-Negotiable):
 
 2. LAW OF COMPLEXITY
    - Cyclomatic Complexity: MUST be ≤ {complexity} per function.
+   - Cognitive Complexity: MUST be ≤ {cognitive} per function.
    - Nesting Depth: MUST be ≤ {depth} levels.
    - Function Arguments: MUST be ≤ {args} parameters.
+   - Struct/Class Fields: MUST be ≤ {fields} fields.
 
 3. LAW OF PARANOIA
    - Use Result<T, E> for I/O and fallible operations.
@@ -66,10 +82,10 @@ THE 3 LAWS (Non-Negotiable):
     }
 
     fn build_reminder(&self) -> String {
-        let tokens = self.config.max_file_tokens;
-        let complexity = self.config.max_cyclomatic_complexity;
-        let depth = self.config.max_nesting_depth;
-        let args = self.config.max_function_args;
+        let tokens = self.config.size.max_file_tokens;
+        let complexity = self.config.functions.max_cyclomatic_complexity;
+        let depth = self.config.functions.max_nesting_depth;
+        let args = self.config.functions.max_function_args;
 
         format!(
             r"SLOPCHOP CONSTRAINTS:
@@ -83,8 +99,17 @@ THE 3 LAWS (Non-Negotiable):
     }
 }
 
-fn build_output_format() -> String {
-    r#"OUTPUT FORMAT (MANDATORY):
+fn build_output_format(line_counts: bool) -> String {
+    let (lines_tag, lines_rule) = if line_counts {
+        (
+            " [lines:42]",
+            "\nAdd [lines:N] with the line count of each file you send so a cut-off payload is caught.",
+        )
+    } else {
+        ("", "")
+    };
+    format!(
+        r#"OUTPUT FORMAT (MANDATORY):
 
 1. Explain the changes (Technical Plan):
    - Must start with "GOAL:"
@@ -100,20 +125,50 @@ CHANGES:
 2. Declare the plan (Manifest):
 
 #__SLOPCHOP_MANIFEST__#
-path/to/file1.rs
-path/to/file2.rs [NEW]
+path/to/file1.rs [base:1a2b3c4d]
+path/to/file2.rs [NEW]{lines_tag}
+path/to/old.rs -> path/to/moved.rs [RENAME]
 #__SLOPCHOP_END__#
 
+A [RENAME] keeps the file's content; send a file block for the new path only if it also changes.
+Copy the [base:...] tag from the file's header in the packed context (in XML packs, the `base` attribute, as [base:HASH]) so edits to files changed since then are rejected.{lines_rule}
+
 3. Provide EACH file:
 
 #__SLOPCHOP_FILE__# path/to/file1.rs
 [file content]
 #__SLOPCHOP_END__#
 
+For a small edit to an existing file, you MAY send search/replace blocks instead of the whole file:
+
+#__SLOPCHOP_EDIT__# path/to/file1.rs
+<<<<<<< SEARCH
+exact lines from the current file
+=======
+replacement lines
+>>>>>>> REPLACE
+#__SLOPCHOP_END__#
+
+Or a unified diff:
+
+```diff
+--- a/path/to/file1.rs
++++ b/path/to/file1.rs
+@@ -10,3 +10,3 @@
+ context line
+-old line
++new line
+ context line
+```
+
 RULES:
-- Do NOT use markdown code blocks (e.g. triple backticks) to wrap the file. The #__SLOPCHOP_FILE__# delimiters ARE the fence.
+- Do NOT use markdown code blocks (e.g. triple backticks) to wrap the file. The #__SLOPCHOP_FILE__# delimiters ARE the fence. Only diffs go in ```diff blocks.
 - You MAY use markdown inside the file content.
-- Every file in the manifest MUST have a matching #__SLOPCHOP_FILE__# block.
+- Every file in the manifest MUST have a matching #__SLOPCHOP_FILE__# block, edit block, or diff.
+- Each SEARCH must match exactly one place in the current file.
+- Diff hunks need at least 3 lines of unchanged context, copied exactly.
 - Paths must match exactly.
-- Do NOT truncate files (No "// ...")."#.to_string()
+- Do NOT truncate files (No "// ...").
+- If the response won't fit in one message, split it between file blocks and end the last message with #__SLOPCHOP_DONE__#."#
+    )
 }
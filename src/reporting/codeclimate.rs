@@ -0,0 +1,112 @@
+// src/reporting/codeclimate.rs
+//! Code Climate issues JSON (`check --format code-climate`), which GitLab
+//! renders in the merge request Code Quality widget.
+//!
+//! Fingerprints hash the path, rule, and the offending line's text rather
+//! than its number, so an issue keeps its identity when code above it moves.
+
+use crate::analysis::cache::fnv1a;
+use crate::types::{ScanReport, Severity, Violation};
+use anyhow::Result;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Serialize)]
+pub struct Issue {
+    #[serde(rename = "type")]
+    pub kind: &'static str,
+    pub check_name: String,
+    pub description: String,
+    pub categories: [&'static str; 1],
+    pub severity: &'static str,
+    pub fingerprint: String,
+    pub location: Location,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Location {
+    pub path: String,
+    pub lines: Lines,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Lines {
+    /// 1-based.
+    pub begin: usize,
+}
+
+/// Builds one issue per failing violation.
+#[must_use]
+pub fn issues(report: &ScanReport) -> Vec<Issue> {
+    let mut seen: HashMap<u64, usize> = HashMap::new();
+    let mut out = Vec::new();
+    for file in &report.files {
+        let content = fs::read_to_string(&file.path).unwrap_or_default();
+        let lines: Vec<&str> = content.lines().collect();
+        for v in &file.violations {
+            let text = lines.get(v.row).map_or("", |l| l.trim());
+            let base = fingerprint(&file.path, v, text);
+            let nth = seen.entry(base).or_default();
+            // Repeats of the same text get their own, still stable, ids.
+            let print = fnv1a(&[&base.to_le_bytes(), &nth.to_le_bytes()]);
+            *nth += 1;
+            out.push(issue(&file.path, v, print));
+        }
+    }
+    out
+}
+
+/// Renders `report` as a Code Climate issues array.
+///
+/// # Errors
+/// Returns error if serialization fails.
+pub fn render(report: &ScanReport) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&issues(report))?)
+}
+
+/// Prints `report` as Code Climate JSON to stdout.
+///
+/// # Errors
+/// Returns error if serialization fails.
+pub fn print(report: &ScanReport) -> Result<()> {
+    println!("{}", render(report)?);
+    Ok(())
+}
+
+fn fingerprint(path: &Path, v: &Violation, text: &str) -> u64 {
+    let path = path.to_string_lossy().replace('\\', "/");
+    fnv1a(&[path.as_bytes(), v.rule().as_bytes(), text.as_bytes()])
+}
+
+fn issue(path: &Path, v: &Violation, print: u64) -> Issue {
+    Issue {
+        kind: "issue",
+        check_name: v.rule(),
+        description: v.message.clone(),
+        categories: [category(v.law)],
+        severity: severity(v.severity),
+        fingerprint: format!("{print:016x}"),
+        location: Location {
+            path: path.to_string_lossy().replace('\\', "/"),
+            lines: Lines { begin: v.row + 1 },
+        },
+    }
+}
+
+fn category(law: &str) -> &'static str {
+    match law {
+        "LAW OF PARANOIA" | "LAW OF SAFETY" => "Bug Risk",
+        "LAW OF COMPLEXITY" | "LAW OF ATOMICITY" => "Complexity",
+        _ => "Style",
+    }
+}
+
+fn severity(severity: Severity) -> &'static str {
+    match severity {
+        Severity::Error => "major",
+        Severity::Warning => "minor",
+        Severity::Info => "info",
+    }
+}
@@ -0,0 +1,87 @@
+// src/reporting/group.rs
+//! Violations aggregated by law or rule (`--group-by`), with the worst
+//! offending files under each, for a project-wide picture.
+
+use crate::types::{ScanReport, Violation};
+use clap::ValueEnum;
+use colored::Colorize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::path::Path;
+
+/// Files listed under each group.
+const WORST: usize = 5;
+
+/// How the text report lists violations.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum GroupBy {
+    /// Every violation, file by file.
+    #[default]
+    File,
+    /// Counts per law.
+    Law,
+    /// Counts per rule and check, e.g. `complexity: Deep Nesting`.
+    Rule,
+}
+
+/// One group's total and its files, most violations first.
+#[derive(Debug)]
+pub struct Group<'a> {
+    pub name: String,
+    pub count: usize,
+    pub files: Vec<(&'a Path, usize)>,
+}
+
+/// Groups failing violations, largest group first. `File` yields one group
+/// per file.
+#[must_use]
+pub fn group(report: &ScanReport, by: GroupBy) -> Vec<Group<'_>> {
+    let mut groups: HashMap<String, HashMap<&Path, usize>> = HashMap::new();
+    for f in &report.files {
+        for v in &f.violations {
+            let files = groups.entry(key(&f.path, v, by)).or_default();
+            *files.entry(&f.path).or_default() += 1;
+        }
+    }
+    let mut out: Vec<Group> = groups
+        .into_iter()
+        .map(|(name, files)| {
+            let mut files: Vec<_> = files.into_iter().collect();
+            files.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(b.0)));
+            let count = files.iter().map(|(_, n)| n).sum();
+            Group { name, count, files }
+        })
+        .collect();
+    out.sort_by(|a, b| b.count.cmp(&a.count).then(a.name.cmp(&b.name)));
+    out
+}
+
+fn key(path: &Path, v: &Violation, by: GroupBy) -> String {
+    match by {
+        GroupBy::File => path.display().to_string(),
+        GroupBy::Law => v.law.to_string(),
+        GroupBy::Rule => match v.message.split_once(':') {
+            Some((label, _)) => format!("{}: {label}", v.rule()),
+            None => v.rule(),
+        },
+    }
+}
+
+/// Prints each group with its count and worst files.
+pub fn print(report: &ScanReport, by: GroupBy) {
+    for g in group(report, by) {
+        println!("{:>6}  {}", g.count.to_string().red().bold(), g.name.bold());
+        for (path, n) in g.files.iter().take(WORST) {
+            println!("        {n:>4}  {}", path.display());
+        }
+        if g.files.len() > WORST {
+            let more = format!("        ... and {} more files", g.files.len() - WORST);
+            println!("{}", more.dimmed());
+        }
+        println!();
+    }
+}
@@ -0,0 +1,155 @@
+// src/reporting/html.rs
+//! Standalone HTML report (`slopchop report --html out.html`): summary cards,
+//! a sortable file table, and violations grouped by law with the code
+//! around each one.
+
+use crate::types::{FileReport, ScanReport, Severity, Violation};
+use anyhow::Result;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::fs;
+use std::path::Path;
+
+const TEMPLATE: &str = include_str!("report.html");
+/// Lines shown above and below each violation.
+const CONTEXT: usize = 2;
+
+/// Renders `report` as a self-contained HTML page.
+#[must_use]
+pub fn render(report: &ScanReport) -> String {
+    let meta = format!(
+        "{} files, {} tokens, scanned in {}ms",
+        report.files.len(),
+        report.total_tokens,
+        report.duration_ms
+    );
+    let mut body = files_table(report);
+    for (law, hits) in by_law(report) {
+        body.push_str(&law_section(law, &hits));
+    }
+    TEMPLATE
+        .replace("{{META}}", &escape(&meta))
+        .replace("{{CARDS}}", &cards(report))
+        .replace("{{BODY}}", &body)
+}
+
+/// Renders `report` and writes it to `path`.
+///
+/// # Errors
+/// Returns error if the file cannot be written.
+pub fn write(report: &ScanReport, path: &Path) -> Result<()> {
+    fs::write(path, render(report))?;
+    Ok(())
+}
+
+fn cards(report: &ScanReport) -> String {
+    [
+        ("Violations", report.total_violations),
+        ("Errors", report.count_severity(Severity::Error)),
+        ("Warnings", report.count_severity(Severity::Warning)),
+        ("Suppressed", report.suppressed_count()),
+        ("Baselined", report.baselined_count()),
+    ]
+    .iter()
+    .fold(String::new(), |mut out, (label, n)| {
+        let _ = write!(out, "<div class=\"card\"><b>{n}</b>{label}</div>");
+        out
+    })
+}
+
+fn files_table(report: &ScanReport) -> String {
+    let mut out = String::from(
+        "<h2>Files</h2>\n<table class=\"sortable\"><thead><tr><th>File</th><th>Violations</th>\
+         <th>Tokens</th><th>Complexity</th><th>Maintainability</th></tr></thead><tbody>\n",
+    );
+    for f in &report.files {
+        let mi = f
+            .metrics
+            .map_or(String::new(), |m| format!("{:.1}", m.maintainability));
+        let _ = writeln!(
+            out,
+            "<tr><td>{}</td><td class=\"num\">{}</td><td class=\"num\">{}</td>\
+             <td class=\"num\">{}</td><td class=\"num\">{mi}</td></tr>",
+            escape(&f.path.display().to_string()),
+            f.violations.len(),
+            f.token_count,
+            f.complexity_score,
+        );
+    }
+    out.push_str("</tbody></table>\n");
+    out
+}
+
+fn by_law(report: &ScanReport) -> BTreeMap<&str, Vec<(&FileReport, &Violation)>> {
+    let mut groups: BTreeMap<&str, Vec<_>> = BTreeMap::new();
+    for f in &report.files {
+        for v in &f.violations {
+            groups.entry(v.law).or_default().push((f, v));
+        }
+    }
+    groups
+}
+
+fn law_section(law: &str, hits: &[(&FileReport, &Violation)]) -> String {
+    let mut out = format!(
+        "<h2>{} ({})</h2>\n<table class=\"sortable\"><thead><tr><th>File</th><th>Line</th>\
+         <th>Severity</th><th>Message</th></tr></thead><tbody>\n",
+        escape(law),
+        hits.len()
+    );
+    for (f, v) in hits {
+        let severity = v.severity.to_string();
+        let _ = writeln!(
+            out,
+            "<tr><td>{}</td><td class=\"num\">{}</td><td class=\"{severity}\" data-key=\"{}\">\
+             {severity}</td><td><details><summary>{}</summary>{}</details></td></tr>",
+            escape(&f.path.display().to_string()),
+            v.row + 1,
+            severity_rank(v.severity),
+            escape(&v.message),
+            snippet(&f.path, v.row),
+        );
+    }
+    out.push_str("</tbody></table>\n");
+    out
+}
+
+/// Sorts errors first when the severity column is sorted ascending.
+fn severity_rank(severity: Severity) -> u8 {
+    match severity {
+        Severity::Error => 0,
+        Severity::Warning => 1,
+        Severity::Info => 2,
+    }
+}
+
+/// The lines around `row`, with `row` highlighted; empty if unreadable.
+fn snippet(path: &Path, row: usize) -> String {
+    let Ok(content) = fs::read_to_string(path) else {
+        return String::new();
+    };
+    let start = row.saturating_sub(CONTEXT);
+    let mut out = String::from("<pre>");
+    for (i, line) in content
+        .lines()
+        .enumerate()
+        .skip(start)
+        .take(CONTEXT * 2 + 1)
+    {
+        let text = format!("{:>5} | {}", i + 1, escape(line));
+        if i == row {
+            let _ = writeln!(out, "<span class=\"hit\">{text}</span>");
+        } else {
+            let _ = writeln!(out, "{text}");
+        }
+    }
+    out.push_str("</pre>");
+    out
+}
+
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
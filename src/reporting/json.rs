@@ -0,0 +1,104 @@
+// src/reporting/json.rs
+//! Machine-readable scan output for CI (`check --format json`).
+//!
+//! The shape is versioned: fields may be added, but existing ones keep their
+//! names and meaning while `version` stays the same.
+
+use crate::types::{LineBlame, ScanReport, Severity};
+use anyhow::Result;
+use serde::Serialize;
+use std::path::Path;
+
+/// Bumped only on breaking changes to the output shape.
+pub const VERSION: u32 = 1;
+
+#[derive(Debug, Serialize)]
+pub struct JsonReport<'a> {
+    pub version: u32,
+    pub summary: Summary,
+    /// Every failing violation, in file order.
+    pub violations: Vec<JsonViolation<'a>>,
+    /// The full report, including suppressed, baselined, debt and metrics.
+    pub report: &'a ScanReport,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Summary {
+    pub files: usize,
+    pub tokens: usize,
+    pub violations: usize,
+    pub errors: usize,
+    pub warnings: usize,
+    pub infos: usize,
+    pub suppressed: usize,
+    pub baselined: usize,
+    pub duration_ms: u128,
+}
+
+#[derive(Debug, Serialize)]
+pub struct JsonViolation<'a> {
+    pub file: &'a Path,
+    /// 1-based.
+    pub line: usize,
+    pub severity: Severity,
+    pub law: &'a str,
+    /// The key used in `[rules.severity]` and inline ignores.
+    pub rule: String,
+    pub message: &'a str,
+    /// Present with `--blame`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blame: Option<&'a LineBlame>,
+}
+
+impl<'a> JsonReport<'a> {
+    #[must_use]
+    pub fn new(report: &'a ScanReport) -> Self {
+        let violations = report
+            .files
+            .iter()
+            .flat_map(|f| f.violations.iter().map(move |v| (f, v)))
+            .map(|(f, v)| JsonViolation {
+                file: &f.path,
+                line: v.row + 1,
+                severity: v.severity,
+                law: v.law,
+                rule: v.rule(),
+                message: &v.message,
+                blame: f.blame.get(&v.row),
+            })
+            .collect();
+        Self {
+            version: VERSION,
+            summary: Summary {
+                files: report.files.len(),
+                tokens: report.total_tokens,
+                violations: report.total_violations,
+                errors: report.count_severity(Severity::Error),
+                warnings: report.count_severity(Severity::Warning),
+                infos: report.count_severity(Severity::Info),
+                suppressed: report.suppressed_count(),
+                baselined: report.baselined_count(),
+                duration_ms: report.duration_ms,
+            },
+            violations,
+            report,
+        }
+    }
+}
+
+/// Renders `report` as pretty-printed JSON.
+///
+/// # Errors
+/// Returns error if serialization fails.
+pub fn render(report: &ScanReport) -> Result<String> {
+    Ok(serde_json::to_string_pretty(&JsonReport::new(report))?)
+}
+
+/// Prints `report` as JSON to stdout.
+///
+/// # Errors
+/// Returns error if serialization fails.
+pub fn print(report: &ScanReport) -> Result<()> {
+    println!("{}", render(report)?);
+    Ok(())
+}
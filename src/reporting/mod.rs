@@ -0,0 +1,160 @@
+// src/reporting/mod.rs
+pub mod codeclimate;
+pub mod group;
+pub mod html;
+pub mod json;
+
+pub use group::GroupBy;
+
+use crate::types::{FileReport, LineBlame, ScanReport, Severity, Violation};
+use anyhow::Result;
+use clap::ValueEnum;
+use colored::Colorize;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// How `check` prints its report.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum, Serialize, Deserialize, JsonSchema,
+)]
+#[serde(rename_all = "kebab-case")]
+pub enum ReportFormat {
+    #[default]
+    Text,
+    Json,
+    /// Code Climate issues, for GitLab Code Quality.
+    #[value(alias = "gitlab")]
+    #[serde(alias = "gitlab")]
+    CodeClimate,
+}
+
+/// Prints the scan report to stdout.
+///
+/// # Errors
+/// Returns `Ok(())` normally.
+pub fn print_report(report: &ScanReport) -> Result<()> {
+    print_report_by(report, GroupBy::File)
+}
+
+/// Prints the scan report with violations listed per file or as grouped
+/// counts.
+///
+/// # Errors
+/// Returns `Ok(())` normally.
+pub fn print_report_by(report: &ScanReport, by: GroupBy) -> Result<()> {
+    let failures = count_failures(report);
+
+    if by == GroupBy::File {
+        report
+            .files
+            .iter()
+            .filter(|f| !f.is_clean())
+            .for_each(print_file_report);
+    } else {
+        group::print(report, by);
+    }
+
+    print_debt(report);
+    print_metrics(report);
+    print_summary(report, failures);
+    Ok(())
+}
+
+fn count_failures(report: &ScanReport) -> usize {
+    report
+        .files
+        .iter()
+        .filter(|f| !f.is_clean())
+        .map(|f| f.violations.len())
+        .sum()
+}
+
+fn print_file_report(file: &FileReport) {
+    for v in &file.violations {
+        print_violation(&file.path, v, file.blame.get(&v.row));
+    }
+}
+
+fn print_violation(path: &std::path::Path, v: &Violation, blame: Option<&LineBlame>) {
+    let filename = path.to_string_lossy();
+    let line_num = v.row + 1;
+
+    let label = match v.severity {
+        Severity::Error => "error".red().bold(),
+        Severity::Warning => "warning".yellow().bold(),
+        Severity::Info => "info".blue().bold(),
+    };
+    println!("{label}: {}", v.message.bold());
+    println!("  {} {}:{}:1", "-->".blue(), filename, line_num);
+    println!("   {}", "|".blue());
+    println!(
+        "   {} {}: Action required",
+        "=".blue().bold(),
+        v.law.white().bold()
+    );
+    if let Some(b) = blame {
+        let line = format!("blame: {} on {} ({})", b.author, b.date, b.commit);
+        println!("   {} {}", "=".blue().bold(), line.dimmed());
+    }
+    println!();
+}
+
+fn print_debt(report: &ScanReport) {
+    let total = report.debt_count();
+    if total == 0 {
+        return;
+    }
+    println!("{}", format!("📝 {total} debt markers:").yellow().bold());
+    for file in report.files.iter().filter(|f| !f.debt.is_empty()) {
+        println!("   {:>4}  {}", file.debt.len(), file.path.display());
+    }
+    println!();
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn print_metrics(report: &ScanReport) {
+    let measured: Vec<_> = report
+        .files
+        .iter()
+        .filter_map(|f| f.metrics.map(|m| (f, m.maintainability)))
+        .collect();
+    let Some((worst, lowest)) = measured.iter().min_by(|a, b| a.1.total_cmp(&b.1)) else {
+        return;
+    };
+    let avg = measured.iter().map(|(_, mi)| mi).sum::<f64>() / measured.len() as f64;
+    let line = format!(
+        "📊 Maintainability: avg {avg:.1}, lowest {lowest:.1} ({})",
+        worst.path.display()
+    );
+    println!("{}", line.dimmed());
+}
+
+fn print_summary(report: &ScanReport, failures: usize) {
+    let errors = report.count_severity(Severity::Error);
+    if errors > 0 {
+        let msg = format!(
+            "❌ SlopChop found {failures} violations ({errors} errors) in {}ms.",
+            report.duration_ms
+        );
+        println!("{}", msg.red().bold());
+    } else if failures > 0 {
+        let msg = format!(
+            "⚠️  SlopChop found {failures} warnings/notes, no errors, in {}ms.",
+            report.duration_ms
+        );
+        println!("{}", msg.yellow().bold());
+    } else {
+        let msg = format!(
+            "✅ All Clear. Scanned {} tokens in {}ms.",
+            report.total_tokens, report.duration_ms
+        );
+        println!("{}", msg.green().bold());
+    }
+    let suppressed = report.suppressed_count();
+    if suppressed > 0 {
+        println!(
+            "{}",
+            format!("   ({suppressed} suppressed inline)").dimmed()
+        );
+    }
+}
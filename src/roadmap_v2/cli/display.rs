@@ -1,25 +1,41 @@
-use crate::roadmap_v2::types::{SectionStatus, TaskStatus, TaskStore};
+use crate::roadmap_v2::subtasks;
+use crate::roadmap_v2::types::{Section, SectionStatus, Task, TaskStatus, TaskStore};
 use crate::roadmap_v2::RoadmapCommand;
 use anyhow::{anyhow, Result};
 use colored::Colorize;
+use std::fmt::Write as _;
 
-pub fn print_stats(store: &TaskStore) {
-    let total = store.tasks.len();
-    let done = store.tasks.iter().filter(|t| t.status == TaskStatus::Done).count();
+pub fn print_stats(store: &TaskStore, tags: &[String]) {
+    let tagged: Vec<&Task> = store.tasks.iter().filter(|t| t.has_tags(tags)).collect();
+    let total = tagged.len();
+    let done = tagged
+        .iter()
+        .filter(|t| t.status == TaskStatus::Done)
+        .count();
     let pending = total - done;
     println!("Tasks: {total} ({done} done, {pending} pending)");
 }
 
-pub fn print_tree(store: &TaskStore) {
+/// Prints every section and its tasks, subtasks indented under their
+/// parents; with `tags`, only the tasks that
+/// have them and the sections holding any.
+pub fn print_tree(store: &TaskStore, tags: &[String]) {
     println!("{}", store.meta.title.cyan().bold());
     println!();
 
     for section in &store.sections {
-        print_section(store, section);
+        let section_tasks: Vec<&Task> = store
+            .tasks
+            .iter()
+            .filter(|t| t.section == section.id && t.has_tags(tags))
+            .collect();
+        if tags.is_empty() || !section_tasks.is_empty() {
+            print_section(store, section, &section_tasks);
+        }
     }
 }
 
-fn print_section(store: &TaskStore, section: &crate::roadmap_v2::types::Section) {
+fn print_section(store: &TaskStore, section: &Section, section_tasks: &[&Task]) {
     let status_icon = match section.status {
         SectionStatus::Complete => "✓".green(),
         SectionStatus::Current => "→".yellow(),
@@ -27,25 +43,94 @@ fn print_section(store: &TaskStore, section: &crate::roadmap_v2::types::Section)
     };
     println!("{status_icon} {}", section.title.bold());
 
-    let section_tasks: Vec<_> = store.tasks.iter()
-        .filter(|t| t.section == section.id)
-        .collect();
-
-    for task in section_tasks {
-        print_task(task);
+    for (task, depth) in store.outline(section_tasks) {
+        print_task(store, task, depth);
     }
     println!();
 }
 
-fn print_task(task: &crate::roadmap_v2::types::Task) {
+fn print_task(store: &TaskStore, task: &Task, depth: usize) {
+    let waiting_on = store.pending_dependencies(task);
     let mark = match task.status {
         TaskStatus::Done | TaskStatus::NoTest => "[x]".green(),
         TaskStatus::Pending => "[ ]".dimmed(),
     };
-    let test_info = task.test.as_ref().map_or(String::new(), |t| {
-        format!(" {}", format!("({t})").dimmed())
+    let test_info = task
+        .test
+        .as_ref()
+        .map_or(String::new(), |t| format!(" {}", format!("({t})").dimmed()));
+    let blocked_info = if waiting_on.is_empty() || task.status != TaskStatus::Pending {
+        String::new()
+    } else {
+        format!(
+            " {}",
+            format!("(blocked by {})", waiting_on.join(", ")).yellow()
+        )
+    };
+    let tags = tag_list(task);
+    let progress = progress_info(store, task);
+    let indent = "  ".repeat(depth);
+    println!(
+        "    {indent}{mark} {}{progress}{tags}{test_info}{blocked_info}",
+        task.text
+    );
+}
+
+/// Prints `tasks` one per line with their ids, subtasks indented.
+pub fn print_task_list(store: &TaskStore, tasks: &[&Task]) {
+    for (task, depth) in store.outline(tasks) {
+        let mark = match task.status {
+            TaskStatus::Done | TaskStatus::NoTest => "[x]",
+            TaskStatus::Pending => "[ ]",
+        };
+        println!(
+            "{}{mark} {} - {}{}{}",
+            "  ".repeat(depth),
+            task.id,
+            task.text,
+            progress_info(store, task),
+            tag_list(task)
+        );
+    }
+}
+
+/// ` (2/3, 66%)` rolled up from a task's subtasks, or nothing without any.
+pub fn progress_info(store: &TaskStore, task: &Task) -> String {
+    let (done, total) = store.progress(&task.id);
+    if total == 0 {
+        return String::new();
+    }
+    let pct = subtasks::percent(done, total);
+    format!(" {}", format!("({done}/{total}, {pct}%)").dimmed())
+}
+
+/// ` #backend #perf`, or nothing for an untagged task.
+pub fn tag_list(task: &Task) -> String {
+    if task.tags.is_empty() {
+        return String::new();
+    }
+    let tags = task.tags.iter().fold(String::new(), |mut out, t| {
+        let _ = write!(out, " #{t}");
+        out
     });
-    println!("    {mark} {}{test_info}", task.text);
+    tags.cyan().to_string()
+}
+
+pub fn print_blocked(store: &TaskStore, tags: &[String]) {
+    let mut blocked = store.blocked();
+    blocked.retain(|(task, _)| task.has_tags(tags));
+    if blocked.is_empty() {
+        println!("{} No blocked tasks.", "✓".green());
+        return;
+    }
+    for (task, waiting_on) in blocked {
+        println!("[ ] {} - {}", task.id, task.text);
+        println!(
+            "    {} {}",
+            "└─ waiting on:".dimmed(),
+            waiting_on.join(", ")
+        );
+    }
 }
 
 pub fn print_dry_run(commands: &[RoadmapCommand]) {
@@ -89,4 +174,4 @@ pub fn print_audit_result(failures: usize, strict: bool) -> Result<()> {
     }
 
     Ok(())
-}
\ No newline at end of file
+}
@@ -1,7 +1,8 @@
 // src/roadmap_v2/cli/handlers.rs
 use crate::clipboard;
 use crate::roadmap_v2::parser::parse_commands;
-use crate::roadmap_v2::types::{RoadmapMeta, Section, SectionStatus, TaskStatus, TaskStore};
+use crate::roadmap_v2::types::{RoadmapMeta, Section, SectionStatus, Task, TaskStatus, TaskStore};
+use crate::roadmap_v2::RoadmapCommand;
 use anyhow::{anyhow, Context, Result};
 use colored::Colorize;
 use std::io::{self, Read};
@@ -11,7 +12,10 @@ use super::display;
 
 pub fn run_init(output: &Path, name: Option<String>) -> Result<()> {
     if output.exists() {
-        return Err(anyhow!("{} already exists. Use --output.", output.display()));
+        return Err(anyhow!(
+            "{} already exists. Use --output.",
+            output.display()
+        ));
     }
 
     let title = name.unwrap_or_else(|| "Project".to_string());
@@ -46,29 +50,58 @@ fn create_template_store(title: &str) -> TaskStore {
     }
 }
 
-pub fn run_show(file: &Path, format: &str) -> Result<()> {
+pub fn run_show(file: &Path, format: &str, tags: &[String]) -> Result<()> {
     let store = load_store(file)?;
 
     if format == "stats" {
-        display::print_stats(&store);
+        display::print_stats(&store, tags);
     } else {
-        display::print_tree(&store);
+        display::print_tree(&store, tags);
     }
     Ok(())
 }
 
-pub fn run_tasks(file: &Path, pending: bool, complete: bool) -> Result<()> {
+pub fn run_tasks(file: &Path, pending: bool, complete: bool, tags: &[String]) -> Result<()> {
     let store = load_store(file)?;
+    let tasks: Vec<&Task> = store
+        .tasks
+        .iter()
+        .filter(|t| t.has_tags(tags) && should_show_task(&t.status, pending, complete))
+        .collect();
+    display::print_task_list(&store, &tasks);
+    Ok(())
+}
 
-    for task in &store.tasks {
-        if should_show_task(&task.status, pending, complete) {
-            let mark = match task.status {
-                TaskStatus::Done | TaskStatus::NoTest => "[x]",
-                TaskStatus::Pending => "[ ]",
-            };
-            println!("{mark} {} - {}", task.id, task.text);
-        }
-    }
+pub fn run_blocked(file: &Path, tags: &[String]) -> Result<()> {
+    let store = load_store(file)?;
+    display::print_blocked(&store, tags);
+    Ok(())
+}
+
+/// Adds `tags` to task `id`, or removes them with `remove`.
+pub fn run_tag(file: &Path, id: &str, tags: Vec<String>, remove: bool) -> Result<()> {
+    let mut store = load_store(file)?;
+    let shown = tags.join(", ");
+    let (cmd, verb) = if remove {
+        (
+            RoadmapCommand::Untag {
+                id: id.to_string(),
+                tags,
+            },
+            "Untagged",
+        )
+    } else {
+        (
+            RoadmapCommand::Tag {
+                id: id.to_string(),
+                tags,
+            },
+            "Tagged",
+        )
+    };
+    store.apply(cmd).map_err(|e| anyhow!("{e}"))?;
+    store.save(Some(file)).map_err(|e| anyhow!("{e}"))?;
+    println!("{} {verb} {id}: {shown}", "✓".green());
     Ok(())
 }
 
@@ -112,7 +145,7 @@ pub fn run_apply(file: &Path, dry_run: bool, stdin: bool, verbose: bool) -> Resu
 
 fn apply_all_commands(
     store: &mut TaskStore,
-    commands: Vec<crate::roadmap_v2::RoadmapCommand>,
+    commands: Vec<RoadmapCommand>,
     verbose: bool,
 ) -> (usize, Vec<String>) {
     let mut success_count = 0;
@@ -207,4 +240,4 @@ fn get_input(stdin: bool) -> Result<String> {
     } else {
         clipboard::read_clipboard().context("Clipboard read failed")
     }
-}
\ No newline at end of file
+}
@@ -4,13 +4,21 @@ use anyhow::{anyhow, Context, Result};
 use colored::Colorize;
 use std::path::Path;
 
+/// Converts the `ROADMAP.md` checklist at `input` into a task file at
+/// `output`.
+///
+/// # Errors
+/// Returns error if `output` exists, or `input` can't be read or the
+/// task file written.
 pub fn run_migrate(input: &Path, output: &Path) -> Result<()> {
     if output.exists() {
-        return Err(anyhow!("{} already exists. Remove it first.", output.display()));
+        return Err(anyhow!(
+            "{} already exists. Remove it first.",
+            output.display()
+        ));
     }
 
-    let content = std::fs::read_to_string(input)
-        .context("Failed to read legacy ROADMAP.md")?;
+    let content = std::fs::read_to_string(input).context("Failed to read legacy ROADMAP.md")?;
 
     let store = parse_legacy_roadmap(&content);
 
@@ -32,11 +40,14 @@ fn print_migration_result(store: &TaskStore, output: &Path) {
     println!("3. Verify ROADMAP.md looks correct");
 }
 
-fn parse_legacy_roadmap(content: &str) -> TaskStore {
+/// The sections and tasks of a hand-kept `ROADMAP.md` checklist.
+#[must_use]
+pub fn parse_legacy_roadmap(content: &str) -> TaskStore {
     let mut store = TaskStore::default();
     let mut ctx = ParseContext::default();
 
     for line in content.lines() {
+        ctx.indent = line.len() - line.trim_start().len();
         parse_line(line.trim(), &mut store, &mut ctx);
     }
 
@@ -49,6 +60,9 @@ struct ParseContext {
     current_group: Option<String>,
     section_order: u32,
     task_order: u32,
+    indent: usize,
+    /// Indent and id of the tasks the next one may be nested under.
+    open_tasks: Vec<(usize, String)>,
 }
 
 fn parse_line(trimmed: &str, store: &mut TaskStore, ctx: &mut ParseContext) {
@@ -64,10 +78,14 @@ fn parse_line(trimmed: &str, store: &mut TaskStore, ctx: &mut ParseContext) {
 
     if let Some(heading) = trimmed.strip_prefix("### ") {
         ctx.current_group = Some(heading.to_string());
+        ctx.open_tasks.clear();
         return;
     }
 
-    if let Some(task) = parse_task_line(trimmed, ctx) {
+    if let Some(mut task) = parse_task_line(trimmed, ctx) {
+        ctx.open_tasks.retain(|(indent, _)| *indent < ctx.indent);
+        task.parent = ctx.open_tasks.last().map(|(_, id)| id.clone());
+        ctx.open_tasks.push((ctx.indent, task.id.clone()));
         store.tasks.push(task);
         ctx.task_order += 1;
     }
@@ -84,6 +102,7 @@ fn add_section(store: &mut TaskStore, ctx: &mut ParseContext, heading: &str) {
     });
     ctx.current_section = Some(section_id);
     ctx.current_group = None;
+    ctx.open_tasks.clear();
     ctx.section_order += 1;
     ctx.task_order = 0;
 }
@@ -110,6 +129,9 @@ fn parse_task_line(line: &str, ctx: &ParseContext) -> Option<Task> {
         group: ctx.current_group.clone(),
         test: test_anchor,
         order: ctx.task_order,
+        depends_on: Vec::new(),
+        tags: Vec::new(),
+        parent: None,
     })
 }
 
@@ -154,7 +176,8 @@ fn extract_test_anchor(input: &str) -> (String, Option<String>) {
 }
 
 fn clean_task_text(input: &str) -> String {
-    input.trim()
+    input
+        .trim()
         .trim_start_matches("**")
         .trim_end_matches("**")
         .trim()
@@ -172,11 +195,12 @@ fn detect_section_status(heading: &str) -> SectionStatus {
 }
 
 fn slugify(input: &str) -> String {
-    input.chars()
+    input
+        .chars()
         .filter(|c| c.is_alphanumeric() || c.is_whitespace() || *c == '-' || *c == '.')
         .collect::<String>()
         .to_lowercase()
         .split_whitespace()
         .collect::<Vec<_>>()
         .join("-")
-}
\ No newline at end of file
+}
@@ -1,7 +1,7 @@
 // src/roadmap_v2/cli/mod.rs
 mod display;
 mod handlers;
-mod migrate;
+pub mod migrate;
 
 use anyhow::Result;
 use clap::Subcommand;
@@ -25,6 +25,9 @@ pub enum RoadmapV2Command {
         file: PathBuf,
         #[arg(long, default_value = "tree")]
         format: String,
+        /// Only tasks with this tag (repeat to require several)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
     },
     /// List tasks with filters
     Tasks {
@@ -34,6 +37,33 @@ pub enum RoadmapV2Command {
         pending: bool,
         #[arg(long)]
         complete: bool,
+        /// Only tasks with this tag (repeat to require several)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+    },
+    /// List pending tasks waiting on pending dependencies
+    Blocked {
+        #[arg(short, long, default_value = DEFAULT_TASKS)]
+        file: PathBuf,
+        /// Only tasks with this tag (repeat to require several)
+        #[arg(long = "tag")]
+        tags: Vec<String>,
+    },
+    /// Add tags to a task
+    Tag {
+        id: String,
+        #[arg(required = true)]
+        tags: Vec<String>,
+        #[arg(short, long, default_value = DEFAULT_TASKS)]
+        file: PathBuf,
+    },
+    /// Remove tags from a task
+    Untag {
+        id: String,
+        #[arg(required = true)]
+        tags: Vec<String>,
+        #[arg(short, long, default_value = DEFAULT_TASKS)]
+        file: PathBuf,
     },
     /// Apply commands from clipboard or stdin
     Apply {
@@ -76,15 +106,32 @@ pub enum RoadmapV2Command {
 pub fn handle_command(cmd: RoadmapV2Command) -> Result<()> {
     match cmd {
         RoadmapV2Command::Init { output, name } => handlers::run_init(&output, name),
-        RoadmapV2Command::Show { file, format } => handlers::run_show(&file, &format),
-        RoadmapV2Command::Tasks { file, pending, complete } => {
-            handlers::run_tasks(&file, pending, complete)
-        }
-        RoadmapV2Command::Apply { file, dry_run, stdin, verbose } => {
-            handlers::run_apply(&file, dry_run, stdin, verbose)
-        }
+        RoadmapV2Command::Apply {
+            file,
+            dry_run,
+            stdin,
+            verbose,
+        } => handlers::run_apply(&file, dry_run, stdin, verbose),
         RoadmapV2Command::Generate { source, output } => handlers::run_generate(&source, &output),
         RoadmapV2Command::Audit { file, strict } => handlers::run_audit(&file, strict),
         RoadmapV2Command::Migrate { input, output } => migrate::run_migrate(&input, &output),
+        task_command => handle_task_command(task_command),
     }
-}
\ No newline at end of file
+}
+
+/// `show`, `tasks`, `blocked`, `tag` and `untag`.
+fn handle_task_command(cmd: RoadmapV2Command) -> Result<()> {
+    match cmd {
+        RoadmapV2Command::Show { file, format, tags } => handlers::run_show(&file, &format, &tags),
+        RoadmapV2Command::Tasks {
+            file,
+            pending,
+            complete,
+            tags,
+        } => handlers::run_tasks(&file, pending, complete, &tags),
+        RoadmapV2Command::Blocked { file, tags } => handlers::run_blocked(&file, &tags),
+        RoadmapV2Command::Tag { id, tags, file } => handlers::run_tag(&file, &id, tags, false),
+        RoadmapV2Command::Untag { id, tags, file } => handlers::run_tag(&file, &id, tags, true),
+        _ => Ok(()),
+    }
+}
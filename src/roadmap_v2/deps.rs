@@ -0,0 +1,80 @@
+// src/roadmap_v2/deps.rs
+//! Task dependencies. Every `depends_on` id must name a task and none may
+//! lead back to the task itself; a task can't be checked off while one of
+//! its dependencies is pending.
+
+use super::types::{Task, TaskStatus, TaskStore};
+use crate::error::SlopChopError;
+
+impl TaskStore {
+    /// The ids in `task.depends_on` whose tasks are still pending.
+    #[must_use]
+    pub fn pending_dependencies<'a>(&self, task: &'a Task) -> Vec<&'a str> {
+        task.depends_on
+            .iter()
+            .filter(|dep| {
+                self.tasks
+                    .iter()
+                    .any(|t| &t.id == *dep && t.status == TaskStatus::Pending)
+            })
+            .map(String::as_str)
+            .collect()
+    }
+
+    /// Pending tasks waiting on pending dependencies, with those ids.
+    #[must_use]
+    pub fn blocked(&self) -> Vec<(&Task, Vec<&str>)> {
+        self.tasks
+            .iter()
+            .filter(|t| t.status == TaskStatus::Pending)
+            .map(|t| (t, self.pending_dependencies(t)))
+            .filter(|(_, deps)| !deps.is_empty())
+            .collect()
+    }
+
+    /// Ids of the tasks that depend on `id`.
+    #[must_use]
+    pub fn dependents(&self, id: &str) -> Vec<&str> {
+        self.tasks
+            .iter()
+            .filter(|t| t.depends_on.iter().any(|d| d == id))
+            .map(|t| t.id.as_str())
+            .collect()
+    }
+
+    /// Fails if task `id` can't depend on `depends_on`: an id is unknown,
+    /// or depends on `id` itself.
+    pub(super) fn check_dependencies(
+        &self,
+        id: &str,
+        depends_on: &[String],
+    ) -> Result<(), SlopChopError> {
+        for dep in depends_on {
+            if !self.tasks.iter().any(|t| &t.id == dep) {
+                return Err(SlopChopError::Other(format!(
+                    "Unknown dependency of {id}: {dep}"
+                )));
+            }
+            if dep == id || self.reaches(dep, id, &mut Vec::new()) {
+                return Err(SlopChopError::Other(format!(
+                    "Dependency cycle: {id} -> {dep}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// True if `from` depends on `target`, directly or through other tasks.
+    fn reaches<'a>(&'a self, from: &'a str, target: &str, seen: &mut Vec<&'a str>) -> bool {
+        if seen.contains(&from) {
+            return false;
+        }
+        seen.push(from);
+        let Some(task) = self.tasks.iter().find(|t| t.id == from) else {
+            return false;
+        };
+        task.depends_on
+            .iter()
+            .any(|dep| dep == target || self.reaches(dep, target, seen))
+    }
+}
@@ -1,17 +1,20 @@
 // src/roadmap_v2/mod.rs
 pub mod cli;
+pub mod deps;
 pub mod generator;
 pub mod parser;
 pub mod store;
+pub mod subtasks;
+pub mod tags;
 pub mod types;
 
-use std::path::Path;
 use anyhow::{Context, Result};
+use std::path::Path;
 
 // Added Task to exports
-pub use types::{RoadmapCommand, TaskStatus, TaskStore, Task};
 pub use cli::{handle_command, RoadmapV2Command};
 pub use parser::parse_commands;
+pub use types::{RoadmapCommand, Task, TaskStatus, TaskStore};
 
 /// Handles raw string input from the clipboard or stdin, parsing it and applying commands to the roadmap.
 ///
@@ -20,7 +23,7 @@ pub use parser::parse_commands;
 pub fn handle_input(path: &Path, content: &str) -> Result<Vec<String>> {
     let mut store = TaskStore::load(Some(path))?;
     let commands = parser::parse_commands(content).map_err(|e| anyhow::anyhow!("{e}"))?;
-    
+
     if commands.is_empty() {
         return Ok(vec![]);
     }
@@ -45,4 +48,4 @@ pub fn handle_input(path: &Path, content: &str) -> Result<Vec<String>> {
     }
 
     Ok(results)
-}
\ No newline at end of file
+}
@@ -1,101 +1,175 @@
-// src/roadmap_v2/store.rs
-use crate::error::SlopChopError;
-use super::types::{TaskStore, Task, TaskStatus, RoadmapCommand, TaskUpdate};
-use std::path::Path;
-
-const DEFAULT_PATH: &str = "tasks.toml";
-
-impl TaskStore {
-    /// Load from tasks.toml (or default path).
-    ///
-    /// # Errors
-    /// Returns error if file cannot be read or contains invalid TOML.
-    pub fn load(path: Option<&Path>) -> Result<Self, SlopChopError> {
-        let path = path.unwrap_or_else(|| Path::new(DEFAULT_PATH));
-        
-        if !path.exists() {
-            return Ok(Self::default());
-        }
-
-        let content = std::fs::read_to_string(path)?;
-
-        toml::from_str(&content)
-            .map_err(|e| SlopChopError::Other(format!("Invalid tasks.toml: {e}")))
-    }
-
-    /// Save to tasks.toml.
-    ///
-    /// # Errors
-    /// Returns error if serialization fails or file cannot be written.
-    pub fn save(&self, path: Option<&Path>) -> Result<(), SlopChopError> {
-        let path = path.unwrap_or_else(|| Path::new(DEFAULT_PATH));
-        
-        let content = toml::to_string_pretty(self)
-            .map_err(|e| SlopChopError::Other(format!("Failed to serialize: {e}")))?;
-
-        std::fs::write(path, content)?;
-        Ok(())
-    }
-
-    /// Apply a command to the store.
-    ///
-    /// # Errors
-    /// Returns error if task not found or duplicate ID on add.
-    pub fn apply(&mut self, cmd: RoadmapCommand) -> Result<(), SlopChopError> {
-        match cmd {
-            RoadmapCommand::Check { id } => self.set_status(&id, TaskStatus::Done),
-            RoadmapCommand::Uncheck { id } => self.set_status(&id, TaskStatus::Pending),
-            RoadmapCommand::Add(task) => self.add_task(task),
-            RoadmapCommand::Update { id, fields } => self.update_task(&id, fields),
-            RoadmapCommand::Delete { id } => self.delete_task(&id),
-        }
-    }
-
-    fn set_status(&mut self, id: &str, status: TaskStatus) -> Result<(), SlopChopError> {
-        let task = self.find_task_mut(id)?;
-        task.status = status;
-        Ok(())
-    }
-
-    fn add_task(&mut self, task: Task) -> Result<(), SlopChopError> {
-        if self.tasks.iter().any(|t| t.id == task.id) {
-            return Err(SlopChopError::Other(format!(
-                "Task already exists: {}", task.id
-            )));
-        }
-        self.tasks.push(task);
-        Ok(())
-    }
-
-    fn update_task(&mut self, id: &str, fields: TaskUpdate) -> Result<(), SlopChopError> {
-        let task = self.find_task_mut(id)?;
-        
-        if let Some(txt) = fields.text {
-            task.text = txt;
-        }
-        if let Some(tst) = fields.test {
-            task.test = Some(tst);
-        }
-        if let Some(sec) = fields.section {
-            task.section = sec;
-        }
-        if let Some(grp) = fields.group {
-            task.group = Some(grp);
-        }
-        
-        Ok(())
-    }
-
-    fn delete_task(&mut self, id: &str) -> Result<(), SlopChopError> {
-        let idx = self.tasks.iter().position(|t| t.id == id)
-            .ok_or_else(|| SlopChopError::Other(format!("Task not found: {id}")))?;
-        self.tasks.remove(idx);
-        Ok(())
-    }
-
-    fn find_task_mut(&mut self, id: &str) -> Result<&mut Task, SlopChopError> {
-        self.tasks.iter_mut()
-            .find(|t| t.id == id)
-            .ok_or_else(|| SlopChopError::Other(format!("Task not found: {id}")))
-    }
-}
\ No newline at end of file
+// src/roadmap_v2/store.rs
+use super::tags;
+use super::types::{RoadmapCommand, Task, TaskStatus, TaskStore, TaskUpdate};
+use crate::error::SlopChopError;
+use std::path::Path;
+
+/// Where the roadmap lives unless a command names another file.
+pub const DEFAULT_PATH: &str = "tasks.toml";
+
+impl TaskStore {
+    /// Load from tasks.toml (or default path).
+    ///
+    /// # Errors
+    /// Returns error if file cannot be read or contains invalid TOML.
+    pub fn load(path: Option<&Path>) -> Result<Self, SlopChopError> {
+        let path = path.unwrap_or_else(|| Path::new(DEFAULT_PATH));
+
+        if !path.exists() {
+            return Ok(Self::default());
+        }
+
+        let content = std::fs::read_to_string(path)?;
+
+        toml::from_str(&content)
+            .map_err(|e| SlopChopError::Other(format!("Invalid tasks.toml: {e}")))
+    }
+
+    /// Save to tasks.toml.
+    ///
+    /// # Errors
+    /// Returns error if serialization fails or file cannot be written.
+    pub fn save(&self, path: Option<&Path>) -> Result<(), SlopChopError> {
+        let path = path.unwrap_or_else(|| Path::new(DEFAULT_PATH));
+
+        let content = toml::to_string_pretty(self)
+            .map_err(|e| SlopChopError::Other(format!("Failed to serialize: {e}")))?;
+
+        std::fs::write(path, content)?;
+        Ok(())
+    }
+
+    /// Apply a command to the store.
+    ///
+    /// # Errors
+    /// Returns error if task not found or duplicate ID on add.
+    pub fn apply(&mut self, cmd: RoadmapCommand) -> Result<(), SlopChopError> {
+        match cmd {
+            RoadmapCommand::Check { id } => self.check_task(&id),
+            RoadmapCommand::Uncheck { id } => self.set_status(&id, TaskStatus::Pending),
+            RoadmapCommand::Add(task) => self.add_task(task),
+            RoadmapCommand::Update { id, fields } => self.update_task(&id, fields),
+            RoadmapCommand::Delete { id } => self.delete_task(&id),
+            RoadmapCommand::Tag { id, tags } => self.tag(&id, &tags),
+            RoadmapCommand::Untag { id, tags } => self.untag(&id, &tags),
+        }
+    }
+
+    fn set_status(&mut self, id: &str, status: TaskStatus) -> Result<(), SlopChopError> {
+        let task = self.find_task_mut(id)?;
+        task.status = status;
+        Ok(())
+    }
+
+    /// Marks `id` done, unless a dependency or subtask is still pending.
+    fn check_task(&mut self, id: &str) -> Result<(), SlopChopError> {
+        let task = self
+            .tasks
+            .iter()
+            .find(|t| t.id == id)
+            .ok_or_else(|| SlopChopError::Other(format!("Task not found: {id}")))?;
+        let pending = self.pending_dependencies(task);
+        if !pending.is_empty() {
+            return Err(SlopChopError::Other(format!(
+                "Task {id} is blocked by pending: {}",
+                pending.join(", ")
+            )));
+        }
+        let open = self.pending_subtasks(id);
+        if !open.is_empty() {
+            return Err(SlopChopError::Other(format!(
+                "Task {id} has pending subtasks: {}",
+                open.join(", ")
+            )));
+        }
+        self.set_status(id, TaskStatus::Done)
+    }
+
+    fn add_task(&mut self, mut task: Task) -> Result<(), SlopChopError> {
+        if self.tasks.iter().any(|t| t.id == task.id) {
+            return Err(SlopChopError::Other(format!(
+                "Task already exists: {}",
+                task.id
+            )));
+        }
+        self.check_dependencies(&task.id, &task.depends_on)?;
+        if let Some(parent) = task.parent.clone() {
+            self.check_parent(&task.id, &parent)?;
+            self.inherit_placement(&mut task, &parent);
+        }
+        task.tags = tags::normalize(&task.tags);
+        self.tasks.push(task);
+        Ok(())
+    }
+
+    fn update_task(&mut self, id: &str, fields: TaskUpdate) -> Result<(), SlopChopError> {
+        self.check_update(id, &fields)?;
+        let task = self.find_task_mut(id)?;
+
+        if let Some(txt) = fields.text {
+            task.text = txt;
+        }
+        if let Some(tst) = fields.test {
+            task.test = Some(tst);
+        }
+        if let Some(sec) = fields.section {
+            task.section = sec;
+        }
+        if let Some(grp) = fields.group {
+            task.group = Some(grp);
+        }
+        if let Some(deps) = fields.depends_on {
+            task.depends_on = deps;
+        }
+        if let Some(tags) = fields.tags {
+            task.tags = tags::normalize(&tags);
+        }
+        if let Some(parent) = fields.parent {
+            task.parent = Some(parent);
+        }
+
+        Ok(())
+    }
+
+    /// Fails if `fields` would give task `id` bad dependencies or a bad parent.
+    fn check_update(&self, id: &str, fields: &TaskUpdate) -> Result<(), SlopChopError> {
+        if let Some(deps) = &fields.depends_on {
+            self.check_dependencies(id, deps)?;
+        }
+        if let Some(parent) = &fields.parent {
+            self.check_parent(id, parent)?;
+        }
+        Ok(())
+    }
+
+    fn delete_task(&mut self, id: &str) -> Result<(), SlopChopError> {
+        let dependents = self.dependents(id);
+        if !dependents.is_empty() {
+            return Err(SlopChopError::Other(format!(
+                "Task {id} is a dependency of: {}",
+                dependents.join(", ")
+            )));
+        }
+        let subtasks: Vec<&str> = self.subtasks(id).iter().map(|t| t.id.as_str()).collect();
+        if !subtasks.is_empty() {
+            return Err(SlopChopError::Other(format!(
+                "Task {id} has subtasks: {}",
+                subtasks.join(", ")
+            )));
+        }
+        let idx = self
+            .tasks
+            .iter()
+            .position(|t| t.id == id)
+            .ok_or_else(|| SlopChopError::Other(format!("Task not found: {id}")))?;
+        self.tasks.remove(idx);
+        Ok(())
+    }
+
+    pub(super) fn find_task_mut(&mut self, id: &str) -> Result<&mut Task, SlopChopError> {
+        self.tasks
+            .iter_mut()
+            .find(|t| t.id == id)
+            .ok_or_else(|| SlopChopError::Other(format!("Task not found: {id}")))
+    }
+}
@@ -0,0 +1,132 @@
+// src/roadmap_v2/subtasks.rs
+//! Subtasks. A task's `parent` must name another task without the chain of
+//! parents looping back; a parent rolls up its subtasks' progress and can't
+//! be checked off while one of them is pending.
+
+use super::types::{Task, TaskStatus, TaskStore};
+use crate::error::SlopChopError;
+
+impl TaskStore {
+    /// The tasks whose parent is `id`.
+    #[must_use]
+    pub fn subtasks(&self, id: &str) -> Vec<&Task> {
+        self.tasks
+            .iter()
+            .filter(|t| t.parent.as_deref() == Some(id))
+            .collect()
+    }
+
+    /// Ids of the subtasks of `id`, at any depth, that are still pending.
+    #[must_use]
+    pub fn pending_subtasks(&self, id: &str) -> Vec<&str> {
+        self.descendants(id)
+            .into_iter()
+            .filter(|t| t.status == TaskStatus::Pending)
+            .map(|t| t.id.as_str())
+            .collect()
+    }
+
+    /// `(done, total)` over the subtasks of `id` at any depth; `NoTest`
+    /// counts as done.
+    #[must_use]
+    pub fn progress(&self, id: &str) -> (usize, usize) {
+        let below = self.descendants(id);
+        let done = below
+            .iter()
+            .filter(|t| t.status != TaskStatus::Pending)
+            .count();
+        (done, below.len())
+    }
+
+    /// `tasks` in outline order with their depth: each task followed by
+    /// its subtasks among `tasks`. A task whose parent isn't in `tasks`
+    /// starts a new outline.
+    #[must_use]
+    pub fn outline<'a>(&self, tasks: &[&'a Task]) -> Vec<(&'a Task, usize)> {
+        let mut out = Vec::new();
+        for task in tasks {
+            let rooted = task
+                .parent
+                .as_ref()
+                .is_some_and(|p| tasks.iter().any(|t| &t.id == p));
+            if !rooted {
+                push_outline(task, 0, tasks, &mut out);
+            }
+        }
+        // Only tasks whose parents loop are left.
+        for task in tasks {
+            push_outline(task, 0, tasks, &mut out);
+        }
+        out
+    }
+
+    /// Fails if task `id` can't sit under `parent`: it's unknown, or `id`
+    /// itself or one of its subtasks.
+    pub(super) fn check_parent(&self, id: &str, parent: &str) -> Result<(), SlopChopError> {
+        if !self.tasks.iter().any(|t| t.id == parent) {
+            return Err(SlopChopError::Other(format!(
+                "Unknown parent of {id}: {parent}"
+            )));
+        }
+        if parent == id || self.descendants(id).iter().any(|t| t.id == parent) {
+            return Err(SlopChopError::Other(format!(
+                "Subtask cycle: {id} -> {parent}"
+            )));
+        }
+        Ok(())
+    }
+
+    /// Puts a subtask added without a section in its parent's section and,
+    /// unless it names one, group.
+    pub(super) fn inherit_placement(&self, task: &mut Task, parent: &str) {
+        let Some(parent) = self.tasks.iter().find(|t| t.id == parent) else {
+            return;
+        };
+        if task.section.is_empty() {
+            task.section.clone_from(&parent.section);
+            if task.group.is_none() {
+                task.group.clone_from(&parent.group);
+            }
+        }
+    }
+
+    /// Every task below `id`, each once even if a hand-edited file loops.
+    fn descendants(&self, id: &str) -> Vec<&Task> {
+        let mut found: Vec<&Task> = Vec::new();
+        let mut queue = vec![id];
+        while let Some(current) = queue.pop() {
+            let fresh: Vec<&Task> = self
+                .subtasks(current)
+                .into_iter()
+                .filter(|t| t.id != id && !found.iter().any(|f| f.id == t.id))
+                .collect();
+            queue.extend(fresh.iter().map(|t| t.id.as_str()));
+            found.extend(fresh);
+        }
+        found
+    }
+}
+
+fn push_outline<'a>(
+    task: &'a Task,
+    depth: usize,
+    tasks: &[&'a Task],
+    out: &mut Vec<(&'a Task, usize)>,
+) {
+    if out.iter().any(|(t, _)| t.id == task.id) {
+        return;
+    }
+    out.push((task, depth));
+    for child in tasks.iter().filter(|t| t.parent.as_ref() == Some(&task.id)) {
+        push_outline(child, depth + 1, tasks, out);
+    }
+}
+
+/// `done` of `total` as a whole percentage; 100 when there's nothing to do.
+#[must_use]
+pub fn percent(done: usize, total: usize) -> usize {
+    if total == 0 {
+        return 100;
+    }
+    done * 100 / total
+}
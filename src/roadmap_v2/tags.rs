@@ -0,0 +1,47 @@
+// src/roadmap_v2/tags.rs
+//! Freeform task tags (`tags = ["backend", "perf"]`), for slicing a long
+//! roadmap with `--tag`.
+
+use super::types::{Task, TaskStore};
+use crate::error::SlopChopError;
+
+impl Task {
+    /// True if the task has every tag in `tags`, so no tags match any task.
+    #[must_use]
+    pub fn has_tags(&self, tags: &[String]) -> bool {
+        tags.iter().all(|tag| self.tags.contains(tag))
+    }
+}
+
+impl TaskStore {
+    /// Adds `tags` to task `id`, keeping the ones it has.
+    pub(super) fn tag(&mut self, id: &str, tags: &[String]) -> Result<(), SlopChopError> {
+        let task = self.find_task_mut(id)?;
+        for tag in normalize(tags) {
+            if !task.tags.contains(&tag) {
+                task.tags.push(tag);
+            }
+        }
+        Ok(())
+    }
+
+    /// Removes `tags` from task `id`.
+    pub(super) fn untag(&mut self, id: &str, tags: &[String]) -> Result<(), SlopChopError> {
+        let remove = normalize(tags);
+        let task = self.find_task_mut(id)?;
+        task.tags.retain(|tag| !remove.contains(tag));
+        Ok(())
+    }
+}
+
+/// `tags` trimmed, without blanks or repeats.
+#[must_use]
+pub fn normalize(tags: &[String]) -> Vec<String> {
+    let mut out: Vec<String> = Vec::new();
+    for tag in tags.iter().map(|t| t.trim()).filter(|t| !t.is_empty()) {
+        if !out.iter().any(|t| t == tag) {
+            out.push(tag.to_string());
+        }
+    }
+    out
+}
@@ -1,78 +1,93 @@
-// src/roadmap_v2/types.rs
-use serde::{Deserialize, Serialize};
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct TaskStore {
-    pub meta: RoadmapMeta,
-    #[serde(default)]
-    pub sections: Vec<Section>,
-    #[serde(default)]
-    pub tasks: Vec<Task>,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default)]
-pub struct RoadmapMeta {
-    pub title: String,
-    #[serde(default)]
-    pub description: String,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Section {
-    pub id: String,
-    pub title: String,
-    #[serde(default)]
-    pub status: SectionStatus,
-    #[serde(default)]
-    pub order: u32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "lowercase")]
-pub enum SectionStatus {
-    #[default]
-    Pending,
-    Current,
-    Complete,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct Task {
-    pub id: String,
-    pub text: String,
-    #[serde(default)]
-    pub status: TaskStatus,
-    pub section: String,
-    #[serde(default)]
-    pub group: Option<String>,
-    #[serde(default)]
-    pub test: Option<String>,
-    #[serde(default)]
-    pub order: u32,
-}
-
-#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
-#[serde(rename_all = "kebab-case")]
-pub enum TaskStatus {
-    #[default]
-    Pending,
-    Done,
-    NoTest,
-}
-
-#[derive(Debug, Clone)]
-pub enum RoadmapCommand {
-    Check { id: String },
-    Uncheck { id: String },
-    Add(Task),
-    Update { id: String, fields: TaskUpdate },
-    Delete { id: String },
-}
-
-#[derive(Debug, Clone, Default, Deserialize)]
-pub struct TaskUpdate {
-    pub text: Option<String>,
-    pub test: Option<String>,
-    pub section: Option<String>,
-    pub group: Option<String>,
-}
\ No newline at end of file
+// src/roadmap_v2/types.rs
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct TaskStore {
+    pub meta: RoadmapMeta,
+    #[serde(default)]
+    pub sections: Vec<Section>,
+    #[serde(default)]
+    pub tasks: Vec<Task>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default)]
+pub struct RoadmapMeta {
+    pub title: String,
+    #[serde(default)]
+    pub description: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Section {
+    pub id: String,
+    pub title: String,
+    #[serde(default)]
+    pub status: SectionStatus,
+    #[serde(default)]
+    pub order: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum SectionStatus {
+    #[default]
+    Pending,
+    Current,
+    Complete,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Task {
+    pub id: String,
+    pub text: String,
+    #[serde(default)]
+    pub status: TaskStatus,
+    pub section: String,
+    #[serde(default)]
+    pub group: Option<String>,
+    #[serde(default)]
+    pub test: Option<String>,
+    #[serde(default)]
+    pub order: u32,
+    /// Ids of the tasks that must be done before this one.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub depends_on: Vec<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub tags: Vec<String>,
+    /// Id of the task this is a subtask of.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub parent: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, Default, PartialEq)]
+#[serde(rename_all = "kebab-case")]
+pub enum TaskStatus {
+    #[default]
+    Pending,
+    Done,
+    NoTest,
+}
+
+#[derive(Debug, Clone)]
+pub enum RoadmapCommand {
+    Check { id: String },
+    Uncheck { id: String },
+    Add(Task),
+    Update { id: String, fields: TaskUpdate },
+    Delete { id: String },
+    Tag { id: String, tags: Vec<String> },
+    Untag { id: String, tags: Vec<String> },
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct TaskUpdate {
+    pub text: Option<String>,
+    pub test: Option<String>,
+    pub section: Option<String>,
+    pub group: Option<String>,
+    pub depends_on: Option<Vec<String>>,
+    /// Replaces the task's tags.
+    pub tags: Option<Vec<String>>,
+    /// Moves the task under another.
+    pub parent: Option<String>,
+}
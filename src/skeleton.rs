@@ -24,12 +24,12 @@ pub fn clean(path: &Path, content: &str) -> String {
     let query_str = lang.q_skeleton();
     let replacement = lang.skeleton_replacement();
     let grammar = lang.grammar();
-    let query = compile_query(grammar, query_str);
+    let query = compile_query(&grammar, query_str);
 
-    apply_skeleton(content, grammar, &query, replacement)
+    apply_skeleton(content, &grammar, &query, replacement)
 }
 
-fn apply_skeleton(source: &str, lang: Language, query: &Query, replacement: &str) -> String {
+fn apply_skeleton(source: &str, lang: &Language, query: &Query, replacement: &str) -> String {
     let mut parser = Parser::new();
     if parser.set_language(lang).is_err() {
         return source.to_string();
@@ -64,7 +64,7 @@ fn filter_nested_ranges(mut ranges: Vec<std::ops::Range<usize>>) -> Vec<std::ops
     let mut i = 0;
     while i < ranges.len() {
         let current = &ranges[i];
-        
+
         // Check if this range is contained by any already added range.
         if let Some(last) = result.last() {
             if last.end >= current.end {
@@ -73,7 +73,7 @@ fn filter_nested_ranges(mut ranges: Vec<std::ops::Range<usize>>) -> Vec<std::ops
                 continue;
             }
         }
-        
+
         result.push(current.clone());
         i += 1;
     }
@@ -89,10 +89,10 @@ fn replace_ranges(source: &str, ranges: &[std::ops::Range<usize>], replacement:
         if range.start > last_pos {
             result.push_str(&source[last_pos..range.start]);
         }
-        
+
         // Push replacement
         result.push_str(replacement);
-        
+
         // Advance
         last_pos = range.end;
     }
@@ -105,9 +105,10 @@ fn replace_ranges(source: &str, ranges: &[std::ops::Range<usize>], replacement:
     result
 }
 
-fn compile_query(lang: Language, pattern: &str) -> Query {
+fn compile_query(lang: &Language, pattern: &str) -> Query {
     match Query::new(lang, pattern) {
         Ok(q) => q,
+        // slopchop:ignore-next-line paranoia
         Err(e) => panic!("Invalid skeleton query: {e}"),
     }
-}
\ No newline at end of file
+}
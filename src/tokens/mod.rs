@@ -0,0 +1,131 @@
+// src/tokens/mod.rs
+pub mod report;
+
+use std::sync::{LazyLock, OnceLock, RwLock};
+
+use clap::ValueEnum;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tiktoken_rs::CoreBPE;
+
+/// The encodings are loaded on first use. If one fails to load (which
+/// should never happen with a valid tiktoken-rs installation), token
+/// counting returns 0 and logs an error.
+static CL100K: LazyLock<Option<CoreBPE>> = LazyLock::new(|| load(tiktoken_rs::cl100k_base));
+static O200K: LazyLock<Option<CoreBPE>> = LazyLock::new(|| load(tiktoken_rs::o200k_base));
+
+/// `--model`, which wins over `[tokens] model`.
+static OVERRIDE: OnceLock<TokenModel> = OnceLock::new();
+static CONFIGURED: RwLock<TokenModel> = RwLock::new(TokenModel::Cl100k);
+
+/// Claude's tokenizer is not public; it yields roughly this many tokens per
+/// `cl100k_base` token on source code.
+const CLAUDE_RATIO: f64 = 1.15;
+
+fn load(encoding: fn() -> anyhow::Result<CoreBPE>) -> Option<CoreBPE> {
+    encoding()
+        .map_err(|e| eprintln!("Failed to load tokenizer: {e}"))
+        .ok()
+}
+
+/// Which tokenizer token counts use (`--model`, `[tokens] model`).
+#[derive(
+    Debug, Clone, Copy, Default, PartialEq, Eq, Hash, Serialize, Deserialize, JsonSchema, ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum TokenModel {
+    /// `cl100k_base` (GPT-4, GPT-3.5-turbo).
+    #[default]
+    Cl100k,
+    /// `o200k_base` (GPT-4o and later).
+    O200k,
+    /// An approximation of Claude's tokenizer.
+    Claude,
+}
+
+impl TokenModel {
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Cl100k => "cl100k",
+            Self::O200k => "o200k",
+            Self::Claude => "claude",
+        }
+    }
+
+    /// Context window, in tokens, of the models this tokenizer is used for.
+    #[must_use]
+    pub fn context_window(self) -> usize {
+        match self {
+            Self::Cl100k | Self::O200k => 128_000,
+            Self::Claude => 200_000,
+        }
+    }
+}
+
+pub struct Tokenizer;
+
+impl Tokenizer {
+    /// Counts the number of tokens in the given text with the selected model.
+    /// Returns 0 if the tokenizer failed to initialize.
+    #[must_use]
+    pub fn count(text: &str) -> usize {
+        Self::count_with(Self::model(), text)
+    }
+
+    /// Counts the number of tokens in `text` with `model`.
+    #[must_use]
+    #[allow(
+        clippy::cast_possible_truncation,
+        clippy::cast_precision_loss,
+        clippy::cast_sign_loss
+    )]
+    pub fn count_with(model: TokenModel, text: &str) -> usize {
+        let bpe = match model {
+            TokenModel::O200k => O200K.as_ref(),
+            TokenModel::Cl100k | TokenModel::Claude => CL100K.as_ref(),
+        };
+        let n = bpe.map_or(0, |bpe| bpe.encode_ordinary(text).len());
+        if model == TokenModel::Claude {
+            (n as f64 * CLAUDE_RATIO).ceil() as usize
+        } else {
+            n
+        }
+    }
+
+    /// The model counts use: `--model`, else `[tokens] model`.
+    #[must_use]
+    pub fn model() -> TokenModel {
+        OVERRIDE
+            .get()
+            .copied()
+            .unwrap_or_else(|| CONFIGURED.read().map_or(TokenModel::default(), |m| *m))
+    }
+
+    /// Sets the model from `[tokens] model`; `--model` still wins.
+    pub fn configure(model: TokenModel) {
+        if let Ok(mut configured) = CONFIGURED.write() {
+            *configured = model;
+        }
+    }
+
+    /// Sets the model from `--model` for the rest of the process.
+    pub fn force(model: TokenModel) {
+        let _ = OVERRIDE.set(model);
+    }
+
+    /// Returns true if the text exceeds the token limit.
+    #[must_use]
+    pub fn exceeds_limit(text: &str, limit: usize) -> bool {
+        Self::count(text) > limit
+    }
+
+    /// Returns true if the tokenizer is available.
+    #[must_use]
+    pub fn is_available() -> bool {
+        match Self::model() {
+            TokenModel::O200k => O200K.is_some(),
+            TokenModel::Cl100k | TokenModel::Claude => CL100K.is_some(),
+        }
+    }
+}
@@ -0,0 +1,140 @@
+// src/tokens/report.rs
+//! `slopchop tokens [PATH]`: token and file counts per directory, so it is
+//! easy to see which parts of the project would dominate a pack.
+
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use anyhow::{bail, Result};
+use serde::Serialize;
+
+use crate::config::Config;
+use crate::discovery;
+use crate::pack::{cache, focus};
+
+/// A directory and the totals of every file below it.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct DirTokens {
+    pub name: String,
+    pub tokens: usize,
+    pub files: usize,
+    /// Subdirectories, most tokens first.
+    pub children: Vec<DirTokens>,
+}
+
+impl DirTokens {
+    fn add(&mut self, dirs: &[String], tokens: usize) {
+        self.tokens += tokens;
+        self.files += 1;
+        let Some((first, rest)) = dirs.split_first() else {
+            return;
+        };
+        let pos = self.children.iter().position(|c| &c.name == first);
+        let pos = pos.unwrap_or_else(|| {
+            self.children.push(DirTokens {
+                name: first.clone(),
+                ..DirTokens::default()
+            });
+            self.children.len() - 1
+        });
+        self.children[pos].add(rest, tokens);
+    }
+
+    fn sort(&mut self) {
+        self.children
+            .sort_by(|a, b| b.tokens.cmp(&a.tokens).then_with(|| a.name.cmp(&b.name)));
+        self.children.iter_mut().for_each(Self::sort);
+    }
+}
+
+/// Sums `(path, tokens)` pairs into a tree of the directories below `root`.
+#[must_use]
+pub fn aggregate(root: &Path, files: &[(PathBuf, usize)]) -> DirTokens {
+    let mut tree = DirTokens {
+        name: root.display().to_string(),
+        ..DirTokens::default()
+    };
+    for (path, tokens) in files {
+        let rel = path.strip_prefix(root).unwrap_or(path);
+        let dirs: Vec<String> = rel
+            .parent()
+            .into_iter()
+            .flat_map(Path::components)
+            .map(|c| c.as_os_str().to_string_lossy().into_owned())
+            .collect();
+        tree.add(&dirs, *tokens);
+    }
+    tree.sort();
+    tree
+}
+
+/// The tree as a table, `depth` levels below the root.
+#[must_use]
+pub fn render(tree: &DirTokens, depth: usize) -> String {
+    let mut out = format!(
+        "{:>9}  {:>6}  {:>5}  DIRECTORY\n",
+        "TOKENS", "SHARE", "FILES"
+    );
+    write_dir(&mut out, tree, tree.tokens, 0, depth);
+    out
+}
+
+#[allow(clippy::cast_precision_loss)]
+fn write_dir(out: &mut String, dir: &DirTokens, total: usize, level: usize, depth: usize) {
+    let share = dir.tokens as f64 * 100.0 / total.max(1) as f64;
+    let indent = "  ".repeat(level);
+    let slash = if level == 0 { "" } else { "/" };
+    let _ = writeln!(
+        out,
+        "{:>9}  {share:>5.1}%  {:>5}  {indent}{}{slash}",
+        dir.tokens, dir.files, dir.name
+    );
+    if level < depth {
+        for child in &dir.children {
+            write_dir(out, child, total, level + 1, depth);
+        }
+    }
+}
+
+/// Counts the discovered files under `path` (default: the project) and
+/// prints the tree, `depth` levels deep.
+///
+/// # Errors
+/// Returns error if discovery fails or no files are under `path`.
+pub fn run(path: Option<&Path>, depth: usize, json: bool) -> Result<()> {
+    let mut config = Config::new();
+    config.load_local_config();
+    let cwd = env::current_dir().unwrap_or_default();
+    let root = path.map_or_else(PathBuf::new, |p| focus::normalize(p, &cwd));
+    let files: Vec<(PathBuf, usize)> = discovery::discover(&config)?
+        .into_iter()
+        .filter(|f| focus::normalize(f, &cwd).starts_with(&root))
+        .filter_map(|f| {
+            let content = fs::read_to_string(&f).ok()?;
+            let tokens = cache::tokens(&f, &content);
+            Some((focus::normalize(&f, &cwd), tokens))
+        })
+        .collect();
+    if files.is_empty() {
+        bail!("No files to count under {}", root.display());
+    }
+    // Saving keeps only the entries used, so a partial count would evict
+    // the rest of the project.
+    if path.is_none() {
+        if let Err(e) = cache::save() {
+            eprintln!("WARN: Could not write pack cache: {e}");
+        }
+    }
+    let mut tree = aggregate(&root, &files);
+    if root.as_os_str().is_empty() {
+        tree.name = ".".to_string();
+    }
+    if json {
+        println!("{}", serde_json::to_string_pretty(&tree)?);
+    } else {
+        print!("{}", render(&tree, depth));
+    }
+    Ok(())
+}
@@ -91,4 +91,3 @@ fn extract_skeleton(path: &Path, content: &str) -> String {
     }
     skeleton
 }
-
@@ -130,11 +130,11 @@ fn write_dir_section(
     graph: Option<&RepoGraph>,
 ) {
     let _ = writeln!(out, "{}/", dir.display().to_string().blue().bold());
-    
+
     for f in files {
         let name = f.file_name().unwrap_or_default().to_string_lossy();
         let stats = get_file_stats(f, contents);
-        
+
         let meta = format!(
             "{} KB • {} toks",
             format!("{:.1}", stats.size_kb).yellow(),
@@ -155,7 +155,7 @@ fn render_dependencies(out: &mut String, graph: &RepoGraph, file: &Path) {
     if deps.is_empty() {
         return;
     }
-    
+
     for dep in deps {
         let dep_name = dep.to_string_lossy();
         let _ = writeln!(out, "  │   └── 🔗 {}", dep_name.dimmed());
@@ -163,16 +163,13 @@ fn render_dependencies(out: &mut String, graph: &RepoGraph, file: &Path) {
 }
 
 #[allow(clippy::cast_precision_loss)]
-fn get_file_stats(
-    path: &Path,
-    contents: &HashMap<PathBuf, String>,
-) -> FileStats {
+fn get_file_stats(path: &Path, contents: &HashMap<PathBuf, String>) -> FileStats {
     let content = contents.get(path).map_or("", String::as_str);
     let tokens = Tokenizer::count(content);
     let size_bytes = content.len();
-    
+
     FileStats {
         size_kb: size_bytes as f64 / 1024.0,
         tokens,
     }
-}
\ No newline at end of file
+}
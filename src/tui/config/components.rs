@@ -67,6 +67,33 @@ pub fn draw_settings_table(f: &mut Frame, app: &ConfigApp, area: Rect, pal: &Pal
 }
 
 fn build_table_rows(app: &ConfigApp, pal: &Palette) -> Vec<Row<'static>> {
+    let active_col = Color::Green;
+
+    table_items(app, pal)
+        .into_iter()
+        .enumerate()
+        .map(|(i, (label, value, color, status))| {
+            let is_selected = i == app.selected_field;
+            let style = if is_selected {
+                Style::default()
+                    .bg(pal.highlight)
+                    .fg(Color::Black)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(color)
+            };
+
+            Row::new(vec![
+                Cell::from(format!("[#] {label}")),
+                Cell::from(value),
+                Cell::from(status).style(Style::default().fg(active_col)),
+            ])
+            .style(style)
+        })
+        .collect()
+}
+
+fn table_items(app: &ConfigApp, pal: &Palette) -> Vec<(&'static str, String, Color, &'static str)> {
     let preset = helpers::detect_preset(app);
     let preset_color = match preset {
         "STRICT" => Color::Green,
@@ -75,37 +102,35 @@ fn build_table_rows(app: &ConfigApp, pal: &Palette) -> Vec<Row<'static>> {
         _ => pal.text,
     };
 
-    let active_col = Color::Green;
-
-    let items = vec![
+    vec![
         ("Global Preset", preset.to_string(), preset_color, "ACTIVE"),
         (
             "Max File Tokens",
-            app.rules.max_file_tokens.to_string(),
+            app.rules.size.max_file_tokens.to_string(),
             pal.text,
             "ACTIVE",
         ),
         (
             "Cyclo. Complexity",
-            app.rules.max_cyclomatic_complexity.to_string(),
+            app.rules.functions.max_cyclomatic_complexity.to_string(),
             pal.text,
             "ACTIVE",
         ),
         (
             "Nesting Depth",
-            app.rules.max_nesting_depth.to_string(),
+            app.rules.functions.max_nesting_depth.to_string(),
             pal.text,
             "ACTIVE",
         ),
         (
             "Func. Arguments",
-            app.rules.max_function_args.to_string(),
+            app.rules.functions.max_function_args.to_string(),
             pal.text,
             "ACTIVE",
         ),
         (
             "Func. Words",
-            app.rules.max_function_words.to_string(),
+            app.rules.functions.max_function_words.to_string(),
             pal.text,
             "ACTIVE",
         ),
@@ -145,30 +170,7 @@ fn build_table_rows(app: &ConfigApp, pal: &Palette) -> Vec<Row<'static>> {
             pal.text,
             "OKAY",
         ),
-    ];
-
-    items
-        .into_iter()
-        .enumerate()
-        .map(|(i, (label, value, color, status))| {
-            let is_selected = i == app.selected_field;
-            let style = if is_selected {
-                Style::default()
-                    .bg(pal.highlight)
-                    .fg(Color::Black)
-                    .add_modifier(Modifier::BOLD)
-            } else {
-                Style::default().fg(color)
-            };
-
-            Row::new(vec![
-                Cell::from(format!("[#] {label}")),
-                Cell::from(value),
-                Cell::from(status).style(Style::default().fg(active_col)),
-            ])
-            .style(style)
-        })
-        .collect()
+    ]
 }
 
 fn bool_str(b: bool) -> String {
@@ -282,4 +284,3 @@ pub fn draw_footer(f: &mut Frame, area: Rect, pal: &Palette) {
         area,
     );
 }
-
@@ -4,11 +4,16 @@ use crate::config::Theme;
 
 pub fn adjust_rule(app: &mut ConfigApp, increase: bool) {
     match app.selected_field {
-        1 => adjust_int(&mut app.rules.max_file_tokens, 100, 100, increase),
-        2 => adjust_int(&mut app.rules.max_cyclomatic_complexity, 1, 1, increase),
-        3 => adjust_int(&mut app.rules.max_nesting_depth, 1, 1, increase),
-        4 => adjust_int(&mut app.rules.max_function_args, 1, 1, increase),
-        5 => adjust_int(&mut app.rules.max_function_words, 1, 1, increase),
+        1 => adjust_int(&mut app.rules.size.max_file_tokens, 100, 100, increase),
+        2 => adjust_int(
+            &mut app.rules.functions.max_cyclomatic_complexity,
+            1,
+            1,
+            increase,
+        ),
+        3 => adjust_int(&mut app.rules.functions.max_nesting_depth, 1, 1, increase),
+        4 => adjust_int(&mut app.rules.functions.max_function_args, 1, 1, increase),
+        5 => adjust_int(&mut app.rules.functions.max_function_words, 1, 1, increase),
         _ => {}
     }
 }
@@ -58,9 +63,9 @@ fn cycle_prefix(app: &mut ConfigApp) {
 }
 
 pub fn cycle_preset(app: &mut ConfigApp, forward: bool) {
-    let current = if app.rules.max_file_tokens <= 1500 {
+    let current = if app.rules.size.max_file_tokens <= 1500 {
         0 // Strict
-    } else if app.rules.max_file_tokens <= 2000 {
+    } else if app.rules.size.max_file_tokens <= 2000 {
         1 // Standard
     } else {
         2 // Relaxed
@@ -81,9 +86,9 @@ pub fn cycle_preset(app: &mut ConfigApp, forward: bool) {
 }
 
 fn apply_preset(app: &mut ConfigApp, tokens: usize, complexity: usize, depth: usize) {
-    app.rules.max_file_tokens = tokens;
-    app.rules.max_cyclomatic_complexity = complexity;
-    app.rules.max_nesting_depth = depth;
+    app.rules.size.max_file_tokens = tokens;
+    app.rules.functions.max_cyclomatic_complexity = complexity;
+    app.rules.functions.max_nesting_depth = depth;
 }
 
 #[must_use]
@@ -125,11 +130,12 @@ pub fn get_active_description(field: usize) -> &'static str {
 
 #[must_use]
 pub fn detect_preset(app: &ConfigApp) -> &'static str {
-    if app.rules.max_file_tokens <= 1500 && app.rules.max_cyclomatic_complexity <= 4 {
+    if app.rules.size.max_file_tokens <= 1500 && app.rules.functions.max_cyclomatic_complexity <= 4
+    {
         "STRICT"
-    } else if app.rules.max_file_tokens >= 3000 {
+    } else if app.rules.size.max_file_tokens >= 3000 {
         "RELAXED"
-    } else if app.rules.max_file_tokens == 2000 {
+    } else if app.rules.size.max_file_tokens == 2000 {
         "STANDARD"
     } else {
         "CUSTOM"
@@ -139,9 +145,9 @@ pub fn detect_preset(app: &ConfigApp) -> &'static str {
 #[must_use]
 #[allow(clippy::cast_precision_loss)]
 pub fn get_integrity_score(app: &ConfigApp) -> f64 {
-    let t_score = (app.rules.max_file_tokens as f64 - 1000.0) / 3000.0;
-    let c_score = (app.rules.max_cyclomatic_complexity as f64 - 1.0) / 15.0;
-    let d_score = (app.rules.max_nesting_depth as f64 - 1.0) / 5.0;
+    let t_score = (app.rules.size.max_file_tokens as f64 - 1000.0) / 3000.0;
+    let c_score = (app.rules.functions.max_cyclomatic_complexity as f64 - 1.0) / 15.0;
+    let d_score = (app.rules.functions.max_nesting_depth as f64 - 1.0) / 5.0;
     let raw_avg = (t_score + c_score + d_score) / 3.0;
     (1.0 - raw_avg).clamp(0.0, 1.0)
 }
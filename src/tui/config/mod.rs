@@ -1,4 +1,4 @@
 pub mod components;
 pub mod helpers;
 pub mod state;
-pub mod view;
\ No newline at end of file
+pub mod view;
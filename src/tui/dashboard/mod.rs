@@ -26,11 +26,11 @@ pub fn run(config: &mut Config) -> Result<()> {
 
     // Initial load
     app.trigger_scan();
-    
-    // Attempt to load slopchop.toml (which contains tasks in v2)
+
+    // Attempt to load warden.toml (which contains tasks in v2)
     match TaskStore::load(None) {
-         Ok(r) => app.roadmap = Some(r),
-         Err(e) => app.log(&format!("Failed to load roadmap: {e}")),
+        Ok(r) => app.roadmap = Some(r),
+        Err(e) => app.log(&format!("Failed to load roadmap: {e}")),
     }
 
     loop {
@@ -42,7 +42,7 @@ pub fn run(config: &mut Config) -> Result<()> {
                 if key.code == KeyCode::Char('q') {
                     break;
                 }
-                
+
                 // Route input
                 match app.active_tab {
                     state::Tab::Config => {
@@ -70,7 +70,7 @@ fn handle_input(app: &mut DashboardApp, key: KeyCode) {
         KeyCode::Char('r') => {
             app.trigger_scan();
             app.log("Manual scan triggered");
-        },
+        }
         _ => {}
     }
-}
\ No newline at end of file
+}
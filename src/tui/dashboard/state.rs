@@ -1,8 +1,8 @@
 // src/tui/dashboard/state.rs
-use crate::types::ScanReport;
 use crate::config::Config;
 use crate::roadmap_v2::types::TaskStore;
 use crate::tui::config::state::ConfigApp;
+use crate::types::ScanReport;
 use std::time::{Duration, Instant};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -96,4 +96,4 @@ impl<'a> DashboardApp<'a> {
     pub fn quit(&mut self) {
         self.should_quit = true;
     }
-}
\ No newline at end of file
+}
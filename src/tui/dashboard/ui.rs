@@ -1,7 +1,8 @@
 // src/tui/dashboard/ui.rs
-use crate::types::FileReport;
-use crate::roadmap_v2::types::TaskStatus;
+use crate::roadmap_v2::subtasks;
+use crate::roadmap_v2::types::{Task, TaskStatus, TaskStore};
 use crate::tui::dashboard::state::{DashboardApp, Tab, TaskStatusFilter};
+use crate::types::FileReport;
 use ratatui::{
     layout::{Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
@@ -21,7 +22,7 @@ pub fn draw(f: &mut Frame, app: &mut DashboardApp) {
         .split(f.area());
 
     draw_tabs(f, app, chunks[0]);
-    
+
     match app.active_tab {
         Tab::Dashboard => draw_dashboard(f, app, chunks[1]),
         Tab::Roadmap => draw_roadmap(f, app, chunks[1]),
@@ -41,8 +42,12 @@ fn draw_tabs(f: &mut Frame, app: &DashboardApp, area: Rect) {
     let tabs = Tabs::new(titles)
         .block(Block::default().borders(Borders::ALL).title("SlopChop"))
         .select(app.active_tab as usize)
-        .highlight_style(Style::default().add_modifier(Modifier::BOLD).bg(Color::DarkGray));
-    
+        .highlight_style(
+            Style::default()
+                .add_modifier(Modifier::BOLD)
+                .bg(Color::DarkGray),
+        );
+
     f.render_widget(tabs, area);
 }
 
@@ -57,15 +62,19 @@ fn draw_dashboard(f: &mut Frame, app: &DashboardApp, area: Rect) {
         format!(
             "Files: {}\nViolations: {}\nClean: {}",
             report.files.len(),
-            report.files.iter().map(FileReport::violation_count).sum::<usize>(),
+            report
+                .files
+                .iter()
+                .map(FileReport::violation_count)
+                .sum::<usize>(),
             report.clean_file_count()
         )
     } else {
         "Scanning...".to_string()
     };
 
-    let status = Paragraph::new(status_text)
-        .block(Block::default().borders(Borders::ALL).title("Status"));
+    let status =
+        Paragraph::new(status_text).block(Block::default().borders(Borders::ALL).title("Status"));
     f.render_widget(status, chunks[0]);
 
     // Right: Recent logs
@@ -74,63 +83,90 @@ fn draw_dashboard(f: &mut Frame, app: &DashboardApp, area: Rect) {
 
 fn draw_roadmap(f: &mut Frame, app: &DashboardApp, area: Rect) {
     let Some(store) = &app.roadmap else {
-        let p = Paragraph::new("No roadmap loaded (slopchop.toml)")
+        let p = Paragraph::new("No roadmap loaded (warden.toml)")
             .block(Block::default().borders(Borders::ALL));
         f.render_widget(p, area);
         return;
     };
 
-    let tasks: Vec<ListItem> = store.tasks.iter()
+    let shown: Vec<&Task> = store
+        .tasks
+        .iter()
         .filter(|t| match app.roadmap_filter {
             TaskStatusFilter::All => true,
             TaskStatusFilter::Pending => t.status == TaskStatus::Pending,
             TaskStatusFilter::Done => matches!(t.status, TaskStatus::Done | TaskStatus::NoTest),
         })
-        .map(|t| {
-            let style = if t.status == TaskStatus::Done {
-                Style::default().fg(Color::Green)
-            } else {
-                Style::default()
-            };
-            let prefix = match t.status {
-                TaskStatus::Done | TaskStatus::NoTest => "[x]",
-                TaskStatus::Pending => "[ ]",
-            };
-            ListItem::new(format!("{} {}", prefix, t.text)).style(style)
-        })
+        .collect();
+    let tasks: Vec<ListItem> = store
+        .outline(&shown)
+        .into_iter()
+        .map(|(t, depth)| roadmap_item(store, t, depth))
         .collect();
 
     let list = List::new(tasks)
-        .block(Block::default().borders(Borders::ALL).title("Roadmap Tasks"))
+        .block(
+            Block::default()
+                .borders(Borders::ALL)
+                .title("Roadmap Tasks"),
+        )
         .highlight_style(Style::default().bg(Color::DarkGray));
 
     f.render_widget(list, area);
 }
 
+/// A task indented under its parent, with its subtasks' rolled-up progress.
+fn roadmap_item<'a>(store: &TaskStore, t: &Task, depth: usize) -> ListItem<'a> {
+    let style = if t.status == TaskStatus::Done {
+        Style::default().fg(Color::Green)
+    } else {
+        Style::default()
+    };
+    let prefix = match t.status {
+        TaskStatus::Done | TaskStatus::NoTest => "[x]",
+        TaskStatus::Pending => "[ ]",
+    };
+    let (done, total) = store.progress(&t.id);
+    let progress = if total == 0 {
+        String::new()
+    } else {
+        let pct = subtasks::percent(done, total);
+        format!(" ({done}/{total}, {pct}%)")
+    };
+    let indent = "  ".repeat(depth);
+    ListItem::new(format!("{indent}{prefix} {}{progress}", t.text)).style(style)
+}
+
 fn draw_config(f: &mut Frame, app: &mut DashboardApp, area: Rect) {
     crate::tui::config::view::draw_embed(f, &app.config_editor, area);
 }
 
 fn draw_logs(f: &mut Frame, app: &DashboardApp, area: Rect) {
-    let logs: Vec<ListItem> = app.logs.iter()
+    let logs: Vec<ListItem> = app
+        .logs
+        .iter()
         .rev()
         .map(|s| ListItem::new(Line::from(s.as_str())))
         .collect();
 
-    let list = List::new(logs)
-        .block(Block::default().borders(Borders::ALL).title("System Logs"));
+    let list = List::new(logs).block(Block::default().borders(Borders::ALL).title("System Logs"));
     f.render_widget(list, area);
 }
 
 fn draw_logs_mini(f: &mut Frame, app: &DashboardApp, area: Rect) {
-     let logs: Vec<ListItem> = app.logs.iter()
+    let logs: Vec<ListItem> = app
+        .logs
+        .iter()
         .rev()
         .take(10)
         .map(|s| ListItem::new(Line::from(s.as_str())))
         .collect();
 
-    let list = List::new(logs)
-        .block(Block::default().borders(Borders::ALL).title("Recent Activity"));
+    let list = List::new(logs).block(
+        Block::default()
+            .borders(Borders::ALL)
+            .title("Recent Activity"),
+    );
     f.render_widget(list, area);
 }
 
@@ -138,4 +174,4 @@ fn draw_footer(f: &mut Frame, area: Rect) {
     let text = "q: Quit | TAB: Switch View | r: Reload";
     let p = Paragraph::new(text).style(Style::default().fg(Color::DarkGray));
     f.render_widget(p, area);
-}
\ No newline at end of file
+}
@@ -27,4 +27,4 @@ pub fn restore_terminal() -> Result<()> {
     let mut stdout = io::stdout();
     execute!(stdout, LeaveAlternateScreen, DisableMouseCapture)?;
     Ok(())
-}
\ No newline at end of file
+}
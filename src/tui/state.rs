@@ -9,6 +9,8 @@ pub enum SortMode {
     Path,
     Tokens,
     Violations,
+    /// Least maintainable first.
+    Maintainability,
 }
 
 pub struct App {
@@ -59,6 +61,7 @@ impl App {
                 SortMode::Path => f1.path.cmp(&f2.path),
                 SortMode::Tokens => f2.token_count.cmp(&f1.token_count),
                 SortMode::Violations => f2.violations.len().cmp(&f1.violations.len()),
+                SortMode::Maintainability => maintainability(f1).total_cmp(&maintainability(f2)),
             }
         });
     }
@@ -150,7 +153,8 @@ impl App {
         self.sort_mode = match self.sort_mode {
             SortMode::Path => SortMode::Tokens,
             SortMode::Tokens => SortMode::Violations,
-            SortMode::Violations => SortMode::Path,
+            SortMode::Violations => SortMode::Maintainability,
+            SortMode::Maintainability => SortMode::Path,
         };
         self.update_view();
     }
@@ -169,3 +173,8 @@ impl App {
         }
     }
 }
+
+/// Files without metrics sort last.
+fn maintainability(file: &FileReport) -> f64 {
+    file.metrics.map_or(f64::MAX, |m| m.maintainability)
+}
@@ -109,7 +109,14 @@ fn draw_header(f: &mut Frame, file: &FileReport, area: Rect) {
 fn draw_stats(f: &mut Frame, file: &FileReport, area: Rect) {
     let chunks = Layout::default()
         .direction(Direction::Horizontal)
-        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)].as_ref())
+        .constraints(
+            [
+                Constraint::Percentage(34),
+                Constraint::Percentage(33),
+                Constraint::Percentage(33),
+            ]
+            .as_ref(),
+        )
         .split(area);
 
     let t_ratio = (file.token_count as f64 / 2000.0).clamp(0.0, 1.0);
@@ -136,6 +143,35 @@ fn draw_stats(f: &mut Frame, file: &FileReport, area: Rect) {
         .ratio(v_ratio)
         .label(format!("{v_count} Found"));
     f.render_widget(v_gauge, chunks[1]);
+
+    draw_maintainability(f, file, chunks[2]);
+}
+
+fn draw_maintainability(f: &mut Frame, file: &FileReport, area: Rect) {
+    let (ratio, label, color) = match file.metrics {
+        Some(m) => {
+            let color = if m.maintainability < 20.0 {
+                Color::Red
+            } else if m.maintainability < 40.0 {
+                Color::Yellow
+            } else {
+                Color::Green
+            };
+            let label = format!("{:.0} (V {:.0})", m.maintainability, m.halstead_volume);
+            ((m.maintainability / 100.0).clamp(0.0, 1.0), label, color)
+        }
+        None => (0.0, "n/a".to_string(), Color::DarkGray),
+    };
+    let gauge = Gauge::default()
+        .block(
+            Block::default()
+                .borders(Borders::NONE)
+                .title("Maintainability"),
+        )
+        .gauge_style(Style::default().fg(color))
+        .ratio(ratio)
+        .label(label);
+    f.render_widget(gauge, area);
 }
 
 fn draw_issues(f: &mut Frame, file: &FileReport, area: Rect) {
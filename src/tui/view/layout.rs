@@ -73,6 +73,7 @@ fn get_sort_label(mode: SortMode) -> &'static str {
         SortMode::Path => "NAME",
         SortMode::Tokens => "SIZE",
         SortMode::Violations => "ERRORS",
+        SortMode::Maintainability => "MAINTAINABILITY",
     }
 }
 
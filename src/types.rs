@@ -1,21 +1,184 @@
 // src/types.rs
+use clap::ValueEnum;
+use schemars::JsonSchema;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
 use std::path::PathBuf;
+use std::sync::{LazyLock, Mutex};
+
+/// How serious a violation is.
+#[derive(
+    Debug,
+    Clone,
+    Copy,
+    PartialEq,
+    Eq,
+    PartialOrd,
+    Ord,
+    Default,
+    Serialize,
+    Deserialize,
+    JsonSchema,
+    ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Info,
+    Warning,
+    #[default]
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let label = match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        };
+        f.write_str(label)
+    }
+}
+
+/// The lowest severity that fails a run, or `never` to only report.
+#[derive(
+    Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema, ValueEnum,
+)]
+#[serde(rename_all = "lowercase")]
+pub enum FailOn {
+    Info,
+    Warning,
+    #[default]
+    Error,
+    Never,
+}
+
+impl FailOn {
+    /// `None` for `Never`.
+    #[must_use]
+    pub fn threshold(self) -> Option<Severity> {
+        match self {
+            Self::Info => Some(Severity::Info),
+            Self::Warning => Some(Severity::Warning),
+            Self::Error => Some(Severity::Error),
+            Self::Never => None,
+        }
+    }
+}
+
+/// The law of a note, such as a file skipped as too large: reported at
+/// info, never failing, and out of reach of `[rules.severity]`.
+pub const NOTE: &str = "NOTE";
 
 /// A single violation detected during analysis.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize)]
 pub struct Violation {
     pub row: usize,
     pub message: String,
     pub law: &'static str,
+    pub severity: Severity,
+}
+
+/// Deserialized form of [`Violation`]; the law is interned on conversion.
+#[derive(Deserialize)]
+struct StoredViolation {
+    row: usize,
+    message: String,
+    law: String,
+    severity: Severity,
+}
+
+impl<'de> Deserialize<'de> for Violation {
+    fn deserialize<D: Deserializer<'de>>(d: D) -> Result<Self, D::Error> {
+        let v = StoredViolation::deserialize(d)?;
+        Ok(Self {
+            row: v.row,
+            message: v.message,
+            law: intern_law(&v.law),
+            severity: v.severity,
+        })
+    }
+}
+
+static LAWS: LazyLock<Mutex<HashSet<&'static str>>> = LazyLock::new(Mutex::default);
+
+/// Returns a `'static` copy of a law name, leaking each distinct name once.
+#[must_use]
+pub fn intern_law(name: &str) -> &'static str {
+    let Ok(mut laws) = LAWS.lock() else {
+        return Box::leak(name.to_string().into_boxed_str());
+    };
+    if let Some(law) = laws.get(name) {
+        return law;
+    }
+    let law: &'static str = Box::leak(name.to_string().into_boxed_str());
+    laws.insert(law);
+    law
+}
+
+impl Violation {
+    /// The rule key used in config: `complexity`, `paranoia`, ..., or a custom rule name.
+    #[must_use]
+    pub fn rule(&self) -> String {
+        self.law
+            .strip_prefix("LAW OF ")
+            .unwrap_or(self.law)
+            .to_lowercase()
+    }
+
+    /// True for a [`NOTE`], which no policy counts.
+    #[must_use]
+    pub fn is_note(&self) -> bool {
+        self.law == NOTE
+    }
+}
+
+/// A TODO/FIXME/HACK style comment.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DebtMarker {
+    pub row: usize,
+    pub marker: String,
+    pub text: String,
+}
+
+/// File-level quality metrics.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct FileMetrics {
+    /// Cyclomatic complexity of the whole file.
+    pub complexity: usize,
+    pub halstead_volume: f64,
+    /// 0-100, higher is easier to maintain.
+    pub maintainability: f64,
 }
 
 /// Analysis results for a single file.
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct FileReport {
     pub path: PathBuf,
     pub token_count: usize,
     pub complexity_score: usize,
     pub violations: Vec<Violation>,
+    /// Violations silenced by inline `slopchop:ignore` comments.
+    pub suppressed: Vec<Violation>,
+    /// Violations already recorded in the baseline file.
+    pub baselined: Vec<Violation>,
+    /// Debt markers, collected when `[rules.debt]` is enabled.
+    pub debt: Vec<DebtMarker>,
+    /// `None` for files without a supported grammar.
+    pub metrics: Option<FileMetrics>,
+    /// Last change to each violation's row, filled in by `--blame`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub blame: BTreeMap<usize, LineBlame>,
+}
+
+/// Who last changed a line, from `git blame`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LineBlame {
+    pub commit: String,
+    pub author: String,
+    /// `YYYY-MM-DD`, UTC.
+    pub date: String,
 }
 
 impl FileReport {
@@ -33,7 +196,7 @@ impl FileReport {
 }
 
 /// Aggregated results from scanning multiple files.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ScanReport {
     pub files: Vec<FileReport>,
     pub total_tokens: usize,
@@ -42,10 +205,45 @@ pub struct ScanReport {
 }
 
 impl ScanReport {
-    /// Returns true if any violations were found.
+    /// Returns true if any error-severity violations were found.
     #[must_use]
     pub fn has_errors(&self) -> bool {
-        self.total_violations > 0
+        self.fails_on(Severity::Error)
+    }
+
+    /// Returns true if any violation is at or above `threshold`.
+    #[must_use]
+    pub fn fails_on(&self, threshold: Severity) -> bool {
+        self.violations()
+            .any(|v| v.severity >= threshold && !v.is_note())
+    }
+
+    /// Returns the number of violations with exactly this severity.
+    #[must_use]
+    pub fn count_severity(&self, severity: Severity) -> usize {
+        self.violations().filter(|v| v.severity == severity).count()
+    }
+
+    fn violations(&self) -> impl Iterator<Item = &Violation> {
+        self.files.iter().flat_map(|f| &f.violations)
+    }
+
+    /// Returns the number of violations silenced inline.
+    #[must_use]
+    pub fn suppressed_count(&self) -> usize {
+        self.files.iter().map(|f| f.suppressed.len()).sum()
+    }
+
+    /// Returns the number of violations accepted by the baseline.
+    #[must_use]
+    pub fn baselined_count(&self) -> usize {
+        self.files.iter().map(|f| f.baselined.len()).sum()
+    }
+
+    /// Returns the number of debt markers across all files.
+    #[must_use]
+    pub fn debt_count(&self) -> usize {
+        self.files.iter().map(|f| f.debt.len()).sum()
     }
 
     /// Returns the number of clean files.
@@ -1,4 +1,5 @@
 // src/wizard.rs
+use crate::config::files;
 use crate::project::{self, ProjectType, Strictness};
 use anyhow::Result;
 use colored::Colorize;
@@ -13,8 +14,10 @@ pub fn run() -> Result<()> {
     println!("{}", "🧙 SlopChop Configuration Wizard".bold().cyan());
     println!("{}", "─────────────────────────────────────".dimmed());
 
-    if Path::new("slopchop.toml").exists() {
-        println!("{}", "⚠️  slopchop.toml already exists.".yellow());
+    let path = files::local(files::CONFIG_FILE);
+    if path.exists() {
+        let exists = format!("⚠️  {} already exists.", path.display());
+        println!("{}", exists.yellow());
         if !confirm("Overwrite it?")? {
             println!("Operation cancelled.");
             return Ok(());
@@ -30,7 +33,7 @@ pub fn run() -> Result<()> {
     println!("  Strictness: {}", format!("{strictness:?}").green());
 
     let content = project::generate_toml(project_type, strictness);
-    std::fs::write("slopchop.toml", content)?;
+    std::fs::write(&path, content)?;
 
     println!();
     println!(
@@ -42,10 +45,30 @@ pub fn run() -> Result<()> {
     Ok(())
 }
 
+/// The types offered when detection is wrong, in menu order.
+const CHOICES: [(ProjectType, &str); 9] = [
+    (ProjectType::Rust, "Rust"),
+    (ProjectType::Node, "Node/TypeScript"),
+    (ProjectType::Deno, "Deno"),
+    (ProjectType::Bun, "Bun"),
+    (ProjectType::Python, "Python"),
+    (ProjectType::Go, "Go"),
+    (ProjectType::Maven, "Java (Maven)"),
+    (ProjectType::Gradle, "Java/Kotlin (Gradle)"),
+    (ProjectType::DotNet, ".NET"),
+];
+
 fn prompt_project_type() -> Result<ProjectType> {
     let detected = ProjectType::detect();
     println!();
     println!("Detected Project Type: {}", format!("{detected:?}").cyan());
+    if detected == ProjectType::Mixed {
+        for member in project::members(Path::new(".")) {
+            let dir = member.dir.display().to_string();
+            let dir = if dir.is_empty() { ".".to_string() } else { dir };
+            println!("  {:<8} {dir}", member.kind.name());
+        }
+    }
 
     if confirm("Is this correct?")? {
         return Ok(detected);
@@ -53,22 +76,19 @@ fn prompt_project_type() -> Result<ProjectType> {
 
     println!();
     println!("Select Project Type:");
-    println!("1. Rust");
-    println!("2. Node/TypeScript");
-    println!("3. Python");
-    println!("4. Go");
+    for (i, (_, label)) in CHOICES.iter().enumerate() {
+        println!("{}. {label}", i + 1);
+    }
 
     loop {
-        print!("Enter selection [1-4]: ");
+        print!("Enter selection [1-{}]: ", CHOICES.len());
         io::stdout().flush()?;
 
         let input = read_line()?;
-        match input.trim() {
-            "1" => return Ok(ProjectType::Rust),
-            "2" => return Ok(ProjectType::Node),
-            "3" => return Ok(ProjectType::Python),
-            "4" => return Ok(ProjectType::Go),
-            _ => println!("{}", "Invalid selection.".red()),
+        let choice = input.trim().parse::<usize>().ok();
+        match choice.and_then(|n| CHOICES.get(n.wrapping_sub(1))) {
+            Some((kind, _)) => return Ok(*kind),
+            None => println!("{}", "Invalid selection.".red()),
         }
     }
 }
@@ -0,0 +1,170 @@
+// src/workspace/mod.rs
+//! Monorepo packages: directories below the root with their own
+//! `warden.toml` (or legacy `slopchop.toml`). Each file is discovered and scanned with the config of
+//! the nearest package above it, or with the root config outside every
+//! package.
+//!
+//! A package config stands alone; to build on the root one, it says so
+//! with `extends = "../../warden.toml"`.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use walkdir::WalkDir;
+
+mod scan;
+
+pub use scan::merge;
+
+use crate::config::files::{self, CONFIG_FILE, IGNORE_FILE};
+use crate::config::{self, env, io, Config};
+use crate::constants::should_prune;
+use crate::error::{Result, SlopChopError};
+use crate::ignore::IgnoreSet;
+
+/// A directory below the root with its own config.
+#[derive(Debug, Clone)]
+pub struct Package {
+    /// The directory relative to the root, with `/` separators.
+    pub name: String,
+    pub dir: PathBuf,
+    pub config: Config,
+}
+
+impl Package {
+    /// True if `path` is inside this package.
+    #[must_use]
+    pub fn contains(&self, path: &Path) -> bool {
+        path.starts_with(&self.dir)
+    }
+
+    fn matches(&self, name: &str) -> bool {
+        let name = name.trim_end_matches('/');
+        self.name == name || self.dir.file_name().is_some_and(|n| n == name)
+    }
+}
+
+/// The root config and the packages found below it.
+#[derive(Debug, Clone)]
+pub struct Workspace {
+    pub root: Config,
+    pub packages: Vec<Package>,
+}
+
+impl Workspace {
+    /// Finds the packages under the current directory.
+    #[must_use]
+    pub fn load(root: Config) -> Self {
+        Self::load_in(Path::new("."), root)
+    }
+
+    /// Finds the packages under `dir`. Each package config is read over a
+    /// copy of `root`, so only the sections of its config file change; its
+    /// exclude patterns are the package's own ignore file.
+    #[must_use]
+    pub fn load_in(dir: &Path, root: Config) -> Self {
+        let overrides = env::from_env();
+        let mut packages: Vec<Package> = package_dirs(dir)
+            .into_iter()
+            .filter_map(|pkg| {
+                let path = files::find(&dir.join(&pkg), CONFIG_FILE);
+                let content = fs::read_to_string(&path).ok()?;
+                let mut config = root.clone();
+                config.sources.push(path);
+                io::parse_toml_with(&mut config, &content, &dir.join(&pkg), &overrides);
+                let ignore = fs::read_to_string(files::find(&dir.join(&pkg), IGNORE_FILE));
+                config.files.exclude_patterns = IgnoreSet::parse(&ignore.unwrap_or_default());
+                Some(Package {
+                    name: pkg.to_string_lossy().replace('\\', "/"),
+                    dir: pkg,
+                    config,
+                })
+            })
+            .collect();
+        packages.sort_by(|a, b| a.name.cmp(&b.name));
+        Self { root, packages }
+    }
+
+    /// The deepest package holding `path`.
+    #[must_use]
+    pub fn package_of(&self, path: &Path) -> Option<&Package> {
+        self.package_index(path).map(|i| &self.packages[i])
+    }
+
+    fn package_index(&self, path: &Path) -> Option<usize> {
+        let path = path.strip_prefix(".").unwrap_or(path);
+        (0..self.packages.len())
+            .filter(|&i| self.packages[i].contains(path))
+            .max_by_key(|&i| self.packages[i].dir.components().count())
+    }
+
+    /// Validates the package configs, and the files they extend, that the
+    /// root config doesn't share (see [`Config::validate`]).
+    ///
+    /// # Errors
+    /// Returns error listing the syntax and type errors in any of them.
+    pub fn validate_packages(&self) -> Result<()> {
+        let mut own: Vec<PathBuf> = Vec::new();
+        for source in self.packages.iter().flat_map(|p| &p.config.sources) {
+            if !self.root.sources.contains(source) && !own.contains(source) {
+                own.push(source.clone());
+            }
+        }
+        config::validate_sources(&own)
+    }
+
+    /// The package called `name`, by path (`packages/api`) or by
+    /// directory name (`api`).
+    ///
+    /// # Errors
+    /// Returns error naming the known packages if none matches.
+    pub fn package(&self, name: &str) -> Result<&Package> {
+        self.packages
+            .iter()
+            .find(|p| p.matches(name))
+            .ok_or_else(|| {
+                let known: Vec<&str> = self.packages.iter().map(|p| p.name.as_str()).collect();
+                let known = if known.is_empty() {
+                    "none".to_string()
+                } else {
+                    known.join(", ")
+                };
+                SlopChopError::Other(format!("Unknown package '{name}' (packages: {known})"))
+            })
+    }
+
+    /// Keeps the files of the package called `name`, if given.
+    ///
+    /// # Errors
+    /// Returns error if there is no such package.
+    pub fn filter(&self, files: Vec<PathBuf>, name: Option<&str>) -> Result<Vec<PathBuf>> {
+        let Some(name) = name else {
+            return Ok(files);
+        };
+        let package = self.package(name)?;
+        Ok(files
+            .into_iter()
+            .filter(|f| package.contains(f.strip_prefix(".").unwrap_or(f)))
+            .collect())
+    }
+}
+
+/// Directories below `dir` holding a `warden.toml` (or legacy
+/// `warden.toml`), relative to `dir`.
+#[must_use]
+pub fn package_dirs(dir: &Path) -> Vec<PathBuf> {
+    let mut dirs: Vec<PathBuf> = WalkDir::new(dir)
+        .min_depth(1)
+        .into_iter()
+        .filter_entry(|e| !should_prune(&e.file_name().to_string_lossy()))
+        .filter_map(std::result::Result::ok)
+        .filter(|e| e.file_type().is_file() && files::is_config(&e.file_name().to_string_lossy()))
+        .filter_map(|e| {
+            let parent = e.path().parent()?.strip_prefix(dir).ok()?;
+            (!parent.as_os_str().is_empty()).then(|| parent.to_path_buf())
+        })
+        .collect();
+    dirs.sort();
+    dirs.dedup();
+    dirs
+}
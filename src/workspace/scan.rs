@@ -0,0 +1,128 @@
+// src/workspace/scan.rs
+//! Discovering and scanning the files of a workspace, each under its
+//! package's config.
+
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::Workspace;
+use crate::analysis::cache::CACHE_DIR;
+use crate::analysis::source::Disk;
+use crate::analysis::{cross, RuleEngine};
+use crate::config::{CrossFileChecks, RuleConfig};
+use crate::discovery;
+use crate::error::Result;
+use crate::types::ScanReport;
+
+impl Workspace {
+    /// Discovers the files to scan. As with nested `.gitignore`s, the root
+    /// config selects from every file, and each package's config then
+    /// selects from its own files, its patterns matched relative to the
+    /// package directory.
+    ///
+    /// # Errors
+    /// Returns error if discovery fails.
+    pub fn discover(&self) -> Result<Vec<PathBuf>> {
+        let mut groups = self.group(discovery::discover(&self.root)?).into_iter();
+        let mut files = groups.next().unwrap_or_default();
+        for (package, group) in self.packages.iter().zip(groups) {
+            let inside = group
+                .iter()
+                .filter_map(|f| f.strip_prefix(&package.dir).ok().map(Path::to_path_buf))
+                .collect();
+            let kept = discovery::select(inside, &package.config)?;
+            files.extend(kept.into_iter().map(|f| package.dir.join(f)));
+        }
+        files.sort();
+        Ok(files)
+    }
+
+    /// Like [`Self::discover`], keeping only the files reported by
+    /// [`discovery::changed_files`].
+    ///
+    /// # Errors
+    /// Returns error if discovery fails or git cannot list the changes.
+    pub fn discover_changed(&self, since: Option<&str>) -> Result<Vec<PathBuf>> {
+        let changed = discovery::changed_files(since)?;
+        let files = self.discover()?;
+        Ok(files.into_iter().filter(|p| changed.contains(p)).collect())
+    }
+
+    /// Scans each file with its package's config, or the root config
+    /// outside packages. Packages keep their own scan cache. The cross-file
+    /// passes (dead code, cycles) run over all files, so calls and imports
+    /// between packages count, but under the rules of the package whose
+    /// files they report on.
+    #[must_use]
+    pub fn scan(&self, files: Vec<PathBuf>) -> ScanReport {
+        let all = (!self.packages.is_empty()).then(|| files.clone());
+        let mut found = CrossRuns::new();
+        let mut reports = Vec::new();
+        for (index, files) in self.group(files).into_iter().enumerate() {
+            if files.is_empty() {
+                continue;
+            }
+            let (config, cache) = match index.checked_sub(1).map(|i| &self.packages[i]) {
+                Some(p) => (p.config.clone(), p.dir.join(CACHE_DIR)),
+                None => (self.root.clone(), PathBuf::from(CACHE_DIR)),
+            };
+            let cross = all.as_ref().map(|all| found.get(&config.rules, all));
+            let engine = RuleEngine::new(config).with_cache(cache);
+            let engine = match cross {
+                Some(cross) => engine.with_cross_findings(cross),
+                None => engine,
+            };
+            reports.push(engine.scan(files));
+        }
+        merge(reports)
+    }
+
+    /// `files` by package: the first group holds the files outside every
+    /// package, group `i + 1` those of package `i`.
+    fn group(&self, files: Vec<PathBuf>) -> Vec<Vec<PathBuf>> {
+        let mut groups: Vec<Vec<PathBuf>> = vec![Vec::new(); self.packages.len() + 1];
+        for file in files {
+            let index = self.package_index(&file).map_or(0, |i| i + 1);
+            groups[index].push(file);
+        }
+        groups
+    }
+}
+
+/// Cross-file findings over all files, one run per distinct setting of
+/// the rules the passes read, since packages usually share them.
+struct CrossRuns(Vec<(CrossFileChecks, u64, Arc<cross::Findings>)>);
+
+impl CrossRuns {
+    fn new() -> Self {
+        Self(Vec::new())
+    }
+
+    fn get(&mut self, rules: &RuleConfig, files: &[PathBuf]) -> Arc<cross::Findings> {
+        let bytes = rules.size.max_file_bytes;
+        if let Some((_, _, found)) = self
+            .0
+            .iter()
+            .find(|(checks, b, _)| *checks == rules.cross_file && *b == bytes)
+        {
+            return Arc::clone(found);
+        }
+        let found = Arc::new(cross::find(rules, files, &Disk));
+        self.0
+            .push((rules.cross_file.clone(), bytes, Arc::clone(&found)));
+        found
+    }
+}
+
+/// One report holding every file of `reports`.
+#[must_use]
+pub fn merge(reports: Vec<ScanReport>) -> ScanReport {
+    let mut merged = ScanReport::default();
+    for report in reports {
+        merged.files.extend(report.files);
+        merged.total_tokens += report.total_tokens;
+        merged.total_violations += report.total_violations;
+        merged.duration_ms += report.duration_ms;
+    }
+    merged
+}
@@ -1,6 +1,6 @@
 // tests/integration_backup.rs
 use slopchop_core::apply::types::{FileContent, ManifestEntry, Operation};
-use slopchop_core::apply::writer;
+use slopchop_core::apply::{undo, writer};
 use std::collections::HashMap;
 use std::fs;
 use tempfile::tempdir;
@@ -93,7 +93,7 @@ fn test_existing_backed_up() -> Result<()> {
 }
 
 #[test]
-fn test_new_file_no_backup() -> Result<()> {
+fn test_new_file_recorded_not_copied() -> Result<()> {
     let d = tempdir()?;
     let file_path = "new.txt";
 
@@ -113,16 +113,10 @@ fn test_new_file_no_backup() -> Result<()> {
 
     writer::write_files(&manifest, &files, Some(d.path()))?;
 
-    let backup_root = d.path().join(".slopchop_apply_backup");
-    if backup_root.exists() {
-        // If directory exists, ensure it's empty or doesn't contain our file
-        // Note: Rephrased comment to avoid 'lazy truncation marker' detection by validation logic
-        let count = fs::read_dir(backup_root)?.count();
-        assert_eq!(
-            count, 0,
-            "Should not create backup folder for strictly new files"
-        );
-    }
+    let sets = undo::list(d.path())?;
+    assert_eq!(sets.len(), 1);
+    assert!(sets[0].files.is_empty(), "new files have nothing to copy");
+    assert_eq!(sets[0].record.created, vec![file_path.to_string()]);
     Ok(())
 }
 
@@ -154,4 +148,3 @@ fn test_path_structure() -> Result<()> {
     assert_eq!(fs::read_to_string(backed_up)?, "old");
     Ok(())
 }
-
@@ -8,7 +8,7 @@
 
 use anyhow::Result;
 use slopchop_core::analysis::RuleEngine;
-use slopchop_core::config::{Config, RuleConfig};
+use slopchop_core::config::{Config, FunctionLimits, RuleConfig, SizeLimits};
 use slopchop_core::types::Violation;
 use std::fs::File;
 use std::io::Write;
@@ -18,28 +18,40 @@ use tempfile::TempDir;
 
 fn config_complexity(limit: usize) -> RuleConfig {
     RuleConfig {
-        max_cyclomatic_complexity: limit,
+        functions: FunctionLimits {
+            max_cyclomatic_complexity: limit,
+            ..Default::default()
+        },
         ..Default::default()
     }
 }
 
 fn config_depth(limit: usize) -> RuleConfig {
     RuleConfig {
-        max_nesting_depth: limit,
+        functions: FunctionLimits {
+            max_nesting_depth: limit,
+            ..Default::default()
+        },
         ..Default::default()
     }
 }
 
 fn config_arity(limit: usize) -> RuleConfig {
     RuleConfig {
-        max_function_args: limit,
+        functions: FunctionLimits {
+            max_function_args: limit,
+            ..Default::default()
+        },
         ..Default::default()
     }
 }
 
 fn config_tokens(limit: usize) -> RuleConfig {
     RuleConfig {
-        max_file_tokens: limit,
+        size: SizeLimits {
+            max_file_tokens: limit,
+            ..Default::default()
+        },
         ..Default::default()
     }
 }
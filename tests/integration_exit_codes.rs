@@ -0,0 +1,49 @@
+// tests/integration_exit_codes.rs
+//! The binary's exit codes: 0 clean, 1 violations, 2 error.
+
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::TempDir;
+
+fn exit_code(dir: &Path, args: &[&str]) -> Option<i32> {
+    Command::new(env!("CARGO_BIN_EXE_slopchop"))
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap()
+        .status
+        .code()
+}
+
+#[test]
+fn test_exit_codes() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("slopchop.toml"), "[rules]\n").unwrap();
+    fs::write(dir.path().join("a.rs"), "fn f() {}\n").unwrap();
+    assert_eq!(exit_code(dir.path(), &[]), Some(0));
+
+    fs::write(
+        dir.path().join("a.rs"),
+        "fn f() {\n    Some(1).unwrap();\n}\n",
+    )
+    .unwrap();
+    assert_eq!(exit_code(dir.path(), &[]), Some(1));
+    assert_eq!(exit_code(dir.path(), &["--fail-on", "never"]), Some(0));
+    assert_eq!(exit_code(dir.path(), &["--max-violations", "1"]), Some(0));
+
+    fs::write(
+        dir.path().join("slopchop.toml"),
+        "[rules.fail]\nmax_violations = 1\n",
+    )
+    .unwrap();
+    assert_eq!(exit_code(dir.path(), &[]), Some(0));
+
+    let history = dir.path().join(".slopchop_history.jsonl");
+    fs::remove_file(history).unwrap();
+    assert_eq!(
+        exit_code(dir.path(), &["diff"]),
+        Some(2),
+        "no history is an error"
+    );
+}
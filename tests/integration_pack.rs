@@ -1,5 +1,5 @@
 use slopchop_core::config::Config;
-use slopchop_core::pack::{self, PackOptions};
+use slopchop_core::pack::{self, PackOptions, PackOutput, SkeletonMode};
 use std::fs;
 use tempfile::tempdir;
 
@@ -13,7 +13,7 @@ fn test_nabla_delimiters_are_unique() {
 
     let config = Config::default();
     let opts = PackOptions {
-        stdout: true,
+        output: PackOutput::Stdout,
         ..Default::default()
     };
 
@@ -80,6 +80,17 @@ fn test_prompt_includes_limits() {
     assert!(prompt.contains("Complexity: MUST be ≤ 8"));
 }
 
+#[test]
+fn test_prompt_asks_for_line_counts_only_when_enabled() {
+    let config = Config::default();
+    let generator = slopchop_core::prompt::PromptGenerator::new(config.rules.clone());
+    assert!(!generator.generate().unwrap().contains("[lines:"));
+
+    let generator =
+        slopchop_core::prompt::PromptGenerator::new(config.rules).with_line_counts(true);
+    assert!(generator.generate().unwrap().contains("Add [lines:N]"));
+}
+
 #[test]
 fn test_reminder_is_concise() {
     let config = Config::default();
@@ -99,7 +110,7 @@ fn test_pack_skeleton_integration() {
 
     let config = Config::default();
     let opts = PackOptions {
-        skeleton: true,
+        skeleton: SkeletonMode::All,
         ..Default::default()
     };
 
@@ -10,7 +10,7 @@ fn test_slopchop_passes_own_rules() {
     config.load_local_config();
 
     // 2. Discover files in the current directory (project root)
-    // This respects .slopchopignore, so generated files/target are skipped.
+    // This respects .wardenignore, so generated files/target are skipped.
     let files = discovery::discover(&config).expect("Discovery failed");
 
     assert!(!files.is_empty(), "Self-host check found no files!");
@@ -71,6 +71,9 @@ fn test_store_apply_add() {
         group: None,
         test: Some("tests/unit.rs::test_new".to_string()),
         order: 10,
+        depends_on: Vec::new(),
+        tags: Vec::new(),
+        parent: None,
     };
 
     store
@@ -107,6 +110,9 @@ fn test_store_apply_update() {
                 test: Some("tests/new.rs::test_fn".to_string()),
                 section: None,
                 group: None,
+                depends_on: None,
+                tags: None,
+                parent: None,
             },
         })
         .expect("Update failed");
@@ -236,6 +242,9 @@ fn create_test_store() -> TaskStore {
             group: None,
             test: None,
             order: 0,
+            depends_on: Vec::new(),
+            tags: Vec::new(),
+            parent: None,
         }],
     }
 }
\ No newline at end of file
@@ -1,7 +1,9 @@
 // tests/unit_analysis.rs
 use slopchop_core::analysis::ast::Analyzer;
 use slopchop_core::analysis::RuleEngine;
-use slopchop_core::config::{Config, RuleConfig};
+use slopchop_core::config::{Config, CustomRule, FunctionLimits, RuleConfig, SizeLimits};
+use slopchop_core::types::{Severity, NOTE};
+use std::fmt::Write as _;
 use std::fs::File;
 use std::io::Write;
 use tempfile::TempDir;
@@ -10,8 +12,11 @@ use tempfile::TempDir;
 fn analyze(lang: &str, code: &str, complexity: usize) -> bool {
     let analyzer = Analyzer::new();
     let config = RuleConfig {
-        max_cyclomatic_complexity: complexity,
-        max_function_words: 5, // Default for naming tests
+        functions: FunctionLimits {
+            max_cyclomatic_complexity: complexity,
+            max_function_words: 5, // Default for naming tests
+            ..Default::default()
+        },
         ..Default::default()
     };
 
@@ -53,11 +58,159 @@ fn test_python_complexity() {
     assert!(!analyze("py", code, 3), "Should pass limit 3");
 }
 
+#[test]
+fn test_cognitive_complexity_penalizes_nesting() {
+    let analyzer = Analyzer::new();
+    let config = RuleConfig {
+        functions: FunctionLimits {
+            max_cognitive_complexity: 5,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+
+    // Nested: 1 + 2 + 3 = 6
+    let nested = "fn f(a: bool) { if a { if a { if a {} } } }";
+    let v = analyzer.analyze("rs", "t.rs", nested, &config);
+    assert!(
+        v.iter().any(|v| v.message.contains("Cognitive")),
+        "Should flag nested ifs"
+    );
+
+    // Flat: 1 + 1 + 1 + (else) 1 = 4
+    let flat = "fn f(a: bool) { if a {} if a {} if a {} else {} }";
+    let v = analyzer.analyze("rs", "t.rs", flat, &config);
+    assert!(v.is_empty(), "Should allow flat ifs: {v:?}");
+}
+
+#[test]
+fn test_function_length() {
+    let analyzer = Analyzer::new();
+    let config = RuleConfig {
+        functions: FunctionLimits {
+            max_function_lines: 5,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let body = "    let x = 1;\n".repeat(5);
+    let code = format!("fn long_one() {{\n{body}}}\n");
+    let v = analyzer.analyze("rs", "t.rs", &code, &config);
+    assert_eq!(v.len(), 1, "Should flag a 7-line function");
+    assert!(v[0].message.contains("'long_one' spans 7 lines (1-7)"));
+
+    let code = "fn short_one() {\n    let x = 1;\n}\n";
+    let v = analyzer.analyze("rs", "t.rs", code, &config);
+    assert!(v.is_empty(), "Should allow a 3-line function");
+}
+
+#[test]
+fn test_configured_banned_calls() {
+    let analyzer = Analyzer::new();
+    let mut config = RuleConfig::default();
+    config.banned_calls.insert(
+        "rs".into(),
+        vec!["dbg!".into(), "std::process::exit".into(), ".clone".into()],
+    );
+    config
+        .banned_calls
+        .insert("ts".into(), vec!["console.log".into()]);
+
+    let code = "fn f(v: Vec<u8>) { dbg!(1); std::process::exit(1); v.clone(); println!(); }";
+    let v = analyzer.analyze("rs", "t.rs", code, &config);
+    assert_eq!(v.len(), 3, "Should flag dbg!, exit, and .clone: {v:?}");
+
+    // Keys are per language: the TS list covers JS too, and not Rust.
+    let v = analyzer.analyze("js", "t.js", "function f() { console.log(1); }", &config);
+    assert_eq!(v.len(), 1);
+    let v = analyzer.analyze("py", "t.py", "def f():\n  console.log(1)\n", &config);
+    assert!(v.is_empty());
+}
+
+#[test]
+fn test_bash_complexity() {
+    // 1 (Func) + 1 (If) + 1 (For) = 3
+    let code = "f() {\n  if true; then\n    for x in a b; do echo $x; done\n  fi\n}\n";
+    assert!(analyze("sh", code, 2), "Should fail limit 2");
+    assert!(!analyze("sh", code, 3), "Should pass limit 3");
+}
+
+#[test]
+fn test_bash_hazards() {
+    let analyzer = Analyzer::new();
+    let config = RuleConfig::default();
+    let hazards = [
+        "eval \"$cmd\"\n",
+        "cp $@ /tmp\n",
+        "rm -rf $DIR\n",
+        "rm -rf \"$DIR/\"\n",
+    ];
+    for code in hazards {
+        let v = analyzer.analyze("sh", "t.sh", code, &config);
+        assert_eq!(v.len(), 1, "Should flag hazard in: {code}");
+    }
+
+    let safe = "cp \"$@\" /tmp\nrm -rf \"${DIR:?}/\"\nrm -f $FILE\n";
+    let v = analyzer.analyze("sh", "t.sh", safe, &config);
+    assert!(v.is_empty(), "Should allow safe constructs: {v:?}");
+}
+
+#[test]
+fn test_sfc_script_rows() {
+    let analyzer = Analyzer::new();
+    let config = RuleConfig {
+        functions: FunctionLimits {
+            max_cyclomatic_complexity: 1,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let code = "<template>\n  <div/>\n</template>\n<script setup lang=\"ts\">\nfunction f(x) {\n  if (x) { return 1; }\n}\n</script>\n";
+
+    for ext in ["vue", "svelte"] {
+        let v = analyzer.analyze(ext, "t", code, &config);
+        assert_eq!(v.len(), 1, "Should analyze <script> in .{ext}");
+        assert_eq!(v[0].row, 4, "Row should map back to the component file");
+    }
+}
+
+#[test]
+fn test_bash_function_count() {
+    let analyzer = Analyzer::new();
+    let config = RuleConfig {
+        size: SizeLimits {
+            max_file_functions: 2,
+            ..Default::default()
+        },
+        ..Default::default()
+    };
+    let two = "a() { echo a; }\nb() { echo b; }\n";
+    assert!(analyzer.analyze("sh", "t.sh", two, &config).is_empty());
+
+    let three = format!("{two}function c {{ echo c; }}\n");
+    let v = analyzer.analyze("sh", "t.sh", &three, &config);
+    assert_eq!(v.len(), 1, "Should flag a third function: {v:?}");
+    assert_eq!(v[0].law, "LAW OF ATOMICITY");
+    assert!(v[0].message.contains("defines 3 functions (Max: 2)"));
+}
+
+#[cfg(feature = "zig")]
+#[test]
+fn test_zig_complexity() {
+    // 1 (Fn) + 1 (If) + 1 (While) = 3
+    let code = "fn f() void { if (true) { while (true) {} } }";
+    assert!(analyze("zig", code, 2), "Should fail limit 2");
+    assert!(!analyze("zig", code, 3), "Should pass limit 3");
+}
+
 #[test]
 fn test_snake_case_words() {
     let analyzer = Analyzer::new();
     let config = RuleConfig {
-        max_function_words: 3,
+        functions: FunctionLimits {
+            max_function_words: 3,
+            ..Default::default()
+        },
         ..Default::default()
     };
 
@@ -76,7 +229,10 @@ fn test_snake_case_words() {
 fn test_camel_case_words() {
     let analyzer = Analyzer::new();
     let config = RuleConfig {
-        max_function_words: 3,
+        functions: FunctionLimits {
+            max_function_words: 3,
+            ..Default::default()
+        },
         ..Default::default()
     };
 
@@ -108,3 +264,506 @@ fn test_slopchop_ignore_html() {
         "Should ignore file with html comment"
     );
 }
+
+#[test]
+fn test_dead_code_across_files() {
+    let dir = TempDir::new().unwrap();
+    let a = dir.path().join("a.rs");
+    let b = dir.path().join("b.rs");
+    std::fs::write(
+        &a,
+        "fn used() {}\nfn unused() {}\n#[test]\nfn check() {}\npub fn exported() {}\n",
+    )
+    .unwrap();
+    std::fs::write(&b, "pub fn caller() { used(); }\n").unwrap();
+
+    let mut config = Config::default();
+    config.rules.cross_file.detect_dead_code = true;
+    let report = RuleEngine::new(config).scan(vec![a.clone(), b]);
+    let dead: Vec<_> = report
+        .files
+        .iter()
+        .flat_map(|f| f.violations.iter().map(move |v| (&f.path, v)))
+        .filter(|(_, v)| v.message.contains("Dead Code"))
+        .collect();
+
+    assert_eq!(dead.len(), 1, "Only 'unused' should be flagged: {dead:?}");
+    assert_eq!(dead[0].0, &a);
+    assert!(dead[0].1.message.contains("'unused'"));
+}
+
+#[test]
+fn test_custom_query_rules() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    std::fs::write(&path, "fn f() {\n    dbg!(1);\n}\n").unwrap();
+
+    let mut config = Config::default();
+    config.rules.custom = vec![
+        CustomRule {
+            name: "no-dbg".into(),
+            lang: "rs".into(),
+            query: r#"(macro_invocation macro: (identifier) @m (#eq? @m "dbg")) @match"#.into(),
+            message: "dbg!() left in code".into(),
+            severity: Severity::Warning,
+        },
+        CustomRule {
+            name: "broken".into(),
+            lang: "rs".into(),
+            query: "(not_a_node) @x".into(),
+            message: "never reported".into(),
+            severity: Severity::Error,
+        },
+    ];
+
+    let report = RuleEngine::new(config).scan(vec![path]);
+    let v = &report.files[0].violations;
+    assert_eq!(v.len(), 1, "Only the valid rule should fire: {v:?}");
+    assert_eq!(v[0].row, 1);
+    assert_eq!(v[0].law, "no-dbg");
+    assert_eq!(v[0].severity, Severity::Warning);
+}
+
+#[test]
+fn test_severity_overrides_and_fail_on() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    std::fs::write(&path, "fn f() { if true { if true {} } }\n").unwrap();
+
+    let mut config = Config::default();
+    config.rules.functions.max_cyclomatic_complexity = 1;
+    config
+        .rules
+        .severity
+        .insert("complexity".into(), Severity::Warning);
+
+    let report = RuleEngine::new(config).scan(vec![path]);
+    assert!(report.total_violations > 0);
+    assert!(report.files[0]
+        .violations
+        .iter()
+        .all(|v| v.severity == Severity::Warning));
+    assert!(!report.has_errors(), "Warnings should not fail by default");
+    assert!(
+        report.fails_on(Severity::Warning),
+        "--fail-on warning should fail"
+    );
+}
+
+#[test]
+fn test_scoped_suppression_covers_types_and_impls() {
+    let dir = TempDir::new().unwrap();
+    let rust = dir.path().join("a.rs");
+    let code = "\
+// slopchop:ignore(complexity)
+struct Wide {
+    a: u8,
+    b: u8,
+    c: u8,
+}
+
+// slopchop:ignore(complexity)
+impl Wide {
+    fn f(&self) { if true { if true {} } }
+}
+
+fn g() { if true { if true {} } }
+";
+    std::fs::write(&rust, code).unwrap();
+    let script = dir.path().join("b.ts");
+    let code = "\
+// slopchop:ignore(complexity)
+class A {
+    m(x) { if (x) { if (x) {} } }
+}
+function h(x) { if (x) { if (x) {} } }
+";
+    std::fs::write(&script, code).unwrap();
+
+    let mut config = Config::default();
+    config.rules.functions.max_cyclomatic_complexity = 1;
+    config.rules.max_struct_fields = 2;
+    let report = RuleEngine::new(config).scan(vec![rust, script]);
+    let rows = |i: usize, suppressed: bool| {
+        let file = &report.files[i];
+        let list = if suppressed {
+            &file.suppressed
+        } else {
+            &file.violations
+        };
+        let mut rows: Vec<usize> = list.iter().map(|v| v.row).collect();
+        rows.sort_unstable();
+        rows
+    };
+    assert_eq!(rows(0, true), vec![1, 9]);
+    assert_eq!(rows(0, false), vec![12]);
+    assert_eq!(rows(1, true), vec![2]);
+    assert_eq!(rows(1, false), vec![4]);
+}
+
+#[test]
+fn test_inline_suppressions() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    let code = "\
+fn a(x: Option<i32>) {
+    // slopchop:ignore-next-line paranoia
+    x.unwrap();
+    x.expect(\"boom\");
+}
+
+// slopchop:ignore(complexity)
+#[inline]
+fn b() { if true { if true {} } }
+
+// slopchop:ignore(paranoia)
+fn c() { if true { if true {} } }
+";
+    std::fs::write(&path, code).unwrap();
+
+    let mut config = Config::default();
+    config.rules.functions.max_cyclomatic_complexity = 1;
+    let report = RuleEngine::new(config).scan(vec![path]);
+    let file = &report.files[0];
+
+    let kept: Vec<_> = file.violations.iter().map(|v| (v.row, v.rule())).collect();
+    let mut muted: Vec<_> = file.suppressed.iter().map(|v| (v.row, v.rule())).collect();
+    muted.sort();
+    assert_eq!(
+        muted,
+        vec![(2, "paranoia".into()), (8, "complexity".into())]
+    );
+    assert!(
+        kept.contains(&(3, "paranoia".into())),
+        "Only the next line is silenced"
+    );
+    assert!(
+        kept.contains(&(11, "complexity".into())),
+        "Rule list must match"
+    );
+    assert_eq!(report.total_violations, file.violations.len());
+}
+
+#[test]
+fn test_struct_field_limit() {
+    let analyzer = Analyzer::new();
+    let config = RuleConfig {
+        max_struct_fields: 2,
+        ..Default::default()
+    };
+    let fields = |lang: &str, code: &str| {
+        analyzer
+            .analyze(lang, "test", code, &config)
+            .iter()
+            .filter(|v| v.message.contains("Too Many Fields"))
+            .count()
+    };
+
+    assert_eq!(fields("rs", "struct A { a: u8, b: u8 }"), 0);
+    assert_eq!(fields("rs", "struct A { a: u8, b: u8, c: u8 }"), 1);
+    assert_eq!(
+        fields("ts", "interface I { a: string; b: number; c: boolean }"),
+        1
+    );
+    assert_eq!(fields("ts", "class C { a = 1; b = 2; c = 3; m() {} }"), 1);
+    let py =
+        "class P:\n    a = 1\n    def __init__(self):\n        self.b = 2\n        self.b = 3\n";
+    assert_eq!(fields("py", py), 0, "Repeated self attrs count once");
+    let py = format!("{py}        self.c = 4\n");
+    assert_eq!(fields("py", &py), 1);
+}
+
+#[test]
+fn test_debt_markers() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    let code = "// TODO: split this\nfn f() {\n    let s = \"TODO in a string\";\n    /* FIXME(bob) leaks */\n}\n# HACK\n";
+    std::fs::write(&path, code).unwrap();
+
+    let mut config = Config::default();
+    config.rules.debt.enabled = true;
+    config.rules.debt.max_per_file = Some(2);
+    let report = RuleEngine::new(config).scan(vec![path]);
+    let file = &report.files[0];
+
+    let found: Vec<_> = file
+        .debt
+        .iter()
+        .map(|d| (d.row, d.marker.as_str()))
+        .collect();
+    assert_eq!(found, vec![(0, "TODO"), (3, "FIXME"), (5, "HACK")]);
+    assert_eq!(file.debt[0].text, "split this");
+    assert!(file
+        .violations
+        .iter()
+        .any(|v| v.law == "LAW OF DEBT" && v.rule() == "debt" && v.row == 5));
+}
+
+#[test]
+fn test_maintainability_metrics() {
+    let analyzer = Analyzer::new();
+    let simple = analyzer.measure("rs", "fn f() -> i32 { 1 }").unwrap();
+    let mut busy = String::new();
+    for i in 0..40 {
+        let _ = writeln!(
+            busy,
+            "fn f{i}(a: i32, b: i32) -> i32 {{ if a > b {{ a * {i} }} else if a < b {{ b - a }} else {{ 0 }} }}"
+        );
+    }
+    let busy = analyzer.measure("rs", &busy).unwrap();
+
+    assert!(simple.halstead_volume > 0.0);
+    assert!(busy.halstead_volume > simple.halstead_volume);
+    assert!(busy.maintainability < simple.maintainability);
+    assert!((0.0..=100.0).contains(&busy.maintainability));
+    assert!(analyzer.measure("txt", "hello").is_none());
+}
+
+#[test]
+fn test_unsafe_block_budget() {
+    let analyzer = Analyzer::new();
+    let code = "fn f() { unsafe { a(); } }\nfn g() { unsafe { b(); } }";
+    let unsafe_hits = |max: usize| {
+        let config = RuleConfig {
+            max_unsafe_blocks: max,
+            ..Default::default()
+        };
+        analyzer
+            .analyze("rs", "test", code, &config)
+            .into_iter()
+            .filter(|v| v.law == "LAW OF SAFETY")
+            .map(|v| v.row)
+            .collect::<Vec<_>>()
+    };
+
+    assert_eq!(unsafe_hits(0), vec![0]);
+    assert_eq!(
+        unsafe_hits(1),
+        vec![1],
+        "Reported at the first block over budget"
+    );
+    assert!(unsafe_hits(2).is_empty());
+}
+
+#[test]
+fn test_panic_macros_outside_tests() {
+    let analyzer = Analyzer::new();
+    let code = r#"
+fn a() { todo!() }
+fn b() { unimplemented!("later") }
+fn c() { panic!("boom") }
+fn d() { println!("fine") }
+
+#[test]
+fn t() { panic!("allowed") }
+
+#[cfg(test)]
+mod tests {
+    fn helper() { todo!() }
+}
+"#;
+    let rows = |filename: &str, config: &RuleConfig| {
+        analyzer
+            .analyze("rs", filename, code, config)
+            .into_iter()
+            .filter(|v| v.message.contains("!()'"))
+            .map(|v| v.row)
+            .collect::<Vec<_>>()
+    };
+
+    let mut config = RuleConfig::default();
+    assert_eq!(rows("src/lib.rs", &config), vec![1, 2, 3]);
+    assert!(rows("tests/it.rs", &config).is_empty());
+    config.exempt.ignore_panics_on = vec!["src/bin".into()];
+    assert!(rows("src/bin/tool.rs", &config).is_empty());
+}
+
+#[test]
+fn test_relaxed_rules_in_test_code() {
+    let dir = TempDir::new().unwrap();
+    let lib = dir.path().join("lib.rs");
+    let code = "\
+fn prod(x: Option<u8>) { x.unwrap(); }
+
+#[cfg(test)]
+mod tests {
+    #[test]
+    fn t() { Some(1).unwrap(); if true { if true {} } }
+}
+";
+    std::fs::write(&lib, code).unwrap();
+    let spec = dir.path().join("widget.spec.ts");
+    std::fs::write(&spec, "function f() { if (a) { if (b) {} } }\n").unwrap();
+
+    let mut config = Config::default();
+    config.rules.functions.max_cyclomatic_complexity = 1;
+    config
+        .rules
+        .tests
+        .severity
+        .insert("complexity".into(), Severity::Info);
+    let report = RuleEngine::new(config).scan(vec![lib, spec]);
+
+    let lib = &report.files[0].violations;
+    assert_eq!(lib.len(), 2, "{lib:?}");
+    assert!(lib.iter().any(|v| v.row == 0 && v.rule() == "paranoia"));
+    assert!(lib
+        .iter()
+        .any(|v| v.row == 5 && v.rule() == "complexity" && v.severity == Severity::Info));
+    assert!(report.files[1]
+        .violations
+        .iter()
+        .all(|v| v.severity == Severity::Info));
+}
+
+#[test]
+fn test_import_cycles() {
+    let dir = TempDir::new().unwrap();
+    let src = dir.path().join("src");
+    std::fs::create_dir_all(&src).unwrap();
+    let files = [
+        ("lib.rs", "mod a;\nmod b;\nmod c;\n"),
+        (
+            "a.rs",
+            "use crate::b::{Bee, other::Thing};\n#[cfg(test)]\nmod tests {\n    use super::*;\n}\n",
+        ),
+        ("b.rs", "use super::a::Ay;\n"),
+        ("c.rs", "use crate::a::Ay;\n"),
+        ("x.ts", "import { y } from \"./y\";\n"),
+        ("y.ts", "import { x } from \"./x\";\n"),
+    ];
+    let paths: Vec<_> = files
+        .iter()
+        .map(|(name, code)| {
+            let path = src.join(name);
+            std::fs::write(&path, code).unwrap();
+            path
+        })
+        .collect();
+
+    let mut config = Config::default();
+    config.rules.cross_file.detect_cycles = true;
+    let report = RuleEngine::new(config).scan(paths);
+
+    let mut cyclic: Vec<_> = report
+        .files
+        .iter()
+        .filter(|f| f.violations.iter().any(|v| v.law == "LAW OF CYCLES"))
+        .map(|f| f.path.file_name().unwrap().to_string_lossy().into_owned())
+        .collect();
+    cyclic.sort();
+    assert_eq!(cyclic, vec!["a.rs", "b.rs", "x.ts", "y.ts"]);
+}
+
+#[test]
+fn test_doc_coverage() {
+    let analyzer = Analyzer::new();
+    let mut config = RuleConfig::default();
+    config.require_docs.insert("rs".into(), Severity::Warning);
+    config.require_docs.insert("py".into(), Severity::Info);
+    config.require_docs.insert("ts".into(), Severity::Info);
+    let missing = |lang: &str, code: &str| {
+        analyzer
+            .analyze(lang, "test", code, &config)
+            .into_iter()
+            .filter(|v| v.law == "LAW OF DOCS")
+            .map(|v| (v.row, v.severity))
+            .collect::<Vec<_>>()
+    };
+
+    let rs = "/// Documented.\n#[must_use]\npub fn a() {}\npub fn b() {}\nfn private() {}\nimpl X {\n    pub fn c() {}\n}\n";
+    assert_eq!(
+        missing("rs", rs),
+        vec![(3, Severity::Warning), (6, Severity::Warning)]
+    );
+
+    let py = "def a():\n    \"\"\"Doc.\"\"\"\n\ndef b():\n    pass\n\ndef _hidden():\n    pass\n";
+    assert_eq!(missing("py", py), vec![(3, Severity::Info)]);
+
+    let ts = "/** Doc. */\nexport function a() {}\nexport class B {}\nfunction local() {}\n";
+    assert_eq!(missing("ts", ts), vec![(2, Severity::Info)]);
+    assert!(missing("sh", "f() { :; }").is_empty());
+}
+
+#[test]
+fn test_scan_reports_progress() {
+    let dir = TempDir::new().unwrap();
+    let files: Vec<_> = (0..3)
+        .map(|i| {
+            let path = dir.path().join(format!("f{i}.rs"));
+            std::fs::write(&path, "fn f() {}\n").unwrap();
+            path
+        })
+        .collect();
+
+    let seen = std::sync::Mutex::new(Vec::new());
+    let report = RuleEngine::new(Config::default())
+        .scan_with_progress(files.clone(), |p| seen.lock().unwrap().push(p));
+    assert_eq!(report.files.len(), 3);
+
+    let mut seen = seen.into_inner().unwrap();
+    seen.sort_by_key(|p| p.completed);
+    let completed: Vec<_> = seen.iter().map(|p| p.completed).collect();
+    assert_eq!(completed, vec![1, 2, 3]);
+    assert!(seen
+        .iter()
+        .all(|p| p.total == 3 && files.contains(&p.current)));
+    assert_eq!(seen[2].eta, std::time::Duration::ZERO);
+}
+
+#[test]
+fn test_oversized_file_is_skipped_with_note() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("big.rs");
+    std::fs::write(&path, "fn f() { Some(1).unwrap(); }\n").unwrap();
+
+    let mut config = Config::default();
+    config.rules.size.max_file_bytes = 10;
+    let report = RuleEngine::new(config).scan(vec![path]);
+    let v = &report.files[0].violations;
+    assert_eq!(v.len(), 1, "Only the skip note should be reported: {v:?}");
+    assert!(v[0].message.starts_with("Skipped: too large"));
+    assert_eq!(v[0].severity, Severity::Info);
+    assert!(!report.has_errors());
+}
+
+#[test]
+fn test_skip_note_ignores_severity_overrides_and_policy() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("big.rs");
+    std::fs::write(&path, "fn f() {}\n").unwrap();
+
+    let mut config = Config::default();
+    config.parse_toml("[rules.severity]\natomicity = \"error\"\nnote = \"error\"\n");
+    config.rules.size.max_file_bytes = 5;
+    let report = RuleEngine::new(config).scan(vec![path]);
+    let v = &report.files[0].violations;
+    assert_eq!(v[0].law, NOTE);
+    assert_eq!(v[0].severity, Severity::Info);
+    assert!(!report.fails_on(Severity::Info));
+}
+
+#[test]
+fn test_scan_sources_never_touches_disk() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.path().join("cache");
+    let mut config = Config::default();
+    config.rules.cross_file.detect_dead_code = true;
+    let engine = RuleEngine::new(config).with_cache(&cache);
+
+    let report = engine.scan_sources(vec![
+        (
+            "unsaved/a.rs".into(),
+            "fn used() {}\nfn unused() { Some(1).unwrap(); }\n".into(),
+        ),
+        (
+            "unsaved/b.rs".into(),
+            "pub fn caller() { used(); }\n".into(),
+        ),
+    ]);
+    let laws: Vec<_> = report.files[0].violations.iter().map(|v| v.law).collect();
+    assert!(laws.contains(&"LAW OF PARANOIA"), "{laws:?}");
+    assert!(laws.contains(&"LAW OF ECONOMY"), "{laws:?}");
+    assert!(report.files[1].violations.is_empty());
+    assert!(!cache.exists(), "In-memory scans must not write the cache");
+}
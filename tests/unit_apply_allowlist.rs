@@ -0,0 +1,42 @@
+// tests/unit_apply_allowlist.rs
+use slopchop_core::apply::allowlist;
+
+const PAYLOAD: &str = "#__SLOPCHOP_MANIFEST__#\n\
+                       src/lib.rs\n\
+                       tests/unit.rs [NEW]\n\
+                       src/old.rs -> docs/old.rs [RENAME]\n\
+                       #__SLOPCHOP_END__#";
+
+fn allowed(globs: &[&str]) -> Vec<String> {
+    globs.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_glob_matching() {
+    assert!(allowlist::matches("src/**", "src/a/b/c.rs"));
+    assert!(allowlist::matches("src/*.rs", "src/lib.rs"));
+    assert!(!allowlist::matches("src/*.rs", "src/a/lib.rs"));
+    assert!(allowlist::matches("**/*.rs", "lib.rs"));
+    assert!(allowlist::matches("**/*.rs", "a/b/lib.rs"));
+    assert!(allowlist::matches("./tests/**", "tests/x.rs"));
+    assert!(!allowlist::matches("src/**", "srcx/a.rs"));
+    assert!(allowlist::matches("file?.txt", "file1.txt"));
+}
+
+#[test]
+fn test_empty_allowlist_allows_everything() {
+    assert!(allowlist::violations(PAYLOAD, &[]).is_empty());
+}
+
+#[test]
+fn test_files_outside_allowlist_are_reported() {
+    let errors = allowlist::violations(PAYLOAD, &allowed(&["src/**", "tests/**"]));
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    assert!(errors[0].starts_with("docs/old.rs is outside the allowed paths"));
+}
+
+#[test]
+fn test_all_inside_allowlist_passes() {
+    let errors = allowlist::violations(PAYLOAD, &allowed(&["src/**", "tests/**", "docs/*"]));
+    assert!(errors.is_empty(), "{errors:?}");
+}
@@ -0,0 +1,37 @@
+// tests/unit_apply_commit_message.rs
+use slopchop_core::apply::commit_message;
+
+const TEMPLATE: &str =
+    "{prefix}{summary}\n\n{body}\n\nRefs: {task}\nCo-authored-by: AI <ai@example.com>";
+
+fn tasks(ids: &[&str]) -> Vec<String> {
+    ids.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_render_fills_placeholders() {
+    let msg = commit_message::render(
+        TEMPLATE,
+        "add retry to fetch\n\nBacks off three times.",
+        "feat: ",
+        &tasks(&["net-retry", "net-timeout"]),
+    );
+    assert_eq!(
+        msg,
+        "feat: add retry to fetch\n\nBacks off three times.\n\nRefs: net-retry, net-timeout\n\
+         Co-authored-by: AI <ai@example.com>"
+    );
+}
+
+#[test]
+fn test_render_drops_lines_with_only_empty_placeholders() {
+    let msg = commit_message::render(TEMPLATE, "fix typo", "fix: ", &[]);
+    assert_eq!(msg, "fix: fix typo\n\nCo-authored-by: AI <ai@example.com>");
+}
+
+#[test]
+fn test_task_ids_from_checked_roadmap_tasks() {
+    let content = "===ROADMAP===\nCHECK\nid = net-retry\n===ROADMAP===\n";
+    assert_eq!(commit_message::task_ids(content), ["net-retry"]);
+    assert!(commit_message::task_ids("no roadmap here").is_empty());
+}
@@ -0,0 +1,95 @@
+// tests/unit_apply_diagnostics.rs
+use slopchop_core::apply::diagnostics::{self, Diagnostic};
+use slopchop_core::config::CommandStep;
+
+const OUTPUT: &str = r#"{"reason":"compiler-artifact","package_id":"dep 0.1.0"}
+{"reason":"compiler-message","message":{"rendered":"error[E0308]: mismatched types\n","level":"error","message":"mismatched types","spans":[{"file_name":"src/lib.rs","line_start":7,"is_primary":true}]}}
+{"reason":"compiler-message","message":{"rendered":"warning: unused variable\n","level":"warning","message":"unused variable: `x`","spans":[{"file_name":"src/a.rs","line_start":2,"is_primary":false},{"file_name":"src/b.rs","line_start":9,"is_primary":true}]}}
+{"reason":"compiler-message","message":{"rendered":"error: aborting due to 1 previous error\n","level":"error","message":"aborting due to 1 previous error","spans":[]}}
+running 1 test
+test tests::it_works ... FAILED
+{"reason":"build-finished","success":false}
+"#;
+
+/// The arguments of `cmd` after `with_json`.
+fn with_json(cmd: &str) -> Vec<String> {
+    let mut args = CommandStep::line(cmd).args();
+    diagnostics::with_json(&mut args);
+    args
+}
+
+#[test]
+fn test_with_json_only_for_cargo_compile_commands() {
+    assert_eq!(
+        with_json("cargo test"),
+        ["cargo", "test", "--message-format=json"]
+    );
+    assert_eq!(
+        with_json("cargo +nightly clippy -- -D warnings"),
+        [
+            "cargo",
+            "+nightly",
+            "clippy",
+            "--message-format=json",
+            "--",
+            "-D",
+            "warnings"
+        ]
+    );
+    assert_eq!(with_json("cargo fmt --check"), ["cargo", "fmt", "--check"]);
+    assert_eq!(
+        with_json("cargo check --message-format=short"),
+        ["cargo", "check", "--message-format=short"]
+    );
+    assert_eq!(with_json("npm test"), ["npm", "test"]);
+}
+
+#[test]
+fn test_with_json_keeps_quoted_arguments() {
+    assert_eq!(
+        with_json(r#"cargo test -- "two words" 'it''s'"#),
+        [
+            "cargo",
+            "test",
+            "--message-format=json",
+            "--",
+            "two words",
+            "its"
+        ]
+    );
+}
+
+#[test]
+fn test_parse_extracts_errors_and_warnings() {
+    let parsed = diagnostics::parse(OUTPUT);
+    assert_eq!(
+        parsed.diagnostics,
+        [
+            Diagnostic {
+                location: Some(("src/lib.rs".to_string(), 7)),
+                level: "error".to_string(),
+                message: "mismatched types".to_string(),
+            },
+            Diagnostic {
+                location: Some(("src/b.rs".to_string(), 9)),
+                level: "warning".to_string(),
+                message: "unused variable: `x`".to_string(),
+            },
+        ]
+    );
+    assert!(parsed.rendered.contains("error[E0308]: mismatched types"));
+    assert_eq!(
+        parsed.other,
+        "running 1 test\ntest tests::it_works ... FAILED\n"
+    );
+}
+
+#[test]
+fn test_summary_lists_locations_then_other_output() {
+    let summary = diagnostics::parse(OUTPUT).summary();
+    assert!(summary.starts_with(
+        "DIAGNOSTICS:\nsrc/lib.rs:7: error: mismatched types\n\
+         src/b.rs:9: warning: unused variable: `x`\nrunning 1 test"
+    ));
+    assert!(!summary.contains("reason"));
+}
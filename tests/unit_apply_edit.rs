@@ -0,0 +1,86 @@
+// tests/unit_apply_edit.rs
+//! Tests for search/replace edit blocks.
+
+use slopchop_core::apply::edit::{self, EditBlock};
+use slopchop_core::apply::extractor::extract_edits;
+use slopchop_core::apply::preserve::WriteOptions;
+use slopchop_core::apply::types::ApplyOutcome;
+use slopchop_core::apply::{validator, writer};
+use slopchop_core::config::TruncationSettings;
+use std::fs;
+use tempfile::TempDir;
+
+const ORIGINAL: &str = "fn a() {\n    one();\n}\n\nfn b() {\n    one();\n}\n";
+
+fn block(search: &str, replace: &str) -> EditBlock {
+    EditBlock {
+        path: "x.rs".into(),
+        search: search.into(),
+        replace: replace.into(),
+    }
+}
+
+fn payload(body: &str) -> String {
+    format!(
+        "#__SLOPCHOP_MANIFEST__#\nx.rs\n#__SLOPCHOP_END__#\n\
+         #__SLOPCHOP_EDIT__# x.rs\n{body}#__SLOPCHOP_END__#\n"
+    )
+}
+
+#[test]
+fn test_extract_edits_parses_pairs() {
+    let body = "<<<<<<< SEARCH\nfn a() {\n=======\nfn alpha() {\n>>>>>>> REPLACE\n\
+                <<<<<<< SEARCH\nfn b() {\n=======\n>>>>>>> REPLACE\n";
+    let edits = extract_edits(&payload(body)).unwrap();
+    assert_eq!(
+        edits,
+        vec![block("fn a() {", "fn alpha() {"), block("fn b() {", "")]
+    );
+}
+
+#[test]
+fn test_exact_then_whitespace_tolerant_match() {
+    let exact = block("fn b() {\n    one();", "fn b() {\n    two();");
+    let out = edit::apply(ORIGINAL, &[&exact]).unwrap();
+    assert!(out.ends_with("fn b() {\n    two();\n}\n"));
+
+    let loose = block("fn a() {\n  one();  \n}", "fn a() {}");
+    let out = edit::apply(ORIGINAL, &[&loose]).unwrap();
+    assert!(out.starts_with("fn a() {}\n\nfn b()"));
+}
+
+#[test]
+fn test_missing_and_ambiguous_anchors_are_actionable() {
+    let missing = edit::apply(ORIGINAL, &[&block("fn c() {", "")]).unwrap_err();
+    assert!(missing.contains("SEARCH block 1 for x.rs was not found"));
+
+    let ambiguous = edit::apply(ORIGINAL, &[&block("    one();", "")]).unwrap_err();
+    assert!(ambiguous.contains("matches 2 places"));
+}
+
+#[test]
+fn test_validate_and_write_edits() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("x.rs"), ORIGINAL).unwrap();
+
+    let bad = payload("<<<<<<< SEARCH\n    one();\n=======\n    two();\n>>>>>>> REPLACE\n");
+    let outcome =
+        validator::validate_payload(&bad, Some(dir.path()), &TruncationSettings::default());
+    let ApplyOutcome::ValidationFailure { errors, .. } = outcome else {
+        panic!("ambiguous anchor should fail validation");
+    };
+    assert_eq!(errors.len(), 1);
+
+    let good = payload(
+        "<<<<<<< SEARCH\nfn b() {\n    one();\n=======\nfn b() {\n    two();\n>>>>>>> REPLACE\n",
+    );
+    let outcome =
+        validator::validate_payload(&good, Some(dir.path()), &TruncationSettings::default());
+    assert!(matches!(outcome, ApplyOutcome::Success { .. }));
+
+    let edits = extract_edits(&good).unwrap();
+    let written = writer::write_edits(&edits, Some(dir.path()), &WriteOptions::default()).unwrap();
+    assert_eq!(written, vec!["x.rs".to_string()]);
+    let content = fs::read_to_string(dir.path().join("x.rs")).unwrap();
+    assert!(content.ends_with("fn b() {\n    two();\n}\n"));
+}
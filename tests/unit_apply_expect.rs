@@ -0,0 +1,78 @@
+// tests/unit_apply_expect.rs
+use slopchop_core::apply::expect::{self, Expectation};
+use slopchop_core::apply::validator;
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const BODY: &str = "fn a() {}\nfn b() {}\n";
+
+fn payload(tags: &str) -> String {
+    format!(
+        "#__SLOPCHOP_MANIFEST__#\n- src/lib.rs [NEW] {tags}\n#__SLOPCHOP_END__#\n\
+         #__SLOPCHOP_FILE__# src/lib.rs\n{BODY}#__SLOPCHOP_END__#\n"
+    )
+}
+
+#[test]
+fn test_parse_tags() -> Result<()> {
+    let parsed = expect::parse(&payload("[lines:2] [SHA256:ABCDEF]"))?;
+    assert_eq!(
+        parsed["src/lib.rs"],
+        Expectation {
+            lines: Some(2),
+            sha256: Some("ABCDEF".to_string()),
+        }
+    );
+    assert!(expect::parse(&payload(""))?.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_sha256_hex() {
+    assert_eq!(
+        expect::sha256_hex("abc"),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+}
+
+#[test]
+fn test_matching_expectations_pass() -> Result<()> {
+    let d = tempdir()?;
+    let content = payload("[lines:2]");
+    let extracted = slopchop_core::apply::extractor::extract_changes(&content, None)?;
+    let sha = expect::sha256_hex(&extracted["src/lib.rs"].content);
+    let content = payload(&format!("[lines:2] [sha256:{}]", &sha[..10]));
+    assert!(validator::expectation_errors(&content, Some(d.path()), true).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_wrong_line_count_is_reported() -> Result<()> {
+    let d = tempdir()?;
+    fs::create_dir(d.path().join("src"))?;
+    let errors = validator::expectation_errors(&payload("[lines:40]"), Some(d.path()), true);
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("src/lib.rs has 2 lines but the manifest expects 40"));
+    Ok(())
+}
+
+#[test]
+fn test_line_counts_are_opt_in() {
+    assert!(validator::expectation_errors(&payload("[lines:40]"), None, false).is_empty());
+    assert_eq!(
+        validator::expectation_errors(&payload("[lines:40] [sha256:0000]"), None, false).len(),
+        1
+    );
+}
+
+#[test]
+fn test_wrong_hash_is_reported() {
+    let errors = validator::expectation_errors(&payload("[sha256:0000]"), None, false);
+    assert_eq!(errors.len(), 1);
+    assert!(
+        errors[0].contains("but the manifest expects 0000"),
+        "{errors:?}"
+    );
+}
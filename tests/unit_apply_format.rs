@@ -0,0 +1,83 @@
+// tests/unit_apply_format.rs
+use slopchop_core::apply::format;
+use slopchop_core::config::{Config, Preferences};
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn prefs(formatters: &[(&str, &str)]) -> Preferences {
+    let mut prefs = Preferences {
+        auto_format: true,
+        ..Preferences::default()
+    };
+    prefs.formatters = formatters
+        .iter()
+        .map(|(e, c)| ((*e).to_string(), (*c).to_string()))
+        .collect();
+    prefs
+}
+
+#[test]
+fn test_formats_files_by_extension() -> Result<()> {
+    let d = tempdir()?;
+    let txt = d.path().join("a.txt");
+    let md = d.path().join("b.md");
+    fs::write(&txt, "messy\n")?;
+    fs::write(&md, "messy\n")?;
+    let written = [txt.display().to_string(), md.display().to_string()];
+
+    let failures = format::format_files(&prefs(&[("txt", "sed -i s/messy/tidy/")]), &written);
+    assert!(failures.is_empty(), "{failures:?}");
+    assert_eq!(fs::read_to_string(&txt)?, "tidy\n");
+    assert_eq!(fs::read_to_string(&md)?, "messy\n");
+    Ok(())
+}
+
+#[test]
+fn test_diff_shows_what_formatting_changed() -> Result<()> {
+    let d = tempdir()?;
+    let txt = d.path().join("a.txt");
+    let md = d.path().join("b.md");
+    fs::write(&txt, "messy\n")?;
+    fs::write(&md, "messy\n")?;
+    let written = [txt.display().to_string(), md.display().to_string()];
+
+    let formatters = prefs(&[("txt", "sed -i s/messy/tidy/"), ("md", "true")]);
+    let (failures, diff) = format::format_with_diff(&formatters, &written);
+    assert!(failures.is_empty(), "{failures:?}");
+    assert!(diff.contains("a.txt") && diff.contains("tidy"), "{diff}");
+    assert!(!diff.contains("b.md"), "{diff}");
+    Ok(())
+}
+
+#[test]
+fn test_failures_are_reported_not_fatal() {
+    let written = ["a.rs".to_string(), "b.py".to_string()];
+    let failures = format::format_files(
+        &prefs(&[("rs", "false"), ("py", "no-such-formatter-xyz")]),
+        &written,
+    );
+    assert_eq!(failures.len(), 2, "{failures:?}");
+    assert!(failures
+        .iter()
+        .any(|f| f.starts_with("no-such-formatter-xyz:")));
+}
+
+#[test]
+fn test_off_unless_auto_format() {
+    let mut prefs = prefs(&[("rs", "false")]);
+    prefs.auto_format = false;
+    assert!(format::format_files(&prefs, &["a.rs".to_string()]).is_empty());
+}
+
+#[test]
+fn test_defaults_and_override_from_toml() {
+    let mut config = Config::new();
+    assert!(config.preferences.formatters.contains_key("py"));
+    config.parse_toml(
+        "[preferences]\nauto_format = true\n\n[preferences.formatters]\nrs = \"rustfmt\"\n",
+    );
+    assert!(config.preferences.auto_format);
+    assert_eq!(config.preferences.formatters.len(), 1);
+}
@@ -0,0 +1,41 @@
+// tests/unit_apply_git.rs
+use slopchop_core::apply::git;
+use slopchop_core::config::GitSettings;
+
+#[test]
+fn test_pr_tool_follows_remote_host() {
+    assert_eq!(git::pr_tool("git@github.com:me/repo.git"), "gh");
+    assert_eq!(git::pr_tool("https://gitlab.com/me/repo.git"), "glab");
+    assert_eq!(git::pr_tool(""), "gh");
+}
+
+#[test]
+fn test_pr_body_has_plan_and_folded_log() {
+    let body = git::pr_body("GOAL: tidy the parser", "test result: ok. 3 passed\n");
+    assert!(body.starts_with("tidy the parser\n\n<details>"));
+    assert!(body.contains("```\ntest result: ok. 3 passed\n```"));
+}
+
+#[test]
+fn test_pr_body_without_log_is_plan() {
+    assert_eq!(git::pr_body("tidy", "  \n"), "tidy");
+}
+
+#[test]
+fn test_commit_args_plain() {
+    let args = git::commit_args("msg", &GitSettings::default());
+    assert_eq!(args, ["commit", "-m", "msg"]);
+}
+
+#[test]
+fn test_commit_args_sign_and_signoff() {
+    let settings = GitSettings {
+        sign: true,
+        signoff: true,
+        ..GitSettings::default()
+    };
+    assert_eq!(
+        git::commit_args("msg", &settings),
+        ["commit", "-S", "--signoff", "-m", "msg"]
+    );
+}
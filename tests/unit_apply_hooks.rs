@@ -0,0 +1,44 @@
+// tests/unit_apply_hooks.rs
+use slopchop_core::apply::types::ApplyContext;
+use slopchop_core::apply::verification;
+use slopchop_core::config::Config;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn config(hooks: &[&str]) -> Config {
+    let mut config = Config::new();
+    config.hooks.post_apply = hooks.iter().map(ToString::to_string).collect();
+    config
+}
+
+#[test]
+fn test_no_hooks_passes() -> Result<()> {
+    let config = config(&[]);
+    let (ok, log) = verification::run_post_apply(&ApplyContext::new(&config))?;
+    assert!(ok);
+    assert!(log.is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_hooks_run_in_order_and_stop_at_failure() -> Result<()> {
+    let config = config(&["echo first", "false", "echo never"]);
+    let (ok, log) = verification::run_post_apply(&ApplyContext::new(&config))?;
+    assert!(!ok);
+    assert!(log.contains("> echo first\nfirst"), "{log}");
+    assert!(log.contains("> false"));
+    assert!(!log.contains("never"));
+    Ok(())
+}
+
+#[test]
+fn test_hung_command_times_out() -> Result<()> {
+    let mut config = config(&["sleep 30", "echo never"]);
+    config.preferences.verify_timeout = 1;
+    let started = std::time::Instant::now();
+    let (ok, log) = verification::run_post_apply(&ApplyContext::new(&config))?;
+    assert!(!ok);
+    assert!(log.contains("TIMED OUT after 1s"), "{log}");
+    assert!(started.elapsed().as_secs() < 10);
+    Ok(())
+}
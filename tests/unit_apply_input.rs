@@ -0,0 +1,63 @@
+// tests/unit_apply_input.rs
+use slopchop_core::apply;
+use slopchop_core::apply::input::{self, InputSource};
+use slopchop_core::apply::types::ApplyContext;
+use slopchop_core::config::Config;
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[test]
+fn test_reads_payload_from_file() -> Result<()> {
+    let d = tempdir()?;
+    let path = d.path().join("transcript.txt");
+    fs::write(&path, "#__SLOPCHOP_FILE__# a.rs\nfn a() {}\n")?;
+
+    let content = input::read(&InputSource::File(path))?;
+    assert!(content.starts_with("#__SLOPCHOP_FILE__# a.rs"));
+    Ok(())
+}
+
+#[test]
+fn test_missing_file_names_path() {
+    let err = input::read(&InputSource::File("no/such/payload.txt".into()));
+    let msg = format!("{:#}", err.err().map(|e| e.to_string()).unwrap_or_default());
+    assert!(msg.contains("no/such/payload.txt"), "{msg}");
+}
+
+#[test]
+fn test_gist_links_resolve_to_raw() {
+    assert_eq!(
+        input::raw_url("https://gist.github.com/me/abc123"),
+        "https://gist.github.com/me/abc123/raw"
+    );
+    assert_eq!(
+        input::raw_url("https://gist.github.com/me/abc123/raw"),
+        "https://gist.github.com/me/abc123/raw"
+    );
+    assert_eq!(
+        input::raw_url("https://example.com/payload.txt"),
+        "https://example.com/payload.txt"
+    );
+}
+
+#[test]
+fn test_stdin_payload_needs_force() {
+    let config = Config::new();
+    let mut ctx = ApplyContext::new(&config);
+    ctx.input = InputSource::Stdin;
+    assert!(!ctx.skips_confirm());
+    let err = apply::run_apply(&ctx).err().map(|e| e.to_string());
+    assert!(err.is_some_and(|e| e.contains("--force")));
+
+    ctx.force = true;
+    assert!(ctx.skips_confirm());
+}
+
+#[test]
+fn test_url_must_be_https() {
+    let err = input::read(&InputSource::Url("http://example.com/p.txt".into()));
+    let msg = err.err().map(|e| e.to_string()).unwrap_or_default();
+    assert!(msg.contains("only https://"), "{msg}");
+}
@@ -0,0 +1,72 @@
+// tests/unit_apply_ledger.rs
+use slopchop_core::apply::ledger::{self, ApplyRecord, Status};
+use slopchop_core::apply::types::ApplyOutcome;
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn success(written: &[&str], deleted: &[&str]) -> ApplyOutcome {
+    ApplyOutcome::Success {
+        written: written.iter().map(ToString::to_string).collect(),
+        deleted: deleted.iter().map(ToString::to_string).collect(),
+        roadmap_results: vec![],
+        backed_up: true,
+    }
+}
+
+#[test]
+fn test_record_captures_plan_and_files() {
+    let outcome = success(&["src/a.rs"], &["src/old.rs"]);
+    let record = ApplyRecord::new(Some("  GOAL: tidy\n"), &outcome, true);
+    assert_eq!(record.plan.as_deref(), Some("GOAL: tidy"));
+    assert_eq!(record.written, ["src/a.rs"]);
+    assert_eq!(record.deleted, ["src/old.rs"]);
+    assert_eq!(record.status, Status::Passed);
+    assert!(record.timestamp > 0);
+}
+
+#[test]
+fn test_record_captures_unwritten_outcomes() {
+    let rejected = ApplyOutcome::ValidationFailure {
+        errors: vec!["src/a.rs: truncated".to_string()],
+        missing: vec![],
+        ai_message: String::new(),
+    };
+    let record = ApplyRecord::new(None, &rejected, false);
+    assert_eq!(record.status, Status::Rejected);
+    assert_eq!(record.errors, ["src/a.rs: truncated"]);
+    assert!(record.written.is_empty());
+
+    let record = ApplyRecord::new(None, &ApplyOutcome::DryRun, false);
+    assert_eq!(record.status, Status::DryRun);
+    let record = ApplyRecord::new(None, &ApplyOutcome::Cancelled, false);
+    assert_eq!(record.status, Status::Cancelled);
+    assert!(record.errors.is_empty());
+    let failed = ApplyRecord::new(None, &success(&["a.rs"], &[]), false);
+    assert_eq!(failed.status, Status::Failed);
+}
+
+#[test]
+fn test_append_and_load_round_trip() -> Result<()> {
+    let d = tempdir()?;
+    let path = d.path().join("ledger.jsonl");
+    let mut first = ApplyRecord::new(None, &success(&["a.rs"], &[]), false);
+    first.commit = None;
+    let mut second = ApplyRecord::new(Some("GOAL: b"), &success(&["b.rs"], &[]), true);
+    second.commit = Some("0123456789abcdef".to_string());
+
+    first.append(&path)?;
+    second.append(&path)?;
+    fs::write(&path, format!("{}not json\n", fs::read_to_string(&path)?))?;
+
+    assert_eq!(ledger::load(&path)?, vec![first, second]);
+    Ok(())
+}
+
+#[test]
+fn test_missing_ledger_is_empty() -> Result<()> {
+    let d = tempdir()?;
+    assert!(ledger::load(&d.path().join("none.jsonl"))?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// tests/unit_apply_multi.rs
+use slopchop_core::apply::{manifest, multi};
+use std::collections::VecDeque;
+use std::io::Cursor;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+const PART_ONE: &str = "#__SLOPCHOP_PLAN__#\nGOAL: split\n#__SLOPCHOP_END__#\n\
+                        #__SLOPCHOP_MANIFEST__#\nsrc/a.rs\nsrc/b.rs [NEW]\n#__SLOPCHOP_END__#\n\
+                        #__SLOPCHOP_FILE__# src/a.rs\nfn a() {}\n#__SLOPCHOP_END__#\n";
+const PART_TWO: &str = "#__SLOPCHOP_MANIFEST__#\nsrc/b.rs [NEW]\nsrc/c.rs\n#__SLOPCHOP_END__#\n\
+                        #__SLOPCHOP_FILE__# src/b.rs\nfn b() {}\n#__SLOPCHOP_END__#\n\
+                        #__SLOPCHOP_DONE__#\n";
+
+fn clipboard(parts: &[&str]) -> impl FnMut() -> anyhow::Result<String> {
+    let mut queue: VecDeque<String> = parts.iter().map(ToString::to_string).collect();
+    move || Ok(queue.pop_front().unwrap_or_default())
+}
+
+#[test]
+fn test_merge_combines_manifests_once() -> Result<()> {
+    let merged = multi::merge(&[PART_ONE.to_string(), PART_TWO.to_string()]);
+    let entries = manifest::parse_manifest(&merged)?.unwrap_or_default();
+    let paths: Vec<_> = entries.iter().map(|e| e.path.as_str()).collect();
+    assert_eq!(paths, ["src/a.rs", "src/b.rs", "src/c.rs"]);
+    assert!(merged.contains("fn a() {}") && merged.contains("fn b() {}"));
+    assert!(!merged.contains(multi::DONE_MARKER));
+    Ok(())
+}
+
+#[test]
+fn test_read_parts_stops_at_done_marker() -> Result<()> {
+    let mut answers = Cursor::new("\n\n\n");
+    let merged = multi::read_parts(clipboard(&[PART_ONE, PART_TWO, "never"]), &mut answers)?;
+    assert!(merged.contains("fn b() {}"));
+    assert!(!merged.contains("never"));
+    Ok(())
+}
+
+#[test]
+fn test_read_parts_stops_on_done_answer_and_skips_repeats() -> Result<()> {
+    let mut answers = Cursor::new("\nd\n");
+    let merged = multi::read_parts(clipboard(&[PART_ONE, PART_ONE, "never"]), &mut answers)?;
+    assert_eq!(merged.matches("fn a() {}").count(), 1);
+    assert!(!merged.contains("never"));
+    Ok(())
+}
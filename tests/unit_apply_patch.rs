@@ -0,0 +1,61 @@
+// tests/unit_apply_patch.rs
+//! Tests for unified diff blocks in apply.
+
+use slopchop_core::apply::extractor::extract_changes;
+use slopchop_core::apply::patch::{apply, parse};
+use std::fs;
+use tempfile::TempDir;
+
+const ORIGINAL: &str = "fn a() {\n    one();\n}\n\nfn b() {\n    two();\n}\n";
+
+fn fence(diff: &str) -> String {
+    format!("{}diff\n{diff}{}\n", "`".repeat(3), "`".repeat(3))
+}
+
+#[test]
+fn test_apply_hunks_with_drifted_line_numbers() {
+    let diff = "--- a/src/x.rs\n+++ b/src/x.rs\n\
+                @@ -40,3 +40,3 @@\n fn a() {\n-    one();\n+    uno();\n }\n\
+                @@ -90,3 +90,4 @@\n fn b() {\n     two();\n+    three();\n }\n";
+    let patches = parse(diff).unwrap();
+    assert_eq!(patches.len(), 1);
+    assert_eq!(patches[0].path, "src/x.rs");
+    assert_eq!(patches[0].hunks.len(), 2);
+
+    let out = apply(ORIGINAL, &patches[0]).unwrap();
+    assert_eq!(
+        out,
+        "fn a() {\n    uno();\n}\n\nfn b() {\n    two();\n    three();\n}\n"
+    );
+}
+
+#[test]
+fn test_mismatched_context_is_an_error() {
+    let diff = "--- a/x.rs\n+++ b/x.rs\n@@ -1,2 +1,2 @@\n fn zzz() {\n-    one();\n+    uno();\n";
+    let patches = parse(diff).unwrap();
+    let err = apply(ORIGINAL, &patches[0]).unwrap_err();
+    assert!(err.to_string().contains("Hunk 1 does not match x.rs"));
+}
+
+#[test]
+fn test_extract_changes_resolves_diffs_against_disk() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("x.rs"), ORIGINAL).unwrap();
+    let edit = "--- a/x.rs\n+++ b/x.rs\n@@ -5,3 +5,3 @@\n fn b() {\n-    two();\n+    dos();\n }\n";
+    let new = "--- /dev/null\n+++ b/y.rs\n@@ -0,0 +1 @@\n+fn y() {}\n";
+    let response = format!("intro\n{}\n{}", fence(edit), fence(new));
+
+    let files = extract_changes(&response, Some(dir.path())).unwrap();
+    assert!(files["x.rs"].content.contains("    dos();\n"));
+    assert!(files["x.rs"].content.starts_with("fn a() {\n    one();"));
+    assert_eq!(files["y.rs"].content, "fn y() {}\n");
+}
+
+#[test]
+fn test_diff_fences_inside_file_blocks_are_content() {
+    let readme = format!("# Docs\n{}", fence("--- a/x.rs\n+++ b/x.rs\n"));
+    let response = format!("#__SLOPCHOP_FILE__# README.md\n{readme}#__SLOPCHOP_END__#\n");
+    let files = extract_changes(&response, None).unwrap();
+    assert_eq!(files.len(), 1);
+    assert!(files["README.md"].content.contains("+++ b/x.rs"));
+}
@@ -0,0 +1,68 @@
+// tests/unit_apply_preserve.rs
+use slopchop_core::apply::preserve::{self, WriteOptions};
+use slopchop_core::config::LineEndings;
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+#[test]
+fn test_convert_normalizes_line_endings() {
+    assert_eq!(preserve::convert("a\r\nb\n", true), "a\r\nb\r\n");
+    assert_eq!(preserve::convert("a\r\nb\n", false), "a\nb\n");
+    assert!(preserve::uses_crlf(b"a\r\nb"));
+    assert!(!preserve::uses_crlf(b"a\nb"));
+}
+
+#[test]
+fn test_rewrite_keeps_crlf() -> Result<()> {
+    let d = tempdir()?;
+    let path = d.path().join("a.txt");
+    fs::write(&path, "old\r\nfile\r\n")?;
+    preserve::write(&path, "a.txt", "new\nfile\n", &WriteOptions::default())?;
+    assert_eq!(fs::read_to_string(&path)?, "new\r\nfile\r\n");
+    Ok(())
+}
+
+#[test]
+fn test_override_forces_lf() -> Result<()> {
+    let d = tempdir()?;
+    let path = d.path().join("a.txt");
+    fs::write(&path, "old\r\n")?;
+    let opts = WriteOptions {
+        line_endings: LineEndings::Lf,
+        ..WriteOptions::default()
+    };
+    preserve::write(&path, "a.txt", "new\r\n", &opts)?;
+    assert_eq!(fs::read_to_string(&path)?, "new\n");
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_rewrite_keeps_executable_bit() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let d = tempdir()?;
+    let path = d.path().join("run.sh");
+    fs::write(&path, "echo old\n")?;
+    fs::set_permissions(&path, fs::Permissions::from_mode(0o755))?;
+    preserve::write(&path, "run.sh", "echo new\n", &WriteOptions::default())?;
+    assert_eq!(fs::metadata(&path)?.permissions().mode() & 0o777, 0o755);
+    Ok(())
+}
+
+#[cfg(unix)]
+#[test]
+fn test_executable_glob_marks_new_file() -> Result<()> {
+    use std::os::unix::fs::PermissionsExt;
+    let d = tempdir()?;
+    fs::create_dir(d.path().join("scripts"))?;
+    let path = d.path().join("scripts/go.sh");
+    let opts = WriteOptions {
+        executable: vec!["scripts/*.sh".into()],
+        ..WriteOptions::default()
+    };
+    preserve::write(&path, "scripts/go.sh", "echo hi\n", &opts)?;
+    assert_ne!(fs::metadata(&path)?.permissions().mode() & 0o111, 0);
+    Ok(())
+}
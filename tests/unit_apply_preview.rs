@@ -0,0 +1,60 @@
+// tests/unit_apply_preview.rs
+use slopchop_core::apply::preview;
+use slopchop_core::apply::types::{FileContent, ManifestEntry, Operation};
+use std::collections::HashMap;
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn entry(path: &str, operation: Operation) -> ManifestEntry {
+    ManifestEntry {
+        path: path.to_string(),
+        operation,
+    }
+}
+
+#[test]
+fn test_preview_marks_new_changed_and_deleted() -> Result<()> {
+    colored::control::set_override(false);
+    let d = tempdir()?;
+    let changed = d.path().join("a.rs").display().to_string();
+    let gone = d.path().join("gone.rs").display().to_string();
+    let new = d.path().join("new.rs").display().to_string();
+    fs::write(&changed, "fn a() {\n    old();\n}\n")?;
+    fs::write(&gone, "fn gone() {}\n")?;
+
+    let manifest = vec![
+        entry(&changed, Operation::Update),
+        entry(&gone, Operation::Delete),
+        entry(&new, Operation::New),
+    ];
+    let mut files = HashMap::new();
+    files.insert(
+        changed.clone(),
+        FileContent::new("fn a() {\n    new();\n}\n".into()),
+    );
+    files.insert(new.clone(), FileContent::new("fn fresh() {}\n".into()));
+
+    let out = preview::render(&manifest, &files, &[])?;
+    assert!(out.contains(&format!("CHANGE {changed}")), "{out}");
+    assert!(out.contains(" -     old();\n +     new();"), "{out}");
+    assert!(out.contains(&format!("DELETE {gone}")));
+    assert!(out.contains(" - fn gone() {}"));
+    assert!(out.contains(&format!("NEW FILE {new}")));
+    assert!(out.contains(" + fn fresh() {}"));
+    Ok(())
+}
+
+#[test]
+fn test_preview_does_not_write() -> Result<()> {
+    let d = tempdir()?;
+    let path = d.path().join("a.rs").display().to_string();
+    fs::write(&path, "fn a() {}\n")?;
+    let mut files = HashMap::new();
+    files.insert(path.clone(), FileContent::new("fn b() {}\n".into()));
+
+    preview::render(&[entry(&path, Operation::Update)], &files, &[])?;
+    assert_eq!(fs::read_to_string(&path)?, "fn a() {}\n");
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// tests/unit_apply_rename.rs
+use slopchop_core::apply::manifest;
+use slopchop_core::apply::types::{ApplyOutcome, FileContent, ManifestEntry, Operation};
+use slopchop_core::apply::{undo, validator, writer};
+use std::collections::HashMap;
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn rename(from: &str, to: &str) -> ManifestEntry {
+    ManifestEntry {
+        path: from.to_string(),
+        operation: Operation::Rename(to.to_string()),
+    }
+}
+
+#[test]
+fn test_parse_rename_forms() -> Result<()> {
+    let response = "#__SLOPCHOP_MANIFEST__#\n\
+                    src/a.rs -> src/b.rs [RENAME]\n\
+                    - [RENAME] src/c.rs -> src/d.rs\n\
+                    [rename src/e.rs -> src/f.rs]\n\
+                    #__SLOPCHOP_END__#";
+    let entries = manifest::parse_manifest(response)?.unwrap_or_default();
+    let parsed: Vec<_> = entries
+        .iter()
+        .map(|e| (e.path.as_str(), e.operation.clone()))
+        .collect();
+    assert_eq!(
+        parsed,
+        [
+            ("src/a.rs", Operation::Rename("src/b.rs".into())),
+            ("src/c.rs", Operation::Rename("src/d.rs".into())),
+            ("src/e.rs", Operation::Rename("src/f.rs".into())),
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_rename_keeps_content_and_undoes() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.rs"), "fn a() {}\n")?;
+
+    let manifest = vec![rename("a.rs", "src/b.rs")];
+    let outcome = writer::write_files(&manifest, &HashMap::new(), Some(d.path()))?;
+    let ApplyOutcome::Success {
+        written, deleted, ..
+    } = outcome
+    else {
+        return Err("rename failed".into());
+    };
+    assert_eq!(
+        (written, deleted),
+        (vec!["src/b.rs".into()], vec!["a.rs".into()])
+    );
+    assert!(!d.path().join("a.rs").exists());
+    assert_eq!(
+        fs::read_to_string(d.path().join("src/b.rs"))?,
+        "fn a() {}\n"
+    );
+
+    let sets = undo::list(d.path())?;
+    undo::restore(d.path(), &sets[0])?;
+    assert!(d.path().join("a.rs").exists());
+    assert!(!d.path().join("src/b.rs").exists());
+    Ok(())
+}
+
+#[test]
+fn test_rename_with_new_content() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.rs"), "fn a() {}\n")?;
+    let mut files = HashMap::new();
+    files.insert(
+        "b.rs".to_string(),
+        FileContent {
+            content: "fn b() {}\n".to_string(),
+            line_count: 1,
+        },
+    );
+    let manifest = vec![rename("a.rs", "b.rs")];
+
+    let check = validator::validate(&manifest, &files);
+    assert!(matches!(check, ApplyOutcome::Success { .. }), "{check:?}");
+    writer::write_files(&manifest, &files, Some(d.path()))?;
+    assert_eq!(fs::read_to_string(d.path().join("b.rs"))?, "fn b() {}\n");
+    Ok(())
+}
+
+#[test]
+fn test_rename_onto_existing_file_rolls_back() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.rs"), "fn a() {}\n")?;
+    fs::write(d.path().join("b.rs"), "fn b() {}\n")?;
+
+    let manifest = vec![rename("a.rs", "b.rs")];
+    let result = writer::write_files(&manifest, &HashMap::new(), Some(d.path()));
+    assert!(result.is_err());
+    assert_eq!(fs::read_to_string(d.path().join("a.rs"))?, "fn a() {}\n");
+    assert_eq!(fs::read_to_string(d.path().join("b.rs"))?, "fn b() {}\n");
+    Ok(())
+}
+
+#[test]
+fn test_rename_target_is_validated() {
+    let manifest = vec![rename("a.rs", "../escape.rs")];
+    let outcome = validator::validate(&manifest, &HashMap::new());
+    assert!(matches!(outcome, ApplyOutcome::ValidationFailure { .. }));
+}
@@ -0,0 +1,58 @@
+// tests/unit_apply_review.rs
+//! Tests for `apply --review`.
+
+use slopchop_core::apply::diff::{diff_lines, DiffLine};
+use slopchop_core::apply::review::review;
+use slopchop_core::apply::types::{FileContent, ManifestEntry, Operation};
+use std::collections::HashMap;
+use std::io::Cursor;
+
+#[test]
+fn test_diff_lines_marks_changes() {
+    let diff = diff_lines("a\nb\nc\n", "a\nB\nc\nd\n");
+    assert_eq!(
+        diff,
+        vec![
+            DiffLine::Same("a"),
+            DiffLine::Removed("b"),
+            DiffLine::Added("B"),
+            DiffLine::Same("c"),
+            DiffLine::Added("d"),
+        ]
+    );
+}
+
+fn payload() -> (Vec<ManifestEntry>, HashMap<String, FileContent>) {
+    let paths = ["review_test_missing/a.rs", "review_test_missing/b.rs"];
+    let manifest = paths
+        .iter()
+        .map(|p| ManifestEntry {
+            path: (*p).to_string(),
+            operation: Operation::New,
+        })
+        .collect();
+    let files = paths
+        .iter()
+        .map(|p| {
+            let content = FileContent {
+                content: "fn x() {}".into(),
+                line_count: 1,
+            };
+            ((*p).to_string(), content)
+        })
+        .collect();
+    (manifest, files)
+}
+
+#[test]
+fn test_review_skips_and_aborts() {
+    let (mut manifest, mut files) = payload();
+    let mut input = Cursor::new("?\ns\na\n");
+    assert!(review(&mut manifest, &mut files, &mut Vec::new(), &mut input).unwrap());
+    assert_eq!(manifest.len(), 1);
+    assert_eq!(manifest[0].path, "review_test_missing/b.rs");
+
+    let (mut manifest, mut files) = payload();
+    let mut input = Cursor::new("b\n");
+    assert!(!review(&mut manifest, &mut files, &mut Vec::new(), &mut input).unwrap());
+}
@@ -0,0 +1,99 @@
+// tests/unit_apply_scope.rs
+use slopchop_core::apply::scope;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn touched(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(ToString::to_string).collect()
+}
+
+fn write(root: &Path, rel: &str, content: &str) -> Result<()> {
+    let path = root.join(rel);
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(path, content)?;
+    Ok(())
+}
+
+fn workspace() -> Result<tempfile::TempDir> {
+    let d = tempdir()?;
+    let root = d.path();
+    write(
+        root,
+        "Cargo.toml",
+        "[workspace]\nmembers = [\"crates/*\"]\n",
+    )?;
+    write(
+        root,
+        "crates/core/Cargo.toml",
+        "[package]\nname = \"core\"\n",
+    )?;
+    write(
+        root,
+        "crates/cli/Cargo.toml",
+        "[package]\nname = \"my-cli\"\n",
+    )?;
+    write(root, "package.json", "{\"name\": \"monorepo\"}")?;
+    write(root, "web/app/package.json", "{\"name\": \"@acme/app\"}")?;
+    Ok(d)
+}
+
+#[test]
+fn test_cargo_scoped_to_owning_crates() -> Result<()> {
+    let d = workspace()?;
+    let files = touched(&[
+        "crates/core/src/lib.rs",
+        "crates/cli/src/main.rs",
+        "README.md",
+    ]);
+    assert_eq!(
+        scope::scope_command("cargo test --workspace", &files, d.path()),
+        "cargo test -p core -p my-cli"
+    );
+    assert_eq!(
+        scope::scope_command("cargo clippy -- -D warnings", &files, d.path()),
+        "cargo clippy -p core -p my-cli -- -D warnings"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_npm_scoped_to_workspace_package() -> Result<()> {
+    let d = workspace()?;
+    let files = touched(&["web/app/src/index.tsx"]);
+    assert_eq!(
+        scope::scope_command("npm test", &files, d.path()),
+        "npm test --workspace=@acme/app"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_file_outside_members_runs_everything() -> Result<()> {
+    let d = workspace()?;
+    let files = touched(&["crates/core/src/lib.rs", "build.rs"]);
+    assert_eq!(
+        scope::scope_command("cargo test", &files, d.path()),
+        "cargo test"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_other_commands_untouched() -> Result<()> {
+    let d = workspace()?;
+    let files = touched(&["crates/core/src/lib.rs"]);
+    assert_eq!(
+        scope::scope_command("make test", &files, d.path()),
+        "make test"
+    );
+    assert_eq!(
+        scope::scope_command("npm test", &files, d.path()),
+        "npm test"
+    );
+    Ok(())
+}
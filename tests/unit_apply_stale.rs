@@ -0,0 +1,65 @@
+// tests/unit_apply_stale.rs
+use slopchop_core::apply::{extractor, manifest, validator};
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn payload(base: &str) -> String {
+    format!(
+        "#__SLOPCHOP_MANIFEST__#\n- src/a.rs [base:{base}]\nsrc/b.rs [NEW]\n#__SLOPCHOP_END__#\n\
+         #__SLOPCHOP_FILE__# src/a.rs [base:{base}]\nfn a() {{}}\n#__SLOPCHOP_END__#\n"
+    )
+}
+
+#[test]
+fn test_split_base() {
+    assert_eq!(
+        manifest::split_base("src/a.rs [base:1a2b3c4d]"),
+        ("src/a.rs", Some("1a2b3c4d"))
+    );
+    assert_eq!(manifest::split_base(" src/a.rs "), ("src/a.rs", None));
+}
+
+#[test]
+fn test_parse_bases_reads_tagged_lines_only() -> Result<()> {
+    let bases = manifest::parse_bases(&payload("deadbeef"))?;
+    assert_eq!(bases.len(), 1);
+    assert_eq!(bases.get("src/a.rs").map(String::as_str), Some("deadbeef"));
+    Ok(())
+}
+
+#[test]
+fn test_file_header_base_tag_is_not_part_of_path() -> Result<()> {
+    let files = extractor::extract_files(&payload("deadbeef"))?;
+    assert!(files.contains_key("src/a.rs"));
+    Ok(())
+}
+
+#[test]
+fn test_unchanged_file_is_not_stale() -> Result<()> {
+    let d = tempdir()?;
+    fs::create_dir_all(d.path().join("src"))?;
+    fs::write(d.path().join("src/a.rs"), "fn a() { old() }\n")?;
+    let base = manifest::base_hash("fn a() { old() }\n");
+
+    assert!(validator::stale_files(&payload(&base), Some(d.path())).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_changed_or_removed_file_is_stale() -> Result<()> {
+    let d = tempdir()?;
+    fs::create_dir_all(d.path().join("src"))?;
+    fs::write(d.path().join("src/a.rs"), "fn a() { edited_by_human() }\n")?;
+    let base = manifest::base_hash("fn a() { old() }\n");
+
+    let stale = validator::stale_files(&payload(&base), Some(d.path()));
+    assert_eq!(stale.len(), 1);
+    assert!(stale[0].contains("src/a.rs changed since it was packed"));
+
+    fs::remove_file(d.path().join("src/a.rs"))?;
+    let stale = validator::stale_files(&payload(&base), Some(d.path()));
+    assert!(stale[0].contains("removed"));
+    Ok(())
+}
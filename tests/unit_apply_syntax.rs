@@ -0,0 +1,61 @@
+// tests/unit_apply_syntax.rs
+use slopchop_core::apply::validator;
+use slopchop_core::config::{CheckMode, Config};
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn file_payload(path: &str, body: &str) -> String {
+    format!(
+        "#__SLOPCHOP_MANIFEST__#\n{path}\n#__SLOPCHOP_END__#\n\
+         #__SLOPCHOP_FILE__# {path}\n{body}\n#__SLOPCHOP_END__#\n"
+    )
+}
+
+#[test]
+fn test_broken_file_is_reported_with_line() -> Result<()> {
+    let d = tempdir()?;
+    let payload = file_payload("src/a.rs", "fn a() {\n    let x = 1;\n");
+    let errors = validator::syntax_errors(&payload, Some(d.path()));
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("src/a.rs:"), "{errors:?}");
+    Ok(())
+}
+
+#[test]
+fn test_clean_and_unsupported_files_pass() {
+    let rust = file_payload("src/a.rs", "fn a() {}");
+    assert!(validator::syntax_errors(&rust, None).is_empty());
+    let text = file_payload("notes.md", "fn a( {");
+    assert!(validator::syntax_errors(&text, None).is_empty());
+}
+
+#[test]
+fn test_edit_result_is_parsed() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.py"), "def a():\n    return 1\n")?;
+    let payload = "#__SLOPCHOP_MANIFEST__#\na.py\n#__SLOPCHOP_END__#\n\
+                   #__SLOPCHOP_EDIT__# a.py\n<<<<<<< SEARCH\n    return 1\n=======\n    return (1\n\
+                   >>>>>>> REPLACE\n#__SLOPCHOP_END__#\n";
+    let errors = validator::syntax_errors(payload, Some(d.path()));
+    assert_eq!(errors.len(), 1, "{errors:?}");
+    Ok(())
+}
+
+#[test]
+fn test_file_already_broken_on_disk_is_not_blamed() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.rs"), "fn a( {")?;
+    let payload = file_payload("a.rs", "fn a( { 1");
+    assert!(validator::syntax_errors(&payload, Some(d.path())).is_empty());
+    Ok(())
+}
+
+#[test]
+fn test_syntax_check_mode_from_toml() {
+    let mut config = Config::new();
+    assert_eq!(config.preferences.syntax_check, CheckMode::Deny);
+    config.parse_toml("[preferences]\nsyntax_check = \"warn\"\n");
+    assert_eq!(config.preferences.syntax_check, CheckMode::Warn);
+}
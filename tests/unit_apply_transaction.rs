@@ -0,0 +1,97 @@
+// tests/unit_apply_transaction.rs
+use slopchop_core::apply::edit::EditBlock;
+use slopchop_core::apply::preserve::WriteOptions;
+use slopchop_core::apply::syntax;
+use slopchop_core::apply::types::{ApplyOutcome, FileContent, ManifestEntry, Operation};
+use slopchop_core::apply::{undo, writer};
+use std::collections::HashMap;
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn entry(path: &str, operation: Operation) -> ManifestEntry {
+    ManifestEntry {
+        path: path.to_string(),
+        operation,
+    }
+}
+
+fn files(list: &[(&str, &str)]) -> HashMap<String, FileContent> {
+    list.iter()
+        .map(|(p, c)| {
+            let content = FileContent {
+                content: (*c).to_string(),
+                line_count: c.lines().count(),
+            };
+            ((*p).to_string(), content)
+        })
+        .collect()
+}
+
+#[test]
+fn test_syntax_error_rolls_back_whole_batch() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.rs"), "fn a() {}\n")?;
+    fs::write(d.path().join("gone.rs"), "fn gone() {}\n")?;
+
+    let manifest = vec![
+        entry("a.rs", Operation::Update),
+        entry("b.rs", Operation::New),
+        entry("gone.rs", Operation::Delete),
+    ];
+    let payload = files(&[("a.rs", "fn a() { 1 }\n"), ("b.rs", "fn b( {\n")]);
+    let outcome = writer::write_files(&manifest, &payload, Some(d.path()))?;
+
+    let ApplyOutcome::ValidationFailure { errors, .. } = outcome else {
+        return Err("expected a validation failure".into());
+    };
+    assert_eq!(errors.len(), 1);
+    assert!(errors[0].starts_with("b.rs:1: syntax error"), "{errors:?}");
+    assert_eq!(fs::read_to_string(d.path().join("a.rs"))?, "fn a() {}\n");
+    assert!(d.path().join("gone.rs").exists());
+    assert!(!d.path().join("b.rs").exists());
+    assert!(
+        undo::list(d.path())?.is_empty(),
+        "rolled-back set is dropped"
+    );
+    Ok(())
+}
+
+#[test]
+fn test_failed_edit_rolls_back_written_files() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.rs"), "fn a() {}\n")?;
+    fs::write(d.path().join("c.rs"), "fn c() {}\n")?;
+
+    let manifest = vec![
+        entry("a.rs", Operation::Update),
+        entry("c.rs", Operation::Update),
+    ];
+    let edits = [EditBlock {
+        path: "c.rs".to_string(),
+        search: "fn missing() {}".to_string(),
+        replace: "fn c2() {}".to_string(),
+    }];
+    let payload = files(&[("a.rs", "fn a() { 2 }\n")]);
+    let opts = WriteOptions {
+        check_syntax: true,
+        ..WriteOptions::default()
+    };
+    let result = writer::write_all(&manifest, &payload, &edits, Some(d.path()), &opts);
+
+    let err = result.err().map(|e| format!("{e:#}")).unwrap_or_default();
+    assert!(err.contains("rolled back"), "{err}");
+    assert_eq!(fs::read_to_string(d.path().join("a.rs"))?, "fn a() {}\n");
+    Ok(())
+}
+
+#[test]
+fn test_existing_syntax_error_is_not_blamed_on_apply() {
+    let broken = "fn a( {\n";
+    assert!(syntax::first_error("a.rs", broken).is_some());
+    assert!(syntax::introduced_error("a.rs", broken, Some(broken)).is_none());
+    assert_eq!(syntax::introduced_error("a.rs", broken, None), Some(1));
+    assert!(syntax::first_error("notes.txt", broken).is_none());
+    assert!(syntax::first_error("view.tsx", "const v = <div>{x}</div>;\n").is_none());
+}
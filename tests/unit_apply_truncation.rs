@@ -0,0 +1,75 @@
+// tests/unit_apply_truncation.rs
+use slopchop_core::apply::truncation;
+use slopchop_core::apply::types::ApplyOutcome;
+use slopchop_core::apply::validator;
+use slopchop_core::config::TruncationSettings;
+use std::fmt::Write as _;
+use std::fs;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn settings(add: &[&str], remove: &[&str], skip: &[&str]) -> TruncationSettings {
+    let strings = |v: &[&str]| v.iter().map(ToString::to_string).collect();
+    TruncationSettings {
+        add: strings(add),
+        remove: strings(remove),
+        skip_langs: strings(skip),
+        strict: false,
+        line_counts: false,
+    }
+}
+
+#[test]
+fn test_defaults_flag_lazy_markers() {
+    let content = "fn a() {}\n// rest of the file unchanged\n";
+    let found = truncation::detect("src/a.rs", content, &TruncationSettings::default());
+    assert_eq!(found, Some(2));
+}
+
+#[test]
+fn test_add_and_remove_patterns() {
+    let s = settings(&["// snip"], &["# ..."], &[]);
+    assert_eq!(truncation::detect("a.rs", "x\n// snip\n", &s), Some(2));
+    assert_eq!(truncation::detect("a.py", "x = [\n# ...\n]\n", &s), None);
+}
+
+#[test]
+fn test_skipped_languages_are_not_checked() {
+    let s = settings(&[], &[], &["md"]);
+    assert_eq!(truncation::detect("notes.md", "# ...\n", &s), None);
+    assert_eq!(truncation::detect("a.py", "# ...\n", &s), Some(1));
+}
+
+#[test]
+fn test_strict_flags_short_replacement_of_long_file() -> Result<()> {
+    let d = tempdir()?;
+    let long = (0..80).fold(String::new(), |mut out, i| {
+        let _ = writeln!(out, "fn f{i}() {{}}");
+        out
+    });
+    fs::write(d.path().join("lib.rs"), &long)?;
+    let payload = "#__SLOPCHOP_MANIFEST__#\nlib.rs\n#__SLOPCHOP_END__#\n\
+                   #__SLOPCHOP_FILE__# lib.rs\nfn f0() {}\nfn f1() {}\n#__SLOPCHOP_END__#\n";
+
+    let lenient =
+        validator::validate_payload(payload, Some(d.path()), &TruncationSettings::default());
+    assert!(
+        matches!(lenient, ApplyOutcome::Success { .. }),
+        "{lenient:?}"
+    );
+
+    let strict = TruncationSettings {
+        strict: true,
+        ..TruncationSettings::default()
+    };
+    let outcome = validator::validate_payload(payload, Some(d.path()), &strict);
+    let ApplyOutcome::ValidationFailure { errors, .. } = outcome else {
+        return Err("strict mode should reject the short replacement".into());
+    };
+    assert!(
+        errors[0].contains("Suspiciously short replacement of lib.rs: 2 lines"),
+        "{errors:?}"
+    );
+    Ok(())
+}
@@ -0,0 +1,130 @@
+// tests/unit_apply_undo.rs
+use slopchop_core::apply::types::{FileContent, ManifestEntry, Operation};
+use slopchop_core::apply::{undo, writer};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
+
+fn entry(path: &str, operation: Operation) -> ManifestEntry {
+    ManifestEntry {
+        path: path.to_string(),
+        operation,
+    }
+}
+
+fn content(text: &str) -> FileContent {
+    FileContent {
+        content: text.to_string(),
+        line_count: text.lines().count(),
+    }
+}
+
+fn apply(root: &Path, manifest: &[ManifestEntry], files: &[(&str, &str)]) -> Result<()> {
+    let files: HashMap<_, _> = files
+        .iter()
+        .map(|(p, c)| ((*p).to_string(), content(c)))
+        .collect();
+    writer::write_files(&manifest.to_vec(), &files, Some(root))?;
+    Ok(())
+}
+
+#[test]
+fn test_undo_restores_updates_and_deletes_and_removes_new_files() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.rs"), "fn old_a() {}")?;
+    fs::write(d.path().join("gone.rs"), "fn gone() {}")?;
+
+    let manifest = [
+        entry("a.rs", Operation::Update),
+        entry("gone.rs", Operation::Delete),
+        entry("src/new.rs", Operation::New),
+    ];
+    apply(
+        d.path(),
+        &manifest,
+        &[("a.rs", "fn new_a() {}"), ("src/new.rs", "fn new() {}")],
+    )?;
+    assert!(!d.path().join("gone.rs").exists());
+
+    let sets = undo::list(d.path())?;
+    assert_eq!(sets.len(), 1);
+    undo::restore(d.path(), &sets[0])?;
+
+    assert_eq!(fs::read_to_string(d.path().join("a.rs"))?, "fn old_a() {}");
+    assert_eq!(
+        fs::read_to_string(d.path().join("gone.rs"))?,
+        "fn gone() {}"
+    );
+    assert!(!d.path().join("src/new.rs").exists());
+    assert!(undo::list(d.path())?.is_empty(), "restored set is removed");
+    Ok(())
+}
+
+#[test]
+fn test_list_is_newest_first_and_reads_commit() -> Result<()> {
+    let d = tempdir()?;
+    let backups = d.path().join(".slopchop_apply_backup");
+    for id in ["100", "300", "200"] {
+        fs::create_dir_all(backups.join(id))?;
+    }
+    undo::record_commit(d.path(), "0123456789abcdef")?;
+
+    let sets = undo::list(d.path())?;
+    let ids: Vec<_> = sets.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(ids, ["300", "200", "100"]);
+    assert_eq!(sets[0].record.commit.as_deref(), Some("0123456789abcdef"));
+    assert_eq!(sets[1].record.commit, None);
+    Ok(())
+}
+
+#[test]
+fn test_back_to_back_applies_get_their_own_sets() -> Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("a.rs"), "fn v1() {}")?;
+    let manifest = [entry("a.rs", Operation::Update)];
+    apply(d.path(), &manifest, &[("a.rs", "fn v2() {}")])?;
+    apply(d.path(), &manifest, &[("a.rs", "fn v3() {}")])?;
+
+    let sets = undo::list(d.path())?;
+    assert_eq!(sets.len(), 2, "second apply must not reuse the first set");
+    undo::restore(d.path(), &sets[0])?;
+    assert_eq!(fs::read_to_string(d.path().join("a.rs"))?, "fn v2() {}");
+    undo::restore(d.path(), &undo::list(d.path())?[0])?;
+    assert_eq!(fs::read_to_string(d.path().join("a.rs"))?, "fn v1() {}");
+    Ok(())
+}
+
+#[test]
+fn test_list_orders_pid_suffixed_ids_by_time() -> Result<()> {
+    let d = tempdir()?;
+    let backups = d.path().join(".slopchop_apply_backup");
+    for id in [
+        "1700000000",
+        "1700000000000000002-9",
+        "1700000000000000001-42",
+    ] {
+        fs::create_dir_all(backups.join(id))?;
+    }
+    let sets = undo::list(d.path())?;
+    let ids: Vec<_> = sets.iter().map(|s| s.id.as_str()).collect();
+    assert_eq!(
+        ids,
+        [
+            "1700000000000000002-9",
+            "1700000000000000001-42",
+            "1700000000"
+        ]
+    );
+    Ok(())
+}
+
+#[test]
+fn test_empty_manifest_makes_no_set() -> Result<()> {
+    let d = tempdir()?;
+    apply(d.path(), &[], &[])?;
+    assert!(undo::list(d.path())?.is_empty());
+    Ok(())
+}
@@ -0,0 +1,80 @@
+// tests/unit_apply_verify.rs
+use slopchop_core::apply::verification;
+use slopchop_core::config::Config;
+
+const PIPELINES: &str = r#"
+[commands]
+check = ["make all"]
+
+[verify.rust]
+commands = ["cargo check", "cargo test"]
+
+[verify.node]
+commands = ["npm test"]
+
+[verify.docs]
+extensions = ["md"]
+commands = ["mdbook build", "cargo test"]
+"#;
+
+fn touched(paths: &[&str]) -> Vec<String> {
+    paths.iter().map(ToString::to_string).collect()
+}
+
+/// The command lines `check_commands` picks.
+fn check_commands(config: &Config, paths: &[&str]) -> Vec<String> {
+    verification::check_commands(config, &touched(paths))
+        .into_iter()
+        .map(|step| step.run)
+        .collect()
+}
+
+fn config(toml: &str) -> Config {
+    let mut config = Config::new();
+    config.parse_toml(toml);
+    config
+}
+
+#[test]
+fn test_without_pipelines_uses_check_commands() {
+    let config = config("[commands]\ncheck = [\"make all\"]\n");
+    let commands = check_commands(&config, &["src/a.rs"]);
+    assert_eq!(commands, ["make all"]);
+}
+
+#[test]
+fn test_runs_only_pipelines_for_touched_languages() {
+    let config = config(PIPELINES);
+    let commands = check_commands(&config, &["src/a.rs"]);
+    assert_eq!(commands, ["cargo check", "cargo test"]);
+
+    let commands = check_commands(&config, &["web/app.tsx"]);
+    assert_eq!(commands, ["npm test"]);
+}
+
+#[test]
+fn test_explicit_extensions_and_shared_commands_run_once() {
+    let config = config(PIPELINES);
+    let commands = check_commands(&config, &["README.md", "src/a.rs"]);
+    assert_eq!(commands, ["mdbook build", "cargo test", "cargo check"]);
+}
+
+#[test]
+fn test_manifests_run_their_language_pipeline() {
+    let pipelines = config(PIPELINES);
+    let commands = check_commands(&pipelines, &["Cargo.toml"]);
+    assert_eq!(commands, ["cargo check", "cargo test"]);
+    let commands = check_commands(&pipelines, &["web/package.json"]);
+    assert_eq!(commands, ["npm test"]);
+
+    let web = config(
+        "[verify.node-web]\ncwd = \"web\"\nextensions = [\"ts\"]\ncommands = [\"npm test\"]\n",
+    );
+    assert_eq!(check_commands(&web, &["web/package.json"]), ["npm test"]);
+}
+
+#[test]
+fn test_untouched_languages_run_nothing() {
+    let config = config(PIPELINES);
+    assert!(check_commands(&config, &["notes.txt"]).is_empty());
+}
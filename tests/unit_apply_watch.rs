@@ -0,0 +1,49 @@
+// tests/unit_apply_watch.rs
+use slopchop_core::apply::watch::{is_payload, Watcher};
+use slopchop_core::config::RuleConfig;
+use slopchop_core::prompt::PromptGenerator;
+
+const PAYLOAD: &str =
+    "#__SLOPCHOP_MANIFEST__#\na.rs\n#__SLOPCHOP_END__#\n#__SLOPCHOP_FILE__# a.rs\n";
+
+#[test]
+fn test_detects_payload_markers() {
+    assert!(is_payload(PAYLOAD));
+    assert!(is_payload("#__SLOPCHOP_PLAN__#\nGOAL: x\n"));
+    assert!(!is_payload("#__SLOPCHOP_FILE__# src/lib.rs\n"));
+    assert!(!is_payload("just some copied prose"));
+}
+
+#[test]
+fn test_ignores_prompts_and_packs() -> anyhow::Result<()> {
+    let prompt = PromptGenerator::new(RuleConfig::default()).generate()?;
+    assert!(!is_payload(&prompt));
+    assert!(!is_payload(&format!(
+        "{prompt}\n#__SLOPCHOP_FILE__# a.rs\n"
+    )));
+    Ok(())
+}
+
+#[test]
+fn test_ignores_payload_present_at_start() {
+    let payload = PAYLOAD.to_string();
+    let mut watcher = Watcher::new(payload.clone());
+    assert_eq!(watcher.next(payload), None);
+}
+
+#[test]
+fn test_skipped_content_is_not_yielded() {
+    let mut watcher = Watcher::new(String::new());
+    watcher.skip(PAYLOAD.to_string());
+    assert_eq!(watcher.next(PAYLOAD.to_string()), None);
+}
+
+#[test]
+fn test_yields_each_new_payload_once() {
+    let mut watcher = Watcher::new(String::new());
+    let payload = PAYLOAD.to_string();
+    assert_eq!(watcher.next(payload.clone()), Some(payload.clone()));
+    assert_eq!(watcher.next(payload.clone()), None);
+    assert_eq!(watcher.next("notes".to_string()), None);
+    assert_eq!(watcher.next(payload.clone()), Some(payload));
+}
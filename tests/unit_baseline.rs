@@ -0,0 +1,50 @@
+// tests/unit_baseline.rs
+//! Tests for violation baselines.
+
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::baseline::Baseline;
+use slopchop_core::config::Config;
+use std::fs;
+use tempfile::TempDir;
+
+fn engine() -> RuleEngine {
+    let mut config = Config::default();
+    config.rules.functions.max_cyclomatic_complexity = 1;
+    RuleEngine::new(config)
+}
+
+#[test]
+fn test_baseline_only_fails_on_new_violations() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    fs::write(&path, "fn old() { if true {} }\n").unwrap();
+
+    let first = engine().scan(vec![path.clone()]);
+    assert!(first.has_errors());
+    let baseline = Baseline::from_report(&first);
+
+    let file = dir.path().join("baseline.json");
+    baseline.save(&file).unwrap();
+    let loaded = Baseline::load(&file).unwrap().unwrap();
+
+    // Shift the old function down and add a new offender.
+    fs::write(
+        &path,
+        "fn new() { if true {} }\n\nfn old() { if false {} }\n",
+    )
+    .unwrap();
+    let mut report = engine().scan(vec![path]);
+    loaded.apply(&mut report);
+
+    assert_eq!(report.baselined_count(), 1);
+    assert_eq!(report.total_violations, 1);
+    assert!(report.has_errors(), "The new violation must still fail");
+}
+
+#[test]
+fn test_missing_baseline_is_none() {
+    let dir = TempDir::new().unwrap();
+    assert!(Baseline::load(&dir.path().join("nope.json"))
+        .unwrap()
+        .is_none());
+}
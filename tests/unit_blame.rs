@@ -0,0 +1,29 @@
+// tests/unit_blame.rs
+//! Tests for `git blame` attribution.
+
+use slopchop_core::blame::parse_porcelain;
+
+const SHA_A: &str = "0123456789abcdef0123456789abcdef01234567";
+const SHA_B: &str = "fedcba9876543210fedcba9876543210fedcba98";
+
+#[test]
+fn test_parse_line_porcelain() {
+    let text = format!(
+        "{SHA_A} 3 5 1\nauthor Ada Lovelace\nauthor-mail <ada@example.com>\n\
+         author-time 1700000000\nauthor-tz +0000\nsummary first\nfilename a.rs\n\
+         \tSome(1).unwrap();\n\
+         {SHA_B} 9 12 1\nauthor Grace Hopper\nauthor-time 951782400\n\
+         summary second\nfilename a.rs\n\tx.expect(\"y\");\n"
+    );
+    let blame = parse_porcelain(&text);
+    assert_eq!(blame.len(), 2);
+
+    let a = &blame[&4];
+    assert_eq!(a.commit, "01234567");
+    assert_eq!(a.author, "Ada Lovelace");
+    assert_eq!(a.date, "2023-11-14");
+
+    let b = &blame[&11];
+    assert_eq!(b.author, "Grace Hopper");
+    assert_eq!(b.date, "2000-02-29");
+}
@@ -0,0 +1,61 @@
+// tests/unit_cache.rs
+//! Tests for the incremental scan cache.
+
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::Config;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn engine(cache: &Path, max_complexity: usize) -> RuleEngine {
+    let mut config = Config::default();
+    config.rules.functions.max_cyclomatic_complexity = max_complexity;
+    RuleEngine::new(config).with_cache(cache)
+}
+
+/// Rewrites every cached token count so a cache hit is observable.
+fn tamper(cache: &Path) {
+    let file = cache.join("scan.json");
+    let mut json: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(&file).unwrap()).unwrap();
+    for entry in json["files"].as_object_mut().unwrap().values_mut() {
+        entry["report"]["token_count"] = 424_242.into();
+    }
+    fs::write(&file, json.to_string()).unwrap();
+}
+
+#[test]
+fn test_cache_reuses_unchanged_files() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.path().join("cache");
+    let path = dir.path().join("a.rs");
+    fs::write(&path, "fn f() { if true {} }\n").unwrap();
+
+    let first = engine(&cache, 1).scan(vec![path.clone()]);
+    assert_eq!(first.total_violations, 1);
+    assert!(cache.join(".gitignore").exists());
+
+    tamper(&cache);
+    let second = engine(&cache, 1).scan(vec![path.clone()]);
+    assert_eq!(second.total_tokens, 424_242, "Unchanged file should hit");
+    assert_eq!(second.files[0].violations[0].law, "LAW OF COMPLEXITY");
+
+    fs::write(&path, "fn f() {}\n").unwrap();
+    let edited = engine(&cache, 1).scan(vec![path]);
+    assert_ne!(edited.total_tokens, 424_242, "Edited file should miss");
+    assert_eq!(edited.total_violations, 0);
+}
+
+#[test]
+fn test_config_change_invalidates_cache() {
+    let dir = TempDir::new().unwrap();
+    let cache = dir.path().join("cache");
+    let path = dir.path().join("a.rs");
+    fs::write(&path, "fn f() { if true {} }\n").unwrap();
+
+    let _ = engine(&cache, 1).scan(vec![path.clone()]);
+    tamper(&cache);
+    let report = engine(&cache, 10).scan(vec![path]);
+    assert_ne!(report.total_tokens, 424_242);
+    assert_eq!(report.total_violations, 0);
+}
@@ -0,0 +1,53 @@
+// tests/unit_changed_files.rs
+//! Tests for git-changed file discovery. Kept in its own binary because it
+//! changes the working directory.
+
+use slopchop_core::discovery::changed_files;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+fn set(names: &[&str]) -> HashSet<PathBuf> {
+    names.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn test_changed_files_and_since() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    git(root, &["init", "-q"]);
+    fs::write(root.join("a.rs"), "fn a() {}\n").unwrap();
+    fs::write(root.join("b.rs"), "fn b() {}\n").unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-qm", "base"]);
+
+    fs::write(root.join("a.rs"), "fn a() { }\n").unwrap();
+    fs::write(root.join("c.rs"), "fn c() {}\n").unwrap();
+    fs::write(root.join("d.rs"), "fn d() {}\n").unwrap();
+    git(root, &["add", "d.rs"]);
+    std::env::set_current_dir(root).unwrap();
+
+    assert_eq!(changed_files(None).unwrap(), set(&["a.rs", "c.rs", "d.rs"]));
+
+    git(root, &["add", "."]);
+    git(root, &["commit", "-qm", "work"]);
+    assert!(changed_files(None).unwrap().is_empty());
+    assert_eq!(
+        changed_files(Some("HEAD~1")).unwrap(),
+        set(&["a.rs", "c.rs", "d.rs"])
+    );
+    assert!(changed_files(Some("no-such-ref")).is_err());
+}
@@ -0,0 +1,74 @@
+// tests/unit_clipboard_backend.rs
+use slopchop_core::clipboard::backend::{self, Copied, Failure, Session};
+use slopchop_core::config::{ClipboardBackend, Config};
+
+#[test]
+fn test_default_chain_prefers_session_display() {
+    use ClipboardBackend::{WlCopy, Xclip, Xsel};
+    assert_eq!(backend::default_chain(true), vec![WlCopy, Xclip, Xsel]);
+    assert_eq!(backend::default_chain(false), vec![Xclip, Xsel, WlCopy]);
+}
+
+#[test]
+fn test_explicit_backends_win() {
+    let chain = [ClipboardBackend::Xsel, ClipboardBackend::Osc52];
+    assert_eq!(backend::configured(&chain), chain.to_vec());
+}
+
+#[test]
+fn test_unavailable_without_display() {
+    let x11 = Session {
+        x11: true,
+        ..Session::default()
+    };
+    let wayland = Session {
+        wayland: true,
+        ..Session::default()
+    };
+    assert!(x11.unavailable(ClipboardBackend::WlCopy).is_some());
+    assert!(wayland.unavailable(ClipboardBackend::WlCopy).is_none());
+    assert!(wayland.unavailable(ClipboardBackend::Xclip).is_some());
+    assert!(x11.unavailable(ClipboardBackend::Xsel).is_none());
+    assert!(Session::default()
+        .unavailable(ClipboardBackend::Osc52)
+        .is_none());
+}
+
+#[test]
+fn test_tmux_needs_tmux_session() {
+    let tmux = Session {
+        tmux: true,
+        ..Session::default()
+    };
+    assert!(Session::default()
+        .unavailable(ClipboardBackend::Tmux)
+        .is_some());
+    assert!(tmux.unavailable(ClipboardBackend::Tmux).is_none());
+}
+
+#[test]
+fn test_describe_names_backend_and_failures() {
+    let copied = Copied {
+        backend: ClipboardBackend::Xclip,
+        failures: vec![Failure {
+            backend: ClipboardBackend::WlCopy,
+            reason: "not installed".to_string(),
+        }],
+    };
+    assert_eq!(copied.describe(), "via xclip (wl-copy: not installed)");
+}
+
+#[test]
+fn test_parse_backends() {
+    let mut config = Config::default();
+    config.parse_toml("[clipboard]\nbackends = [\"wl-copy\", \"tmux\", \"osc52\"]\ntmux = true\n");
+    assert_eq!(
+        config.clipboard.backends,
+        vec![
+            ClipboardBackend::WlCopy,
+            ClipboardBackend::Tmux,
+            ClipboardBackend::Osc52
+        ]
+    );
+    assert!(config.clipboard.tmux);
+}
@@ -0,0 +1,67 @@
+// tests/unit_clipboard_history.rs
+use slopchop_core::clipboard::history::{self, HISTORY_DIR};
+use slopchop_core::config::{ClipboardSettings, Config};
+use tempfile::TempDir;
+
+#[test]
+fn test_save_and_list_newest_first() {
+    let dir = TempDir::new().unwrap();
+    history::save(dir.path(), "pack", "first", Some("abc12345"), 5).unwrap();
+    std::thread::sleep(std::time::Duration::from_millis(2));
+    history::save(dir.path(), "prompt", "second", None, 5).unwrap();
+
+    let entries = history::list(dir.path()).unwrap();
+    assert_eq!(entries.len(), 2);
+    assert_eq!(entries[0].kind, "prompt");
+    assert_eq!(entries[0].commit, None);
+    assert_eq!(entries[0].content().unwrap(), "second");
+    assert_eq!(entries[1].kind, "pack");
+    assert_eq!(entries[1].commit.as_deref(), Some("abc12345"));
+    assert!(dir.path().join(HISTORY_DIR).join(".gitignore").exists());
+}
+
+#[test]
+fn test_save_keeps_newest() {
+    let dir = TempDir::new().unwrap();
+    for i in 0..4 {
+        history::save(dir.path(), "pack", &i.to_string(), None, 2).unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(2));
+    }
+    let kept: Vec<_> = history::list(dir.path())
+        .unwrap()
+        .iter()
+        .map(|e| e.content().unwrap())
+        .collect();
+    assert_eq!(kept, ["3", "2"]);
+}
+
+#[test]
+fn test_contains_matches_saved_copies() {
+    let dir = TempDir::new().unwrap();
+    assert!(!history::contains(dir.path(), "pack text"));
+    history::save(dir.path(), "pack", "pack text", None, 5).unwrap();
+    assert!(history::contains(dir.path(), "pack text"));
+    assert!(!history::contains(dir.path(), "pack"));
+}
+
+#[test]
+fn test_keep_zero_saves_nothing() {
+    let dir = TempDir::new().unwrap();
+    assert!(history::save(dir.path(), "pack", "x", None, 0)
+        .unwrap()
+        .is_none());
+    assert!(history::list(dir.path()).unwrap().is_empty());
+}
+
+#[test]
+fn test_timestamp() {
+    assert_eq!(history::timestamp("86460000"), "1970-01-02 00:01");
+}
+
+#[test]
+fn test_parse_history_setting() {
+    assert_eq!(ClipboardSettings::default().history, 20);
+    let mut config = Config::default();
+    config.parse_toml("[clipboard]\nhistory = 0\n");
+    assert_eq!(config.clipboard.history, 0);
+}
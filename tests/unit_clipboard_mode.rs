@@ -0,0 +1,42 @@
+// tests/unit_clipboard_mode.rs
+use slopchop_core::clipboard;
+use slopchop_core::config::{ClipboardSettings, Config, CopyMode};
+
+#[test]
+fn test_default_threshold() {
+    let settings = ClipboardSettings::default();
+    assert_eq!(settings.file_copy_threshold, 1500);
+    assert!(!clipboard::copies_as_file(1499, &settings));
+    assert!(clipboard::copies_as_file(1500, &settings));
+}
+
+#[test]
+fn test_modes_override_size() {
+    let text = ClipboardSettings {
+        mode: CopyMode::Text,
+        ..ClipboardSettings::default()
+    };
+    let file = ClipboardSettings {
+        mode: CopyMode::File,
+        ..ClipboardSettings::default()
+    };
+    assert!(!clipboard::copies_as_file(50_000, &text));
+    assert!(clipboard::copies_as_file(10, &file));
+}
+
+#[test]
+fn test_parse_clipboard_section() {
+    let mut config = Config::default();
+    config.parse_toml("[clipboard]\nfile_copy_threshold = 60000\nmode = \"text\"\n");
+    assert_eq!(config.clipboard.file_copy_threshold, 60_000);
+    assert_eq!(config.clipboard.mode, CopyMode::Text);
+
+    config.parse_toml("[clipboard]\nmode = \"file\"\n");
+    assert_eq!(
+        config.clipboard,
+        ClipboardSettings {
+            mode: CopyMode::File,
+            ..ClipboardSettings::default()
+        }
+    );
+}
@@ -0,0 +1,29 @@
+// tests/unit_clipboard_osc52.rs
+use slopchop_core::clipboard::osc52;
+
+#[test]
+fn test_encode_base64() {
+    assert_eq!(osc52::encode(b""), "");
+    assert_eq!(osc52::encode(b"f"), "Zg==");
+    assert_eq!(osc52::encode(b"fo"), "Zm8=");
+    assert_eq!(osc52::encode(b"foo"), "Zm9v");
+    assert_eq!(osc52::encode(b"foobar"), "Zm9vYmFy");
+    assert_eq!(osc52::encode("héllo\n".as_bytes()), "aMOpbGxvCg==");
+}
+
+#[test]
+fn test_fits_under_the_encoded_limit() {
+    assert!(osc52::fits("hi"));
+    let limit = osc52::MAX_ENCODED / 4 * 3;
+    assert!(osc52::fits(&"a".repeat(limit)));
+    assert!(!osc52::fits(&"a".repeat(limit + 1)));
+}
+
+#[test]
+fn test_sequence() {
+    assert_eq!(osc52::sequence("hi", false), "\x1b]52;c;aGk=\x07");
+    assert_eq!(
+        osc52::sequence("hi", true),
+        "\x1bPtmux;\x1b\x1b]52;c;aGk=\x07\x1b\\"
+    );
+}
@@ -1,5 +1,7 @@
 // tests/unit_config.rs
 use slopchop_core::config::Config;
+use slopchop_core::ignore::IgnoreSet;
+use slopchop_core::types::Severity;
 
 #[test]
 fn test_load_toml() {
@@ -15,16 +17,35 @@ fn test_load_toml() {
     let mut config = Config::new();
     config.parse_toml(toml);
 
-    assert_eq!(config.rules.max_file_tokens, 3000);
-    assert!(config.rules.ignore_naming_on.contains(&"foo".to_string()));
+    assert_eq!(config.rules.size.max_file_tokens, 3000);
+    assert!(config
+        .rules
+        .exempt
+        .ignore_naming_on
+        .contains(&"foo".to_string()));
     assert!(!config.preferences.auto_copy);
 }
 
+#[test]
+fn test_banned_calls_per_language() {
+    let toml = r#"
+        [rules]
+        banned_calls.rs = ["dbg!", "println!"]
+        banned_calls.ts = ["console.log"]
+    "#;
+
+    let mut config = Config::new();
+    config.parse_toml(toml);
+
+    assert_eq!(config.rules.banned_calls["rs"], vec!["dbg!", "println!"]);
+    assert_eq!(config.rules.banned_calls["ts"], vec!["console.log"]);
+}
+
 #[test]
 fn test_defaults() {
     let config = Config::new();
-    assert_eq!(config.rules.max_file_tokens, 2000);
-    assert_eq!(config.rules.max_cyclomatic_complexity, 8);
+    assert_eq!(config.rules.size.max_file_tokens, 2000);
+    assert_eq!(config.rules.functions.max_cyclomatic_complexity, 8);
     assert!(config.preferences.auto_copy);
 }
 
@@ -39,7 +60,7 @@ fn test_command_single() {
 
     let cmds = config.commands.get("check").expect("check command missing");
     assert_eq!(cmds.len(), 1);
-    assert_eq!(cmds[0], "cargo check");
+    assert_eq!(cmds[0].run, "cargo check");
 }
 
 #[test]
@@ -53,8 +74,8 @@ fn test_command_list() {
 
     let cmds = config.commands.get("check").expect("check command missing");
     assert_eq!(cmds.len(), 2);
-    assert_eq!(cmds[0], "cargo fmt");
-    assert_eq!(cmds[1], "cargo test");
+    assert_eq!(cmds[0].run, "cargo fmt");
+    assert_eq!(cmds[1].run, "cargo test");
 }
 
 #[test]
@@ -63,31 +84,29 @@ fn test_slopchopignore() {
 
     // Should be ignored
     config.process_ignore_line("target");
-    // SlopChop uses Regex for ignore patterns, not globs.
-    // ".*\.log" matches any characters followed by .log
-    config.process_ignore_line(r".*\.log");
+    // Lines are gitignore patterns, not regexes.
+    config.process_ignore_line("*.log");
 
     // Should be skipped
     config.process_ignore_line("# comment");
     config.process_ignore_line("");
 
-    assert!(config.exclude_patterns.iter().any(|r| r.is_match("target")));
-    assert!(config
-        .exclude_patterns
-        .iter()
-        .any(|r| r.is_match("app.log")));
-    assert!(!config.exclude_patterns.iter().any(|r| r.is_match("src")));
+    assert!(config.files.exclude_patterns.is_ignored("target/debug/app"));
+    assert!(config.files.exclude_patterns.is_ignored("logs/app.log"));
+    assert!(!config.files.exclude_patterns.is_ignored("src/main.rs"));
 }
 
 #[test]
 fn test_ignore_tokens_on() {
-    // Default includes "lock" and "README.md"
+    // Default covers lockfiles and README.md
     let config = Config::new();
     let rules = config.rules;
 
-    let is_ignored = |path: &str| rules.ignore_tokens_on.iter().any(|p| path.contains(p));
+    let is_ignored =
+        |path: &str| IgnoreSet::from_patterns(&rules.exempt.ignore_tokens_on).is_ignored(path);
 
     assert!(is_ignored("Cargo.lock"));
+    assert!(is_ignored("web/package-lock.json"));
     assert!(is_ignored("README.md"));
     assert!(!is_ignored("src/main.rs"));
 }
@@ -96,20 +115,106 @@ fn test_ignore_tokens_on() {
 fn test_ignore_naming_on() {
     let toml = r#"
         [rules]
-        ignore_naming_on = ["tests", "spec"]
+        ignore_naming_on = ["tests", "*.spec.*"]
     "#;
     let mut config = Config::new();
     config.parse_toml(toml);
 
     let is_ignored = |path: &str| {
-        config
-            .rules
-            .ignore_naming_on
-            .iter()
-            .any(|p| path.contains(p))
+        IgnoreSet::from_patterns(&config.rules.exempt.ignore_naming_on).is_ignored(path)
     };
 
     assert!(is_ignored("tests/my_test.rs"));
-    assert!(is_ignored("src/spec.rs"));
+    assert!(is_ignored("web/app.spec.ts"));
+    assert!(!is_ignored("src/tests_util.rs"));
     assert!(!is_ignored("src/main.rs"));
 }
+
+#[test]
+fn test_custom_rules_from_toml_and_dir() {
+    let toml = r#"
+        [[rules.custom]]
+        name = "no-print"
+        lang = "py"
+        query = "(call function: (identifier) @f (#eq? @f \"print\")) @match"
+        message = "Use logging"
+    "#;
+    let mut config = Config::new();
+    config.parse_toml(toml);
+
+    let dir = tempfile::TempDir::new().expect("tempdir");
+    std::fs::write(
+        dir.path().join("extra.toml"),
+        "[[custom]]\nname = \"no-log\"\nlang = \"ts\"\nquery = \"(call_expression) @match\"\nmessage = \"m\"\nseverity = \"info\"\n",
+    )
+    .expect("write rules file");
+    slopchop_core::config::io::load_rules_dir(&mut config, dir.path());
+
+    let names: Vec<_> = config
+        .rules
+        .custom
+        .iter()
+        .map(|r| r.name.as_str())
+        .collect();
+    assert_eq!(names, ["no-print", "no-log"]);
+    assert_eq!(config.rules.custom[0].severity, Severity::Error);
+    assert_eq!(config.rules.custom[1].severity, Severity::Info);
+}
+
+#[test]
+fn test_severity_overrides() {
+    let toml = r#"
+        [rules.severity]
+        complexity = "warning"
+        no-todo = "info"
+    "#;
+    let mut config = Config::new();
+    config.parse_toml(toml);
+
+    assert_eq!(config.rules.severity["complexity"], Severity::Warning);
+    assert_eq!(config.rules.severity["no-todo"], Severity::Info);
+}
+
+#[test]
+fn test_post_apply_hooks() {
+    let toml = r#"
+        [hooks]
+        post_apply = ["cargo fmt", "npm run lint:fix"]
+    "#;
+
+    let mut config = Config::new();
+    assert!(config.hooks.is_empty());
+    config.parse_toml(toml);
+
+    assert_eq!(config.hooks.post_apply, ["cargo fmt", "npm run lint:fix"]);
+}
+
+#[test]
+fn test_git_create_pr() {
+    let mut config = Config::new();
+    assert!(!config.git.create_pr);
+    config.parse_toml("[git]\ncreate_pr = true\n");
+
+    assert!(config.git.create_pr);
+}
+
+#[test]
+fn test_git_commit_template() {
+    let mut config = Config::new();
+    assert!(config.git.commit_template.is_none());
+    config.parse_toml("[git]\ncommit_template = \"feat: {summary}\"\n");
+
+    assert_eq!(
+        config.git.commit_template.as_deref(),
+        Some("feat: {summary}")
+    );
+}
+
+#[test]
+fn test_apply_allowed_paths() {
+    let mut config = Config::new();
+    assert!(config.apply.allowed_paths.is_empty());
+    config.parse_toml("[apply]\nallowed_paths = [\"src/**\", \"tests/**\"]\n");
+
+    assert_eq!(config.apply.allowed_paths, ["src/**", "tests/**"]);
+}
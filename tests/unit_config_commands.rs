@@ -0,0 +1,133 @@
+// tests/unit_config_commands.rs
+use std::path::PathBuf;
+
+use slopchop_core::config::commands::{ChangedFiles, CommandStep, Condition, StepEntry};
+use slopchop_core::config::Config;
+
+fn parse(content: &str) -> Config {
+    let mut config = Config::new();
+    config.parse_toml(content);
+    config
+}
+
+fn listed(files: &[&str]) -> ChangedFiles {
+    let files: Vec<String> = files.iter().map(ToString::to_string).collect();
+    ChangedFiles::listed(&files)
+}
+
+fn step_if(only_if: Condition) -> CommandStep {
+    CommandStep {
+        only_if: Some(only_if),
+        ..CommandStep::line("true")
+    }
+}
+
+#[test]
+fn test_steps_mix_lines_and_tables() {
+    let config = parse(
+        r#"
+        [commands]
+        fix = [
+            "cargo fmt",
+            { run = "npx prettier --write .", cwd = "web", env = { CI = "1" }, continue_on_error = true, only_if = { changed = "*.ts" } },
+        ]
+        "#,
+    );
+    let fix = &config.commands["fix"];
+    assert_eq!(fix[0], CommandStep::line("cargo fmt"));
+    assert_eq!(fix[1].run, "npx prettier --write .");
+    assert_eq!(fix[1].cwd, Some(PathBuf::from("web")));
+    assert_eq!(fix[1].env["CI"], "1");
+    assert!(fix[1].continue_on_error);
+    let only_if = fix[1].only_if.as_ref().unwrap();
+    assert_eq!(only_if.changed.as_deref(), Some("*.ts"));
+    assert_eq!(only_if.exists, None);
+}
+
+#[test]
+fn test_single_table_step() {
+    let config = parse(
+        r#"
+        [commands.check]
+        run = "cargo clippy"
+        only_if = { exists = "Cargo.toml" }
+        "#,
+    );
+    let check = &config.commands["check"];
+    assert_eq!(check.len(), 1);
+    assert_eq!(check[0].run, "cargo clippy");
+}
+
+#[test]
+fn test_only_if_exists() {
+    let mut changed = listed(&[]);
+    let present = step_if(Condition {
+        exists: Some("Cargo.toml".into()),
+        changed: None,
+    });
+    let missing = step_if(Condition {
+        exists: Some("no-such-file.toml".into()),
+        changed: None,
+    });
+    assert!(present.should_run(&mut changed));
+    assert!(!missing.should_run(&mut changed));
+    assert!(CommandStep::line("true").should_run(&mut changed));
+}
+
+#[test]
+fn test_only_if_changed_matches_patterns() {
+    let step = step_if(Condition {
+        exists: None,
+        changed: Some("*.ts".into()),
+    });
+    assert!(step.should_run(&mut listed(&["README.md", "web/src/app.ts"])));
+    assert!(!step.should_run(&mut listed(&["src/main.rs"])));
+    assert!(!step.should_run(&mut listed(&[])));
+}
+
+#[test]
+fn test_only_if_needs_every_key() {
+    let step = step_if(Condition {
+        exists: Some("no-such-file.toml".into()),
+        changed: Some("*.rs".into()),
+    });
+    assert!(!step.should_run(&mut listed(&["src/main.rs"])));
+}
+
+#[test]
+fn test_command_sets_cwd_and_env() {
+    let mut step = CommandStep::line("cargo test --quiet");
+    step.cwd = Some(PathBuf::from("crates/core"));
+    step.env.insert("RUST_LOG".into(), "debug".into());
+    let command = step.command().unwrap();
+    assert_eq!(command.get_program(), "cargo");
+    assert_eq!(command.get_args().collect::<Vec<_>>(), ["test", "--quiet"]);
+    assert_eq!(
+        command.get_current_dir(),
+        Some(PathBuf::from("crates/core").as_path())
+    );
+    let envs: Vec<_> = command.get_envs().collect();
+    assert_eq!(envs.len(), 1);
+    assert_eq!(envs[0].0, "RUST_LOG");
+    assert!(CommandStep::line("  ").command().is_none());
+}
+
+#[test]
+fn test_args_honour_quotes_and_escapes() {
+    let step = CommandStep::line(r#"grep -r "TODO: fix" 'a b' c\ d "" C:\dir"#);
+    assert_eq!(
+        step.args(),
+        ["grep", "-r", "TODO: fix", "a b", "c d", "", r"C:\dir"]
+    );
+}
+
+#[test]
+fn test_plain_steps_save_as_lines() {
+    assert!(matches!(
+        StepEntry::from(CommandStep::line("cargo fmt")),
+        StepEntry::Line(run) if run == "cargo fmt"
+    ));
+    let mut step = CommandStep::line("cargo fmt");
+    step.continue_on_error = true;
+    assert!(matches!(StepEntry::from(step), StepEntry::Step(_)));
+}
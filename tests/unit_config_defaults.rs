@@ -0,0 +1,109 @@
+// tests/unit_config_defaults.rs
+use slopchop_core::cli::CheckArgs;
+use slopchop_core::config::validate::{self, IssueKind};
+use slopchop_core::config::Config;
+use slopchop_core::pack::comments::StripComments;
+use slopchop_core::pack::OutputFormat;
+use slopchop_core::reporting::{GroupBy, ReportFormat};
+
+fn parse(content: &str) -> Config {
+    let mut config = Config::new();
+    config.parse_toml(content);
+    config
+}
+
+fn check_args() -> CheckArgs {
+    CheckArgs {
+        fail_on: None,
+        max_violations: None,
+        changed: false,
+        no_changed: false,
+        since: None,
+        format: None,
+        group_by: None,
+        blame: false,
+        no_blame: false,
+        package: None,
+    }
+}
+
+#[test]
+fn test_defaults_parse_with_flag_names() {
+    let config = parse(
+        r#"
+        [defaults.pack]
+        copy = true
+        format = "claude-xml"
+        strip_comments = "keep-docs"
+        skeleton = false
+
+        [defaults.check]
+        format = "gitlab"
+        group_by = "rule"
+
+        [defaults.apply]
+        review = true
+        "#,
+    );
+    let defaults = &config.defaults;
+    assert!(defaults.pack.copy);
+    assert!(!defaults.pack.skeleton);
+    assert_eq!(defaults.pack.format, Some(OutputFormat::ClaudeXml));
+    assert_eq!(defaults.pack.strip_comments, Some(StripComments::KeepDocs));
+    assert_eq!(defaults.check.format, Some(ReportFormat::CodeClimate));
+    assert_eq!(defaults.check.group_by, Some(GroupBy::Rule));
+    assert!(defaults.apply.review);
+}
+
+#[test]
+fn test_missing_defaults_leave_everything_off() {
+    let config = parse("[rules]\nmax_file_tokens = 1000\n");
+    assert!(config.defaults.is_default());
+}
+
+#[test]
+fn test_check_flags_win_over_defaults() {
+    let config = parse("[defaults.check]\nformat = \"json\"\ngroup_by = \"law\"\nblame = true\n");
+    let defaults = &config.defaults.check;
+
+    let filled = check_args().with_defaults(defaults);
+    assert_eq!(filled.format, Some(ReportFormat::Json));
+    assert_eq!(filled.group_by, Some(GroupBy::Law));
+    assert!(filled.blame);
+    assert!(!filled.changed);
+
+    let mut given = check_args();
+    given.format = Some(ReportFormat::Text);
+    let filled = given.with_defaults(defaults);
+    assert_eq!(filled.format, Some(ReportFormat::Text));
+    assert_eq!(filled.group_by, Some(GroupBy::Law));
+}
+
+#[test]
+fn test_no_flags_turn_off_default_switches() {
+    let config = parse(
+        "[defaults.check]
+blame = true
+changed = true
+",
+    );
+    let mut given = check_args();
+    given.no_blame = true;
+    given.no_changed = true;
+
+    let filled = given.with_defaults(&config.defaults.check);
+    assert!(!filled.blame);
+    assert!(!filled.changed);
+}
+
+#[test]
+fn test_validate_checks_defaults() {
+    let issues = validate::check("[defaults.pack]\nformt = \"xml\"\n");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IssueKind::UnknownKey);
+    assert!(issues[0].message.contains("`format`"));
+
+    let issues = validate::check("[defaults.check]\nformat = \"yaml\"\n");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IssueKind::Type);
+}
@@ -0,0 +1,75 @@
+// tests/unit_config_env.rs
+use slopchop_core::config::env::{self, Override};
+use slopchop_core::config::io::parse_toml_with;
+use slopchop_core::config::Config;
+use std::path::Path;
+
+fn vars(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+    pairs
+        .iter()
+        .map(|(k, v)| ((*k).to_string(), (*v).to_string()))
+        .collect()
+}
+
+#[test]
+fn test_maps_names_to_key_paths() {
+    let found = env::overrides(vars(&[
+        ("SLOPCHOP_RULES__MAX_FILE_TOKENS", "3000"),
+        ("SLOPCHOP_CLIPBOARD", "osc52"),
+        ("PATH", "/bin"),
+        ("SLOPCHOP_RULES__", "1"),
+    ]));
+    assert_eq!(
+        found,
+        vec![Override {
+            path: vec!["rules".into(), "max_file_tokens".into()],
+            value: toml::Value::Integer(3000),
+        }]
+    );
+}
+
+#[test]
+fn test_values_parse_as_toml_or_string() {
+    let found = env::overrides(vars(&[
+        ("SLOPCHOP_A__BOOL", "true"),
+        ("SLOPCHOP_B__LIST", "[\"x\", \"y\"]"),
+        ("SLOPCHOP_C__TEXT", "cargo test --all"),
+    ]));
+    assert_eq!(found[0].value, toml::Value::Boolean(true));
+    assert_eq!(found[1].value.as_array().map(Vec::len), Some(2));
+    assert_eq!(found[2].value.as_str(), Some("cargo test --all"));
+}
+
+#[test]
+fn test_overrides_apply_over_file() {
+    let overrides = env::overrides(vars(&[
+        ("SLOPCHOP_RULES__MAX_FILE_TOKENS", "3000"),
+        ("SLOPCHOP_RULES__PYTHON__MAX_NESTING_DEPTH", "5"),
+        ("SLOPCHOP_PREFERENCES__AUTO_COPY", "false"),
+    ]));
+    let mut config = Config::default();
+    parse_toml_with(
+        &mut config,
+        "[rules]\nmax_file_tokens = 1000\nmax_nesting_depth = 2\n",
+        Path::new("."),
+        &overrides,
+    );
+    assert_eq!(config.rules.size.max_file_tokens, 3000);
+    assert_eq!(config.rules.functions.max_nesting_depth, 2);
+    let python = config.rules.languages.python.as_ref().unwrap();
+    assert_eq!(python.max_nesting_depth, Some(5));
+    assert!(!config.preferences.auto_copy);
+}
+
+#[test]
+fn test_bad_override_keeps_file_config() {
+    let overrides = env::overrides(vars(&[("SLOPCHOP_RULES__MAX_FILE_TOKENS", "lots")]));
+    let mut config = Config::default();
+    parse_toml_with(
+        &mut config,
+        "[rules]\nmax_file_tokens = 1000\n",
+        Path::new("."),
+        &overrides,
+    );
+    assert_eq!(config.rules.size.max_file_tokens, 1000);
+}
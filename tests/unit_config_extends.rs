@@ -0,0 +1,129 @@
+// tests/unit_config_extends.rs
+use slopchop_core::config::extends;
+use slopchop_core::config::io::parse_toml_in;
+use slopchop_core::config::Config;
+use std::fs;
+use tempfile::TempDir;
+
+fn parse_in(dir: &TempDir, content: &str) -> Config {
+    let mut config = Config::default();
+    parse_toml_in(&mut config, content, dir.path());
+    config
+}
+
+#[test]
+fn test_extends_file_deep_merges() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("shared")).unwrap();
+    fs::write(
+        dir.path().join("shared/base.toml"),
+        "[rules]\nmax_file_tokens = 1200\nmax_nesting_depth = 2\n\n[preferences]\nauto_copy = false\n",
+    )
+    .unwrap();
+
+    let config = parse_in(
+        &dir,
+        "extends = \"shared/base.toml\"\n\n[rules]\nmax_nesting_depth = 4\n",
+    );
+    assert_eq!(config.rules.size.max_file_tokens, 1200);
+    assert_eq!(config.rules.functions.max_nesting_depth, 4);
+    assert!(!config.preferences.auto_copy);
+}
+
+#[test]
+fn test_nested_paths_resolve_from_each_file() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir(dir.path().join("org")).unwrap();
+    fs::write(
+        dir.path().join("org/root.toml"),
+        "[rules]\nmax_function_args = 3\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("org/team.toml"),
+        "extends = \"root.toml\"\n[rules]\nmax_function_lines = 40\n",
+    )
+    .unwrap();
+
+    let config = parse_in(&dir, "extends = \"org/team.toml\"\n");
+    assert_eq!(config.rules.functions.max_function_args, 3);
+    assert_eq!(config.rules.functions.max_function_lines, 40);
+}
+
+#[test]
+fn test_extends_preset_and_later_bases_win() {
+    let dir = TempDir::new().unwrap();
+    fs::write(
+        dir.path().join("team.toml"),
+        "[rules]\nmax_file_tokens = 1800\n",
+    )
+    .unwrap();
+
+    let config = parse_in(&dir, "extends = [\"preset:strict\", \"team.toml\"]\n");
+    assert_eq!(config.rules.functions.max_cyclomatic_complexity, 4);
+    assert_eq!(config.rules.size.max_file_tokens, 1800);
+}
+
+#[test]
+fn test_missing_base_keeps_local_config() {
+    let dir = TempDir::new().unwrap();
+    let config = parse_in(
+        &dir,
+        "extends = \"nope.toml\"\n[rules]\nmax_file_tokens = 900\n",
+    );
+    assert_eq!(config.rules.size.max_file_tokens, 900);
+}
+
+#[test]
+fn test_cycle_is_an_error() {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("a.toml"), "extends = \"b.toml\"\n").unwrap();
+    fs::write(dir.path().join("b.toml"), "extends = \"a.toml\"\n").unwrap();
+
+    let table = toml::from_str("extends = \"a.toml\"\n").unwrap();
+    let err = extends::resolve(table, dir.path()).unwrap_err();
+    assert!(err.contains("cycle"), "{err}");
+}
+
+#[test]
+fn test_merge_replaces_arrays() {
+    let mut base: toml::Table = toml::from_str("[rules]\nignore_naming_on = [\"a\"]\n").unwrap();
+    extends::merge(
+        &mut base,
+        toml::from_str("[rules]\nignore_naming_on = [\"b\"]\n").unwrap(),
+    );
+    assert_eq!(
+        base["rules"]["ignore_naming_on"],
+        toml::Value::Array(vec!["b".into()])
+    );
+}
+
+#[test]
+fn test_validate_covers_extended_files() {
+    let dir = TempDir::new().unwrap();
+    fs::create_dir_all(dir.path().join("team")).unwrap();
+    fs::write(
+        dir.path().join("team/base.toml"),
+        "extends = \"deep.toml\"\n[rules]\nmax_file_tokens = 100\n",
+    )
+    .unwrap();
+    fs::write(
+        dir.path().join("team/deep.toml"),
+        "[rules]\nmax_file_tokens = \"many\"\n",
+    )
+    .unwrap();
+    let config = parse_in(&dir, "extends = [\"preset:strict\", \"team/base.toml\"]\n");
+    assert_eq!(
+        config.sources,
+        vec![
+            dir.path().join("team/base.toml"),
+            dir.path().join("team/deep.toml"),
+        ]
+    );
+    let err = config.validate().unwrap_err().to_string();
+    assert!(
+        err.contains("deep.toml: line 2: `rules.max_file_tokens`"),
+        "{err}"
+    );
+    assert!(!err.contains("base.toml"), "{err}");
+}
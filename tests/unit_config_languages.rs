@@ -0,0 +1,81 @@
+// tests/unit_config_languages.rs
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::Config;
+use slopchop_core::lang::Lang;
+use std::path::PathBuf;
+
+const NESTED_PY: &str = "def f(x):\n    if x:\n        for i in x:\n            while i:\n                if i:\n                    return i\n";
+
+fn config(toml: &str) -> Config {
+    let mut config = Config::default();
+    config.parse_toml(toml);
+    config
+}
+
+fn nesting_violations(config: Config, file: &str, content: &str) -> usize {
+    let report =
+        RuleEngine::new(config).scan_sources(vec![(PathBuf::from(file), content.to_string())]);
+    report.files[0]
+        .violations
+        .iter()
+        .filter(|v| v.message.contains("Deep Nesting"))
+        .count()
+}
+
+#[test]
+fn test_parse_language_tables() {
+    let config = config(
+        r#"
+        [rules]
+        max_nesting_depth = 3
+
+        [rules.python]
+        max_nesting_depth = 5
+        banned_calls = ["eval"]
+    "#,
+    );
+    let python = config.rules.languages.python.as_ref().unwrap();
+    assert_eq!(python.max_nesting_depth, Some(5));
+    assert_eq!(config.rules.functions.max_nesting_depth, 3);
+    assert!(config.rules.languages.rust.is_none());
+}
+
+#[test]
+fn test_bash_table_sets_function_count() {
+    let config = config("[rules.bash]\nmax_file_functions = 1\n");
+    let script = "a() { echo a; }\nb() { echo b; }\n";
+    let report =
+        RuleEngine::new(config).scan_sources(vec![(PathBuf::from("t.sh"), script.to_string())]);
+    assert!(report.files[0]
+        .violations
+        .iter()
+        .any(|v| v.message.contains("defines 2 functions (Max: 1)")));
+}
+
+#[test]
+fn test_for_lang_overrides_only_that_language() {
+    let config = config(
+        "[rules]\nmax_function_args = 4\n\n[rules.python]\nmax_function_args = 7\nbanned_calls = [\"eval\"]\n",
+    );
+    let python = config.rules.for_lang(Lang::Python);
+    assert_eq!(python.functions.max_function_args, 7);
+    assert_eq!(python.banned_calls["py"], ["eval"]);
+    assert_eq!(
+        config
+            .rules
+            .for_lang(Lang::Rust)
+            .functions
+            .max_function_args,
+        4
+    );
+}
+
+#[test]
+fn test_analysis_uses_language_limits() {
+    let strict = config("[rules]\nmax_nesting_depth = 2\n");
+    assert!(nesting_violations(strict, "a.py", NESTED_PY) > 0);
+
+    let relaxed =
+        config("[rules]\nmax_nesting_depth = 2\n\n[rules.python]\nmax_nesting_depth = 6\n");
+    assert_eq!(nesting_violations(relaxed, "a.py", NESTED_PY), 0);
+}
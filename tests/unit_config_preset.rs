@@ -0,0 +1,72 @@
+// tests/unit_config_preset.rs
+use slopchop_core::config::validate::{self, IssueKind};
+use slopchop_core::config::{preset, Config};
+use slopchop_core::project::Strictness;
+
+fn parse(content: &str) -> Config {
+    let mut config = Config::new();
+    config.parse_toml(content);
+    config
+}
+
+#[test]
+fn test_preset_fills_in_limits() {
+    let config = parse("[rules]\npreset = \"strict\"\n");
+    assert_eq!(config.rules.preset, Some(Strictness::Strict));
+    assert_eq!(config.rules.size.max_file_tokens, 1500);
+    assert_eq!(config.rules.functions.max_cyclomatic_complexity, 4);
+    assert_eq!(config.rules.functions.max_nesting_depth, 2);
+
+    let config = parse("[rules]\npreset = \"relaxed\"\n");
+    assert_eq!(config.rules.size.max_file_tokens, 3000);
+}
+
+#[test]
+fn test_explicit_keys_win_over_preset() {
+    let config = parse("[rules]\npreset = \"strict\"\nmax_nesting_depth = 5\n");
+    assert_eq!(config.rules.size.max_file_tokens, 1500);
+    assert_eq!(config.rules.functions.max_nesting_depth, 5);
+}
+
+#[test]
+fn test_expand_keeps_keys_already_set() {
+    let mut table: toml::Table = toml::from_str("[rules]\nmax_file_tokens = 900\n").unwrap();
+    table["rules"]
+        .as_table_mut()
+        .unwrap()
+        .insert("preset".into(), "relaxed".into());
+    preset::expand(&mut table);
+    assert_eq!(table["rules"]["max_file_tokens"].as_integer(), Some(900));
+    assert_eq!(table["rules"]["max_nesting_depth"].as_integer(), Some(4));
+}
+
+#[test]
+fn test_unknown_preset_is_a_type_error() {
+    let issues = validate::check("[rules]\npreset = \"extreme\"\n");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IssueKind::Type);
+    assert_eq!(issues[0].key, "rules.preset");
+}
+
+#[test]
+fn test_set_switches_preset_and_drops_its_limits() {
+    let content = "# team policy\n[rules]\nmax_file_tokens = 2000\nmax_function_args = 4\n\n[commands]\ncheck = \"cargo test\"\n";
+    let (updated, removed) = preset::set(content, Strictness::Relaxed).unwrap();
+    assert_eq!(removed, vec!["max_file_tokens"]);
+    assert!(updated.starts_with("# team policy\n"));
+    assert!(updated.contains("preset = \"relaxed\""));
+    assert!(updated.contains("max_function_args = 4"));
+    assert!(updated.contains("check = \"cargo test\""));
+
+    let config = parse(&updated);
+    assert_eq!(config.rules.size.max_file_tokens, 3000);
+    assert_eq!(config.rules.functions.max_function_args, 4);
+}
+
+#[test]
+fn test_set_creates_rules_table() {
+    let (updated, removed) = preset::set("", Strictness::Strict).unwrap();
+    assert!(removed.is_empty());
+    assert_eq!(parse(&updated).rules.size.max_file_tokens, 1500);
+    assert!(preset::set("rules = 3\n", Strictness::Strict).is_err());
+}
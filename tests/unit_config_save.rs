@@ -0,0 +1,37 @@
+// tests/unit_config_save.rs
+use slopchop_core::config::{io, save_to_file, Config};
+use std::env;
+use std::fs;
+use tempfile::tempdir;
+
+// One test: the config is read from and saved to the process working directory.
+#[test]
+fn test_save_keeps_loaded_sections_and_refuses_invalid_file() -> anyhow::Result<()> {
+    let dir = tempdir()?;
+    let home = env::current_dir()?;
+    env::set_current_dir(dir.path())?;
+    fs::write(
+        "slopchop.toml",
+        "extends = \"preset:strict\"\n\n[pack]\nchunk_tokens = 5000\n",
+    )?;
+
+    let saved = io::saved_sections()?;
+    fs::write("slopchop.toml", "not = [valid")?;
+    let mut config = Config::new();
+    config.rules.size.max_file_tokens = 1234;
+    save_to_file(&config.rules, &config.preferences, &config.commands, &saved)?;
+    let written = fs::read_to_string("slopchop.toml")?;
+
+    fs::write("slopchop.toml", "[rules]\nmax_file_tokens = \"many\"\n")?;
+    let invalid = io::saved_sections();
+    fs::remove_file("slopchop.toml")?;
+    let missing = io::saved_sections();
+    env::set_current_dir(home)?;
+
+    assert!(written.contains("extends = \"preset:strict\""), "{written}");
+    assert!(written.contains("chunk_tokens = 5000"), "{written}");
+    assert!(written.contains("max_file_tokens = 1234"), "{written}");
+    assert!(invalid.is_err());
+    assert!(missing?.pack.chunk_tokens.is_none());
+    Ok(())
+}
@@ -0,0 +1,123 @@
+// tests/unit_config_validate.rs
+use serde_json::json;
+use slopchop_core::config::schema;
+use slopchop_core::config::validate::{check, check_with, IssueKind};
+
+fn keys(content: &str) -> Vec<(IssueKind, String, usize)> {
+    check(content)
+        .into_iter()
+        .map(|i| (i.kind, i.key, i.line))
+        .collect()
+}
+
+#[test]
+fn test_generated_config_is_valid() {
+    let content = slopchop_core::project::generate_toml(
+        slopchop_core::project::ProjectType::Rust,
+        slopchop_core::project::Strictness::Standard,
+    );
+    assert_eq!(check(&content), vec![]);
+}
+
+#[test]
+fn test_unknown_keys_with_suggestions() {
+    let issues = check("[rules]\nmax_file_tokns = 10\n\n[rules.rust]\nbogus = 1\n\n[extra]\n");
+    assert_eq!(issues.len(), 3);
+    assert_eq!(issues[0].kind, IssueKind::UnknownKey);
+    assert_eq!(issues[0].key, "rules.max_file_tokns");
+    assert!(issues[0].message.contains("did you mean `max_file_tokens`"));
+    assert_eq!(
+        (issues[1].key.as_str(), issues[1].line),
+        ("rules.rust.bogus", 5)
+    );
+    assert_eq!((issues[2].key.as_str(), issues[2].line), ("extra", 7));
+}
+
+#[test]
+fn test_type_errors_are_all_reported() {
+    let content = "[rules]\nmax_nesting_depth = \"3\"\nmax_function_args = -1\n\n[preferences]\nsyntax_check = \"nope\"\n";
+    assert_eq!(
+        keys(content),
+        vec![
+            (IssueKind::Type, "rules.max_nesting_depth".into(), 2),
+            (IssueKind::Type, "rules.max_function_args".into(), 3),
+            (IssueKind::Type, "preferences.syntax_check".into(), 6),
+        ]
+    );
+    let issues = check(content);
+    assert!(issues[0]
+        .message
+        .contains("expected an integer, found a string"));
+    assert!(issues[2]
+        .message
+        .contains("\"deny\" or \"warn\" or \"off\""));
+}
+
+#[test]
+fn test_nested_arrays_and_maps() {
+    let content = "[commands]\ncheck = [\"cargo test\",\n  3]\nfix = \"cargo fmt\"\n\n[[rules.custom]]\nname = \"x\"\nlang = \"rs\"\nquery = \"q\"\nmessage = 4\n\n[rules.severity]\ncomplexity = \"fatal\"\n";
+    assert_eq!(
+        keys(content),
+        vec![
+            (IssueKind::Type, "commands.check[1]".into(), 3),
+            (IssueKind::Type, "rules.custom[0].message".into(), 10),
+            (IssueKind::Type, "rules.severity.complexity".into(), 13),
+        ]
+    );
+}
+
+#[test]
+fn test_extends_accepts_string_or_list() {
+    assert!(check("extends = \"preset:strict\"\n").is_empty());
+    assert!(check("extends = [\"preset:strict\", \"base.toml\"]\n").is_empty());
+    assert_eq!(check("extends = 3\n")[0].kind, IssueKind::Type);
+}
+
+#[test]
+fn test_syntax_error_has_line() {
+    let issues = check("[rules]\nmax_file_tokens = \n");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IssueKind::Syntax);
+    assert_eq!(issues[0].line, 2);
+}
+
+#[test]
+fn test_deprecated_options_are_warnings() {
+    let schema = json!({
+        "type": "object",
+        "additionalProperties": false,
+        "properties": {
+            "old": { "type": "integer", "deprecated": true, "description": "Use `new`." },
+            "new": { "type": "integer" }
+        }
+    });
+    let issues = check_with(&schema, "new = 1\nold = 2\n");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IssueKind::Deprecated);
+    assert!(!issues[0].is_error());
+    assert_eq!(
+        issues[0].to_string(),
+        "line 2: `old` is deprecated: Use `new`."
+    );
+}
+
+#[test]
+fn test_schema_marks_force_deprecated() {
+    let issues = check("[defaults.apply]\nreview = true\nforce = true\n");
+    assert_eq!(issues.len(), 1);
+    assert_eq!(issues[0].kind, IssueKind::Deprecated);
+    assert_eq!(
+        issues[0].to_string(),
+        "line 3: `defaults.apply.force` is deprecated: Ignored: skipping the confirmation takes `--force` on each run."
+    );
+}
+
+#[test]
+fn test_schema_closes_sections() {
+    let schema = schema::json();
+    assert_eq!(schema["additionalProperties"], json!(false));
+    let rules = &schema["definitions"]["RuleConfig"];
+    assert_eq!(rules["additionalProperties"], json!(false));
+    assert!(rules["properties"]["rust"].is_object());
+    assert!(schema["properties"]["commands"]["additionalProperties"].is_object());
+}
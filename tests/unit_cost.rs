@@ -0,0 +1,39 @@
+// tests/unit_cost.rs
+use std::collections::BTreeMap;
+
+use slopchop_core::config::Config;
+use slopchop_core::cost;
+
+#[test]
+fn test_estimate() {
+    assert!((cost::estimate(1_000_000, 3.0) - 3.0).abs() < f64::EPSILON);
+    assert!((cost::estimate(143_000, 3.0) - 0.429).abs() < 1e-9);
+}
+
+#[test]
+fn test_render_lines() {
+    let prices = BTreeMap::from([
+        ("claude-sonnet".to_string(), 3.0),
+        ("gpt-4o-mini".to_string(), 0.15),
+    ]);
+    assert_eq!(
+        cost::render(143_000, &prices),
+        vec![
+            "~$0.43 at input pricing for claude-sonnet",
+            "~$0.02 at input pricing for gpt-4o-mini",
+        ]
+    );
+    assert_eq!(
+        cost::render(10, &prices)[1],
+        "<$0.01 at input pricing for gpt-4o-mini"
+    );
+    assert!(cost::summary(143_000, &BTreeMap::new()).is_empty());
+}
+
+#[test]
+fn test_prices_from_config() {
+    let mut config = Config::default();
+    config.parse_toml("[tokens.prices]\n\"gpt-4o\" = 2.5\nclaude-sonnet = 3\n");
+    assert_eq!(config.tokens.prices.get("gpt-4o"), Some(&2.5));
+    assert_eq!(config.tokens.prices.get("claude-sonnet"), Some(&3.0));
+}
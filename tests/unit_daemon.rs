@@ -0,0 +1,136 @@
+// tests/unit_daemon.rs
+//! Tests for the daemon protocol.
+
+use slopchop_core::config::Config;
+use slopchop_core::daemon::auth::{self, Handle};
+use slopchop_core::daemon::client;
+use slopchop_core::daemon::{Daemon, Request, Response};
+use std::fs;
+use std::net::{TcpListener, TcpStream};
+use std::path::{Path, PathBuf};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+use tempfile::TempDir;
+
+const TOKEN: &str = "secret";
+
+fn start(cache: &Path) -> (Handle, JoinHandle<anyhow::Result<()>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let handle = Handle {
+        addr: listener.local_addr().unwrap(),
+        token: TOKEN.to_string(),
+    };
+    let cache = cache.to_path_buf();
+    let server = thread::spawn(move || Daemon::new(&cache, TOKEN).serve(&listener));
+    (handle, server)
+}
+
+/// The fingerprint of the rules the daemon loads from the working directory.
+fn local_rules() -> u64 {
+    let mut config = Config::new();
+    config.load_local_config();
+    auth::fingerprint(&config.rules)
+}
+
+fn scan(path: &Path, rules: u64) -> Request {
+    Request::Scan {
+        files: Some(vec![PathBuf::from(path)]),
+        cross_file: false,
+        rules,
+    }
+}
+
+#[test]
+fn test_daemon_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    fs::write(&path, "fn f() {\n    let x = Some(1).unwrap();\n}\n").unwrap();
+    let (handle, server) = start(&dir.path().join("cache"));
+
+    assert!(matches!(
+        client::request(&handle, Request::Ping).unwrap(),
+        Response::Ok
+    ));
+
+    for _ in 0..2 {
+        let Response::Report { report } =
+            client::request(&handle, scan(&path, local_rules())).unwrap()
+        else {
+            panic!("Expected a report");
+        };
+        assert_eq!(report.files.len(), 1);
+        assert_eq!(report.files[0].violations[0].law, "LAW OF PARANOIA");
+    }
+    assert!(dir.path().join("cache/scan.json").exists());
+
+    client::request(&handle, Request::Shutdown).unwrap();
+    server.join().unwrap().unwrap();
+}
+
+#[test]
+fn test_daemon_rejects_wrong_token_and_other_rules() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    fs::write(&path, "fn f() {}\n").unwrap();
+    let (handle, server) = start(&dir.path().join("cache"));
+    let intruder = Handle {
+        token: "guess".to_string(),
+        ..handle.clone()
+    };
+
+    assert!(matches!(
+        client::request(&intruder, Request::Shutdown).unwrap(),
+        Response::Error { .. }
+    ));
+    assert!(matches!(
+        client::request(&intruder, scan(&path, local_rules())).unwrap(),
+        Response::Error { .. }
+    ));
+    assert!(matches!(
+        client::request(&handle, scan(&path, local_rules() ^ 1)).unwrap(),
+        Response::Mismatch { .. }
+    ));
+
+    client::request(&handle, Request::Shutdown).unwrap();
+    server.join().unwrap().unwrap();
+}
+
+#[test]
+fn test_idle_connection_does_not_block_others() {
+    let dir = TempDir::new().unwrap();
+    let (handle, server) = start(&dir.path().join("cache"));
+    let _idle = TcpStream::connect(handle.addr).unwrap();
+
+    // Answered on its own thread, well before the idle read times out.
+    let started = Instant::now();
+    assert!(matches!(
+        client::request(&handle, Request::Ping).unwrap(),
+        Response::Ok
+    ));
+    assert!(started.elapsed() < Duration::from_secs(1));
+    client::request(&handle, Request::Shutdown).unwrap();
+    server.join().unwrap().unwrap();
+}
+
+#[test]
+fn test_address_file_round_trip() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("daemon.addr");
+    let handle = Handle {
+        addr: "127.0.0.1:4000".parse().unwrap(),
+        token: auth::new_token(),
+    };
+    handle.write(&path).unwrap();
+    handle.write(&path).unwrap();
+
+    let read = Handle::read(&path).unwrap();
+    assert_eq!(read.addr, handle.addr);
+    assert_eq!(read.token.len(), 32);
+    assert_ne!(read.token, auth::new_token());
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        let mode = fs::metadata(&path).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+    }
+}
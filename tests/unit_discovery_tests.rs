@@ -0,0 +1,40 @@
+// tests/unit_discovery_tests.rs
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::Config;
+use slopchop_core::discovery;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+// One test: discovery walks the working directory, so this changes it.
+#[test]
+fn test_test_dirs_are_scanned_under_the_test_profile() -> anyhow::Result<()> {
+    let dir = TempDir::new()?;
+    for sub in ["src", "tests", "fixtures"] {
+        fs::create_dir_all(dir.path().join(sub))?;
+    }
+    let panics = "fn f() {\n    Some(1).unwrap();\n}\n";
+    fs::write(dir.path().join("src/lib.rs"), panics)?;
+    fs::write(dir.path().join("tests/t.rs"), panics)?;
+    fs::write(dir.path().join("fixtures/bad.rs"), panics)?;
+    env::set_current_dir(dir.path())?;
+
+    let config = Config::default();
+    let mut files = discovery::discover(&config)?;
+    files.sort();
+    assert_eq!(
+        files,
+        [PathBuf::from("src/lib.rs"), PathBuf::from("tests/t.rs")]
+    );
+
+    let report = RuleEngine::new(config).scan(files);
+    let paranoid: Vec<_> = report
+        .files
+        .iter()
+        .filter(|f| f.violations.iter().any(|v| v.law == "LAW OF PARANOIA"))
+        .map(|f| f.path.clone())
+        .collect();
+    assert_eq!(paranoid, [PathBuf::from("src/lib.rs")]);
+    Ok(())
+}
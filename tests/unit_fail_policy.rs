@@ -0,0 +1,50 @@
+// tests/unit_fail_policy.rs
+//! Tests for `[rules.fail]` thresholds.
+
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::{Config, FailPolicy};
+use slopchop_core::types::{FailOn, ScanReport};
+use std::path::PathBuf;
+
+/// Two paranoia errors and one warning-level complexity violation.
+fn report() -> ScanReport {
+    let mut config = Config::default();
+    config.parse_toml(
+        "[rules]\nmax_cyclomatic_complexity = 1\n[rules.severity]\ncomplexity = \"warning\"\n",
+    );
+    let src = "fn f() {\n    if true { Some(1).unwrap(); }\n    Some(2).unwrap();\n}\n";
+    RuleEngine::new(config).scan_sources(vec![(PathBuf::from("a.rs"), src.into())])
+}
+
+#[test]
+fn test_fail_on_and_never() {
+    let report = report();
+    assert!(FailPolicy::default().fails(&report));
+    let never = FailPolicy::default().with_flags(Some(FailOn::Never), None);
+    assert!(!never.fails(&report));
+}
+
+#[test]
+fn test_max_violations_counts_only_failing_severities() {
+    let report = report();
+    let policy = |on, max| FailPolicy::default().with_flags(Some(on), Some(max));
+    assert!(!policy(FailOn::Error, 2).fails(&report));
+    assert!(policy(FailOn::Error, 1).fails(&report));
+    assert!(policy(FailOn::Warning, 2).fails(&report));
+    assert!(!policy(FailOn::Warning, 3).fails(&report));
+}
+
+#[test]
+fn test_per_law_allowance_from_config() {
+    let report = report();
+    let mut config = Config::default();
+    config.parse_toml("[rules.fail.per_law]\nparanoia = 2\n");
+    assert!(!config.rules.fail.fails(&report));
+
+    config.parse_toml("[rules.fail]\non = \"warning\"\n[rules.fail.per_law]\nparanoia = 1\n");
+    assert_eq!(config.rules.fail.on, FailOn::Warning);
+    assert!(
+        config.rules.fail.fails(&report),
+        "paranoia is over its allowance"
+    );
+}
@@ -0,0 +1,70 @@
+// tests/unit_history.rs
+//! Tests for scan history and violation diffs.
+
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::Config;
+use slopchop_core::history::diff::{compare, count_changes};
+use slopchop_core::history::{load, trim, Snapshot};
+use slopchop_core::types::ScanReport;
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+fn scan(content: &str) -> ScanReport {
+    let engine = RuleEngine::new(Config::default());
+    engine.scan_sources(vec![(PathBuf::from("a.rs"), content.to_string())])
+}
+
+#[test]
+fn test_snapshot_counts_and_round_trip() {
+    let report = scan("fn f() {\n    Some(1).unwrap();\n    Some(2).unwrap();\n}\n");
+    let snap = Snapshot::from_report(&report);
+    assert_eq!(snap.violations, 2);
+    assert_eq!(snap.laws.get("LAW OF PARANOIA"), Some(&2));
+    assert_eq!(snap.files.get(&PathBuf::from("a.rs")), Some(&2));
+
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("history.jsonl");
+    assert!(load(&path).unwrap().is_empty());
+    snap.append(&path).unwrap();
+    snap.append(&path).unwrap();
+    assert_eq!(load(&path).unwrap(), vec![snap.clone(), snap]);
+}
+
+#[test]
+fn test_trim_keeps_newest_snapshots() {
+    let dir = tempfile::TempDir::new().unwrap();
+    let path = dir.path().join("history.jsonl");
+    let mut snap = Snapshot::from_report(&scan("fn f() {}\n"));
+    for timestamp in 0..5 {
+        snap.timestamp = timestamp;
+        snap.append(&path).unwrap();
+    }
+    trim(&path, 3).unwrap();
+    let kept: Vec<u64> = load(&path).unwrap().iter().map(|s| s.timestamp).collect();
+    assert_eq!(kept, vec![2, 3, 4]);
+
+    trim(&path, 10).unwrap();
+    assert_eq!(load(&path).unwrap().len(), 3);
+}
+
+#[test]
+fn test_compare_ignores_moved_code() {
+    let old = scan("fn f() {\n    Some(1).unwrap();\n}\n");
+    let new = scan("// moved\n\nfn f() {\n    Some(1).unwrap();\n}\nfn g() {\n    None::<u8>.expect(\"x\");\n}\n");
+
+    let diff = compare(&old, &new);
+    assert!(diff.fixed.is_empty());
+    assert_eq!(diff.introduced.len(), 1);
+    assert_eq!(diff.introduced[0].1.row, 6);
+
+    let back = compare(&new, &old);
+    assert_eq!(back.fixed.len(), 1);
+    assert!(back.introduced.is_empty());
+}
+
+#[test]
+fn test_count_changes_lists_only_differences() {
+    let old = BTreeMap::from([("a", 1), ("b", 2)]);
+    let new = BTreeMap::from([("b", 2), ("c", 3)]);
+    assert_eq!(count_changes(&old, &new), vec![("a", 1, 0), ("c", 0, 3)]);
+}
@@ -0,0 +1,50 @@
+// tests/unit_hotspots.rs
+//! Tests for hotspot ranking.
+
+use slopchop_core::hotspots::rank;
+use slopchop_core::types::{FileReport, ScanReport, Severity, Violation};
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+fn file(path: &str, complexity: usize, violations: usize) -> FileReport {
+    let violation = Violation {
+        row: 0,
+        message: "x".into(),
+        law: "LAW OF COMPLEXITY",
+        severity: Severity::Error,
+    };
+    FileReport {
+        path: PathBuf::from(path),
+        token_count: 0,
+        complexity_score: complexity,
+        violations: vec![violation; violations],
+        suppressed: vec![],
+        baselined: vec![],
+        debt: vec![],
+        metrics: None,
+        blame: BTreeMap::new(),
+    }
+}
+
+#[test]
+fn test_rank_weighs_churn_complexity_and_violations() {
+    let report = ScanReport {
+        files: vec![
+            file("calm.rs", 40, 0),
+            file("hot.rs", 10, 2),
+            file("frozen.rs", 90, 3),
+        ],
+        ..ScanReport::default()
+    };
+    let churn = HashMap::from([(PathBuf::from("calm.rs"), 1), (PathBuf::from("hot.rs"), 5)]);
+
+    let spots = rank(&report, &churn);
+    let order: Vec<_> = spots.iter().map(|h| h.path.to_str().unwrap()).collect();
+    assert_eq!(
+        order,
+        vec!["hot.rs", "calm.rs"],
+        "Unchanged files are left out"
+    );
+    assert_eq!(spots[0].score, 5 * (10 + 2 * 5));
+    assert_eq!(spots[1].score, 40);
+}
@@ -0,0 +1,76 @@
+// tests/unit_ignore.rs
+use slopchop_core::ignore::IgnoreSet;
+
+#[test]
+fn test_unanchored_name_matches_at_any_depth() {
+    let set = IgnoreSet::parse("target\n*.log\n");
+    assert!(set.is_ignored("target/debug/app"));
+    assert!(set.is_ignored("crates/a/target/out.rs"));
+    assert!(set.is_ignored("app.log"));
+    assert!(set.is_ignored("logs/today/app.log"));
+    assert!(!set.is_ignored("src/targets.rs"));
+    assert!(!set.is_ignored("src/main.rs"));
+}
+
+#[test]
+fn test_slash_anchors_to_root() {
+    let set = IgnoreSet::parse("/build\ndocs/*.md\n");
+    assert!(set.is_ignored("build/out.js"));
+    assert!(!set.is_ignored("web/build/out.js"));
+    assert!(set.is_ignored("docs/intro.md"));
+    assert!(!set.is_ignored("docs/api/intro.md"));
+    assert!(!set.is_ignored("web/docs/intro.md"));
+}
+
+#[test]
+fn test_trailing_slash_matches_directories_only() {
+    let set = IgnoreSet::parse("vendor/\n");
+    assert!(set.is_ignored("vendor/lib.rs"));
+    assert!(set.is_ignored("src/vendor/lib.rs"));
+    assert!(!set.is_ignored("src/vendor"));
+}
+
+#[test]
+fn test_double_star() {
+    let set = IgnoreSet::parse("**/gen/**\na/**/b.rs\n");
+    assert!(set.is_ignored("gen/x.rs"));
+    assert!(set.is_ignored("src/gen/deep/x.rs"));
+    assert!(set.is_ignored("a/b.rs"));
+    assert!(set.is_ignored("a/x/y/b.rs"));
+    assert!(!set.is_ignored("x/a/b.rs"));
+}
+
+#[test]
+fn test_negation_last_match_wins() {
+    let set = IgnoreSet::parse("src/*\n!src/keep.rs\n");
+    assert!(set.is_ignored("src/drop.rs"));
+    assert!(!set.is_ignored("src/keep.rs"));
+
+    let set = IgnoreSet::parse("!src/keep.rs\nsrc/*\n");
+    assert!(set.is_ignored("src/keep.rs"));
+}
+
+#[test]
+fn test_negation_cannot_reach_into_ignored_directory() {
+    let set = IgnoreSet::parse("src/\n!src/keep.rs\n");
+    assert!(set.is_ignored("src/keep.rs"));
+}
+
+#[test]
+fn test_classes_escapes_and_comments() {
+    let set = IgnoreSet::parse("# comment\n\nfile[0-9].txt\n\\#notes\n\\!bang\n");
+    assert!(set.is_ignored("file1.txt"));
+    assert!(!set.is_ignored("filex.txt"));
+    assert!(set.is_ignored("#notes"));
+    assert!(set.is_ignored("!bang"));
+    assert!(!set.is_ignored("comment"));
+}
+
+#[test]
+fn test_paths_are_normalized() {
+    let set = IgnoreSet::from_patterns(&["/out"]);
+    assert!(set.is_ignored("./out/a.js"));
+    assert!(set.is_ignored("out\\a.js"));
+    assert!(IgnoreSet::default().is_empty());
+    assert!(!IgnoreSet::default().is_ignored("anything"));
+}
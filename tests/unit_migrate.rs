@@ -0,0 +1,141 @@
+// tests/unit_migrate.rs
+use slopchop_core::migrate;
+use slopchop_core::roadmap_v2::TaskStore;
+use std::fs;
+use tempfile::TempDir;
+
+const LEGACY: &str = r#"# project config
+[rules]
+max_file_tokens = 1500
+
+[meta]
+title = "Legacy"
+
+[[sections]]
+id = "core"
+title = "Core"
+
+[[tasks]]
+id = "parse"
+text = "Parse input"
+section = "core"
+
+[[tasks]]
+id = "emit"
+text = "Emit output"
+section = "core"
+"#;
+
+fn project(config: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("warden.toml"), config).unwrap();
+    dir
+}
+
+fn read(dir: &TempDir, name: &str) -> String {
+    fs::read_to_string(dir.path().join(name)).unwrap()
+}
+
+#[test]
+fn test_moves_roadmap_out_of_config() {
+    let dir = project(LEGACY);
+    let changes = migrate::run_in(dir.path(), false).unwrap();
+    assert_eq!(
+        changes,
+        vec![
+            "tasks.toml: added 2 task(s) and 1 section(s) from warden.toml",
+            "warden.toml: removed `meta`, `sections`, `tasks`",
+        ]
+    );
+
+    let config = read(&dir, "warden.toml");
+    assert!(config.starts_with("# project config\n[rules]\nmax_file_tokens = 1500\n"));
+    assert!(!config.contains("tasks"));
+
+    let store = TaskStore::load(Some(&dir.path().join("tasks.toml"))).unwrap();
+    assert_eq!(store.meta.title, "Legacy");
+    assert_eq!(store.tasks.len(), 2);
+}
+
+#[test]
+fn test_keeps_existing_tasks() {
+    let dir = project(LEGACY);
+    fs::write(
+        dir.path().join("tasks.toml"),
+        "[meta]\ntitle = \"Current\"\n\n[[tasks]]\nid = \"parse\"\ntext = \"Parse input (v2)\"\nsection = \"core\"\n",
+    )
+    .unwrap();
+    let changes = migrate::run_in(dir.path(), false).unwrap();
+    assert_eq!(
+        changes[0],
+        "tasks.toml: added 1 task(s) and 1 section(s) from warden.toml (1 already there)"
+    );
+
+    let store = TaskStore::load(Some(&dir.path().join("tasks.toml"))).unwrap();
+    assert_eq!(store.meta.title, "Current");
+    let texts: Vec<&str> = store.tasks.iter().map(|t| t.text.as_str()).collect();
+    assert_eq!(texts, vec!["Parse input (v2)", "Emit output"]);
+}
+
+#[test]
+fn test_dry_run_writes_nothing() {
+    let dir = project(LEGACY);
+    let changes = migrate::run_in(dir.path(), true).unwrap();
+    assert_eq!(changes.len(), 2);
+    assert_eq!(read(&dir, "warden.toml"), LEGACY);
+    assert!(!dir.path().join("tasks.toml").exists());
+}
+
+#[test]
+fn test_nothing_to_migrate() {
+    let dir = project("[rules]\nmax_file_tokens = 1500\n");
+    assert!(migrate::run_in(dir.path(), false).unwrap().is_empty());
+    assert!(migrate::run_in(TempDir::new().unwrap().path(), false)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_tasks_without_meta() {
+    let dir = project("[[tasks]]\nid = \"a\"\ntext = \"A\"\nsection = \"s\"\n");
+    migrate::run_in(dir.path(), false).unwrap();
+    let store = TaskStore::load(Some(&dir.path().join("tasks.toml"))).unwrap();
+    assert_eq!(store.tasks.len(), 1);
+    assert_eq!(read(&dir, "warden.toml").trim(), "");
+}
+
+#[test]
+fn test_converts_roadmap_markdown() {
+    let dir = project("[rules]\nmax_file_tokens = 1500\n");
+    let markdown = "# Plan\n\n## Core\n- [x] Parse input\n  - [ ] Parse flags\n- [ ] Emit output\n";
+    fs::write(dir.path().join("ROADMAP.md"), markdown).unwrap();
+
+    let changes = migrate::run_in(dir.path(), true).unwrap();
+    assert_eq!(
+        changes,
+        vec!["tasks.toml: added 3 task(s) and 1 section(s) from ROADMAP.md"]
+    );
+    assert!(!dir.path().join("tasks.toml").exists());
+
+    migrate::run_in(dir.path(), false).unwrap();
+    let store = TaskStore::load(Some(&dir.path().join("tasks.toml"))).unwrap();
+    assert_eq!(store.meta.title, "Plan");
+    assert_eq!(store.tasks.len(), 3);
+    assert_eq!(store.tasks[1].parent.as_deref(), Some("parse-input"));
+    assert_eq!(read(&dir, "ROADMAP.md"), markdown);
+    assert!(migrate::run_in(dir.path(), false).unwrap().is_empty());
+}
+
+#[test]
+fn test_removes_deprecated_options() {
+    let dir = project("[defaults.apply]\nreview = true\n# skip the prompt\nforce = true\n");
+    let changes = migrate::run_in(dir.path(), false).unwrap();
+    assert_eq!(
+        changes,
+        vec!["warden.toml: removed deprecated `defaults.apply.force`"]
+    );
+    assert_eq!(
+        read(&dir, "warden.toml"),
+        "[defaults.apply]\nreview = true\n"
+    );
+}
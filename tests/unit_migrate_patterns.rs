@@ -0,0 +1,103 @@
+// tests/unit_migrate_patterns.rs
+//! `slopchop migrate` and the load-time warning for ignore patterns
+//! written as the regexes older versions took.
+
+use slopchop_core::config::Config;
+use slopchop_core::migrate::{
+    self,
+    legacy::{self, legacy_glob, looks_like_regex},
+};
+use slopchop_core::project::{self, ProjectType, Strictness};
+use std::fs;
+use tempfile::TempDir;
+
+fn project(config: &str) -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("warden.toml"), config).unwrap();
+    dir
+}
+
+fn read(dir: &TempDir, name: &str) -> String {
+    fs::read_to_string(dir.path().join(name)).unwrap()
+}
+
+#[test]
+fn test_rewrites_legacy_ignore_patterns() {
+    let dir = project(
+        "[rules]\n# exempt generated code\nignore_tokens_on = [\"lock\", \"^gen/.*\", \"tests\"]\nignore_panics_on = [\"(a|b)\"]\n",
+    );
+    fs::write(
+        dir.path().join(".wardenignore"),
+        "# logs\n.*\\.log\n!keep\\.log$\n/build\n",
+    )
+    .unwrap();
+    fs::create_dir_all(dir.path().join("tests")).unwrap();
+    fs::write(dir.path().join("tests/a.rs"), "").unwrap();
+    fs::write(dir.path().join("deps.lock"), "").unwrap();
+
+    let changes = migrate::run_in(dir.path(), false).unwrap();
+    assert_eq!(
+        changes,
+        vec![
+            ".wardenignore: `.*\\.log` -> `*.log`",
+            ".wardenignore: `!keep\\.log$` -> `!*keep.log`",
+            "warden.toml: ignore_tokens_on `lock` -> `*lock*`",
+            "warden.toml: ignore_tokens_on `^gen/.*` -> `gen/*`",
+        ]
+    );
+    assert_eq!(
+        read(&dir, ".wardenignore"),
+        "# logs\n*.log\n!*keep.log\n/build\n"
+    );
+    assert_eq!(
+        read(&dir, "warden.toml"),
+        "[rules]\n# exempt generated code\nignore_tokens_on = [\"*lock*\", \"gen/*\", \"tests\"]\nignore_panics_on = [\"(a|b)\"]\n"
+    );
+    assert!(migrate::run_in(dir.path(), false).unwrap().is_empty());
+}
+
+#[test]
+fn test_legacy_regexes_become_globs() {
+    assert_eq!(legacy_glob(r".*\.log").as_deref(), Some("*.log"));
+    assert_eq!(legacy_glob(r"\.lock$").as_deref(), Some("*.lock"));
+    assert_eq!(legacy_glob("^target").as_deref(), Some("/target"));
+    assert_eq!(legacy_glob("^src/gen/.*").as_deref(), Some("src/gen/*"));
+    assert_eq!(legacy_glob(r"a.+\.rs").as_deref(), Some("*a?*.rs"));
+    assert!(looks_like_regex("(foo|bar)"));
+    assert_eq!(legacy_glob("(foo|bar)"), None);
+    assert_eq!(legacy_glob(r"\d+\.txt"), None);
+    assert_eq!(legacy_glob(".*"), None);
+    assert!(!looks_like_regex("*.log"));
+    assert!(!looks_like_regex("/build"));
+    assert_eq!(legacy_glob("tests"), None);
+}
+
+#[test]
+fn test_globs_are_not_legacy_regexes() {
+    for glob in [
+        "*-lock.*",
+        "*.spec.*",
+        "*_test.*",
+        "*.{js,ts}",
+        "src/**/*.rs",
+    ] {
+        assert!(!looks_like_regex(glob), "{glob}");
+    }
+    assert!(legacy::warnings(&Config::default(), "").is_empty());
+
+    let mut generated = Config::new();
+    generated.parse_toml(&project::generate_toml(
+        ProjectType::Node,
+        Strictness::Standard,
+    ));
+    assert!(legacy::warnings(&generated, "*.{js,ts}\n").is_empty());
+    let warned = legacy::warnings(&generated, ".*\\.log\n");
+    assert_eq!(warned.len(), 1, "{warned:?}");
+}
+
+#[test]
+fn test_migrate_leaves_globs_alone() {
+    let dir = project("[rules]\nignore_naming_on = [\"*.spec.*\", \"*_test.*\"]\nignore_tokens_on = [\"*-lock.*\"]\n");
+    fs::write(dir.path().join(".wardenignore"), "*.{js,ts}\n*-lock.*\n").unwrap();
+    assert!(migrate::run_in(dir.path(), true).unwrap().is_empty());
+}
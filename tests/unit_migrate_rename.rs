@@ -0,0 +1,81 @@
+// tests/unit_migrate_rename.rs
+//! `slopchop migrate` renaming the files older versions kept under
+//! slopchop names, and those names still being read until it does.
+
+use slopchop_core::config::files::{self, CONFIG_FILE, IGNORE_FILE, INTENT_FILE};
+use slopchop_core::config::Config;
+use slopchop_core::migrate;
+use slopchop_core::workspace::Workspace;
+use std::fs;
+use tempfile::TempDir;
+
+fn legacy_project() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    fs::write(dir.path().join("slopchop.toml"), "[rules]\n").unwrap();
+    fs::write(dir.path().join(".slopchopignore"), "/build\n").unwrap();
+    fs::write(dir.path().join(".slopchop_intent"), "goal").unwrap();
+    fs::create_dir_all(dir.path().join("pkg")).unwrap();
+    fs::write(dir.path().join("pkg/slopchop.toml"), "").unwrap();
+    dir
+}
+
+#[test]
+fn test_renames_legacy_files() {
+    let dir = legacy_project();
+    let changes = migrate::run_in(dir.path(), false).unwrap();
+    assert_eq!(
+        changes,
+        vec![
+            "slopchop.toml: renamed to warden.toml",
+            ".slopchopignore: renamed to .wardenignore",
+            ".slopchop_intent: renamed to .warden_intent",
+            "pkg/slopchop.toml: renamed to pkg/warden.toml",
+        ]
+    );
+    for name in [CONFIG_FILE, IGNORE_FILE, INTENT_FILE, "pkg/warden.toml"] {
+        assert!(dir.path().join(name).exists(), "{name}");
+    }
+    assert!(!dir.path().join("slopchop.toml").exists());
+    assert_eq!(
+        fs::read_to_string(dir.path().join(IGNORE_FILE)).unwrap(),
+        "/build\n"
+    );
+    assert!(migrate::run_in(dir.path(), false).unwrap().is_empty());
+}
+
+#[test]
+fn test_dry_run_keeps_legacy_names() {
+    let dir = legacy_project();
+    let changes = migrate::run_in(dir.path(), true).unwrap();
+    assert_eq!(changes.len(), 4);
+    assert!(dir.path().join("slopchop.toml").exists());
+    assert!(!dir.path().join(CONFIG_FILE).exists());
+}
+
+#[test]
+fn test_current_name_wins() {
+    let dir = legacy_project();
+    fs::write(dir.path().join(CONFIG_FILE), "").unwrap();
+    assert_eq!(
+        files::find(dir.path(), CONFIG_FILE),
+        dir.path().join(CONFIG_FILE)
+    );
+
+    let changes = migrate::run_in(dir.path(), false).unwrap();
+    assert_eq!(
+        changes[0],
+        "slopchop.toml: kept, warden.toml exists and is read instead"
+    );
+    assert!(dir.path().join("slopchop.toml").exists());
+}
+
+#[test]
+fn test_legacy_names_still_read() {
+    let dir = legacy_project();
+    assert_eq!(
+        files::find(dir.path(), IGNORE_FILE),
+        dir.path().join(".slopchopignore")
+    );
+    let workspace = Workspace::load_in(dir.path(), Config::default());
+    assert_eq!(workspace.packages.len(), 1);
+}
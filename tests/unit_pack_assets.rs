@@ -0,0 +1,67 @@
+// tests/unit_pack_assets.rs
+use slopchop_core::config::{Config, FileFilter, GitMode};
+use slopchop_core::pack::assets::{self, Asset};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::tempdir;
+
+#[test]
+fn test_kind_by_extension_then_content() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let blob = d.path().join("blob.dat");
+    fs::write(&blob, [0u8, 1, 2, 3])?;
+    let sql = d.path().join("001_init.sql");
+    fs::write(&sql, "create table t (id int);\n")?;
+
+    assert_eq!(assets::kind(Path::new("logo.PNG")), "image");
+    assert_eq!(assets::kind(Path::new("app.wasm")), "compiled");
+    assert_eq!(assets::kind(&blob), "binary");
+    assert_eq!(assets::kind(&sql), "text");
+    Ok(())
+}
+
+#[test]
+fn test_render_aligns_paths() {
+    let list = assets::render(&[
+        Asset {
+            path: PathBuf::from("assets/logo.png"),
+            bytes: 2048,
+            kind: "image",
+        },
+        Asset {
+            path: PathBuf::from("db.sqlite"),
+            bytes: 300,
+            kind: "binary",
+        },
+    ]);
+    assert_eq!(
+        list,
+        "assets/logo.png  image     2.0 KB\ndb.sqlite        binary    300 B\n"
+    );
+}
+
+// The only test here that changes the working directory.
+#[test]
+fn test_collect_lists_skipped_files_but_not_secrets() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("main.rs"), "fn main() {}\n")?;
+    fs::write(d.path().join("logo.png"), [0x89u8, b'P', b'N', b'G', 0])?;
+    fs::write(d.path().join("server.pem"), "-----BEGIN-----\n")?;
+    let home = env::current_dir()?;
+    env::set_current_dir(d.path())?;
+
+    let config = Config {
+        files: FileFilter {
+            git_mode: GitMode::No,
+            ..FileFilter::default()
+        },
+        ..Config::default()
+    };
+    let found = assets::collect(&config, &[PathBuf::from("main.rs")]);
+    env::set_current_dir(home)?;
+
+    let paths: Vec<PathBuf> = found?.into_iter().map(|a| a.path).collect();
+    assert_eq!(paths, [PathBuf::from("logo.png")]);
+    Ok(())
+}
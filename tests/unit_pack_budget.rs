@@ -0,0 +1,88 @@
+// tests/unit_pack_budget.rs
+use slopchop_core::config::Config;
+use slopchop_core::pack::budget::{self, Budget, Entry, Tier, TrimAction};
+use slopchop_core::pack::PackOptions;
+use std::collections::HashSet;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::tempdir;
+
+fn entry(path: &str, tier: Tier, full: usize, skeleton: usize) -> Entry {
+    Entry {
+        path: PathBuf::from(path),
+        tier,
+        full,
+        skeleton,
+        skeletonized: false,
+    }
+}
+
+#[test]
+fn test_tier_priority_order() {
+    let changed: HashSet<PathBuf> = [PathBuf::from("src/hot.rs")].into();
+    let tier = |p: &str, focused| budget::tier(Path::new(p), focused, &changed);
+    assert_eq!(tier("src/a.rs", true), Tier::Focused);
+    assert_eq!(tier("src/hot.rs", false), Tier::Changed);
+    assert_eq!(tier("src/a.rs", false), Tier::Core);
+    assert_eq!(tier("build.sh", false), Tier::Other);
+    assert_eq!(tier("tests/a.rs", false), Tier::Tests);
+    assert_eq!(tier("README.md", false), Tier::Docs);
+    assert!(Tier::Docs < Tier::Tests && Tier::Core < Tier::Focused);
+}
+
+#[test]
+fn test_plan_trims_lowest_priority_first() {
+    let entries = [
+        entry("src/main.rs", Tier::Core, 1000, 200),
+        entry("tests/a.rs", Tier::Tests, 500, 100),
+        entry("README.md", Tier::Docs, 300, 300),
+    ];
+    let trims = budget::plan(&entries, 600);
+    let summary: Vec<_> = trims.iter().map(|t| (t.path.to_str(), t.action)).collect();
+    assert_eq!(
+        summary,
+        [
+            (Some("README.md"), TrimAction::Drop),
+            (Some("tests/a.rs"), TrimAction::Skeleton),
+        ]
+    );
+}
+
+#[test]
+fn test_plan_drops_skeletons_when_still_over() {
+    let entries = [
+        entry("src/main.rs", Tier::Core, 1000, 200),
+        entry("src/focus.rs", Tier::Focused, 1000, 200),
+    ];
+    let trims = budget::plan(&entries, 1700);
+    assert_eq!(trims[0].action, TrimAction::Drop);
+    assert_eq!(trims[0].saved, 1000);
+    assert_eq!(trims[1].action, TrimAction::Skeleton);
+}
+
+#[test]
+fn test_generate_fits_budget() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let code = d.path().join("lib.rs");
+    let body = "    let x = 1;\n".repeat(200);
+    fs::write(&code, format!("fn big() {{\n{body}}}\n"))?;
+    let notes = d.path().join("notes.md");
+    fs::write(&notes, "word ".repeat(500))?;
+
+    let opts = PackOptions {
+        budget: Budget {
+            limit: Some(300),
+            ..Budget::default()
+        },
+        ..PackOptions::default()
+    };
+    let files = [code, notes];
+    let (content, trims) = budget::generate(&files, &opts, &Config::default())?;
+
+    assert!(!trims.is_empty());
+    assert!(!content.contains("word word"));
+    let unlimited = budget::generate(&files, &PackOptions::default(), &Config::default())?;
+    assert!(unlimited.1.is_empty());
+    assert!(unlimited.0.len() > content.len());
+    Ok(())
+}
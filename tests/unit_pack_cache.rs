@@ -0,0 +1,34 @@
+// tests/unit_pack_cache.rs
+use slopchop_core::pack::cache;
+use slopchop_core::skeleton;
+use slopchop_core::tokens::Tokenizer;
+use std::path::Path;
+
+const SOURCE: &str = "pub fn add(a: u32, b: u32) -> u32 {\n    a + b\n}\n";
+
+#[test]
+fn test_cached_values_match_fresh_ones() {
+    let path = Path::new("src/cache_fixture.rs");
+    for _ in 0..2 {
+        assert_eq!(cache::tokens(path, SOURCE), Tokenizer::count(SOURCE));
+        assert_eq!(cache::skeleton(path, SOURCE), skeleton::clean(path, SOURCE));
+        let skel = skeleton::clean(path, SOURCE);
+        assert_eq!(
+            cache::skeleton_tokens(path, SOURCE),
+            Tokenizer::count(&skel)
+        );
+    }
+}
+
+#[test]
+fn test_entries_follow_content_and_path() {
+    let path = Path::new("src/cache_changed.rs");
+    let edited = format!("{SOURCE}pub fn sub(a: u32, b: u32) -> u32 {{\n    a - b\n}}\n");
+    assert_eq!(cache::tokens(path, SOURCE), Tokenizer::count(SOURCE));
+    assert_eq!(cache::tokens(path, &edited), Tokenizer::count(&edited));
+
+    // Skeletons depend on the language, which comes from the path.
+    let text = Path::new("notes.txt");
+    assert_eq!(cache::skeleton(text, SOURCE), SOURCE);
+    assert_ne!(cache::skeleton(path, SOURCE), SOURCE);
+}
@@ -0,0 +1,68 @@
+// tests/unit_pack_chunk.rs
+use slopchop_core::pack::chunk;
+use std::fmt::Write as _;
+use std::fs;
+use tempfile::tempdir;
+
+fn pack_of(files: usize) -> String {
+    let body = "fn f() { let x = 1; }\n".repeat(20);
+    (0..files).fold(String::new(), |mut out, i| {
+        let _ = write!(
+            out,
+            "#__SLOPCHOP_FILE__# f{i}.rs\n{body}#__SLOPCHOP_END__#\n\n"
+        );
+        out
+    })
+}
+
+#[test]
+fn test_split_breaks_between_files_with_headers() {
+    let parts = chunk::split(&pack_of(6), 500);
+    assert!(parts.len() > 1);
+    let total = parts.len();
+    for (i, part) in parts.iter().enumerate() {
+        assert!(part.starts_with(&format!("# ═══ CONTEXT PART {} OF {total} ═══", i + 1)));
+        let opened = part.matches("#__SLOPCHOP_FILE__#").count();
+        assert_eq!(opened, part.matches("#__SLOPCHOP_END__#").count());
+    }
+    assert!(parts[0].contains("More parts follow"));
+    assert!(parts[total - 1].contains("last part"));
+}
+
+#[test]
+fn test_split_keeps_every_line() {
+    let content = pack_of(5);
+    let parts = chunk::split(&content, 400);
+    for line in content
+        .lines()
+        .filter(|l| l.starts_with("#__SLOPCHOP_FILE__#"))
+    {
+        assert_eq!(parts.iter().filter(|p| p.contains(line)).count(), 1);
+    }
+}
+
+#[test]
+fn test_split_cuts_a_single_oversized_file() {
+    let big = format!(
+        "#__SLOPCHOP_FILE__# big.rs\n{}#__SLOPCHOP_END__#\n",
+        "let x = 1;\n".repeat(400)
+    );
+    assert!(chunk::split(&big, 300).len() > 1);
+}
+
+#[test]
+fn test_write_parts_removes_stale_parts() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    for n in 1..=4 {
+        fs::write(d.path().join(format!("context_part{n}.txt")), "old")?;
+    }
+    let paths = chunk::write_parts(&["one".into(), "two".into()], d.path())?;
+    assert_eq!(paths.len(), 2);
+    assert_eq!(
+        fs::read_to_string(d.path().join("context_part2.txt"))?,
+        "two"
+    );
+    assert!(!d.path().join("context_part3.txt").exists());
+    assert!(!d.path().join("context_part4.txt").exists());
+    Ok(())
+}
@@ -0,0 +1,111 @@
+// tests/unit_pack_comments.rs
+use slopchop_core::apply::types::ApplyOutcome;
+use slopchop_core::apply::validator;
+use slopchop_core::config::{Config, TruncationSettings};
+use slopchop_core::pack::comments::{self, StripComments};
+use slopchop_core::pack::{self, PackExtras, PackOptions};
+use std::fs;
+use std::path::Path;
+use tempfile::tempdir;
+
+const RUST: &str = "//! Crate docs.\n\n// setup\n/// Adds.\nfn add(a: u8) -> u8 {\n    a + 1 // bump\n}\n/* block\n   comment */\nconst S: &str = \"// not a comment\";\n";
+
+#[test]
+fn test_keep_docs_strips_plain_comments() {
+    let out = comments::strip(Path::new("lib.rs"), RUST, StripComments::KeepDocs);
+    assert_eq!(
+        out,
+        "//! Crate docs.\n\n/// Adds.\nfn add(a: u8) -> u8 {\n    a + 1\n}\nconst S: &str = \"// not a comment\";\n"
+    );
+}
+
+#[test]
+fn test_all_strips_doc_comments() {
+    let out = comments::strip(Path::new("lib.rs"), RUST, StripComments::All);
+    assert!(!out.contains("docs"));
+    assert!(!out.contains("Adds"));
+    assert!(out.contains("\"// not a comment\""));
+}
+
+#[test]
+fn test_other_languages_and_shebang() {
+    let py = "#!/usr/bin/env python3\n# note\nx = 1  # trailing\n";
+    let out = comments::strip(Path::new("run.py"), py, StripComments::KeepDocs);
+    assert_eq!(out, "#!/usr/bin/env python3\nx = 1\n");
+
+    let text = "# not code\n";
+    assert_eq!(
+        comments::strip(Path::new("notes.md"), text, StripComments::All),
+        text
+    );
+}
+
+#[test]
+fn test_line_numbers_survive_stripping() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let file = d.path().join("lib.rs");
+    fs::write(&file, RUST)?;
+    let opts = PackOptions {
+        extras: PackExtras {
+            line_numbers: true,
+            strip_comments: StripComments::KeepDocs,
+            ..PackExtras::default()
+        },
+        ..PackOptions::default()
+    };
+    let content = pack::generate_content(&[file], &opts, &Config::default())?;
+    assert!(content.contains(" 4 | /// Adds.\n 5 | fn add"));
+    assert!(content.contains("10 | const S"));
+    assert!(!content.contains("setup"));
+    Ok(())
+}
+
+#[test]
+fn test_stripped_files_are_marked() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let (lib, plain) = (d.path().join("lib.rs"), d.path().join("plain.rs"));
+    fs::write(&lib, RUST)?;
+    fs::write(&plain, "fn plain() {}\n")?;
+    let opts = PackOptions {
+        prompt: true,
+        extras: PackExtras {
+            strip_comments: StripComments::KeepDocs,
+            ..PackExtras::default()
+        },
+        ..PackOptions::default()
+    };
+    let content = pack::generate_content(&[lib, plain], &opts, &Config::default())?;
+    assert!(content.contains(comments::NOTE));
+    assert_eq!(content.matches("] [STRIPPED]\n").count(), 1);
+    Ok(())
+}
+
+#[test]
+fn test_whole_file_replies_to_stripped_files_are_rejected() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    fs::write(d.path().join("lib.rs"), RUST)?;
+    comments::record(d.path(), &["lib.rs".to_string()])?;
+    let check = |payload: &str| {
+        validator::validate_payload(payload, Some(d.path()), &TruncationSettings::default())
+    };
+
+    let whole = "#__SLOPCHOP_MANIFEST__#\nlib.rs\n#__SLOPCHOP_END__#\n\
+                 #__SLOPCHOP_FILE__# lib.rs\nfn add(a: u8) -> u8 {\n    a + 2\n}\n#__SLOPCHOP_END__#\n";
+    let ApplyOutcome::ValidationFailure { errors, .. } = check(whole) else {
+        panic!("whole file should be rejected");
+    };
+    assert!(errors[0].contains("comments stripped"), "{errors:?}");
+
+    let edit = "#__SLOPCHOP_MANIFEST__#\nlib.rs\n#__SLOPCHOP_END__#\n\
+                #__SLOPCHOP_EDIT__# lib.rs\n<<<<<<< SEARCH\n    a + 1 // bump\n=======\n    a + 2 // bump\n>>>>>>> REPLACE\n\
+                #__SLOPCHOP_END__#\n";
+    let outcome = check(edit);
+    assert!(
+        matches!(outcome, ApplyOutcome::Success { .. }),
+        "{outcome:?}"
+    );
+
+    comments::record(d.path(), &[])?;
+    assert!(comments::stripped(d.path()).is_empty());
+    Ok(())
+}
@@ -0,0 +1,52 @@
+// tests/unit_pack_diff.rs
+//! Tests for `pack --diff`. Kept in its own binary because it changes the
+//! working directory.
+
+use slopchop_core::config::Config;
+use slopchop_core::pack::{self, diff, PackOptions};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use tempfile::TempDir;
+
+fn git(dir: &Path, args: &[&str]) {
+    let status = Command::new("git")
+        .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+        .args(args)
+        .current_dir(dir)
+        .output()
+        .unwrap()
+        .status;
+    assert!(status.success(), "git {args:?} failed");
+}
+
+#[test]
+fn test_diff_pack_has_diff_and_changed_files_only() {
+    let dir = TempDir::new().unwrap();
+    let root = dir.path();
+    git(root, &["init", "-q"]);
+    fs::write(root.join("a.rs"), "fn a() {}\n").unwrap();
+    fs::write(root.join("b.rs"), "fn b() {}\n").unwrap();
+    git(root, &["add", "."]);
+    git(root, &["commit", "-qm", "base"]);
+    std::env::set_current_dir(root).unwrap();
+    let files = [PathBuf::from("a.rs"), PathBuf::from("b.rs")];
+    let opts = PackOptions {
+        diff: Some("HEAD".into()),
+        depth: 0,
+        ..PackOptions::default()
+    };
+
+    let err = diff::focus_changes(&opts, &files).unwrap_err();
+    assert!(err.to_string().contains("No changes since HEAD"));
+
+    fs::write(root.join("a.rs"), "fn a() { todo() }\n").unwrap();
+    let opts = diff::focus_changes(&opts, &files).unwrap();
+    assert_eq!(opts.focus, [PathBuf::from("a.rs")]);
+
+    let content = pack::generate_content(&files, &opts, &Config::default()).unwrap();
+    assert!(content.contains("# ═══ DIFF (since HEAD) ═══"));
+    assert!(content.contains("+fn a() { todo() }"));
+    assert!(content.contains("#__SLOPCHOP_FILE__# a.rs"));
+    assert!(!content.contains("#__SLOPCHOP_FILE__# b.rs"));
+}
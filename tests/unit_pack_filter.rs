@@ -0,0 +1,61 @@
+// tests/unit_pack_filter.rs
+use slopchop_core::config::PackSettings;
+use slopchop_core::pack::filter::{self, FileFilter};
+use std::path::{Path, PathBuf};
+
+fn paths(list: &[&str]) -> Vec<PathBuf> {
+    list.iter().map(PathBuf::from).collect()
+}
+
+#[test]
+fn test_is_test() {
+    assert!(filter::is_test(Path::new("tests/unit_pack.rs")));
+    assert!(filter::is_test(Path::new("src/app.spec.ts")));
+    assert!(filter::is_test(Path::new("pkg/server_test.go")));
+    assert!(filter::is_test(Path::new("test_parser.py")));
+    assert!(!filter::is_test(Path::new("src/testing.rs")));
+}
+
+#[test]
+fn test_default_generated_globs() {
+    let generated = PackSettings::default().generated;
+    assert!(filter::is_generated(Path::new("Cargo.lock"), &generated));
+    assert!(filter::is_generated(
+        Path::new("./api/user.pb.go"),
+        &generated
+    ));
+    assert!(filter::is_generated(
+        Path::new("web/dist/app.min.js"),
+        &generated
+    ));
+    assert!(filter::is_generated(
+        Path::new("src/generated/schema.rs"),
+        &generated
+    ));
+    assert!(!filter::is_generated(Path::new("src/main.rs"), &generated));
+}
+
+#[test]
+fn test_apply_only_drops_what_is_asked() {
+    let files = paths(&["src/lib.rs", "tests/it.rs", "schema.gen.ts"]);
+    let generated = vec!["*.gen.ts".to_string()];
+
+    let kept = filter::apply(files.clone(), FileFilter::default(), &generated);
+    assert_eq!(kept, files);
+
+    let no_tests = FileFilter {
+        no_tests: true,
+        ..FileFilter::default()
+    };
+    let kept = filter::apply(files.clone(), no_tests, &generated);
+    assert_eq!(kept, paths(&["src/lib.rs", "schema.gen.ts"]));
+
+    let both = FileFilter {
+        no_generated: true,
+        ..no_tests
+    };
+    assert_eq!(
+        filter::apply(files, both, &generated),
+        paths(&["src/lib.rs"])
+    );
+}
@@ -0,0 +1,57 @@
+// tests/unit_pack_focus.rs
+use slopchop_core::pack::focus;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::tempdir;
+
+fn files() -> Vec<PathBuf> {
+    ["src/main.rs", "src/util.rs"]
+        .iter()
+        .map(PathBuf::from)
+        .collect()
+}
+
+#[test]
+fn test_resolve_accepts_spellings_of_a_file() -> anyhow::Result<()> {
+    let cwd = env::current_dir()?;
+    let spellings = [
+        PathBuf::from("src/util.rs"),
+        PathBuf::from("./src/util.rs"),
+        PathBuf::from("src\\util.rs"),
+        cwd.join("src/util.rs"),
+    ];
+    for spelling in spellings {
+        let resolved = focus::resolve(&[spelling], &files())?;
+        assert_eq!(resolved, [PathBuf::from("src/util.rs")]);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_resolve_rejects_unknown_files() {
+    let err = focus::resolve(&[PathBuf::from("src/missing.rs")], &files());
+    assert!(err.is_err_and(|e| e.to_string().contains("src/missing.rs")));
+}
+
+#[test]
+fn test_depth_expands_through_imports() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let write = |name: &str, text: &str| -> anyhow::Result<PathBuf> {
+        let path = d.path().join(name);
+        fs::write(&path, text)?;
+        Ok(path)
+    };
+    let a = write("a.rs", "use crate::b::Bee;\nfn main() { Bee::new(); }\n")?;
+    let b = write("b.rs", "use crate::c::Sea;\npub struct Bee { s: Sea }\n")?;
+    let c = write("c.rs", "pub struct Sea {}\n")?;
+    let all = [a.clone(), b.clone(), c.clone()];
+
+    let (foveal, near) = focus::compute_sets(&all, std::slice::from_ref(&a), 1);
+    assert!(foveal.contains(&a));
+    assert!(near.contains(&b) && !near.contains(&c));
+
+    let (_, far) = focus::compute_sets(&all, &[a], 2);
+    assert!(far.contains(&b) && far.contains(&c));
+    Ok(())
+}
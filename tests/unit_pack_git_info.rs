@@ -0,0 +1,55 @@
+// tests/unit_pack_git_info.rs
+use slopchop_core::config::Config;
+use slopchop_core::pack::git_info::{self, GitInfo};
+use slopchop_core::pack::{self, PackExtras, PackOptions};
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_render_lists_commits_and_changes() {
+    let info = GitInfo {
+        branch: "main".into(),
+        commits: vec!["abc1234 Add pack order".into()],
+        changes: vec![" M src/lib.rs".into(), "?? notes.md".into()],
+    };
+    let body = git_info::render(&info);
+    assert!(body.starts_with("Branch: main\n"));
+    assert!(body.contains("Recent commits:\n  abc1234 Add pack order\n"));
+    assert!(body.contains("Uncommitted changes (2):\n   M src/lib.rs\n  ?? notes.md\n"));
+}
+
+#[test]
+fn test_render_clean_tree() {
+    let body = git_info::render(&GitInfo {
+        branch: "dev".into(),
+        ..GitInfo::default()
+    });
+    assert!(!body.contains("Recent commits"));
+    assert!(body.contains("Uncommitted changes: none"));
+}
+
+#[test]
+fn test_section_only_with_flag() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let file = d.path().join("a.rs");
+    fs::write(&file, "fn a() {}\n")?;
+    let config = Config::default();
+
+    let plain = pack::generate_content(
+        std::slice::from_ref(&file),
+        &PackOptions::default(),
+        &config,
+    )?;
+    assert!(!plain.contains("═══ GIT ═══"));
+
+    let opts = PackOptions {
+        extras: PackExtras {
+            git_info: Some(3),
+            ..PackExtras::default()
+        },
+        ..Default::default()
+    };
+    let content = pack::generate_content(&[file], &opts, &config)?;
+    assert!(content.contains("# ═══ GIT ═══\n\nBranch: "));
+    Ok(())
+}
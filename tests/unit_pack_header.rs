@@ -0,0 +1,53 @@
+// tests/unit_pack_header.rs
+use slopchop_core::config::Config;
+use slopchop_core::pack::header::{self, HeaderVars};
+use slopchop_core::pack::{self, PackOptions};
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_render_fills_placeholders_once() {
+    let vars = HeaderVars {
+        rules: "RULES".into(),
+        files: "src/{date}.rs\n".into(),
+        date: "2026-01-02".into(),
+        ..HeaderVars::default()
+    };
+    let out = header::render("{rules} on {date}\n{files}{unknown}", &vars);
+    assert_eq!(out, "RULES on 2026-01-02\nsrc/{date}.rs\n{unknown}");
+}
+
+#[test]
+fn test_template_replaces_builtin_header() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let template = d.path().join("header.tmpl");
+    fs::write(&template, "TEAM PROMPT\nFiles:\n{files}---\n")?;
+    let code = d.path().join("a.rs");
+    fs::write(&code, "fn a() {}\n")?;
+
+    let mut config = Config::default();
+    config.pack.header_template = Some(template.to_string_lossy().into_owned());
+    let opts = PackOptions {
+        prompt: true,
+        ..PackOptions::default()
+    };
+    let content = pack::generate_content(std::slice::from_ref(&code), &opts, &config)?;
+
+    let listed = format!("Files:\n{}\n---", code.to_string_lossy().replace('\\', "/"));
+    assert!(content.starts_with("TEAM PROMPT\n"));
+    assert!(content.contains(&listed));
+    assert!(!content.contains("SYSTEM MANDATE"));
+    Ok(())
+}
+
+#[test]
+fn test_missing_template_is_an_error() {
+    let mut config = Config::default();
+    config.pack.header_template = Some("no/such/header.tmpl".into());
+    let opts = PackOptions {
+        prompt: true,
+        ..PackOptions::default()
+    };
+    let err = pack::generate_content(&[], &opts, &config).unwrap_err();
+    assert!(err.to_string().contains("no/such/header.tmpl"));
+}
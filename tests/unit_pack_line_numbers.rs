@@ -0,0 +1,47 @@
+// tests/unit_pack_line_numbers.rs
+use slopchop_core::config::Config;
+use slopchop_core::pack::{self, formats, OutputFormat, PackExtras, PackOptions, SkeletonMode};
+use std::fs;
+use tempfile::tempdir;
+
+const SOURCE: &str = "fn a() {\n    1;\n}\n\n\n\n\n\n\nfn b() {}\n";
+
+#[test]
+fn test_number_lines_aligns_numbers() {
+    let numbered = formats::number_lines(SOURCE);
+    assert!(numbered.starts_with(" 1 | fn a() {\n 2 |     1;\n"));
+    assert!(numbered.ends_with("10 | fn b() {}\n"));
+    assert_eq!(formats::number_lines(""), "");
+}
+
+#[test]
+fn test_only_full_files_are_numbered() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let file = d.path().join("a.rs");
+    fs::write(&file, SOURCE)?;
+    let files = [file];
+    let numbered = PackExtras {
+        line_numbers: true,
+        ..PackExtras::default()
+    };
+
+    for format in [OutputFormat::Text, OutputFormat::Xml, OutputFormat::Openai] {
+        let opts = PackOptions {
+            format,
+            extras: numbered,
+            ..PackOptions::default()
+        };
+        let content = pack::generate_content(&files, &opts, &Config::default())?;
+        assert!(content.contains(" 1 | fn a() {\n"));
+        assert!(content.contains("never include them in replies"));
+    }
+
+    let opts = PackOptions {
+        skeleton: SkeletonMode::All,
+        extras: numbered,
+        ..PackOptions::default()
+    };
+    let content = pack::generate_content(&files, &opts, &Config::default())?;
+    assert!(!content.contains(" 1 | "));
+    Ok(())
+}
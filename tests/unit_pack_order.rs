@@ -0,0 +1,51 @@
+// tests/unit_pack_order.rs
+use slopchop_core::pack::order::{self, FileOrder};
+use std::fs;
+use std::path::PathBuf;
+use tempfile::tempdir;
+
+#[test]
+fn test_path_and_size_orders() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let small = d.path().join("a.rs");
+    let big = d.path().join("b.rs");
+    fs::write(&small, "fn a() {}\n")?;
+    fs::write(&big, "fn b() {}\n".repeat(10))?;
+    let files = [big.clone(), small.clone()];
+
+    assert_eq!(
+        order::sort(&files, FileOrder::Path),
+        [small.clone(), big.clone()]
+    );
+    assert_eq!(order::sort(&files, FileOrder::Size), [big, small]);
+    Ok(())
+}
+
+#[test]
+fn test_topo_puts_dependencies_first() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let main = d.path().join("main.rs");
+    let util = d.path().join("util.rs");
+    fs::write(
+        &main,
+        "use crate::util::helper_value;\nfn main() { helper_value(); }\n",
+    )?;
+    fs::write(&util, "pub fn helper_value() -> u32 { 1 }\n")?;
+
+    let sorted = order::topo(&[main.clone(), util.clone()]);
+    assert_eq!(sorted, [util, main]);
+    Ok(())
+}
+
+#[test]
+fn test_topo_keeps_every_file() {
+    let files: Vec<PathBuf> = ["x.rs", "y.rs", "missing.rs"]
+        .iter()
+        .map(PathBuf::from)
+        .collect();
+    let mut sorted = order::topo(&files);
+    sorted.sort();
+    let mut expected = files;
+    expected.sort();
+    assert_eq!(sorted, expected);
+}
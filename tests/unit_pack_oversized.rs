@@ -0,0 +1,59 @@
+// tests/unit_pack_oversized.rs
+use slopchop_core::config::Config;
+use slopchop_core::pack::{self, OutputFormat, PackOptions, SkeletonMode};
+use std::fs;
+use std::path::PathBuf;
+use tempfile::tempdir;
+
+fn files(dir: &std::path::Path) -> anyhow::Result<[PathBuf; 2]> {
+    let small = dir.join("small.rs");
+    let big = dir.join("big.rs");
+    fs::write(&small, "fn small() { let kept = 1; }\n")?;
+    let body = "    let hidden = 1;\n".repeat(100);
+    fs::write(&big, format!("fn big() {{\n{body}}}\n"))?;
+    Ok([small, big])
+}
+
+#[test]
+fn test_oversized_files_are_marked_skeletons() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let files = files(d.path())?;
+    let opts = PackOptions {
+        skeleton: SkeletonMode::Over(200),
+        ..PackOptions::default()
+    };
+    let content = pack::generate_content(&files, &opts, &Config::default())?;
+
+    assert!(content.contains("let kept = 1;"));
+    assert!(!content.contains("let hidden = 1;"));
+    assert!(content.contains("tokens; ask for the full file to edit it]"));
+    Ok(())
+}
+
+#[test]
+fn test_oversized_files_are_marked_in_xml() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let files = files(d.path())?;
+    let opts = PackOptions {
+        skeleton: SkeletonMode::Over(200),
+        format: OutputFormat::Xml,
+        ..PackOptions::default()
+    };
+    let content = pack::generate_content(&files, &opts, &Config::default())?;
+
+    assert!(content.contains("skeleton=\"oversized\""));
+    assert!(!content.contains("let hidden = 1;"));
+    Ok(())
+}
+
+#[test]
+fn test_no_threshold_packs_everything_in_full() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let content = pack::generate_content(
+        &files(d.path())?,
+        &PackOptions::default(),
+        &Config::default(),
+    )?;
+    assert!(content.contains("let hidden = 1;"));
+    Ok(())
+}
@@ -0,0 +1,75 @@
+// tests/unit_pack_presets.rs
+use slopchop_core::apply::manifest;
+use slopchop_core::config::Config;
+use slopchop_core::pack::{self, presets, stats, OutputFormat, PackOptions};
+use std::fs;
+use std::path::PathBuf;
+use tempfile::tempdir;
+
+fn pack_as(format: OutputFormat, files: &[PathBuf]) -> anyhow::Result<String> {
+    let opts = PackOptions {
+        format,
+        ..PackOptions::default()
+    };
+    pack::generate_content(files, &opts, &Config::default())
+}
+
+#[test]
+fn test_claude_xml_uses_document_tags() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let a = d.path().join("a.rs");
+    fs::write(&a, "fn a() {}\n")?;
+    let content = pack_as(OutputFormat::ClaudeXml, std::slice::from_ref(&a))?;
+
+    let source = format!(
+        "<source>{} [base:{}]</source>",
+        a.to_string_lossy().replace('\\', "/"),
+        manifest::base_hash("fn a() {}\n")
+    );
+    assert!(content.contains("<documents>\n<document index=\"1\">"));
+    assert!(content.contains(&source));
+    assert!(content.contains("<document_content>\nfn a() {}\n</document_content>"));
+    let found = stats::file_stats(&content);
+    assert_eq!(found.len(), 1);
+    assert_eq!(found[0].path, a.to_string_lossy().replace('\\', "/"));
+    Ok(())
+}
+
+#[test]
+fn test_openai_uses_markdown_sections() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let a = d.path().join("a.rs");
+    let notes = d.path().join("notes.md");
+    fs::write(&a, "fn a() {}\n")?;
+    fs::write(&notes, "```sh\nrun\n```\n")?;
+    let content = pack_as(OutputFormat::Openai, &[a.clone(), notes])?;
+
+    let header = format!(
+        "## File: {} [base:{}]\n\n```rs\nfn a() {{}}\n```",
+        a.to_string_lossy().replace('\\', "/"),
+        manifest::base_hash("fn a() {}\n")
+    );
+    assert!(content.contains(&header));
+    assert!(content.contains("````md\n```sh\nrun\n```\n````"));
+    let found = stats::file_stats(&content);
+    assert_eq!(found.len(), 2);
+    Ok(())
+}
+
+#[test]
+fn test_xml_carries_base_hash() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let a = d.path().join("a.rs");
+    fs::write(&a, "fn a() {}\n")?;
+    let content = pack_as(OutputFormat::Xml, std::slice::from_ref(&a))?;
+
+    let base = format!(" base=\"{}\"", manifest::base_hash("fn a() {}\n"));
+    assert!(content.contains(&base));
+    Ok(())
+}
+
+#[test]
+fn test_fence_outgrows_backtick_runs() {
+    assert_eq!(presets::fence("plain"), "```");
+    assert_eq!(presets::fence("has ```` inside"), "`````");
+}
@@ -0,0 +1,58 @@
+// tests/unit_pack_remote.rs
+use slopchop_core::pack::remote::{self, Remote};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::tempdir;
+
+fn git(dir: &Path, args: &[&str]) {
+    let ok = Command::new("git")
+        .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .is_ok_and(|s| s.success());
+    assert!(ok, "git {args:?} failed");
+}
+
+// One test: `within` changes the process working directory.
+#[test]
+fn test_within_checks_out_ref_and_cleans_up() -> anyhow::Result<()> {
+    let repo = tempdir()?;
+    git(repo.path(), &["init", "-q"]);
+    fs::write(repo.path().join("lib.rs"), "pub fn v1() {}\n")?;
+    git(repo.path(), &["add", "."]);
+    git(repo.path(), &["commit", "-qm", "v1"]);
+    git(repo.path(), &["tag", "v1"]);
+    fs::write(repo.path().join("lib.rs"), "pub fn v2() {}\n")?;
+    git(repo.path(), &["commit", "-qam", "v2"]);
+
+    let home = env::current_dir()?;
+    let url = format!("file://{}", repo.path().display());
+    let latest = Remote {
+        url: url.clone(),
+        git_ref: None,
+    };
+    let (content, checkout) = remote::within(&latest, || {
+        Ok((fs::read_to_string("lib.rs")?, env::current_dir()?))
+    })?;
+    assert_eq!(content, "pub fn v2() {}\n");
+    assert_eq!(env::current_dir()?, home);
+    assert!(!checkout.exists());
+
+    let tagged = Remote {
+        url,
+        git_ref: Some("v1".into()),
+    };
+    let content = remote::within(&tagged, || Ok(fs::read_to_string("lib.rs")?))?;
+    assert_eq!(content, "pub fn v1() {}\n");
+
+    let missing = Remote {
+        url: format!("file://{}", home.join("no_such_repo").display()),
+        git_ref: None,
+    };
+    assert!(remote::within(&missing, || Ok(())).is_err());
+    assert_eq!(env::current_dir()?, home);
+    Ok(())
+}
@@ -0,0 +1,50 @@
+// tests/unit_pack_remote_config.rs
+use slopchop_core::pack::remote::{self, Remote};
+use slopchop_core::pack::{PackOptions, PackOutput};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process::Command;
+use tempfile::tempdir;
+
+fn git(dir: &Path, args: &[&str]) {
+    let ok = Command::new("git")
+        .args(["-c", "user.name=t", "-c", "user.email=t@t"])
+        .args(args)
+        .current_dir(dir)
+        .status()
+        .is_ok_and(|s| s.success());
+    assert!(ok, "git {args:?} failed");
+}
+
+// One test: `build` changes the process working directory.
+#[test]
+fn test_remote_config_is_ignored() -> anyhow::Result<()> {
+    let home = tempdir()?;
+    let secret = home.path().join("secret.txt");
+    fs::write(&secret, "TOP-SECRET-VALUE\n")?;
+
+    let repo = tempdir()?;
+    let hostile = format!("[pack]\nheader_template = {:?}\n", secret.display());
+    fs::write(repo.path().join("slopchop.toml"), hostile)?;
+    fs::write(repo.path().join("lib.rs"), "pub fn remote_code() {}\n")?;
+    git(repo.path(), &["init", "-q"]);
+    git(repo.path(), &["add", "."]);
+    git(repo.path(), &["commit", "-qm", "init"]);
+
+    env::set_current_dir(home.path())?;
+    let remote = Remote {
+        url: format!("file://{}", repo.path().display()),
+        git_ref: None,
+    };
+    let opts = PackOptions {
+        output: PackOutput::Stdout,
+        prompt: true,
+        ..PackOptions::default()
+    };
+    let (content, _, _) = remote::build(&remote, &opts)?;
+
+    assert!(content.contains("remote_code"));
+    assert!(!content.contains("TOP-SECRET-VALUE"));
+    Ok(())
+}
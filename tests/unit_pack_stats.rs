@@ -0,0 +1,37 @@
+// tests/unit_pack_stats.rs
+use slopchop_core::config::Config;
+use slopchop_core::pack::{self, stats, OutputFormat, PackOptions};
+use std::fs;
+use tempfile::tempdir;
+
+#[test]
+fn test_file_stats_sorted_biggest_first() {
+    let content = "header\n#__SLOPCHOP_FILE__# small.rs [base:abc]\nfn a() {}\n#__SLOPCHOP_END__#\n\
+                   #__SLOPCHOP_FILE__# big.rs\nfn b() { let x = 1; let y = 2; }\n#__SLOPCHOP_END__#\nfooter\n";
+    let stats = stats::file_stats(content);
+    let paths: Vec<_> = stats.iter().map(|s| s.path.as_str()).collect();
+    assert_eq!(paths, ["big.rs", "small.rs"]);
+    assert!(stats[0].tokens > stats[1].tokens);
+}
+
+#[test]
+fn test_file_stats_reads_packs_in_both_formats() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let a = d.path().join("a.rs");
+    let b = d.path().join("b.rs");
+    fs::write(&a, "fn a() {}\n")?;
+    fs::write(&b, "fn b() {}\n".repeat(20))?;
+    let files = [a, b.clone()];
+
+    for format in [OutputFormat::Text, OutputFormat::Xml] {
+        let opts = PackOptions {
+            format,
+            ..PackOptions::default()
+        };
+        let content = pack::generate_content(&files, &opts, &Config::default())?;
+        let stats = stats::file_stats(&content);
+        assert_eq!(stats.len(), 2);
+        assert_eq!(stats[0].path, b.to_string_lossy().replace('\\', "/"));
+    }
+    Ok(())
+}
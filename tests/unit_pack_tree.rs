@@ -0,0 +1,57 @@
+// tests/unit_pack_tree.rs
+use slopchop_core::config::Config;
+use slopchop_core::pack::tree::{self, Packed};
+use slopchop_core::pack::{self, PackOptions};
+use std::fs;
+use std::path::PathBuf;
+use tempfile::tempdir;
+
+#[test]
+fn test_render_indents_with_totals_and_marks() {
+    let entries = [
+        (PathBuf::from("src/main.rs"), 100, Packed::Full),
+        (PathBuf::from("src/pack/mod.rs"), 50, Packed::Skeleton),
+        (PathBuf::from("README.md"), 30, Packed::Omitted),
+    ];
+    assert_eq!(
+        tree::render(&entries),
+        "./ (180)\n  src/ (150)\n    pack/ (50)\n      mod.rs (50) [skeleton]\n    \
+         main.rs (100)\n  README.md (30) [omitted]\n"
+    );
+}
+
+#[test]
+fn test_render_is_relative_to_common_root() {
+    let entries = [
+        (PathBuf::from("/tmp/x/a.rs"), 1, Packed::Full),
+        (PathBuf::from("/tmp/x/b/c.rs"), 2, Packed::Full),
+    ];
+    assert_eq!(
+        tree::render(&entries),
+        "./ (3)\n  b/ (2)\n    c.rs (2)\n  a.rs (1)\n"
+    );
+}
+
+#[test]
+fn test_pack_tree_lists_files_left_out() -> anyhow::Result<()> {
+    let d = tempdir()?;
+    let a = d.path().join("a.rs");
+    let b = d.path().join("b.rs");
+    fs::write(&a, "fn a() {}\n")?;
+    fs::write(&b, "fn b() {}\n")?;
+    let opts = PackOptions {
+        focus: vec![a.clone()],
+        depth: 0,
+        ..PackOptions::default()
+    };
+
+    let content = pack::generate_content(&[a, b], &opts, &Config::default())?;
+    assert!(content.contains("# ═══ PROJECT TREE (tokens) ═══"));
+    assert!(content.contains("b.rs (") && content.contains("[omitted]"));
+
+    let mut config = Config::default();
+    config.pack.tree = false;
+    let content = pack::generate_content(&[], &PackOptions::default(), &config)?;
+    assert!(!content.contains("PROJECT TREE"));
+    Ok(())
+}
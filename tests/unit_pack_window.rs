@@ -0,0 +1,53 @@
+// tests/unit_pack_window.rs
+use slopchop_core::config::Config;
+use slopchop_core::pack::budget::Budget;
+use slopchop_core::pack::window;
+use slopchop_core::tokens::{TokenModel, Tokenizer};
+
+#[test]
+fn test_parse_sizes() {
+    assert_eq!(window::parse("128k"), Ok(128_000));
+    assert_eq!(window::parse("200K"), Ok(200_000));
+    assert_eq!(window::parse("1m"), Ok(1_000_000));
+    assert_eq!(window::parse("50000"), Ok(50_000));
+    assert!(window::parse("0").is_err());
+    assert!(window::parse("big").is_err());
+}
+
+#[test]
+fn test_size_precedence() {
+    let mut config = Config::default();
+    config.parse_toml("[tokens]\nmodel = \"claude\"\n");
+    Tokenizer::configure(config.tokens.model);
+    assert_eq!(window::size(Budget::default(), &config), 200_000);
+    assert_eq!(TokenModel::Cl100k.context_window(), 128_000);
+
+    config.parse_toml("[tokens]\nwindow = 1000000\n");
+    assert_eq!(window::size(Budget::default(), &config), 1_000_000);
+
+    let cli = Budget {
+        window: Some(32_000),
+        ..Budget::default()
+    };
+    assert_eq!(window::size(cli, &config), 32_000);
+}
+
+#[test]
+fn test_strict_fails_over_window_or_budget() {
+    let mut config = Config::default();
+    config.parse_toml("[tokens]\nwindow = 1000\n");
+    let strict = Budget {
+        strict: true,
+        ..Budget::default()
+    };
+    assert_eq!(window::percent(900, 1000), 90);
+    assert!(window::check(900, strict, &config).is_ok());
+    assert!(window::check(1001, strict, &config).is_err());
+    assert!(window::check(1001, Budget::default(), &config).is_ok());
+
+    let limited = Budget {
+        limit: Some(500),
+        ..strict
+    };
+    assert!(window::check(600, limited, &config).is_err());
+}
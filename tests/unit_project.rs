@@ -1,6 +1,9 @@
 // tests/unit_project.rs
-use slopchop_core::project::ProjectType;
-use std::fs::File;
+use slopchop_core::config::validate;
+use slopchop_core::config::Config;
+use slopchop_core::project::{self, ProjectType, Strictness};
+use std::fs::{self, File};
+use std::path::{Path, PathBuf};
 use tempfile::TempDir;
 
 #[test]
@@ -36,3 +39,179 @@ fn test_detect_unknown() {
     let temp = TempDir::new().unwrap();
     assert_eq!(ProjectType::detect_in(temp.path()), ProjectType::Unknown);
 }
+
+fn project_with(files: &[&str]) -> TempDir {
+    let temp = TempDir::new().unwrap();
+    for file in files {
+        let path = temp.path().join(file);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        File::create(path).unwrap();
+    }
+    temp
+}
+
+fn detect(files: &[&str]) -> ProjectType {
+    ProjectType::detect_in(project_with(files).path())
+}
+
+#[test]
+fn test_detect_new_types() {
+    assert_eq!(detect(&["pom.xml"]), ProjectType::Maven);
+    assert_eq!(detect(&["build.gradle.kts"]), ProjectType::Gradle);
+    assert_eq!(detect(&["App.sln"]), ProjectType::DotNet);
+    assert_eq!(detect(&["App.csproj"]), ProjectType::DotNet);
+    assert_eq!(detect(&["setup.py"]), ProjectType::Python);
+}
+
+#[test]
+fn test_deno_and_bun_win_over_node() {
+    assert_eq!(detect(&["deno.json"]), ProjectType::Deno);
+    assert_eq!(detect(&["package.json", "deno.jsonc"]), ProjectType::Deno);
+    assert_eq!(detect(&["package.json", "bun.lockb"]), ProjectType::Bun);
+    assert_eq!(detect(&["pom.xml", "build.gradle"]), ProjectType::Maven);
+}
+
+#[test]
+fn test_detect_mixed() {
+    assert_eq!(
+        detect(&["Cargo.toml", "web/package.json"]),
+        ProjectType::Mixed
+    );
+    assert_eq!(detect(&["web/package.json"]), ProjectType::Mixed);
+    assert_eq!(
+        detect(&["services/api/go.mod", "services/site/package.json"]),
+        ProjectType::Mixed
+    );
+}
+
+#[test]
+fn test_workspace_members_of_the_root_type_are_not_mixed() {
+    assert_eq!(
+        detect(&["Cargo.toml", "crates/core/Cargo.toml"]),
+        ProjectType::Rust
+    );
+    assert_eq!(
+        detect(&[
+            "package.json",
+            "packages/ui/package.json",
+            "docs/requirements.txt"
+        ]),
+        ProjectType::Node
+    );
+}
+
+#[test]
+fn test_members_lists_directories() {
+    let temp = project_with(&["Cargo.toml", "web/package.json", "services/api/go.mod"]);
+    let found: Vec<(ProjectType, PathBuf)> = project::members(temp.path())
+        .into_iter()
+        .map(|m| (m.kind, m.dir))
+        .collect();
+    assert_eq!(
+        found,
+        [
+            (ProjectType::Rust, PathBuf::new()),
+            (ProjectType::Go, PathBuf::from("services/api")),
+            (ProjectType::Node, PathBuf::from("web")),
+        ]
+    );
+}
+
+#[test]
+fn test_generated_configs_are_valid() {
+    let kinds = [
+        ProjectType::Rust,
+        ProjectType::Node,
+        ProjectType::Deno,
+        ProjectType::Bun,
+        ProjectType::Python,
+        ProjectType::Go,
+        ProjectType::Maven,
+        ProjectType::Gradle,
+        ProjectType::DotNet,
+        ProjectType::Unknown,
+    ];
+    let temp = TempDir::new().unwrap();
+    for kind in kinds {
+        let content = project::generate_toml_in(temp.path(), kind, Strictness::Standard);
+        assert_eq!(validate::check(&content), vec![], "{kind:?}:\n{content}");
+    }
+}
+
+#[test]
+fn test_generated_config_uses_project_patterns() {
+    let temp = project_with(&["App.csproj"]);
+    let content = project::generate_toml_in(temp.path(), ProjectType::DotNet, Strictness::Standard);
+    let mut config = Config::new();
+    config.parse_toml(&content);
+    assert_eq!(config.commands["check"][0].run, "dotnet build");
+    assert!(config
+        .rules
+        .exempt
+        .ignore_naming_on
+        .contains(&"*.Tests".to_string()));
+    assert!(config
+        .rules
+        .exempt
+        .ignore_tokens_on
+        .contains(&"*.lock".to_string()));
+    assert!(config
+        .rules
+        .exempt
+        .ignore_tokens_on
+        .contains(&"packages.lock.json".to_string()));
+    assert!(config
+        .pack
+        .generated
+        .contains(&"**/*.Designer.cs".to_string()));
+    assert!(config.pack.generated.contains(&"**/*.min.js".to_string()));
+}
+
+#[test]
+fn test_poetry_and_wrappers() {
+    let temp = project_with(&["poetry.lock", "gradlew"]);
+    let content = project::generate_toml_in(temp.path(), ProjectType::Python, Strictness::Standard);
+    assert!(content.contains("check = \"poetry run ruff check .\""));
+    let content = project::generate_toml_in(temp.path(), ProjectType::Gradle, Strictness::Standard);
+    let gradle = if cfg!(windows) { "gradle" } else { "./gradlew" };
+    assert!(content.contains(&format!("check = \"{gradle} check\"")));
+}
+
+#[test]
+fn test_mixed_config_runs_members_in_their_directories() {
+    let temp = project_with(&["Cargo.toml", "web/package.json", "web/tsconfig.json"]);
+    let content = project::generate_toml_in(temp.path(), ProjectType::Mixed, Strictness::Standard);
+    assert_eq!(validate::check(&content), vec![], "{content}");
+
+    let mut config = Config::new();
+    config.parse_toml(&content);
+    let check = &config.commands["check"];
+    assert_eq!(check.len(), 3);
+    assert_eq!(check[0].cwd, None);
+    assert_eq!(check[2].cwd, Some(PathBuf::from("web")));
+    assert!(check[2].run.ends_with("@biomejs/biome check src/"));
+
+    let web = &config.verify["node-web"];
+    assert_eq!(web.cwd, Some(PathBuf::from("web")));
+    assert!(web.extensions.contains(&"tsx".to_string()));
+    assert_eq!(config.verify["rust"].cwd, None);
+}
+
+#[test]
+fn test_default_commands_follow_detection() {
+    let temp = project_with(&["go.mod", "frontend/deno.json"]);
+    let commands = project::default_commands(temp.path());
+    let check: Vec<(&str, Option<&Path>)> = commands["check"]
+        .iter()
+        .map(|s| (s.run.as_str(), s.cwd.as_deref()))
+        .collect();
+    assert_eq!(
+        check,
+        [
+            ("go vet ./...", None),
+            ("go test ./...", None),
+            ("deno lint", Some(Path::new("frontend"))),
+            ("deno fmt --check", Some(Path::new("frontend"))),
+        ]
+    );
+}
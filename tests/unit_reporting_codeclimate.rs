@@ -0,0 +1,50 @@
+// tests/unit_reporting_codeclimate.rs
+//! Tests for the Code Climate / GitLab Code Quality format.
+
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::Config;
+use slopchop_core::reporting::codeclimate;
+use std::fs;
+use std::path::Path;
+use tempfile::TempDir;
+
+fn fingerprints(path: &Path, content: &str) -> Vec<String> {
+    fs::write(path, content).unwrap();
+    let report = RuleEngine::new(Config::default()).scan(vec![path.to_path_buf()]);
+    codeclimate::issues(&report)
+        .into_iter()
+        .map(|i| i.fingerprint)
+        .collect()
+}
+
+#[test]
+fn test_code_climate_issue_shape() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    fs::write(&path, "fn f() {\n    Some(1).unwrap();\n}\n").unwrap();
+    let report = RuleEngine::new(Config::default()).scan(vec![path.clone()]);
+
+    let out: serde_json::Value =
+        serde_json::from_str(&codeclimate::render(&report).unwrap()).unwrap();
+    let issue = &out[0];
+    assert_eq!(issue["type"], "issue");
+    assert_eq!(issue["check_name"], "paranoia");
+    assert_eq!(issue["categories"][0], "Bug Risk");
+    assert_eq!(issue["severity"], "major");
+    assert_eq!(issue["location"]["path"], path.to_str().unwrap());
+    assert_eq!(issue["location"]["lines"]["begin"], 2);
+    assert_eq!(issue["fingerprint"].as_str().unwrap().len(), 16);
+}
+
+#[test]
+fn test_fingerprints_survive_line_shifts_and_stay_unique() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    let body = "fn f() {\n    Some(1).unwrap();\n    Some(1).unwrap();\n}\n";
+    let before = fingerprints(&path, body);
+    let after = fingerprints(&path, &format!("// header\n\n{body}"));
+
+    assert_eq!(before.len(), 2);
+    assert_ne!(before[0], before[1], "identical lines need distinct ids");
+    assert_eq!(before, after);
+}
@@ -0,0 +1,31 @@
+// tests/unit_reporting_group.rs
+//! Tests for `--group-by` aggregation.
+
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::Config;
+use slopchop_core::reporting::group::{group, GroupBy};
+use std::path::{Path, PathBuf};
+
+#[test]
+fn test_groups_by_law_and_rule_with_worst_files() {
+    let mut config = Config::default();
+    config.rules.functions.max_nesting_depth = 1;
+    let nested = "fn f() {\n    if true {\n        if true {}\n    }\n}\n";
+    let unwraps = "fn g() {\n    Some(1).unwrap();\n    Some(2).unwrap();\n}\n";
+    let report = RuleEngine::new(config).scan_sources(vec![
+        (PathBuf::from("a.rs"), format!("{unwraps}{nested}")),
+        (PathBuf::from("b.rs"), unwraps.replace('g', "h")),
+    ]);
+
+    let laws = group(&report, GroupBy::Law);
+    assert_eq!(laws[0].name, "LAW OF PARANOIA");
+    assert_eq!(laws[0].count, 4);
+    let files: Vec<_> = laws[0].files.iter().map(|(p, n)| (*p, *n)).collect();
+    assert_eq!(files, vec![(Path::new("a.rs"), 2), (Path::new("b.rs"), 2)]);
+
+    let rules = group(&report, GroupBy::Rule);
+    assert!(rules.iter().any(|g| g.name == "complexity: Deep Nesting"));
+    assert!(rules
+        .iter()
+        .any(|g| g.name == "paranoia: Banned" && g.count == 4));
+}
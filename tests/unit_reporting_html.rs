@@ -0,0 +1,27 @@
+// tests/unit_reporting_html.rs
+//! Tests for the HTML report.
+
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::Config;
+use slopchop_core::reporting::html;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_html_report_groups_by_law_with_snippet() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    fs::write(
+        &path,
+        "fn f() {\n    let v: Vec<u8> = x();\n    Some(1).unwrap();\n}\n",
+    )
+    .unwrap();
+    let report = RuleEngine::new(Config::default()).scan(vec![path]);
+
+    let out = html::render(&report);
+    assert!(out.starts_with("<!DOCTYPE html>"));
+    assert!(out.contains("<h2>LAW OF PARANOIA (1)</h2>"));
+    assert!(out.contains("<span class=\"hit\">    3 |     Some(1).unwrap();</span>"));
+    assert!(out.contains("Vec&lt;u8&gt;"), "snippet should be escaped");
+    assert!(!out.contains("{{"), "all placeholders filled");
+}
@@ -0,0 +1,30 @@
+// tests/unit_reporting_json.rs
+//! Tests for the JSON report format.
+
+use slopchop_core::analysis::RuleEngine;
+use slopchop_core::config::Config;
+use slopchop_core::reporting::json;
+use std::fs;
+use tempfile::TempDir;
+
+#[test]
+fn test_json_report_shape() {
+    let dir = TempDir::new().unwrap();
+    let path = dir.path().join("a.rs");
+    fs::write(&path, "fn f() {\n    Some(1).unwrap();\n}\n").unwrap();
+    let report = RuleEngine::new(Config::default()).scan(vec![path.clone()]);
+
+    let out: serde_json::Value = serde_json::from_str(&json::render(&report).unwrap()).unwrap();
+    assert_eq!(out["version"], json::VERSION);
+    assert_eq!(out["summary"]["errors"], 1);
+    assert_eq!(out["summary"]["files"], 1);
+
+    let v = &out["violations"][0];
+    assert_eq!(v["file"], path.to_str().unwrap());
+    assert_eq!(v["line"], 2);
+    assert_eq!(v["severity"], "error");
+    assert_eq!(v["law"], "LAW OF PARANOIA");
+    assert_eq!(v["rule"], "paranoia");
+    assert!(v["message"].as_str().unwrap().contains("unwrap"));
+    assert_eq!(out["report"]["files"][0]["violations"][0]["row"], 1);
+}
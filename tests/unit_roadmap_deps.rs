@@ -0,0 +1,162 @@
+// tests/unit_roadmap_deps.rs
+use slopchop_core::roadmap_v2::types::TaskUpdate;
+use slopchop_core::roadmap_v2::{parse_commands, RoadmapCommand, TaskStatus, TaskStore};
+use tempfile::TempDir;
+
+const TASKS: &str = r#"
+[meta]
+title = "Test"
+
+[[tasks]]
+id = "schema"
+text = "Design schema"
+section = "v1"
+
+[[tasks]]
+id = "api"
+text = "Build API"
+section = "v1"
+depends_on = ["schema"]
+
+[[tasks]]
+id = "ui"
+text = "Build UI"
+section = "v1"
+depends_on = ["api", "schema"]
+"#;
+
+fn store() -> TaskStore {
+    toml::from_str(TASKS).unwrap()
+}
+
+fn apply(store: &mut TaskStore, input: &str) -> Result<(), String> {
+    let cmds = parse_commands(input).map_err(|e| e.to_string())?;
+    for cmd in cmds {
+        store.apply(cmd).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn status(store: &TaskStore, id: &str) -> TaskStatus {
+    store
+        .tasks
+        .iter()
+        .find(|t| t.id == id)
+        .unwrap()
+        .status
+        .clone()
+}
+
+#[test]
+fn test_check_rejected_while_dependencies_pending() {
+    let mut store = store();
+    let err = apply(&mut store, "===ROADMAP===\nCHECK\nid = ui\n===ROADMAP===").unwrap_err();
+    assert!(err.contains("blocked by pending: api, schema"), "{err}");
+    assert_eq!(status(&store, "ui"), TaskStatus::Pending);
+
+    apply(
+        &mut store,
+        "===ROADMAP===\nCHECK\nid = schema\n===ROADMAP===",
+    )
+    .unwrap();
+    apply(&mut store, "===ROADMAP===\nCHECK\nid = api\n===ROADMAP===").unwrap();
+    apply(&mut store, "===ROADMAP===\nCHECK\nid = ui\n===ROADMAP===").unwrap();
+    assert_eq!(status(&store, "ui"), TaskStatus::Done);
+}
+
+#[test]
+fn test_no_test_dependencies_count_as_done() {
+    let mut store = store();
+    store.tasks[0].status = TaskStatus::NoTest;
+    apply(&mut store, "===ROADMAP===\nCHECK\nid = api\n===ROADMAP===").unwrap();
+    assert_eq!(status(&store, "api"), TaskStatus::Done);
+}
+
+#[test]
+fn test_blocked_lists_pending_dependencies() {
+    let mut store = store();
+    let blocked: Vec<(&str, Vec<&str>)> = store
+        .blocked()
+        .into_iter()
+        .map(|(t, deps)| (t.id.as_str(), deps))
+        .collect();
+    assert_eq!(
+        blocked,
+        [("api", vec!["schema"]), ("ui", vec!["api", "schema"])]
+    );
+
+    store.tasks[0].status = TaskStatus::Done;
+    let blocked: Vec<&str> = store.blocked().iter().map(|(t, _)| t.id.as_str()).collect();
+    assert_eq!(blocked, ["ui"]);
+}
+
+#[test]
+fn test_add_and_update_parse_dependencies() {
+    let mut store = store();
+    let add = "===ROADMAP===\nADD\nid = docs\ntext = Write docs\nsection = v1\ndepends_on = api, ui\n===ROADMAP===";
+    apply(&mut store, add).unwrap();
+    let docs = store.tasks.iter().find(|t| t.id == "docs").unwrap();
+    assert_eq!(docs.depends_on, ["api", "ui"]);
+
+    let update = "===ROADMAP===\nUPDATE\nid = docs\ndepends_on = [\"schema\"]\n===ROADMAP===";
+    apply(&mut store, update).unwrap();
+    let docs = store.tasks.iter().find(|t| t.id == "docs").unwrap();
+    assert_eq!(docs.depends_on, ["schema"]);
+
+    let clear = "===ROADMAP===\nUPDATE\nid = docs\ndepends_on = []\n===ROADMAP===";
+    apply(&mut store, clear).unwrap();
+    assert!(store
+        .tasks
+        .iter()
+        .find(|t| t.id == "docs")
+        .unwrap()
+        .depends_on
+        .is_empty());
+}
+
+#[test]
+fn test_unknown_and_cyclic_dependencies_rejected() {
+    let mut store = store();
+    let unknown =
+        "===ROADMAP===\nADD\nid = x\ntext = X\nsection = v1\ndepends_on = nope\n===ROADMAP===";
+    assert!(apply(&mut store, unknown)
+        .unwrap_err()
+        .contains("Unknown dependency"));
+
+    let cycle = RoadmapCommand::Update {
+        id: "schema".to_string(),
+        fields: TaskUpdate {
+            depends_on: Some(vec!["ui".to_string()]),
+            ..TaskUpdate::default()
+        },
+    };
+    let err = store.apply(cycle).unwrap_err().to_string();
+    assert!(err.contains("Dependency cycle"), "{err}");
+
+    let own = "===ROADMAP===\nUPDATE\nid = api\ndepends_on = api\n===ROADMAP===";
+    assert!(apply(&mut store, own).is_err());
+}
+
+#[test]
+fn test_delete_rejected_while_depended_on() {
+    let mut store = store();
+    let err = apply(
+        &mut store,
+        "===ROADMAP===\nDELETE\nid = schema\n===ROADMAP===",
+    )
+    .unwrap_err();
+    assert!(err.contains("dependency of: api, ui"), "{err}");
+    apply(&mut store, "===ROADMAP===\nDELETE\nid = ui\n===ROADMAP===").unwrap();
+}
+
+#[test]
+fn test_dependencies_round_trip() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("tasks.toml");
+    store().save(Some(&path)).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(content.matches("depends_on").count(), 2);
+    let loaded = TaskStore::load(Some(&path)).unwrap();
+    assert_eq!(loaded.tasks[2].depends_on, ["api", "schema"]);
+}
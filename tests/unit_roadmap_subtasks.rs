@@ -0,0 +1,179 @@
+// tests/unit_roadmap_subtasks.rs
+use slopchop_core::roadmap_v2::subtasks;
+use slopchop_core::roadmap_v2::types::TaskUpdate;
+use slopchop_core::roadmap_v2::{parse_commands, RoadmapCommand, Task, TaskStatus, TaskStore};
+use tempfile::TempDir;
+
+const TASKS: &str = r#"
+[meta]
+title = "Test"
+
+[[sections]]
+id = "v1"
+title = "v1"
+
+[[tasks]]
+id = "auth"
+text = "Authentication"
+section = "v1"
+group = "Backend"
+
+[[tasks]]
+id = "login"
+text = "Login"
+section = "v1"
+group = "Backend"
+parent = "auth"
+
+[[tasks]]
+id = "tokens"
+text = "Tokens"
+section = "v1"
+group = "Backend"
+parent = "auth"
+
+[[tasks]]
+id = "refresh"
+text = "Refresh tokens"
+section = "v1"
+group = "Backend"
+parent = "tokens"
+"#;
+
+fn store() -> TaskStore {
+    toml::from_str(TASKS).unwrap()
+}
+
+fn apply(store: &mut TaskStore, input: &str) -> Result<(), String> {
+    let cmds = parse_commands(input).map_err(|e| e.to_string())?;
+    for cmd in cmds {
+        store.apply(cmd).map_err(|e| e.to_string())?;
+    }
+    Ok(())
+}
+
+fn task<'a>(store: &'a TaskStore, id: &str) -> &'a Task {
+    store.tasks.iter().find(|t| t.id == id).unwrap()
+}
+
+#[test]
+fn test_progress_rolls_up_every_level() {
+    let mut store = store();
+    assert_eq!(store.progress("auth"), (0, 3));
+    assert_eq!(store.progress("tokens"), (0, 1));
+    assert_eq!(store.progress("login"), (0, 0));
+
+    apply(
+        &mut store,
+        "===ROADMAP===\nCHECK\nid = refresh\n===ROADMAP===",
+    )
+    .unwrap();
+    apply(
+        &mut store,
+        "===ROADMAP===\nCHECK\nid = login\n===ROADMAP===",
+    )
+    .unwrap();
+    let (done, total) = store.progress("auth");
+    assert_eq!((done, total), (2, 3));
+    assert_eq!(subtasks::percent(done, total), 66);
+    assert_eq!(subtasks::percent(0, 0), 100);
+}
+
+#[test]
+fn test_outline_nests_subtasks_under_parents() {
+    let store = store();
+    let mut tasks: Vec<_> = store.tasks.iter().collect();
+    tasks.reverse();
+    let outline: Vec<(&str, usize)> = store
+        .outline(&tasks)
+        .into_iter()
+        .map(|(t, depth)| (t.id.as_str(), depth))
+        .collect();
+    assert_eq!(
+        outline,
+        [("auth", 0), ("tokens", 1), ("refresh", 2), ("login", 1)]
+    );
+
+    let partial = [task(&store, "refresh"), task(&store, "login")];
+    assert_eq!(store.outline(&partial).len(), 2);
+    assert!(store.outline(&partial).iter().all(|(_, depth)| *depth == 0));
+}
+
+#[test]
+fn test_add_subtask_takes_parent_placement() {
+    let mut store = store();
+    apply(
+        &mut store,
+        "===ROADMAP===\nADD SUBTASK\nid = logout\ntext = Logout\nparent = auth\n===ROADMAP===",
+    )
+    .unwrap();
+    let logout = task(&store, "logout");
+    assert_eq!(logout.parent.as_deref(), Some("auth"));
+    assert_eq!(logout.section, "v1");
+    assert_eq!(logout.group.as_deref(), Some("Backend"));
+
+    let missing = "===ROADMAP===\nADD SUBTASK\nid = x\ntext = X\n===ROADMAP===";
+    assert!(apply(&mut store, missing).unwrap_err().contains("parent"));
+    let unknown = "===ROADMAP===\nADD\nid = x\ntext = X\nparent = nope\n===ROADMAP===";
+    assert!(apply(&mut store, unknown)
+        .unwrap_err()
+        .contains("Unknown parent"));
+}
+
+#[test]
+fn test_check_rejected_while_subtasks_pending() {
+    let mut store = store();
+    let err = apply(&mut store, "===ROADMAP===\nCHECK\nid = auth\n===ROADMAP===").unwrap_err();
+    assert!(err.contains("pending subtasks"), "{err}");
+
+    for id in ["login", "refresh", "tokens", "auth"] {
+        let check = format!("===ROADMAP===\nCHECK\nid = {id}\n===ROADMAP===");
+        apply(&mut store, &check).unwrap();
+    }
+    assert_eq!(task(&store, "auth").status, TaskStatus::Done);
+}
+
+#[test]
+fn test_parent_cycles_and_deleting_parents_rejected() {
+    let mut store = store();
+    let cycle = RoadmapCommand::Update {
+        id: "auth".to_string(),
+        fields: TaskUpdate {
+            parent: Some("refresh".to_string()),
+            ..TaskUpdate::default()
+        },
+    };
+    let err = store.apply(cycle).unwrap_err().to_string();
+    assert!(err.contains("Subtask cycle"), "{err}");
+
+    let err = apply(
+        &mut store,
+        "===ROADMAP===\nDELETE\nid = auth\n===ROADMAP===",
+    )
+    .unwrap_err();
+    assert!(err.contains("has subtasks: login, tokens"), "{err}");
+
+    let moved = "===ROADMAP===\nUPDATE\nid = refresh\nparent = login\n===ROADMAP===";
+    apply(&mut store, moved).unwrap();
+    assert_eq!(task(&store, "refresh").parent.as_deref(), Some("login"));
+}
+
+#[test]
+fn test_markdown_indents_subtasks() {
+    let markdown = store().to_markdown();
+    assert!(markdown.contains("\n- [ ] **Authentication**\n"));
+    assert!(markdown.contains("\n  - [ ] **Login**\n"));
+    assert!(markdown.contains("\n    - [ ] **Refresh tokens**\n"));
+}
+
+#[test]
+fn test_parent_round_trip() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("tasks.toml");
+    store().save(Some(&path)).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(content.matches("parent =").count(), 3);
+    let loaded = TaskStore::load(Some(&path)).unwrap();
+    assert_eq!(loaded.tasks[3].parent.as_deref(), Some("tokens"));
+}
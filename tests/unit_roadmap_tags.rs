@@ -0,0 +1,131 @@
+// tests/unit_roadmap_tags.rs
+use slopchop_core::roadmap_v2::tags;
+use slopchop_core::roadmap_v2::{parse_commands, RoadmapCommand, TaskStore};
+use tempfile::TempDir;
+
+const TASKS: &str = r#"
+[meta]
+title = "Test"
+
+[[tasks]]
+id = "cache"
+text = "Add cache"
+section = "v1"
+tags = ["backend", "perf"]
+
+[[tasks]]
+id = "login"
+text = "Login page"
+section = "v1"
+tags = ["frontend"]
+
+[[tasks]]
+id = "docs"
+text = "Write docs"
+section = "v1"
+"#;
+
+fn store() -> TaskStore {
+    toml::from_str(TASKS).unwrap()
+}
+
+fn apply(store: &mut TaskStore, input: &str) {
+    for cmd in parse_commands(input).unwrap() {
+        store.apply(cmd).unwrap();
+    }
+}
+
+fn tags_of<'a>(store: &'a TaskStore, id: &str) -> &'a [String] {
+    &store.tasks.iter().find(|t| t.id == id).unwrap().tags
+}
+
+fn strings(items: &[&str]) -> Vec<String> {
+    items.iter().map(ToString::to_string).collect()
+}
+
+#[test]
+fn test_has_tags_requires_every_tag() {
+    let store = store();
+    let matching = |wanted: &[&str]| -> Vec<String> {
+        let wanted = strings(wanted);
+        store
+            .tasks
+            .iter()
+            .filter(|t| t.has_tags(&wanted))
+            .map(|t| t.id.clone())
+            .collect()
+    };
+    assert_eq!(matching(&["backend"]), ["cache"]);
+    assert_eq!(matching(&["backend", "perf"]), ["cache"]);
+    assert!(matching(&["backend", "frontend"]).is_empty());
+    assert_eq!(matching(&[]), ["cache", "login", "docs"]);
+}
+
+#[test]
+fn test_tag_and_untag_commands() {
+    let mut store = store();
+    apply(
+        &mut store,
+        "===ROADMAP===\nTAG\nid = docs\ntags = docs, perf, docs\n===ROADMAP===",
+    );
+    assert_eq!(tags_of(&store, "docs"), ["docs", "perf"]);
+
+    apply(
+        &mut store,
+        "===ROADMAP===\nTAG\nid = cache\ntags = [\"perf\", \"infra\"]\n===ROADMAP===",
+    );
+    assert_eq!(tags_of(&store, "cache"), ["backend", "perf", "infra"]);
+
+    apply(
+        &mut store,
+        "===ROADMAP===\nUNTAG\nid = cache\ntags = perf, missing\n===ROADMAP===",
+    );
+    assert_eq!(tags_of(&store, "cache"), ["backend", "infra"]);
+}
+
+#[test]
+fn test_tag_requires_tags_and_task() {
+    assert!(parse_commands("===ROADMAP===\nTAG\nid = docs\n===ROADMAP===").is_err());
+    let mut store = store();
+    let cmd = RoadmapCommand::Tag {
+        id: "nope".to_string(),
+        tags: strings(&["x"]),
+    };
+    assert!(store.apply(cmd).is_err());
+}
+
+#[test]
+fn test_add_and_update_set_tags() {
+    let mut store = store();
+    apply(
+        &mut store,
+        "===ROADMAP===\nADD\nid = api\ntext = API\nsection = v1\ntags = backend, api\n===ROADMAP===",
+    );
+    assert_eq!(tags_of(&store, "api"), ["backend", "api"]);
+
+    apply(
+        &mut store,
+        "===ROADMAP===\nUPDATE\nid = api\ntags = frontend\n===ROADMAP===",
+    );
+    assert_eq!(tags_of(&store, "api"), ["frontend"]);
+}
+
+#[test]
+fn test_normalize_trims_and_dedupes() {
+    assert_eq!(
+        tags::normalize(&strings(&[" perf ", "", "perf", "ui"])),
+        ["perf", "ui"]
+    );
+}
+
+#[test]
+fn test_tags_round_trip() {
+    let temp = TempDir::new().unwrap();
+    let path = temp.path().join("tasks.toml");
+    store().save(Some(&path)).unwrap();
+
+    let content = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(content.matches("tags =").count(), 2);
+    let loaded = TaskStore::load(Some(&path)).unwrap();
+    assert_eq!(loaded.tasks[0].tags, ["backend", "perf"]);
+}
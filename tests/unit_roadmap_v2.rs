@@ -149,6 +149,9 @@ fn test_duplicate_add_rejected() {
         group: None,
         test: None,
         order: 0,
+        depends_on: Vec::new(),
+        tags: Vec::new(),
+        parent: None,
     });
     
     let result = store.apply(cmd);
@@ -191,6 +194,9 @@ fn create_test_store() -> TaskStore {
                 group: Some("Test Group".to_string()),
                 test: None,
                 order: 1,
+                depends_on: Vec::new(),
+                tags: Vec::new(),
+                parent: None,
             },
             Task {
                 id: "task-two".to_string(),
@@ -200,6 +206,9 @@ fn create_test_store() -> TaskStore {
                 group: Some("Test Group".to_string()),
                 test: None,
                 order: 2,
+                depends_on: Vec::new(),
+                tags: Vec::new(),
+                parent: None,
             },
         ],
     }
@@ -0,0 +1,36 @@
+// tests/unit_tokens_model.rs
+use slopchop_core::config::Config;
+use slopchop_core::tokens::{TokenModel, Tokenizer};
+
+const TEXT: &str = "fn main() {\n    println!(\"Hello, tokenizer backends!\");\n}\n";
+
+#[test]
+fn test_models_count_differently() {
+    let cl100k = Tokenizer::count_with(TokenModel::Cl100k, TEXT);
+    let o200k = Tokenizer::count_with(TokenModel::O200k, TEXT);
+    let claude = Tokenizer::count_with(TokenModel::Claude, TEXT);
+    assert!(cl100k > 0 && o200k > 0);
+    assert!(claude > cl100k);
+    assert_eq!(Tokenizer::count_with(TokenModel::O200k, ""), 0);
+}
+
+#[test]
+fn test_config_selects_model() {
+    let mut config = Config::default();
+    config.parse_toml("[tokens]\nmodel = \"o200k\"\n");
+    assert_eq!(config.tokens.model, TokenModel::O200k);
+
+    Tokenizer::configure(config.tokens.model);
+    assert_eq!(Tokenizer::model(), TokenModel::O200k);
+    assert_eq!(
+        Tokenizer::count(TEXT),
+        Tokenizer::count_with(TokenModel::O200k, TEXT)
+    );
+}
+
+#[test]
+fn test_unknown_model_is_rejected() {
+    let mut config = Config::default();
+    config.parse_toml("[tokens]\nmodel = \"gpt2\"\n");
+    assert_eq!(config.tokens.model, TokenModel::Cl100k);
+}
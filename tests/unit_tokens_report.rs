@@ -0,0 +1,50 @@
+// tests/unit_tokens_report.rs
+use std::path::{Path, PathBuf};
+
+use slopchop_core::tokens::report::{self, DirTokens};
+
+fn files() -> Vec<(PathBuf, usize)> {
+    vec![
+        (PathBuf::from("src/main.rs"), 100),
+        (PathBuf::from("src/pack/mod.rs"), 300),
+        (PathBuf::from("src/pack/xml.rs"), 200),
+        (PathBuf::from("src/cli/mod.rs"), 50),
+        (PathBuf::from("README.md"), 350),
+    ]
+}
+
+fn child<'a>(dir: &'a DirTokens, name: &str) -> &'a DirTokens {
+    dir.children
+        .iter()
+        .find(|c| c.name == name)
+        .unwrap_or_else(|| panic!("no {name}"))
+}
+
+#[test]
+fn test_aggregate_sums_directories() {
+    let tree = report::aggregate(Path::new(""), &files());
+    assert_eq!((tree.tokens, tree.files), (1000, 5));
+    let src = child(&tree, "src");
+    assert_eq!((src.tokens, src.files), (650, 4));
+    let names: Vec<_> = src.children.iter().map(|c| c.name.as_str()).collect();
+    assert_eq!(names, ["pack", "cli"]);
+    assert_eq!(child(src, "pack").tokens, 500);
+}
+
+#[test]
+fn test_aggregate_below_root() {
+    let tree = report::aggregate(Path::new("src"), &files()[..4]);
+    assert_eq!(tree.name, "src");
+    assert_eq!(tree.tokens, 650);
+    assert_eq!(child(&tree, "pack").files, 2);
+}
+
+#[test]
+fn test_render_respects_depth() {
+    let tree = report::aggregate(Path::new(""), &files());
+    let shallow = report::render(&tree, 1);
+    assert!(shallow.contains("src/"));
+    assert!(shallow.contains(" 65.0%"));
+    assert!(!shallow.contains("pack/"));
+    assert!(report::render(&tree, 2).contains("    pack/"));
+}
@@ -0,0 +1,96 @@
+// tests/unit_workspace.rs
+use slopchop_core::config::Config;
+use slopchop_core::workspace::{self, Workspace};
+use std::fs;
+use std::path::{Path, PathBuf};
+use tempfile::TempDir;
+
+fn monorepo() -> TempDir {
+    let dir = TempDir::new().unwrap();
+    for (pkg, tokens) in [("packages/api", 900), ("packages/api/legacy", 5000)] {
+        fs::create_dir_all(dir.path().join(pkg)).unwrap();
+        fs::write(
+            dir.path().join(pkg).join("slopchop.toml"),
+            format!("[rules]\nmax_file_tokens = {tokens}\n"),
+        )
+        .unwrap();
+    }
+    fs::create_dir_all(dir.path().join("node_modules/dep")).unwrap();
+    fs::write(dir.path().join("node_modules/dep/slopchop.toml"), "").unwrap();
+    dir
+}
+
+fn load(dir: &TempDir) -> Workspace {
+    Workspace::load_in(dir.path(), Config::default())
+}
+
+#[test]
+fn test_package_dirs_skip_root_and_pruned() {
+    let dir = monorepo();
+    fs::write(dir.path().join("slopchop.toml"), "").unwrap();
+    let mut dirs = workspace::package_dirs(dir.path());
+    dirs.sort();
+    assert_eq!(
+        dirs,
+        vec![
+            PathBuf::from("packages/api"),
+            PathBuf::from("packages/api/legacy")
+        ]
+    );
+}
+
+#[test]
+fn test_nearest_package_config_wins() {
+    let dir = monorepo();
+    let ws = load(&dir);
+    let legacy = ws
+        .package_of(Path::new("packages/api/legacy/old.rs"))
+        .unwrap();
+    assert_eq!(legacy.name, "packages/api/legacy");
+    assert_eq!(legacy.config.rules.size.max_file_tokens, 5000);
+
+    let api = ws
+        .package_of(Path::new("./packages/api/src/lib.rs"))
+        .unwrap();
+    assert_eq!(api.config.rules.size.max_file_tokens, 900);
+
+    assert!(ws.package_of(Path::new("src/main.rs")).is_none());
+}
+
+#[test]
+fn test_package_by_path_or_name() {
+    let dir = monorepo();
+    let ws = load(&dir);
+    assert_eq!(ws.package("packages/api/").unwrap().name, "packages/api");
+    assert_eq!(ws.package("legacy").unwrap().name, "packages/api/legacy");
+
+    let err = ws.package("web").unwrap_err().to_string();
+    assert!(err.contains("web"));
+    assert!(err.contains("packages/api, packages/api/legacy"));
+}
+
+#[test]
+fn test_filter_keeps_package_files() {
+    let dir = monorepo();
+    let ws = load(&dir);
+    let files = vec![
+        PathBuf::from("src/main.rs"),
+        PathBuf::from("packages/api/src/lib.rs"),
+        PathBuf::from("packages/api/legacy/old.rs"),
+    ];
+    let kept = ws.filter(files.clone(), Some("api")).unwrap();
+    assert_eq!(kept, files[1..].to_vec());
+    assert_eq!(ws.filter(files.clone(), None).unwrap(), files);
+    assert!(ws.filter(files, Some("web")).is_err());
+}
+
+#[test]
+fn test_validate_packages_reports_package_errors() {
+    let dir = monorepo();
+    assert!(load(&dir).validate_packages().is_ok());
+
+    let broken = dir.path().join("packages/api/slopchop.toml");
+    fs::write(&broken, "[rules]\nmax_file_tokens = true\n").unwrap();
+    let err = load(&dir).validate_packages().unwrap_err().to_string();
+    assert!(err.contains("packages/api/slopchop.toml: line 2"), "{err}");
+}
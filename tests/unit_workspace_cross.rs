@@ -0,0 +1,46 @@
+// tests/unit_workspace_cross.rs
+use slopchop_core::config::Config;
+use slopchop_core::workspace::Workspace;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+// One test: `Workspace::scan` reads relative paths, so this changes the
+// process working directory.
+#[test]
+fn test_dead_code_sees_calls_from_other_packages() -> anyhow::Result<()> {
+    let dir = TempDir::new()?;
+    fs::create_dir_all(dir.path().join("pkg"))?;
+    fs::create_dir_all(dir.path().join("quiet"))?;
+    fs::write(
+        dir.path().join("pkg/slopchop.toml"),
+        "[rules]\ndetect_dead_code = true\n",
+    )?;
+    fs::write(
+        dir.path().join("pkg/lib.rs"),
+        "fn shared() {}\nfn unused() {}\n",
+    )?;
+    // Its own rules leave dead code detection off.
+    fs::write(dir.path().join("quiet/slopchop.toml"), "")?;
+    fs::write(dir.path().join("quiet/lib.rs"), "fn idle() {}\n")?;
+    fs::write(dir.path().join("main.rs"), "pub fn run() { shared(); }\n")?;
+    env::set_current_dir(dir.path())?;
+
+    let mut config = Config::default();
+    config.rules.cross_file.detect_dead_code = true;
+    let workspace = Workspace::load(config);
+    let files = ["pkg/lib.rs", "quiet/lib.rs", "main.rs"].map(PathBuf::from);
+    let report = workspace.scan(files.to_vec());
+
+    let dead: Vec<&str> = report
+        .files
+        .iter()
+        .flat_map(|f| &f.violations)
+        .filter(|v| v.message.contains("Dead Code"))
+        .map(|v| v.message.as_str())
+        .collect();
+    assert_eq!(dead.len(), 1, "{dead:?}");
+    assert!(dead[0].contains("'unused'"));
+    Ok(())
+}
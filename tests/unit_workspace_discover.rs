@@ -0,0 +1,32 @@
+// tests/unit_workspace_discover.rs
+use slopchop_core::config::{Config, GitMode};
+use slopchop_core::workspace::Workspace;
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+use tempfile::TempDir;
+
+// One test: discovery walks the current directory, so this changes the
+// process working directory.
+#[test]
+fn test_package_ignore_file_applies_inside_the_package() -> anyhow::Result<()> {
+    let dir = TempDir::new()?;
+    for sub in ["pkg/src", "pkg/proto", "proto"] {
+        fs::create_dir_all(dir.path().join(sub))?;
+    }
+    fs::write(dir.path().join("pkg/slopchop.toml"), "")?;
+    fs::write(dir.path().join("pkg/.slopchopignore"), "/proto\n")?;
+    for file in ["pkg/src/lib.rs", "pkg/proto/api.rs", "proto/root.rs"] {
+        fs::write(dir.path().join(file), "fn f() {}\n")?;
+    }
+    env::set_current_dir(dir.path())?;
+
+    let mut config = Config::default();
+    config.files.git_mode = GitMode::No;
+    let files = Workspace::load(config).discover()?;
+
+    assert!(files.contains(&PathBuf::from("pkg/src/lib.rs")), "{files:?}");
+    assert!(files.contains(&PathBuf::from("proto/root.rs")), "{files:?}");
+    assert!(!files.contains(&PathBuf::from("pkg/proto/api.rs")), "{files:?}");
+    Ok(())
+}